@@ -12,7 +12,27 @@ impl From<&ASTControl> for Rng {
 
 impl From<&Alternation> for Rng {
     fn from(val: &Alternation) -> Self {
-        Self::from_slice(&val.alternation_list)
+        let rng = Self::from_slice(&val.alternation_list);
+        let rng = val.alternation_opt.as_ref().map_or(rng, |alternation_opt| {
+            rng.extend(Self::from(alternation_opt))
+        });
+        val.alternation_opt0
+            .as_ref()
+            .map_or(rng, |alternation_opt0| {
+                rng.extend(Self::from(&alternation_opt0.alternation_label))
+            })
+    }
+}
+
+impl From<&AlternationLabel> for Rng {
+    fn from(val: &AlternationLabel) -> Self {
+        Self::from(&val.hash).extend(Self::from(&val.identifier))
+    }
+}
+
+impl From<&AlternationOpt> for Rng {
+    fn from(val: &AlternationOpt) -> Self {
+        Self::from(&val.percent_prec).extend(Self::from(&val.precedence_symbol))
     }
 }
 
@@ -34,6 +54,16 @@ impl From<&AlternationsList> for Rng {
     }
 }
 
+impl From<&Associativity> for Rng {
+    fn from(val: &Associativity) -> Self {
+        match val {
+            Associativity::PercentLeft(left) => Self::from(&left.percent_left),
+            Associativity::PercentRight(right) => Self::from(&right.percent_right),
+            Associativity::PercentNonassoc(non_assoc) => Self::from(&non_assoc.percent_nonassoc),
+        }
+    }
+}
+
 impl From<&CutOperator> for Rng {
     fn from(val: &CutOperator) -> Self {
         Self::from(&val.cut_operator)
@@ -53,6 +83,28 @@ impl From<&Declaration> for Rng {
                 Self::from(&user_type.percent_user_underscore_type)
                     .extend(Self::from(&user_type.user_type_name))
             }
+            Declaration::PercentIncludeString(include) => {
+                Self::from(&include.percent_include).extend(Self::from(&include.string))
+            }
+            Declaration::PercentKeywordsIdentifierList(keywords) => {
+                Self::from(&keywords.percent_keywords).extend(Self::from(&keywords.identifier_list))
+            }
+            Declaration::PercentInlineIdentifierList(inline) => {
+                Self::from(&inline.percent_inline).extend(Self::from(&inline.identifier_list))
+            }
+            Declaration::PercentDisplayIdentifierString(display) => {
+                Self::from(&display.percent_display).extend(Self::from(&display.string))
+            }
+            Declaration::ExpectDirectiveNumber(expect) => {
+                Self::from(&expect.expect_directive.expect_directive)
+                    .extend(Self::from(&expect.number))
+            }
+            Declaration::PercentDeriveDeclarationOptDeriveList(derive) => {
+                Self::from(&derive.percent_derive).extend(Self::from(&derive.derive_list))
+            }
+            Declaration::PrecedenceDeclaration(precedence) => {
+                Self::from(&precedence.precedence_declaration)
+            }
             Declaration::ScannerDirectives(scanner) => Self::from(&scanner.scanner_directives),
             Declaration::PercentGrammarUnderscoreTypeLiteralString(grammar_type) => {
                 Self::from(&grammar_type.percent_grammar_underscore_type)
@@ -68,6 +120,21 @@ impl From<&LiteralString> for Rng {
     }
 }
 
+impl From<&DeriveList> for Rng {
+    fn from(val: &DeriveList) -> Self {
+        let rng = Self::from(&val.user_type_name);
+        val.derive_list_list
+            .last()
+            .map_or(rng, |d| rng.extend(Self::from(d)))
+    }
+}
+
+impl From<&DeriveListList> for Rng {
+    fn from(val: &DeriveListList) -> Self {
+        Self::from(&val.comma).extend(Self::from(&val.user_type_name))
+    }
+}
+
 impl From<&DoubleColon> for Rng {
     fn from(val: &DoubleColon) -> Self {
         Self::from(&val.double_colon)
@@ -78,13 +145,22 @@ impl From<&Factor> for Rng {
     fn from(val: &Factor) -> Self {
         match val {
             Factor::Group(grp) => Self::from(&grp.group),
-            Factor::Repeat(rpt) => Self::from(&rpt.repeat),
+            Factor::Repeat(rpt) => Self::from(&*rpt.repeat),
             Factor::Optional(opt) => Self::from(&opt.optional),
-            Factor::Symbol(sym) => Self::from(&sym.symbol),
+            Factor::Symbol(sym) => Self::from(&*sym.symbol),
+            Factor::FieldLabelSymbol(sym) => {
+                Self::from(&sym.field_label).extend(Self::from(&*sym.symbol))
+            }
         }
     }
 }
 
+impl From<&FieldLabel> for Rng {
+    fn from(val: &FieldLabel) -> Self {
+        Self::from(&val.identifier).extend(Self::from(&val.equ))
+    }
+}
+
 impl From<&GrammarDefinition> for Rng {
     fn from(val: &GrammarDefinition) -> Self {
         Self::from(&val.percent_percent)
@@ -111,6 +187,12 @@ impl From<&Identifier> for Rng {
     }
 }
 
+impl From<&Number> for Rng {
+    fn from(val: &Number) -> Self {
+        Self::from(&val.number)
+    }
+}
+
 impl From<&NonTerminal> for Rng {
     fn from(val: &NonTerminal) -> Self {
         let rng = Self::from(&val.identifier.identifier);
@@ -144,6 +226,24 @@ impl From<&ParolLs> for Rng {
     }
 }
 
+impl From<&PrecedenceDeclaration> for Rng {
+    fn from(val: &PrecedenceDeclaration) -> Self {
+        let rng = Self::from(&val.associativity).extend(Self::from(&val.precedence_symbol));
+        val.precedence_declaration_list
+            .last()
+            .map_or(rng, |p| rng.extend(Self::from(&p.precedence_symbol)))
+    }
+}
+
+impl From<&PrecedenceSymbol> for Rng {
+    fn from(val: &PrecedenceSymbol) -> Self {
+        match val {
+            PrecedenceSymbol::TokenLiteral(t) => Self::from(&t.token_literal),
+            PrecedenceSymbol::Identifier(i) => Self::from(&i.identifier.identifier),
+        }
+    }
+}
+
 impl From<&Production> for Rng {
     fn from(val: &Production) -> Self {
         Self::from(&val.production_l_h_s).extend(Self::from(&val.semicolon))
@@ -152,7 +252,18 @@ impl From<&Production> for Rng {
 
 impl From<&ProductionLHS> for Rng {
     fn from(val: &ProductionLHS) -> Self {
-        Self::from(&val.identifier.identifier).extend(Self::from(&val.colon))
+        Self::from(&val.production_name).extend(Self::from(&val.colon))
+    }
+}
+
+impl From<&ProductionName> for Rng {
+    fn from(val: &ProductionName) -> Self {
+        let rng = Self::from(&val.identifier.identifier);
+        val.production_name_opt
+            .as_ref()
+            .map_or(rng, |production_name_opt| {
+                rng.extend(Self::from(&production_name_opt.template_parameters.g_t))
+            })
     }
 }
 
@@ -178,7 +289,10 @@ impl From<&PrologList0> for Rng {
 
 impl From<&Repeat> for Rng {
     fn from(val: &Repeat) -> Self {
-        Self::from(&val.l_brace).extend(Self::from(&val.r_brace))
+        let rng = Self::from(&val.l_brace).extend(Self::from(&val.r_brace));
+        val.repeat_opt0.as_ref().map_or(rng, |repeat_opt0| {
+            rng.extend(Self::from(&repeat_opt0.one_or_more.one_or_more))
+        })
     }
 }
 
@@ -193,12 +307,25 @@ impl From<&ScannerDirectives> for Rng {
                 Self::from(&bc.percent_block_underscore_comment)
                     .extend(Self::from(&bc.token_literal0))
             }
+            ScannerDirectives::PercentNestedUnderscoreBlockUnderscoreCommentTokenLiteralTokenLiteral(bc) => {
+                Self::from(&bc.percent_nested_underscore_block_underscore_comment)
+                    .extend(Self::from(&bc.token_literal0))
+            }
             ScannerDirectives::PercentAutoUnderscoreNewlineUnderscoreOff(auto_nl) => {
                 Self::from(&auto_nl.percent_auto_underscore_newline_underscore_off)
             }
             ScannerDirectives::PercentAutoUnderscoreWsUnderscoreOff(auto_ws) => {
                 Self::from(&auto_ws.percent_auto_underscore_ws_underscore_off)
             }
+            ScannerDirectives::PercentLongestUnderscoreMatch(longest_match) => {
+                Self::from(&longest_match.percent_longest_underscore_match)
+            }
+            ScannerDirectives::PercentCaseUnderscoreInsensitive(case_insensitive) => {
+                Self::from(&case_insensitive.percent_case_underscore_insensitive)
+            }
+            ScannerDirectives::PercentOffsideIdentifierIdentifierIdentifier(offside) => {
+                Self::from(&offside.percent_offside).extend(Self::from(&offside.identifier1))
+            }
             ScannerDirectives::PercentOnIdentifierListPercentEnterIdentifier(trans) => {
                 Self::from(&trans.percent_on).extend(Self::from(&trans.identifier))
             }
@@ -242,7 +369,7 @@ impl From<&ScannerSwitchOpt> for Rng {
 
 impl From<&SimpleToken> for Rng {
     fn from(val: &SimpleToken) -> Self {
-        let rng = Self::from(&val.token_literal);
+        let rng = Self::from(&val.token_expression);
         val.simple_token_opt
             .as_ref()
             .map_or(rng, |simple_token_opt| {
@@ -291,12 +418,36 @@ impl From<&Symbol> for Rng {
         match val {
             Symbol::NonTerminal(nt) => Self::from(&nt.non_terminal.identifier.identifier),
             Symbol::SimpleToken(to) => Self::from(&to.simple_token),
-            Symbol::TokenWithStates(ts) => Self::from(&ts.token_with_states),
+            Symbol::AngleArguments(aa) => Self::from(&aa.angle_arguments),
             Symbol::ScannerSwitch(sw) => Self::from(&sw.scanner_switch),
         }
     }
 }
 
+impl From<&AngleArguments> for Rng {
+    fn from(val: &AngleArguments) -> Self {
+        let rng = Self::from(&val.l_t).extend(Self::from(&val.g_t));
+        val.angle_arguments_opt
+            .as_ref()
+            .map_or(rng, |angle_arguments_opt| {
+                rng.extend(Self::from(&angle_arguments_opt.a_s_t_control))
+            })
+    }
+}
+
+impl From<&TokenExpression> for Rng {
+    fn from(val: &TokenExpression) -> Self {
+        let rng = Self::from(&val.token_literal);
+        val.token_expression_opt
+            .as_ref()
+            .map_or(rng, |token_expression_opt| {
+                rng.extend(Self::from(
+                    &token_expression_opt.trailing_context.token_literal,
+                ))
+            })
+    }
+}
+
 impl From<&TokenLiteral> for Rng {
     fn from(val: &TokenLiteral) -> Self {
         match val {
@@ -307,22 +458,6 @@ impl From<&TokenLiteral> for Rng {
     }
 }
 
-impl From<&TokenWithStates> for Rng {
-    fn from(val: &TokenWithStates) -> Self {
-        let rng = Self::from(&val.l_t);
-        val.token_with_states_opt.as_ref().map_or(
-            rng.extend(Self::from(&val.token_literal)),
-            |token_with_states| rng.extend(Self::from(token_with_states)),
-        )
-    }
-}
-
-impl From<&TokenWithStatesOpt> for Rng {
-    fn from(val: &TokenWithStatesOpt) -> Self {
-        Self::from(&val.a_s_t_control)
-    }
-}
-
 impl From<&UserTypeDeclaration> for Rng {
     fn from(val: &UserTypeDeclaration) -> Self {
         Self::from(&val.colon).extend(Self::from(&val.user_type_name))
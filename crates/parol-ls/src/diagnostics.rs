@@ -6,6 +6,7 @@ use parol::{
 };
 use parol_runtime::{ParolError, ParserError, SyntaxError};
 use std::error::Error;
+use std::path::PathBuf;
 
 use crate::{
     document_state::{DocumentState, LocatedDocumentState},
@@ -29,7 +30,11 @@ impl Diagnostics {
         let message = err.to_string();
 
         // Extract additional information from certain errors
-        if let Some(e) = err.downcast_ref::<ParolError>() {
+        if let Some(e) = err.downcast_ref::<GrammarAnalysisError>() {
+            let located_document_state = LocatedDocumentState::new(uri, document_state);
+            extract_grammar_analysis_error(e, &located_document_state, &mut diagnostics, uri);
+            return diagnostics;
+        } else if let Some(e) = err.downcast_ref::<ParolError>() {
             match e {
                 ParolError::ParserError(err) => {
                     if let ParserError::SyntaxErrors { entries } = err {
@@ -243,8 +248,39 @@ fn extract_grammar_analysis_error(
                 }
             }
         }
-        GrammarAnalysisError::MaxKExceeded { max_k: _ } => {
-            // No additional information attached
+        GrammarAnalysisError::MaxKExceeded { conflict, .. } => {
+            let ranges = Server::find_non_terminal_definitions(
+                located_document_state.document_state,
+                &conflict.non_terminal,
+            )
+            .unwrap_or_else(|| vec![Range::default()]);
+            for range in ranges {
+                diagnostics.push(Diagnostic {
+                    range,
+                    severity: Some(DiagnosticSeverity::ERROR),
+                    code: Some(lsp_types::NumberOrString::String(
+                        "parol::analysis::max_k_exceeded".to_owned(),
+                    )),
+                    code_description: None,
+                    source: error.source().map(|s| s.to_string()),
+                    message: error.to_string(),
+                    related_information: Some(
+                        conflict
+                            .witnesses
+                            .iter()
+                            .map(|w| DiagnosticRelatedInformation {
+                                location: Location {
+                                    uri: uri.clone(),
+                                    range,
+                                },
+                                message: w.to_string(),
+                            })
+                            .collect(),
+                    ),
+                    data: None,
+                    tags: None,
+                });
+            }
         }
         GrammarAnalysisError::LALR1ParseTableConstructionFailed { conflict } => {
             diagnostics.push(Diagnostic {
@@ -255,7 +291,7 @@ fn extract_grammar_analysis_error(
                 )),
                 code_description: None,
                 source: error.source().map(|s| s.to_string()),
-                message: format!("{:?}", conflict),
+                message: conflict.to_string(),
                 related_information: Some(vec![DiagnosticRelatedInformation {
                     location: Location {
                         uri: uri.clone(),
@@ -267,6 +303,32 @@ fn extract_grammar_analysis_error(
                 tags: None,
             });
         }
+        GrammarAnalysisError::UnexpectedConflicts { conflicts, .. } => {
+            diagnostics.push(Diagnostic {
+                range: Range::default(),
+                severity: Some(DiagnosticSeverity::ERROR),
+                code: Some(lsp_types::NumberOrString::String(
+                    "parol::analysis::unexpected_conflicts".to_owned(),
+                )),
+                code_description: None,
+                source: error.source().map(|s| s.to_string()),
+                message: error.to_string(),
+                related_information: Some(
+                    conflicts
+                        .iter()
+                        .map(|c| DiagnosticRelatedInformation {
+                            location: Location {
+                                uri: uri.clone(),
+                                range: Range::default(),
+                            },
+                            message: c.to_string(),
+                        })
+                        .collect(),
+                ),
+                data: None,
+                tags: None,
+            });
+        }
     }
 }
 
@@ -413,6 +475,58 @@ fn extract_parser_error(
                 ),
             });
         }
+        ParolParserError::UndeclaredPrecedenceSymbol {
+            context,
+            symbol,
+            input,
+            location,
+        } => {
+            *range = location_to_range(location);
+            related_information.push(DiagnosticRelatedInformation {
+                location: location_to_location(location, located_document_state.uri),
+                message: format!(
+                    "Context: {}, Symbol: {}, Input: {}",
+                    context,
+                    symbol,
+                    input.display()
+                ),
+            });
+        }
+        ParolParserError::ConflictingDefinitions {
+            kind,
+            name,
+            first,
+            second,
+            ..
+        } => {
+            // Only the definitions that are located in this document can be referred to
+            let document_path = PathBuf::from(located_document_state.uri.path().to_string());
+            for (location, message) in [(first, "First definition"), (second, "Second definition")]
+            {
+                if *location.file_name == document_path {
+                    *range = location_to_range(location);
+                    related_information.push(DiagnosticRelatedInformation {
+                        location: location_to_location(location, located_document_state.uri),
+                        message: format!("{kind} '{name}': {message}"),
+                    });
+                }
+            }
+        }
+        ParolParserError::DuplicateLabel {
+            kind,
+            label,
+            first,
+            second,
+            ..
+        } => {
+            *range = location_to_range(second);
+            for (location, message) in [(first, "First usage"), (second, "Second usage")] {
+                related_information.push(DiagnosticRelatedInformation {
+                    location: location_to_location(location, located_document_state.uri),
+                    message: format!("{kind} '{label}': {message}"),
+                });
+            }
+        }
         ParolParserError::MixedScannerSwitching {
             context,
             input,
@@ -424,5 +538,122 @@ fn extract_parser_error(
                 message: format!("Context: {}, Input: {}", context, input.display()),
             });
         }
+        ParolParserError::EmptyMatchingTerminal {
+            context,
+            terminal,
+            input,
+            location,
+        } => {
+            *range = location_to_range(location);
+            related_information.push(DiagnosticRelatedInformation {
+                location: location_to_location(location, located_document_state.uri),
+                message: format!(
+                    "Context: {}, Terminal: {}, Input: {}",
+                    context,
+                    terminal,
+                    input.display()
+                ),
+            });
+        }
+        ParolParserError::InvalidKeyword {
+            context,
+            keyword,
+            input,
+            location,
+        } => {
+            *range = location_to_range(location);
+            related_information.push(DiagnosticRelatedInformation {
+                location: location_to_location(location, located_document_state.uri),
+                message: format!(
+                    "Context: {}, Keyword: {}, Input: {}",
+                    context,
+                    keyword,
+                    input.display()
+                ),
+            });
+        }
+        ParolParserError::InvalidOffsideToken {
+            context,
+            token,
+            input,
+            location,
+        } => {
+            *range = location_to_range(location);
+            related_information.push(DiagnosticRelatedInformation {
+                location: location_to_location(location, located_document_state.uri),
+                message: format!(
+                    "Context: {}, Token: {}, Input: {}",
+                    context,
+                    token,
+                    input.display()
+                ),
+            });
+        }
+        ParolParserError::UnknownNonTerminal {
+            context,
+            name,
+            input,
+            location,
+        } => {
+            *range = location_to_range(location);
+            related_information.push(DiagnosticRelatedInformation {
+                location: location_to_location(location, located_document_state.uri),
+                message: format!(
+                    "Context: {}, Non-terminal: {}, Input: {}",
+                    context,
+                    name,
+                    input.display()
+                ),
+            });
+        }
+        ParolParserError::InvalidInlineNonTerminal {
+            context,
+            name,
+            input,
+            location,
+        } => {
+            *range = location_to_range(location);
+            related_information.push(DiagnosticRelatedInformation {
+                location: location_to_location(location, located_document_state.uri),
+                message: format!(
+                    "Context: {}, Non-terminal can't be inlined: {}, Input: {}",
+                    context,
+                    name,
+                    input.display()
+                ),
+            });
+        }
+        ParolParserError::InvalidDisplayNameTarget {
+            context,
+            token,
+            input,
+            location,
+        } => {
+            *range = location_to_range(location);
+            related_information.push(DiagnosticRelatedInformation {
+                location: location_to_location(location, located_document_state.uri),
+                message: format!(
+                    "Context: {}, Token: {}, Input: {}",
+                    context,
+                    token,
+                    input.display()
+                ),
+            });
+        }
+        ParolParserError::InvalidErrorTokenDefinition {
+            context,
+            input,
+            location,
+        } => {
+            *range = location_to_range(location);
+            related_information.push(DiagnosticRelatedInformation {
+                location: location_to_location(location, located_document_state.uri),
+                message: format!(
+                    "Context: {}, The error token can't be defined, Input: {}",
+                    context,
+                    input.display()
+                ),
+            });
+        }
     }
 }
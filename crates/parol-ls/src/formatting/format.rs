@@ -4,13 +4,16 @@ use std::vec;
 use crate::{
     parol_ls_grammar::OwnedToken,
     parol_ls_grammar_trait::{
-        ASTControl, Alternation, AlternationList, Alternations, AlternationsList, CutOperator,
-        Declaration, DoubleColon, Factor, GrammarDefinition, GrammarDefinitionList, Group,
-        Identifier, IdentifierList, IdentifierListList, LiteralString, NonTerminal, NonTerminalOpt,
-        Optional, ParolLs, Production, ProductionLHS, Prolog, PrologList, PrologList0, Regex,
-        Repeat, ScannerDirectives, ScannerState, ScannerStateList, ScannerSwitch, ScannerSwitchOpt,
-        SimpleToken, SimpleTokenOpt, StartDeclaration, Symbol, TokenLiteral, TokenWithStates,
-        TokenWithStatesOpt, UserTypeDeclaration, UserTypeName, UserTypeNameList,
+        ASTControl, Alternation, AlternationLabel, AlternationList, AlternationOpt, Alternations,
+        AlternationsList, AngleArguments, Associativity, CutOperator, Declaration, DeriveList,
+        DeriveListList, DeriveTarget, DoubleColon, Factor, FieldLabel, GrammarDefinition,
+        GrammarDefinitionList, Group, Identifier, IdentifierList, IdentifierListList,
+        LiteralString, NonTerminal, NonTerminalOpt, Number, Optional, ParolLs,
+        PrecedenceDeclaration, PrecedenceSymbol, Production, ProductionLHS, ProductionName, Prolog,
+        PrologList, PrologList0, Regex, Repeat, RepeatSeparator, ScannerDirectives, ScannerState,
+        ScannerStateList, ScannerSwitch, ScannerSwitchOpt, SimpleToken, SimpleTokenOpt,
+        StartDeclaration, Symbol, TemplateArgument, TemplateArguments, TokenExpression,
+        TokenLiteral, UserTypeDeclaration, UserTypeName, UserTypeNameList,
     },
     rng::Rng,
     utils::RX_NEW_LINE,
@@ -54,6 +57,16 @@ impl LastToken for TokenLiteral {
     }
 }
 
+impl Associativity {
+    fn get_first_token(&self) -> &OwnedToken {
+        match self {
+            Associativity::PercentLeft(left) => &left.percent_left,
+            Associativity::PercentRight(right) => &right.percent_right,
+            Associativity::PercentNonassoc(non_assoc) => &non_assoc.percent_nonassoc,
+        }
+    }
+}
+
 impl Format for &ParolLs {
     fn format(&self, options: &FormattingOptions, comments: Comments) -> Vec<TextEdit> {
         // We use the complete document's range for the edit to ensure that the whole document is
@@ -82,10 +95,19 @@ impl Fmt for ASTControl {
 impl Fmt for Alternation {
     fn txt(&self, options: &FmtOptions, comments: Comments) -> (String, Comments) {
         let next_option = options.clone().next_depth();
-        let (mut alternation_str, comments) = self.alternation_list.iter().fold(
+        let symbols = self
+            .alternation_list
+            .iter()
+            .map(|a| factor_symbol(&a.factor))
+            .collect::<Vec<_>>();
+        let (mut alternation_str, comments) = self.alternation_list.iter().enumerate().fold(
             (String::new(), comments),
-            |(mut acc, comments), e| {
+            |(mut acc, comments), (i, e)| {
                 let (mut next_part, comments) = e.txt(&next_option, comments);
+                if glued(&symbols, i) {
+                    acc.extend(next_part.drain(..));
+                    return (acc, comments);
+                }
                 if options.nesting_depth == 0 {
                     // We do the line length control only at top level (i.e. at production level)
                     let lines: Vec<&str> = RX_NEW_LINE.split(&acc).collect();
@@ -108,12 +130,53 @@ impl Fmt for Alternation {
                 (acc, comments)
             },
         );
+        let comments = if let Some(alternation_opt) = &self.alternation_opt {
+            let (precedence_str, comments) = alternation_opt.txt(options, comments);
+            if !alternation_str.is_empty()
+                && !Line::ends_with_nl(&alternation_str)
+                && !Line::ends_with_space(&alternation_str)
+            {
+                alternation_str.push(' ');
+            }
+            alternation_str.push_str(&precedence_str);
+            comments
+        } else {
+            comments
+        };
+        let comments = if let Some(alternation_opt0) = &self.alternation_opt0 {
+            let (label_str, comments) = alternation_opt0.alternation_label.txt(options, comments);
+            if !alternation_str.is_empty()
+                && !Line::ends_with_nl(&alternation_str)
+                && !Line::ends_with_space(&alternation_str)
+            {
+                alternation_str.push(' ');
+            }
+            alternation_str.push_str(&label_str);
+            comments
+        } else {
+            comments
+        };
         if options.nesting_depth == 0 && !Line::ends_with_nl(&alternation_str) {
             alternation_str.push('\n');
         }
         (alternation_str, comments)
     }
 }
+impl Fmt for AlternationOpt {
+    fn txt(&self, options: &FmtOptions, comments: Comments) -> (String, Comments) {
+        let (precedence_symbol_str, comments) = self.precedence_symbol.txt(options, comments);
+        (
+            format!("{} {}", self.percent_prec, precedence_symbol_str),
+            comments,
+        )
+    }
+}
+impl Fmt for AlternationLabel {
+    fn txt(&self, options: &FmtOptions, comments: Comments) -> (String, Comments) {
+        let (identifier_str, comments) = self.identifier.txt(options, comments);
+        (format!("{}{}", self.hash, identifier_str), comments)
+    }
+}
 impl Fmt for AlternationList {
     fn txt(&self, options: &FmtOptions, comments: Comments) -> (String, Comments) {
         self.factor.txt(options, comments)
@@ -166,6 +229,11 @@ impl Fmt for AlternationsList {
         )
     }
 }
+impl Fmt for Associativity {
+    fn txt(&self, _options: &FmtOptions, comments: Comments) -> (String, Comments) {
+        (self.get_first_token().text().to_string(), comments)
+    }
+}
 impl Fmt for CutOperator {
     fn txt(&self, _options: &FmtOptions, comments: Comments) -> (String, Comments) {
         (self.cut_operator.text().to_string(), comments)
@@ -211,6 +279,118 @@ impl Fmt for Declaration {
                     comments,
                 )
             }
+            Declaration::PercentIncludeString(include) => {
+                let (comments_before_token, comments) = Comments::format_comments_before(
+                    comments,
+                    &include.percent_include,
+                    &options.clone().with_padding(Padding::Left),
+                );
+                if comments_before_token.is_empty() || !Line::ends_with_nl(&comments_before_token) {
+                    delim = "\n";
+                };
+                let (str, comments) = include.string.txt(options, comments);
+                (
+                    format!(
+                        "{}{}{} {}",
+                        comments_before_token, delim, include.percent_include, str
+                    ),
+                    comments,
+                )
+            }
+            Declaration::PercentKeywordsIdentifierList(keywords) => {
+                let (comments_before_token, comments) = Comments::format_comments_before(
+                    comments,
+                    &keywords.percent_keywords,
+                    &options.clone().with_padding(Padding::Left),
+                );
+                if comments_before_token.is_empty() || !Line::ends_with_nl(&comments_before_token) {
+                    delim = "\n";
+                };
+                let (str, comments) = keywords.identifier_list.txt(options, comments);
+                (
+                    format!(
+                        "{}{}{} {}",
+                        comments_before_token, delim, keywords.percent_keywords, str
+                    ),
+                    comments,
+                )
+            }
+            Declaration::PercentInlineIdentifierList(inline) => {
+                let (comments_before_token, comments) = Comments::format_comments_before(
+                    comments,
+                    &inline.percent_inline,
+                    &options.clone().with_padding(Padding::Left),
+                );
+                if comments_before_token.is_empty() || !Line::ends_with_nl(&comments_before_token) {
+                    delim = "\n";
+                };
+                let (str, comments) = inline.identifier_list.txt(options, comments);
+                (
+                    format!(
+                        "{}{}{} {}",
+                        comments_before_token, delim, inline.percent_inline, str
+                    ),
+                    comments,
+                )
+            }
+            Declaration::PercentDisplayIdentifierString(display) => {
+                let (comments_before_token, comments) = Comments::format_comments_before(
+                    comments,
+                    &display.percent_display,
+                    &options.clone().with_padding(Padding::Left),
+                );
+                if comments_before_token.is_empty() || !Line::ends_with_nl(&comments_before_token) {
+                    delim = "\n";
+                };
+                let (terminal, comments) = display.identifier.txt(options, comments);
+                let (str, comments) = display.string.txt(options, comments);
+                (
+                    format!(
+                        "{}{}{} {} {}",
+                        comments_before_token, delim, display.percent_display, terminal, str
+                    ),
+                    comments,
+                )
+            }
+            Declaration::ExpectDirectiveNumber(expect) => {
+                let directive = &expect.expect_directive.expect_directive;
+                let (comments_before_token, comments) = Comments::format_comments_before(
+                    comments,
+                    directive,
+                    &options.clone().with_padding(Padding::Left),
+                );
+                if comments_before_token.is_empty() || !Line::ends_with_nl(&comments_before_token) {
+                    delim = "\n";
+                };
+                let (number, comments) = expect.number.txt(options, comments);
+                (
+                    format!("{}{}{} {}", comments_before_token, delim, directive, number),
+                    comments,
+                )
+            }
+            Declaration::PercentDeriveDeclarationOptDeriveList(derive) => {
+                let (comments_before_token, comments) = Comments::format_comments_before(
+                    comments,
+                    &derive.percent_derive,
+                    &options.clone().with_padding(Padding::Left),
+                );
+                if comments_before_token.is_empty() || !Line::ends_with_nl(&comments_before_token) {
+                    delim = "\n";
+                };
+                let (target, comments) = if let Some(target) = &derive.declaration_opt {
+                    target.derive_target.txt(options, comments)
+                } else {
+                    (String::new(), comments)
+                };
+                let (str, comments) = derive.derive_list.txt(options, comments);
+                (
+                    format!(
+                        "{}{}{} {}{}",
+                        comments_before_token, delim, derive.percent_derive, target, str
+                    ),
+                    comments,
+                )
+            }
             Declaration::PercentUserUnderscoreTypeIdentifierEquUserTypeName(user_type) => {
                 // "%user_type" Identifier "=" UserTypeName;
                 // %user_type UserType1 = UserDefinedTypeName1 // comment
@@ -250,6 +430,24 @@ impl Fmt for Declaration {
                     comments,
                 )
             }
+            Declaration::PrecedenceDeclaration(precedence) => {
+                let (comments_before_token, comments) = Comments::format_comments_before(
+                    comments,
+                    precedence
+                        .precedence_declaration
+                        .associativity
+                        .get_first_token(),
+                    &options.clone().with_padding(Padding::Left),
+                );
+                if comments_before_token.is_empty() || !Line::ends_with_nl(&comments_before_token) {
+                    delim = "\n";
+                };
+                let (str, comments) = precedence.precedence_declaration.txt(options, comments);
+                (
+                    format!("{}{}{}", comments_before_token, delim, str),
+                    comments,
+                )
+            }
             Declaration::ScannerDirectives(scanner_directives) => {
                 handle_scanner_directives(&scanner_directives.scanner_directives, options, comments)
             }
@@ -291,9 +489,20 @@ impl Fmt for Factor {
             Factor::Repeat(r) => r.repeat.txt(&next_depth_option, comments),
             Factor::Optional(o) => o.optional.txt(&next_depth_option, comments),
             Factor::Symbol(s) => handle_symbol(&s.symbol, options, comments),
+            Factor::FieldLabelSymbol(s) => {
+                let (field_label_str, comments) = s.field_label.txt(options, comments);
+                let (symbol_str, comments) = handle_symbol(&s.symbol, options, comments);
+                (format!("{}{}", field_label_str, symbol_str), comments)
+            }
         }
     }
 }
+impl Fmt for FieldLabel {
+    fn txt(&self, options: &FmtOptions, comments: Comments) -> (String, Comments) {
+        let (identifier_str, comments) = self.identifier.txt(options, comments);
+        (format!("{}{}", identifier_str, self.equ), comments)
+    }
+}
 impl Fmt for GrammarDefinition {
     fn txt(&self, options: &FmtOptions, comments: Comments) -> (String, Comments) {
         let (production_str, comments) = self.production.txt(options, comments);
@@ -343,6 +552,11 @@ impl Fmt for Identifier {
         (self.identifier.text().to_string(), comments)
     }
 }
+impl Fmt for Number {
+    fn txt(&self, _options: &FmtOptions, comments: Comments) -> (String, Comments) {
+        (self.number.text().to_string(), comments)
+    }
+}
 impl Fmt for LiteralString {
     fn txt(&self, _options: &FmtOptions, comments: Comments) -> (String, Comments) {
         (self.literal_string.text().to_string(), comments)
@@ -412,6 +626,29 @@ impl Fmt for ParolLs {
         )
     }
 }
+impl Fmt for PrecedenceDeclaration {
+    fn txt(&self, options: &FmtOptions, comments: Comments) -> (String, Comments) {
+        let (associativity_str, comments) = self.associativity.txt(options, comments);
+        let (symbols, comments) = self.precedence_declaration_list.iter().fold(
+            self.precedence_symbol.txt(options, comments),
+            |(mut acc, comments), p| {
+                let (symbol_str, comments) = p.precedence_symbol.txt(options, comments);
+                acc.push(' ');
+                acc.push_str(&symbol_str);
+                (acc, comments)
+            },
+        );
+        (format!("{} {}", associativity_str, symbols), comments)
+    }
+}
+impl Fmt for PrecedenceSymbol {
+    fn txt(&self, options: &FmtOptions, comments: Comments) -> (String, Comments) {
+        match self {
+            PrecedenceSymbol::TokenLiteral(t) => t.token_literal.txt(options, comments),
+            PrecedenceSymbol::Identifier(i) => i.identifier.txt(options, comments),
+        }
+    }
+}
 impl Fmt for Production {
     fn txt(&self, options: &FmtOptions, comments: Comments) -> (String, Comments) {
         let (production_l_h_s, comments) = self.production_l_h_s.txt(options, comments);
@@ -455,21 +692,22 @@ impl Fmt for ProductionLHS {
     fn txt(&self, options: &FmtOptions, comments: Comments) -> (String, Comments) {
         let (comments_before_non_terminal, comments) = Comments::format_comments_before(
             comments,
-            &self.identifier.identifier,
+            self.production_name.first_token(),
             &options.clone().with_line_end(LineEnd::ForceSingleNewline),
         );
+        let (production_name, comments) = self.production_name.txt(options, comments);
         let (comments_before_colon, comments) = Comments::format_comments_before(
             comments,
             &self.colon,
             &options.clone().with_padding(Padding::Left),
         );
-        if self.identifier.identifier.text().len() + comments_before_colon.len() < 5 {
-            let padding = " ".repeat(4 - self.identifier.identifier.text().len());
+        if production_name.len() + comments_before_colon.len() < 5 {
+            let padding = " ".repeat(4 - production_name.len());
             (
                 format!(
                     "\n{}{}{}{}{}",
                     comments_before_non_terminal,
-                    self.identifier.identifier,
+                    production_name,
                     padding,
                     comments_before_colon,
                     self.colon
@@ -481,7 +719,7 @@ impl Fmt for ProductionLHS {
                 format!(
                     "\n{}{}{}\n    {}",
                     comments_before_non_terminal,
-                    self.identifier.identifier,
+                    production_name,
                     comments_before_colon,
                     self.colon
                 ),
@@ -490,6 +728,27 @@ impl Fmt for ProductionLHS {
         }
     }
 }
+impl Fmt for ProductionName {
+    fn txt(&self, options: &FmtOptions, comments: Comments) -> (String, Comments) {
+        if let Some(production_name_opt) = &self.production_name_opt {
+            let template_parameters = &production_name_opt.template_parameters;
+            let (identifier_list, comments) =
+                template_parameters.identifier_list.txt(options, comments);
+            (
+                format!(
+                    "{}{}{}{}",
+                    self.identifier.identifier,
+                    template_parameters.l_t,
+                    identifier_list,
+                    template_parameters.g_t
+                ),
+                comments,
+            )
+        } else {
+            (self.identifier.identifier.to_string(), comments)
+        }
+    }
+}
 impl Fmt for Prolog {
     fn txt(&self, options: &FmtOptions, comments: Comments) -> (String, Comments) {
         let (start_declaration, comments) = self.start_declaration.txt(options, comments);
@@ -538,15 +797,48 @@ impl Fmt for Repeat {
         } else {
             " "
         };
+        let (separator_str, comments) = if let Some(repeat_opt) = &self.repeat_opt {
+            let (separator_str, comments) = repeat_opt.repeat_separator.txt(options, comments);
+            (format!("{} ", separator_str), comments)
+        } else {
+            (String::new(), comments)
+        };
+        let one_or_more = self
+            .repeat_opt0
+            .as_ref()
+            .map_or(String::new(), |repeat_opt0| {
+                repeat_opt0.one_or_more.one_or_more.to_string()
+            });
         (
             format!(
-                "{} {}{}{}",
-                self.l_brace, alternations_str, sep, self.r_brace,
+                "{} {}{}{}{}{}",
+                self.l_brace, alternations_str, sep, separator_str, self.r_brace, one_or_more,
             ),
             comments,
         )
     }
 }
+impl Fmt for RepeatSeparator {
+    fn txt(&self, options: &FmtOptions, comments: Comments) -> (String, Comments) {
+        let (symbol_str, comments) = self.symbol.txt(options, comments);
+        let symbols = std::iter::once(&self.symbol)
+            .chain(self.repeat_separator_list.iter().map(|s| &s.symbol))
+            .map(|s| (Some(s), false))
+            .collect::<Vec<_>>();
+        let (symbol_str, comments) = self.repeat_separator_list.iter().enumerate().fold(
+            (symbol_str, comments),
+            |(mut acc, comments), (i, s)| {
+                let (next_part, comments) = s.symbol.txt(options, comments);
+                if !glued(&symbols, i + 1) {
+                    acc.push(' ');
+                }
+                acc.push_str(&next_part);
+                (acc, comments)
+            },
+        );
+        (format!("{} {}", self.percent, symbol_str), comments)
+    }
+}
 impl Fmt for ScannerDirectives {
     fn txt(&self, options: &FmtOptions, comments: Comments) -> (String, Comments) {
         handle_scanner_directives(self, options, comments)
@@ -637,7 +929,7 @@ impl Fmt for ScannerSwitchOpt {
 }
 impl Fmt for SimpleToken {
     fn txt(&self, options: &FmtOptions, comments: Comments) -> (String, Comments) {
-        let (token_literal, comments) = self.token_literal.txt(options, comments);
+        let (token_literal, comments) = self.token_expression.txt(options, comments);
         let (simple_token_opt, comments) =
             if let Some(simple_token_opt) = self.simple_token_opt.as_ref() {
                 simple_token_opt.txt(options, comments)
@@ -690,6 +982,32 @@ impl Fmt for IdentifierListList {
         (format!("{} {}", self.comma, identifier), comments)
     }
 }
+impl Fmt for DeriveTarget {
+    fn txt(&self, options: &FmtOptions, comments: Comments) -> (String, Comments) {
+        let (identifier, comments) = self.identifier.txt(options, comments);
+        (format!("{}{} ", identifier, self.colon), comments)
+    }
+}
+impl Fmt for DeriveList {
+    fn txt(&self, options: &FmtOptions, comments: Comments) -> (String, Comments) {
+        let (user_type_name, comments) = self.user_type_name.txt(options, comments);
+        let (derive_list_list, comments) = self.derive_list_list.iter().fold(
+            (String::new(), comments),
+            |(mut acc, comments), d| {
+                let (d_str, comments) = d.txt(options, comments);
+                acc.push_str(&d_str);
+                (acc, comments)
+            },
+        );
+        (format!("{}{}", user_type_name, derive_list_list), comments)
+    }
+}
+impl Fmt for DeriveListList {
+    fn txt(&self, options: &FmtOptions, comments: Comments) -> (String, Comments) {
+        let (user_type_name, comments) = self.user_type_name.txt(options, comments);
+        (format!("{} {}", self.comma, user_type_name), comments)
+    }
+}
 impl Fmt for crate::parol_ls_grammar_trait::String {
     fn txt(&self, options: &FmtOptions, comments: Comments) -> (String, Comments) {
         let (comments_before_string, comments) = Comments::format_comments_before(
@@ -708,38 +1026,86 @@ impl Fmt for Symbol {
         handle_symbol(self, options, comments)
     }
 }
-impl Fmt for TokenLiteral {
+impl Fmt for TemplateArgument {
     fn txt(&self, options: &FmtOptions, comments: Comments) -> (String, Comments) {
-        match self {
-            TokenLiteral::String(s) => s.string.txt(options, comments),
-            TokenLiteral::LiteralString(l) => l.literal_string.txt(options, comments),
-            TokenLiteral::Regex(r) => r.regex.txt(options, comments),
-        }
+        let (factor, comments) = self.factor.txt(options, comments);
+        let symbols = std::iter::once(&self.factor)
+            .chain(self.template_argument_list.iter().map(|f| &f.factor))
+            .map(factor_symbol)
+            .collect::<Vec<_>>();
+        self.template_argument_list.iter().enumerate().fold(
+            (factor, comments),
+            |(mut acc, comments), (i, f)| {
+                let (next_part, comments) = f.factor.txt(options, comments);
+                if !glued(&symbols, i + 1) {
+                    acc.push(' ');
+                }
+                acc.push_str(&next_part);
+                (acc, comments)
+            },
+        )
     }
 }
-impl Fmt for TokenWithStates {
+impl Fmt for TemplateArguments {
     fn txt(&self, options: &FmtOptions, comments: Comments) -> (String, Comments) {
-        let (mut state_list, comments) = self.identifier_list.txt(options, comments);
-        let (token_literal, comments) = self.token_literal.txt(options, comments);
-        let (token_with_states_opt, comments) =
-            if let Some(token_with_states_opt) = self.token_with_states_opt.as_ref() {
-                token_with_states_opt.txt(options, comments)
+        let (argument, comments) = self.template_argument.txt(options, comments);
+        self.template_arguments_list
+            .iter()
+            .fold((argument, comments), |(mut acc, comments), a| {
+                let (argument, comments) = a.template_argument.txt(options, comments);
+                acc.push_str(&format!("{} {}", a.comma, argument));
+                (acc, comments)
+            })
+    }
+}
+impl Fmt for AngleArguments {
+    fn txt(&self, options: &FmtOptions, comments: Comments) -> (String, Comments) {
+        let (comments_before_angle, comments) = Comments::format_comments_before(
+            comments,
+            &self.l_t,
+            &options.clone().with_padding(Padding::Right),
+        );
+        let (template_arguments, comments) = self.template_arguments.txt(options, comments);
+        let (ast_control_str, comments) =
+            if let Some(angle_arguments_opt) = self.angle_arguments_opt.as_ref() {
+                angle_arguments_opt.a_s_t_control.txt(options, comments)
             } else {
                 (String::default(), comments)
             };
-        state_list.clone_from(&state_list.trim().to_owned());
         (
             format!(
                 "{}{}{}{}{}",
-                self.l_t, state_list, self.g_t, token_literal, token_with_states_opt
+                comments_before_angle, self.l_t, template_arguments, self.g_t, ast_control_str
             ),
             comments,
         )
     }
 }
-impl Fmt for TokenWithStatesOpt {
+impl Fmt for TokenExpression {
     fn txt(&self, options: &FmtOptions, comments: Comments) -> (String, Comments) {
-        self.a_s_t_control.txt(options, comments)
+        let (token_literal, comments) = self.token_literal.txt(options, comments);
+        if let Some(token_expression_opt) = self.token_expression_opt.as_ref() {
+            let trailing_context = &token_expression_opt.trailing_context;
+            let (context, comments) = trailing_context.token_literal.txt(options, comments);
+            (
+                format!(
+                    "{} {} {}",
+                    token_literal, trailing_context.quest_equ, context
+                ),
+                comments,
+            )
+        } else {
+            (token_literal, comments)
+        }
+    }
+}
+impl Fmt for TokenLiteral {
+    fn txt(&self, options: &FmtOptions, comments: Comments) -> (String, Comments) {
+        match self {
+            TokenLiteral::String(s) => s.string.txt(options, comments),
+            TokenLiteral::LiteralString(l) => l.literal_string.txt(options, comments),
+            TokenLiteral::Regex(r) => r.regex.txt(options, comments),
+        }
     }
 }
 impl Fmt for UserTypeDeclaration {
@@ -843,6 +1209,39 @@ fn handle_scanner_directives(
             )
         }
 
+        ScannerDirectives::PercentNestedUnderscoreBlockUnderscoreCommentTokenLiteralTokenLiteral(b) => {
+            let (comments_before_token, comments) = Comments::format_comments_before(
+                comments,
+                &b.percent_nested_underscore_block_underscore_comment,
+                &options.clone().with_padding(Padding::Left),
+            );
+            let (str1, comments) = b.token_literal.txt(options, comments);
+            let (str2, comments) = b.token_literal0.txt(options, comments);
+            if comments_before_token.is_empty() || !Line::ends_with_nl(&comments_before_token) {
+                indent.insert(0, '\n');
+            };
+            let (following_comment, comments) = Comments::formatted_immediately_following_comment(
+                comments,
+                b.token_literal0.get_last_token(),
+                &options
+                    .clone()
+                    .with_padding(Padding::Left)
+                    .with_line_end(LineEnd::ForceRemove),
+            );
+            (
+                format!(
+                    "{}{}{} {} {}{}",
+                    comments_before_token,
+                    indent,
+                    b.percent_nested_underscore_block_underscore_comment,
+                    str1,
+                    str2,
+                    following_comment,
+                ),
+                comments,
+            )
+        }
+
         ScannerDirectives::PercentAutoUnderscoreNewlineUnderscoreOff(n) => {
             let (comments_before_token, comments) = Comments::format_comments_before(
                 comments,
@@ -899,6 +1298,92 @@ fn handle_scanner_directives(
                 comments,
             )
         }
+
+        ScannerDirectives::PercentLongestUnderscoreMatch(l) => {
+            let (comments_before_token, comments) = comments.format_comments_before(
+                &l.percent_longest_underscore_match,
+                &options.clone().with_padding(Padding::Left),
+            );
+            if comments_before_token.is_empty() || !Line::ends_with_nl(&comments_before_token) {
+                indent.insert(0, '\n');
+            };
+            let (following_comment, comments) = Comments::formatted_immediately_following_comment(
+                comments,
+                &l.percent_longest_underscore_match,
+                &options
+                    .clone()
+                    .with_padding(Padding::Left)
+                    .with_line_end(LineEnd::ForceRemove),
+            );
+            (
+                format!(
+                    "{}{}{}{}",
+                    comments_before_token,
+                    indent,
+                    l.percent_longest_underscore_match,
+                    following_comment
+                ),
+                comments,
+            )
+        }
+        ScannerDirectives::PercentCaseUnderscoreInsensitive(l) => {
+            let (comments_before_token, comments) = comments.format_comments_before(
+                &l.percent_case_underscore_insensitive,
+                &options.clone().with_padding(Padding::Left),
+            );
+            if comments_before_token.is_empty() || !Line::ends_with_nl(&comments_before_token) {
+                indent.insert(0, '\n');
+            };
+            let (following_comment, comments) = Comments::formatted_immediately_following_comment(
+                comments,
+                &l.percent_case_underscore_insensitive,
+                &options
+                    .clone()
+                    .with_padding(Padding::Left)
+                    .with_line_end(LineEnd::ForceRemove),
+            );
+            (
+                format!(
+                    "{}{}{}{}",
+                    comments_before_token,
+                    indent,
+                    l.percent_case_underscore_insensitive,
+                    following_comment
+                ),
+                comments,
+            )
+        }
+        ScannerDirectives::PercentOffsideIdentifierIdentifierIdentifier(offside) => {
+            let (comments_before_token, comments) = Comments::format_comments_before(
+                comments,
+                &offside.percent_offside,
+                &options.clone().with_padding(Padding::Left),
+            );
+            if comments_before_token.is_empty() || !Line::ends_with_nl(&comments_before_token) {
+                indent.insert(0, '\n');
+            };
+            let (following_comment, comments) = Comments::formatted_immediately_following_comment(
+                comments,
+                &offside.identifier1.identifier,
+                &options
+                    .clone()
+                    .with_padding(Padding::Left)
+                    .with_line_end(LineEnd::ForceRemove),
+            );
+            (
+                format!(
+                    "{}{}{} {} {} {}{}",
+                    comments_before_token,
+                    indent,
+                    offside.percent_offside,
+                    offside.identifier.identifier.text(),
+                    offside.identifier0.identifier.text(),
+                    offside.identifier1.identifier.text(),
+                    following_comment
+                ),
+                comments,
+            )
+        }
         ScannerDirectives::PercentOnIdentifierListPercentEnterIdentifier(trans) => {
             let (comments_before_token, comments) = Comments::format_comments_before(
                 comments,
@@ -954,11 +1439,38 @@ fn handle_symbol(symbol: &Symbol, options: &FmtOptions, comments: Comments) -> (
     match symbol {
         Symbol::NonTerminal(n) => n.non_terminal.txt(options, comments),
         Symbol::SimpleToken(t) => t.simple_token.txt(options, comments),
-        Symbol::TokenWithStates(t) => t.token_with_states.txt(options, comments),
+        Symbol::AngleArguments(a) => a.angle_arguments.txt(options, comments),
         Symbol::ScannerSwitch(s) => s.scanner_switch.txt(options, comments),
     }
 }
 
+// The symbol of a factor and whether it is labeled
+fn factor_symbol(factor: &Factor) -> (Option<&Symbol>, bool) {
+    match factor {
+        Factor::Symbol(s) => (Some(&s.symbol), false),
+        Factor::FieldLabelSymbol(s) => (Some(&s.symbol), true),
+        _ => (None, false),
+    }
+}
+
+// Angle arguments are written without whitespace after the name of a template. Angle arguments
+// that don't follow a non-terminal are the scanner states of the next terminal and are written
+// without whitespace before it.
+fn glued(symbols: &[(Option<&Symbol>, bool)], i: usize) -> bool {
+    if i == 0 || symbols[i].1 {
+        return false;
+    }
+    match (symbols[i - 1].0, symbols[i].0) {
+        (Some(Symbol::NonTerminal(n)), Some(Symbol::AngleArguments(_))) => {
+            n.non_terminal.non_terminal_opt.is_none()
+        }
+        (Some(Symbol::AngleArguments(a)), Some(Symbol::SimpleToken(_))) => {
+            a.angle_arguments.angle_arguments_opt.is_none() && !glued(symbols, i - 1)
+        }
+        _ => false,
+    }
+}
+
 #[cfg(test)]
 mod test {
     use std::{ffi::OsStr, fs};
@@ -1087,7 +1599,7 @@ mod test {
 
             // Only to support debugging we write out the currently generated source
             let mut actual_file = std::path::PathBuf::from(ACTUAL_FOLDER);
-            let expected_sub_folder = expected_file.iter().last().unwrap();
+            let expected_sub_folder = expected_file.iter().next_back().unwrap();
             actual_file.push(expected_sub_folder);
             fs::DirBuilder::new()
                 .recursive(true)
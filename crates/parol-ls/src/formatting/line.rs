@@ -11,7 +11,7 @@ impl Line {
     }
 
     pub(crate) fn ends_with_nl(line: &str) -> bool {
-        line.ends_with(|c| c == '\n' || c == '\r')
+        line.ends_with(['\n', '\r'])
     }
 
     // fn ends_with_nls_after_line_comment(line: &str) -> bool {
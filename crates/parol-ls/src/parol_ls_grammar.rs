@@ -1,9 +1,10 @@
 use crate::{
     formatting::Comments,
     parol_ls_grammar_trait::{
-        self, Declaration, NonTerminal, ParolLs, ParolLsGrammarTrait, Production, ProductionLHS,
-        Prolog, ScannerDirectives, ScannerState, StartDeclaration, TokenLiteral, TokenWithStates,
-        UserTypeDeclaration,
+        self, Alternation, Associativity, Declaration, Factor, NonTerminal, Number, ParolLs,
+        ParolLsGrammarTrait, PrecedenceSymbol, Production, ProductionLHS, ProductionName, Prolog,
+        RepeatSeparator, ScannerDirectives, ScannerState, StartDeclaration, Symbol,
+        TemplateArgument, TokenLiteral, UserTypeDeclaration,
     },
     rng::Rng,
     symbol_def::SymbolDefs,
@@ -58,6 +59,11 @@ pub struct ParolLsGrammar {
 
     // A list of comments
     pub(crate) comments: Comments,
+
+    // Identifiers in angle brackets with the name of the non-terminal that precedes the brackets.
+    // They are scanner states unless the non-terminal is a template, which is only known after
+    // all productions are parsed.
+    angle_arguments: Vec<(Option<String>, Vec<OwnedToken>)>,
 }
 
 impl ParolLsGrammar {
@@ -110,6 +116,68 @@ impl ParolLsGrammar {
         self.add_scanner_state_ref(identifier);
     }
 
+    // Collects the identifiers in angle brackets of a sequence of symbols
+    fn add_angle_arguments<'a>(&mut self, symbols: impl Iterator<Item = Option<&'a Symbol>>) {
+        let mut previous: Option<&Symbol> = None;
+        for symbol in symbols {
+            if let Some(Symbol::AngleArguments(a)) = symbol {
+                let name = match previous {
+                    Some(Symbol::NonTerminal(n)) if n.non_terminal.non_terminal_opt.is_none() => {
+                        Some(n.non_terminal.identifier.identifier.text().to_string())
+                    }
+                    _ => None,
+                };
+                let arguments = &a.angle_arguments.template_arguments;
+                let identifiers = std::iter::once(&arguments.template_argument)
+                    .chain(
+                        arguments
+                            .template_arguments_list
+                            .iter()
+                            .map(|a| &a.template_argument),
+                    )
+                    .filter(|a| a.template_argument_list.is_empty())
+                    .filter_map(|a| match &a.factor {
+                        Factor::Symbol(s) => match &*s.symbol {
+                            Symbol::NonTerminal(n) => {
+                                Some(n.non_terminal.identifier.identifier.clone())
+                            }
+                            _ => None,
+                        },
+                        _ => None,
+                    })
+                    .collect();
+                self.angle_arguments.push((name, identifiers));
+            }
+            previous = symbol;
+        }
+    }
+
+    // Identifiers in angle brackets that don't follow the name of a template are scanner states
+    fn resolve_angle_arguments(&mut self) {
+        let templates = self
+            .productions
+            .values()
+            .flatten()
+            .filter(|p| {
+                p.production_l_h_s
+                    .production_name
+                    .production_name_opt
+                    .is_some()
+            })
+            .map(|p| p.production_l_h_s.production_name.name().text().to_string())
+            .collect::<Vec<_>>();
+        for (name, identifiers) in std::mem::take(&mut self.angle_arguments) {
+            if name.is_some_and(|n| templates.contains(&n)) {
+                continue;
+            }
+            for identifier in &identifiers {
+                self.non_terminal_definitions
+                    .remove_reference_by_token(identifier);
+                self.add_scanner_state_ref(identifier);
+            }
+        }
+    }
+
     fn add_scanner_state_ref(&mut self, token: &OwnedToken) {
         // eprintln!("add_scanner_state_ref: {range:?}, {}", token);
         self.scanner_state_definitions.add_reference_by_token(token);
@@ -198,6 +266,48 @@ impl ParolLsGrammar {
                     ]),
                 });
             }
+            ScannerDirectives::PercentNestedUnderscoreBlockUnderscoreCommentTokenLiteralTokenLiteral(
+                block_comment,
+            ) => {
+                #[allow(deprecated)]
+                symbols.push(DocumentSymbol {
+                    name: block_comment
+                        .percent_nested_underscore_block_underscore_comment
+                        .text()
+                        .to_string(),
+                    detail: Some("Nested block comment for the scanner state".to_string()),
+                    kind: SymbolKind::PROPERTY,
+                    tags: None,
+                    deprecated: None,
+                    range: Into::<Rng>::into(arg).0,
+                    selection_range: Into::<Rng>::into(
+                        &block_comment.percent_nested_underscore_block_underscore_comment,
+                    )
+                    .0,
+                    children: Some(vec![
+                        DocumentSymbol {
+                            name: Self::expanded_token_literal(&block_comment.token_literal),
+                            detail: Some("Text".to_string()),
+                            kind: SymbolKind::STRING,
+                            tags: None,
+                            deprecated: None,
+                            range: Into::<Rng>::into(arg).0,
+                            selection_range: Into::<Rng>::into(&block_comment.token_literal).0,
+                            children: None,
+                        },
+                        DocumentSymbol {
+                            name: Self::expanded_token_literal(&block_comment.token_literal0),
+                            detail: Some("Text".to_string()),
+                            kind: SymbolKind::STRING,
+                            tags: None,
+                            deprecated: None,
+                            range: Into::<Rng>::into(arg).0,
+                            selection_range: Into::<Rng>::into(&block_comment.token_literal0).0,
+                            children: None,
+                        },
+                    ]),
+                });
+            }
             ScannerDirectives::PercentAutoUnderscoreNewlineUnderscoreOff(auto_newline) => {
                 #[allow(deprecated)]
                 symbols.push(DocumentSymbol {
@@ -236,6 +346,69 @@ impl ParolLsGrammar {
                     children: None,
                 });
             }
+            ScannerDirectives::PercentLongestUnderscoreMatch(longest_match) => {
+                #[allow(deprecated)]
+                symbols.push(DocumentSymbol {
+                    name: longest_match
+                        .percent_longest_underscore_match
+                        .text()
+                        .to_string(),
+                    detail: Some("Take the longest match".to_string()),
+                    kind: SymbolKind::PROPERTY,
+                    tags: None,
+                    deprecated: None,
+                    range: Into::<Rng>::into(arg).0,
+                    selection_range: Into::<Rng>::into(
+                        &longest_match.percent_longest_underscore_match,
+                    )
+                    .0,
+                    children: None,
+                });
+            }
+            ScannerDirectives::PercentCaseUnderscoreInsensitive(case_insensitive) => {
+                #[allow(deprecated)]
+                symbols.push(DocumentSymbol {
+                    name: case_insensitive
+                        .percent_case_underscore_insensitive
+                        .text()
+                        .to_string(),
+                    detail: Some("Match all terminals case insensitively".to_string()),
+                    kind: SymbolKind::PROPERTY,
+                    tags: None,
+                    deprecated: None,
+                    range: Into::<Rng>::into(arg).0,
+                    selection_range: Into::<Rng>::into(
+                        &case_insensitive.percent_case_underscore_insensitive,
+                    )
+                    .0,
+                    children: None,
+                });
+            }
+            ScannerDirectives::PercentOffsideIdentifierIdentifierIdentifier(offside) => {
+                let children = [
+                    (&offside.identifier, "Indent terminal"),
+                    (&offside.identifier0, "Dedent terminal"),
+                    (&offside.identifier1, "Newline terminal"),
+                ]
+                .iter()
+                .map(|(id, detail)| {
+                    // Add the reference to the non-terminal for hover and rename support
+                    self.add_non_terminal_ref(&id.identifier);
+                    let mut id_sym: DocumentSymbol = (&id.identifier).into();
+                    id_sym.detail = Some(detail.to_string());
+                    id_sym
+                })
+                .collect::<Vec<_>>();
+
+                let mut offside_directive: DocumentSymbol = (&offside.percent_offside).into();
+                offside_directive.detail = Some("Synthesize indentation tokens".to_string());
+                offside_directive.range = Into::<Rng>::into(arg).0;
+                offside_directive.selection_range = Into::<Rng>::into(&offside.percent_offside).0;
+                offside_directive.kind = SymbolKind::PROPERTY;
+                offside_directive.children = Some(children);
+
+                symbols.push(offside_directive);
+            }
             ScannerDirectives::PercentOnIdentifierListPercentEnterIdentifier(trans) => {
                 // Add the reference to the non-terminal for hover and rename support
                 // This is the first non-terminal in the struct `identifier_list`
@@ -280,6 +453,35 @@ impl ParolLsGrammar {
         }
     }
 
+    fn add_expect_symbol(
+        &mut self,
+        arg: &Declaration,
+        directive: &OwnedToken,
+        number: &Number,
+        detail: &str,
+    ) {
+        #[allow(deprecated)]
+        self.symbols.push(DocumentSymbol {
+            name: directive.text().to_string(),
+            detail: Some(detail.to_string()),
+            kind: SymbolKind::PROPERTY,
+            tags: None,
+            deprecated: None,
+            range: Into::<Rng>::into(arg).0,
+            selection_range: Into::<Rng>::into(directive).0,
+            children: Some(vec![DocumentSymbol {
+                name: number.number.text().to_string(),
+                detail: Some("Number".to_string()),
+                kind: SymbolKind::NUMBER,
+                tags: None,
+                deprecated: None,
+                range: Into::<Rng>::into(arg).0,
+                selection_range: Into::<Rng>::into(&number.number).0,
+                children: None,
+            }]),
+        });
+    }
+
     pub(crate) fn hover(&self, params: HoverParams, input: &str) -> Hover {
         let mut value = String::new();
         let ident = self.ident_at_position(params.text_document_position_params.position);
@@ -430,8 +632,10 @@ impl ParolLsGrammar {
         }
     }
 
+    // Removes the delimiters and the optional case insensitivity flag
     fn trim_quotes(string: &str) -> String {
         let delimiters: &[_] = &['"', '\'', '/'];
+        let string = string.strip_suffix('i').unwrap_or(string);
         string
             .strip_prefix(delimiters)
             .unwrap()
@@ -441,22 +645,26 @@ impl ParolLsGrammar {
     }
 
     pub(crate) fn expanded_token_literal(token_literal: &TokenLiteral) -> String {
-        match token_literal {
-            TokenLiteral::String(s) => {
-                TerminalKind::Legacy.expand(Self::trim_quotes(s.string.string.text()).as_str())
-            }
-            TokenLiteral::LiteralString(l) => TerminalKind::Raw
-                .expand(Self::trim_quotes(l.literal_string.literal_string.text()).as_str()),
-            TokenLiteral::Regex(r) => {
-                TerminalKind::Regex.expand(Self::trim_quotes(r.regex.regex.text()).as_str())
+        let (text, kind) = match token_literal {
+            TokenLiteral::String(s) => (s.string.string.text(), TerminalKind::Legacy),
+            TokenLiteral::LiteralString(l) => {
+                (l.literal_string.literal_string.text(), TerminalKind::Raw)
             }
-        }
+            TokenLiteral::Regex(r) => (r.regex.regex.text(), TerminalKind::Regex),
+        };
+        let kind = if text.ends_with('i') {
+            kind.case_insensitive()
+        } else {
+            kind
+        };
+        kind.expand(Self::trim_quotes(text).as_str())
     }
 }
 
 impl ParolLsGrammarTrait for ParolLsGrammar {
     /// Semantic action for non-terminal 'ParolLs'
     fn parol_ls(&mut self, arg: &ParolLs) -> Result<()> {
+        self.resolve_angle_arguments();
         self.grammar = Some(arg.clone());
         Ok(())
     }
@@ -548,6 +756,28 @@ impl ParolLsGrammarTrait for ParolLsGrammar {
                     }]),
                 });
             }
+            Declaration::PercentIncludeString(include) => {
+                #[allow(deprecated)]
+                self.symbols.push(DocumentSymbol {
+                    name: include.percent_include.text().to_string(),
+                    detail: Some("Included grammar file".to_string()),
+                    kind: SymbolKind::PROPERTY,
+                    tags: None,
+                    deprecated: None,
+                    range: Into::<Rng>::into(arg).0,
+                    selection_range: Into::<Rng>::into(&include.percent_include).0,
+                    children: Some(vec![DocumentSymbol {
+                        name: include.string.string.text().to_string(),
+                        detail: Some("File name".to_string()),
+                        kind: SymbolKind::FILE,
+                        tags: None,
+                        deprecated: None,
+                        range: Into::<Rng>::into(arg).0,
+                        selection_range: Into::<Rng>::into(&include.string.string).0,
+                        children: None,
+                    }]),
+                });
+            }
             Declaration::PercentUserUnderscoreTypeIdentifierEquUserTypeName(user_type_def) => {
                 let token = &user_type_def.identifier.identifier;
                 let range: Rng = arg.into();
@@ -580,6 +810,211 @@ impl ParolLsGrammarTrait for ParolLsGrammar {
                     }]),
                 });
             }
+            Declaration::PercentKeywordsIdentifierList(keywords) => {
+                let identifier_list = &keywords.identifier_list;
+                let children = [&identifier_list.identifier.identifier]
+                    .into_iter()
+                    .chain(
+                        identifier_list
+                            .identifier_list_list
+                            .iter()
+                            .map(|i| &i.identifier.identifier),
+                    )
+                    .map(|i| {
+                        // Add the reference to the non-terminal for hover and rename support
+                        self.add_non_terminal_ref(i);
+                        let mut keyword: DocumentSymbol = i.into();
+                        keyword.detail = Some("Keyword".to_string());
+                        keyword
+                    })
+                    .collect::<Vec<DocumentSymbol>>();
+                #[allow(deprecated)]
+                self.symbols.push(DocumentSymbol {
+                    name: keywords.percent_keywords.text().to_string(),
+                    detail: Some("Keywords".to_string()),
+                    kind: SymbolKind::PROPERTY,
+                    tags: None,
+                    deprecated: None,
+                    range: Into::<Rng>::into(arg).0,
+                    selection_range: Into::<Rng>::into(&keywords.percent_keywords).0,
+                    children: Some(children),
+                });
+            }
+            Declaration::PercentInlineIdentifierList(inline) => {
+                let identifier_list = &inline.identifier_list;
+                let children = [&identifier_list.identifier.identifier]
+                    .into_iter()
+                    .chain(
+                        identifier_list
+                            .identifier_list_list
+                            .iter()
+                            .map(|i| &i.identifier.identifier),
+                    )
+                    .map(|i| {
+                        // Add the reference to the non-terminal for hover and rename support
+                        self.add_non_terminal_ref(i);
+                        let mut non_terminal: DocumentSymbol = i.into();
+                        non_terminal.detail = Some("Inlined non-terminal".to_string());
+                        non_terminal
+                    })
+                    .collect::<Vec<DocumentSymbol>>();
+                #[allow(deprecated)]
+                self.symbols.push(DocumentSymbol {
+                    name: inline.percent_inline.text().to_string(),
+                    detail: Some("Inlined non-terminals".to_string()),
+                    kind: SymbolKind::PROPERTY,
+                    tags: None,
+                    deprecated: None,
+                    range: Into::<Rng>::into(arg).0,
+                    selection_range: Into::<Rng>::into(&inline.percent_inline).0,
+                    children: Some(children),
+                });
+            }
+            Declaration::PercentDisplayIdentifierString(display) => {
+                let terminal = &display.identifier.identifier;
+                // Add the reference to the non-terminal for hover and rename support
+                self.add_non_terminal_ref(terminal);
+                let mut non_terminal: DocumentSymbol = terminal.into();
+                non_terminal.detail = Some("Terminal".to_string());
+                #[allow(deprecated)]
+                self.symbols.push(DocumentSymbol {
+                    name: display.percent_display.text().to_string(),
+                    detail: Some("Display name of a terminal".to_string()),
+                    kind: SymbolKind::PROPERTY,
+                    tags: None,
+                    deprecated: None,
+                    range: Into::<Rng>::into(arg).0,
+                    selection_range: Into::<Rng>::into(&display.percent_display).0,
+                    children: Some(vec![
+                        non_terminal,
+                        DocumentSymbol {
+                            name: display.string.string.text().to_string(),
+                            detail: Some("Text".to_string()),
+                            kind: SymbolKind::STRING,
+                            tags: None,
+                            deprecated: None,
+                            range: Into::<Rng>::into(arg).0,
+                            selection_range: Into::<Rng>::into(&display.string.string).0,
+                            children: None,
+                        },
+                    ]),
+                });
+            }
+            Declaration::ExpectDirectiveNumber(expect) => {
+                let directive = &expect.expect_directive.expect_directive;
+                let detail = if directive.text() == "%expect-rr" {
+                    "Expected reduce-reduce conflicts"
+                } else {
+                    "Expected shift-reduce conflicts"
+                };
+                self.add_expect_symbol(arg, directive, &expect.number, detail);
+            }
+            Declaration::PercentDeriveDeclarationOptDeriveList(derive) => {
+                let derive_list = &derive.derive_list;
+                let children = [&derive_list.user_type_name]
+                    .into_iter()
+                    .chain(
+                        derive_list
+                            .derive_list_list
+                            .iter()
+                            .map(|d| &d.user_type_name),
+                    )
+                    .map(|d| {
+                        let range = Into::<Rng>::into(d).0;
+                        #[allow(deprecated)]
+                        DocumentSymbol {
+                            name: [d.identifier.identifier.text()]
+                                .into_iter()
+                                .chain(
+                                    d.user_type_name_list
+                                        .iter()
+                                        .map(|u| u.identifier.identifier.text()),
+                                )
+                                .collect::<Vec<_>>()
+                                .join("::"),
+                            detail: Some("Derive".to_string()),
+                            kind: SymbolKind::INTERFACE,
+                            tags: None,
+                            deprecated: None,
+                            range,
+                            selection_range: range,
+                            children: None,
+                        }
+                    })
+                    .collect::<Vec<DocumentSymbol>>();
+                if let Some(target) = &derive.declaration_opt {
+                    // Add the reference to the non-terminal for hover and rename support
+                    self.add_non_terminal_ref(&target.derive_target.identifier.identifier);
+                }
+                #[allow(deprecated)]
+                self.symbols.push(DocumentSymbol {
+                    name: derive.percent_derive.text().to_string(),
+                    detail: Some(derive.declaration_opt.as_ref().map_or(
+                        "Derives of all generated types".to_string(),
+                        |target| {
+                            format!(
+                                "Derives of the types of {}",
+                                target.derive_target.identifier.identifier.text()
+                            )
+                        },
+                    )),
+                    kind: SymbolKind::PROPERTY,
+                    tags: None,
+                    deprecated: None,
+                    range: Into::<Rng>::into(arg).0,
+                    selection_range: Into::<Rng>::into(&derive.percent_derive).0,
+                    children: Some(children),
+                });
+            }
+            Declaration::PrecedenceDeclaration(precedence) => {
+                let precedence = &precedence.precedence_declaration;
+                let children = [&precedence.precedence_symbol]
+                    .into_iter()
+                    .chain(
+                        precedence
+                            .precedence_declaration_list
+                            .iter()
+                            .map(|p| &p.precedence_symbol),
+                    )
+                    .map(|p| {
+                        let range = Into::<Rng>::into(p).0;
+                        #[allow(deprecated)]
+                        DocumentSymbol {
+                            name: match p {
+                                PrecedenceSymbol::TokenLiteral(t) => {
+                                    Self::expanded_token_literal(&t.token_literal)
+                                }
+                                PrecedenceSymbol::Identifier(i) => {
+                                    i.identifier.identifier.text().to_string()
+                                }
+                            },
+                            detail: Some("Operator".to_string()),
+                            kind: SymbolKind::OPERATOR,
+                            tags: None,
+                            deprecated: None,
+                            range,
+                            selection_range: range,
+                            children: None,
+                        }
+                    })
+                    .collect::<Vec<DocumentSymbol>>();
+                let associativity = match &precedence.associativity {
+                    Associativity::PercentLeft(left) => &left.percent_left,
+                    Associativity::PercentRight(right) => &right.percent_right,
+                    Associativity::PercentNonassoc(non_assoc) => &non_assoc.percent_nonassoc,
+                };
+                #[allow(deprecated)]
+                self.symbols.push(DocumentSymbol {
+                    name: associativity.text().to_string(),
+                    detail: Some("Operator precedence".to_string()),
+                    kind: SymbolKind::OPERATOR,
+                    tags: None,
+                    deprecated: None,
+                    range: Into::<Rng>::into(arg).0,
+                    selection_range: Into::<Rng>::into(associativity).0,
+                    children: Some(children),
+                });
+            }
             Declaration::ScannerDirectives(scanner) => {
                 let mut scanner_symbols: Vec<DocumentSymbol> = vec![];
                 self.add_scanner_symbols(&mut scanner_symbols, &scanner.scanner_directives);
@@ -626,57 +1061,61 @@ impl ParolLsGrammarTrait for ParolLsGrammar {
 
     /// Semantic action for non-terminal 'ProductionLHS'
     fn production_l_h_s(&mut self, arg: &ProductionLHS) -> Result<()> {
-        let token = &arg.identifier.identifier;
+        let token = &arg.production_name.name();
         self.add_non_terminal_definition(token);
         self.add_non_terminal_ref(token);
         Ok(())
     }
 
-    /// Semantic action for non-terminal 'TokenWithStates'
-    fn token_with_states(&mut self, arg: &TokenWithStates) -> Result<()> {
-        [arg.identifier_list.identifier.identifier.clone()]
-            .iter()
-            .chain(
-                arg.identifier_list
-                    .identifier_list_list
-                    .iter()
-                    .map(|id| &id.identifier.identifier),
-            )
-            .for_each(|id| {
-                let token = &id;
-                self.add_scanner_state_ref(token);
-            });
+    /// Semantic action for non-terminal 'Alternation'
+    fn alternation(&mut self, arg: &Alternation) -> Result<()> {
+        self.add_angle_arguments(
+            arg.alternation_list
+                .iter()
+                .map(|a| factor_symbol(&a.factor)),
+        );
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'TemplateArgument'
+    fn template_argument(&mut self, arg: &TemplateArgument) -> Result<()> {
+        self.add_angle_arguments(
+            std::iter::once(&arg.factor)
+                .chain(arg.template_argument_list.iter().map(|f| &f.factor))
+                .map(factor_symbol),
+        );
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'RepeatSeparator'
+    fn repeat_separator(&mut self, arg: &RepeatSeparator) -> Result<()> {
+        self.add_angle_arguments(
+            std::iter::once(&arg.symbol)
+                .chain(arg.repeat_separator_list.iter().map(|s| &s.symbol))
+                .map(Some),
+        );
         Ok(())
     }
 
     /// Semantic action for non-terminal 'Production'
     fn production(&mut self, arg: &Production) -> Result<()> {
-        let nt = arg
-            .production_l_h_s
-            .identifier
-            .identifier
-            .text()
-            .to_string();
+        let name = arg.production_l_h_s.production_name.name();
+        let nt = name.text().to_string();
         // let rng: Rng = arg.into();
         // eprintln!("Adding production {nt:?}: {rng:?}");
-        let entry = self.productions.entry(nt).or_default();
+        let entry = self.productions.entry(nt.clone()).or_default();
         entry.push(arg.clone());
         // eprintln!("Length: {}", entry.len());
 
         #[allow(deprecated)]
         self.symbols.push(DocumentSymbol {
-            name: arg
-                .production_l_h_s
-                .identifier
-                .identifier
-                .text()
-                .to_string(),
+            name: nt,
             detail: Some("Production".to_string()),
             kind: SymbolKind::FUNCTION,
             tags: None,
             deprecated: None,
             range: Into::<Rng>::into(arg).0,
-            selection_range: Into::<Rng>::into(&arg.production_l_h_s.identifier.identifier).0,
+            selection_range: Into::<Rng>::into(&name).0,
             children: None,
         });
         Ok(())
@@ -689,6 +1128,14 @@ impl ParolLsGrammarTrait for ParolLsGrammar {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'PrecedenceSymbol'
+    fn precedence_symbol(&mut self, arg: &PrecedenceSymbol) -> Result<()> {
+        if let PrecedenceSymbol::Identifier(identifier) = arg {
+            self.add_non_terminal_ref(&identifier.identifier.identifier);
+        }
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'ScannerState'
     fn scanner_state(&mut self, arg: &ScannerState) -> Result<()> {
         let scanner_state_symbols: Vec<DocumentSymbol> =
@@ -726,6 +1173,27 @@ impl ParolLsGrammarTrait for ParolLsGrammar {
     }
 }
 
+impl ProductionName {
+    /// The name of the production, for templates without the parameters
+    pub(crate) fn name(&self) -> OwnedToken {
+        self.identifier.identifier.clone()
+    }
+
+    /// The first token of the production's name
+    pub(crate) fn first_token(&self) -> &OwnedToken {
+        &self.identifier.identifier
+    }
+}
+
+// The symbol of a factor, a field label is irrelevant for the resolution of angle arguments
+fn factor_symbol(factor: &Factor) -> Option<&Symbol> {
+    match factor {
+        Factor::Symbol(s) => Some(&s.symbol),
+        Factor::FieldLabelSymbol(s) => Some(&s.symbol),
+        _ => None,
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct OwnedToken(Token<'static>);
 
@@ -40,6 +40,26 @@ pub trait ParolLsGrammarTrait {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'ExpectDirective'
+    fn expect_directive(&mut self, _arg: &ExpectDirective) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'PrecedenceDeclaration'
+    fn precedence_declaration(&mut self, _arg: &PrecedenceDeclaration) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'Associativity'
+    fn associativity(&mut self, _arg: &Associativity) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'PrecedenceSymbol'
+    fn precedence_symbol(&mut self, _arg: &PrecedenceSymbol) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'ScannerDirectives'
     fn scanner_directives(&mut self, _arg: &ScannerDirectives) -> Result<()> {
         Ok(())
@@ -55,11 +75,31 @@ pub trait ParolLsGrammarTrait {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'DeriveTarget'
+    fn derive_target(&mut self, _arg: &DeriveTarget) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'DeriveList'
+    fn derive_list(&mut self, _arg: &DeriveList) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'ProductionLHS'
     fn production_l_h_s(&mut self, _arg: &ProductionLHS) -> Result<()> {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'ProductionName'
+    fn production_name(&mut self, _arg: &ProductionName) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'TemplateParameters'
+    fn template_parameters(&mut self, _arg: &TemplateParameters) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'Production'
     fn production(&mut self, _arg: &Production) -> Result<()> {
         Ok(())
@@ -75,11 +115,21 @@ pub trait ParolLsGrammarTrait {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'AlternationLabel'
+    fn alternation_label(&mut self, _arg: &AlternationLabel) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'Factor'
     fn factor(&mut self, _arg: &Factor) -> Result<()> {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'FieldLabel'
+    fn field_label(&mut self, _arg: &FieldLabel) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'Symbol'
     fn symbol(&mut self, _arg: &Symbol) -> Result<()> {
         Ok(())
@@ -90,13 +140,18 @@ pub trait ParolLsGrammarTrait {
         Ok(())
     }
 
-    /// Semantic action for non-terminal 'SimpleToken'
-    fn simple_token(&mut self, _arg: &SimpleToken) -> Result<()> {
+    /// Semantic action for non-terminal 'TokenExpression'
+    fn token_expression(&mut self, _arg: &TokenExpression) -> Result<()> {
         Ok(())
     }
 
-    /// Semantic action for non-terminal 'TokenWithStates'
-    fn token_with_states(&mut self, _arg: &TokenWithStates) -> Result<()> {
+    /// Semantic action for non-terminal 'TrailingContext'
+    fn trailing_context(&mut self, _arg: &TrailingContext) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'SimpleToken'
+    fn simple_token(&mut self, _arg: &SimpleToken) -> Result<()> {
         Ok(())
     }
 
@@ -120,11 +175,31 @@ pub trait ParolLsGrammarTrait {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'AngleArguments'
+    fn angle_arguments(&mut self, _arg: &AngleArguments) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'TemplateArguments'
+    fn template_arguments(&mut self, _arg: &TemplateArguments) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'TemplateArgument'
+    fn template_argument(&mut self, _arg: &TemplateArgument) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'Identifier'
     fn identifier(&mut self, _arg: &Identifier) -> Result<()> {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'Number'
+    fn number(&mut self, _arg: &Number) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'String'
     fn string(&mut self, _arg: &String) -> Result<()> {
         Ok(())
@@ -170,6 +245,16 @@ pub trait ParolLsGrammarTrait {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'RepeatSeparator'
+    fn repeat_separator(&mut self, _arg: &RepeatSeparator) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'OneOrMore'
+    fn one_or_more(&mut self, _arg: &OneOrMore) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'Regex'
     fn regex(&mut self, _arg: &Regex) -> Result<()> {
         Ok(())
@@ -242,6 +327,98 @@ pub struct DeclarationPercentGrammarUnderscoreTypeLiteralString {
 ///
 /// Type derived for production 11
 ///
+/// `Declaration: "%include" : OwnedToken String;`
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct DeclarationPercentIncludeString {
+    pub percent_include: crate::parol_ls_grammar::OwnedToken, /* %include */
+    pub string: String,
+}
+
+///
+/// Type derived for production 12
+///
+/// `Declaration: "%keywords" : OwnedToken IdentifierList;`
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct DeclarationPercentKeywordsIdentifierList {
+    pub percent_keywords: crate::parol_ls_grammar::OwnedToken, /* %keywords */
+    pub identifier_list: IdentifierList,
+}
+
+///
+/// Type derived for production 13
+///
+/// `Declaration: "%derive" : OwnedToken DeclarationOpt /* Option */ DeriveList;`
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct DeclarationPercentDeriveDeclarationOptDeriveList {
+    pub percent_derive: crate::parol_ls_grammar::OwnedToken, /* %derive */
+    pub declaration_opt: Option<DeclarationOpt>,
+    pub derive_list: DeriveList,
+}
+
+///
+/// Type derived for production 14
+///
+/// `Declaration: "%inline" : OwnedToken IdentifierList;`
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct DeclarationPercentInlineIdentifierList {
+    pub percent_inline: crate::parol_ls_grammar::OwnedToken, /* %inline */
+    pub identifier_list: IdentifierList,
+}
+
+///
+/// Type derived for production 15
+///
+/// `Declaration: "%display" : OwnedToken Identifier String;`
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct DeclarationPercentDisplayIdentifierString {
+    pub percent_display: crate::parol_ls_grammar::OwnedToken, /* %display */
+    pub identifier: Identifier,
+    pub string: String,
+}
+
+///
+/// Type derived for production 16
+///
+/// `Declaration: ExpectDirective Number;`
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct DeclarationExpectDirectiveNumber {
+    pub expect_directive: ExpectDirective,
+    pub number: Number,
+}
+
+///
+/// Type derived for production 17
+///
+/// `Declaration: PrecedenceDeclaration;`
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct DeclarationPrecedenceDeclaration {
+    pub precedence_declaration: PrecedenceDeclaration,
+}
+
+///
+/// Type derived for production 18
+///
 /// `Declaration: ScannerDirectives;`
 ///
 #[allow(dead_code)]
@@ -252,7 +429,67 @@ pub struct DeclarationScannerDirectives {
 }
 
 ///
-/// Type derived for production 12
+/// Type derived for production 25
+///
+/// `Associativity: '%left' : OwnedToken;`
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct AssociativityPercentLeft {
+    pub percent_left: crate::parol_ls_grammar::OwnedToken, /* %left */
+}
+
+///
+/// Type derived for production 26
+///
+/// `Associativity: '%right' : OwnedToken;`
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct AssociativityPercentRight {
+    pub percent_right: crate::parol_ls_grammar::OwnedToken, /* %right */
+}
+
+///
+/// Type derived for production 27
+///
+/// `Associativity: '%nonassoc' : OwnedToken;`
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct AssociativityPercentNonassoc {
+    pub percent_nonassoc: crate::parol_ls_grammar::OwnedToken, /* %nonassoc */
+}
+
+///
+/// Type derived for production 28
+///
+/// `PrecedenceSymbol: TokenLiteral;`
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct PrecedenceSymbolTokenLiteral {
+    pub token_literal: TokenLiteral,
+}
+
+///
+/// Type derived for production 29
+///
+/// `PrecedenceSymbol: Identifier;`
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct PrecedenceSymbolIdentifier {
+    pub identifier: Identifier,
+}
+
+///
+/// Type derived for production 30
 ///
 /// `ScannerDirectives: "%line_comment" : OwnedToken TokenLiteral;`
 ///
@@ -265,7 +502,7 @@ pub struct ScannerDirectivesPercentLineUnderscoreCommentTokenLiteral {
 }
 
 ///
-/// Type derived for production 13
+/// Type derived for production 31
 ///
 /// `ScannerDirectives: "%block_comment" : OwnedToken TokenLiteral TokenLiteral;`
 ///
@@ -279,7 +516,21 @@ pub struct ScannerDirectivesPercentBlockUnderscoreCommentTokenLiteralTokenLitera
 }
 
 ///
-/// Type derived for production 14
+/// Type derived for production 32
+///
+/// `ScannerDirectives: "%nested_block_comment" : OwnedToken TokenLiteral TokenLiteral;`
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct ScannerDirectivesPercentNestedUnderscoreBlockUnderscoreCommentTokenLiteralTokenLiteral {
+    pub percent_nested_underscore_block_underscore_comment: crate::parol_ls_grammar::OwnedToken, /* %nested_block_comment */
+    pub token_literal: TokenLiteral,
+    pub token_literal0: TokenLiteral,
+}
+
+///
+/// Type derived for production 33
 ///
 /// `ScannerDirectives: "%auto_newline_off" : OwnedToken;`
 ///
@@ -291,7 +542,7 @@ pub struct ScannerDirectivesPercentAutoUnderscoreNewlineUnderscoreOff {
 }
 
 ///
-/// Type derived for production 15
+/// Type derived for production 34
 ///
 /// `ScannerDirectives: "%auto_ws_off" : OwnedToken;`
 ///
@@ -303,7 +554,46 @@ pub struct ScannerDirectivesPercentAutoUnderscoreWsUnderscoreOff {
 }
 
 ///
-/// Type derived for production 16
+/// Type derived for production 35
+///
+/// `ScannerDirectives: "%longest_match" : OwnedToken;`
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct ScannerDirectivesPercentLongestUnderscoreMatch {
+    pub percent_longest_underscore_match: crate::parol_ls_grammar::OwnedToken, /* %longest_match */
+}
+
+///
+/// Type derived for production 36
+///
+/// `ScannerDirectives: "%case_insensitive" : OwnedToken;`
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct ScannerDirectivesPercentCaseUnderscoreInsensitive {
+    pub percent_case_underscore_insensitive: crate::parol_ls_grammar::OwnedToken, /* %case_insensitive */
+}
+
+///
+/// Type derived for production 37
+///
+/// `ScannerDirectives: "%offside" : OwnedToken Identifier Identifier Identifier;`
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct ScannerDirectivesPercentOffsideIdentifierIdentifierIdentifier {
+    pub percent_offside: crate::parol_ls_grammar::OwnedToken, /* %offside */
+    pub identifier: Identifier,
+    pub identifier0: Identifier,
+    pub identifier1: Identifier,
+}
+
+///
+/// Type derived for production 38
 ///
 /// `ScannerDirectives: '%on' : OwnedToken IdentifierList '%enter' : OwnedToken Identifier;`
 ///
@@ -318,7 +608,7 @@ pub struct ScannerDirectivesPercentOnIdentifierListPercentEnterIdentifier {
 }
 
 ///
-/// Type derived for production 29
+/// Type derived for production 64
 ///
 /// `Factor: Group;`
 ///
@@ -330,7 +620,7 @@ pub struct FactorGroup {
 }
 
 ///
-/// Type derived for production 30
+/// Type derived for production 65
 ///
 /// `Factor: Repeat;`
 ///
@@ -338,11 +628,11 @@ pub struct FactorGroup {
 #[derive(Builder, Debug, Clone)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct FactorRepeat {
-    pub repeat: Repeat,
+    pub repeat: Box<Repeat>,
 }
 
 ///
-/// Type derived for production 31
+/// Type derived for production 66
 ///
 /// `Factor: Optional;`
 ///
@@ -354,7 +644,7 @@ pub struct FactorOptional {
 }
 
 ///
-/// Type derived for production 32
+/// Type derived for production 67
 ///
 /// `Factor: Symbol;`
 ///
@@ -362,11 +652,24 @@ pub struct FactorOptional {
 #[derive(Builder, Debug, Clone)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct FactorSymbol {
-    pub symbol: Symbol,
+    pub symbol: Box<Symbol>,
 }
 
 ///
-/// Type derived for production 33
+/// Type derived for production 68
+///
+/// `Factor: FieldLabel Symbol;`
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct FactorFieldLabelSymbol {
+    pub field_label: FieldLabel,
+    pub symbol: Box<Symbol>,
+}
+
+///
+/// Type derived for production 70
 ///
 /// `Symbol: NonTerminal;`
 ///
@@ -378,7 +681,7 @@ pub struct SymbolNonTerminal {
 }
 
 ///
-/// Type derived for production 34
+/// Type derived for production 71
 ///
 /// `Symbol: SimpleToken;`
 ///
@@ -390,19 +693,19 @@ pub struct SymbolSimpleToken {
 }
 
 ///
-/// Type derived for production 35
+/// Type derived for production 72
 ///
-/// `Symbol: TokenWithStates;`
+/// `Symbol: AngleArguments;`
 ///
 #[allow(dead_code)]
 #[derive(Builder, Debug, Clone)]
 #[builder(crate = "parol_runtime::derive_builder")]
-pub struct SymbolTokenWithStates {
-    pub token_with_states: TokenWithStates,
+pub struct SymbolAngleArguments {
+    pub angle_arguments: AngleArguments,
 }
 
 ///
-/// Type derived for production 36
+/// Type derived for production 73
 ///
 /// `Symbol: ScannerSwitch;`
 ///
@@ -414,7 +717,7 @@ pub struct SymbolScannerSwitch {
 }
 
 ///
-/// Type derived for production 37
+/// Type derived for production 74
 ///
 /// `TokenLiteral: String;`
 ///
@@ -426,7 +729,7 @@ pub struct TokenLiteralString {
 }
 
 ///
-/// Type derived for production 38
+/// Type derived for production 75
 ///
 /// `TokenLiteral: LiteralString;`
 ///
@@ -438,7 +741,7 @@ pub struct TokenLiteralLiteralString {
 }
 
 ///
-/// Type derived for production 39
+/// Type derived for production 76
 ///
 /// `TokenLiteral: Regex;`
 ///
@@ -450,7 +753,7 @@ pub struct TokenLiteralRegex {
 }
 
 ///
-/// Type derived for production 61
+/// Type derived for production 113
 ///
 /// `ScannerSwitch: "%sc" : OwnedToken '(' : OwnedToken ScannerSwitchOpt /* Option */ ')' : OwnedToken;`
 ///
@@ -465,7 +768,7 @@ pub struct ScannerSwitchPercentScLParenScannerSwitchOptRParen {
 }
 
 ///
-/// Type derived for production 62
+/// Type derived for production 114
 ///
 /// `ScannerSwitch: "%push" : OwnedToken '(' : OwnedToken Identifier ')' : OwnedToken;`
 ///
@@ -480,7 +783,7 @@ pub struct ScannerSwitchPercentPushLParenIdentifierRParen {
 }
 
 ///
-/// Type derived for production 63
+/// Type derived for production 115
 ///
 /// `ScannerSwitch: "%pop" : OwnedToken '(' : OwnedToken ')' : OwnedToken;`
 ///
@@ -494,7 +797,7 @@ pub struct ScannerSwitchPercentPopLParenRParen {
 }
 
 ///
-/// Type derived for production 66
+/// Type derived for production 118
 ///
 /// `ASTControl: CutOperator;`
 ///
@@ -506,7 +809,7 @@ pub struct ASTControlCutOperator {
 }
 
 ///
-/// Type derived for production 67
+/// Type derived for production 119
 ///
 /// `ASTControl: UserTypeDeclaration;`
 ///
@@ -540,52 +843,120 @@ pub enum ASTControl {
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Alternation {
     pub alternation_list: Vec<AlternationList>,
+    pub alternation_opt: Option<AlternationOpt>,
+    pub alternation_opt0: Option<AlternationOpt0>,
 }
 
 ///
-/// Type derived for non-terminal AlternationList
+/// Type derived for non-terminal AlternationLabel
 ///
 #[allow(dead_code)]
 #[derive(Builder, Debug, Clone)]
 #[builder(crate = "parol_runtime::derive_builder")]
-pub struct AlternationList {
-    pub factor: Factor,
+pub struct AlternationLabel {
+    pub hash: crate::parol_ls_grammar::OwnedToken, /* # */
+    pub identifier: Identifier,
 }
 
 ///
-/// Type derived for non-terminal Alternations
+/// Type derived for non-terminal AlternationList
 ///
 #[allow(dead_code)]
 #[derive(Builder, Debug, Clone)]
 #[builder(crate = "parol_runtime::derive_builder")]
-pub struct Alternations {
-    pub alternation: Alternation,
-    pub alternations_list: Vec<AlternationsList>,
+pub struct AlternationList {
+    pub factor: Factor,
 }
 
 ///
-/// Type derived for non-terminal AlternationsList
+/// Type derived for non-terminal AlternationOpt
 ///
 #[allow(dead_code)]
 #[derive(Builder, Debug, Clone)]
 #[builder(crate = "parol_runtime::derive_builder")]
-pub struct AlternationsList {
-    pub or: crate::parol_ls_grammar::OwnedToken, /* | */
-    pub alternation: Alternation,
+pub struct AlternationOpt {
+    pub percent_prec: crate::parol_ls_grammar::OwnedToken, /* %prec */
+    pub precedence_symbol: PrecedenceSymbol,
 }
 
 ///
-/// Type derived for non-terminal CutOperator
+/// Type derived for non-terminal AlternationOpt0
 ///
 #[allow(dead_code)]
 #[derive(Builder, Debug, Clone)]
 #[builder(crate = "parol_runtime::derive_builder")]
-pub struct CutOperator {
-    pub cut_operator: crate::parol_ls_grammar::OwnedToken, /* ^ */
+pub struct AlternationOpt0 {
+    pub alternation_label: AlternationLabel,
 }
 
 ///
-/// Type derived for non-terminal Declaration
+/// Type derived for non-terminal Alternations
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct Alternations {
+    pub alternation: Alternation,
+    pub alternations_list: Vec<AlternationsList>,
+}
+
+///
+/// Type derived for non-terminal AlternationsList
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct AlternationsList {
+    pub or: crate::parol_ls_grammar::OwnedToken, /* | */
+    pub alternation: Alternation,
+}
+
+///
+/// Type derived for non-terminal AngleArguments
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct AngleArguments {
+    pub l_t: crate::parol_ls_grammar::OwnedToken, /* < */
+    pub template_arguments: TemplateArguments,
+    pub g_t: crate::parol_ls_grammar::OwnedToken, /* > */
+    pub angle_arguments_opt: Option<AngleArgumentsOpt>,
+}
+
+///
+/// Type derived for non-terminal AngleArgumentsOpt
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct AngleArgumentsOpt {
+    pub a_s_t_control: ASTControl,
+}
+
+///
+/// Type derived for non-terminal Associativity
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub enum Associativity {
+    PercentLeft(AssociativityPercentLeft),
+    PercentRight(AssociativityPercentRight),
+    PercentNonassoc(AssociativityPercentNonassoc),
+}
+
+///
+/// Type derived for non-terminal CutOperator
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct CutOperator {
+    pub cut_operator: crate::parol_ls_grammar::OwnedToken, /* ^ */
+}
+
+///
+/// Type derived for non-terminal Declaration
 ///
 #[allow(dead_code)]
 #[derive(Debug, Clone)]
@@ -596,9 +967,59 @@ pub enum Declaration {
         DeclarationPercentUserUnderscoreTypeIdentifierEquUserTypeName,
     ),
     PercentGrammarUnderscoreTypeLiteralString(DeclarationPercentGrammarUnderscoreTypeLiteralString),
+    PercentIncludeString(DeclarationPercentIncludeString),
+    PercentKeywordsIdentifierList(DeclarationPercentKeywordsIdentifierList),
+    PercentDeriveDeclarationOptDeriveList(DeclarationPercentDeriveDeclarationOptDeriveList),
+    PercentInlineIdentifierList(DeclarationPercentInlineIdentifierList),
+    PercentDisplayIdentifierString(DeclarationPercentDisplayIdentifierString),
+    ExpectDirectiveNumber(DeclarationExpectDirectiveNumber),
+    PrecedenceDeclaration(DeclarationPrecedenceDeclaration),
     ScannerDirectives(DeclarationScannerDirectives),
 }
 
+///
+/// Type derived for non-terminal DeclarationOpt
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct DeclarationOpt {
+    pub derive_target: DeriveTarget,
+}
+
+///
+/// Type derived for non-terminal DeriveList
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct DeriveList {
+    pub user_type_name: UserTypeName,
+    pub derive_list_list: Vec<DeriveListList>,
+}
+
+///
+/// Type derived for non-terminal DeriveListList
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct DeriveListList {
+    pub comma: crate::parol_ls_grammar::OwnedToken, /* , */
+    pub user_type_name: UserTypeName,
+}
+
+///
+/// Type derived for non-terminal DeriveTarget
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct DeriveTarget {
+    pub identifier: Identifier,
+    pub colon: crate::parol_ls_grammar::OwnedToken, /* : */
+}
+
 ///
 /// Type derived for non-terminal DoubleColon
 ///
@@ -609,6 +1030,16 @@ pub struct DoubleColon {
     pub double_colon: crate::parol_ls_grammar::OwnedToken, /* :: */
 }
 
+///
+/// Type derived for non-terminal ExpectDirective
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct ExpectDirective {
+    pub expect_directive: crate::parol_ls_grammar::OwnedToken, /* %expect(-rr)? */
+}
+
 ///
 /// Type derived for non-terminal Factor
 ///
@@ -619,6 +1050,18 @@ pub enum Factor {
     Repeat(FactorRepeat),
     Optional(FactorOptional),
     Symbol(FactorSymbol),
+    FieldLabelSymbol(FactorFieldLabelSymbol),
+}
+
+///
+/// Type derived for non-terminal FieldLabel
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct FieldLabel {
+    pub identifier: Identifier,
+    pub equ: crate::parol_ls_grammar::OwnedToken, /* = */
 }
 
 ///
@@ -694,7 +1137,7 @@ pub struct IdentifierListList {
 #[derive(Builder, Debug, Clone)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct LiteralString {
-    pub literal_string: crate::parol_ls_grammar::OwnedToken, /* '(\\'|[^'])*?' */
+    pub literal_string: crate::parol_ls_grammar::OwnedToken, /* '(\\'|[^'])*?'i? */
 }
 
 ///
@@ -718,6 +1161,26 @@ pub struct NonTerminalOpt {
     pub a_s_t_control: ASTControl,
 }
 
+///
+/// Type derived for non-terminal Number
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct Number {
+    pub number: crate::parol_ls_grammar::OwnedToken, /* [0-9]+ */
+}
+
+///
+/// Type derived for non-terminal OneOrMore
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct OneOrMore {
+    pub one_or_more: crate::parol_ls_grammar::OwnedToken, /* + */
+}
+
 ///
 /// Type derived for non-terminal Optional
 ///
@@ -741,6 +1204,38 @@ pub struct ParolLs {
     pub grammar_definition: GrammarDefinition,
 }
 
+///
+/// Type derived for non-terminal PrecedenceDeclaration
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct PrecedenceDeclaration {
+    pub associativity: Associativity,
+    pub precedence_symbol: PrecedenceSymbol,
+    pub precedence_declaration_list: Vec<PrecedenceDeclarationList>,
+}
+
+///
+/// Type derived for non-terminal PrecedenceDeclarationList
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct PrecedenceDeclarationList {
+    pub precedence_symbol: PrecedenceSymbol,
+}
+
+///
+/// Type derived for non-terminal PrecedenceSymbol
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub enum PrecedenceSymbol {
+    TokenLiteral(PrecedenceSymbolTokenLiteral),
+    Identifier(PrecedenceSymbolIdentifier),
+}
+
 ///
 /// Type derived for non-terminal Production
 ///
@@ -760,10 +1255,31 @@ pub struct Production {
 #[derive(Builder, Debug, Clone)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ProductionLHS {
-    pub identifier: Identifier,
+    pub production_name: ProductionName,
     pub colon: crate::parol_ls_grammar::OwnedToken, /* : */
 }
 
+///
+/// Type derived for non-terminal ProductionName
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct ProductionName {
+    pub identifier: Identifier,
+    pub production_name_opt: Option<ProductionNameOpt>,
+}
+
+///
+/// Type derived for non-terminal ProductionNameOpt
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct ProductionNameOpt {
+    pub template_parameters: TemplateParameters,
+}
+
 ///
 /// Type derived for non-terminal Prolog
 ///
@@ -803,7 +1319,7 @@ pub struct PrologList0 {
 #[derive(Builder, Debug, Clone)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Regex {
-    pub regex: crate::parol_ls_grammar::OwnedToken, /* \u{2f}(\\.|[^\\])*?\u{2f} */
+    pub regex: crate::parol_ls_grammar::OwnedToken, /* \u{2f}(\\.|[^\\])*?\u{2f}i? */
 }
 
 ///
@@ -815,7 +1331,51 @@ pub struct Regex {
 pub struct Repeat {
     pub l_brace: crate::parol_ls_grammar::OwnedToken, /* { */
     pub alternations: Alternations,
+    pub repeat_opt: Option<RepeatOpt>,
     pub r_brace: crate::parol_ls_grammar::OwnedToken, /* } */
+    pub repeat_opt0: Option<RepeatOpt0>,
+}
+
+///
+/// Type derived for non-terminal RepeatOpt
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct RepeatOpt {
+    pub repeat_separator: RepeatSeparator,
+}
+
+///
+/// Type derived for non-terminal RepeatOpt0
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct RepeatOpt0 {
+    pub one_or_more: OneOrMore,
+}
+
+///
+/// Type derived for non-terminal RepeatSeparator
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct RepeatSeparator {
+    pub percent: crate::parol_ls_grammar::OwnedToken, /* % */
+    pub symbol: Symbol,
+    pub repeat_separator_list: Vec<RepeatSeparatorList>,
+}
+
+///
+/// Type derived for non-terminal RepeatSeparatorList
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct RepeatSeparatorList {
+    pub symbol: Symbol,
 }
 
 ///
@@ -830,10 +1390,18 @@ pub enum ScannerDirectives {
     PercentBlockUnderscoreCommentTokenLiteralTokenLiteral(
         ScannerDirectivesPercentBlockUnderscoreCommentTokenLiteralTokenLiteral,
     ),
+    PercentNestedUnderscoreBlockUnderscoreCommentTokenLiteralTokenLiteral(
+        ScannerDirectivesPercentNestedUnderscoreBlockUnderscoreCommentTokenLiteralTokenLiteral,
+    ),
     PercentAutoUnderscoreNewlineUnderscoreOff(
         ScannerDirectivesPercentAutoUnderscoreNewlineUnderscoreOff,
     ),
     PercentAutoUnderscoreWsUnderscoreOff(ScannerDirectivesPercentAutoUnderscoreWsUnderscoreOff),
+    PercentLongestUnderscoreMatch(ScannerDirectivesPercentLongestUnderscoreMatch),
+    PercentCaseUnderscoreInsensitive(ScannerDirectivesPercentCaseUnderscoreInsensitive),
+    PercentOffsideIdentifierIdentifierIdentifier(
+        ScannerDirectivesPercentOffsideIdentifierIdentifierIdentifier,
+    ),
     PercentOnIdentifierListPercentEnterIdentifier(
         ScannerDirectivesPercentOnIdentifierListPercentEnterIdentifier,
     ),
@@ -891,7 +1459,7 @@ pub struct ScannerSwitchOpt {
 #[derive(Builder, Debug, Clone)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct SimpleToken {
-    pub token_literal: TokenLiteral,
+    pub token_expression: TokenExpression,
     pub simple_token_opt: Option<SimpleTokenOpt>,
 }
 
@@ -923,7 +1491,7 @@ pub struct StartDeclaration {
 #[derive(Builder, Debug, Clone)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct String {
-    pub string: crate::parol_ls_grammar::OwnedToken, /* "(\\.|[^\\])*?" */
+    pub string: crate::parol_ls_grammar::OwnedToken, /* "(\\.|[^\\])*?"i? */
 }
 
 ///
@@ -934,43 +1502,106 @@ pub struct String {
 pub enum Symbol {
     NonTerminal(SymbolNonTerminal),
     SimpleToken(SymbolSimpleToken),
-    TokenWithStates(SymbolTokenWithStates),
+    AngleArguments(SymbolAngleArguments),
     ScannerSwitch(SymbolScannerSwitch),
 }
 
 ///
-/// Type derived for non-terminal TokenLiteral
+/// Type derived for non-terminal TemplateArgument
 ///
 #[allow(dead_code)]
-#[derive(Debug, Clone)]
-pub enum TokenLiteral {
-    String(TokenLiteralString),
-    LiteralString(TokenLiteralLiteralString),
-    Regex(TokenLiteralRegex),
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct TemplateArgument {
+    pub factor: Factor,
+    pub template_argument_list: Vec<TemplateArgumentList>,
+}
+
+///
+/// Type derived for non-terminal TemplateArgumentList
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct TemplateArgumentList {
+    pub factor: Factor,
+}
+
+///
+/// Type derived for non-terminal TemplateArguments
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct TemplateArguments {
+    pub template_argument: TemplateArgument,
+    pub template_arguments_list: Vec<TemplateArgumentsList>,
+}
+
+///
+/// Type derived for non-terminal TemplateArgumentsList
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct TemplateArgumentsList {
+    pub comma: crate::parol_ls_grammar::OwnedToken, /* , */
+    pub template_argument: TemplateArgument,
 }
 
 ///
-/// Type derived for non-terminal TokenWithStates
+/// Type derived for non-terminal TemplateParameters
 ///
 #[allow(dead_code)]
 #[derive(Builder, Debug, Clone)]
 #[builder(crate = "parol_runtime::derive_builder")]
-pub struct TokenWithStates {
+pub struct TemplateParameters {
     pub l_t: crate::parol_ls_grammar::OwnedToken, /* < */
     pub identifier_list: IdentifierList,
     pub g_t: crate::parol_ls_grammar::OwnedToken, /* > */
+}
+
+///
+/// Type derived for non-terminal TokenExpression
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct TokenExpression {
     pub token_literal: TokenLiteral,
-    pub token_with_states_opt: Option<TokenWithStatesOpt>,
+    pub token_expression_opt: Option<TokenExpressionOpt>,
 }
 
 ///
-/// Type derived for non-terminal TokenWithStatesOpt
+/// Type derived for non-terminal TokenExpressionOpt
 ///
 #[allow(dead_code)]
 #[derive(Builder, Debug, Clone)]
 #[builder(crate = "parol_runtime::derive_builder")]
-pub struct TokenWithStatesOpt {
-    pub a_s_t_control: ASTControl,
+pub struct TokenExpressionOpt {
+    pub trailing_context: TrailingContext,
+}
+
+///
+/// Type derived for non-terminal TokenLiteral
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub enum TokenLiteral {
+    String(TokenLiteralString),
+    LiteralString(TokenLiteralLiteralString),
+    Regex(TokenLiteralRegex),
+}
+
+///
+/// Type derived for non-terminal TrailingContext
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct TrailingContext {
+    pub quest_equ: crate::parol_ls_grammar::OwnedToken, /* ?= */
+    pub token_literal: TokenLiteral,
 }
 
 ///
@@ -1016,13 +1647,25 @@ pub struct UserTypeNameList {
 pub enum ASTType {
     ASTControl(ASTControl),
     Alternation(Alternation),
+    AlternationLabel(AlternationLabel),
     AlternationList(Vec<AlternationList>),
+    AlternationOpt(Option<AlternationOpt>),
+    AlternationOpt0(Option<AlternationOpt0>),
     Alternations(Alternations),
     AlternationsList(Vec<AlternationsList>),
+    AngleArguments(AngleArguments),
+    AngleArgumentsOpt(Option<AngleArgumentsOpt>),
+    Associativity(Associativity),
     CutOperator(CutOperator),
     Declaration(Declaration),
+    DeclarationOpt(Option<DeclarationOpt>),
+    DeriveList(DeriveList),
+    DeriveListList(Vec<DeriveListList>),
+    DeriveTarget(DeriveTarget),
     DoubleColon(DoubleColon),
+    ExpectDirective(ExpectDirective),
     Factor(Factor),
+    FieldLabel(FieldLabel),
     GrammarDefinition(GrammarDefinition),
     GrammarDefinitionList(Vec<GrammarDefinitionList>),
     Group(Group),
@@ -1032,15 +1675,26 @@ pub enum ASTType {
     LiteralString(LiteralString),
     NonTerminal(NonTerminal),
     NonTerminalOpt(Option<NonTerminalOpt>),
+    Number(Number),
+    OneOrMore(OneOrMore),
     Optional(Optional),
     ParolLs(ParolLs),
+    PrecedenceDeclaration(PrecedenceDeclaration),
+    PrecedenceDeclarationList(Vec<PrecedenceDeclarationList>),
+    PrecedenceSymbol(PrecedenceSymbol),
     Production(Production),
     ProductionLHS(ProductionLHS),
+    ProductionName(ProductionName),
+    ProductionNameOpt(Option<ProductionNameOpt>),
     Prolog(Prolog),
     PrologList(Vec<PrologList>),
     PrologList0(Vec<PrologList0>),
     Regex(Regex),
     Repeat(Repeat),
+    RepeatOpt(Option<RepeatOpt>),
+    RepeatOpt0(Option<RepeatOpt0>),
+    RepeatSeparator(RepeatSeparator),
+    RepeatSeparatorList(Vec<RepeatSeparatorList>),
     ScannerDirectives(ScannerDirectives),
     ScannerState(ScannerState),
     ScannerStateList(Vec<ScannerStateList>),
@@ -1051,9 +1705,15 @@ pub enum ASTType {
     StartDeclaration(StartDeclaration),
     String(String),
     Symbol(Symbol),
+    TemplateArgument(TemplateArgument),
+    TemplateArgumentList(Vec<TemplateArgumentList>),
+    TemplateArguments(TemplateArguments),
+    TemplateArgumentsList(Vec<TemplateArgumentsList>),
+    TemplateParameters(TemplateParameters),
+    TokenExpression(TokenExpression),
+    TokenExpressionOpt(Option<TokenExpressionOpt>),
     TokenLiteral(TokenLiteral),
-    TokenWithStates(TokenWithStates),
-    TokenWithStatesOpt(Option<TokenWithStatesOpt>),
+    TrailingContext(TrailingContext),
     UserTypeDeclaration(UserTypeDeclaration),
     UserTypeName(UserTypeName),
     UserTypeNameList(Vec<UserTypeNameList>),
@@ -1393,282 +2053,225 @@ impl<'t, 'u> ParolLsGrammarAuto<'t, 'u> {
 
     /// Semantic action for production 11:
     ///
-    /// `Declaration: ScannerDirectives;`
+    /// `Declaration: "%include" : OwnedToken String;`
     ///
     #[parol_runtime::function_name::named]
-    fn declaration_4(&mut self, _scanner_directives: &ParseTreeType<'t>) -> Result<()> {
+    fn declaration_4(
+        &mut self,
+        percent_include: &ParseTreeType<'t>,
+        _string: &ParseTreeType<'t>,
+    ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let scanner_directives = pop_item!(self, scanner_directives, ScannerDirectives, context);
-        let declaration_4_built = DeclarationScannerDirectives { scanner_directives };
-        let declaration_4_built = Declaration::ScannerDirectives(declaration_4_built);
-        // Calling user action here
-        self.user_grammar.declaration(&declaration_4_built)?;
-        self.push(ASTType::Declaration(declaration_4_built), context);
-        Ok(())
-    }
+        let percent_include = percent_include
+            .token()?
+            .try_into()
+            .map_err(parol_runtime::ParolError::UserError)?;
+        let string = pop_item!(self, string, String, context);
+        let declaration_4_built = DeclarationPercentIncludeString {
+            percent_include,
+            string,
+        };
+        let declaration_4_built = Declaration::PercentIncludeString(declaration_4_built);
+        // Calling user action here
+        self.user_grammar.declaration(&declaration_4_built)?;
+        self.push(ASTType::Declaration(declaration_4_built), context);
+        Ok(())
+    }
 
     /// Semantic action for production 12:
     ///
-    /// `ScannerDirectives: "%line_comment" : OwnedToken TokenLiteral;`
+    /// `Declaration: "%keywords" : OwnedToken IdentifierList;`
     ///
     #[parol_runtime::function_name::named]
-    fn scanner_directives_0(
+    fn declaration_5(
         &mut self,
-        percent_line_underscore_comment: &ParseTreeType<'t>,
-        _token_literal: &ParseTreeType<'t>,
+        percent_keywords: &ParseTreeType<'t>,
+        _identifier_list: &ParseTreeType<'t>,
     ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let percent_line_underscore_comment = percent_line_underscore_comment
+        let percent_keywords = percent_keywords
             .token()?
             .try_into()
             .map_err(parol_runtime::ParolError::UserError)?;
-        let token_literal = pop_item!(self, token_literal, TokenLiteral, context);
-        let scanner_directives_0_built =
-            ScannerDirectivesPercentLineUnderscoreCommentTokenLiteral {
-                percent_line_underscore_comment,
-                token_literal,
-            };
-        let scanner_directives_0_built =
-            ScannerDirectives::PercentLineUnderscoreCommentTokenLiteral(scanner_directives_0_built);
+        let identifier_list = pop_item!(self, identifier_list, IdentifierList, context);
+        let declaration_5_built = DeclarationPercentKeywordsIdentifierList {
+            percent_keywords,
+            identifier_list,
+        };
+        let declaration_5_built = Declaration::PercentKeywordsIdentifierList(declaration_5_built);
         // Calling user action here
-        self.user_grammar
-            .scanner_directives(&scanner_directives_0_built)?;
-        self.push(
-            ASTType::ScannerDirectives(scanner_directives_0_built),
-            context,
-        );
+        self.user_grammar.declaration(&declaration_5_built)?;
+        self.push(ASTType::Declaration(declaration_5_built), context);
         Ok(())
     }
 
     /// Semantic action for production 13:
     ///
-    /// `ScannerDirectives: "%block_comment" : OwnedToken TokenLiteral TokenLiteral;`
+    /// `Declaration: "%derive" : OwnedToken DeclarationOpt /* Option */ DeriveList;`
     ///
     #[parol_runtime::function_name::named]
-    fn scanner_directives_1(
+    fn declaration_6(
         &mut self,
-        percent_block_underscore_comment: &ParseTreeType<'t>,
-        _token_literal: &ParseTreeType<'t>,
-        _token_literal0: &ParseTreeType<'t>,
+        percent_derive: &ParseTreeType<'t>,
+        _declaration_opt: &ParseTreeType<'t>,
+        _derive_list: &ParseTreeType<'t>,
     ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let percent_block_underscore_comment = percent_block_underscore_comment
+        let percent_derive = percent_derive
             .token()?
             .try_into()
             .map_err(parol_runtime::ParolError::UserError)?;
-        let token_literal0 = pop_item!(self, token_literal0, TokenLiteral, context);
-        let token_literal = pop_item!(self, token_literal, TokenLiteral, context);
-        let scanner_directives_1_built =
-            ScannerDirectivesPercentBlockUnderscoreCommentTokenLiteralTokenLiteral {
-                percent_block_underscore_comment,
-                token_literal,
-                token_literal0,
-            };
-        let scanner_directives_1_built =
-            ScannerDirectives::PercentBlockUnderscoreCommentTokenLiteralTokenLiteral(
-                scanner_directives_1_built,
-            );
+        let derive_list = pop_item!(self, derive_list, DeriveList, context);
+        let declaration_opt = pop_item!(self, declaration_opt, DeclarationOpt, context);
+        let declaration_6_built = DeclarationPercentDeriveDeclarationOptDeriveList {
+            percent_derive,
+            declaration_opt,
+            derive_list,
+        };
+        let declaration_6_built =
+            Declaration::PercentDeriveDeclarationOptDeriveList(declaration_6_built);
         // Calling user action here
-        self.user_grammar
-            .scanner_directives(&scanner_directives_1_built)?;
-        self.push(
-            ASTType::ScannerDirectives(scanner_directives_1_built),
-            context,
-        );
+        self.user_grammar.declaration(&declaration_6_built)?;
+        self.push(ASTType::Declaration(declaration_6_built), context);
         Ok(())
     }
 
     /// Semantic action for production 14:
     ///
-    /// `ScannerDirectives: "%auto_newline_off" : OwnedToken;`
+    /// `Declaration: "%inline" : OwnedToken IdentifierList;`
     ///
     #[parol_runtime::function_name::named]
-    fn scanner_directives_2(
+    fn declaration_7(
         &mut self,
-        percent_auto_underscore_newline_underscore_off: &ParseTreeType<'t>,
+        percent_inline: &ParseTreeType<'t>,
+        _identifier_list: &ParseTreeType<'t>,
     ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let percent_auto_underscore_newline_underscore_off =
-            percent_auto_underscore_newline_underscore_off
-                .token()?
-                .try_into()
-                .map_err(parol_runtime::ParolError::UserError)?;
-        let scanner_directives_2_built =
-            ScannerDirectivesPercentAutoUnderscoreNewlineUnderscoreOff {
-                percent_auto_underscore_newline_underscore_off,
-            };
-        let scanner_directives_2_built =
-            ScannerDirectives::PercentAutoUnderscoreNewlineUnderscoreOff(
-                scanner_directives_2_built,
-            );
+        let percent_inline = percent_inline
+            .token()?
+            .try_into()
+            .map_err(parol_runtime::ParolError::UserError)?;
+        let identifier_list = pop_item!(self, identifier_list, IdentifierList, context);
+        let declaration_7_built = DeclarationPercentInlineIdentifierList {
+            percent_inline,
+            identifier_list,
+        };
+        let declaration_7_built = Declaration::PercentInlineIdentifierList(declaration_7_built);
         // Calling user action here
-        self.user_grammar
-            .scanner_directives(&scanner_directives_2_built)?;
-        self.push(
-            ASTType::ScannerDirectives(scanner_directives_2_built),
-            context,
-        );
+        self.user_grammar.declaration(&declaration_7_built)?;
+        self.push(ASTType::Declaration(declaration_7_built), context);
         Ok(())
     }
 
     /// Semantic action for production 15:
     ///
-    /// `ScannerDirectives: "%auto_ws_off" : OwnedToken;`
+    /// `Declaration: "%display" : OwnedToken Identifier String;`
     ///
     #[parol_runtime::function_name::named]
-    fn scanner_directives_3(
+    fn declaration_8(
         &mut self,
-        percent_auto_underscore_ws_underscore_off: &ParseTreeType<'t>,
+        percent_display: &ParseTreeType<'t>,
+        _identifier: &ParseTreeType<'t>,
+        _string: &ParseTreeType<'t>,
     ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let percent_auto_underscore_ws_underscore_off = percent_auto_underscore_ws_underscore_off
+        let percent_display = percent_display
             .token()?
             .try_into()
             .map_err(parol_runtime::ParolError::UserError)?;
-        let scanner_directives_3_built = ScannerDirectivesPercentAutoUnderscoreWsUnderscoreOff {
-            percent_auto_underscore_ws_underscore_off,
+        let string = pop_item!(self, string, String, context);
+        let identifier = pop_item!(self, identifier, Identifier, context);
+        let declaration_8_built = DeclarationPercentDisplayIdentifierString {
+            percent_display,
+            identifier,
+            string,
         };
-        let scanner_directives_3_built =
-            ScannerDirectives::PercentAutoUnderscoreWsUnderscoreOff(scanner_directives_3_built);
+        let declaration_8_built = Declaration::PercentDisplayIdentifierString(declaration_8_built);
         // Calling user action here
-        self.user_grammar
-            .scanner_directives(&scanner_directives_3_built)?;
-        self.push(
-            ASTType::ScannerDirectives(scanner_directives_3_built),
-            context,
-        );
+        self.user_grammar.declaration(&declaration_8_built)?;
+        self.push(ASTType::Declaration(declaration_8_built), context);
         Ok(())
     }
 
     /// Semantic action for production 16:
     ///
-    /// `ScannerDirectives: '%on' : OwnedToken IdentifierList '%enter' : OwnedToken Identifier;`
+    /// `Declaration: ExpectDirective Number;`
     ///
     #[parol_runtime::function_name::named]
-    fn scanner_directives_4(
+    fn declaration_9(
         &mut self,
-        percent_on: &ParseTreeType<'t>,
-        _identifier_list: &ParseTreeType<'t>,
-        percent_enter: &ParseTreeType<'t>,
-        _identifier: &ParseTreeType<'t>,
+        _expect_directive: &ParseTreeType<'t>,
+        _number: &ParseTreeType<'t>,
     ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let percent_on = percent_on
-            .token()?
-            .try_into()
-            .map_err(parol_runtime::ParolError::UserError)?;
-        let percent_enter = percent_enter
-            .token()?
-            .try_into()
-            .map_err(parol_runtime::ParolError::UserError)?;
-        let identifier = pop_item!(self, identifier, Identifier, context);
-        let identifier_list = pop_item!(self, identifier_list, IdentifierList, context);
-        let scanner_directives_4_built =
-            ScannerDirectivesPercentOnIdentifierListPercentEnterIdentifier {
-                percent_on,
-                identifier_list,
-                percent_enter,
-                identifier,
-            };
-        let scanner_directives_4_built =
-            ScannerDirectives::PercentOnIdentifierListPercentEnterIdentifier(
-                scanner_directives_4_built,
-            );
+        let number = pop_item!(self, number, Number, context);
+        let expect_directive = pop_item!(self, expect_directive, ExpectDirective, context);
+        let declaration_9_built = DeclarationExpectDirectiveNumber {
+            expect_directive,
+            number,
+        };
+        let declaration_9_built = Declaration::ExpectDirectiveNumber(declaration_9_built);
         // Calling user action here
-        self.user_grammar
-            .scanner_directives(&scanner_directives_4_built)?;
-        self.push(
-            ASTType::ScannerDirectives(scanner_directives_4_built),
-            context,
-        );
+        self.user_grammar.declaration(&declaration_9_built)?;
+        self.push(ASTType::Declaration(declaration_9_built), context);
         Ok(())
     }
 
     /// Semantic action for production 17:
     ///
-    /// `GrammarDefinition: "%%" : OwnedToken Production GrammarDefinitionList /* Vec */;`
+    /// `Declaration: PrecedenceDeclaration;`
     ///
     #[parol_runtime::function_name::named]
-    fn grammar_definition(
-        &mut self,
-        percent_percent: &ParseTreeType<'t>,
-        _production: &ParseTreeType<'t>,
-        _grammar_definition_list: &ParseTreeType<'t>,
-    ) -> Result<()> {
+    fn declaration_10(&mut self, _precedence_declaration: &ParseTreeType<'t>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let percent_percent = percent_percent
-            .token()?
-            .try_into()
-            .map_err(parol_runtime::ParolError::UserError)?;
-        let grammar_definition_list = pop_and_reverse_item!(
-            self,
-            grammar_definition_list,
-            GrammarDefinitionList,
-            context
-        );
-        let production = pop_item!(self, production, Production, context);
-        let grammar_definition_built = GrammarDefinition {
-            percent_percent,
-            production,
-            grammar_definition_list,
+        let precedence_declaration =
+            pop_item!(self, precedence_declaration, PrecedenceDeclaration, context);
+        let declaration_10_built = DeclarationPrecedenceDeclaration {
+            precedence_declaration,
         };
+        let declaration_10_built = Declaration::PrecedenceDeclaration(declaration_10_built);
         // Calling user action here
-        self.user_grammar
-            .grammar_definition(&grammar_definition_built)?;
-        self.push(
-            ASTType::GrammarDefinition(grammar_definition_built),
-            context,
-        );
+        self.user_grammar.declaration(&declaration_10_built)?;
+        self.push(ASTType::Declaration(declaration_10_built), context);
         Ok(())
     }
 
     /// Semantic action for production 18:
     ///
-    /// `GrammarDefinitionList /* Vec<T>::Push */: Production GrammarDefinitionList;`
+    /// `Declaration: ScannerDirectives;`
     ///
     #[parol_runtime::function_name::named]
-    fn grammar_definition_list_0(
-        &mut self,
-        _production: &ParseTreeType<'t>,
-        _grammar_definition_list: &ParseTreeType<'t>,
-    ) -> Result<()> {
+    fn declaration_11(&mut self, _scanner_directives: &ParseTreeType<'t>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let mut grammar_definition_list = pop_item!(
-            self,
-            grammar_definition_list,
-            GrammarDefinitionList,
-            context
-        );
-        let production = pop_item!(self, production, Production, context);
-        let grammar_definition_list_0_built = GrammarDefinitionList { production };
-        // Add an element to the vector
-        grammar_definition_list.push(grammar_definition_list_0_built);
-        self.push(
-            ASTType::GrammarDefinitionList(grammar_definition_list),
-            context,
-        );
+        let scanner_directives = pop_item!(self, scanner_directives, ScannerDirectives, context);
+        let declaration_11_built = DeclarationScannerDirectives { scanner_directives };
+        let declaration_11_built = Declaration::ScannerDirectives(declaration_11_built);
+        // Calling user action here
+        self.user_grammar.declaration(&declaration_11_built)?;
+        self.push(ASTType::Declaration(declaration_11_built), context);
         Ok(())
     }
 
     /// Semantic action for production 19:
     ///
-    /// `GrammarDefinitionList /* Vec<T>::New */: ;`
+    /// `DeclarationOpt /* Option<T>::Some */: DeriveTarget;`
     ///
     #[parol_runtime::function_name::named]
-    fn grammar_definition_list_1(&mut self) -> Result<()> {
+    fn declaration_opt_0(&mut self, _derive_target: &ParseTreeType<'t>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let grammar_definition_list_1_built = Vec::new();
+        let derive_target = pop_item!(self, derive_target, DeriveTarget, context);
+        let declaration_opt_0_built = DeclarationOpt { derive_target };
         self.push(
-            ASTType::GrammarDefinitionList(grammar_definition_list_1_built),
+            ASTType::DeclarationOpt(Some(declaration_opt_0_built)),
             context,
         );
         Ok(())
@@ -1676,342 +2279,1305 @@ impl<'t, 'u> ParolLsGrammarAuto<'t, 'u> {
 
     /// Semantic action for production 20:
     ///
-    /// `DoubleColon: "::" : OwnedToken;`
+    /// `DeclarationOpt /* Option<T>::None */: ;`
     ///
     #[parol_runtime::function_name::named]
-    fn double_colon(&mut self, double_colon: &ParseTreeType<'t>) -> Result<()> {
+    fn declaration_opt_1(&mut self) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let double_colon = double_colon
-            .token()?
-            .try_into()
-            .map_err(parol_runtime::ParolError::UserError)?;
-        let double_colon_built = DoubleColon { double_colon };
-        // Calling user action here
-        self.user_grammar.double_colon(&double_colon_built)?;
-        self.push(ASTType::DoubleColon(double_colon_built), context);
+        self.push(ASTType::DeclarationOpt(None), context);
         Ok(())
     }
 
     /// Semantic action for production 21:
     ///
-    /// `ProductionLHS: Identifier ":" : OwnedToken;`
+    /// `ExpectDirective: /%expect(-rr)?/ : OwnedToken;`
     ///
     #[parol_runtime::function_name::named]
-    fn production_l_h_s(
-        &mut self,
-        _identifier: &ParseTreeType<'t>,
-        colon: &ParseTreeType<'t>,
-    ) -> Result<()> {
+    fn expect_directive(&mut self, expect_directive: &ParseTreeType<'t>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let colon = colon
+        let expect_directive = expect_directive
             .token()?
             .try_into()
             .map_err(parol_runtime::ParolError::UserError)?;
-        let identifier = pop_item!(self, identifier, Identifier, context);
-        let production_l_h_s_built = ProductionLHS { identifier, colon };
+        let expect_directive_built = ExpectDirective { expect_directive };
         // Calling user action here
         self.user_grammar
-            .production_l_h_s(&production_l_h_s_built)?;
-        self.push(ASTType::ProductionLHS(production_l_h_s_built), context);
+            .expect_directive(&expect_directive_built)?;
+        self.push(ASTType::ExpectDirective(expect_directive_built), context);
         Ok(())
     }
 
     /// Semantic action for production 22:
     ///
-    /// `Production: ProductionLHS Alternations ";" : OwnedToken;`
+    /// `PrecedenceDeclaration: Associativity PrecedenceSymbol PrecedenceDeclarationList /* Vec */;`
     ///
     #[parol_runtime::function_name::named]
-    fn production(
+    fn precedence_declaration(
         &mut self,
-        _production_l_h_s: &ParseTreeType<'t>,
-        _alternations: &ParseTreeType<'t>,
-        semicolon: &ParseTreeType<'t>,
+        _associativity: &ParseTreeType<'t>,
+        _precedence_symbol: &ParseTreeType<'t>,
+        _precedence_declaration_list: &ParseTreeType<'t>,
     ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let semicolon = semicolon
-            .token()?
-            .try_into()
-            .map_err(parol_runtime::ParolError::UserError)?;
-        let alternations = pop_item!(self, alternations, Alternations, context);
-        let production_l_h_s = pop_item!(self, production_l_h_s, ProductionLHS, context);
-        let production_built = Production {
-            production_l_h_s,
-            alternations,
-            semicolon,
+        let precedence_declaration_list = pop_and_reverse_item!(
+            self,
+            precedence_declaration_list,
+            PrecedenceDeclarationList,
+            context
+        );
+        let precedence_symbol = pop_item!(self, precedence_symbol, PrecedenceSymbol, context);
+        let associativity = pop_item!(self, associativity, Associativity, context);
+        let precedence_declaration_built = PrecedenceDeclaration {
+            associativity,
+            precedence_symbol,
+            precedence_declaration_list,
         };
         // Calling user action here
-        self.user_grammar.production(&production_built)?;
-        self.push(ASTType::Production(production_built), context);
+        self.user_grammar
+            .precedence_declaration(&precedence_declaration_built)?;
+        self.push(
+            ASTType::PrecedenceDeclaration(precedence_declaration_built),
+            context,
+        );
         Ok(())
     }
 
     /// Semantic action for production 23:
     ///
-    /// `Alternations: Alternation AlternationsList /* Vec */;`
+    /// `PrecedenceDeclarationList /* Vec<T>::Push */: PrecedenceSymbol PrecedenceDeclarationList;`
     ///
     #[parol_runtime::function_name::named]
-    fn alternations(
+    fn precedence_declaration_list_0(
         &mut self,
-        _alternation: &ParseTreeType<'t>,
-        _alternations_list: &ParseTreeType<'t>,
+        _precedence_symbol: &ParseTreeType<'t>,
+        _precedence_declaration_list: &ParseTreeType<'t>,
     ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let alternations_list =
-            pop_and_reverse_item!(self, alternations_list, AlternationsList, context);
-        let alternation = pop_item!(self, alternation, Alternation, context);
-        let alternations_built = Alternations {
-            alternation,
-            alternations_list,
-        };
-        // Calling user action here
-        self.user_grammar.alternations(&alternations_built)?;
-        self.push(ASTType::Alternations(alternations_built), context);
+        let mut precedence_declaration_list = pop_item!(
+            self,
+            precedence_declaration_list,
+            PrecedenceDeclarationList,
+            context
+        );
+        let precedence_symbol = pop_item!(self, precedence_symbol, PrecedenceSymbol, context);
+        let precedence_declaration_list_0_built = PrecedenceDeclarationList { precedence_symbol };
+        // Add an element to the vector
+        precedence_declaration_list.push(precedence_declaration_list_0_built);
+        self.push(
+            ASTType::PrecedenceDeclarationList(precedence_declaration_list),
+            context,
+        );
         Ok(())
     }
 
     /// Semantic action for production 24:
     ///
-    /// `AlternationsList /* Vec<T>::Push */: '|' : OwnedToken Alternation AlternationsList;`
+    /// `PrecedenceDeclarationList /* Vec<T>::New */: ;`
     ///
     #[parol_runtime::function_name::named]
-    fn alternations_list_0(
-        &mut self,
-        or: &ParseTreeType<'t>,
-        _alternation: &ParseTreeType<'t>,
-        _alternations_list: &ParseTreeType<'t>,
-    ) -> Result<()> {
+    fn precedence_declaration_list_1(&mut self) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let or = or
-            .token()?
-            .try_into()
-            .map_err(parol_runtime::ParolError::UserError)?;
-        let mut alternations_list = pop_item!(self, alternations_list, AlternationsList, context);
-        let alternation = pop_item!(self, alternation, Alternation, context);
-        let alternations_list_0_built = AlternationsList { alternation, or };
-        // Add an element to the vector
-        alternations_list.push(alternations_list_0_built);
-        self.push(ASTType::AlternationsList(alternations_list), context);
+        let precedence_declaration_list_1_built = Vec::new();
+        self.push(
+            ASTType::PrecedenceDeclarationList(precedence_declaration_list_1_built),
+            context,
+        );
         Ok(())
     }
 
     /// Semantic action for production 25:
     ///
-    /// `AlternationsList /* Vec<T>::New */: ;`
+    /// `Associativity: '%left' : OwnedToken;`
     ///
     #[parol_runtime::function_name::named]
-    fn alternations_list_1(&mut self) -> Result<()> {
+    fn associativity_0(&mut self, percent_left: &ParseTreeType<'t>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let alternations_list_1_built = Vec::new();
-        self.push(
-            ASTType::AlternationsList(alternations_list_1_built),
-            context,
-        );
+        let percent_left = percent_left
+            .token()?
+            .try_into()
+            .map_err(parol_runtime::ParolError::UserError)?;
+        let associativity_0_built = AssociativityPercentLeft { percent_left };
+        let associativity_0_built = Associativity::PercentLeft(associativity_0_built);
+        // Calling user action here
+        self.user_grammar.associativity(&associativity_0_built)?;
+        self.push(ASTType::Associativity(associativity_0_built), context);
         Ok(())
     }
 
     /// Semantic action for production 26:
     ///
-    /// `Alternation: AlternationList /* Vec */;`
+    /// `Associativity: '%right' : OwnedToken;`
     ///
     #[parol_runtime::function_name::named]
-    fn alternation(&mut self, _alternation_list: &ParseTreeType<'t>) -> Result<()> {
+    fn associativity_1(&mut self, percent_right: &ParseTreeType<'t>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let alternation_list =
-            pop_and_reverse_item!(self, alternation_list, AlternationList, context);
-        let alternation_built = Alternation { alternation_list };
+        let percent_right = percent_right
+            .token()?
+            .try_into()
+            .map_err(parol_runtime::ParolError::UserError)?;
+        let associativity_1_built = AssociativityPercentRight { percent_right };
+        let associativity_1_built = Associativity::PercentRight(associativity_1_built);
         // Calling user action here
-        self.user_grammar.alternation(&alternation_built)?;
-        self.push(ASTType::Alternation(alternation_built), context);
+        self.user_grammar.associativity(&associativity_1_built)?;
+        self.push(ASTType::Associativity(associativity_1_built), context);
         Ok(())
     }
 
     /// Semantic action for production 27:
     ///
-    /// `AlternationList /* Vec<T>::Push */: Factor AlternationList;`
+    /// `Associativity: '%nonassoc' : OwnedToken;`
     ///
     #[parol_runtime::function_name::named]
-    fn alternation_list_0(
-        &mut self,
-        _factor: &ParseTreeType<'t>,
-        _alternation_list: &ParseTreeType<'t>,
-    ) -> Result<()> {
+    fn associativity_2(&mut self, percent_nonassoc: &ParseTreeType<'t>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let mut alternation_list = pop_item!(self, alternation_list, AlternationList, context);
-        let factor = pop_item!(self, factor, Factor, context);
-        let alternation_list_0_built = AlternationList { factor };
-        // Add an element to the vector
-        alternation_list.push(alternation_list_0_built);
-        self.push(ASTType::AlternationList(alternation_list), context);
+        let percent_nonassoc = percent_nonassoc
+            .token()?
+            .try_into()
+            .map_err(parol_runtime::ParolError::UserError)?;
+        let associativity_2_built = AssociativityPercentNonassoc { percent_nonassoc };
+        let associativity_2_built = Associativity::PercentNonassoc(associativity_2_built);
+        // Calling user action here
+        self.user_grammar.associativity(&associativity_2_built)?;
+        self.push(ASTType::Associativity(associativity_2_built), context);
         Ok(())
     }
 
     /// Semantic action for production 28:
     ///
-    /// `AlternationList /* Vec<T>::New */: ;`
+    /// `PrecedenceSymbol: TokenLiteral;`
     ///
     #[parol_runtime::function_name::named]
-    fn alternation_list_1(&mut self) -> Result<()> {
+    fn precedence_symbol_0(&mut self, _token_literal: &ParseTreeType<'t>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let alternation_list_1_built = Vec::new();
-        self.push(ASTType::AlternationList(alternation_list_1_built), context);
+        let token_literal = pop_item!(self, token_literal, TokenLiteral, context);
+        let precedence_symbol_0_built = PrecedenceSymbolTokenLiteral { token_literal };
+        let precedence_symbol_0_built = PrecedenceSymbol::TokenLiteral(precedence_symbol_0_built);
+        // Calling user action here
+        self.user_grammar
+            .precedence_symbol(&precedence_symbol_0_built)?;
+        self.push(
+            ASTType::PrecedenceSymbol(precedence_symbol_0_built),
+            context,
+        );
         Ok(())
     }
 
     /// Semantic action for production 29:
     ///
-    /// `Factor: Group;`
+    /// `PrecedenceSymbol: Identifier;`
     ///
     #[parol_runtime::function_name::named]
-    fn factor_0(&mut self, _group: &ParseTreeType<'t>) -> Result<()> {
+    fn precedence_symbol_1(&mut self, _identifier: &ParseTreeType<'t>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let group = pop_item!(self, group, Group, context);
-        let factor_0_built = FactorGroup { group };
-        let factor_0_built = Factor::Group(factor_0_built);
+        let identifier = pop_item!(self, identifier, Identifier, context);
+        let precedence_symbol_1_built = PrecedenceSymbolIdentifier { identifier };
+        let precedence_symbol_1_built = PrecedenceSymbol::Identifier(precedence_symbol_1_built);
         // Calling user action here
-        self.user_grammar.factor(&factor_0_built)?;
-        self.push(ASTType::Factor(factor_0_built), context);
+        self.user_grammar
+            .precedence_symbol(&precedence_symbol_1_built)?;
+        self.push(
+            ASTType::PrecedenceSymbol(precedence_symbol_1_built),
+            context,
+        );
         Ok(())
     }
 
     /// Semantic action for production 30:
     ///
-    /// `Factor: Repeat;`
+    /// `ScannerDirectives: "%line_comment" : OwnedToken TokenLiteral;`
     ///
     #[parol_runtime::function_name::named]
-    fn factor_1(&mut self, _repeat: &ParseTreeType<'t>) -> Result<()> {
+    fn scanner_directives_0(
+        &mut self,
+        percent_line_underscore_comment: &ParseTreeType<'t>,
+        _token_literal: &ParseTreeType<'t>,
+    ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let repeat = pop_item!(self, repeat, Repeat, context);
-        let factor_1_built = FactorRepeat { repeat };
-        let factor_1_built = Factor::Repeat(factor_1_built);
+        let percent_line_underscore_comment = percent_line_underscore_comment
+            .token()?
+            .try_into()
+            .map_err(parol_runtime::ParolError::UserError)?;
+        let token_literal = pop_item!(self, token_literal, TokenLiteral, context);
+        let scanner_directives_0_built =
+            ScannerDirectivesPercentLineUnderscoreCommentTokenLiteral {
+                percent_line_underscore_comment,
+                token_literal,
+            };
+        let scanner_directives_0_built =
+            ScannerDirectives::PercentLineUnderscoreCommentTokenLiteral(scanner_directives_0_built);
         // Calling user action here
-        self.user_grammar.factor(&factor_1_built)?;
-        self.push(ASTType::Factor(factor_1_built), context);
+        self.user_grammar
+            .scanner_directives(&scanner_directives_0_built)?;
+        self.push(
+            ASTType::ScannerDirectives(scanner_directives_0_built),
+            context,
+        );
         Ok(())
     }
 
     /// Semantic action for production 31:
     ///
-    /// `Factor: Optional;`
+    /// `ScannerDirectives: "%block_comment" : OwnedToken TokenLiteral TokenLiteral;`
     ///
     #[parol_runtime::function_name::named]
-    fn factor_2(&mut self, _optional: &ParseTreeType<'t>) -> Result<()> {
+    fn scanner_directives_1(
+        &mut self,
+        percent_block_underscore_comment: &ParseTreeType<'t>,
+        _token_literal: &ParseTreeType<'t>,
+        _token_literal0: &ParseTreeType<'t>,
+    ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let optional = pop_item!(self, optional, Optional, context);
-        let factor_2_built = FactorOptional { optional };
-        let factor_2_built = Factor::Optional(factor_2_built);
+        let percent_block_underscore_comment = percent_block_underscore_comment
+            .token()?
+            .try_into()
+            .map_err(parol_runtime::ParolError::UserError)?;
+        let token_literal0 = pop_item!(self, token_literal0, TokenLiteral, context);
+        let token_literal = pop_item!(self, token_literal, TokenLiteral, context);
+        let scanner_directives_1_built =
+            ScannerDirectivesPercentBlockUnderscoreCommentTokenLiteralTokenLiteral {
+                percent_block_underscore_comment,
+                token_literal,
+                token_literal0,
+            };
+        let scanner_directives_1_built =
+            ScannerDirectives::PercentBlockUnderscoreCommentTokenLiteralTokenLiteral(
+                scanner_directives_1_built,
+            );
         // Calling user action here
-        self.user_grammar.factor(&factor_2_built)?;
-        self.push(ASTType::Factor(factor_2_built), context);
+        self.user_grammar
+            .scanner_directives(&scanner_directives_1_built)?;
+        self.push(
+            ASTType::ScannerDirectives(scanner_directives_1_built),
+            context,
+        );
         Ok(())
     }
 
     /// Semantic action for production 32:
     ///
-    /// `Factor: Symbol;`
+    /// `ScannerDirectives: "%nested_block_comment" : OwnedToken TokenLiteral TokenLiteral;`
     ///
     #[parol_runtime::function_name::named]
-    fn factor_3(&mut self, _symbol: &ParseTreeType<'t>) -> Result<()> {
+    fn scanner_directives_2(
+        &mut self,
+        percent_nested_underscore_block_underscore_comment: &ParseTreeType<'t>,
+        _token_literal: &ParseTreeType<'t>,
+        _token_literal0: &ParseTreeType<'t>,
+    ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let symbol = pop_item!(self, symbol, Symbol, context);
-        let factor_3_built = FactorSymbol { symbol };
-        let factor_3_built = Factor::Symbol(factor_3_built);
+        let percent_nested_underscore_block_underscore_comment =
+            percent_nested_underscore_block_underscore_comment
+                .token()?
+                .try_into()
+                .map_err(parol_runtime::ParolError::UserError)?;
+        let token_literal0 = pop_item!(self, token_literal0, TokenLiteral, context);
+        let token_literal = pop_item!(self, token_literal, TokenLiteral, context);
+        let scanner_directives_2_built = ScannerDirectivesPercentNestedUnderscoreBlockUnderscoreCommentTokenLiteralTokenLiteral {
+            percent_nested_underscore_block_underscore_comment,
+            token_literal,
+            token_literal0,
+        };
+        let scanner_directives_2_built = ScannerDirectives::PercentNestedUnderscoreBlockUnderscoreCommentTokenLiteralTokenLiteral(scanner_directives_2_built);
         // Calling user action here
-        self.user_grammar.factor(&factor_3_built)?;
-        self.push(ASTType::Factor(factor_3_built), context);
+        self.user_grammar
+            .scanner_directives(&scanner_directives_2_built)?;
+        self.push(
+            ASTType::ScannerDirectives(scanner_directives_2_built),
+            context,
+        );
         Ok(())
     }
 
     /// Semantic action for production 33:
     ///
-    /// `Symbol: NonTerminal;`
+    /// `ScannerDirectives: "%auto_newline_off" : OwnedToken;`
     ///
     #[parol_runtime::function_name::named]
-    fn symbol_0(&mut self, _non_terminal: &ParseTreeType<'t>) -> Result<()> {
+    fn scanner_directives_3(
+        &mut self,
+        percent_auto_underscore_newline_underscore_off: &ParseTreeType<'t>,
+    ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let non_terminal = pop_item!(self, non_terminal, NonTerminal, context);
-        let symbol_0_built = SymbolNonTerminal { non_terminal };
-        let symbol_0_built = Symbol::NonTerminal(symbol_0_built);
+        let percent_auto_underscore_newline_underscore_off =
+            percent_auto_underscore_newline_underscore_off
+                .token()?
+                .try_into()
+                .map_err(parol_runtime::ParolError::UserError)?;
+        let scanner_directives_3_built =
+            ScannerDirectivesPercentAutoUnderscoreNewlineUnderscoreOff {
+                percent_auto_underscore_newline_underscore_off,
+            };
+        let scanner_directives_3_built =
+            ScannerDirectives::PercentAutoUnderscoreNewlineUnderscoreOff(
+                scanner_directives_3_built,
+            );
         // Calling user action here
-        self.user_grammar.symbol(&symbol_0_built)?;
-        self.push(ASTType::Symbol(symbol_0_built), context);
+        self.user_grammar
+            .scanner_directives(&scanner_directives_3_built)?;
+        self.push(
+            ASTType::ScannerDirectives(scanner_directives_3_built),
+            context,
+        );
         Ok(())
     }
 
     /// Semantic action for production 34:
     ///
-    /// `Symbol: SimpleToken;`
+    /// `ScannerDirectives: "%auto_ws_off" : OwnedToken;`
     ///
     #[parol_runtime::function_name::named]
-    fn symbol_1(&mut self, _simple_token: &ParseTreeType<'t>) -> Result<()> {
+    fn scanner_directives_4(
+        &mut self,
+        percent_auto_underscore_ws_underscore_off: &ParseTreeType<'t>,
+    ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let simple_token = pop_item!(self, simple_token, SimpleToken, context);
-        let symbol_1_built = SymbolSimpleToken { simple_token };
-        let symbol_1_built = Symbol::SimpleToken(symbol_1_built);
+        let percent_auto_underscore_ws_underscore_off = percent_auto_underscore_ws_underscore_off
+            .token()?
+            .try_into()
+            .map_err(parol_runtime::ParolError::UserError)?;
+        let scanner_directives_4_built = ScannerDirectivesPercentAutoUnderscoreWsUnderscoreOff {
+            percent_auto_underscore_ws_underscore_off,
+        };
+        let scanner_directives_4_built =
+            ScannerDirectives::PercentAutoUnderscoreWsUnderscoreOff(scanner_directives_4_built);
         // Calling user action here
-        self.user_grammar.symbol(&symbol_1_built)?;
-        self.push(ASTType::Symbol(symbol_1_built), context);
+        self.user_grammar
+            .scanner_directives(&scanner_directives_4_built)?;
+        self.push(
+            ASTType::ScannerDirectives(scanner_directives_4_built),
+            context,
+        );
         Ok(())
     }
 
     /// Semantic action for production 35:
     ///
-    /// `Symbol: TokenWithStates;`
+    /// `ScannerDirectives: "%longest_match" : OwnedToken;`
     ///
     #[parol_runtime::function_name::named]
-    fn symbol_2(&mut self, _token_with_states: &ParseTreeType<'t>) -> Result<()> {
+    fn scanner_directives_5(
+        &mut self,
+        percent_longest_underscore_match: &ParseTreeType<'t>,
+    ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let token_with_states = pop_item!(self, token_with_states, TokenWithStates, context);
-        let symbol_2_built = SymbolTokenWithStates { token_with_states };
-        let symbol_2_built = Symbol::TokenWithStates(symbol_2_built);
+        let percent_longest_underscore_match = percent_longest_underscore_match
+            .token()?
+            .try_into()
+            .map_err(parol_runtime::ParolError::UserError)?;
+        let scanner_directives_5_built = ScannerDirectivesPercentLongestUnderscoreMatch {
+            percent_longest_underscore_match,
+        };
+        let scanner_directives_5_built =
+            ScannerDirectives::PercentLongestUnderscoreMatch(scanner_directives_5_built);
         // Calling user action here
-        self.user_grammar.symbol(&symbol_2_built)?;
-        self.push(ASTType::Symbol(symbol_2_built), context);
+        self.user_grammar
+            .scanner_directives(&scanner_directives_5_built)?;
+        self.push(
+            ASTType::ScannerDirectives(scanner_directives_5_built),
+            context,
+        );
         Ok(())
     }
 
     /// Semantic action for production 36:
     ///
-    /// `Symbol: ScannerSwitch;`
+    /// `ScannerDirectives: "%case_insensitive" : OwnedToken;`
     ///
     #[parol_runtime::function_name::named]
-    fn symbol_3(&mut self, _scanner_switch: &ParseTreeType<'t>) -> Result<()> {
+    fn scanner_directives_6(
+        &mut self,
+        percent_case_underscore_insensitive: &ParseTreeType<'t>,
+    ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let scanner_switch = pop_item!(self, scanner_switch, ScannerSwitch, context);
-        let symbol_3_built = SymbolScannerSwitch { scanner_switch };
-        let symbol_3_built = Symbol::ScannerSwitch(symbol_3_built);
+        let percent_case_underscore_insensitive = percent_case_underscore_insensitive
+            .token()?
+            .try_into()
+            .map_err(parol_runtime::ParolError::UserError)?;
+        let scanner_directives_6_built = ScannerDirectivesPercentCaseUnderscoreInsensitive {
+            percent_case_underscore_insensitive,
+        };
+        let scanner_directives_6_built =
+            ScannerDirectives::PercentCaseUnderscoreInsensitive(scanner_directives_6_built);
         // Calling user action here
-        self.user_grammar.symbol(&symbol_3_built)?;
-        self.push(ASTType::Symbol(symbol_3_built), context);
+        self.user_grammar
+            .scanner_directives(&scanner_directives_6_built)?;
+        self.push(
+            ASTType::ScannerDirectives(scanner_directives_6_built),
+            context,
+        );
         Ok(())
     }
 
     /// Semantic action for production 37:
     ///
-    /// `TokenLiteral: String;`
+    /// `ScannerDirectives: "%offside" : OwnedToken Identifier Identifier Identifier;`
     ///
     #[parol_runtime::function_name::named]
-    fn token_literal_0(&mut self, _string: &ParseTreeType<'t>) -> Result<()> {
+    fn scanner_directives_7(
+        &mut self,
+        percent_offside: &ParseTreeType<'t>,
+        _identifier: &ParseTreeType<'t>,
+        _identifier0: &ParseTreeType<'t>,
+        _identifier1: &ParseTreeType<'t>,
+    ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let string = pop_item!(self, string, String, context);
-        let token_literal_0_built = TokenLiteralString { string };
+        let percent_offside = percent_offside
+            .token()?
+            .try_into()
+            .map_err(parol_runtime::ParolError::UserError)?;
+        let identifier1 = pop_item!(self, identifier1, Identifier, context);
+        let identifier0 = pop_item!(self, identifier0, Identifier, context);
+        let identifier = pop_item!(self, identifier, Identifier, context);
+        let scanner_directives_7_built =
+            ScannerDirectivesPercentOffsideIdentifierIdentifierIdentifier {
+                percent_offside,
+                identifier,
+                identifier0,
+                identifier1,
+            };
+        let scanner_directives_7_built =
+            ScannerDirectives::PercentOffsideIdentifierIdentifierIdentifier(
+                scanner_directives_7_built,
+            );
+        // Calling user action here
+        self.user_grammar
+            .scanner_directives(&scanner_directives_7_built)?;
+        self.push(
+            ASTType::ScannerDirectives(scanner_directives_7_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 38:
+    ///
+    /// `ScannerDirectives: '%on' : OwnedToken IdentifierList '%enter' : OwnedToken Identifier;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn scanner_directives_8(
+        &mut self,
+        percent_on: &ParseTreeType<'t>,
+        _identifier_list: &ParseTreeType<'t>,
+        percent_enter: &ParseTreeType<'t>,
+        _identifier: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let percent_on = percent_on
+            .token()?
+            .try_into()
+            .map_err(parol_runtime::ParolError::UserError)?;
+        let percent_enter = percent_enter
+            .token()?
+            .try_into()
+            .map_err(parol_runtime::ParolError::UserError)?;
+        let identifier = pop_item!(self, identifier, Identifier, context);
+        let identifier_list = pop_item!(self, identifier_list, IdentifierList, context);
+        let scanner_directives_8_built =
+            ScannerDirectivesPercentOnIdentifierListPercentEnterIdentifier {
+                percent_on,
+                identifier_list,
+                percent_enter,
+                identifier,
+            };
+        let scanner_directives_8_built =
+            ScannerDirectives::PercentOnIdentifierListPercentEnterIdentifier(
+                scanner_directives_8_built,
+            );
+        // Calling user action here
+        self.user_grammar
+            .scanner_directives(&scanner_directives_8_built)?;
+        self.push(
+            ASTType::ScannerDirectives(scanner_directives_8_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 39:
+    ///
+    /// `GrammarDefinition: "%%" : OwnedToken Production GrammarDefinitionList /* Vec */;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn grammar_definition(
+        &mut self,
+        percent_percent: &ParseTreeType<'t>,
+        _production: &ParseTreeType<'t>,
+        _grammar_definition_list: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let percent_percent = percent_percent
+            .token()?
+            .try_into()
+            .map_err(parol_runtime::ParolError::UserError)?;
+        let grammar_definition_list = pop_and_reverse_item!(
+            self,
+            grammar_definition_list,
+            GrammarDefinitionList,
+            context
+        );
+        let production = pop_item!(self, production, Production, context);
+        let grammar_definition_built = GrammarDefinition {
+            percent_percent,
+            production,
+            grammar_definition_list,
+        };
+        // Calling user action here
+        self.user_grammar
+            .grammar_definition(&grammar_definition_built)?;
+        self.push(
+            ASTType::GrammarDefinition(grammar_definition_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 40:
+    ///
+    /// `GrammarDefinitionList /* Vec<T>::Push */: Production GrammarDefinitionList;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn grammar_definition_list_0(
+        &mut self,
+        _production: &ParseTreeType<'t>,
+        _grammar_definition_list: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let mut grammar_definition_list = pop_item!(
+            self,
+            grammar_definition_list,
+            GrammarDefinitionList,
+            context
+        );
+        let production = pop_item!(self, production, Production, context);
+        let grammar_definition_list_0_built = GrammarDefinitionList { production };
+        // Add an element to the vector
+        grammar_definition_list.push(grammar_definition_list_0_built);
+        self.push(
+            ASTType::GrammarDefinitionList(grammar_definition_list),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 41:
+    ///
+    /// `GrammarDefinitionList /* Vec<T>::New */: ;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn grammar_definition_list_1(&mut self) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let grammar_definition_list_1_built = Vec::new();
+        self.push(
+            ASTType::GrammarDefinitionList(grammar_definition_list_1_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 42:
+    ///
+    /// `DoubleColon: "::" : OwnedToken;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn double_colon(&mut self, double_colon: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let double_colon = double_colon
+            .token()?
+            .try_into()
+            .map_err(parol_runtime::ParolError::UserError)?;
+        let double_colon_built = DoubleColon { double_colon };
+        // Calling user action here
+        self.user_grammar.double_colon(&double_colon_built)?;
+        self.push(ASTType::DoubleColon(double_colon_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 43:
+    ///
+    /// `DeriveTarget: Identifier ":" : OwnedToken;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn derive_target(
+        &mut self,
+        _identifier: &ParseTreeType<'t>,
+        colon: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let colon = colon
+            .token()?
+            .try_into()
+            .map_err(parol_runtime::ParolError::UserError)?;
+        let identifier = pop_item!(self, identifier, Identifier, context);
+        let derive_target_built = DeriveTarget { identifier, colon };
+        // Calling user action here
+        self.user_grammar.derive_target(&derive_target_built)?;
+        self.push(ASTType::DeriveTarget(derive_target_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 44:
+    ///
+    /// `DeriveList: UserTypeName DeriveListList /* Vec */;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn derive_list(
+        &mut self,
+        _user_type_name: &ParseTreeType<'t>,
+        _derive_list_list: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let derive_list_list =
+            pop_and_reverse_item!(self, derive_list_list, DeriveListList, context);
+        let user_type_name = pop_item!(self, user_type_name, UserTypeName, context);
+        let derive_list_built = DeriveList {
+            user_type_name,
+            derive_list_list,
+        };
+        // Calling user action here
+        self.user_grammar.derive_list(&derive_list_built)?;
+        self.push(ASTType::DeriveList(derive_list_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 45:
+    ///
+    /// `DeriveListList /* Vec<T>::Push */: "," : OwnedToken UserTypeName DeriveListList;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn derive_list_list_0(
+        &mut self,
+        comma: &ParseTreeType<'t>,
+        _user_type_name: &ParseTreeType<'t>,
+        _derive_list_list: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let comma = comma
+            .token()?
+            .try_into()
+            .map_err(parol_runtime::ParolError::UserError)?;
+        let mut derive_list_list = pop_item!(self, derive_list_list, DeriveListList, context);
+        let user_type_name = pop_item!(self, user_type_name, UserTypeName, context);
+        let derive_list_list_0_built = DeriveListList {
+            user_type_name,
+            comma,
+        };
+        // Add an element to the vector
+        derive_list_list.push(derive_list_list_0_built);
+        self.push(ASTType::DeriveListList(derive_list_list), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 46:
+    ///
+    /// `DeriveListList /* Vec<T>::New */: ;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn derive_list_list_1(&mut self) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let derive_list_list_1_built = Vec::new();
+        self.push(ASTType::DeriveListList(derive_list_list_1_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 47:
+    ///
+    /// `ProductionLHS: ProductionName ":" : OwnedToken;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn production_l_h_s(
+        &mut self,
+        _production_name: &ParseTreeType<'t>,
+        colon: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let colon = colon
+            .token()?
+            .try_into()
+            .map_err(parol_runtime::ParolError::UserError)?;
+        let production_name = pop_item!(self, production_name, ProductionName, context);
+        let production_l_h_s_built = ProductionLHS {
+            production_name,
+            colon,
+        };
+        // Calling user action here
+        self.user_grammar
+            .production_l_h_s(&production_l_h_s_built)?;
+        self.push(ASTType::ProductionLHS(production_l_h_s_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 48:
+    ///
+    /// `ProductionName: Identifier ProductionNameOpt /* Option */;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn production_name(
+        &mut self,
+        _identifier: &ParseTreeType<'t>,
+        _production_name_opt: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let production_name_opt = pop_item!(self, production_name_opt, ProductionNameOpt, context);
+        let identifier = pop_item!(self, identifier, Identifier, context);
+        let production_name_built = ProductionName {
+            identifier,
+            production_name_opt,
+        };
+        // Calling user action here
+        self.user_grammar.production_name(&production_name_built)?;
+        self.push(ASTType::ProductionName(production_name_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 49:
+    ///
+    /// `ProductionNameOpt /* Option<T>::Some */: TemplateParameters;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn production_name_opt_0(&mut self, _template_parameters: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let template_parameters = pop_item!(self, template_parameters, TemplateParameters, context);
+        let production_name_opt_0_built = ProductionNameOpt {
+            template_parameters,
+        };
+        self.push(
+            ASTType::ProductionNameOpt(Some(production_name_opt_0_built)),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 50:
+    ///
+    /// `ProductionNameOpt /* Option<T>::None */: ;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn production_name_opt_1(&mut self) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        self.push(ASTType::ProductionNameOpt(None), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 51:
+    ///
+    /// `TemplateParameters: "<" : OwnedToken IdentifierList ">" : OwnedToken;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn template_parameters(
+        &mut self,
+        l_t: &ParseTreeType<'t>,
+        _identifier_list: &ParseTreeType<'t>,
+        g_t: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let l_t = l_t
+            .token()?
+            .try_into()
+            .map_err(parol_runtime::ParolError::UserError)?;
+        let g_t = g_t
+            .token()?
+            .try_into()
+            .map_err(parol_runtime::ParolError::UserError)?;
+        let identifier_list = pop_item!(self, identifier_list, IdentifierList, context);
+        let template_parameters_built = TemplateParameters {
+            l_t,
+            identifier_list,
+            g_t,
+        };
+        // Calling user action here
+        self.user_grammar
+            .template_parameters(&template_parameters_built)?;
+        self.push(
+            ASTType::TemplateParameters(template_parameters_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 52:
+    ///
+    /// `Production: ProductionLHS Alternations ";" : OwnedToken;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn production(
+        &mut self,
+        _production_l_h_s: &ParseTreeType<'t>,
+        _alternations: &ParseTreeType<'t>,
+        semicolon: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let semicolon = semicolon
+            .token()?
+            .try_into()
+            .map_err(parol_runtime::ParolError::UserError)?;
+        let alternations = pop_item!(self, alternations, Alternations, context);
+        let production_l_h_s = pop_item!(self, production_l_h_s, ProductionLHS, context);
+        let production_built = Production {
+            production_l_h_s,
+            alternations,
+            semicolon,
+        };
+        // Calling user action here
+        self.user_grammar.production(&production_built)?;
+        self.push(ASTType::Production(production_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 53:
+    ///
+    /// `Alternations: Alternation AlternationsList /* Vec */;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn alternations(
+        &mut self,
+        _alternation: &ParseTreeType<'t>,
+        _alternations_list: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let alternations_list =
+            pop_and_reverse_item!(self, alternations_list, AlternationsList, context);
+        let alternation = pop_item!(self, alternation, Alternation, context);
+        let alternations_built = Alternations {
+            alternation,
+            alternations_list,
+        };
+        // Calling user action here
+        self.user_grammar.alternations(&alternations_built)?;
+        self.push(ASTType::Alternations(alternations_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 54:
+    ///
+    /// `AlternationsList /* Vec<T>::Push */: '|' : OwnedToken Alternation AlternationsList;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn alternations_list_0(
+        &mut self,
+        or: &ParseTreeType<'t>,
+        _alternation: &ParseTreeType<'t>,
+        _alternations_list: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let or = or
+            .token()?
+            .try_into()
+            .map_err(parol_runtime::ParolError::UserError)?;
+        let mut alternations_list = pop_item!(self, alternations_list, AlternationsList, context);
+        let alternation = pop_item!(self, alternation, Alternation, context);
+        let alternations_list_0_built = AlternationsList { alternation, or };
+        // Add an element to the vector
+        alternations_list.push(alternations_list_0_built);
+        self.push(ASTType::AlternationsList(alternations_list), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 55:
+    ///
+    /// `AlternationsList /* Vec<T>::New */: ;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn alternations_list_1(&mut self) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let alternations_list_1_built = Vec::new();
+        self.push(
+            ASTType::AlternationsList(alternations_list_1_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 56:
+    ///
+    /// `Alternation: AlternationList /* Vec */ AlternationOpt /* Option */ AlternationOpt0 /* Option */;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn alternation(
+        &mut self,
+        _alternation_list: &ParseTreeType<'t>,
+        _alternation_opt: &ParseTreeType<'t>,
+        _alternation_opt0: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let alternation_opt0 = pop_item!(self, alternation_opt0, AlternationOpt0, context);
+        let alternation_opt = pop_item!(self, alternation_opt, AlternationOpt, context);
+        let alternation_list =
+            pop_and_reverse_item!(self, alternation_list, AlternationList, context);
+        let alternation_built = Alternation {
+            alternation_list,
+            alternation_opt,
+            alternation_opt0,
+        };
+        // Calling user action here
+        self.user_grammar.alternation(&alternation_built)?;
+        self.push(ASTType::Alternation(alternation_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 57:
+    ///
+    /// `AlternationList /* Vec<T>::Push */: Factor AlternationList;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn alternation_list_0(
+        &mut self,
+        _factor: &ParseTreeType<'t>,
+        _alternation_list: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let mut alternation_list = pop_item!(self, alternation_list, AlternationList, context);
+        let factor = pop_item!(self, factor, Factor, context);
+        let alternation_list_0_built = AlternationList { factor };
+        // Add an element to the vector
+        alternation_list.push(alternation_list_0_built);
+        self.push(ASTType::AlternationList(alternation_list), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 58:
+    ///
+    /// `AlternationList /* Vec<T>::New */: ;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn alternation_list_1(&mut self) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let alternation_list_1_built = Vec::new();
+        self.push(ASTType::AlternationList(alternation_list_1_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 59:
+    ///
+    /// `AlternationOpt0 /* Option<T>::Some */: AlternationLabel;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn alternation_opt0_0(&mut self, _alternation_label: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let alternation_label = pop_item!(self, alternation_label, AlternationLabel, context);
+        let alternation_opt0_0_built = AlternationOpt0 { alternation_label };
+        self.push(
+            ASTType::AlternationOpt0(Some(alternation_opt0_0_built)),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 60:
+    ///
+    /// `AlternationOpt0 /* Option<T>::None */: ;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn alternation_opt0_1(&mut self) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        self.push(ASTType::AlternationOpt0(None), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 61:
+    ///
+    /// `AlternationOpt /* Option<T>::Some */: '%prec' : OwnedToken PrecedenceSymbol;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn alternation_opt_0(
+        &mut self,
+        percent_prec: &ParseTreeType<'t>,
+        _precedence_symbol: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let percent_prec = percent_prec
+            .token()?
+            .try_into()
+            .map_err(parol_runtime::ParolError::UserError)?;
+        let precedence_symbol = pop_item!(self, precedence_symbol, PrecedenceSymbol, context);
+        let alternation_opt_0_built = AlternationOpt {
+            percent_prec,
+            precedence_symbol,
+        };
+        self.push(
+            ASTType::AlternationOpt(Some(alternation_opt_0_built)),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 62:
+    ///
+    /// `AlternationOpt /* Option<T>::None */: ;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn alternation_opt_1(&mut self) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        self.push(ASTType::AlternationOpt(None), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 63:
+    ///
+    /// `AlternationLabel: '#' : OwnedToken Identifier;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn alternation_label(
+        &mut self,
+        hash: &ParseTreeType<'t>,
+        _identifier: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let hash = hash
+            .token()?
+            .try_into()
+            .map_err(parol_runtime::ParolError::UserError)?;
+        let identifier = pop_item!(self, identifier, Identifier, context);
+        let alternation_label_built = AlternationLabel { hash, identifier };
+        // Calling user action here
+        self.user_grammar
+            .alternation_label(&alternation_label_built)?;
+        self.push(ASTType::AlternationLabel(alternation_label_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 64:
+    ///
+    /// `Factor: Group;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn factor_0(&mut self, _group: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let group = pop_item!(self, group, Group, context);
+        let factor_0_built = FactorGroup { group };
+        let factor_0_built = Factor::Group(factor_0_built);
+        // Calling user action here
+        self.user_grammar.factor(&factor_0_built)?;
+        self.push(ASTType::Factor(factor_0_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 65:
+    ///
+    /// `Factor: Repeat;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn factor_1(&mut self, _repeat: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let repeat = pop_item!(self, repeat, Repeat, context);
+        let factor_1_built = FactorRepeat {
+            repeat: Box::new(repeat),
+        };
+        let factor_1_built = Factor::Repeat(factor_1_built);
+        // Calling user action here
+        self.user_grammar.factor(&factor_1_built)?;
+        self.push(ASTType::Factor(factor_1_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 66:
+    ///
+    /// `Factor: Optional;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn factor_2(&mut self, _optional: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let optional = pop_item!(self, optional, Optional, context);
+        let factor_2_built = FactorOptional { optional };
+        let factor_2_built = Factor::Optional(factor_2_built);
+        // Calling user action here
+        self.user_grammar.factor(&factor_2_built)?;
+        self.push(ASTType::Factor(factor_2_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 67:
+    ///
+    /// `Factor: Symbol;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn factor_3(&mut self, _symbol: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let symbol = pop_item!(self, symbol, Symbol, context);
+        let factor_3_built = FactorSymbol {
+            symbol: Box::new(symbol),
+        };
+        let factor_3_built = Factor::Symbol(factor_3_built);
+        // Calling user action here
+        self.user_grammar.factor(&factor_3_built)?;
+        self.push(ASTType::Factor(factor_3_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 68:
+    ///
+    /// `Factor: FieldLabel Symbol;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn factor_4(
+        &mut self,
+        _field_label: &ParseTreeType<'t>,
+        _symbol: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let symbol = pop_item!(self, symbol, Symbol, context);
+        let field_label = pop_item!(self, field_label, FieldLabel, context);
+        let factor_4_built = FactorFieldLabelSymbol {
+            field_label,
+            symbol: Box::new(symbol),
+        };
+        let factor_4_built = Factor::FieldLabelSymbol(factor_4_built);
+        // Calling user action here
+        self.user_grammar.factor(&factor_4_built)?;
+        self.push(ASTType::Factor(factor_4_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 69:
+    ///
+    /// `FieldLabel: Identifier "=" : OwnedToken;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn field_label(
+        &mut self,
+        _identifier: &ParseTreeType<'t>,
+        equ: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let equ = equ
+            .token()?
+            .try_into()
+            .map_err(parol_runtime::ParolError::UserError)?;
+        let identifier = pop_item!(self, identifier, Identifier, context);
+        let field_label_built = FieldLabel { identifier, equ };
+        // Calling user action here
+        self.user_grammar.field_label(&field_label_built)?;
+        self.push(ASTType::FieldLabel(field_label_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 70:
+    ///
+    /// `Symbol: NonTerminal;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn symbol_0(&mut self, _non_terminal: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let non_terminal = pop_item!(self, non_terminal, NonTerminal, context);
+        let symbol_0_built = SymbolNonTerminal { non_terminal };
+        let symbol_0_built = Symbol::NonTerminal(symbol_0_built);
+        // Calling user action here
+        self.user_grammar.symbol(&symbol_0_built)?;
+        self.push(ASTType::Symbol(symbol_0_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 71:
+    ///
+    /// `Symbol: SimpleToken;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn symbol_1(&mut self, _simple_token: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let simple_token = pop_item!(self, simple_token, SimpleToken, context);
+        let symbol_1_built = SymbolSimpleToken { simple_token };
+        let symbol_1_built = Symbol::SimpleToken(symbol_1_built);
+        // Calling user action here
+        self.user_grammar.symbol(&symbol_1_built)?;
+        self.push(ASTType::Symbol(symbol_1_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 72:
+    ///
+    /// `Symbol: AngleArguments;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn symbol_2(&mut self, _angle_arguments: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let angle_arguments = pop_item!(self, angle_arguments, AngleArguments, context);
+        let symbol_2_built = SymbolAngleArguments { angle_arguments };
+        let symbol_2_built = Symbol::AngleArguments(symbol_2_built);
+        // Calling user action here
+        self.user_grammar.symbol(&symbol_2_built)?;
+        self.push(ASTType::Symbol(symbol_2_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 73:
+    ///
+    /// `Symbol: ScannerSwitch;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn symbol_3(&mut self, _scanner_switch: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let scanner_switch = pop_item!(self, scanner_switch, ScannerSwitch, context);
+        let symbol_3_built = SymbolScannerSwitch { scanner_switch };
+        let symbol_3_built = Symbol::ScannerSwitch(symbol_3_built);
+        // Calling user action here
+        self.user_grammar.symbol(&symbol_3_built)?;
+        self.push(ASTType::Symbol(symbol_3_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 74:
+    ///
+    /// `TokenLiteral: String;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn token_literal_0(&mut self, _string: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let string = pop_item!(self, string, String, context);
+        let token_literal_0_built = TokenLiteralString { string };
         let token_literal_0_built = TokenLiteral::String(token_literal_0_built);
         // Calling user action here
         self.user_grammar.token_literal(&token_literal_0_built)?;
@@ -2019,7 +3585,7 @@ impl<'t, 'u> ParolLsGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 38:
+    /// Semantic action for production 75:
     ///
     /// `TokenLiteral: LiteralString;`
     ///
@@ -2036,7 +3602,7 @@ impl<'t, 'u> ParolLsGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 39:
+    /// Semantic action for production 76:
     ///
     /// `TokenLiteral: Regex;`
     ///
@@ -2044,80 +3610,372 @@ impl<'t, 'u> ParolLsGrammarAuto<'t, 'u> {
     fn token_literal_2(&mut self, _regex: &ParseTreeType<'t>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let regex = pop_item!(self, regex, Regex, context);
-        let token_literal_2_built = TokenLiteralRegex { regex };
-        let token_literal_2_built = TokenLiteral::Regex(token_literal_2_built);
-        // Calling user action here
-        self.user_grammar.token_literal(&token_literal_2_built)?;
-        self.push(ASTType::TokenLiteral(token_literal_2_built), context);
+        let regex = pop_item!(self, regex, Regex, context);
+        let token_literal_2_built = TokenLiteralRegex { regex };
+        let token_literal_2_built = TokenLiteral::Regex(token_literal_2_built);
+        // Calling user action here
+        self.user_grammar.token_literal(&token_literal_2_built)?;
+        self.push(ASTType::TokenLiteral(token_literal_2_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 77:
+    ///
+    /// `TokenExpression: TokenLiteral TokenExpressionOpt /* Option */;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn token_expression(
+        &mut self,
+        _token_literal: &ParseTreeType<'t>,
+        _token_expression_opt: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let token_expression_opt =
+            pop_item!(self, token_expression_opt, TokenExpressionOpt, context);
+        let token_literal = pop_item!(self, token_literal, TokenLiteral, context);
+        let token_expression_built = TokenExpression {
+            token_literal,
+            token_expression_opt,
+        };
+        // Calling user action here
+        self.user_grammar
+            .token_expression(&token_expression_built)?;
+        self.push(ASTType::TokenExpression(token_expression_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 78:
+    ///
+    /// `TokenExpressionOpt /* Option<T>::Some */: TrailingContext;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn token_expression_opt_0(&mut self, _trailing_context: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let trailing_context = pop_item!(self, trailing_context, TrailingContext, context);
+        let token_expression_opt_0_built = TokenExpressionOpt { trailing_context };
+        self.push(
+            ASTType::TokenExpressionOpt(Some(token_expression_opt_0_built)),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 79:
+    ///
+    /// `TokenExpressionOpt /* Option<T>::None */: ;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn token_expression_opt_1(&mut self) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        self.push(ASTType::TokenExpressionOpt(None), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 80:
+    ///
+    /// `TrailingContext: '?=' : OwnedToken TokenLiteral;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn trailing_context(
+        &mut self,
+        quest_equ: &ParseTreeType<'t>,
+        _token_literal: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let quest_equ = quest_equ
+            .token()?
+            .try_into()
+            .map_err(parol_runtime::ParolError::UserError)?;
+        let token_literal = pop_item!(self, token_literal, TokenLiteral, context);
+        let trailing_context_built = TrailingContext {
+            quest_equ,
+            token_literal,
+        };
+        // Calling user action here
+        self.user_grammar
+            .trailing_context(&trailing_context_built)?;
+        self.push(ASTType::TrailingContext(trailing_context_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 81:
+    ///
+    /// `SimpleToken: TokenExpression SimpleTokenOpt /* Option */;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn simple_token(
+        &mut self,
+        _token_expression: &ParseTreeType<'t>,
+        _simple_token_opt: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let simple_token_opt = pop_item!(self, simple_token_opt, SimpleTokenOpt, context);
+        let token_expression = pop_item!(self, token_expression, TokenExpression, context);
+        let simple_token_built = SimpleToken {
+            token_expression,
+            simple_token_opt,
+        };
+        // Calling user action here
+        self.user_grammar.simple_token(&simple_token_built)?;
+        self.push(ASTType::SimpleToken(simple_token_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 82:
+    ///
+    /// `SimpleTokenOpt /* Option<T>::Some */: ASTControl;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn simple_token_opt_0(&mut self, _a_s_t_control: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let a_s_t_control = pop_item!(self, a_s_t_control, ASTControl, context);
+        let simple_token_opt_0_built = SimpleTokenOpt { a_s_t_control };
+        self.push(
+            ASTType::SimpleTokenOpt(Some(simple_token_opt_0_built)),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 83:
+    ///
+    /// `SimpleTokenOpt /* Option<T>::None */: ;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn simple_token_opt_1(&mut self) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        self.push(ASTType::SimpleTokenOpt(None), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 84:
+    ///
+    /// `Group: '(' : OwnedToken Alternations ')' : OwnedToken;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn group(
+        &mut self,
+        l_paren: &ParseTreeType<'t>,
+        _alternations: &ParseTreeType<'t>,
+        r_paren: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let l_paren = l_paren
+            .token()?
+            .try_into()
+            .map_err(parol_runtime::ParolError::UserError)?;
+        let r_paren = r_paren
+            .token()?
+            .try_into()
+            .map_err(parol_runtime::ParolError::UserError)?;
+        let alternations = pop_item!(self, alternations, Alternations, context);
+        let group_built = Group {
+            l_paren,
+            alternations,
+            r_paren,
+        };
+        // Calling user action here
+        self.user_grammar.group(&group_built)?;
+        self.push(ASTType::Group(group_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 85:
+    ///
+    /// `Optional: '[' : OwnedToken Alternations ']' : OwnedToken;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn optional(
+        &mut self,
+        l_bracket: &ParseTreeType<'t>,
+        _alternations: &ParseTreeType<'t>,
+        r_bracket: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let l_bracket = l_bracket
+            .token()?
+            .try_into()
+            .map_err(parol_runtime::ParolError::UserError)?;
+        let r_bracket = r_bracket
+            .token()?
+            .try_into()
+            .map_err(parol_runtime::ParolError::UserError)?;
+        let alternations = pop_item!(self, alternations, Alternations, context);
+        let optional_built = Optional {
+            l_bracket,
+            alternations,
+            r_bracket,
+        };
+        // Calling user action here
+        self.user_grammar.optional(&optional_built)?;
+        self.push(ASTType::Optional(optional_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 86:
+    ///
+    /// `Repeat: '{' : OwnedToken Alternations RepeatOpt /* Option */ '}' : OwnedToken RepeatOpt0 /* Option */;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn repeat(
+        &mut self,
+        l_brace: &ParseTreeType<'t>,
+        _alternations: &ParseTreeType<'t>,
+        _repeat_opt: &ParseTreeType<'t>,
+        r_brace: &ParseTreeType<'t>,
+        _repeat_opt0: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let l_brace = l_brace
+            .token()?
+            .try_into()
+            .map_err(parol_runtime::ParolError::UserError)?;
+        let r_brace = r_brace
+            .token()?
+            .try_into()
+            .map_err(parol_runtime::ParolError::UserError)?;
+        let repeat_opt0 = pop_item!(self, repeat_opt0, RepeatOpt0, context);
+        let repeat_opt = pop_item!(self, repeat_opt, RepeatOpt, context);
+        let alternations = pop_item!(self, alternations, Alternations, context);
+        let repeat_built = Repeat {
+            l_brace,
+            alternations,
+            repeat_opt,
+            r_brace,
+            repeat_opt0,
+        };
+        // Calling user action here
+        self.user_grammar.repeat(&repeat_built)?;
+        self.push(ASTType::Repeat(repeat_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 87:
+    ///
+    /// `RepeatOpt0 /* Option<T>::Some */: OneOrMore;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn repeat_opt0_0(&mut self, _one_or_more: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let one_or_more = pop_item!(self, one_or_more, OneOrMore, context);
+        let repeat_opt0_0_built = RepeatOpt0 { one_or_more };
+        self.push(ASTType::RepeatOpt0(Some(repeat_opt0_0_built)), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 88:
+    ///
+    /// `RepeatOpt0 /* Option<T>::None */: ;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn repeat_opt0_1(&mut self) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        self.push(ASTType::RepeatOpt0(None), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 89:
+    ///
+    /// `RepeatOpt /* Option<T>::Some */: RepeatSeparator;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn repeat_opt_0(&mut self, _repeat_separator: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let repeat_separator = pop_item!(self, repeat_separator, RepeatSeparator, context);
+        let repeat_opt_0_built = RepeatOpt { repeat_separator };
+        self.push(ASTType::RepeatOpt(Some(repeat_opt_0_built)), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 90:
+    ///
+    /// `RepeatOpt /* Option<T>::None */: ;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn repeat_opt_1(&mut self) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        self.push(ASTType::RepeatOpt(None), context);
         Ok(())
     }
 
-    /// Semantic action for production 40:
+    /// Semantic action for production 91:
     ///
-    /// `SimpleToken: TokenLiteral SimpleTokenOpt /* Option */;`
+    /// `NonTerminal: Identifier NonTerminalOpt /* Option */;`
     ///
     #[parol_runtime::function_name::named]
-    fn simple_token(
+    fn non_terminal(
         &mut self,
-        _token_literal: &ParseTreeType<'t>,
-        _simple_token_opt: &ParseTreeType<'t>,
+        _identifier: &ParseTreeType<'t>,
+        _non_terminal_opt: &ParseTreeType<'t>,
     ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let simple_token_opt = pop_item!(self, simple_token_opt, SimpleTokenOpt, context);
-        let token_literal = pop_item!(self, token_literal, TokenLiteral, context);
-        let simple_token_built = SimpleToken {
-            token_literal,
-            simple_token_opt,
+        let non_terminal_opt = pop_item!(self, non_terminal_opt, NonTerminalOpt, context);
+        let identifier = pop_item!(self, identifier, Identifier, context);
+        let non_terminal_built = NonTerminal {
+            identifier,
+            non_terminal_opt,
         };
         // Calling user action here
-        self.user_grammar.simple_token(&simple_token_built)?;
-        self.push(ASTType::SimpleToken(simple_token_built), context);
+        self.user_grammar.non_terminal(&non_terminal_built)?;
+        self.push(ASTType::NonTerminal(non_terminal_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 41:
+    /// Semantic action for production 92:
     ///
-    /// `SimpleTokenOpt /* Option<T>::Some */: ASTControl;`
+    /// `NonTerminalOpt /* Option<T>::Some */: ASTControl;`
     ///
     #[parol_runtime::function_name::named]
-    fn simple_token_opt_0(&mut self, _a_s_t_control: &ParseTreeType<'t>) -> Result<()> {
+    fn non_terminal_opt_0(&mut self, _a_s_t_control: &ParseTreeType<'t>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let a_s_t_control = pop_item!(self, a_s_t_control, ASTControl, context);
-        let simple_token_opt_0_built = SimpleTokenOpt { a_s_t_control };
+        let non_terminal_opt_0_built = NonTerminalOpt { a_s_t_control };
         self.push(
-            ASTType::SimpleTokenOpt(Some(simple_token_opt_0_built)),
+            ASTType::NonTerminalOpt(Some(non_terminal_opt_0_built)),
             context,
         );
         Ok(())
     }
 
-    /// Semantic action for production 42:
+    /// Semantic action for production 93:
     ///
-    /// `SimpleTokenOpt /* Option<T>::None */: ;`
+    /// `NonTerminalOpt /* Option<T>::None */: ;`
     ///
     #[parol_runtime::function_name::named]
-    fn simple_token_opt_1(&mut self) -> Result<()> {
+    fn non_terminal_opt_1(&mut self) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        self.push(ASTType::SimpleTokenOpt(None), context);
+        self.push(ASTType::NonTerminalOpt(None), context);
         Ok(())
     }
 
-    /// Semantic action for production 43:
+    /// Semantic action for production 94:
     ///
-    /// `TokenWithStates: "<" : OwnedToken IdentifierList ">" : OwnedToken TokenLiteral TokenWithStatesOpt /* Option */;`
+    /// `AngleArguments: "<" : OwnedToken TemplateArguments ">" : OwnedToken AngleArgumentsOpt /* Option */;`
     ///
     #[parol_runtime::function_name::named]
-    fn token_with_states(
+    fn angle_arguments(
         &mut self,
         l_t: &ParseTreeType<'t>,
-        _identifier_list: &ParseTreeType<'t>,
+        _template_arguments: &ParseTreeType<'t>,
         g_t: &ParseTreeType<'t>,
-        _token_literal: &ParseTreeType<'t>,
-        _token_with_states_opt: &ParseTreeType<'t>,
+        _angle_arguments_opt: &ParseTreeType<'t>,
     ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
@@ -2129,206 +3987,203 @@ impl<'t, 'u> ParolLsGrammarAuto<'t, 'u> {
             .token()?
             .try_into()
             .map_err(parol_runtime::ParolError::UserError)?;
-        let token_with_states_opt =
-            pop_item!(self, token_with_states_opt, TokenWithStatesOpt, context);
-        let token_literal = pop_item!(self, token_literal, TokenLiteral, context);
-        let identifier_list = pop_item!(self, identifier_list, IdentifierList, context);
-        let token_with_states_built = TokenWithStates {
+        let angle_arguments_opt = pop_item!(self, angle_arguments_opt, AngleArgumentsOpt, context);
+        let template_arguments = pop_item!(self, template_arguments, TemplateArguments, context);
+        let angle_arguments_built = AngleArguments {
             l_t,
-            identifier_list,
+            template_arguments,
             g_t,
-            token_literal,
-            token_with_states_opt,
+            angle_arguments_opt,
         };
         // Calling user action here
-        self.user_grammar
-            .token_with_states(&token_with_states_built)?;
-        self.push(ASTType::TokenWithStates(token_with_states_built), context);
+        self.user_grammar.angle_arguments(&angle_arguments_built)?;
+        self.push(ASTType::AngleArguments(angle_arguments_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 44:
+    /// Semantic action for production 95:
     ///
-    /// `TokenWithStatesOpt /* Option<T>::Some */: ASTControl;`
+    /// `AngleArgumentsOpt /* Option<T>::Some */: ASTControl;`
     ///
     #[parol_runtime::function_name::named]
-    fn token_with_states_opt_0(&mut self, _a_s_t_control: &ParseTreeType<'t>) -> Result<()> {
+    fn angle_arguments_opt_0(&mut self, _a_s_t_control: &ParseTreeType<'t>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let a_s_t_control = pop_item!(self, a_s_t_control, ASTControl, context);
-        let token_with_states_opt_0_built = TokenWithStatesOpt { a_s_t_control };
+        let angle_arguments_opt_0_built = AngleArgumentsOpt { a_s_t_control };
         self.push(
-            ASTType::TokenWithStatesOpt(Some(token_with_states_opt_0_built)),
+            ASTType::AngleArgumentsOpt(Some(angle_arguments_opt_0_built)),
             context,
         );
         Ok(())
     }
 
-    /// Semantic action for production 45:
+    /// Semantic action for production 96:
     ///
-    /// `TokenWithStatesOpt /* Option<T>::None */: ;`
+    /// `AngleArgumentsOpt /* Option<T>::None */: ;`
     ///
     #[parol_runtime::function_name::named]
-    fn token_with_states_opt_1(&mut self) -> Result<()> {
+    fn angle_arguments_opt_1(&mut self) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        self.push(ASTType::TokenWithStatesOpt(None), context);
+        self.push(ASTType::AngleArgumentsOpt(None), context);
         Ok(())
     }
 
-    /// Semantic action for production 46:
+    /// Semantic action for production 97:
     ///
-    /// `Group: '(' : OwnedToken Alternations ')' : OwnedToken;`
+    /// `TemplateArguments: TemplateArgument TemplateArgumentsList /* Vec */;`
     ///
     #[parol_runtime::function_name::named]
-    fn group(
+    fn template_arguments(
         &mut self,
-        l_paren: &ParseTreeType<'t>,
-        _alternations: &ParseTreeType<'t>,
-        r_paren: &ParseTreeType<'t>,
+        _template_argument: &ParseTreeType<'t>,
+        _template_arguments_list: &ParseTreeType<'t>,
     ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let l_paren = l_paren
-            .token()?
-            .try_into()
-            .map_err(parol_runtime::ParolError::UserError)?;
-        let r_paren = r_paren
-            .token()?
-            .try_into()
-            .map_err(parol_runtime::ParolError::UserError)?;
-        let alternations = pop_item!(self, alternations, Alternations, context);
-        let group_built = Group {
-            l_paren,
-            alternations,
-            r_paren,
+        let template_arguments_list = pop_and_reverse_item!(
+            self,
+            template_arguments_list,
+            TemplateArgumentsList,
+            context
+        );
+        let template_argument = pop_item!(self, template_argument, TemplateArgument, context);
+        let template_arguments_built = TemplateArguments {
+            template_argument,
+            template_arguments_list,
         };
         // Calling user action here
-        self.user_grammar.group(&group_built)?;
-        self.push(ASTType::Group(group_built), context);
+        self.user_grammar
+            .template_arguments(&template_arguments_built)?;
+        self.push(
+            ASTType::TemplateArguments(template_arguments_built),
+            context,
+        );
         Ok(())
     }
 
-    /// Semantic action for production 47:
+    /// Semantic action for production 98:
     ///
-    /// `Optional: '[' : OwnedToken Alternations ']' : OwnedToken;`
+    /// `TemplateArgumentsList /* Vec<T>::Push */: "," : OwnedToken TemplateArgument TemplateArgumentsList;`
     ///
     #[parol_runtime::function_name::named]
-    fn optional(
+    fn template_arguments_list_0(
         &mut self,
-        l_bracket: &ParseTreeType<'t>,
-        _alternations: &ParseTreeType<'t>,
-        r_bracket: &ParseTreeType<'t>,
+        comma: &ParseTreeType<'t>,
+        _template_argument: &ParseTreeType<'t>,
+        _template_arguments_list: &ParseTreeType<'t>,
     ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let l_bracket = l_bracket
-            .token()?
-            .try_into()
-            .map_err(parol_runtime::ParolError::UserError)?;
-        let r_bracket = r_bracket
+        let comma = comma
             .token()?
             .try_into()
             .map_err(parol_runtime::ParolError::UserError)?;
-        let alternations = pop_item!(self, alternations, Alternations, context);
-        let optional_built = Optional {
-            l_bracket,
-            alternations,
-            r_bracket,
+        let mut template_arguments_list = pop_item!(
+            self,
+            template_arguments_list,
+            TemplateArgumentsList,
+            context
+        );
+        let template_argument = pop_item!(self, template_argument, TemplateArgument, context);
+        let template_arguments_list_0_built = TemplateArgumentsList {
+            template_argument,
+            comma,
         };
-        // Calling user action here
-        self.user_grammar.optional(&optional_built)?;
-        self.push(ASTType::Optional(optional_built), context);
+        // Add an element to the vector
+        template_arguments_list.push(template_arguments_list_0_built);
+        self.push(
+            ASTType::TemplateArgumentsList(template_arguments_list),
+            context,
+        );
         Ok(())
     }
 
-    /// Semantic action for production 48:
+    /// Semantic action for production 99:
     ///
-    /// `Repeat: '{' : OwnedToken Alternations '}' : OwnedToken;`
+    /// `TemplateArgumentsList /* Vec<T>::New */: ;`
     ///
     #[parol_runtime::function_name::named]
-    fn repeat(
-        &mut self,
-        l_brace: &ParseTreeType<'t>,
-        _alternations: &ParseTreeType<'t>,
-        r_brace: &ParseTreeType<'t>,
-    ) -> Result<()> {
+    fn template_arguments_list_1(&mut self) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let l_brace = l_brace
-            .token()?
-            .try_into()
-            .map_err(parol_runtime::ParolError::UserError)?;
-        let r_brace = r_brace
-            .token()?
-            .try_into()
-            .map_err(parol_runtime::ParolError::UserError)?;
-        let alternations = pop_item!(self, alternations, Alternations, context);
-        let repeat_built = Repeat {
-            l_brace,
-            alternations,
-            r_brace,
-        };
-        // Calling user action here
-        self.user_grammar.repeat(&repeat_built)?;
-        self.push(ASTType::Repeat(repeat_built), context);
+        let template_arguments_list_1_built = Vec::new();
+        self.push(
+            ASTType::TemplateArgumentsList(template_arguments_list_1_built),
+            context,
+        );
         Ok(())
     }
 
-    /// Semantic action for production 49:
+    /// Semantic action for production 100:
     ///
-    /// `NonTerminal: Identifier NonTerminalOpt /* Option */;`
+    /// `TemplateArgument: Factor TemplateArgumentList /* Vec */;`
     ///
     #[parol_runtime::function_name::named]
-    fn non_terminal(
+    fn template_argument(
         &mut self,
-        _identifier: &ParseTreeType<'t>,
-        _non_terminal_opt: &ParseTreeType<'t>,
+        _factor: &ParseTreeType<'t>,
+        _template_argument_list: &ParseTreeType<'t>,
     ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let non_terminal_opt = pop_item!(self, non_terminal_opt, NonTerminalOpt, context);
-        let identifier = pop_item!(self, identifier, Identifier, context);
-        let non_terminal_built = NonTerminal {
-            identifier,
-            non_terminal_opt,
+        let template_argument_list =
+            pop_and_reverse_item!(self, template_argument_list, TemplateArgumentList, context);
+        let factor = pop_item!(self, factor, Factor, context);
+        let template_argument_built = TemplateArgument {
+            factor,
+            template_argument_list,
         };
         // Calling user action here
-        self.user_grammar.non_terminal(&non_terminal_built)?;
-        self.push(ASTType::NonTerminal(non_terminal_built), context);
+        self.user_grammar
+            .template_argument(&template_argument_built)?;
+        self.push(ASTType::TemplateArgument(template_argument_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 50:
+    /// Semantic action for production 101:
     ///
-    /// `NonTerminalOpt /* Option<T>::Some */: ASTControl;`
+    /// `TemplateArgumentList /* Vec<T>::Push */: Factor TemplateArgumentList;`
     ///
     #[parol_runtime::function_name::named]
-    fn non_terminal_opt_0(&mut self, _a_s_t_control: &ParseTreeType<'t>) -> Result<()> {
+    fn template_argument_list_0(
+        &mut self,
+        _factor: &ParseTreeType<'t>,
+        _template_argument_list: &ParseTreeType<'t>,
+    ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let a_s_t_control = pop_item!(self, a_s_t_control, ASTControl, context);
-        let non_terminal_opt_0_built = NonTerminalOpt { a_s_t_control };
+        let mut template_argument_list =
+            pop_item!(self, template_argument_list, TemplateArgumentList, context);
+        let factor = pop_item!(self, factor, Factor, context);
+        let template_argument_list_0_built = TemplateArgumentList { factor };
+        // Add an element to the vector
+        template_argument_list.push(template_argument_list_0_built);
         self.push(
-            ASTType::NonTerminalOpt(Some(non_terminal_opt_0_built)),
+            ASTType::TemplateArgumentList(template_argument_list),
             context,
         );
         Ok(())
     }
 
-    /// Semantic action for production 51:
+    /// Semantic action for production 102:
     ///
-    /// `NonTerminalOpt /* Option<T>::None */: ;`
+    /// `TemplateArgumentList /* Vec<T>::New */: ;`
     ///
     #[parol_runtime::function_name::named]
-    fn non_terminal_opt_1(&mut self) -> Result<()> {
+    fn template_argument_list_1(&mut self) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        self.push(ASTType::NonTerminalOpt(None), context);
+        let template_argument_list_1_built = Vec::new();
+        self.push(
+            ASTType::TemplateArgumentList(template_argument_list_1_built),
+            context,
+        );
         Ok(())
     }
 
-    /// Semantic action for production 52:
+    /// Semantic action for production 103:
     ///
     /// `Identifier: /[a-zA-Z_][a-zA-Z0-9_]*/ : OwnedToken;`
     ///
@@ -2347,9 +4202,28 @@ impl<'t, 'u> ParolLsGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 53:
+    /// Semantic action for production 104:
+    ///
+    /// `Number: /[0-9]+/ : OwnedToken;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn number(&mut self, number: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let number = number
+            .token()?
+            .try_into()
+            .map_err(parol_runtime::ParolError::UserError)?;
+        let number_built = Number { number };
+        // Calling user action here
+        self.user_grammar.number(&number_built)?;
+        self.push(ASTType::Number(number_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 105:
     ///
-    /// `String: /"(\\.|[^\\])*?"/ : OwnedToken;`
+    /// `String: /"(\\.|[^\\])*?"i?/ : OwnedToken;`
     ///
     #[parol_runtime::function_name::named]
     fn string(&mut self, string: &ParseTreeType<'t>) -> Result<()> {
@@ -2366,9 +4240,9 @@ impl<'t, 'u> ParolLsGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 54:
+    /// Semantic action for production 106:
     ///
-    /// `LiteralString: /'(\\'|[^'])*?'/ : OwnedToken;`
+    /// `LiteralString: /'(\\'|[^'])*?'i?/ : OwnedToken;`
     ///
     #[parol_runtime::function_name::named]
     fn literal_string(&mut self, literal_string: &ParseTreeType<'t>) -> Result<()> {
@@ -2385,7 +4259,7 @@ impl<'t, 'u> ParolLsGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 55:
+    /// Semantic action for production 107:
     ///
     /// `ScannerState: "%scanner" : OwnedToken Identifier '{' : OwnedToken ScannerStateList /* Vec */ '}' : OwnedToken;`
     ///
@@ -2428,7 +4302,7 @@ impl<'t, 'u> ParolLsGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 56:
+    /// Semantic action for production 108:
     ///
     /// `ScannerStateList /* Vec<T>::Push */: ScannerDirectives ScannerStateList;`
     ///
@@ -2449,7 +4323,7 @@ impl<'t, 'u> ParolLsGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 57:
+    /// Semantic action for production 109:
     ///
     /// `ScannerStateList /* Vec<T>::New */: ;`
     ///
@@ -2465,7 +4339,7 @@ impl<'t, 'u> ParolLsGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 58:
+    /// Semantic action for production 110:
     ///
     /// `IdentifierList: Identifier IdentifierListList /* Vec */;`
     ///
@@ -2490,7 +4364,7 @@ impl<'t, 'u> ParolLsGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 59:
+    /// Semantic action for production 111:
     ///
     /// `IdentifierListList /* Vec<T>::Push */: "," : OwnedToken Identifier IdentifierListList;`
     ///
@@ -2517,7 +4391,7 @@ impl<'t, 'u> ParolLsGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 60:
+    /// Semantic action for production 112:
     ///
     /// `IdentifierListList /* Vec<T>::New */: ;`
     ///
@@ -2533,7 +4407,7 @@ impl<'t, 'u> ParolLsGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 61:
+    /// Semantic action for production 113:
     ///
     /// `ScannerSwitch: "%sc" : OwnedToken '(' : OwnedToken ScannerSwitchOpt /* Option */ ')' : OwnedToken;`
     ///
@@ -2574,7 +4448,7 @@ impl<'t, 'u> ParolLsGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 62:
+    /// Semantic action for production 114:
     ///
     /// `ScannerSwitch: "%push" : OwnedToken '(' : OwnedToken Identifier ')' : OwnedToken;`
     ///
@@ -2615,7 +4489,7 @@ impl<'t, 'u> ParolLsGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 63:
+    /// Semantic action for production 115:
     ///
     /// `ScannerSwitch: "%pop" : OwnedToken '(' : OwnedToken ')' : OwnedToken;`
     ///
@@ -2652,7 +4526,7 @@ impl<'t, 'u> ParolLsGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 64:
+    /// Semantic action for production 116:
     ///
     /// `ScannerSwitchOpt /* Option<T>::Some */: Identifier;`
     ///
@@ -2669,7 +4543,7 @@ impl<'t, 'u> ParolLsGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 65:
+    /// Semantic action for production 117:
     ///
     /// `ScannerSwitchOpt /* Option<T>::None */: ;`
     ///
@@ -2681,7 +4555,7 @@ impl<'t, 'u> ParolLsGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 66:
+    /// Semantic action for production 118:
     ///
     /// `ASTControl: CutOperator;`
     ///
@@ -2698,7 +4572,7 @@ impl<'t, 'u> ParolLsGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 67:
+    /// Semantic action for production 119:
     ///
     /// `ASTControl: UserTypeDeclaration;`
     ///
@@ -2718,7 +4592,7 @@ impl<'t, 'u> ParolLsGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 68:
+    /// Semantic action for production 120:
     ///
     /// `CutOperator: '^' : OwnedToken;`
     ///
@@ -2737,7 +4611,7 @@ impl<'t, 'u> ParolLsGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 69:
+    /// Semantic action for production 121:
     ///
     /// `UserTypeDeclaration: ":" : OwnedToken UserTypeName;`
     ///
@@ -2768,7 +4642,7 @@ impl<'t, 'u> ParolLsGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 70:
+    /// Semantic action for production 122:
     ///
     /// `UserTypeName: Identifier UserTypeNameList /* Vec */;`
     ///
@@ -2793,7 +4667,7 @@ impl<'t, 'u> ParolLsGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 71:
+    /// Semantic action for production 123:
     ///
     /// `UserTypeNameList /* Vec<T>::Push */: DoubleColon Identifier UserTypeNameList;`
     ///
@@ -2820,7 +4694,7 @@ impl<'t, 'u> ParolLsGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 72:
+    /// Semantic action for production 124:
     ///
     /// `UserTypeNameList /* Vec<T>::New */: ;`
     ///
@@ -2836,9 +4710,98 @@ impl<'t, 'u> ParolLsGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 73:
+    /// Semantic action for production 125:
+    ///
+    /// `RepeatSeparator: '%' : OwnedToken Symbol RepeatSeparatorList /* Vec */;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn repeat_separator(
+        &mut self,
+        percent: &ParseTreeType<'t>,
+        _symbol: &ParseTreeType<'t>,
+        _repeat_separator_list: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let percent = percent
+            .token()?
+            .try_into()
+            .map_err(parol_runtime::ParolError::UserError)?;
+        let repeat_separator_list =
+            pop_and_reverse_item!(self, repeat_separator_list, RepeatSeparatorList, context);
+        let symbol = pop_item!(self, symbol, Symbol, context);
+        let repeat_separator_built = RepeatSeparator {
+            percent,
+            symbol,
+            repeat_separator_list,
+        };
+        // Calling user action here
+        self.user_grammar
+            .repeat_separator(&repeat_separator_built)?;
+        self.push(ASTType::RepeatSeparator(repeat_separator_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 126:
+    ///
+    /// `RepeatSeparatorList /* Vec<T>::Push */: Symbol RepeatSeparatorList;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn repeat_separator_list_0(
+        &mut self,
+        _symbol: &ParseTreeType<'t>,
+        _repeat_separator_list: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let mut repeat_separator_list =
+            pop_item!(self, repeat_separator_list, RepeatSeparatorList, context);
+        let symbol = pop_item!(self, symbol, Symbol, context);
+        let repeat_separator_list_0_built = RepeatSeparatorList { symbol };
+        // Add an element to the vector
+        repeat_separator_list.push(repeat_separator_list_0_built);
+        self.push(ASTType::RepeatSeparatorList(repeat_separator_list), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 127:
+    ///
+    /// `RepeatSeparatorList /* Vec<T>::New */: ;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn repeat_separator_list_1(&mut self) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let repeat_separator_list_1_built = Vec::new();
+        self.push(
+            ASTType::RepeatSeparatorList(repeat_separator_list_1_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 128:
+    ///
+    /// `OneOrMore: '+' : OwnedToken;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn one_or_more(&mut self, one_or_more: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let one_or_more = one_or_more
+            .token()?
+            .try_into()
+            .map_err(parol_runtime::ParolError::UserError)?;
+        let one_or_more_built = OneOrMore { one_or_more };
+        // Calling user action here
+        self.user_grammar.one_or_more(&one_or_more_built)?;
+        self.push(ASTType::OneOrMore(one_or_more_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 129:
     ///
-    /// `Regex: /\u{2f}(\\.|[^\\])*?\u{2f}/ : OwnedToken;`
+    /// `Regex: /\u{2f}(\\.|[^\\])*?\u{2f}i?/ : OwnedToken;`
     ///
     #[parol_runtime::function_name::named]
     fn regex(&mut self, regex: &ParseTreeType<'t>) -> Result<()> {
@@ -2877,81 +4840,137 @@ impl<'t> UserActionsTrait<'t> for ParolLsGrammarAuto<'t, '_> {
             8 => self.declaration_1(&children[0], &children[1]),
             9 => self.declaration_2(&children[0], &children[1], &children[2], &children[3]),
             10 => self.declaration_3(&children[0], &children[1]),
-            11 => self.declaration_4(&children[0]),
-            12 => self.scanner_directives_0(&children[0], &children[1]),
-            13 => self.scanner_directives_1(&children[0], &children[1], &children[2]),
-            14 => self.scanner_directives_2(&children[0]),
-            15 => self.scanner_directives_3(&children[0]),
-            16 => self.scanner_directives_4(&children[0], &children[1], &children[2], &children[3]),
-            17 => self.grammar_definition(&children[0], &children[1], &children[2]),
-            18 => self.grammar_definition_list_0(&children[0], &children[1]),
-            19 => self.grammar_definition_list_1(),
-            20 => self.double_colon(&children[0]),
-            21 => self.production_l_h_s(&children[0], &children[1]),
-            22 => self.production(&children[0], &children[1], &children[2]),
-            23 => self.alternations(&children[0], &children[1]),
-            24 => self.alternations_list_0(&children[0], &children[1], &children[2]),
-            25 => self.alternations_list_1(),
-            26 => self.alternation(&children[0]),
-            27 => self.alternation_list_0(&children[0], &children[1]),
-            28 => self.alternation_list_1(),
-            29 => self.factor_0(&children[0]),
-            30 => self.factor_1(&children[0]),
-            31 => self.factor_2(&children[0]),
-            32 => self.factor_3(&children[0]),
-            33 => self.symbol_0(&children[0]),
-            34 => self.symbol_1(&children[0]),
-            35 => self.symbol_2(&children[0]),
-            36 => self.symbol_3(&children[0]),
-            37 => self.token_literal_0(&children[0]),
-            38 => self.token_literal_1(&children[0]),
-            39 => self.token_literal_2(&children[0]),
-            40 => self.simple_token(&children[0], &children[1]),
-            41 => self.simple_token_opt_0(&children[0]),
-            42 => self.simple_token_opt_1(),
-            43 => self.token_with_states(
+            11 => self.declaration_4(&children[0], &children[1]),
+            12 => self.declaration_5(&children[0], &children[1]),
+            13 => self.declaration_6(&children[0], &children[1], &children[2]),
+            14 => self.declaration_7(&children[0], &children[1]),
+            15 => self.declaration_8(&children[0], &children[1], &children[2]),
+            16 => self.declaration_9(&children[0], &children[1]),
+            17 => self.declaration_10(&children[0]),
+            18 => self.declaration_11(&children[0]),
+            19 => self.declaration_opt_0(&children[0]),
+            20 => self.declaration_opt_1(),
+            21 => self.expect_directive(&children[0]),
+            22 => self.precedence_declaration(&children[0], &children[1], &children[2]),
+            23 => self.precedence_declaration_list_0(&children[0], &children[1]),
+            24 => self.precedence_declaration_list_1(),
+            25 => self.associativity_0(&children[0]),
+            26 => self.associativity_1(&children[0]),
+            27 => self.associativity_2(&children[0]),
+            28 => self.precedence_symbol_0(&children[0]),
+            29 => self.precedence_symbol_1(&children[0]),
+            30 => self.scanner_directives_0(&children[0], &children[1]),
+            31 => self.scanner_directives_1(&children[0], &children[1], &children[2]),
+            32 => self.scanner_directives_2(&children[0], &children[1], &children[2]),
+            33 => self.scanner_directives_3(&children[0]),
+            34 => self.scanner_directives_4(&children[0]),
+            35 => self.scanner_directives_5(&children[0]),
+            36 => self.scanner_directives_6(&children[0]),
+            37 => self.scanner_directives_7(&children[0], &children[1], &children[2], &children[3]),
+            38 => self.scanner_directives_8(&children[0], &children[1], &children[2], &children[3]),
+            39 => self.grammar_definition(&children[0], &children[1], &children[2]),
+            40 => self.grammar_definition_list_0(&children[0], &children[1]),
+            41 => self.grammar_definition_list_1(),
+            42 => self.double_colon(&children[0]),
+            43 => self.derive_target(&children[0], &children[1]),
+            44 => self.derive_list(&children[0], &children[1]),
+            45 => self.derive_list_list_0(&children[0], &children[1], &children[2]),
+            46 => self.derive_list_list_1(),
+            47 => self.production_l_h_s(&children[0], &children[1]),
+            48 => self.production_name(&children[0], &children[1]),
+            49 => self.production_name_opt_0(&children[0]),
+            50 => self.production_name_opt_1(),
+            51 => self.template_parameters(&children[0], &children[1], &children[2]),
+            52 => self.production(&children[0], &children[1], &children[2]),
+            53 => self.alternations(&children[0], &children[1]),
+            54 => self.alternations_list_0(&children[0], &children[1], &children[2]),
+            55 => self.alternations_list_1(),
+            56 => self.alternation(&children[0], &children[1], &children[2]),
+            57 => self.alternation_list_0(&children[0], &children[1]),
+            58 => self.alternation_list_1(),
+            59 => self.alternation_opt0_0(&children[0]),
+            60 => self.alternation_opt0_1(),
+            61 => self.alternation_opt_0(&children[0], &children[1]),
+            62 => self.alternation_opt_1(),
+            63 => self.alternation_label(&children[0], &children[1]),
+            64 => self.factor_0(&children[0]),
+            65 => self.factor_1(&children[0]),
+            66 => self.factor_2(&children[0]),
+            67 => self.factor_3(&children[0]),
+            68 => self.factor_4(&children[0], &children[1]),
+            69 => self.field_label(&children[0], &children[1]),
+            70 => self.symbol_0(&children[0]),
+            71 => self.symbol_1(&children[0]),
+            72 => self.symbol_2(&children[0]),
+            73 => self.symbol_3(&children[0]),
+            74 => self.token_literal_0(&children[0]),
+            75 => self.token_literal_1(&children[0]),
+            76 => self.token_literal_2(&children[0]),
+            77 => self.token_expression(&children[0], &children[1]),
+            78 => self.token_expression_opt_0(&children[0]),
+            79 => self.token_expression_opt_1(),
+            80 => self.trailing_context(&children[0], &children[1]),
+            81 => self.simple_token(&children[0], &children[1]),
+            82 => self.simple_token_opt_0(&children[0]),
+            83 => self.simple_token_opt_1(),
+            84 => self.group(&children[0], &children[1], &children[2]),
+            85 => self.optional(&children[0], &children[1], &children[2]),
+            86 => self.repeat(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 &children[4],
             ),
-            44 => self.token_with_states_opt_0(&children[0]),
-            45 => self.token_with_states_opt_1(),
-            46 => self.group(&children[0], &children[1], &children[2]),
-            47 => self.optional(&children[0], &children[1], &children[2]),
-            48 => self.repeat(&children[0], &children[1], &children[2]),
-            49 => self.non_terminal(&children[0], &children[1]),
-            50 => self.non_terminal_opt_0(&children[0]),
-            51 => self.non_terminal_opt_1(),
-            52 => self.identifier(&children[0]),
-            53 => self.string(&children[0]),
-            54 => self.literal_string(&children[0]),
-            55 => self.scanner_state(
+            87 => self.repeat_opt0_0(&children[0]),
+            88 => self.repeat_opt0_1(),
+            89 => self.repeat_opt_0(&children[0]),
+            90 => self.repeat_opt_1(),
+            91 => self.non_terminal(&children[0], &children[1]),
+            92 => self.non_terminal_opt_0(&children[0]),
+            93 => self.non_terminal_opt_1(),
+            94 => self.angle_arguments(&children[0], &children[1], &children[2], &children[3]),
+            95 => self.angle_arguments_opt_0(&children[0]),
+            96 => self.angle_arguments_opt_1(),
+            97 => self.template_arguments(&children[0], &children[1]),
+            98 => self.template_arguments_list_0(&children[0], &children[1], &children[2]),
+            99 => self.template_arguments_list_1(),
+            100 => self.template_argument(&children[0], &children[1]),
+            101 => self.template_argument_list_0(&children[0], &children[1]),
+            102 => self.template_argument_list_1(),
+            103 => self.identifier(&children[0]),
+            104 => self.number(&children[0]),
+            105 => self.string(&children[0]),
+            106 => self.literal_string(&children[0]),
+            107 => self.scanner_state(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 &children[4],
             ),
-            56 => self.scanner_state_list_0(&children[0], &children[1]),
-            57 => self.scanner_state_list_1(),
-            58 => self.identifier_list(&children[0], &children[1]),
-            59 => self.identifier_list_list_0(&children[0], &children[1], &children[2]),
-            60 => self.identifier_list_list_1(),
-            61 => self.scanner_switch_0(&children[0], &children[1], &children[2], &children[3]),
-            62 => self.scanner_switch_1(&children[0], &children[1], &children[2], &children[3]),
-            63 => self.scanner_switch_2(&children[0], &children[1], &children[2]),
-            64 => self.scanner_switch_opt_0(&children[0]),
-            65 => self.scanner_switch_opt_1(),
-            66 => self.a_s_t_control_0(&children[0]),
-            67 => self.a_s_t_control_1(&children[0]),
-            68 => self.cut_operator(&children[0]),
-            69 => self.user_type_declaration(&children[0], &children[1]),
-            70 => self.user_type_name(&children[0], &children[1]),
-            71 => self.user_type_name_list_0(&children[0], &children[1], &children[2]),
-            72 => self.user_type_name_list_1(),
-            73 => self.regex(&children[0]),
+            108 => self.scanner_state_list_0(&children[0], &children[1]),
+            109 => self.scanner_state_list_1(),
+            110 => self.identifier_list(&children[0], &children[1]),
+            111 => self.identifier_list_list_0(&children[0], &children[1], &children[2]),
+            112 => self.identifier_list_list_1(),
+            113 => self.scanner_switch_0(&children[0], &children[1], &children[2], &children[3]),
+            114 => self.scanner_switch_1(&children[0], &children[1], &children[2], &children[3]),
+            115 => self.scanner_switch_2(&children[0], &children[1], &children[2]),
+            116 => self.scanner_switch_opt_0(&children[0]),
+            117 => self.scanner_switch_opt_1(),
+            118 => self.a_s_t_control_0(&children[0]),
+            119 => self.a_s_t_control_1(&children[0]),
+            120 => self.cut_operator(&children[0]),
+            121 => self.user_type_declaration(&children[0], &children[1]),
+            122 => self.user_type_name(&children[0], &children[1]),
+            123 => self.user_type_name_list_0(&children[0], &children[1], &children[2]),
+            124 => self.user_type_name_list_1(),
+            125 => self.repeat_separator(&children[0], &children[1], &children[2]),
+            126 => self.repeat_separator_list_0(&children[0], &children[1]),
+            127 => self.repeat_separator_list_1(),
+            128 => self.one_or_more(&children[0]),
+            129 => self.regex(&children[0]),
             _ => Err(ParserError::InternalError(format!(
                 "Unhandled production number: {}",
                 prod_num
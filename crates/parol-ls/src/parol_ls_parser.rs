@@ -18,7 +18,7 @@ use parol_runtime::lexer::tokenizer::{
     ERROR_TOKEN, NEW_LINE_TOKEN, UNMATCHABLE_TOKEN, WHITESPACE_TOKEN,
 };
 
-pub const TERMINALS: &[&str; 41] = &[
+pub const TERMINALS: &[&str; 60] = &[
     /*  0 */ UNMATCHABLE_TOKEN,
     /*  1 */ UNMATCHABLE_TOKEN,
     /*  2 */ UNMATCHABLE_TOKEN,
@@ -30,39 +30,58 @@ pub const TERMINALS: &[&str; 41] = &[
     /*  8 */ r"%user_type",
     /*  9 */ r"=",
     /* 10 */ r"%grammar_type",
-    /* 11 */ r"%line_comment",
-    /* 12 */ r"%block_comment",
-    /* 13 */ r"%auto_newline_off",
-    /* 14 */ r"%auto_ws_off",
-    /* 15 */ r"%on",
-    /* 16 */ r"%enter",
-    /* 17 */ r"%%",
-    /* 18 */ r"::",
-    /* 19 */ r":",
-    /* 20 */ r";",
-    /* 21 */ r"\|",
-    /* 22 */ r"<",
-    /* 23 */ r">",
-    /* 24 */ r"\(",
-    /* 25 */ r"\)",
-    /* 26 */ r"\[",
-    /* 27 */ r"\]",
-    /* 28 */ r"\{",
-    /* 29 */ r"\}",
-    /* 30 */ r"[a-zA-Z_][a-zA-Z0-9_]*",
-    /* 31 */ r#""(\\.|[^\\])*?""#,
-    /* 32 */ r"'(\\'|[^'])*?'",
-    /* 33 */ r"%scanner",
-    /* 34 */ r",",
-    /* 35 */ r"%sc",
-    /* 36 */ r"%push",
-    /* 37 */ r"%pop",
-    /* 38 */ r"\^",
-    /* 39 */ r"\u{2f}(\\.|[^\\])*?\u{2f}",
-    /* 40 */ ERROR_TOKEN,
+    /* 11 */ r"%include",
+    /* 12 */ r"%keywords",
+    /* 13 */ r"%derive",
+    /* 14 */ r"%inline",
+    /* 15 */ r"%display",
+    /* 16 */ r"%expect(-rr)?",
+    /* 17 */ r"%left",
+    /* 18 */ r"%right",
+    /* 19 */ r"%nonassoc",
+    /* 20 */ r"%line_comment",
+    /* 21 */ r"%block_comment",
+    /* 22 */ r"%nested_block_comment",
+    /* 23 */ r"%auto_newline_off",
+    /* 24 */ r"%auto_ws_off",
+    /* 25 */ r"%longest_match",
+    /* 26 */ r"%case_insensitive",
+    /* 27 */ r"%offside",
+    /* 28 */ r"%on",
+    /* 29 */ r"%enter",
+    /* 30 */ r"%%",
+    /* 31 */ r"::",
+    /* 32 */ r":",
+    /* 33 */ r",",
+    /* 34 */ r"<",
+    /* 35 */ r">",
+    /* 36 */ r";",
+    /* 37 */ r"\|",
+    /* 38 */ r"%prec",
+    /* 39 */ r"\#",
+    /* 40 */ r"\?=",
+    /* 41 */ r"\(",
+    /* 42 */ r"\)",
+    /* 43 */ r"\[",
+    /* 44 */ r"\]",
+    /* 45 */ r"\{",
+    /* 46 */ r"\}",
+    /* 47 */ r"[a-zA-Z_][a-zA-Z0-9_]*",
+    /* 48 */ r"[0-9]+",
+    /* 49 */ r#""(\\.|[^\\])*?"i?"#,
+    /* 50 */ r"'(\\'|[^'])*?'i?",
+    /* 51 */ r"%scanner",
+    /* 52 */ r"%sc",
+    /* 53 */ r"%push",
+    /* 54 */ r"%pop",
+    /* 55 */ r"\^",
+    /* 56 */ r"%",
+    /* 57 */ r"\+",
+    /* 58 */ r"\u{2f}(\\.|[^\\])*?\u{2f}i?",
+    /* 59 */ ERROR_TOKEN,
 ];
 
-pub const TERMINAL_NAMES: &[&str; 41] = &[
+pub const TERMINAL_NAMES: &[&str; 60] = &[
     /*  0 */ "EndOfInput",
     /*  1 */ "Newline",
     /*  2 */ "Whitespace",
@@ -74,40 +93,59 @@ pub const TERMINAL_NAMES: &[&str; 41] = &[
     /*  8 */ "PercentUserUnderscoreType",
     /*  9 */ "Equ",
     /* 10 */ "PercentGrammarUnderscoreType",
-    /* 11 */ "PercentLineUnderscoreComment",
-    /* 12 */ "PercentBlockUnderscoreComment",
-    /* 13 */ "PercentAutoUnderscoreNewlineUnderscoreOff",
-    /* 14 */ "PercentAutoUnderscoreWsUnderscoreOff",
-    /* 15 */ "PercentOn",
-    /* 16 */ "PercentEnter",
-    /* 17 */ "PercentPercent",
-    /* 18 */ "DoubleColon",
-    /* 19 */ "Colon",
-    /* 20 */ "Semicolon",
-    /* 21 */ "Or",
-    /* 22 */ "LT",
-    /* 23 */ "GT",
-    /* 24 */ "LParen",
-    /* 25 */ "RParen",
-    /* 26 */ "LBracket",
-    /* 27 */ "RBracket",
-    /* 28 */ "LBrace",
-    /* 29 */ "RBrace",
-    /* 30 */ "Identifier",
-    /* 31 */ "String",
-    /* 32 */ "LiteralString",
-    /* 33 */ "PercentScanner",
-    /* 34 */ "Comma",
-    /* 35 */ "PercentSc",
-    /* 36 */ "PercentPush",
-    /* 37 */ "PercentPop",
-    /* 38 */ "CutOperator",
-    /* 39 */ "Regex",
-    /* 40 */ "Error",
+    /* 11 */ "PercentInclude",
+    /* 12 */ "PercentKeywords",
+    /* 13 */ "PercentDerive",
+    /* 14 */ "PercentInline",
+    /* 15 */ "PercentDisplay",
+    /* 16 */ "ExpectDirective",
+    /* 17 */ "PercentLeft",
+    /* 18 */ "PercentRight",
+    /* 19 */ "PercentNonassoc",
+    /* 20 */ "PercentLineUnderscoreComment",
+    /* 21 */ "PercentBlockUnderscoreComment",
+    /* 22 */ "PercentNestedUnderscoreBlockUnderscoreComment",
+    /* 23 */ "PercentAutoUnderscoreNewlineUnderscoreOff",
+    /* 24 */ "PercentAutoUnderscoreWsUnderscoreOff",
+    /* 25 */ "PercentLongestUnderscoreMatch",
+    /* 26 */ "PercentCaseUnderscoreInsensitive",
+    /* 27 */ "PercentOffside",
+    /* 28 */ "PercentOn",
+    /* 29 */ "PercentEnter",
+    /* 30 */ "PercentPercent",
+    /* 31 */ "DoubleColon",
+    /* 32 */ "Colon",
+    /* 33 */ "Comma",
+    /* 34 */ "LT",
+    /* 35 */ "GT",
+    /* 36 */ "Semicolon",
+    /* 37 */ "Or",
+    /* 38 */ "PercentPrec",
+    /* 39 */ "Hash",
+    /* 40 */ "QuestEqu",
+    /* 41 */ "LParen",
+    /* 42 */ "RParen",
+    /* 43 */ "LBracket",
+    /* 44 */ "RBracket",
+    /* 45 */ "LBrace",
+    /* 46 */ "RBrace",
+    /* 47 */ "Identifier",
+    /* 48 */ "Number",
+    /* 49 */ "String",
+    /* 50 */ "LiteralString",
+    /* 51 */ "PercentScanner",
+    /* 52 */ "PercentSc",
+    /* 53 */ "PercentPush",
+    /* 54 */ "PercentPop",
+    /* 55 */ "CutOperator",
+    /* 56 */ "Percent",
+    /* 57 */ "OneOrMore",
+    /* 58 */ "Regex",
+    /* 59 */ "Error",
 ];
 
 /* SCANNER_0: "INITIAL" */
-const SCANNER_0: (&[&str; 5], &[TerminalIndex; 35]) = (
+const SCANNER_0: (&[&str; 5], &[TerminalIndex; 54]) = (
     &[
         /*  0 */ UNMATCHABLE_TOKEN,
         /*  1 */ NEW_LINE_TOKEN,
@@ -122,147 +160,280 @@ const SCANNER_0: (&[&str; 5], &[TerminalIndex; 35]) = (
         8,  /* PercentUserUnderscoreType */
         9,  /* Equ */
         10, /* PercentGrammarUnderscoreType */
-        11, /* PercentLineUnderscoreComment */
-        12, /* PercentBlockUnderscoreComment */
-        13, /* PercentAutoUnderscoreNewlineUnderscoreOff */
-        14, /* PercentAutoUnderscoreWsUnderscoreOff */
-        15, /* PercentOn */
-        16, /* PercentEnter */
-        17, /* PercentPercent */
-        18, /* DoubleColon */
-        19, /* Colon */
-        20, /* Semicolon */
-        21, /* Or */
-        22, /* LT */
-        23, /* GT */
-        24, /* LParen */
-        25, /* RParen */
-        26, /* LBracket */
-        27, /* RBracket */
-        28, /* LBrace */
-        29, /* RBrace */
-        30, /* Identifier */
-        31, /* String */
-        32, /* LiteralString */
-        33, /* PercentScanner */
-        34, /* Comma */
-        35, /* PercentSc */
-        36, /* PercentPush */
-        37, /* PercentPop */
-        38, /* CutOperator */
-        39, /* Regex */
+        11, /* PercentInclude */
+        12, /* PercentKeywords */
+        13, /* PercentDerive */
+        14, /* PercentInline */
+        15, /* PercentDisplay */
+        16, /* ExpectDirective */
+        17, /* PercentLeft */
+        18, /* PercentRight */
+        19, /* PercentNonassoc */
+        20, /* PercentLineUnderscoreComment */
+        21, /* PercentBlockUnderscoreComment */
+        22, /* PercentNestedUnderscoreBlockUnderscoreComment */
+        23, /* PercentAutoUnderscoreNewlineUnderscoreOff */
+        24, /* PercentAutoUnderscoreWsUnderscoreOff */
+        25, /* PercentLongestUnderscoreMatch */
+        26, /* PercentCaseUnderscoreInsensitive */
+        27, /* PercentOffside */
+        28, /* PercentOn */
+        29, /* PercentEnter */
+        30, /* PercentPercent */
+        31, /* DoubleColon */
+        32, /* Colon */
+        33, /* Comma */
+        34, /* LT */
+        35, /* GT */
+        36, /* Semicolon */
+        37, /* Or */
+        38, /* PercentPrec */
+        39, /* Hash */
+        40, /* QuestEqu */
+        41, /* LParen */
+        42, /* RParen */
+        43, /* LBracket */
+        44, /* RBracket */
+        45, /* LBrace */
+        46, /* RBrace */
+        47, /* Identifier */
+        48, /* Number */
+        49, /* String */
+        50, /* LiteralString */
+        51, /* PercentScanner */
+        52, /* PercentSc */
+        53, /* PercentPush */
+        54, /* PercentPop */
+        55, /* CutOperator */
+        56, /* Percent */
+        57, /* OneOrMore */
+        58, /* Regex */
     ],
 );
 
-const MAX_K: usize = 1;
+const MAX_K: usize = 2;
 
-pub const NON_TERMINALS: &[&str; 43] = &[
+pub const NON_TERMINALS: &[&str; 72] = &[
     /*  0 */ "ASTControl",
     /*  1 */ "Alternation",
-    /*  2 */ "AlternationList",
-    /*  3 */ "Alternations",
-    /*  4 */ "AlternationsList",
-    /*  5 */ "CutOperator",
-    /*  6 */ "Declaration",
-    /*  7 */ "DoubleColon",
-    /*  8 */ "Factor",
-    /*  9 */ "GrammarDefinition",
-    /* 10 */ "GrammarDefinitionList",
-    /* 11 */ "Group",
-    /* 12 */ "Identifier",
-    /* 13 */ "IdentifierList",
-    /* 14 */ "IdentifierListList",
-    /* 15 */ "LiteralString",
-    /* 16 */ "NonTerminal",
-    /* 17 */ "NonTerminalOpt",
-    /* 18 */ "Optional",
-    /* 19 */ "ParolLs",
-    /* 20 */ "Production",
-    /* 21 */ "ProductionLHS",
-    /* 22 */ "Prolog",
-    /* 23 */ "PrologList",
-    /* 24 */ "PrologList0",
-    /* 25 */ "Regex",
-    /* 26 */ "Repeat",
-    /* 27 */ "ScannerDirectives",
-    /* 28 */ "ScannerState",
-    /* 29 */ "ScannerStateList",
-    /* 30 */ "ScannerSwitch",
-    /* 31 */ "ScannerSwitchOpt",
-    /* 32 */ "SimpleToken",
-    /* 33 */ "SimpleTokenOpt",
-    /* 34 */ "StartDeclaration",
-    /* 35 */ "String",
-    /* 36 */ "Symbol",
-    /* 37 */ "TokenLiteral",
-    /* 38 */ "TokenWithStates",
-    /* 39 */ "TokenWithStatesOpt",
-    /* 40 */ "UserTypeDeclaration",
-    /* 41 */ "UserTypeName",
-    /* 42 */ "UserTypeNameList",
+    /*  2 */ "AlternationLabel",
+    /*  3 */ "AlternationList",
+    /*  4 */ "AlternationOpt",
+    /*  5 */ "AlternationOpt0",
+    /*  6 */ "Alternations",
+    /*  7 */ "AlternationsList",
+    /*  8 */ "AngleArguments",
+    /*  9 */ "AngleArgumentsOpt",
+    /* 10 */ "Associativity",
+    /* 11 */ "CutOperator",
+    /* 12 */ "Declaration",
+    /* 13 */ "DeclarationOpt",
+    /* 14 */ "DeriveList",
+    /* 15 */ "DeriveListList",
+    /* 16 */ "DeriveTarget",
+    /* 17 */ "DoubleColon",
+    /* 18 */ "ExpectDirective",
+    /* 19 */ "Factor",
+    /* 20 */ "FieldLabel",
+    /* 21 */ "GrammarDefinition",
+    /* 22 */ "GrammarDefinitionList",
+    /* 23 */ "Group",
+    /* 24 */ "Identifier",
+    /* 25 */ "IdentifierList",
+    /* 26 */ "IdentifierListList",
+    /* 27 */ "LiteralString",
+    /* 28 */ "NonTerminal",
+    /* 29 */ "NonTerminalOpt",
+    /* 30 */ "Number",
+    /* 31 */ "OneOrMore",
+    /* 32 */ "Optional",
+    /* 33 */ "ParolLs",
+    /* 34 */ "PrecedenceDeclaration",
+    /* 35 */ "PrecedenceDeclarationList",
+    /* 36 */ "PrecedenceSymbol",
+    /* 37 */ "Production",
+    /* 38 */ "ProductionLHS",
+    /* 39 */ "ProductionName",
+    /* 40 */ "ProductionNameOpt",
+    /* 41 */ "Prolog",
+    /* 42 */ "PrologList",
+    /* 43 */ "PrologList0",
+    /* 44 */ "Regex",
+    /* 45 */ "Repeat",
+    /* 46 */ "RepeatOpt",
+    /* 47 */ "RepeatOpt0",
+    /* 48 */ "RepeatSeparator",
+    /* 49 */ "RepeatSeparatorList",
+    /* 50 */ "ScannerDirectives",
+    /* 51 */ "ScannerState",
+    /* 52 */ "ScannerStateList",
+    /* 53 */ "ScannerSwitch",
+    /* 54 */ "ScannerSwitchOpt",
+    /* 55 */ "SimpleToken",
+    /* 56 */ "SimpleTokenOpt",
+    /* 57 */ "StartDeclaration",
+    /* 58 */ "String",
+    /* 59 */ "Symbol",
+    /* 60 */ "TemplateArgument",
+    /* 61 */ "TemplateArgumentList",
+    /* 62 */ "TemplateArguments",
+    /* 63 */ "TemplateArgumentsList",
+    /* 64 */ "TemplateParameters",
+    /* 65 */ "TokenExpression",
+    /* 66 */ "TokenExpressionOpt",
+    /* 67 */ "TokenLiteral",
+    /* 68 */ "TrailingContext",
+    /* 69 */ "UserTypeDeclaration",
+    /* 70 */ "UserTypeName",
+    /* 71 */ "UserTypeNameList",
 ];
 
-pub const LOOKAHEAD_AUTOMATA: &[LookaheadDFA; 43] = &[
+pub const LOOKAHEAD_AUTOMATA: &[LookaheadDFA; 72] = &[
     /* 0 - "ASTControl" */
     LookaheadDFA {
         prod0: -1,
-        transitions: &[Trans(0, 19, 2, 67), Trans(0, 38, 1, 66)],
+        transitions: &[Trans(0, 32, 2, 119), Trans(0, 55, 1, 118)],
         k: 1,
     },
     /* 1 - "Alternation" */
     LookaheadDFA {
-        prod0: 26,
+        prod0: 56,
         transitions: &[],
         k: 0,
     },
-    /* 2 - "AlternationList" */
+    /* 2 - "AlternationLabel" */
+    LookaheadDFA {
+        prod0: 63,
+        transitions: &[],
+        k: 0,
+    },
+    /* 3 - "AlternationList" */
+    LookaheadDFA {
+        prod0: -1,
+        transitions: &[
+            Trans(0, 34, 1, 57),
+            Trans(0, 36, 2, 58),
+            Trans(0, 37, 2, 58),
+            Trans(0, 38, 2, 58),
+            Trans(0, 39, 2, 58),
+            Trans(0, 41, 1, 57),
+            Trans(0, 42, 2, 58),
+            Trans(0, 43, 1, 57),
+            Trans(0, 44, 2, 58),
+            Trans(0, 45, 1, 57),
+            Trans(0, 46, 2, 58),
+            Trans(0, 47, 1, 57),
+            Trans(0, 49, 1, 57),
+            Trans(0, 50, 1, 57),
+            Trans(0, 52, 1, 57),
+            Trans(0, 53, 1, 57),
+            Trans(0, 54, 1, 57),
+            Trans(0, 56, 2, 58),
+            Trans(0, 58, 1, 57),
+        ],
+        k: 1,
+    },
+    /* 4 - "AlternationOpt" */
+    LookaheadDFA {
+        prod0: -1,
+        transitions: &[
+            Trans(0, 36, 2, 62),
+            Trans(0, 37, 2, 62),
+            Trans(0, 38, 1, 61),
+            Trans(0, 39, 2, 62),
+            Trans(0, 42, 2, 62),
+            Trans(0, 44, 2, 62),
+            Trans(0, 46, 2, 62),
+            Trans(0, 56, 2, 62),
+        ],
+        k: 1,
+    },
+    /* 5 - "AlternationOpt0" */
+    LookaheadDFA {
+        prod0: -1,
+        transitions: &[
+            Trans(0, 36, 2, 60),
+            Trans(0, 37, 2, 60),
+            Trans(0, 39, 1, 59),
+            Trans(0, 42, 2, 60),
+            Trans(0, 44, 2, 60),
+            Trans(0, 46, 2, 60),
+            Trans(0, 56, 2, 60),
+        ],
+        k: 1,
+    },
+    /* 6 - "Alternations" */
+    LookaheadDFA {
+        prod0: 53,
+        transitions: &[],
+        k: 0,
+    },
+    /* 7 - "AlternationsList" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 20, 2, 28),
-            Trans(0, 21, 2, 28),
-            Trans(0, 22, 1, 27),
-            Trans(0, 24, 1, 27),
-            Trans(0, 25, 2, 28),
-            Trans(0, 26, 1, 27),
-            Trans(0, 27, 2, 28),
-            Trans(0, 28, 1, 27),
-            Trans(0, 29, 2, 28),
-            Trans(0, 30, 1, 27),
-            Trans(0, 31, 1, 27),
-            Trans(0, 32, 1, 27),
-            Trans(0, 35, 1, 27),
-            Trans(0, 36, 1, 27),
-            Trans(0, 37, 1, 27),
-            Trans(0, 39, 1, 27),
+            Trans(0, 36, 2, 55),
+            Trans(0, 37, 1, 54),
+            Trans(0, 42, 2, 55),
+            Trans(0, 44, 2, 55),
+            Trans(0, 46, 2, 55),
+            Trans(0, 56, 2, 55),
         ],
         k: 1,
     },
-    /* 3 - "Alternations" */
+    /* 8 - "AngleArguments" */
     LookaheadDFA {
-        prod0: 23,
+        prod0: 94,
         transitions: &[],
         k: 0,
     },
-    /* 4 - "AlternationsList" */
+    /* 9 - "AngleArgumentsOpt" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 20, 2, 25),
-            Trans(0, 21, 1, 24),
-            Trans(0, 25, 2, 25),
-            Trans(0, 27, 2, 25),
-            Trans(0, 29, 2, 25),
+            Trans(0, 32, 1, 95),
+            Trans(0, 33, 2, 96),
+            Trans(0, 34, 2, 96),
+            Trans(0, 35, 2, 96),
+            Trans(0, 36, 2, 96),
+            Trans(0, 37, 2, 96),
+            Trans(0, 38, 2, 96),
+            Trans(0, 39, 2, 96),
+            Trans(0, 41, 2, 96),
+            Trans(0, 42, 2, 96),
+            Trans(0, 43, 2, 96),
+            Trans(0, 44, 2, 96),
+            Trans(0, 45, 2, 96),
+            Trans(0, 46, 2, 96),
+            Trans(0, 47, 2, 96),
+            Trans(0, 49, 2, 96),
+            Trans(0, 50, 2, 96),
+            Trans(0, 52, 2, 96),
+            Trans(0, 53, 2, 96),
+            Trans(0, 54, 2, 96),
+            Trans(0, 55, 1, 95),
+            Trans(0, 56, 2, 96),
+            Trans(0, 58, 2, 96),
         ],
         k: 1,
     },
-    /* 5 - "CutOperator" */
+    /* 10 - "Associativity" */
     LookaheadDFA {
-        prod0: 68,
+        prod0: -1,
+        transitions: &[
+            Trans(0, 17, 1, 25),
+            Trans(0, 18, 2, 26),
+            Trans(0, 19, 3, 27),
+        ],
+        k: 1,
+    },
+    /* 11 - "CutOperator" */
+    LookaheadDFA {
+        prod0: 120,
         transitions: &[],
         k: 0,
     },
-    /* 6 - "Declaration" */
+    /* 12 - "Declaration" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
@@ -271,145 +442,460 @@ pub const LOOKAHEAD_AUTOMATA: &[LookaheadDFA; 43] = &[
             Trans(0, 8, 3, 9),
             Trans(0, 10, 4, 10),
             Trans(0, 11, 5, 11),
-            Trans(0, 12, 5, 11),
-            Trans(0, 13, 5, 11),
-            Trans(0, 14, 5, 11),
-            Trans(0, 15, 5, 11),
+            Trans(0, 12, 6, 12),
+            Trans(0, 13, 7, 13),
+            Trans(0, 14, 8, 14),
+            Trans(0, 15, 9, 15),
+            Trans(0, 16, 10, 16),
+            Trans(0, 17, 11, 17),
+            Trans(0, 18, 11, 17),
+            Trans(0, 19, 11, 17),
+            Trans(0, 20, 12, 18),
+            Trans(0, 21, 12, 18),
+            Trans(0, 22, 12, 18),
+            Trans(0, 23, 12, 18),
+            Trans(0, 24, 12, 18),
+            Trans(0, 25, 12, 18),
+            Trans(0, 26, 12, 18),
+            Trans(0, 27, 12, 18),
+            Trans(0, 28, 12, 18),
         ],
         k: 1,
     },
-    /* 7 - "DoubleColon" */
+    /* 13 - "DeclarationOpt" */
+    LookaheadDFA {
+        prod0: -1,
+        transitions: &[
+            Trans(0, 47, 1, -1),
+            Trans(1, 6, 3, 20),
+            Trans(1, 7, 3, 20),
+            Trans(1, 8, 3, 20),
+            Trans(1, 10, 3, 20),
+            Trans(1, 11, 3, 20),
+            Trans(1, 12, 3, 20),
+            Trans(1, 13, 3, 20),
+            Trans(1, 14, 3, 20),
+            Trans(1, 15, 3, 20),
+            Trans(1, 16, 3, 20),
+            Trans(1, 17, 3, 20),
+            Trans(1, 18, 3, 20),
+            Trans(1, 19, 3, 20),
+            Trans(1, 20, 3, 20),
+            Trans(1, 21, 3, 20),
+            Trans(1, 22, 3, 20),
+            Trans(1, 23, 3, 20),
+            Trans(1, 24, 3, 20),
+            Trans(1, 25, 3, 20),
+            Trans(1, 26, 3, 20),
+            Trans(1, 27, 3, 20),
+            Trans(1, 28, 3, 20),
+            Trans(1, 30, 3, 20),
+            Trans(1, 31, 3, 20),
+            Trans(1, 32, 2, 19),
+            Trans(1, 33, 3, 20),
+            Trans(1, 51, 3, 20),
+        ],
+        k: 2,
+    },
+    /* 14 - "DeriveList" */
     LookaheadDFA {
-        prod0: 20,
+        prod0: 44,
         transitions: &[],
         k: 0,
     },
-    /* 8 - "Factor" */
+    /* 15 - "DeriveListList" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 22, 4, 32),
-            Trans(0, 24, 1, 29),
-            Trans(0, 26, 3, 31),
-            Trans(0, 28, 2, 30),
-            Trans(0, 30, 4, 32),
-            Trans(0, 31, 4, 32),
-            Trans(0, 32, 4, 32),
-            Trans(0, 35, 4, 32),
-            Trans(0, 36, 4, 32),
-            Trans(0, 37, 4, 32),
-            Trans(0, 39, 4, 32),
+            Trans(0, 6, 2, 46),
+            Trans(0, 7, 2, 46),
+            Trans(0, 8, 2, 46),
+            Trans(0, 10, 2, 46),
+            Trans(0, 11, 2, 46),
+            Trans(0, 12, 2, 46),
+            Trans(0, 13, 2, 46),
+            Trans(0, 14, 2, 46),
+            Trans(0, 15, 2, 46),
+            Trans(0, 16, 2, 46),
+            Trans(0, 17, 2, 46),
+            Trans(0, 18, 2, 46),
+            Trans(0, 19, 2, 46),
+            Trans(0, 20, 2, 46),
+            Trans(0, 21, 2, 46),
+            Trans(0, 22, 2, 46),
+            Trans(0, 23, 2, 46),
+            Trans(0, 24, 2, 46),
+            Trans(0, 25, 2, 46),
+            Trans(0, 26, 2, 46),
+            Trans(0, 27, 2, 46),
+            Trans(0, 28, 2, 46),
+            Trans(0, 30, 2, 46),
+            Trans(0, 33, 1, 45),
+            Trans(0, 51, 2, 46),
         ],
         k: 1,
     },
-    /* 9 - "GrammarDefinition" */
+    /* 16 - "DeriveTarget" */
+    LookaheadDFA {
+        prod0: 43,
+        transitions: &[],
+        k: 0,
+    },
+    /* 17 - "DoubleColon" */
     LookaheadDFA {
-        prod0: 17,
+        prod0: 42,
+        transitions: &[],
+        k: 0,
+    },
+    /* 18 - "ExpectDirective" */
+    LookaheadDFA {
+        prod0: 21,
         transitions: &[],
         k: 0,
     },
-    /* 10 - "GrammarDefinitionList" */
+    /* 19 - "Factor" */
     LookaheadDFA {
         prod0: -1,
-        transitions: &[Trans(0, 0, 2, 19), Trans(0, 30, 1, 18)],
+        transitions: &[
+            Trans(0, 34, 7, -1),
+            Trans(0, 41, 1, -1),
+            Trans(0, 43, 5, -1),
+            Trans(0, 45, 3, -1),
+            Trans(0, 47, 8, -1),
+            Trans(0, 49, 9, -1),
+            Trans(0, 50, 9, -1),
+            Trans(0, 52, 10, -1),
+            Trans(0, 53, 10, -1),
+            Trans(0, 54, 10, -1),
+            Trans(0, 58, 9, -1),
+            Trans(1, 34, 2, 64),
+            Trans(1, 37, 2, 64),
+            Trans(1, 38, 2, 64),
+            Trans(1, 39, 2, 64),
+            Trans(1, 41, 2, 64),
+            Trans(1, 42, 2, 64),
+            Trans(1, 43, 2, 64),
+            Trans(1, 45, 2, 64),
+            Trans(1, 47, 2, 64),
+            Trans(1, 49, 2, 64),
+            Trans(1, 50, 2, 64),
+            Trans(1, 52, 2, 64),
+            Trans(1, 53, 2, 64),
+            Trans(1, 54, 2, 64),
+            Trans(1, 58, 2, 64),
+            Trans(3, 34, 4, 65),
+            Trans(3, 37, 4, 65),
+            Trans(3, 38, 4, 65),
+            Trans(3, 39, 4, 65),
+            Trans(3, 41, 4, 65),
+            Trans(3, 43, 4, 65),
+            Trans(3, 45, 4, 65),
+            Trans(3, 46, 4, 65),
+            Trans(3, 47, 4, 65),
+            Trans(3, 49, 4, 65),
+            Trans(3, 50, 4, 65),
+            Trans(3, 52, 4, 65),
+            Trans(3, 53, 4, 65),
+            Trans(3, 54, 4, 65),
+            Trans(3, 56, 4, 65),
+            Trans(3, 58, 4, 65),
+            Trans(5, 34, 6, 66),
+            Trans(5, 37, 6, 66),
+            Trans(5, 38, 6, 66),
+            Trans(5, 39, 6, 66),
+            Trans(5, 41, 6, 66),
+            Trans(5, 43, 6, 66),
+            Trans(5, 44, 6, 66),
+            Trans(5, 45, 6, 66),
+            Trans(5, 47, 6, 66),
+            Trans(5, 49, 6, 66),
+            Trans(5, 50, 6, 66),
+            Trans(5, 52, 6, 66),
+            Trans(5, 53, 6, 66),
+            Trans(5, 54, 6, 66),
+            Trans(5, 58, 6, 66),
+            Trans(7, 34, 11, 67),
+            Trans(7, 41, 11, 67),
+            Trans(7, 43, 11, 67),
+            Trans(7, 45, 11, 67),
+            Trans(7, 47, 11, 67),
+            Trans(7, 49, 11, 67),
+            Trans(7, 50, 11, 67),
+            Trans(7, 52, 11, 67),
+            Trans(7, 53, 11, 67),
+            Trans(7, 54, 11, 67),
+            Trans(7, 58, 11, 67),
+            Trans(8, 9, 12, 68),
+            Trans(8, 32, 11, 67),
+            Trans(8, 33, 11, 67),
+            Trans(8, 34, 11, 67),
+            Trans(8, 35, 11, 67),
+            Trans(8, 36, 11, 67),
+            Trans(8, 37, 11, 67),
+            Trans(8, 38, 11, 67),
+            Trans(8, 39, 11, 67),
+            Trans(8, 41, 11, 67),
+            Trans(8, 42, 11, 67),
+            Trans(8, 43, 11, 67),
+            Trans(8, 44, 11, 67),
+            Trans(8, 45, 11, 67),
+            Trans(8, 46, 11, 67),
+            Trans(8, 47, 11, 67),
+            Trans(8, 49, 11, 67),
+            Trans(8, 50, 11, 67),
+            Trans(8, 52, 11, 67),
+            Trans(8, 53, 11, 67),
+            Trans(8, 54, 11, 67),
+            Trans(8, 55, 11, 67),
+            Trans(8, 56, 11, 67),
+            Trans(8, 58, 11, 67),
+            Trans(9, 32, 11, 67),
+            Trans(9, 33, 11, 67),
+            Trans(9, 34, 11, 67),
+            Trans(9, 35, 11, 67),
+            Trans(9, 36, 11, 67),
+            Trans(9, 37, 11, 67),
+            Trans(9, 38, 11, 67),
+            Trans(9, 39, 11, 67),
+            Trans(9, 40, 11, 67),
+            Trans(9, 41, 11, 67),
+            Trans(9, 42, 11, 67),
+            Trans(9, 43, 11, 67),
+            Trans(9, 44, 11, 67),
+            Trans(9, 45, 11, 67),
+            Trans(9, 46, 11, 67),
+            Trans(9, 47, 11, 67),
+            Trans(9, 49, 11, 67),
+            Trans(9, 50, 11, 67),
+            Trans(9, 52, 11, 67),
+            Trans(9, 53, 11, 67),
+            Trans(9, 54, 11, 67),
+            Trans(9, 55, 11, 67),
+            Trans(9, 56, 11, 67),
+            Trans(9, 58, 11, 67),
+            Trans(10, 41, 11, 67),
+        ],
+        k: 2,
+    },
+    /* 20 - "FieldLabel" */
+    LookaheadDFA {
+        prod0: 69,
+        transitions: &[],
+        k: 0,
+    },
+    /* 21 - "GrammarDefinition" */
+    LookaheadDFA {
+        prod0: 39,
+        transitions: &[],
+        k: 0,
+    },
+    /* 22 - "GrammarDefinitionList" */
+    LookaheadDFA {
+        prod0: -1,
+        transitions: &[Trans(0, 0, 2, 41), Trans(0, 47, 1, 40)],
         k: 1,
     },
-    /* 11 - "Group" */
+    /* 23 - "Group" */
     LookaheadDFA {
-        prod0: 46,
+        prod0: 84,
         transitions: &[],
         k: 0,
     },
-    /* 12 - "Identifier" */
+    /* 24 - "Identifier" */
     LookaheadDFA {
-        prod0: 52,
+        prod0: 103,
         transitions: &[],
         k: 0,
     },
-    /* 13 - "IdentifierList" */
+    /* 25 - "IdentifierList" */
     LookaheadDFA {
-        prod0: 58,
+        prod0: 110,
         transitions: &[],
         k: 0,
     },
-    /* 14 - "IdentifierListList" */
+    /* 26 - "IdentifierListList" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 16, 2, 60),
-            Trans(0, 23, 2, 60),
-            Trans(0, 34, 1, 59),
+            Trans(0, 6, 2, 112),
+            Trans(0, 7, 2, 112),
+            Trans(0, 8, 2, 112),
+            Trans(0, 10, 2, 112),
+            Trans(0, 11, 2, 112),
+            Trans(0, 12, 2, 112),
+            Trans(0, 13, 2, 112),
+            Trans(0, 14, 2, 112),
+            Trans(0, 15, 2, 112),
+            Trans(0, 16, 2, 112),
+            Trans(0, 17, 2, 112),
+            Trans(0, 18, 2, 112),
+            Trans(0, 19, 2, 112),
+            Trans(0, 20, 2, 112),
+            Trans(0, 21, 2, 112),
+            Trans(0, 22, 2, 112),
+            Trans(0, 23, 2, 112),
+            Trans(0, 24, 2, 112),
+            Trans(0, 25, 2, 112),
+            Trans(0, 26, 2, 112),
+            Trans(0, 27, 2, 112),
+            Trans(0, 28, 2, 112),
+            Trans(0, 29, 2, 112),
+            Trans(0, 30, 2, 112),
+            Trans(0, 33, 1, 111),
+            Trans(0, 35, 2, 112),
+            Trans(0, 51, 2, 112),
         ],
         k: 1,
     },
-    /* 15 - "LiteralString" */
+    /* 27 - "LiteralString" */
     LookaheadDFA {
-        prod0: 54,
+        prod0: 106,
         transitions: &[],
         k: 0,
     },
-    /* 16 - "NonTerminal" */
+    /* 28 - "NonTerminal" */
     LookaheadDFA {
-        prod0: 49,
+        prod0: 91,
         transitions: &[],
         k: 0,
     },
-    /* 17 - "NonTerminalOpt" */
+    /* 29 - "NonTerminalOpt" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 19, 1, 50),
-            Trans(0, 20, 2, 51),
-            Trans(0, 21, 2, 51),
-            Trans(0, 22, 2, 51),
-            Trans(0, 24, 2, 51),
-            Trans(0, 25, 2, 51),
-            Trans(0, 26, 2, 51),
-            Trans(0, 27, 2, 51),
-            Trans(0, 28, 2, 51),
-            Trans(0, 29, 2, 51),
-            Trans(0, 30, 2, 51),
-            Trans(0, 31, 2, 51),
-            Trans(0, 32, 2, 51),
-            Trans(0, 35, 2, 51),
-            Trans(0, 36, 2, 51),
-            Trans(0, 37, 2, 51),
-            Trans(0, 38, 1, 50),
-            Trans(0, 39, 2, 51),
+            Trans(0, 32, 1, 92),
+            Trans(0, 33, 2, 93),
+            Trans(0, 34, 2, 93),
+            Trans(0, 35, 2, 93),
+            Trans(0, 36, 2, 93),
+            Trans(0, 37, 2, 93),
+            Trans(0, 38, 2, 93),
+            Trans(0, 39, 2, 93),
+            Trans(0, 41, 2, 93),
+            Trans(0, 42, 2, 93),
+            Trans(0, 43, 2, 93),
+            Trans(0, 44, 2, 93),
+            Trans(0, 45, 2, 93),
+            Trans(0, 46, 2, 93),
+            Trans(0, 47, 2, 93),
+            Trans(0, 49, 2, 93),
+            Trans(0, 50, 2, 93),
+            Trans(0, 52, 2, 93),
+            Trans(0, 53, 2, 93),
+            Trans(0, 54, 2, 93),
+            Trans(0, 55, 1, 92),
+            Trans(0, 56, 2, 93),
+            Trans(0, 58, 2, 93),
         ],
         k: 1,
     },
-    /* 18 - "Optional" */
+    /* 30 - "Number" */
     LookaheadDFA {
-        prod0: 47,
+        prod0: 104,
+        transitions: &[],
+        k: 0,
+    },
+    /* 31 - "OneOrMore" */
+    LookaheadDFA {
+        prod0: 128,
+        transitions: &[],
+        k: 0,
+    },
+    /* 32 - "Optional" */
+    LookaheadDFA {
+        prod0: 85,
         transitions: &[],
         k: 0,
     },
-    /* 19 - "ParolLs" */
+    /* 33 - "ParolLs" */
     LookaheadDFA {
         prod0: 0,
         transitions: &[],
         k: 0,
     },
-    /* 20 - "Production" */
+    /* 34 - "PrecedenceDeclaration" */
     LookaheadDFA {
         prod0: 22,
         transitions: &[],
         k: 0,
     },
-    /* 21 - "ProductionLHS" */
+    /* 35 - "PrecedenceDeclarationList" */
     LookaheadDFA {
-        prod0: 21,
+        prod0: -1,
+        transitions: &[
+            Trans(0, 6, 2, 24),
+            Trans(0, 7, 2, 24),
+            Trans(0, 8, 2, 24),
+            Trans(0, 10, 2, 24),
+            Trans(0, 11, 2, 24),
+            Trans(0, 12, 2, 24),
+            Trans(0, 13, 2, 24),
+            Trans(0, 14, 2, 24),
+            Trans(0, 15, 2, 24),
+            Trans(0, 16, 2, 24),
+            Trans(0, 17, 2, 24),
+            Trans(0, 18, 2, 24),
+            Trans(0, 19, 2, 24),
+            Trans(0, 20, 2, 24),
+            Trans(0, 21, 2, 24),
+            Trans(0, 22, 2, 24),
+            Trans(0, 23, 2, 24),
+            Trans(0, 24, 2, 24),
+            Trans(0, 25, 2, 24),
+            Trans(0, 26, 2, 24),
+            Trans(0, 27, 2, 24),
+            Trans(0, 28, 2, 24),
+            Trans(0, 30, 2, 24),
+            Trans(0, 47, 1, 23),
+            Trans(0, 49, 1, 23),
+            Trans(0, 50, 1, 23),
+            Trans(0, 51, 2, 24),
+            Trans(0, 58, 1, 23),
+        ],
+        k: 1,
+    },
+    /* 36 - "PrecedenceSymbol" */
+    LookaheadDFA {
+        prod0: -1,
+        transitions: &[
+            Trans(0, 47, 2, 29),
+            Trans(0, 49, 1, 28),
+            Trans(0, 50, 1, 28),
+            Trans(0, 58, 1, 28),
+        ],
+        k: 1,
+    },
+    /* 37 - "Production" */
+    LookaheadDFA {
+        prod0: 52,
+        transitions: &[],
+        k: 0,
+    },
+    /* 38 - "ProductionLHS" */
+    LookaheadDFA {
+        prod0: 47,
         transitions: &[],
         k: 0,
     },
-    /* 22 - "Prolog" */
+    /* 39 - "ProductionName" */
+    LookaheadDFA {
+        prod0: 48,
+        transitions: &[],
+        k: 0,
+    },
+    /* 40 - "ProductionNameOpt" */
+    LookaheadDFA {
+        prod0: -1,
+        transitions: &[Trans(0, 32, 2, 50), Trans(0, 34, 1, 49)],
+        k: 1,
+    },
+    /* 41 - "Prolog" */
     LookaheadDFA {
         prod0: 1,
         transitions: &[],
         k: 0,
     },
-    /* 23 - "PrologList" */
+    /* 42 - "PrologList" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
@@ -422,626 +908,1073 @@ pub const LOOKAHEAD_AUTOMATA: &[LookaheadDFA; 43] = &[
             Trans(0, 13, 1, 4),
             Trans(0, 14, 1, 4),
             Trans(0, 15, 1, 4),
-            Trans(0, 17, 2, 5),
-            Trans(0, 33, 2, 5),
+            Trans(0, 16, 1, 4),
+            Trans(0, 17, 1, 4),
+            Trans(0, 18, 1, 4),
+            Trans(0, 19, 1, 4),
+            Trans(0, 20, 1, 4),
+            Trans(0, 21, 1, 4),
+            Trans(0, 22, 1, 4),
+            Trans(0, 23, 1, 4),
+            Trans(0, 24, 1, 4),
+            Trans(0, 25, 1, 4),
+            Trans(0, 26, 1, 4),
+            Trans(0, 27, 1, 4),
+            Trans(0, 28, 1, 4),
+            Trans(0, 30, 2, 5),
+            Trans(0, 51, 2, 5),
         ],
         k: 1,
     },
-    /* 24 - "PrologList0" */
+    /* 43 - "PrologList0" */
     LookaheadDFA {
         prod0: -1,
-        transitions: &[Trans(0, 17, 2, 3), Trans(0, 33, 1, 2)],
+        transitions: &[Trans(0, 30, 2, 3), Trans(0, 51, 1, 2)],
         k: 1,
     },
-    /* 25 - "Regex" */
+    /* 44 - "Regex" */
     LookaheadDFA {
-        prod0: 73,
+        prod0: 129,
         transitions: &[],
         k: 0,
     },
-    /* 26 - "Repeat" */
+    /* 45 - "Repeat" */
     LookaheadDFA {
-        prod0: 48,
+        prod0: 86,
         transitions: &[],
         k: 0,
     },
-    /* 27 - "ScannerDirectives" */
+    /* 46 - "RepeatOpt" */
+    LookaheadDFA {
+        prod0: -1,
+        transitions: &[Trans(0, 46, 2, 90), Trans(0, 56, 1, 89)],
+        k: 1,
+    },
+    /* 47 - "RepeatOpt0" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 11, 1, 12),
-            Trans(0, 12, 2, 13),
-            Trans(0, 13, 3, 14),
-            Trans(0, 14, 4, 15),
-            Trans(0, 15, 5, 16),
+            Trans(0, 33, 2, 88),
+            Trans(0, 34, 2, 88),
+            Trans(0, 35, 2, 88),
+            Trans(0, 36, 2, 88),
+            Trans(0, 37, 2, 88),
+            Trans(0, 38, 2, 88),
+            Trans(0, 39, 2, 88),
+            Trans(0, 41, 2, 88),
+            Trans(0, 42, 2, 88),
+            Trans(0, 43, 2, 88),
+            Trans(0, 44, 2, 88),
+            Trans(0, 45, 2, 88),
+            Trans(0, 46, 2, 88),
+            Trans(0, 47, 2, 88),
+            Trans(0, 49, 2, 88),
+            Trans(0, 50, 2, 88),
+            Trans(0, 52, 2, 88),
+            Trans(0, 53, 2, 88),
+            Trans(0, 54, 2, 88),
+            Trans(0, 56, 2, 88),
+            Trans(0, 57, 1, 87),
+            Trans(0, 58, 2, 88),
         ],
         k: 1,
     },
-    /* 28 - "ScannerState" */
+    /* 48 - "RepeatSeparator" */
     LookaheadDFA {
-        prod0: 55,
+        prod0: 125,
         transitions: &[],
         k: 0,
     },
-    /* 29 - "ScannerStateList" */
+    /* 49 - "RepeatSeparatorList" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 11, 1, 56),
-            Trans(0, 12, 1, 56),
-            Trans(0, 13, 1, 56),
-            Trans(0, 14, 1, 56),
-            Trans(0, 15, 1, 56),
-            Trans(0, 29, 2, 57),
+            Trans(0, 34, 1, 126),
+            Trans(0, 46, 2, 127),
+            Trans(0, 47, 1, 126),
+            Trans(0, 49, 1, 126),
+            Trans(0, 50, 1, 126),
+            Trans(0, 52, 1, 126),
+            Trans(0, 53, 1, 126),
+            Trans(0, 54, 1, 126),
+            Trans(0, 58, 1, 126),
         ],
         k: 1,
     },
-    /* 30 - "ScannerSwitch" */
+    /* 50 - "ScannerDirectives" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 35, 1, 61),
-            Trans(0, 36, 2, 62),
-            Trans(0, 37, 3, 63),
+            Trans(0, 20, 1, 30),
+            Trans(0, 21, 2, 31),
+            Trans(0, 22, 3, 32),
+            Trans(0, 23, 4, 33),
+            Trans(0, 24, 5, 34),
+            Trans(0, 25, 6, 35),
+            Trans(0, 26, 7, 36),
+            Trans(0, 27, 8, 37),
+            Trans(0, 28, 9, 38),
         ],
         k: 1,
     },
-    /* 31 - "ScannerSwitchOpt" */
+    /* 51 - "ScannerState" */
+    LookaheadDFA {
+        prod0: 107,
+        transitions: &[],
+        k: 0,
+    },
+    /* 52 - "ScannerStateList" */
     LookaheadDFA {
         prod0: -1,
-        transitions: &[Trans(0, 25, 2, 65), Trans(0, 30, 1, 64)],
+        transitions: &[
+            Trans(0, 20, 1, 108),
+            Trans(0, 21, 1, 108),
+            Trans(0, 22, 1, 108),
+            Trans(0, 23, 1, 108),
+            Trans(0, 24, 1, 108),
+            Trans(0, 25, 1, 108),
+            Trans(0, 26, 1, 108),
+            Trans(0, 27, 1, 108),
+            Trans(0, 28, 1, 108),
+            Trans(0, 46, 2, 109),
+        ],
+        k: 1,
+    },
+    /* 53 - "ScannerSwitch" */
+    LookaheadDFA {
+        prod0: -1,
+        transitions: &[
+            Trans(0, 52, 1, 113),
+            Trans(0, 53, 2, 114),
+            Trans(0, 54, 3, 115),
+        ],
         k: 1,
     },
-    /* 32 - "SimpleToken" */
+    /* 54 - "ScannerSwitchOpt" */
     LookaheadDFA {
-        prod0: 40,
+        prod0: -1,
+        transitions: &[Trans(0, 42, 2, 117), Trans(0, 47, 1, 116)],
+        k: 1,
+    },
+    /* 55 - "SimpleToken" */
+    LookaheadDFA {
+        prod0: 81,
         transitions: &[],
         k: 0,
     },
-    /* 33 - "SimpleTokenOpt" */
+    /* 56 - "SimpleTokenOpt" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 19, 1, 41),
-            Trans(0, 20, 2, 42),
-            Trans(0, 21, 2, 42),
-            Trans(0, 22, 2, 42),
-            Trans(0, 24, 2, 42),
-            Trans(0, 25, 2, 42),
-            Trans(0, 26, 2, 42),
-            Trans(0, 27, 2, 42),
-            Trans(0, 28, 2, 42),
-            Trans(0, 29, 2, 42),
-            Trans(0, 30, 2, 42),
-            Trans(0, 31, 2, 42),
-            Trans(0, 32, 2, 42),
-            Trans(0, 35, 2, 42),
-            Trans(0, 36, 2, 42),
-            Trans(0, 37, 2, 42),
-            Trans(0, 38, 1, 41),
-            Trans(0, 39, 2, 42),
+            Trans(0, 32, 1, 82),
+            Trans(0, 33, 2, 83),
+            Trans(0, 34, 2, 83),
+            Trans(0, 35, 2, 83),
+            Trans(0, 36, 2, 83),
+            Trans(0, 37, 2, 83),
+            Trans(0, 38, 2, 83),
+            Trans(0, 39, 2, 83),
+            Trans(0, 41, 2, 83),
+            Trans(0, 42, 2, 83),
+            Trans(0, 43, 2, 83),
+            Trans(0, 44, 2, 83),
+            Trans(0, 45, 2, 83),
+            Trans(0, 46, 2, 83),
+            Trans(0, 47, 2, 83),
+            Trans(0, 49, 2, 83),
+            Trans(0, 50, 2, 83),
+            Trans(0, 52, 2, 83),
+            Trans(0, 53, 2, 83),
+            Trans(0, 54, 2, 83),
+            Trans(0, 55, 1, 82),
+            Trans(0, 56, 2, 83),
+            Trans(0, 58, 2, 83),
         ],
         k: 1,
     },
-    /* 34 - "StartDeclaration" */
+    /* 57 - "StartDeclaration" */
     LookaheadDFA {
         prod0: 6,
         transitions: &[],
         k: 0,
     },
-    /* 35 - "String" */
+    /* 58 - "String" */
     LookaheadDFA {
-        prod0: 53,
+        prod0: 105,
         transitions: &[],
         k: 0,
     },
-    /* 36 - "Symbol" */
+    /* 59 - "Symbol" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 22, 3, 35),
-            Trans(0, 30, 1, 33),
-            Trans(0, 31, 2, 34),
-            Trans(0, 32, 2, 34),
-            Trans(0, 35, 4, 36),
-            Trans(0, 36, 4, 36),
-            Trans(0, 37, 4, 36),
-            Trans(0, 39, 2, 34),
+            Trans(0, 34, 3, 72),
+            Trans(0, 47, 1, 70),
+            Trans(0, 49, 2, 71),
+            Trans(0, 50, 2, 71),
+            Trans(0, 52, 4, 73),
+            Trans(0, 53, 4, 73),
+            Trans(0, 54, 4, 73),
+            Trans(0, 58, 2, 71),
         ],
         k: 1,
     },
-    /* 37 - "TokenLiteral" */
+    /* 60 - "TemplateArgument" */
+    LookaheadDFA {
+        prod0: 100,
+        transitions: &[],
+        k: 0,
+    },
+    /* 61 - "TemplateArgumentList" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 31, 1, 37),
-            Trans(0, 32, 2, 38),
-            Trans(0, 39, 3, 39),
+            Trans(0, 33, 2, 102),
+            Trans(0, 34, 1, 101),
+            Trans(0, 35, 2, 102),
+            Trans(0, 41, 1, 101),
+            Trans(0, 43, 1, 101),
+            Trans(0, 45, 1, 101),
+            Trans(0, 47, 1, 101),
+            Trans(0, 49, 1, 101),
+            Trans(0, 50, 1, 101),
+            Trans(0, 52, 1, 101),
+            Trans(0, 53, 1, 101),
+            Trans(0, 54, 1, 101),
+            Trans(0, 58, 1, 101),
         ],
         k: 1,
     },
-    /* 38 - "TokenWithStates" */
+    /* 62 - "TemplateArguments" */
     LookaheadDFA {
-        prod0: 43,
+        prod0: 97,
         transitions: &[],
         k: 0,
     },
-    /* 39 - "TokenWithStatesOpt" */
+    /* 63 - "TemplateArgumentsList" */
+    LookaheadDFA {
+        prod0: -1,
+        transitions: &[Trans(0, 33, 1, 98), Trans(0, 35, 2, 99)],
+        k: 1,
+    },
+    /* 64 - "TemplateParameters" */
+    LookaheadDFA {
+        prod0: 51,
+        transitions: &[],
+        k: 0,
+    },
+    /* 65 - "TokenExpression" */
+    LookaheadDFA {
+        prod0: 77,
+        transitions: &[],
+        k: 0,
+    },
+    /* 66 - "TokenExpressionOpt" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 19, 1, 44),
-            Trans(0, 20, 2, 45),
-            Trans(0, 21, 2, 45),
-            Trans(0, 22, 2, 45),
-            Trans(0, 24, 2, 45),
-            Trans(0, 25, 2, 45),
-            Trans(0, 26, 2, 45),
-            Trans(0, 27, 2, 45),
-            Trans(0, 28, 2, 45),
-            Trans(0, 29, 2, 45),
-            Trans(0, 30, 2, 45),
-            Trans(0, 31, 2, 45),
-            Trans(0, 32, 2, 45),
-            Trans(0, 35, 2, 45),
-            Trans(0, 36, 2, 45),
-            Trans(0, 37, 2, 45),
-            Trans(0, 38, 1, 44),
-            Trans(0, 39, 2, 45),
+            Trans(0, 32, 2, 79),
+            Trans(0, 33, 2, 79),
+            Trans(0, 34, 2, 79),
+            Trans(0, 35, 2, 79),
+            Trans(0, 36, 2, 79),
+            Trans(0, 37, 2, 79),
+            Trans(0, 38, 2, 79),
+            Trans(0, 39, 2, 79),
+            Trans(0, 40, 1, 78),
+            Trans(0, 41, 2, 79),
+            Trans(0, 42, 2, 79),
+            Trans(0, 43, 2, 79),
+            Trans(0, 44, 2, 79),
+            Trans(0, 45, 2, 79),
+            Trans(0, 46, 2, 79),
+            Trans(0, 47, 2, 79),
+            Trans(0, 49, 2, 79),
+            Trans(0, 50, 2, 79),
+            Trans(0, 52, 2, 79),
+            Trans(0, 53, 2, 79),
+            Trans(0, 54, 2, 79),
+            Trans(0, 55, 2, 79),
+            Trans(0, 56, 2, 79),
+            Trans(0, 58, 2, 79),
         ],
         k: 1,
     },
-    /* 40 - "UserTypeDeclaration" */
+    /* 67 - "TokenLiteral" */
     LookaheadDFA {
-        prod0: 69,
+        prod0: -1,
+        transitions: &[
+            Trans(0, 49, 1, 74),
+            Trans(0, 50, 2, 75),
+            Trans(0, 58, 3, 76),
+        ],
+        k: 1,
+    },
+    /* 68 - "TrailingContext" */
+    LookaheadDFA {
+        prod0: 80,
         transitions: &[],
         k: 0,
     },
-    /* 41 - "UserTypeName" */
+    /* 69 - "UserTypeDeclaration" */
     LookaheadDFA {
-        prod0: 70,
+        prod0: 121,
         transitions: &[],
         k: 0,
     },
-    /* 42 - "UserTypeNameList" */
+    /* 70 - "UserTypeName" */
+    LookaheadDFA {
+        prod0: 122,
+        transitions: &[],
+        k: 0,
+    },
+    /* 71 - "UserTypeNameList" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 6, 2, 72),
-            Trans(0, 7, 2, 72),
-            Trans(0, 8, 2, 72),
-            Trans(0, 10, 2, 72),
-            Trans(0, 11, 2, 72),
-            Trans(0, 12, 2, 72),
-            Trans(0, 13, 2, 72),
-            Trans(0, 14, 2, 72),
-            Trans(0, 15, 2, 72),
-            Trans(0, 17, 2, 72),
-            Trans(0, 18, 1, 71),
-            Trans(0, 20, 2, 72),
-            Trans(0, 21, 2, 72),
-            Trans(0, 22, 2, 72),
-            Trans(0, 24, 2, 72),
-            Trans(0, 25, 2, 72),
-            Trans(0, 26, 2, 72),
-            Trans(0, 27, 2, 72),
-            Trans(0, 28, 2, 72),
-            Trans(0, 29, 2, 72),
-            Trans(0, 30, 2, 72),
-            Trans(0, 31, 2, 72),
-            Trans(0, 32, 2, 72),
-            Trans(0, 33, 2, 72),
-            Trans(0, 35, 2, 72),
-            Trans(0, 36, 2, 72),
-            Trans(0, 37, 2, 72),
-            Trans(0, 39, 2, 72),
+            Trans(0, 6, 2, 124),
+            Trans(0, 7, 2, 124),
+            Trans(0, 8, 2, 124),
+            Trans(0, 10, 2, 124),
+            Trans(0, 11, 2, 124),
+            Trans(0, 12, 2, 124),
+            Trans(0, 13, 2, 124),
+            Trans(0, 14, 2, 124),
+            Trans(0, 15, 2, 124),
+            Trans(0, 16, 2, 124),
+            Trans(0, 17, 2, 124),
+            Trans(0, 18, 2, 124),
+            Trans(0, 19, 2, 124),
+            Trans(0, 20, 2, 124),
+            Trans(0, 21, 2, 124),
+            Trans(0, 22, 2, 124),
+            Trans(0, 23, 2, 124),
+            Trans(0, 24, 2, 124),
+            Trans(0, 25, 2, 124),
+            Trans(0, 26, 2, 124),
+            Trans(0, 27, 2, 124),
+            Trans(0, 28, 2, 124),
+            Trans(0, 30, 2, 124),
+            Trans(0, 31, 1, 123),
+            Trans(0, 33, 2, 124),
+            Trans(0, 34, 2, 124),
+            Trans(0, 35, 2, 124),
+            Trans(0, 36, 2, 124),
+            Trans(0, 37, 2, 124),
+            Trans(0, 38, 2, 124),
+            Trans(0, 39, 2, 124),
+            Trans(0, 41, 2, 124),
+            Trans(0, 42, 2, 124),
+            Trans(0, 43, 2, 124),
+            Trans(0, 44, 2, 124),
+            Trans(0, 45, 2, 124),
+            Trans(0, 46, 2, 124),
+            Trans(0, 47, 2, 124),
+            Trans(0, 49, 2, 124),
+            Trans(0, 50, 2, 124),
+            Trans(0, 51, 2, 124),
+            Trans(0, 52, 2, 124),
+            Trans(0, 53, 2, 124),
+            Trans(0, 54, 2, 124),
+            Trans(0, 56, 2, 124),
+            Trans(0, 58, 2, 124),
         ],
         k: 1,
     },
 ];
 
-pub const PRODUCTIONS: &[Production; 74] = &[
+pub const PRODUCTIONS: &[Production; 130] = &[
     // 0 - ParolLs: Prolog GrammarDefinition;
     Production {
-        lhs: 19,
-        production: &[ParseType::N(9), ParseType::N(22)],
+        lhs: 33,
+        production: &[ParseType::N(21), ParseType::N(41)],
     },
     // 1 - Prolog: StartDeclaration PrologList /* Vec */ PrologList0 /* Vec */;
     Production {
-        lhs: 22,
-        production: &[ParseType::N(24), ParseType::N(23), ParseType::N(34)],
+        lhs: 41,
+        production: &[ParseType::N(43), ParseType::N(42), ParseType::N(57)],
     },
     // 2 - PrologList0: ScannerState PrologList0;
     Production {
-        lhs: 24,
-        production: &[ParseType::N(24), ParseType::N(28)],
+        lhs: 43,
+        production: &[ParseType::N(43), ParseType::N(51)],
     },
     // 3 - PrologList0: ;
     Production {
-        lhs: 24,
+        lhs: 43,
         production: &[],
     },
     // 4 - PrologList: Declaration PrologList;
     Production {
-        lhs: 23,
-        production: &[ParseType::N(23), ParseType::N(6)],
+        lhs: 42,
+        production: &[ParseType::N(42), ParseType::N(12)],
     },
     // 5 - PrologList: ;
     Production {
-        lhs: 23,
+        lhs: 42,
         production: &[],
     },
     // 6 - StartDeclaration: "%start" Identifier;
     Production {
-        lhs: 34,
-        production: &[ParseType::N(12), ParseType::T(5)],
+        lhs: 57,
+        production: &[ParseType::N(24), ParseType::T(5)],
     },
     // 7 - Declaration: "%title" String;
     Production {
-        lhs: 6,
-        production: &[ParseType::N(35), ParseType::T(6)],
+        lhs: 12,
+        production: &[ParseType::N(58), ParseType::T(6)],
     },
     // 8 - Declaration: "%comment" String;
     Production {
-        lhs: 6,
-        production: &[ParseType::N(35), ParseType::T(7)],
+        lhs: 12,
+        production: &[ParseType::N(58), ParseType::T(7)],
     },
     // 9 - Declaration: "%user_type" Identifier "=" UserTypeName;
     Production {
-        lhs: 6,
+        lhs: 12,
         production: &[
-            ParseType::N(41),
+            ParseType::N(70),
             ParseType::T(9),
-            ParseType::N(12),
+            ParseType::N(24),
             ParseType::T(8),
         ],
     },
     // 10 - Declaration: '%grammar_type' LiteralString;
     Production {
-        lhs: 6,
-        production: &[ParseType::N(15), ParseType::T(10)],
+        lhs: 12,
+        production: &[ParseType::N(27), ParseType::T(10)],
     },
-    // 11 - Declaration: ScannerDirectives;
+    // 11 - Declaration: "%include" String;
     Production {
-        lhs: 6,
-        production: &[ParseType::N(27)],
+        lhs: 12,
+        production: &[ParseType::N(58), ParseType::T(11)],
     },
-    // 12 - ScannerDirectives: "%line_comment" TokenLiteral;
+    // 12 - Declaration: "%keywords" IdentifierList;
     Production {
-        lhs: 27,
-        production: &[ParseType::N(37), ParseType::T(11)],
+        lhs: 12,
+        production: &[ParseType::N(25), ParseType::T(12)],
     },
-    // 13 - ScannerDirectives: "%block_comment" TokenLiteral TokenLiteral;
+    // 13 - Declaration: "%derive" DeclarationOpt /* Option */ DeriveList;
     Production {
-        lhs: 27,
-        production: &[ParseType::N(37), ParseType::N(37), ParseType::T(12)],
+        lhs: 12,
+        production: &[ParseType::N(14), ParseType::N(13), ParseType::T(13)],
     },
-    // 14 - ScannerDirectives: "%auto_newline_off";
+    // 14 - Declaration: "%inline" IdentifierList;
     Production {
-        lhs: 27,
-        production: &[ParseType::T(13)],
+        lhs: 12,
+        production: &[ParseType::N(25), ParseType::T(14)],
     },
-    // 15 - ScannerDirectives: "%auto_ws_off";
+    // 15 - Declaration: "%display" Identifier String;
     Production {
-        lhs: 27,
-        production: &[ParseType::T(14)],
+        lhs: 12,
+        production: &[ParseType::N(58), ParseType::N(24), ParseType::T(15)],
     },
-    // 16 - ScannerDirectives: '%on' IdentifierList '%enter' Identifier;
+    // 16 - Declaration: ExpectDirective Number;
     Production {
-        lhs: 27,
-        production: &[
-            ParseType::N(12),
-            ParseType::T(16),
-            ParseType::N(13),
-            ParseType::T(15),
-        ],
+        lhs: 12,
+        production: &[ParseType::N(30), ParseType::N(18)],
     },
-    // 17 - GrammarDefinition: "%%" Production GrammarDefinitionList /* Vec */;
+    // 17 - Declaration: PrecedenceDeclaration;
     Production {
-        lhs: 9,
-        production: &[ParseType::N(10), ParseType::N(20), ParseType::T(17)],
+        lhs: 12,
+        production: &[ParseType::N(34)],
     },
-    // 18 - GrammarDefinitionList: Production GrammarDefinitionList;
+    // 18 - Declaration: ScannerDirectives;
+    Production {
+        lhs: 12,
+        production: &[ParseType::N(50)],
+    },
+    // 19 - DeclarationOpt: DeriveTarget;
+    Production {
+        lhs: 13,
+        production: &[ParseType::N(16)],
+    },
+    // 20 - DeclarationOpt: ;
+    Production {
+        lhs: 13,
+        production: &[],
+    },
+    // 21 - ExpectDirective: /%expect(-rr)?/;
+    Production {
+        lhs: 18,
+        production: &[ParseType::T(16)],
+    },
+    // 22 - PrecedenceDeclaration: Associativity PrecedenceSymbol PrecedenceDeclarationList /* Vec */;
+    Production {
+        lhs: 34,
+        production: &[ParseType::N(35), ParseType::N(36), ParseType::N(10)],
+    },
+    // 23 - PrecedenceDeclarationList: PrecedenceSymbol PrecedenceDeclarationList;
+    Production {
+        lhs: 35,
+        production: &[ParseType::N(35), ParseType::N(36)],
+    },
+    // 24 - PrecedenceDeclarationList: ;
+    Production {
+        lhs: 35,
+        production: &[],
+    },
+    // 25 - Associativity: '%left';
     Production {
         lhs: 10,
-        production: &[ParseType::N(10), ParseType::N(20)],
+        production: &[ParseType::T(17)],
     },
-    // 19 - GrammarDefinitionList: ;
+    // 26 - Associativity: '%right';
     Production {
         lhs: 10,
+        production: &[ParseType::T(18)],
+    },
+    // 27 - Associativity: '%nonassoc';
+    Production {
+        lhs: 10,
+        production: &[ParseType::T(19)],
+    },
+    // 28 - PrecedenceSymbol: TokenLiteral;
+    Production {
+        lhs: 36,
+        production: &[ParseType::N(67)],
+    },
+    // 29 - PrecedenceSymbol: Identifier;
+    Production {
+        lhs: 36,
+        production: &[ParseType::N(24)],
+    },
+    // 30 - ScannerDirectives: "%line_comment" TokenLiteral;
+    Production {
+        lhs: 50,
+        production: &[ParseType::N(67), ParseType::T(20)],
+    },
+    // 31 - ScannerDirectives: "%block_comment" TokenLiteral TokenLiteral;
+    Production {
+        lhs: 50,
+        production: &[ParseType::N(67), ParseType::N(67), ParseType::T(21)],
+    },
+    // 32 - ScannerDirectives: "%nested_block_comment" TokenLiteral TokenLiteral;
+    Production {
+        lhs: 50,
+        production: &[ParseType::N(67), ParseType::N(67), ParseType::T(22)],
+    },
+    // 33 - ScannerDirectives: "%auto_newline_off";
+    Production {
+        lhs: 50,
+        production: &[ParseType::T(23)],
+    },
+    // 34 - ScannerDirectives: "%auto_ws_off";
+    Production {
+        lhs: 50,
+        production: &[ParseType::T(24)],
+    },
+    // 35 - ScannerDirectives: "%longest_match";
+    Production {
+        lhs: 50,
+        production: &[ParseType::T(25)],
+    },
+    // 36 - ScannerDirectives: "%case_insensitive";
+    Production {
+        lhs: 50,
+        production: &[ParseType::T(26)],
+    },
+    // 37 - ScannerDirectives: "%offside" Identifier Identifier Identifier;
+    Production {
+        lhs: 50,
+        production: &[
+            ParseType::N(24),
+            ParseType::N(24),
+            ParseType::N(24),
+            ParseType::T(27),
+        ],
+    },
+    // 38 - ScannerDirectives: '%on' IdentifierList '%enter' Identifier;
+    Production {
+        lhs: 50,
+        production: &[
+            ParseType::N(24),
+            ParseType::T(29),
+            ParseType::N(25),
+            ParseType::T(28),
+        ],
+    },
+    // 39 - GrammarDefinition: "%%" Production GrammarDefinitionList /* Vec */;
+    Production {
+        lhs: 21,
+        production: &[ParseType::N(22), ParseType::N(37), ParseType::T(30)],
+    },
+    // 40 - GrammarDefinitionList: Production GrammarDefinitionList;
+    Production {
+        lhs: 22,
+        production: &[ParseType::N(22), ParseType::N(37)],
+    },
+    // 41 - GrammarDefinitionList: ;
+    Production {
+        lhs: 22,
+        production: &[],
+    },
+    // 42 - DoubleColon: "::";
+    Production {
+        lhs: 17,
+        production: &[ParseType::T(31)],
+    },
+    // 43 - DeriveTarget: Identifier ":";
+    Production {
+        lhs: 16,
+        production: &[ParseType::T(32), ParseType::N(24)],
+    },
+    // 44 - DeriveList: UserTypeName DeriveListList /* Vec */;
+    Production {
+        lhs: 14,
+        production: &[ParseType::N(15), ParseType::N(70)],
+    },
+    // 45 - DeriveListList: "," UserTypeName DeriveListList;
+    Production {
+        lhs: 15,
+        production: &[ParseType::N(15), ParseType::N(70), ParseType::T(33)],
+    },
+    // 46 - DeriveListList: ;
+    Production {
+        lhs: 15,
+        production: &[],
+    },
+    // 47 - ProductionLHS: ProductionName ":";
+    Production {
+        lhs: 38,
+        production: &[ParseType::T(32), ParseType::N(39)],
+    },
+    // 48 - ProductionName: Identifier ProductionNameOpt /* Option */;
+    Production {
+        lhs: 39,
+        production: &[ParseType::N(40), ParseType::N(24)],
+    },
+    // 49 - ProductionNameOpt: TemplateParameters;
+    Production {
+        lhs: 40,
+        production: &[ParseType::N(64)],
+    },
+    // 50 - ProductionNameOpt: ;
+    Production {
+        lhs: 40,
         production: &[],
     },
-    // 20 - DoubleColon: "::";
+    // 51 - TemplateParameters: "<" IdentifierList ">";
+    Production {
+        lhs: 64,
+        production: &[ParseType::T(35), ParseType::N(25), ParseType::T(34)],
+    },
+    // 52 - Production: ProductionLHS Alternations ";";
+    Production {
+        lhs: 37,
+        production: &[ParseType::T(36), ParseType::N(6), ParseType::N(38)],
+    },
+    // 53 - Alternations: Alternation AlternationsList /* Vec */;
+    Production {
+        lhs: 6,
+        production: &[ParseType::N(7), ParseType::N(1)],
+    },
+    // 54 - AlternationsList: '|' Alternation AlternationsList;
     Production {
         lhs: 7,
-        production: &[ParseType::T(18)],
+        production: &[ParseType::N(7), ParseType::N(1), ParseType::T(37)],
     },
-    // 21 - ProductionLHS: Identifier ":";
+    // 55 - AlternationsList: ;
     Production {
-        lhs: 21,
-        production: &[ParseType::T(19), ParseType::N(12)],
+        lhs: 7,
+        production: &[],
     },
-    // 22 - Production: ProductionLHS Alternations ";";
+    // 56 - Alternation: AlternationList /* Vec */ AlternationOpt /* Option */ AlternationOpt0 /* Option */;
     Production {
-        lhs: 20,
-        production: &[ParseType::T(20), ParseType::N(3), ParseType::N(21)],
+        lhs: 1,
+        production: &[ParseType::N(5), ParseType::N(4), ParseType::N(3)],
     },
-    // 23 - Alternations: Alternation AlternationsList /* Vec */;
+    // 57 - AlternationList: Factor AlternationList;
     Production {
         lhs: 3,
-        production: &[ParseType::N(4), ParseType::N(1)],
+        production: &[ParseType::N(3), ParseType::N(19)],
+    },
+    // 58 - AlternationList: ;
+    Production {
+        lhs: 3,
+        production: &[],
     },
-    // 24 - AlternationsList: '|' Alternation AlternationsList;
+    // 59 - AlternationOpt0: AlternationLabel;
+    Production {
+        lhs: 5,
+        production: &[ParseType::N(2)],
+    },
+    // 60 - AlternationOpt0: ;
+    Production {
+        lhs: 5,
+        production: &[],
+    },
+    // 61 - AlternationOpt: '%prec' PrecedenceSymbol;
     Production {
         lhs: 4,
-        production: &[ParseType::N(4), ParseType::N(1), ParseType::T(21)],
+        production: &[ParseType::N(36), ParseType::T(38)],
     },
-    // 25 - AlternationsList: ;
+    // 62 - AlternationOpt: ;
     Production {
         lhs: 4,
         production: &[],
     },
-    // 26 - Alternation: AlternationList /* Vec */;
+    // 63 - AlternationLabel: '#' Identifier;
     Production {
-        lhs: 1,
-        production: &[ParseType::N(2)],
+        lhs: 2,
+        production: &[ParseType::N(24), ParseType::T(39)],
     },
-    // 27 - AlternationList: Factor AlternationList;
+    // 64 - Factor: Group;
     Production {
-        lhs: 2,
-        production: &[ParseType::N(2), ParseType::N(8)],
+        lhs: 19,
+        production: &[ParseType::N(23)],
     },
-    // 28 - AlternationList: ;
+    // 65 - Factor: Repeat;
     Production {
-        lhs: 2,
-        production: &[],
+        lhs: 19,
+        production: &[ParseType::N(45)],
     },
-    // 29 - Factor: Group;
+    // 66 - Factor: Optional;
     Production {
-        lhs: 8,
-        production: &[ParseType::N(11)],
+        lhs: 19,
+        production: &[ParseType::N(32)],
     },
-    // 30 - Factor: Repeat;
+    // 67 - Factor: Symbol;
     Production {
-        lhs: 8,
-        production: &[ParseType::N(26)],
+        lhs: 19,
+        production: &[ParseType::N(59)],
     },
-    // 31 - Factor: Optional;
+    // 68 - Factor: FieldLabel Symbol;
     Production {
-        lhs: 8,
-        production: &[ParseType::N(18)],
+        lhs: 19,
+        production: &[ParseType::N(59), ParseType::N(20)],
     },
-    // 32 - Factor: Symbol;
+    // 69 - FieldLabel: Identifier "=";
     Production {
-        lhs: 8,
-        production: &[ParseType::N(36)],
+        lhs: 20,
+        production: &[ParseType::T(9), ParseType::N(24)],
     },
-    // 33 - Symbol: NonTerminal;
+    // 70 - Symbol: NonTerminal;
     Production {
-        lhs: 36,
-        production: &[ParseType::N(16)],
+        lhs: 59,
+        production: &[ParseType::N(28)],
     },
-    // 34 - Symbol: SimpleToken;
+    // 71 - Symbol: SimpleToken;
     Production {
-        lhs: 36,
-        production: &[ParseType::N(32)],
+        lhs: 59,
+        production: &[ParseType::N(55)],
     },
-    // 35 - Symbol: TokenWithStates;
+    // 72 - Symbol: AngleArguments;
     Production {
-        lhs: 36,
-        production: &[ParseType::N(38)],
+        lhs: 59,
+        production: &[ParseType::N(8)],
     },
-    // 36 - Symbol: ScannerSwitch;
+    // 73 - Symbol: ScannerSwitch;
     Production {
-        lhs: 36,
-        production: &[ParseType::N(30)],
+        lhs: 59,
+        production: &[ParseType::N(53)],
     },
-    // 37 - TokenLiteral: String;
+    // 74 - TokenLiteral: String;
     Production {
-        lhs: 37,
-        production: &[ParseType::N(35)],
+        lhs: 67,
+        production: &[ParseType::N(58)],
     },
-    // 38 - TokenLiteral: LiteralString;
+    // 75 - TokenLiteral: LiteralString;
     Production {
-        lhs: 37,
-        production: &[ParseType::N(15)],
+        lhs: 67,
+        production: &[ParseType::N(27)],
     },
-    // 39 - TokenLiteral: Regex;
+    // 76 - TokenLiteral: Regex;
     Production {
-        lhs: 37,
-        production: &[ParseType::N(25)],
+        lhs: 67,
+        production: &[ParseType::N(44)],
+    },
+    // 77 - TokenExpression: TokenLiteral TokenExpressionOpt /* Option */;
+    Production {
+        lhs: 65,
+        production: &[ParseType::N(66), ParseType::N(67)],
+    },
+    // 78 - TokenExpressionOpt: TrailingContext;
+    Production {
+        lhs: 66,
+        production: &[ParseType::N(68)],
+    },
+    // 79 - TokenExpressionOpt: ;
+    Production {
+        lhs: 66,
+        production: &[],
+    },
+    // 80 - TrailingContext: '?=' TokenLiteral;
+    Production {
+        lhs: 68,
+        production: &[ParseType::N(67), ParseType::T(40)],
+    },
+    // 81 - SimpleToken: TokenExpression SimpleTokenOpt /* Option */;
+    Production {
+        lhs: 55,
+        production: &[ParseType::N(56), ParseType::N(65)],
+    },
+    // 82 - SimpleTokenOpt: ASTControl;
+    Production {
+        lhs: 56,
+        production: &[ParseType::N(0)],
     },
-    // 40 - SimpleToken: TokenLiteral SimpleTokenOpt /* Option */;
+    // 83 - SimpleTokenOpt: ;
+    Production {
+        lhs: 56,
+        production: &[],
+    },
+    // 84 - Group: '(' Alternations ')';
+    Production {
+        lhs: 23,
+        production: &[ParseType::T(42), ParseType::N(6), ParseType::T(41)],
+    },
+    // 85 - Optional: '[' Alternations ']';
     Production {
         lhs: 32,
-        production: &[ParseType::N(33), ParseType::N(37)],
+        production: &[ParseType::T(44), ParseType::N(6), ParseType::T(43)],
     },
-    // 41 - SimpleTokenOpt: ASTControl;
+    // 86 - Repeat: '{' Alternations RepeatOpt /* Option */ '}' RepeatOpt0 /* Option */;
     Production {
-        lhs: 33,
+        lhs: 45,
+        production: &[
+            ParseType::N(47),
+            ParseType::T(46),
+            ParseType::N(46),
+            ParseType::N(6),
+            ParseType::T(45),
+        ],
+    },
+    // 87 - RepeatOpt0: OneOrMore;
+    Production {
+        lhs: 47,
+        production: &[ParseType::N(31)],
+    },
+    // 88 - RepeatOpt0: ;
+    Production {
+        lhs: 47,
+        production: &[],
+    },
+    // 89 - RepeatOpt: RepeatSeparator;
+    Production {
+        lhs: 46,
+        production: &[ParseType::N(48)],
+    },
+    // 90 - RepeatOpt: ;
+    Production {
+        lhs: 46,
+        production: &[],
+    },
+    // 91 - NonTerminal: Identifier NonTerminalOpt /* Option */;
+    Production {
+        lhs: 28,
+        production: &[ParseType::N(29), ParseType::N(24)],
+    },
+    // 92 - NonTerminalOpt: ASTControl;
+    Production {
+        lhs: 29,
         production: &[ParseType::N(0)],
     },
-    // 42 - SimpleTokenOpt: ;
+    // 93 - NonTerminalOpt: ;
     Production {
-        lhs: 33,
+        lhs: 29,
         production: &[],
     },
-    // 43 - TokenWithStates: "<" IdentifierList ">" TokenLiteral TokenWithStatesOpt /* Option */;
+    // 94 - AngleArguments: "<" TemplateArguments ">" AngleArgumentsOpt /* Option */;
     Production {
-        lhs: 38,
+        lhs: 8,
         production: &[
-            ParseType::N(39),
-            ParseType::N(37),
-            ParseType::T(23),
-            ParseType::N(13),
-            ParseType::T(22),
+            ParseType::N(9),
+            ParseType::T(35),
+            ParseType::N(62),
+            ParseType::T(34),
         ],
     },
-    // 44 - TokenWithStatesOpt: ASTControl;
+    // 95 - AngleArgumentsOpt: ASTControl;
     Production {
-        lhs: 39,
+        lhs: 9,
         production: &[ParseType::N(0)],
     },
-    // 45 - TokenWithStatesOpt: ;
+    // 96 - AngleArgumentsOpt: ;
     Production {
-        lhs: 39,
+        lhs: 9,
         production: &[],
     },
-    // 46 - Group: '(' Alternations ')';
+    // 97 - TemplateArguments: TemplateArgument TemplateArgumentsList /* Vec */;
     Production {
-        lhs: 11,
-        production: &[ParseType::T(25), ParseType::N(3), ParseType::T(24)],
+        lhs: 62,
+        production: &[ParseType::N(63), ParseType::N(60)],
     },
-    // 47 - Optional: '[' Alternations ']';
+    // 98 - TemplateArgumentsList: "," TemplateArgument TemplateArgumentsList;
     Production {
-        lhs: 18,
-        production: &[ParseType::T(27), ParseType::N(3), ParseType::T(26)],
+        lhs: 63,
+        production: &[ParseType::N(63), ParseType::N(60), ParseType::T(33)],
     },
-    // 48 - Repeat: '{' Alternations '}';
+    // 99 - TemplateArgumentsList: ;
     Production {
-        lhs: 26,
-        production: &[ParseType::T(29), ParseType::N(3), ParseType::T(28)],
+        lhs: 63,
+        production: &[],
     },
-    // 49 - NonTerminal: Identifier NonTerminalOpt /* Option */;
+    // 100 - TemplateArgument: Factor TemplateArgumentList /* Vec */;
     Production {
-        lhs: 16,
-        production: &[ParseType::N(17), ParseType::N(12)],
+        lhs: 60,
+        production: &[ParseType::N(61), ParseType::N(19)],
     },
-    // 50 - NonTerminalOpt: ASTControl;
+    // 101 - TemplateArgumentList: Factor TemplateArgumentList;
     Production {
-        lhs: 17,
-        production: &[ParseType::N(0)],
+        lhs: 61,
+        production: &[ParseType::N(61), ParseType::N(19)],
     },
-    // 51 - NonTerminalOpt: ;
+    // 102 - TemplateArgumentList: ;
     Production {
-        lhs: 17,
+        lhs: 61,
         production: &[],
     },
-    // 52 - Identifier: /[a-zA-Z_][a-zA-Z0-9_]*/;
+    // 103 - Identifier: /[a-zA-Z_][a-zA-Z0-9_]*/;
     Production {
-        lhs: 12,
-        production: &[ParseType::T(30)],
+        lhs: 24,
+        production: &[ParseType::T(47)],
     },
-    // 53 - String: /"(\\.|[^\\])*?"/;
+    // 104 - Number: /[0-9]+/;
     Production {
-        lhs: 35,
-        production: &[ParseType::T(31)],
+        lhs: 30,
+        production: &[ParseType::T(48)],
     },
-    // 54 - LiteralString: /'(\\'|[^'])*?'/;
+    // 105 - String: /"(\\.|[^\\])*?"i?/;
     Production {
-        lhs: 15,
-        production: &[ParseType::T(32)],
+        lhs: 58,
+        production: &[ParseType::T(49)],
     },
-    // 55 - ScannerState: "%scanner" Identifier '{' ScannerStateList /* Vec */ '}';
+    // 106 - LiteralString: /'(\\'|[^'])*?'i?/;
     Production {
-        lhs: 28,
+        lhs: 27,
+        production: &[ParseType::T(50)],
+    },
+    // 107 - ScannerState: "%scanner" Identifier '{' ScannerStateList /* Vec */ '}';
+    Production {
+        lhs: 51,
         production: &[
-            ParseType::T(29),
-            ParseType::N(29),
-            ParseType::T(28),
-            ParseType::N(12),
-            ParseType::T(33),
+            ParseType::T(46),
+            ParseType::N(52),
+            ParseType::T(45),
+            ParseType::N(24),
+            ParseType::T(51),
         ],
     },
-    // 56 - ScannerStateList: ScannerDirectives ScannerStateList;
+    // 108 - ScannerStateList: ScannerDirectives ScannerStateList;
     Production {
-        lhs: 29,
-        production: &[ParseType::N(29), ParseType::N(27)],
+        lhs: 52,
+        production: &[ParseType::N(52), ParseType::N(50)],
     },
-    // 57 - ScannerStateList: ;
+    // 109 - ScannerStateList: ;
     Production {
-        lhs: 29,
+        lhs: 52,
         production: &[],
     },
-    // 58 - IdentifierList: Identifier IdentifierListList /* Vec */;
+    // 110 - IdentifierList: Identifier IdentifierListList /* Vec */;
     Production {
-        lhs: 13,
-        production: &[ParseType::N(14), ParseType::N(12)],
+        lhs: 25,
+        production: &[ParseType::N(26), ParseType::N(24)],
     },
-    // 59 - IdentifierListList: "," Identifier IdentifierListList;
+    // 111 - IdentifierListList: "," Identifier IdentifierListList;
     Production {
-        lhs: 14,
-        production: &[ParseType::N(14), ParseType::N(12), ParseType::T(34)],
+        lhs: 26,
+        production: &[ParseType::N(26), ParseType::N(24), ParseType::T(33)],
     },
-    // 60 - IdentifierListList: ;
+    // 112 - IdentifierListList: ;
     Production {
-        lhs: 14,
+        lhs: 26,
         production: &[],
     },
-    // 61 - ScannerSwitch: "%sc" '(' ScannerSwitchOpt /* Option */ ')';
+    // 113 - ScannerSwitch: "%sc" '(' ScannerSwitchOpt /* Option */ ')';
     Production {
-        lhs: 30,
+        lhs: 53,
         production: &[
-            ParseType::T(25),
-            ParseType::N(31),
-            ParseType::T(24),
-            ParseType::T(35),
+            ParseType::T(42),
+            ParseType::N(54),
+            ParseType::T(41),
+            ParseType::T(52),
         ],
     },
-    // 62 - ScannerSwitch: "%push" '(' Identifier ')';
+    // 114 - ScannerSwitch: "%push" '(' Identifier ')';
     Production {
-        lhs: 30,
+        lhs: 53,
         production: &[
-            ParseType::T(25),
-            ParseType::N(12),
-            ParseType::T(24),
-            ParseType::T(36),
+            ParseType::T(42),
+            ParseType::N(24),
+            ParseType::T(41),
+            ParseType::T(53),
         ],
     },
-    // 63 - ScannerSwitch: "%pop" '(' ')';
+    // 115 - ScannerSwitch: "%pop" '(' ')';
     Production {
-        lhs: 30,
-        production: &[ParseType::T(25), ParseType::T(24), ParseType::T(37)],
+        lhs: 53,
+        production: &[ParseType::T(42), ParseType::T(41), ParseType::T(54)],
     },
-    // 64 - ScannerSwitchOpt: Identifier;
+    // 116 - ScannerSwitchOpt: Identifier;
     Production {
-        lhs: 31,
-        production: &[ParseType::N(12)],
+        lhs: 54,
+        production: &[ParseType::N(24)],
     },
-    // 65 - ScannerSwitchOpt: ;
+    // 117 - ScannerSwitchOpt: ;
     Production {
-        lhs: 31,
+        lhs: 54,
         production: &[],
     },
-    // 66 - ASTControl: CutOperator;
+    // 118 - ASTControl: CutOperator;
     Production {
         lhs: 0,
-        production: &[ParseType::N(5)],
+        production: &[ParseType::N(11)],
     },
-    // 67 - ASTControl: UserTypeDeclaration;
+    // 119 - ASTControl: UserTypeDeclaration;
     Production {
         lhs: 0,
-        production: &[ParseType::N(40)],
+        production: &[ParseType::N(69)],
     },
-    // 68 - CutOperator: '^';
+    // 120 - CutOperator: '^';
     Production {
-        lhs: 5,
-        production: &[ParseType::T(38)],
+        lhs: 11,
+        production: &[ParseType::T(55)],
     },
-    // 69 - UserTypeDeclaration: ":" UserTypeName;
+    // 121 - UserTypeDeclaration: ":" UserTypeName;
     Production {
-        lhs: 40,
-        production: &[ParseType::N(41), ParseType::T(19)],
+        lhs: 69,
+        production: &[ParseType::N(70), ParseType::T(32)],
     },
-    // 70 - UserTypeName: Identifier UserTypeNameList /* Vec */;
+    // 122 - UserTypeName: Identifier UserTypeNameList /* Vec */;
     Production {
-        lhs: 41,
-        production: &[ParseType::N(42), ParseType::N(12)],
+        lhs: 70,
+        production: &[ParseType::N(71), ParseType::N(24)],
     },
-    // 71 - UserTypeNameList: DoubleColon Identifier UserTypeNameList;
+    // 123 - UserTypeNameList: DoubleColon Identifier UserTypeNameList;
     Production {
-        lhs: 42,
-        production: &[ParseType::N(42), ParseType::N(12), ParseType::N(7)],
+        lhs: 71,
+        production: &[ParseType::N(71), ParseType::N(24), ParseType::N(17)],
     },
-    // 72 - UserTypeNameList: ;
+    // 124 - UserTypeNameList: ;
     Production {
-        lhs: 42,
+        lhs: 71,
         production: &[],
     },
-    // 73 - Regex: /\u{2f}(\\.|[^\\])*?\u{2f}/;
+    // 125 - RepeatSeparator: '%' Symbol RepeatSeparatorList /* Vec */;
     Production {
-        lhs: 25,
-        production: &[ParseType::T(39)],
+        lhs: 48,
+        production: &[ParseType::N(49), ParseType::N(59), ParseType::T(56)],
+    },
+    // 126 - RepeatSeparatorList: Symbol RepeatSeparatorList;
+    Production {
+        lhs: 49,
+        production: &[ParseType::N(49), ParseType::N(59)],
+    },
+    // 127 - RepeatSeparatorList: ;
+    Production {
+        lhs: 49,
+        production: &[],
+    },
+    // 128 - OneOrMore: '+';
+    Production {
+        lhs: 31,
+        production: &[ParseType::T(57)],
+    },
+    // 129 - Regex: /\u{2f}(\\.|[^\\])*?\u{2f}i?/;
+    Production {
+        lhs: 44,
+        production: &[ParseType::T(58)],
     },
 ];
 
@@ -1062,7 +1995,7 @@ where
     T: AsRef<Path>,
 {
     let mut llk_parser = LLKParser::new(
-        19,
+        33,
         LOOKAHEAD_AUTOMATA,
         PRODUCTIONS,
         TERMINAL_NAMES,
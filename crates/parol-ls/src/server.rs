@@ -19,8 +19,11 @@ use lsp_types::{
     TextDocumentContentChangeEvent, TextDocumentPositionParams, TextEdit, Uri, WorkspaceEdit,
 };
 use parol::{
-    analysis::lalr1_parse_table::calculate_lalr1_parse_table, calculate_lookahead_dfas,
-    check_and_transform_grammar, parser::parol_grammar::GrammarType, GrammarConfig, ParolGrammar,
+    analysis::lalr1_parse_table::{calculate_lalr1_parse_table, LRResolvedConflict},
+    calculate_glr_parse_table, calculate_lookahead_dfas, calculate_lr1_parse_table,
+    check_and_transform_grammar,
+    parser::parol_grammar::GrammarType,
+    GrammarConfig, LRParseTable, ParolGrammar,
 };
 
 use crate::{
@@ -224,33 +227,55 @@ impl Server {
                         Self::notify_analysis_error(err, connection, &uri, version, document_state);
                 }
             }
-            GrammarType::LALR1 => {
-                let result = calculate_lalr1_parse_table(&grammar_config);
-                match result {
-                    Ok((_, resolved_conflicts)) => {
-                        let _ = Self::notify_resolved_conlicts(
-                            resolved_conflicts,
-                            connection,
-                            &uri,
-                            version,
-                        );
-                    }
-                    Err(err) => {
-                        eprintln!("check_grammar: errors from calculate_lookahead_dfas");
-                        let _ = Self::notify_analysis_error(
-                            err,
-                            connection,
-                            &uri,
-                            version,
-                            document_state,
-                        );
-                    }
+            GrammarType::GLR => {
+                // Conflicts are followed in parallel by the GLR parser and aren't reported
+                if let Err(err) = calculate_glr_parse_table(&grammar_config) {
+                    let _ =
+                        Self::notify_analysis_error(err, connection, &uri, version, document_state);
                 }
             }
+            GrammarType::LALR1 => Self::notify_lr_analysis_result(
+                calculate_lalr1_parse_table(&grammar_config),
+                &grammar_config,
+                connection,
+                &uri,
+                version,
+                document_state,
+            ),
+            GrammarType::LR1 => Self::notify_lr_analysis_result(
+                calculate_lr1_parse_table(&grammar_config),
+                &grammar_config,
+                connection,
+                &uri,
+                version,
+                document_state,
+            ),
         });
         Ok(())
     }
 
+    fn notify_lr_analysis_result(
+        result: anyhow::Result<(LRParseTable, Vec<LRResolvedConflict>)>,
+        grammar_config: &GrammarConfig,
+        connection: Arc<lsp_server::Connection>,
+        uri: &Uri,
+        version: i32,
+        document_state: DocumentState,
+    ) {
+        match result {
+            // Conflicts declared with %expect or %expect-rr have already been reviewed
+            Ok(_) if grammar_config.expects_conflicts() => (),
+            Ok((_, resolved_conflicts)) => {
+                let _ =
+                    Self::notify_resolved_conlicts(resolved_conflicts, connection, uri, version);
+            }
+            Err(err) => {
+                eprintln!("check_grammar: errors from LR parse table calculation");
+                let _ = Self::notify_analysis_error(err, connection, uri, version, document_state);
+            }
+        }
+    }
+
     pub(crate) fn handle_open_document(
         &mut self,
         connection: Arc<lsp_server::Connection>,
@@ -582,7 +607,7 @@ impl Server {
     }
 
     fn notify_resolved_conlicts(
-        resolved_conflicts: Vec<parol::analysis::lalr1_parse_table::LRResolvedConflict>,
+        resolved_conflicts: Vec<LRResolvedConflict>,
         connection: Arc<lsp_server::Connection>,
         uri: &Uri,
         version: i32,
@@ -62,6 +62,13 @@ impl SymbolDefs {
             .insert(location_to_range(&token.location));
     }
 
+    // Remove the reference to the symbol by token
+    pub(crate) fn remove_reference_by_token(&mut self, token: &OwnedToken) {
+        if let Some(dr) = self.symbols.get_mut(token.text()) {
+            dr.references.remove(&location_to_range(&token.location));
+        }
+    }
+
     // Find the name of the reference at the given position
     pub(crate) fn find_reference(&self, position: Position) -> Option<&str> {
         for (name, dr) in &self.symbols {
@@ -1,4 +1,5 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { SymbolId } from "./SymbolId";
 
 /**
  *
@@ -9,4 +10,14 @@ export type InstanceEntrails = {
 /**
  * Indicates if the argument is used
  */
-used: boolean, };
+used: boolean, 
+/**
+ * The non-terminal declared with %inline whose members replace the argument in the struct
+ * types
+ */
+inline_non_terminal: string | null, 
+/**
+ * The argument and the member of the inlined non-terminal's type a struct member is spliced
+ * from
+ */
+inlined_from: [SymbolId, SymbolId] | null, };
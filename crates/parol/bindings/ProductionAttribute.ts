@@ -5,4 +5,4 @@
  * Attributes applicable to a production or an alternation
  *
  */
-export type ProductionAttribute = "None" | "CollectionStart" | "AddToCollection" | "OptionalSome" | "OptionalNone";
+export type ProductionAttribute = "None" | "CollectionStart" | "AddToCollection" | "NonEmptyCollectionStart" | "OptionalSome" | "OptionalNone";
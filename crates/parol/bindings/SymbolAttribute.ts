@@ -5,4 +5,4 @@
  * Attributes applicable to a grammar symbol
  *
  */
-export type SymbolAttribute = "None" | "RepetitionAnchor" | "Option" | "Clipped";
+export type SymbolAttribute = "None" | "RepetitionAnchor" | "NonEmptyRepetitionAnchor" | "Option" | "Clipped";
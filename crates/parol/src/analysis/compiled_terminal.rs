@@ -33,9 +33,11 @@ impl CompiledTerminal {
         F: TerminalIndexFn,
     {
         match s {
-            Symbol::T(Terminal::Trm(t, k, ..)) => {
-                Self(terminal_index_resolver.as_ref().terminal_index(t, *k))
-            }
+            Symbol::T(Terminal::Trm(t, k, _, _, _, c, _)) => Self(
+                terminal_index_resolver
+                    .as_ref()
+                    .terminal_index(t, *k, c.as_ref()),
+            ),
             Symbol::T(Terminal::End) => Self(EOI),
             _ => panic!("Unexpected symbol type: {:?}", s),
         }
@@ -0,0 +1,49 @@
+//! Detection of terminals that match the empty string
+//! Such terminals would make the scanner produce empty tokens without making progress in the
+//! input.
+
+use crate::TerminalKind;
+use anyhow::{anyhow, Result};
+
+///
+/// Checks whether the given terminal matches the empty string.
+/// The terminal is expanded according to its kind and then compiled into its high-level
+/// intermediate representation which provides the minimum length of all matches.
+///
+pub fn matches_empty_string(terminal: &str, kind: TerminalKind) -> Result<bool> {
+    let expanded = kind.expand(terminal);
+    let hir = regex_syntax::ParserBuilder::new()
+        .build()
+        .parse(&expanded)
+        .map_err(|err| anyhow!(err).context(format!("regex_syntax can't parse /{expanded}/")))?;
+    Ok(hir.properties().minimum_len() == Some(0))
+}
+
+#[cfg(test)]
+mod test {
+    use super::matches_empty_string;
+    use crate::TerminalKind;
+
+    #[test]
+    fn check_matches_empty_string() {
+        let test_data = [
+            ("a", TerminalKind::Legacy, false),
+            ("a?", TerminalKind::Legacy, true),
+            ("a*", TerminalKind::Regex, true),
+            ("a+", TerminalKind::Regex, false),
+            (r"\b", TerminalKind::Regex, true),
+            ("^", TerminalKind::Regex, true),
+            ("(a|)", TerminalKind::Regex, true),
+            // Raw strings are escaped and thus match literally
+            ("a?", TerminalKind::Raw, false),
+            ("", TerminalKind::Raw, true),
+        ];
+        for (terminal, kind, expected) in test_data {
+            assert_eq!(
+                expected,
+                matches_empty_string(terminal, kind).unwrap(),
+                "Terminal {terminal:?} of kind {kind:?}"
+            );
+        }
+    }
+}
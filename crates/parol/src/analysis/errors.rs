@@ -1,6 +1,6 @@
 use thiserror::Error;
 
-use super::LRConflictError;
+use super::{LLKConflict, LRConflictError};
 
 // ---------------------------------------------------
 // Part of the Public API
@@ -32,10 +32,12 @@ pub enum GrammarAnalysisError {
     },
 
     /// Maximum lookahead exceeded.
-    #[error("Maximum lookahead of {max_k} exceeded")]
+    #[error("Maximum lookahead of {max_k} exceeded for non-terminal {}", conflict.non_terminal)]
     MaxKExceeded {
         /// Maximum lookahead
         max_k: usize,
+        /// The lookaheads the productions of the non-terminal share at the maximum lookahead
+        conflict: LLKConflict,
     },
 
     /// The LALR(1) parse table construction failed with conflicts.
@@ -44,6 +46,22 @@ pub enum GrammarAnalysisError {
         /// Conflict
         conflict: LRConflictError,
     },
+
+    /// The numbers of conflicts that were resolved automatically differ from the ones declared
+    /// with %expect and %expect-rr.
+    #[error("Expected {expected_shift_reduce} shift-reduce and {expected_reduce_reduce} reduce-reduce conflicts but found {shift_reduce} and {reduce_reduce}")]
+    UnexpectedConflicts {
+        /// Expected number of shift-reduce conflicts
+        expected_shift_reduce: usize,
+        /// Actual number of shift-reduce conflicts
+        shift_reduce: usize,
+        /// Expected number of reduce-reduce conflicts
+        expected_reduce_reduce: usize,
+        /// Actual number of reduce-reduce conflicts
+        reduce_reduce: usize,
+        /// The conflicts that were resolved automatically
+        conflicts: Vec<LRConflictError>,
+    },
 }
 
 /// A single recursive non-terminal
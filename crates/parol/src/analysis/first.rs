@@ -285,7 +285,7 @@ where
                     )
                 });
             }
-            Symbol::N(nt, _, _) => {
+            Symbol::N(nt, ..) => {
                 let f = create_union_access_function(nt, pr_count, non_terminal_index.clone());
                 result_function = Arc::new(move |result_vector: Arc<ResultVector>| {
                     result_function(result_vector.clone()).k_concat(&f(result_vector), k)
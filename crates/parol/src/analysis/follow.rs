@@ -330,7 +330,7 @@ where
                                 )
                             });
                     }
-                    Symbol::N(nt, _, _) => {
+                    Symbol::N(nt, ..) => {
                         let first_k_of_nt = args.first_k_of_nt.clone();
                         let nt_i = args.nti.non_terminal_index(&nt);
                         result_function =
@@ -1,11 +1,14 @@
-use crate::analysis::lookahead_dfa::ProductionIndex;
-use crate::analysis::LookaheadDFA;
+use crate::analysis::lookahead_dfa::{
+    CompiledProductionIndex, DFAState, ProductionIndex, INVALID_PROD,
+};
+use crate::analysis::{find_llk_conflict, LookaheadDFA};
 use crate::analysis::{first_k, follow_k, FirstSet, FollowSet};
 use crate::grammar::cfg::NonTerminalIndexFn;
+use crate::{Cfg, GrammarConfig, KTuples};
 use crate::{GrammarAnalysisError, MAX_K};
-use crate::{GrammarConfig, KTuples};
 use anyhow::{anyhow, bail, Result};
 use parol_runtime::log::trace;
+use parol_runtime::TerminalIndex;
 use std::cell::RefCell;
 use std::collections::BTreeMap;
 use std::rc::Rc;
@@ -86,7 +89,8 @@ impl FollowCache {
 /// If all k-tuples are distinct between all productions the number k is
 /// returned. Otherwise the value of k is incremented by 1 and the process is
 /// retried.
-/// If k_max is exceeded the function returns an error.
+/// If k_max is exceeded the function returns an error that contains the conflict of the
+/// productions.
 ///
 pub fn decidable(
     grammar_config: &GrammarConfig,
@@ -95,6 +99,36 @@ pub fn decidable(
     first_cache: &FirstCache,
     follow_cache: &FollowCache,
 ) -> Result<usize> {
+    match lookahead_k(
+        grammar_config,
+        non_terminal,
+        max_k,
+        first_cache,
+        follow_cache,
+    )? {
+        Some(k) => Ok(k),
+        None => {
+            let conflict = find_llk_conflict(
+                grammar_config,
+                non_terminal,
+                max_k,
+                first_cache,
+                follow_cache,
+            )?;
+            bail!(GrammarAnalysisError::MaxKExceeded { max_k, conflict })
+        }
+    }
+}
+
+// Calculates the lookahead like `decidable` but returns `None` if max_k is exceeded, thus without
+// searching the conflict of the productions.
+fn lookahead_k(
+    grammar_config: &GrammarConfig,
+    non_terminal: &str,
+    max_k: usize,
+    first_cache: &FirstCache,
+    follow_cache: &FollowCache,
+) -> Result<Option<usize>> {
     let cfg = &grammar_config.cfg;
     let productions = cfg.matching_productions(non_terminal);
     if productions.is_empty() {
@@ -103,7 +137,7 @@ pub fn decidable(
         ))
     } else if productions.len() == 1 {
         // The trivial case - no lookahead is needed.
-        Ok(0)
+        Ok(Some(0))
     } else {
         let nti = cfg.get_non_terminal_index_function();
         let mut current_k = 1;
@@ -132,17 +166,54 @@ pub fn decidable(
                         .iter()
                         .all(|(j, t2)| i == j || t1.is_disjoint(t2))
                 }) {
-                    return Ok(current_k);
+                    return Ok(Some(current_k));
                 }
             } else {
                 bail!("Internal error");
             }
             current_k += 1;
         }
-        bail!(GrammarAnalysisError::MaxKExceeded { max_k })
+        Ok(None)
     }
 }
 
+///
+/// Decides all non-terminals of the grammar, where max_k is the limit.
+/// Returns the lookahead each non-terminal needs, or `None` for the non-terminals that need more
+/// than max_k tokens of lookahead. Their conflicts aren't searched here because this is expensive;
+/// use [find_llk_conflict] for the ones that are reported.
+///
+pub fn decide_non_terminals(
+    grammar_config: &GrammarConfig,
+    max_k: usize,
+    first_cache: &FirstCache,
+    follow_cache: &FollowCache,
+) -> Vec<(String, Result<Option<usize>>)> {
+    grammar_config
+        .cfg
+        .get_non_terminal_set()
+        .into_iter()
+        .map(|n| {
+            let k = lookahead_k(grammar_config, &n, max_k, first_cache, follow_cache);
+            (n, k)
+        })
+        .collect()
+}
+
+///
+/// Returns the maximum lookahead of the given results of [decide_non_terminals].
+/// Non-terminals that can't be decided count with max_k.
+///
+pub fn max_lookahead(decisions: &[(String, Result<Option<usize>>)], max_k: usize) -> usize {
+    decisions
+        .iter()
+        .map(|(_, k)| match k {
+            Ok(Some(k)) => *k,
+            _ => max_k,
+        })
+        .fold(0, std::cmp::max)
+}
+
 ///
 /// Calculates maximum lookahead size where max_k is the limit.
 ///
@@ -152,17 +223,8 @@ pub fn calculate_k(
     first_cache: &FirstCache,
     follow_cache: &FollowCache,
 ) -> Result<usize> {
-    let cfg = &grammar_config.cfg;
-    Ok(cfg
-        .get_non_terminal_set()
-        .iter()
-        .map(|n| decidable(grammar_config, n, max_k, first_cache, follow_cache).unwrap_or(max_k))
-        .fold(0, std::cmp::max))
-    // .fold(Ok(0), |k, r| match (&k, &r) {
-    //     (Err(_), _) => k, // The first error is retained
-    //     (Ok(max_k), Ok(current_k)) => Ok(std::cmp::max(*max_k, *current_k)),
-    //     (Ok(_), Err(_)) => r, // The first error occurred here
-    // })
+    let decisions = decide_non_terminals(grammar_config, max_k, first_cache, follow_cache);
+    Ok(max_lookahead(&decisions, max_k))
 }
 
 ///
@@ -241,9 +303,70 @@ pub fn calculate_lookahead_dfas(
 
     let k_tuples_of_productions =
         calculate_k_tuples(grammar_config, max_k, &first_cache, &follow_cache)?;
-    k_tuples_of_productions.iter().try_fold(
-        BTreeMap::<String, LookaheadDFA>::new(),
-        |mut acc, (i, t)| {
+    unite_lookahead_dfas(cfg, &k_tuples_of_productions, BTreeMap::new())
+}
+
+// ---------------------------------------------------
+// Part of the Public API
+// *Changes will affect crate's version according to semver*
+// ---------------------------------------------------
+///
+/// Calculates lookahead DFAs for all non-terminals, where k is the limit.
+/// Other than [calculate_lookahead_dfas] this doesn't fail for non-terminals that need more than
+/// max_k tokens of lookahead. Their DFAs are marked as adaptive and decide only with one token
+/// where this is unambiguous. The parser predicts the production by speculative parsing otherwise.
+///
+pub fn calculate_adaptive_lookahead_dfas(
+    grammar_config: &GrammarConfig,
+    max_k: usize,
+) -> Result<BTreeMap<String, LookaheadDFA>> {
+    let cfg = &grammar_config.cfg;
+
+    let first_cache = FirstCache::new();
+    let follow_cache = FollowCache::new();
+    let nti = Rc::new(cfg.get_non_terminal_index_function());
+
+    cfg.get_non_terminal_set()
+        .into_iter()
+        .try_fold(BTreeMap::new(), |mut acc, nt| {
+            let Some(k) = lookahead_k(grammar_config, &nt, max_k, &first_cache, &follow_cache)?
+            else {
+                trace!("Non-terminal {} is predicted adaptively", nt);
+                let k_tuples = calculate_tuples_for_non_terminal(
+                    nt.clone(),
+                    1,
+                    grammar_config,
+                    &first_cache,
+                    &follow_cache,
+                    nti.clone(),
+                    BTreeMap::new(),
+                )?;
+                acc.insert(nt, adaptive_lookahead_dfa(&k_tuples));
+                return Ok(acc);
+            };
+            let k_tuples = calculate_tuples_for_non_terminal(
+                nt.clone(),
+                k,
+                grammar_config,
+                &first_cache,
+                &follow_cache,
+                nti.clone(),
+                BTreeMap::new(),
+            )?;
+            unite_lookahead_dfas(cfg, &k_tuples, acc)
+        })
+}
+
+// Unites the DFAs built from the k-tuples of the given productions into the DFAs of their
+// non-terminals.
+fn unite_lookahead_dfas(
+    cfg: &Cfg,
+    k_tuples_of_productions: &BTreeMap<ProductionIndex, KTuples>,
+    dfas: BTreeMap<String, LookaheadDFA>,
+) -> Result<BTreeMap<String, LookaheadDFA>> {
+    k_tuples_of_productions
+        .iter()
+        .try_fold(dfas, |mut acc, (i, t)| {
             let nt = cfg[*i].get_n();
             let dfa = LookaheadDFA::from_k_tuples(t, *i);
             if let Some(found_dfa) = acc.remove(&nt) {
@@ -253,8 +376,44 @@ pub fn calculate_lookahead_dfas(
                 acc.insert(nt, dfa);
             }
             Ok(acc)
+        })
+}
+
+// Builds the DFA of an adaptively predicted non-terminal from the 1-tuples of its productions.
+// A state after the first token is only accepting if exactly one production can start with it.
+fn adaptive_lookahead_dfa(k_tuples: &BTreeMap<ProductionIndex, KTuples>) -> LookaheadDFA {
+    let productions_by_terminal = k_tuples.iter().fold(
+        BTreeMap::<TerminalIndex, Vec<ProductionIndex>>::new(),
+        |mut acc, (pi, t)| {
+            for k_tuple in t.sorted() {
+                if let Some(ti) = k_tuple.terminals().iter().next() {
+                    acc.entry(ti).or_default().push(*pi);
+                }
+            }
+            acc
         },
-    )
+    );
+    let mut dfa = LookaheadDFA {
+        states: vec![DFAState {
+            id: 0,
+            prod_num: INVALID_PROD,
+        }],
+        k: 1,
+        adaptive: true,
+        ..Default::default()
+    };
+    for (ti, productions) in productions_by_terminal {
+        let id = dfa.states.len();
+        dfa.states.push(DFAState {
+            id,
+            prod_num: match productions[..] {
+                [pi] => pi as CompiledProductionIndex,
+                _ => INVALID_PROD,
+            },
+        });
+        dfa.transitions.entry(0).or_default().insert(ti, id);
+    }
+    dfa
 }
 
 ///
@@ -314,9 +473,13 @@ pub fn explain_conflicts(
 
 #[cfg(test)]
 mod test {
-    use super::{calculate_k, decidable, FirstCache, FollowCache};
+    use super::{
+        calculate_adaptive_lookahead_dfas, calculate_k, decidable, decide_non_terminals,
+        max_lookahead, FirstCache, FollowCache,
+    };
+    use crate::analysis::lookahead_dfa::INVALID_PROD;
     use crate::grammar::SymbolAttribute;
-    use crate::{Cfg, GrammarConfig, Pr, Symbol, Terminal, TerminalKind};
+    use crate::{Cfg, GrammarAnalysisError, GrammarConfig, Pr, Symbol, Terminal, TerminalKind};
 
     macro_rules! terminal {
         ($term:literal) => {
@@ -326,6 +489,8 @@ mod test {
                 vec![0],
                 SymbolAttribute::None,
                 None,
+                None,
+                None,
             ))
         };
     }
@@ -396,4 +561,58 @@ mod test {
         let result = calculate_k(&grammar_config, 5, &first_cache, &follow_cache).unwrap();
         assert_eq!(1, result);
     }
+
+    #[test]
+    fn check_decide_non_terminals() {
+        // S can't be decided with any k
+        let cfg = Cfg::with_start_symbol("S")
+            .add_pr(Pr::new("S", vec![Symbol::n("A"), terminal!("x")]))
+            .add_pr(Pr::new("S", vec![Symbol::n("B"), terminal!("y")]))
+            .add_pr(Pr::new("A", vec![terminal!("a"), Symbol::n("A")]))
+            .add_pr(Pr::new("A", vec![terminal!("a")]))
+            .add_pr(Pr::new("B", vec![terminal!("a"), Symbol::n("B")]))
+            .add_pr(Pr::new("B", vec![terminal!("a")]));
+        let grammar_config = GrammarConfig::new(cfg, 3);
+        let first_cache = FirstCache::new();
+        let follow_cache = FollowCache::new();
+        let decisions = decide_non_terminals(&grammar_config, 3, &first_cache, &follow_cache);
+        let k_of = |nt: &str| {
+            decisions
+                .iter()
+                .find(|(n, _)| n == nt)
+                .map(|(_, k)| *k.as_ref().unwrap())
+                .unwrap()
+        };
+        assert_eq!(None, k_of("S"));
+        assert_eq!(Some(2), k_of("A"));
+        assert_eq!(3, max_lookahead(&decisions, 3));
+        assert!(matches!(
+            decidable(&grammar_config, "S", 3, &first_cache, &follow_cache)
+                .unwrap_err()
+                .downcast_ref::<GrammarAnalysisError>(),
+            Some(GrammarAnalysisError::MaxKExceeded { .. })
+        ));
+    }
+
+    #[test]
+    fn check_calculate_adaptive_lookahead_dfas() {
+        // S can't be decided with any k
+        let cfg = Cfg::with_start_symbol("S")
+            .add_pr(Pr::new("S", vec![Symbol::n("A"), terminal!("x")]))
+            .add_pr(Pr::new("S", vec![Symbol::n("B"), terminal!("y")]))
+            .add_pr(Pr::new("A", vec![terminal!("a"), Symbol::n("A")]))
+            .add_pr(Pr::new("A", vec![terminal!("a")]))
+            .add_pr(Pr::new("B", vec![terminal!("a"), Symbol::n("B")]))
+            .add_pr(Pr::new("B", vec![terminal!("a")]));
+        let grammar_config = GrammarConfig::new(cfg, 3);
+        let la_dfas = calculate_adaptive_lookahead_dfas(&grammar_config, 3).unwrap();
+        let s = &la_dfas["S"];
+        assert!(s.adaptive);
+        assert_eq!(1, s.k);
+        // The only first terminal 'a' is shared by both productions
+        assert!(s.states.iter().all(|state| state.prod_num == INVALID_PROD));
+        let a = &la_dfas["A"];
+        assert!(!a.adaptive);
+        assert_eq!(2, a.k);
+    }
 }
@@ -0,0 +1,493 @@
+//! Counterexamples for LALR(1) parse table conflicts.
+//! A counterexample consists of two derivations from the start symbol, one for each of the two
+//! conflicting actions. Both derivations reach the conflicting state with the conflicting token as
+//! lookahead. If they derive the same sentential form the grammar is ambiguous.
+//! The derivations are found by a breadth-first search backwards through the items of the LR(0)
+//! state machine, similar to the counterexamples of Bison.
+use std::{
+    collections::{BTreeMap, BTreeSet, VecDeque},
+    fmt::Display,
+};
+
+use crate::Cfg;
+use parol_runtime::{lexer::EOI, NonTerminalIndex, ProductionIndex, TerminalIndex};
+
+use super::lalr1_parse_table::{Item, LRConflict};
+
+type GrammarLalr = lalry::Grammar<TerminalIndex, NonTerminalIndex, ProductionIndex>;
+type SymbolLalr = lalry::Symbol<TerminalIndex, NonTerminalIndex>;
+
+/// One of the two derivations of a counterexample.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LRConflictDerivation {
+    /// The conflicting action, e.g. "reducing with production 3"
+    pub action: String,
+    /// The derived sentential form, the bullet marks the position of the conflict
+    pub example: String,
+    /// The derivation tree, one line for the start symbol and one for each applied production
+    pub derivation: Vec<String>,
+}
+
+/// A counterexample for a LALR(1) parse table conflict.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LRCounterexample {
+    /// The derivation for the reduction, or for the first reduction of a reduce-reduce conflict
+    pub first: LRConflictDerivation,
+    /// The derivation for the shift, or for the second reduction of a reduce-reduce conflict
+    pub second: LRConflictDerivation,
+}
+
+impl LRCounterexample {
+    /// Returns true if both derivations derive the same sentential form. This proves that the
+    /// grammar is ambiguous.
+    pub fn is_ambiguous(&self) -> bool {
+        self.first.example == self.second.example
+    }
+}
+
+impl Display for LRCounterexample {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let ambiguous = self.is_ambiguous();
+        if ambiguous {
+            writeln!(f, "Ambiguous example: {}", self.first.example)?;
+        }
+        for (ordinal, derivation) in [("First", &self.first), ("Second", &self.second)] {
+            if !ambiguous {
+                writeln!(f, "{} example: {}", ordinal, derivation.example)?;
+            }
+            writeln!(f, "Derivation for {}:", derivation.action)?;
+            for line in &derivation.derivation {
+                writeln!(f, "  {}", line)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The lookahead constraint of a node in the search. The conflicting token must follow the
+/// derived prefix. This is decided by the first non-nullable symbol after the conflict position.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Lookahead {
+    /// The symbols after the conflict position seen so far are nullable
+    Pending,
+    /// The conflicting token follows the conflict position
+    Satisfied,
+}
+
+/// A node of the search: a position in the state path, a state and an item of this state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+struct Node {
+    path_index: usize,
+    state: usize,
+    prod: ProductionIndex,
+    pos: usize,
+    lookahead: Lookahead,
+}
+
+/// Finds counterexamples in the LR(0) state machine of a grammar.
+pub(crate) struct CounterexampleFinder<'a> {
+    cfg: &'a Cfg,
+    /// The left-hand side and the right-hand side symbols of each production
+    rules: Vec<(NonTerminalIndex, Vec<SymbolLalr>)>,
+    /// The FIRST set and the nullability of each non-terminal
+    first_sets: BTreeMap<NonTerminalIndex, (BTreeSet<TerminalIndex>, bool)>,
+    /// The items of each state
+    states: Vec<BTreeSet<Item>>,
+    /// The states with a transition into each state
+    predecessors: Vec<BTreeSet<usize>>,
+    /// The production of the start symbol the LR(0) state machine starts with
+    start_prod: ProductionIndex,
+}
+
+impl<'a> CounterexampleFinder<'a> {
+    pub(crate) fn new(cfg: &'a Cfg, grammar: &GrammarLalr) -> Self {
+        let mut rules = vec![(0, vec![]); cfg.pr.len()];
+        for (lhs, rhss) in &grammar.rules {
+            for rhs in rhss {
+                rules[rhs.act] = (*lhs, rhs.syms.clone());
+            }
+        }
+        let first_sets = grammar
+            .first_sets()
+            .into_iter()
+            .map(|(n, (first, nullable))| (*n, (first.into_iter().cloned().collect(), nullable)))
+            .collect();
+        let state_machine = grammar.lr0_state_machine();
+        let mut predecessors = vec![BTreeSet::new(); state_machine.states.len()];
+        for (p, (_, transitions)) in state_machine.states.iter().enumerate() {
+            for q in transitions.values() {
+                predecessors[*q].insert(p);
+            }
+        }
+        let states = state_machine
+            .states
+            .into_iter()
+            .map(|(item_set, _)| item_set.items.into_iter().map(Item::from).collect())
+            .collect();
+        let start_prod = grammar.rules[&grammar.start][0].act;
+        CounterexampleFinder {
+            cfg,
+            rules,
+            first_sets,
+            states,
+            predecessors,
+            start_prod,
+        }
+    }
+
+    /// Finds a counterexample for the given conflict.
+    /// Returns `None` if no derivation can be found for one of the two actions.
+    pub(crate) fn find(&self, conflict: &LRConflict) -> Option<LRCounterexample> {
+        let (state_items, token, first_item, second_items, second_action) = match conflict {
+            LRConflict::ShiftReduce { state, token, rule } => {
+                let shift_items = state
+                    .items
+                    .iter()
+                    .filter(|i| {
+                        self.rules[i.prod].1.get(i.pos) == Some(&SymbolLalr::Terminal(*token))
+                    })
+                    .map(|i| (i.prod, i.pos))
+                    .collect::<Vec<_>>();
+                let action = shift_items.first().map_or(String::default(), |(p, pos)| {
+                    format!("shifting {}", self.cfg.pr[*p].get_r()[*pos])
+                });
+                (&state.items, *token, *rule, shift_items, action)
+            }
+            LRConflict::ReduceReduce {
+                state,
+                token,
+                r1,
+                r2,
+            } => (
+                &state.items,
+                *token,
+                *r1,
+                vec![(*r2, self.rules[*r2].1.len())],
+                format!("reducing with production {}", r2),
+            ),
+        };
+        let state = self.states.iter().position(|s| s == state_items)?;
+
+        let first_item = (first_item, self.rules[first_item].1.len());
+        let first_path = self.search(state, first_item, token, None)?;
+        // The second derivation preferably shares the prefix with the first one
+        let state_path = self.state_path(&first_path);
+        let second_path = second_items
+            .iter()
+            .filter_map(|i| self.search(state, *i, token, Some(&state_path)))
+            .min_by_key(|p| p.len())
+            .or_else(|| {
+                second_items
+                    .iter()
+                    .filter_map(|i| self.search(state, *i, token, None))
+                    .min_by_key(|p| p.len())
+            })?;
+
+        Some(LRCounterexample {
+            first: self.derivation(
+                format!("reducing with production {}", first_item.0),
+                &first_path,
+            ),
+            second: self.derivation(second_action, &second_path),
+        })
+    }
+
+    /// Searches backwards from the given item in the conflicting state to the start item.
+    /// If a state path is given, the search is restricted to derivations that pass its states.
+    /// Returns the nodes from the start item to the conflicting item.
+    fn search(
+        &self,
+        state: usize,
+        (prod, pos): (ProductionIndex, usize),
+        token: TerminalIndex,
+        state_path: Option<&[usize]>,
+    ) -> Option<Vec<Node>> {
+        let path_index = state_path.map_or(0, |p| p.len() - 1);
+        if state_path.is_some_and(|p| p[path_index] != state) {
+            return None;
+        }
+        let lookahead = if pos < self.rules[prod].1.len() {
+            // The conflicting token is shifted
+            Lookahead::Satisfied
+        } else {
+            Lookahead::Pending
+        };
+        let goal = Node {
+            path_index,
+            state,
+            prod,
+            pos,
+            lookahead,
+        };
+        let mut successors = BTreeMap::new();
+        let mut queue = VecDeque::from([goal]);
+        successors.insert(goal, None);
+        while let Some(node) = queue.pop_front() {
+            if self.is_start(&node, token) {
+                let mut path = vec![node];
+                let mut current = node;
+                while let Some(Some(next)) = successors.get(&current) {
+                    path.push(*next);
+                    current = *next;
+                }
+                return Some(path);
+            }
+            for predecessor in self.predecessor_nodes(&node, token, state_path) {
+                if let std::collections::btree_map::Entry::Vacant(e) = successors.entry(predecessor)
+                {
+                    e.insert(Some(node));
+                    queue.push_back(predecessor);
+                }
+            }
+        }
+        None
+    }
+
+    fn is_start(&self, node: &Node, token: TerminalIndex) -> bool {
+        node.state == 0
+            && node.path_index == 0
+            && node.prod == self.start_prod
+            && node.pos == 0
+            && (node.lookahead == Lookahead::Satisfied || token == EOI)
+    }
+
+    fn predecessor_nodes(
+        &self,
+        node: &Node,
+        token: TerminalIndex,
+        state_path: Option<&[usize]>,
+    ) -> Vec<Node> {
+        if node.pos > 0 {
+            // Reverse transition over the symbol before the dot
+            let item = Item {
+                prod: node.prod,
+                pos: node.pos - 1,
+            };
+            let path_index = match state_path {
+                Some(_) if node.path_index == 0 => return vec![],
+                Some(_) => node.path_index - 1,
+                None => 0,
+            };
+            self.predecessors[node.state]
+                .iter()
+                .filter(|p| match state_path {
+                    Some(s) => s[path_index] == **p,
+                    None => true,
+                })
+                .filter(|p| self.states[**p].contains(&item))
+                .map(|p| Node {
+                    path_index,
+                    state: *p,
+                    prod: node.prod,
+                    pos: node.pos - 1,
+                    lookahead: node.lookahead,
+                })
+                .collect()
+        } else {
+            // Reverse production step to the items in the same state that expand the left-hand
+            // side
+            let lhs = SymbolLalr::Nonterminal(self.rules[node.prod].0);
+            self.states[node.state]
+                .iter()
+                .filter(|i| self.rules[i.prod].1.get(i.pos) == Some(&lhs))
+                .filter_map(|i| {
+                    let lookahead = match node.lookahead {
+                        Lookahead::Satisfied => Lookahead::Satisfied,
+                        Lookahead::Pending => {
+                            self.lookahead_after(&self.rules[i.prod].1[i.pos + 1..], token)?
+                        }
+                    };
+                    Some(Node {
+                        path_index: node.path_index,
+                        state: node.state,
+                        prod: i.prod,
+                        pos: i.pos,
+                        lookahead,
+                    })
+                })
+                .collect()
+        }
+    }
+
+    /// Decides if the token can follow the conflict position when the given symbols come next.
+    /// Returns `None` if the symbols exclude the token.
+    fn lookahead_after(&self, symbols: &[SymbolLalr], token: TerminalIndex) -> Option<Lookahead> {
+        for symbol in symbols {
+            match symbol {
+                SymbolLalr::Terminal(t) => {
+                    return (*t == token).then_some(Lookahead::Satisfied);
+                }
+                SymbolLalr::Nonterminal(n) => {
+                    let (first, nullable) = &self.first_sets[n];
+                    if first.contains(&token) {
+                        return Some(Lookahead::Satisfied);
+                    }
+                    if !nullable {
+                        return None;
+                    }
+                }
+            }
+        }
+        Some(Lookahead::Pending)
+    }
+
+    /// The states of the path that are entered by transitions, starting with the start state.
+    /// A step of the path is a transition if it advances the dot, otherwise it's a production step.
+    fn state_path(&self, path: &[Node]) -> Vec<usize> {
+        path.windows(2)
+            .filter(|w| w[1].pos > 0)
+            .fold(vec![path[0].state], |mut acc, w| {
+                acc.push(w[1].state);
+                acc
+            })
+    }
+
+    /// Builds the derivation tree and the derived sentential form of the path.
+    fn derivation(&self, action: String, path: &[Node]) -> LRConflictDerivation {
+        // Each production step opens a new frame, a transition advances the innermost frame
+        let mut frames: Vec<(ProductionIndex, usize)> = vec![(path[0].prod, path[0].pos)];
+        for w in path.windows(2) {
+            let top = frames.last_mut().unwrap();
+            if w[1].pos > 0 {
+                top.1 += 1;
+            } else {
+                frames.push((w[1].prod, w[1].pos));
+            }
+        }
+
+        let names = |prod: ProductionIndex| {
+            self.cfg.pr[prod]
+                .get_r()
+                .iter()
+                .map(|s| s.to_string())
+                .collect::<Vec<_>>()
+        };
+
+        let mut prefix = vec![];
+        let mut suffix = vec![];
+        for (i, (prod, pos)) in frames.iter().enumerate() {
+            let symbols = names(*prod);
+            prefix.extend_from_slice(&symbols[..*pos]);
+            // The innermost frame continues after the dot, the others after the expanded symbol
+            let rest = if i + 1 == frames.len() { *pos } else { pos + 1 };
+            suffix.splice(0..0, symbols[rest.min(symbols.len())..].iter().cloned());
+        }
+        let example = prefix
+            .into_iter()
+            .chain(std::iter::once("•".to_string()))
+            .chain(suffix)
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        let mut derivation = vec![self.cfg.pr[frames[0].0].get_n_str().to_string()];
+        let mut column = 0;
+        for (i, (prod, pos)) in frames.iter().enumerate() {
+            let mut symbols = names(*prod);
+            let head = format!("↳ {}: ", prod);
+            // The column of the expanded symbol that the next frame's line starts under
+            let next_column = column
+                + head.chars().count()
+                + symbols[..*pos]
+                    .iter()
+                    .map(|s| s.chars().count() + 1)
+                    .sum::<usize>();
+            if i + 1 == frames.len() {
+                symbols.insert(*pos, "•".to_string());
+            }
+            derivation.push(format!(
+                "{}{}{}",
+                " ".repeat(column),
+                head,
+                symbols.join(" ")
+            ));
+            column = next_column;
+        }
+
+        LRConflictDerivation {
+            action,
+            example,
+            derivation,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        analysis::lalr1_parse_table::calculate_lalr1_parse_table,
+        generators::grammar_trans::check_and_transform_grammar, LRCounterexample,
+    };
+
+    fn counterexamples(grammar: &str) -> Vec<LRCounterexample> {
+        let mut grammar_config = crate::obtain_grammar_config_from_string(grammar, false).unwrap();
+        let cfg =
+            check_and_transform_grammar(&grammar_config.cfg, grammar_config.grammar_type).unwrap();
+        grammar_config.update_cfg(cfg);
+        let (_, resolved_conflicts) = calculate_lalr1_parse_table(&grammar_config).unwrap();
+        resolved_conflicts
+            .into_iter()
+            .map(|c| c.counterexample.unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn check_ambiguous_shift_reduce_conflict() {
+        let counterexamples = counterexamples(
+            r"%start Stmt
+%grammar_type 'LALR(1)'
+%%
+Stmt: If | 's';
+If: 'if' Stmt | 'if' Stmt 'else' Stmt;
+",
+        );
+        assert_eq!(1, counterexamples.len());
+        let counterexample = &counterexamples[0];
+        assert!(counterexample.is_ambiguous());
+        assert_eq!("'if' 'if' Stmt • 'else' Stmt", counterexample.first.example);
+        assert_eq!("reducing with production 3", counterexample.first.action);
+        assert_eq!("shifting 'else'", counterexample.second.action);
+        assert_eq!(
+            vec![
+                "Stmt0",
+                "↳ 0: Stmt",
+                "     ↳ 1: If",
+                "          ↳ 4: 'if' Stmt 'else' Stmt",
+                "                    ↳ 1: If",
+                "                         ↳ 3: 'if' Stmt •",
+            ],
+            counterexample.first.derivation
+        );
+        assert_eq!(
+            vec![
+                "Stmt0",
+                "↳ 0: Stmt",
+                "     ↳ 1: If",
+                "          ↳ 3: 'if' Stmt",
+                "                    ↳ 1: If",
+                "                         ↳ 4: 'if' Stmt • 'else' Stmt",
+            ],
+            counterexample.second.derivation
+        );
+    }
+
+    #[test]
+    fn check_unambiguous_reduce_reduce_conflict() {
+        // The grammar is LR(2)
+        let counterexamples = counterexamples(
+            r"%start S
+%grammar_type 'LALR(1)'
+%%
+S: A 'x' | B 'x' 'y';
+A: X;
+B: X;
+X: 'a';
+",
+        );
+        assert_eq!(1, counterexamples.len());
+        let counterexample = &counterexamples[0];
+        assert!(!counterexample.is_ambiguous());
+        assert_eq!("X • 'x'", counterexample.first.example);
+        assert_eq!("X • 'x' 'y'", counterexample.second.example);
+        assert_eq!("reducing with production 4", counterexample.second.action);
+    }
+}
@@ -13,9 +13,14 @@ use std::{
     fmt::Display,
 };
 
+use super::lalr1_counterexample::{CounterexampleFinder, LRCounterexample};
 use crate::{
-    grammar::cfg::{NonTerminalIndexFn, TerminalIndexFn},
-    render_par_string, Cfg, GrammarAnalysisError, GrammarConfig, Pr, Terminal,
+    grammar::{
+        cfg::{NonTerminalIndexFn, TerminalIndexFn},
+        ERROR_TOKEN_NAME,
+    },
+    render_par_string, Associativity, Cfg, GrammarAnalysisError, GrammarConfig, Pr,
+    PrecedenceSymbol, Symbol, Terminal,
 };
 use anyhow::{anyhow, Result};
 use lalry::{Config, LR1ResolvedConflict, LRConflictResolution};
@@ -27,7 +32,6 @@ use parol_runtime::{
 
 /// Type aliases for the LALR(1) parse table construction.
 /// The generic parameters are defined to be terminal, non-terminal, and production indices.
-
 type LR1ParseTableLalr<'a> =
     lalry::LR1ParseTable<'a, TerminalIndex, NonTerminalIndex, ProductionIndex>;
 type LR1StateLalr<'a> = lalry::LR1State<'a, TerminalIndex, NonTerminalIndex, ProductionIndex>;
@@ -44,23 +48,27 @@ impl From<&Cfg> for GrammarLalr {
     fn from(cfg: &Cfg) -> Self {
         let ti = cfg.get_terminal_index_function();
         let nti = cfg.get_non_terminal_index_function();
+        let error_token_index = cfg.get_error_token_index();
 
         let mut grammar = GrammarLalr {
             rules: BTreeMap::new(),
             start: nti.non_terminal_index(&cfg.st),
         };
 
-        for (i, Pr(s, rhs, _)) in cfg.pr.iter().enumerate() {
+        for (i, Pr(s, rhs, ..)) in cfg.pr.iter().enumerate() {
             let lhs = nti.non_terminal_index(s.get_n_ref().unwrap());
             let rhs = RhsLalr {
                 syms: rhs
                     .iter()
                     .map(|s| match s {
-                        crate::Symbol::N(n, _, _) => {
+                        crate::Symbol::N(n, ..) => {
                             lalry::Symbol::Nonterminal(nti.non_terminal_index(n))
                         }
-                        crate::Symbol::T(Terminal::Trm(s, k, _, _, _)) => {
-                            lalry::Symbol::Terminal(ti.terminal_index(s, *k))
+                        crate::Symbol::T(Terminal::Trm(s, k, _, _, _, c, _)) => {
+                            lalry::Symbol::Terminal(ti.terminal_index(s, *k, c.as_ref()))
+                        }
+                        crate::Symbol::T(Terminal::Error(..)) => {
+                            lalry::Symbol::Terminal(error_token_index)
                         }
                         _ => unreachable!(),
                     })
@@ -77,7 +85,7 @@ impl From<&Cfg> for GrammarLalr {
 
 /// An item in the LR(0) state machine.
 /// Duplicate of the `lalr` crate's `Item` type without the reference to the creating grammar.
-#[derive(Debug, Ord, PartialOrd, Eq, PartialEq)]
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq)]
 pub struct Item {
     /// The production of the item.
     pub prod: ProductionIndex,
@@ -96,7 +104,7 @@ impl From<ItemLalr<'_>> for Item {
 
 /// An item set in the LR(0) state machine.
 /// Duplicate of the `lalr` crate's `ItemSet` type without the reference to the creating grammar.
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ItemSet {
     /// The items in the set.
     pub items: BTreeSet<Item>,
@@ -140,6 +148,9 @@ pub struct LR1State {
     pub actions: BTreeMap<TerminalIndex, LRAction>,
     /// The gotos to take for each non-terminal in the state.
     pub gotos: BTreeMap<NonTerminalIndex, usize>,
+    /// The actions of unresolved conflicts that are taken in addition to the ones in `actions`.
+    /// Only the parse table of a GLR grammar contains them.
+    pub conflicting_actions: BTreeMap<TerminalIndex, Vec<LRAction>>,
 }
 
 impl From<LR1StateLalr<'_>> for LR1State {
@@ -158,13 +169,17 @@ impl From<LR1StateLalr<'_>> for LR1State {
 
         let gotos = state.goto.into_iter().map(|(n, s)| (*n, s)).collect();
 
-        LR1State { actions, gotos }
+        LR1State {
+            actions,
+            gotos,
+            conflicting_actions: BTreeMap::new(),
+        }
     }
 }
 
 /// A LALR(1) parse table conflict.
 /// Duplicate of the `lalr` crate's `LR1Conflict` type without the reference to the creating grammar.
-#[derive(Debug)]
+#[derive(Debug, PartialEq, Eq)]
 pub enum LRConflict {
     /// A reduce-reduce conflict.
     ReduceReduce {
@@ -254,18 +269,33 @@ pub struct LRConflictError {
     /// The conflict that occurred.
     pub conflict: LRConflict,
     cfg: Option<Cfg>,
+    counterexample: Option<LRCounterexample>,
 }
 
 impl LRConflictError {
     /// Create a new `LRConflictError` with the given conflict and optional grammar configuration.
     pub fn new(conflict: LRConflict, cfg: Option<Cfg>) -> Self {
-        LRConflictError { conflict, cfg }
+        LRConflictError {
+            conflict,
+            cfg,
+            counterexample: None,
+        }
     }
 
     /// Set the grammar configuration for the error.
     pub fn set_cfg(&mut self, cfg: Cfg) {
         self.cfg = Some(cfg);
     }
+
+    /// Set the counterexample that shows the conflict.
+    pub fn set_counterexample(&mut self, counterexample: Option<LRCounterexample>) {
+        self.counterexample = counterexample;
+    }
+
+    /// The counterexample that shows the conflict, if one was found.
+    pub fn counterexample(&self) -> Option<&LRCounterexample> {
+        self.counterexample.as_ref()
+    }
 }
 
 impl From<LRConflict> for LRConflictError {
@@ -276,94 +306,111 @@ impl From<LRConflict> for LRConflictError {
 
 impl Display for LRConflictError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        // TODO: Provide a terminal resolver and non-terminal resolver implementation in the Cfg
+        write_conflict(f, &self.conflict, self.cfg.as_ref())?;
+        if let Some(counterexample) = &self.counterexample {
+            write!(f, "{}", counterexample)?;
+        }
+        Ok(())
+    }
+}
 
-        // Terminal index to string function (terminal resolver)
-        let tr: Box<dyn Fn(TerminalIndex) -> String> = if let Some(cfg) = &self.cfg {
-            let terminals = cfg
-                .get_ordered_terminals()
-                .iter()
-                .map(|(t, _, _)| t.to_string())
-                .collect::<Vec<_>>();
-            Box::new(move |ti: TerminalIndex| {
-                if ti >= FIRST_USER_TOKEN {
-                    terminals[(ti - FIRST_USER_TOKEN) as usize].clone()
-                } else {
-                    match ti {
-                        EOI => "<$>".to_owned(),
-                        NEW_LINE => "<NL>".to_owned(),
-                        WHITESPACE => "<WS>".to_owned(),
-                        LINE_COMMENT => "<LC>".to_owned(),
-                        BLOCK_COMMENT => "<BC>".to_owned(),
-                        _ => unreachable!(),
-                    }
+/// Writes the conflict with the productions and terminals of the grammar, if given.
+fn write_conflict(
+    f: &mut std::fmt::Formatter<'_>,
+    conflict: &LRConflict,
+    cfg: Option<&Cfg>,
+) -> std::fmt::Result {
+    // TODO: Provide a terminal resolver and non-terminal resolver implementation in the Cfg
+
+    // Terminal index to string function (terminal resolver)
+    let tr: Box<dyn Fn(TerminalIndex) -> String> = if let Some(cfg) = cfg {
+        let terminals = cfg
+            .get_ordered_terminals()
+            .iter()
+            .map(|(t, ..)| t.to_string())
+            .collect::<Vec<_>>();
+        Box::new(move |ti: TerminalIndex| {
+            if ti >= FIRST_USER_TOKEN {
+                // The error token is the only one that follows the user terminals
+                terminals
+                    .get((ti - FIRST_USER_TOKEN) as usize)
+                    .cloned()
+                    .unwrap_or_else(|| ERROR_TOKEN_NAME.to_owned())
+            } else {
+                match ti {
+                    EOI => "<$>".to_owned(),
+                    NEW_LINE => "<NL>".to_owned(),
+                    WHITESPACE => "<WS>".to_owned(),
+                    LINE_COMMENT => "<LC>".to_owned(),
+                    BLOCK_COMMENT => "<BC>".to_owned(),
+                    _ => unreachable!(),
                 }
-            }) as Box<dyn Fn(TerminalIndex) -> String>
-        } else {
-            // Default resolver that just returns the index as string
-            Box::new(|i: TerminalIndex| i.to_string()) as Box<dyn Fn(TerminalIndex) -> String>
-        };
-
-        match &self.conflict {
-            LRConflict::ReduceReduce {
+            }
+        }) as Box<dyn Fn(TerminalIndex) -> String>
+    } else {
+        // Default resolver that just returns the index as string
+        Box::new(|i: TerminalIndex| i.to_string()) as Box<dyn Fn(TerminalIndex) -> String>
+    };
+
+    match conflict {
+        LRConflict::ReduceReduce {
+            state,
+            token,
+            r1,
+            r2,
+        } => {
+            writeln!(
+                f,
+                "Reduce-reduce conflict in state {:?} on token {}",
                 state,
-                token,
-                r1,
-                r2,
-            } => {
+                tr(*token)
+            )?;
+            if let Some(cfg) = cfg {
                 writeln!(
                     f,
-                    "Reduce-reduce conflict in state {:?} on token {}",
-                    state,
-                    tr(*token)
+                    "Can't decide which of the following two productions to reduce with:",
                 )?;
-                if let Some(cfg) = &self.cfg {
-                    writeln!(
-                        f,
-                        "Can't decide which of the following two productions to reduce with:",
-                    )?;
-                    writeln!(f, "  Production {}: {}", r1, cfg.pr[*r1])?;
-                    writeln!(f, "  Production {}: {}", r2, cfg.pr[*r2])?;
-                }
-                Ok(())
+                writeln!(f, "  Production {}: {}", r1, cfg.pr[*r1])?;
+                writeln!(f, "  Production {}: {}", r2, cfg.pr[*r2])?;
             }
-            LRConflict::ShiftReduce { state, token, rule } => {
-                if let Some(cfg) = &self.cfg {
-                    writeln!(f, "Shift-reduce conflict in state")?;
-                    state.items.iter().for_each(|item| {
-                        let Pr(lhs, rhs, _) = &cfg.pr[item.prod];
-                        let mut r = rhs
-                            .iter()
-                            .enumerate()
-                            .map(|(i, s)| {
-                                if i == item.pos {
-                                    format!("•{}", s)
-                                } else {
-                                    format!("{}", s)
-                                }
-                            })
-                            .collect::<Vec<_>>()
-                            .join(" ");
-                        if item.pos == rhs.len() {
-                            r.push('•');
-                        }
-                        writeln!(f, "  {},{}: {} -> {}", item.prod, item.pos, lhs, r).unwrap();
-                    });
-                    writeln!(
-                        f,
-                        "Can't decide between shifting the token or reducing with the production:",
-                    )?;
-                    writeln!(f, "  Token      {}: {}", token, tr(*token))?;
-                    writeln!(f, "  Production {}: {}", rule, cfg.pr[*rule])?;
-                } else {
-                    writeln!(
-                        f,
-                        "Shift-reduce conflict in state {:?} on token {:?}",
-                        state, token
-                    )?;
-                }
-                Ok(())
+            Ok(())
+        }
+        LRConflict::ShiftReduce { state, token, rule } => {
+            if let Some(cfg) = cfg {
+                writeln!(f, "Shift-reduce conflict in state")?;
+                state.items.iter().for_each(|item| {
+                    let Pr(lhs, rhs, ..) = &cfg.pr[item.prod];
+                    let mut r = rhs
+                        .iter()
+                        .enumerate()
+                        .map(|(i, s)| {
+                            if i == item.pos {
+                                format!("•{}", s)
+                            } else {
+                                format!("{}", s)
+                            }
+                        })
+                        .collect::<Vec<_>>()
+                        .join(" ");
+                    if item.pos == rhs.len() {
+                        r.push('•');
+                    }
+                    writeln!(f, "  {},{}: {} -> {}", item.prod, item.pos, lhs, r).unwrap();
+                });
+                writeln!(
+                    f,
+                    "Can't decide between shifting the token or reducing with the production:",
+                )?;
+                writeln!(f, "  Token      {}: {}", token, tr(*token))?;
+                writeln!(f, "  Production {}: {}", rule, cfg.pr[*rule])?;
+            } else {
+                writeln!(
+                    f,
+                    "Shift-reduce conflict in state {:?} on token {:?}",
+                    state, token
+                )?;
             }
+            Ok(())
         }
     }
 }
@@ -375,6 +422,8 @@ pub struct LRResolvedConflict {
     pub conflict: LRConflict,
     /// The resolution that was applied.
     pub applied_resolution: LRConflictResolution,
+    /// The counterexample that shows the conflict, if one was found.
+    pub counterexample: Option<LRCounterexample>,
 }
 
 impl From<LR1ResolvedConflict<'_, TerminalIndex, NonTerminalIndex, ProductionIndex>>
@@ -386,6 +435,52 @@ impl From<LR1ResolvedConflict<'_, TerminalIndex, NonTerminalIndex, ProductionInd
         LRResolvedConflict {
             conflict: conflict.conflict.into(),
             applied_resolution: conflict.applied_resolution,
+            counterexample: None,
+        }
+    }
+}
+
+impl LRResolvedConflict {
+    /// Returns true if the conflict is a shift-reduce conflict.
+    pub fn is_shift_reduce(&self) -> bool {
+        matches!(self.conflict, LRConflict::ShiftReduce { .. })
+    }
+
+    /// Returns a displayable form of the conflict that shows the productions and terminals of the
+    /// given grammar.
+    pub fn display<'a>(&'a self, cfg: &'a Cfg) -> impl Display + 'a {
+        ResolvedConflictDisplay {
+            resolved_conflict: self,
+            cfg,
+        }
+    }
+}
+
+struct ResolvedConflictDisplay<'a> {
+    resolved_conflict: &'a LRResolvedConflict,
+    cfg: &'a Cfg,
+}
+
+impl Display for ResolvedConflictDisplay<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let LRResolvedConflict {
+            conflict,
+            applied_resolution,
+            counterexample,
+        } = self.resolved_conflict;
+        write_conflict(f, conflict, Some(self.cfg))?;
+        if let Some(counterexample) = counterexample {
+            write!(f, "{}", counterexample)?;
+        }
+        match (conflict, applied_resolution) {
+            (_, LRConflictResolution::ShiftOverReduce) => {
+                writeln!(f, "Resolved by shifting the token")
+            }
+            (LRConflict::ReduceReduce { r1, .. }, LRConflictResolution::ReduceFirstRule)
+            | (LRConflict::ReduceReduce { r2: r1, .. }, LRConflictResolution::ReduceSecondRule) => {
+                writeln!(f, "Resolved by reducing with production {}", r1)
+            }
+            (_, resolution) => writeln!(f, "Resolved by {:?}", resolution),
         }
     }
 }
@@ -396,7 +491,11 @@ impl Display for LRResolvedConflict {
             f,
             "{} resolved by {:?}",
             self.conflict, self.applied_resolution
-        )
+        )?;
+        if let Some(counterexample) = &self.counterexample {
+            write!(f, "{}", counterexample)?;
+        }
+        Ok(())
     }
 }
 
@@ -433,6 +532,8 @@ impl LALRConfig {
 
 impl<'a> Config<'a, TerminalIndex, NonTerminalIndex, ProductionIndex> for LALRConfig {
     fn resolve_shift_reduce_conflict_in_favor_of_shift(&self) -> bool {
+        // Shift-reduce conflicts are collected here and finally resolved by the declared operator
+        // precedences in `Precedences::resolve_shift_reduce_conflicts`.
         true
     }
 
@@ -444,9 +545,12 @@ impl<'a> Config<'a, TerminalIndex, NonTerminalIndex, ProductionIndex> for LALRCo
         &self,
         conflict: LR1ResolvedConflict<'a, TerminalIndex, NonTerminalIndex, ProductionIndex>,
     ) {
+        // The same conflict can be reported more than once during the propagation of lookaheads
         let conflict: LRResolvedConflict = conflict.into();
-        println!("{}", conflict);
-        self.calls.borrow_mut().push(conflict);
+        let mut calls = self.calls.borrow_mut();
+        if !calls.iter().any(|c| c.conflict == conflict.conflict) {
+            calls.push(conflict);
+        }
     }
 
     fn priority_of(
@@ -454,13 +558,187 @@ impl<'a> Config<'a, TerminalIndex, NonTerminalIndex, ProductionIndex> for LALRCo
         rhs: &lalry::Rhs<TerminalIndex, NonTerminalIndex, ProductionIndex>,
         _lookahead: Option<&TerminalIndex>,
     ) -> i32 {
+        // Only used for reduce-reduce conflicts.
         // Use negative production index as priority:
         // The production which comes earlier in the grammar description has the higher priority.
         -(rhs.act as i32)
     }
 }
 
+/// The action chosen for a shift-reduce conflict based on the declared operator precedences.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum PrecedenceResolution {
+    /// The token binds tighter than the production or the operator is right associative.
+    Shift,
+    /// The production binds tighter than the token or the operator is left associative.
+    Reduce,
+    /// The operator is non-associative, the token is a syntax error in this state.
+    Error,
+}
+
+/// Precedence levels and associativities of terminals and productions.
+/// Levels are counted from one, a higher level binds tighter.
+pub(crate) struct Precedences {
+    terminals: BTreeMap<TerminalIndex, (usize, Associativity)>,
+    productions: BTreeMap<ProductionIndex, (usize, Associativity)>,
+}
+
+impl Precedences {
+    pub(crate) fn new(grammar_config: &GrammarConfig) -> Self {
+        let cfg = &grammar_config.cfg;
+        let levels = &grammar_config.precedence_levels;
+        let ti = cfg.get_terminal_index_function();
+        let primary_non_terminal_finder = cfg.get_primary_non_terminal_finder();
+
+        let level_of = |symbol: &PrecedenceSymbol| {
+            levels
+                .iter()
+                .enumerate()
+                .rev()
+                .find(|(_, l)| l.symbols.iter().any(|s| s.matches(symbol)))
+                .map(|(i, l)| (i + 1, l.associativity))
+        };
+
+        let terminals = cfg.get_ordered_terminals().into_iter().fold(
+            BTreeMap::new(),
+            |mut acc, (t, k, c, _)| {
+                let index = ti.terminal_index(t, k, c);
+                let level = level_of(&PrecedenceSymbol::Terminal(t.to_string(), k)).or_else(|| {
+                    primary_non_terminal_finder(index)
+                        .and_then(|n| level_of(&PrecedenceSymbol::Name(n)))
+                });
+                if let Some(level) = level {
+                    acc.insert(index, level);
+                }
+                acc
+            },
+        );
+
+        let productions = cfg
+            .pr
+            .iter()
+            .enumerate()
+            .fold(BTreeMap::new(), |mut acc, (i, p)| {
+                // A production gets its precedence from its %prec directive or otherwise from its
+                // last terminal.
+                let level = if let Some(precedence) = p.get_precedence() {
+                    level_of(precedence)
+                } else {
+                    p.get_r()
+                        .iter()
+                        .rev()
+                        .find_map(|s| match s {
+                            Symbol::T(Terminal::Trm(t, k, _, _, _, c, _)) => {
+                                Some(ti.terminal_index(t, *k, c.as_ref()))
+                            }
+                            _ => None,
+                        })
+                        .and_then(|t| terminals.get(&t).cloned())
+                };
+                if let Some(level) = level {
+                    acc.insert(i, level);
+                }
+                acc
+            });
+
+        Precedences {
+            terminals,
+            productions,
+        }
+    }
+
+    /// Decides a shift-reduce conflict between the given token and production.
+    /// Returns `None` if one of them has no declared precedence.
+    pub(crate) fn decide(
+        &self,
+        token: TerminalIndex,
+        rule: ProductionIndex,
+    ) -> Option<PrecedenceResolution> {
+        let (token_level, _) = self.terminals.get(&token)?;
+        let (rule_level, associativity) = self.productions.get(&rule)?;
+        Some(match token_level.cmp(rule_level) {
+            std::cmp::Ordering::Greater => PrecedenceResolution::Shift,
+            std::cmp::Ordering::Less => PrecedenceResolution::Reduce,
+            std::cmp::Ordering::Equal => match associativity {
+                Associativity::Left => PrecedenceResolution::Reduce,
+                Associativity::Right => PrecedenceResolution::Shift,
+                Associativity::NonAssoc => PrecedenceResolution::Error,
+            },
+        })
+    }
+
+    /// Applies the declared precedences to the shift-reduce conflicts that were resolved by
+    /// shifting during the construction of the parse table.
+    /// Returns the conflicts that could not be decided by precedence and that are therefore left
+    /// resolved in favor of shift, and the reduce-reduce conflicts between several reductions that
+    /// win over the same shift, which are resolved in favor of the first rule.
+    fn resolve_shift_reduce_conflicts(
+        &self,
+        parse_table: &mut LRParseTable,
+        states: &[ItemSet],
+        cfg: &Cfg,
+        conflicts: Vec<LRResolvedConflict>,
+    ) -> Vec<LRResolvedConflict> {
+        let nti = cfg.get_non_terminal_index_function();
+        conflicts
+            .into_iter()
+            .filter_map(|c| {
+                let LRConflict::ShiftReduce { state, token, rule } = &c.conflict else {
+                    return Some(c);
+                };
+                let state_index = states
+                    .iter()
+                    .position(|s| s.items == state.items)
+                    .expect("Conflicting state should be part of the LR(0) state machine");
+                let actions = &mut parse_table.states[state_index].actions;
+                if let Some(LRAction::Reduce(lhs, r)) = actions.get(token).cloned() {
+                    // Another reduction already won over the shift by precedence
+                    let (r1, r2) = (r.min(*rule), r.max(*rule));
+                    if *rule < r {
+                        let lhs = nti.non_terminal_index(cfg.pr[*rule].get_n_str());
+                        actions.insert(*token, LRAction::Reduce(lhs, *rule));
+                    } else {
+                        actions.insert(*token, LRAction::Reduce(lhs, r));
+                    }
+                    return Some(LRResolvedConflict {
+                        conflict: LRConflict::ReduceReduce {
+                            state: state.clone(),
+                            token: *token,
+                            r1,
+                            r2,
+                        },
+                        applied_resolution: LRConflictResolution::ReduceFirstRule,
+                        counterexample: None,
+                    });
+                }
+                let Some(resolution) = self.decide(*token, *rule) else {
+                    return Some(c);
+                };
+                trace!(
+                    "Shift-reduce conflict in state {} on token {} with production {} resolved by precedence: {:?}",
+                    state_index, token, rule, resolution
+                );
+                match resolution {
+                    PrecedenceResolution::Shift => (),
+                    PrecedenceResolution::Reduce => {
+                        let lhs = nti.non_terminal_index(cfg.pr[*rule].get_n_str());
+                        actions.insert(*token, LRAction::Reduce(lhs, *rule));
+                    }
+                    PrecedenceResolution::Error => {
+                        actions.remove(token);
+                    }
+                }
+                None
+            })
+            .collect()
+    }
+}
+
 /// Calculate the LALR(1) parse table for the given grammar configuration.
+/// Shift-reduce conflicts are resolved by the precedences declared with `%left`, `%right` and
+/// `%nonassoc`. Conflicts that can't be decided this way are resolved in favor of shift.
+/// These automatically resolved conflicts are returned along with the parse table. If their
+/// numbers are declared with `%expect` or `%expect-rr` and differ, an error is returned instead.
 pub fn calculate_lalr1_parse_table(
     grammar_config: &GrammarConfig,
 ) -> Result<(LRParseTable, Vec<LRResolvedConflict>)> {
@@ -472,11 +750,208 @@ pub fn calculate_lalr1_parse_table(
     let parse_table = grammar.lalr1(&config).map_err(|e| {
         let conflict: LRConflict = e.into();
         let mut conflict: LRConflictError = conflict.into();
+        conflict
+            .set_counterexample(CounterexampleFinder::new(cfg, &grammar).find(&conflict.conflict));
         conflict.set_cfg(cfg.clone());
         anyhow!(GrammarAnalysisError::LALR1ParseTableConstructionFailed { conflict })
     })?;
     trace!("LALR(1) parse table: {:#?}", parse_table);
-    let parse_table = LRParseTable::from(parse_table);
+    let mut parse_table = LRParseTable::from(parse_table);
+    let states = grammar
+        .lr0_state_machine()
+        .states
+        .into_iter()
+        .map(|(item_set, _)| item_set.into())
+        .collect::<Vec<ItemSet>>();
+    let mut resolved_conflicts = Precedences::new(grammar_config).resolve_shift_reduce_conflicts(
+        &mut parse_table,
+        &states,
+        cfg,
+        config.calls.into_inner(),
+    );
+    if !resolved_conflicts.is_empty() {
+        let finder = CounterexampleFinder::new(cfg, &grammar);
+        for resolved_conflict in &mut resolved_conflicts {
+            resolved_conflict.counterexample = finder.find(&resolved_conflict.conflict);
+        }
+    }
     trace!("Converted LALR(1) parse table: {:#?}", parse_table);
-    Ok((parse_table, config.calls.into_inner()))
+    check_expected_conflicts(grammar_config, resolved_conflicts)
+        .map(|resolved_conflicts| (parse_table, resolved_conflicts))
+}
+
+/// Compares the numbers of the automatically resolved conflicts with the ones declared with
+/// `%expect` and `%expect-rr`. An undeclared number defaults to zero if the other one is declared.
+pub(crate) fn check_expected_conflicts(
+    grammar_config: &GrammarConfig,
+    resolved_conflicts: Vec<LRResolvedConflict>,
+) -> Result<Vec<LRResolvedConflict>> {
+    if !grammar_config.expects_conflicts() {
+        return Ok(resolved_conflicts);
+    }
+    let expected_shift_reduce = grammar_config.expected_shift_reduce_conflicts.unwrap_or(0);
+    let expected_reduce_reduce = grammar_config.expected_reduce_reduce_conflicts.unwrap_or(0);
+    let shift_reduce = resolved_conflicts
+        .iter()
+        .filter(|c| c.is_shift_reduce())
+        .count();
+    let reduce_reduce = resolved_conflicts.len() - shift_reduce;
+    if shift_reduce == expected_shift_reduce && reduce_reduce == expected_reduce_reduce {
+        return Ok(resolved_conflicts);
+    }
+    let conflicts = resolved_conflicts
+        .into_iter()
+        .map(|c| {
+            let mut conflict = LRConflictError::new(c.conflict, Some(grammar_config.cfg.clone()));
+            conflict.set_counterexample(c.counterexample);
+            conflict
+        })
+        .collect();
+    Err(anyhow!(GrammarAnalysisError::UnexpectedConflicts {
+        expected_shift_reduce,
+        shift_reduce,
+        expected_reduce_reduce,
+        reduce_reduce,
+        conflicts,
+    }))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{generators::grammar_trans::check_and_transform_grammar, TerminalKind};
+
+    const GRAMMAR: &str = r"%start Calc
+%grammar_type 'LALR(1)'
+%nonassoc '=='
+%left '+' '-'
+%left '*'
+%right '^'
+%right UMinus
+%%
+Calc: Expr;
+Expr: Expr '==' Expr
+    | Expr '+' Expr
+    | Expr '-' Expr
+    | Expr '*' Expr
+    | Expr '^' Expr
+    | '-' Expr %prec UMinus
+    | 'n';
+";
+
+    fn parse_table() -> (GrammarConfig, LRParseTable, Vec<LRResolvedConflict>) {
+        let mut grammar_config = crate::obtain_grammar_config_from_string(GRAMMAR, false).unwrap();
+        let cfg =
+            check_and_transform_grammar(&grammar_config.cfg, grammar_config.grammar_type).unwrap();
+        grammar_config.update_cfg(cfg);
+        let (parse_table, resolved_conflicts) =
+            calculate_lalr1_parse_table(&grammar_config).unwrap();
+        (grammar_config, parse_table, resolved_conflicts)
+    }
+
+    // Runs the parse table on the given terminals and returns the parenthesized input, or `None`
+    // if the input is rejected.
+    fn parse(grammar_config: &GrammarConfig, table: &LRParseTable, input: &str) -> Option<String> {
+        let cfg = &grammar_config.cfg;
+        let ti = cfg.get_terminal_index_function();
+        let input = input.split_whitespace().collect::<Vec<_>>();
+        let mut pos = 0;
+        let mut states = vec![0];
+        let mut values: Vec<String> = vec![];
+        loop {
+            let token = input
+                .get(pos)
+                .map_or(EOI, |t| ti.terminal_index(t, TerminalKind::Raw, None));
+            match table.states[*states.last().unwrap()].actions.get(&token)? {
+                LRAction::Shift(s) => {
+                    states.push(*s);
+                    values.push(input[pos].to_string());
+                    pos += 1;
+                }
+                LRAction::Reduce(nt, p) => {
+                    let len = cfg.pr[*p].len();
+                    states.truncate(states.len() - len);
+                    let value = values.split_off(values.len() - len);
+                    values.push(if len == 1 {
+                        value[0].clone()
+                    } else {
+                        format!("({})", value.join(" "))
+                    });
+                    states.push(table.states[*states.last().unwrap()].gotos[nt]);
+                }
+                LRAction::Accept => return values.pop(),
+            }
+        }
+    }
+
+    #[test]
+    fn check_conflicts_resolved_by_precedence() {
+        let (_, _, resolved_conflicts) = parse_table();
+        assert!(resolved_conflicts.is_empty());
+    }
+
+    #[test]
+    fn check_expected_conflicts() {
+        let dangling_else = |expect: &str| {
+            let grammar = format!(
+                r"%start Stmt
+%grammar_type 'LALR(1)'
+{expect}
+%%
+Stmt: 'if' 'c' Stmt | 'if' 'c' Stmt 'else' Stmt | 's';
+"
+            );
+            let mut grammar_config =
+                crate::obtain_grammar_config_from_string(&grammar, false).unwrap();
+            let cfg = check_and_transform_grammar(&grammar_config.cfg, grammar_config.grammar_type)
+                .unwrap();
+            grammar_config.update_cfg(cfg);
+            calculate_lalr1_parse_table(&grammar_config).map(|(_, c)| c)
+        };
+        assert_eq!(1, dangling_else("").unwrap().len());
+        assert_eq!(1, dangling_else("%expect 1").unwrap().len());
+        assert_eq!(1, dangling_else("%expect 1 %expect-rr 0").unwrap().len());
+        assert!(dangling_else("%expect 0").is_err());
+        assert!(dangling_else("%expect 2").is_err());
+        assert!(dangling_else("%expect-rr 0").is_err());
+    }
+
+    #[test]
+    fn check_precedence_and_associativity() {
+        let (grammar_config, table, _) = parse_table();
+        let parse = |input: &str| parse(&grammar_config, &table, input);
+        assert_eq!(Some("(n + (n * n))".to_string()), parse("n + n * n"));
+        assert_eq!(Some("((n * n) + n)".to_string()), parse("n * n + n"));
+        assert_eq!(Some("((n - n) - n)".to_string()), parse("n - n - n"));
+        assert_eq!(Some("(n ^ (n ^ n))".to_string()), parse("n ^ n ^ n"));
+        assert_eq!(Some("((- n) ^ n)".to_string()), parse("- n ^ n"));
+        assert_eq!(Some("((- n) - n)".to_string()), parse("- n - n"));
+        assert_eq!(Some("((n + n) == n)".to_string()), parse("n + n == n"));
+        assert_eq!(None, parse("n == n == n"));
+    }
+
+    #[test]
+    fn check_reductions_winning_over_a_shift_keep_the_first_rule() {
+        let grammar = r"%start S
+%grammar_type 'LALR(1)'
+%expect-rr 1
+%left '+'
+%left 'a'
+%%
+S: A '+' 'b' | B '+' 'c' | 'x' 'a' '+' 'd';
+A: 'x' 'a';
+B: 'x' 'a';
+";
+        let mut grammar_config = crate::obtain_grammar_config_from_string(grammar, false).unwrap();
+        let cfg =
+            check_and_transform_grammar(&grammar_config.cfg, grammar_config.grammar_type).unwrap();
+        grammar_config.update_cfg(cfg);
+        let (table, resolved_conflicts) = calculate_lalr1_parse_table(&grammar_config).unwrap();
+        assert_eq!(1, resolved_conflicts.len());
+        assert!(!resolved_conflicts[0].is_shift_reduce());
+        let parse = |input: &str| parse(&grammar_config, &table, input);
+        assert_eq!(Some("((x a) + b)".to_string()), parse("x a + b"));
+        assert_eq!(None, parse("x a + c"));
+        assert_eq!(None, parse("x a + d"));
+    }
 }
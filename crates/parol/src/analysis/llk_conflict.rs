@@ -0,0 +1,474 @@
+//! Witnesses for LL(k) conflicts.
+//! A witness is a k-tuple of terminals that is contained in the lookahead sets of two productions
+//! of the same non-terminal. For each of the two productions a sample derivation from the start
+//! symbol is searched that reaches the non-terminal and derives the k-tuple with this production.
+//! The search is a breadth-first search with limits, so the shortest derivations are found and the
+//! search always terminates.
+use std::{
+    collections::{BTreeMap, HashSet, VecDeque},
+    fmt::Display,
+};
+
+use crate::analysis::compiled_terminal::EPS;
+use crate::analysis::{FirstCache, FollowCache, KTuples};
+use crate::grammar::cfg::{NonTerminalIndexFn, TerminalIndexFn};
+use crate::{GrammarConfig, Symbol, Terminal};
+use anyhow::{anyhow, Result};
+use parol_runtime::{lexer::EOI, ProductionIndex, TerminalIndex};
+
+/// The maximum number of witnesses for each pair of conflicting productions
+const MAX_WITNESSES: usize = 3;
+
+/// The maximum number of paths from the start symbol that are examined
+const MAX_CONTEXTS: usize = 1_000;
+
+/// The maximum number of configurations that are examined to derive one k-tuple
+const MAX_CONFIGURATIONS: usize = 10_000;
+
+/// The derivation of a k-tuple with one of the two conflicting productions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LLKConflictDerivation {
+    /// The production of the non-terminal
+    pub production: ProductionIndex,
+    /// The derived sentential form, the bullet marks the position of the non-terminal, the
+    /// terminals of the k-tuple follow it.
+    /// Empty if no derivation was found within the search limits.
+    pub example: String,
+    /// The derivation tree, one line for the start symbol and one for each production on the path
+    /// to the non-terminal and for the production of the non-terminal itself.
+    /// Empty if no derivation was found within the search limits.
+    pub derivation: Vec<String>,
+}
+
+/// A k-tuple that is shared by the lookahead sets of two productions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LLKConflictWitness {
+    /// The shared k-tuple, `$` stands for the end of the input
+    pub k_tuple: String,
+    /// The derivation with the first production
+    pub first: LLKConflictDerivation,
+    /// The derivation with the second production
+    pub second: LLKConflictDerivation,
+}
+
+impl Display for LLKConflictWitness {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "Productions {} and {} share the lookahead {}",
+            self.first.production, self.second.production, self.k_tuple
+        )?;
+        for derivation in [&self.first, &self.second] {
+            if derivation.derivation.is_empty() {
+                writeln!(
+                    f,
+                    "No sample derivation found for production {}",
+                    derivation.production
+                )?;
+                continue;
+            }
+            writeln!(f, "Example: {}", derivation.example)?;
+            writeln!(f, "Derivation for production {}:", derivation.production)?;
+            for line in &derivation.derivation {
+                writeln!(f, "  {}", line)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The productions of a non-terminal that can't be decided with a lookahead of k tokens.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LLKConflict {
+    /// The non-terminal
+    pub non_terminal: String,
+    /// The lookahead size
+    pub k: usize,
+    /// The shared k-tuples with sample derivations
+    pub witnesses: Vec<LLKConflictWitness>,
+    /// The number of shared k-tuples that are not contained in the witnesses
+    pub omitted: usize,
+}
+
+impl Display for LLKConflict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "Non-terminal {} can't be decided with a lookahead of {} tokens",
+            self.non_terminal, self.k
+        )?;
+        for witness in &self.witnesses {
+            write!(f, "{}", witness)?;
+        }
+        if self.omitted > 0 {
+            writeln!(f, "{} more shared lookahead(s) omitted", self.omitted)?;
+        }
+        Ok(())
+    }
+}
+
+/// A symbol of a production that is relevant for the derivation of terminals
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum DerivationSymbol {
+    T(TerminalIndex),
+    N(String),
+}
+
+/// A path from the start symbol to the non-terminal
+#[derive(Debug, Clone)]
+struct Context {
+    /// The production and the position of the expanded symbol for each step
+    path: Vec<(ProductionIndex, usize)>,
+    /// The symbols that follow the non-terminal, the next one is the last one
+    continuation: Vec<DerivationSymbol>,
+}
+
+/// Finds sample derivations for the k-tuples of conflicting productions.
+struct WitnessFinder<'a> {
+    grammar_config: &'a GrammarConfig,
+    /// The symbols of each production, scanner switches are omitted
+    rules: Vec<Vec<DerivationSymbol>>,
+    /// The display names of the terminals in terminal index order
+    terminal_names: BTreeMap<TerminalIndex, String>,
+    /// The paths from the start symbol to the non-terminal in the order of their lengths
+    contexts: Vec<Context>,
+}
+
+impl<'a> WitnessFinder<'a> {
+    fn new(grammar_config: &'a GrammarConfig, non_terminal: &str) -> Self {
+        let ti = grammar_config.cfg.get_terminal_index_function();
+        let mut terminal_names = BTreeMap::new();
+        terminal_names.insert(EOI, "$".to_string());
+        let rules = grammar_config
+            .cfg
+            .pr
+            .iter()
+            .map(|p| {
+                p.get_r()
+                    .iter()
+                    .filter_map(|s| match s {
+                        Symbol::N(n, ..) => Some(DerivationSymbol::N(n.clone())),
+                        Symbol::T(t @ Terminal::Trm(n, k, _, _, _, c, _)) => {
+                            let i = ti.terminal_index(n, *k, c.as_ref());
+                            terminal_names.entry(i).or_insert_with(|| t.to_string());
+                            Some(DerivationSymbol::T(i))
+                        }
+                        Symbol::T(Terminal::End) => Some(DerivationSymbol::T(EOI)),
+                        _ => None,
+                    })
+                    .collect()
+            })
+            .collect();
+        let mut finder = Self {
+            grammar_config,
+            rules,
+            terminal_names,
+            contexts: Vec::new(),
+        };
+        finder.contexts = finder.find_contexts(non_terminal);
+        finder
+    }
+
+    fn productions(&self, non_terminal: &str) -> impl Iterator<Item = ProductionIndex> + '_ {
+        let non_terminal = non_terminal.to_string();
+        self.grammar_config
+            .cfg
+            .pr
+            .iter()
+            .enumerate()
+            .filter(move |(_, p)| p.get_n_str() == non_terminal)
+            .map(|(i, _)| i)
+    }
+
+    fn symbol_name(&self, symbol: &DerivationSymbol) -> String {
+        match symbol {
+            DerivationSymbol::T(t) => self.terminal_name(*t),
+            DerivationSymbol::N(n) => n.clone(),
+        }
+    }
+
+    fn terminal_name(&self, t: TerminalIndex) -> String {
+        self.terminal_names
+            .get(&t)
+            .cloned()
+            .unwrap_or_else(|| t.to_string())
+    }
+
+    // Collects the paths from the start symbol to the non-terminal breadth-first
+    fn find_contexts(&self, non_terminal: &str) -> Vec<Context> {
+        let mut contexts = Vec::new();
+        let mut visited = HashSet::new();
+        let mut queue = VecDeque::new();
+        queue.push_back((
+            self.grammar_config.cfg.get_start_symbol().to_string(),
+            Context {
+                path: Vec::new(),
+                continuation: Vec::new(),
+            },
+        ));
+        let mut count = 0;
+        while let Some((n, context)) = queue.pop_front() {
+            count += 1;
+            if count > MAX_CONTEXTS {
+                break;
+            }
+            if n == non_terminal {
+                contexts.push(context.clone());
+            }
+            for p in self.productions(&n) {
+                for (pos, symbol) in self.rules[p].iter().enumerate() {
+                    if let DerivationSymbol::N(m) = symbol {
+                        let mut next = context.clone();
+                        next.path.push((p, pos));
+                        next.continuation
+                            .extend(self.rules[p][pos + 1..].iter().rev().cloned());
+                        if visited.insert((m.clone(), next.continuation.clone())) {
+                            queue.push_back((m.clone(), next));
+                        }
+                    }
+                }
+            }
+        }
+        contexts
+    }
+
+    // Searches a derivation of the k-tuple with the given production in one of the contexts.
+    // Returns the context and the symbols that remain after the k-tuple.
+    fn derive(
+        &self,
+        production: ProductionIndex,
+        k_tuple: &[TerminalIndex],
+    ) -> Option<(&Context, Vec<DerivationSymbol>)> {
+        let mut count = 0;
+        for context in &self.contexts {
+            let mut stack = context.continuation.clone();
+            stack.extend(self.rules[production].iter().rev().cloned());
+            let mut visited = HashSet::new();
+            let mut queue = VecDeque::new();
+            queue.push_back((stack, 0));
+            while let Some((mut stack, pos)) = queue.pop_front() {
+                count += 1;
+                if count > MAX_CONFIGURATIONS {
+                    return None;
+                }
+                if pos == k_tuple.len() {
+                    return Some((context, stack));
+                }
+                match stack.pop() {
+                    // The end of the input
+                    None => {
+                        if k_tuple[pos] == EOI {
+                            return Some((context, stack));
+                        }
+                    }
+                    Some(DerivationSymbol::T(t)) => {
+                        if t == k_tuple[pos] && visited.insert((stack.clone(), pos + 1)) {
+                            queue.push_back((stack, pos + 1));
+                        }
+                    }
+                    Some(DerivationSymbol::N(n)) => {
+                        for p in self.productions(&n) {
+                            let mut expanded = stack.clone();
+                            expanded.extend(self.rules[p].iter().rev().cloned());
+                            if visited.insert((expanded.clone(), pos)) {
+                                queue.push_back((expanded, pos));
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        None
+    }
+
+    fn derivation(
+        &self,
+        production: ProductionIndex,
+        k_tuple: &[TerminalIndex],
+    ) -> LLKConflictDerivation {
+        let Some((context, rest)) = self.derive(production, k_tuple) else {
+            return LLKConflictDerivation {
+                production,
+                example: String::new(),
+                derivation: Vec::new(),
+            };
+        };
+
+        let names = |p: ProductionIndex| {
+            self.rules[p]
+                .iter()
+                .map(|s| self.symbol_name(s))
+                .collect::<Vec<String>>()
+        };
+
+        let prefix = context
+            .path
+            .iter()
+            .flat_map(|(p, pos)| names(*p).into_iter().take(*pos))
+            .collect::<Vec<String>>();
+        let example = prefix
+            .into_iter()
+            .chain(std::iter::once("•".to_string()))
+            .chain(
+                k_tuple
+                    .iter()
+                    .filter(|t| **t != EOI)
+                    .map(|t| self.terminal_name(*t)),
+            )
+            .chain(rest.iter().rev().map(|s| self.symbol_name(s)))
+            .collect::<Vec<String>>()
+            .join(" ");
+
+        let mut derivation = vec![self.grammar_config.cfg.get_start_symbol().to_string()];
+        let mut column = 0;
+        for (p, pos) in context.path.iter().chain(std::iter::once(&(production, 0))) {
+            let symbols = names(*p);
+            let head = format!("↳ {}: ", p);
+            // The column of the expanded symbol that the next line starts under
+            let next_column = column
+                + head.chars().count()
+                + symbols[..*pos]
+                    .iter()
+                    .map(|s| s.chars().count() + 1)
+                    .sum::<usize>();
+            derivation.push(format!(
+                "{}{}{}",
+                " ".repeat(column),
+                head,
+                symbols.join(" ")
+            ));
+            column = next_column;
+        }
+
+        LLKConflictDerivation {
+            production,
+            example,
+            derivation,
+        }
+    }
+}
+
+// ---------------------------------------------------
+// Part of the Public API
+// *Changes will affect crate's version according to semver*
+// ---------------------------------------------------
+///
+/// Finds the k-tuples that the lookahead sets of the productions of the given non-terminal share
+/// at lookahead size k. For each pair of conflicting productions at most three of them are
+/// returned as witnesses with sample derivations from the start symbol.
+///
+pub fn find_llk_conflict(
+    grammar_config: &GrammarConfig,
+    non_terminal: &str,
+    k: usize,
+    first_cache: &FirstCache,
+    follow_cache: &FollowCache,
+) -> Result<LLKConflict> {
+    let cfg = &grammar_config.cfg;
+    let productions = cfg.matching_productions(non_terminal);
+    if productions.is_empty() {
+        return Err(anyhow!(
+            "The given non-terminal isn't part of the given grammar!"
+        ));
+    }
+    let mut conflict = LLKConflict {
+        non_terminal: non_terminal.to_string(),
+        k,
+        witnesses: Vec::new(),
+        omitted: 0,
+    };
+    if productions.len() == 1 || k == 0 {
+        return Ok(conflict);
+    }
+
+    let nti = cfg.get_non_terminal_index_function();
+    let cached = follow_cache.get(k, grammar_config, first_cache);
+    let follow_set = cached
+        .1
+        .get(nti.non_terminal_index(non_terminal))
+        .ok_or_else(|| anyhow!("Internal error"))?;
+    let first_set = first_cache.get(k, grammar_config);
+    let k_tuples_of_productions = productions
+        .iter()
+        .map(|(pi, _)| (*pi, first_set.0[*pi].clone().k_concat(follow_set, k)))
+        .collect::<Vec<(ProductionIndex, KTuples)>>();
+
+    let finder = WitnessFinder::new(grammar_config, non_terminal);
+    for (i, (p1, t1)) in k_tuples_of_productions.iter().enumerate() {
+        for (p2, t2) in &k_tuples_of_productions[i + 1..] {
+            let shared = t1.intersection(t2).sorted();
+            conflict.omitted += shared.len().saturating_sub(MAX_WITNESSES);
+            for k_tuple in shared.iter().take(MAX_WITNESSES) {
+                let terminals = k_tuple
+                    .terminals()
+                    .iter()
+                    .filter(|t| *t != EPS)
+                    .collect::<Vec<TerminalIndex>>();
+                conflict.witnesses.push(LLKConflictWitness {
+                    k_tuple: terminals
+                        .iter()
+                        .map(|t| finder.terminal_name(*t))
+                        .collect::<Vec<String>>()
+                        .join(" "),
+                    first: finder.derivation(*p1, &terminals),
+                    second: finder.derivation(*p2, &terminals),
+                });
+            }
+        }
+    }
+    Ok(conflict)
+}
+
+#[cfg(test)]
+mod test {
+    use super::find_llk_conflict;
+    use crate::analysis::{FirstCache, FollowCache};
+    use crate::obtain_grammar_config_from_string;
+
+    #[test]
+    fn check_find_llk_conflict() {
+        let grammar_config = obtain_grammar_config_from_string(
+            r#"%start S %% S: A "x" | B "y"; A: "a" A | "a"; B: "a" B | "a";"#,
+            false,
+        )
+        .unwrap();
+        let first_cache = FirstCache::new();
+        let follow_cache = FollowCache::new();
+        let conflict =
+            find_llk_conflict(&grammar_config, "S", 2, &first_cache, &follow_cache).unwrap();
+        assert_eq!(1, conflict.witnesses.len());
+        let witness = &conflict.witnesses[0];
+        assert_eq!(r#""a" "a""#, witness.k_tuple);
+        assert_eq!(0, witness.first.production);
+        assert_eq!(r#"• "a" "a" A "x""#, witness.first.example);
+        assert_eq!(vec!["S", r#"↳ 0: A "x""#], witness.first.derivation);
+        assert_eq!(1, witness.second.production);
+        assert_eq!(r#"• "a" "a" B "y""#, witness.second.example);
+    }
+
+    #[test]
+    fn check_find_llk_conflict_in_context() {
+        let grammar_config = obtain_grammar_config_from_string(
+            r#"%start S %% S: "(" L ")"; L: I | I "," L; I: "i";"#,
+            false,
+        )
+        .unwrap();
+        let first_cache = FirstCache::new();
+        let follow_cache = FollowCache::new();
+        let conflict =
+            find_llk_conflict(&grammar_config, "L", 1, &first_cache, &follow_cache).unwrap();
+        assert_eq!(1, conflict.witnesses.len());
+        let witness = &conflict.witnesses[0];
+        assert_eq!(r#""i""#, witness.k_tuple);
+        assert_eq!(r#""(" • "i" ")""#, witness.first.example);
+        assert_eq!(
+            vec!["S", r#"↳ 0: "(" L ")""#, r#"         ↳ 1: I"#],
+            witness.first.derivation
+        );
+        assert_eq!(r#""(" • "i" "," L ")""#, witness.second.example);
+        // At k = 2 the productions are decidable
+        let conflict =
+            find_llk_conflict(&grammar_config, "L", 2, &first_cache, &follow_cache).unwrap();
+        assert!(conflict.witnesses.is_empty());
+    }
+}
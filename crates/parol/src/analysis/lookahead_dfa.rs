@@ -76,6 +76,11 @@ pub struct LookaheadDFA {
     /// Maximum number of tokens needed to reach an accepting state
     /// It is equivalent to the maximum length over all contributing k-tuples.
     pub k: usize,
+
+    /// Set if the non-terminal can't be decided with the maximum lookahead size.
+    /// The DFA then only decides with one token where this is unambiguous and the parser predicts
+    /// the production by speculative parsing otherwise.
+    pub adaptive: bool,
 }
 
 ///
@@ -111,6 +116,7 @@ impl LookaheadDFA {
             }],
             transitions: BTreeMap::new(),
             k: 0,
+            adaptive: false,
         };
         trace!("KTuples for production {prod_num}");
         for k_tuple in &k_tuples.sorted() {
@@ -0,0 +1,750 @@
+//! LR(1) parse table construction for the given grammar configuration.
+//! The LALR(1) construction merges all states with the same LR(0) core. This can introduce
+//! reduce-reduce conflicts in grammars that are actually LR(1).
+//! Here the canonical LR(1) collection of item sets is built first. Afterwards states with the
+//! same LR(0) core are merged as long as the merge doesn't add a reduce-reduce conflict, similar
+//! to Pager's minimal LR(1) construction. Thus the parse table stays small for grammars that are
+//! LALR(1) anyway.
+//! The resulting parse table has the same format as the LALR(1) parse table.
+//! The parse table of GLR grammars is built the same way, but it keeps unresolved conflicts.
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+
+use super::lalr1_counterexample::CounterexampleFinder;
+use super::lalr1_parse_table::{
+    check_expected_conflicts, Item, ItemSet, LR1State, LRAction, LRConflict, LRParseTable,
+    LRResolvedConflict, PrecedenceResolution, Precedences,
+};
+use crate::{render_par_string, GrammarConfig};
+use anyhow::Result;
+use lalry::LRConflictResolution;
+use parol_runtime::{lexer::EOI, log::trace, NonTerminalIndex, ProductionIndex, TerminalIndex};
+
+type GrammarLalr = lalry::Grammar<TerminalIndex, NonTerminalIndex, ProductionIndex>;
+type SymbolLalr = lalry::Symbol<TerminalIndex, NonTerminalIndex>;
+
+/// The items of a LR(1) state, i.e. the LR(0) items (production and position) with their
+/// lookahead terminals
+type Items = BTreeMap<(ProductionIndex, usize), BTreeSet<TerminalIndex>>;
+
+/// A state of the canonical LR(1) collection
+#[derive(Debug)]
+struct State {
+    /// The closure of the kernel items
+    items: Items,
+    /// The successor state for each symbol
+    transitions: BTreeMap<SymbolLalr, usize>,
+}
+
+/// Builds the canonical LR(1) collection and merges its states.
+struct LR1Builder {
+    /// The left-hand side and the right-hand side symbols of each production
+    rules: Vec<(NonTerminalIndex, Vec<SymbolLalr>)>,
+    /// The productions of each non-terminal
+    productions: BTreeMap<NonTerminalIndex, Vec<ProductionIndex>>,
+    /// The FIRST set and the nullability of each non-terminal
+    first_sets: BTreeMap<NonTerminalIndex, (BTreeSet<TerminalIndex>, bool)>,
+    /// The start symbol
+    start: NonTerminalIndex,
+    /// The production of the start symbol the parser starts with
+    start_prod: ProductionIndex,
+}
+
+impl LR1Builder {
+    fn new(grammar: &GrammarLalr, production_count: usize) -> Self {
+        let mut rules = vec![(0, vec![]); production_count];
+        let mut productions = BTreeMap::<NonTerminalIndex, Vec<ProductionIndex>>::new();
+        for (lhs, rhss) in &grammar.rules {
+            for rhs in rhss {
+                rules[rhs.act] = (*lhs, rhs.syms.clone());
+                productions.entry(*lhs).or_default().push(rhs.act);
+            }
+        }
+        let first_sets = grammar
+            .first_sets()
+            .into_iter()
+            .map(|(n, (first, nullable))| (*n, (first.into_iter().cloned().collect(), nullable)))
+            .collect();
+        LR1Builder {
+            rules,
+            productions,
+            first_sets,
+            start: grammar.start,
+            start_prod: grammar.rules[&grammar.start][0].act,
+        }
+    }
+
+    /// The terminals that can start the given symbols followed by one of the given lookaheads.
+    fn first_of(
+        &self,
+        symbols: &[SymbolLalr],
+        lookahead: &BTreeSet<TerminalIndex>,
+    ) -> BTreeSet<TerminalIndex> {
+        let mut first = BTreeSet::new();
+        for symbol in symbols {
+            match symbol {
+                SymbolLalr::Terminal(t) => {
+                    first.insert(*t);
+                    return first;
+                }
+                SymbolLalr::Nonterminal(n) => {
+                    let (first_n, nullable) = &self.first_sets[n];
+                    first.extend(first_n.iter().cloned());
+                    if !nullable {
+                        return first;
+                    }
+                }
+            }
+        }
+        first.extend(lookahead.iter().cloned());
+        first
+    }
+
+    fn closure(&self, kernel: Items) -> Items {
+        let mut items = kernel;
+        let mut pending = items.keys().cloned().collect::<VecDeque<_>>();
+        while let Some((prod, pos)) = pending.pop_front() {
+            let symbols = &self.rules[prod].1;
+            if let Some(SymbolLalr::Nonterminal(n)) = symbols.get(pos) {
+                let lookahead = self.first_of(&symbols[pos + 1..], &items[&(prod, pos)]);
+                for p in &self.productions[n] {
+                    let item_lookahead = items.entry((*p, 0)).or_default();
+                    let len = item_lookahead.len();
+                    item_lookahead.extend(lookahead.iter().cloned());
+                    if item_lookahead.len() > len {
+                        pending.push_back((*p, 0));
+                    }
+                }
+            }
+        }
+        items
+    }
+
+    /// Builds the canonical LR(1) collection of item sets.
+    fn canonical_collection(&self) -> Vec<State> {
+        let mut states: Vec<State> = vec![];
+        let mut kernels = BTreeMap::<Items, usize>::new();
+        let mut add_state = |states: &mut Vec<State>, kernel: Items| -> usize {
+            if let Some(index) = kernels.get(&kernel) {
+                return *index;
+            }
+            let index = states.len();
+            states.push(State {
+                items: self.closure(kernel.clone()),
+                transitions: BTreeMap::new(),
+            });
+            kernels.insert(kernel, index);
+            index
+        };
+
+        add_state(
+            &mut states,
+            Items::from([((self.start_prod, 0), BTreeSet::from([EOI]))]),
+        );
+        let mut finished = 0;
+        while finished < states.len() {
+            let mut successor_kernels = BTreeMap::<SymbolLalr, Items>::new();
+            for ((prod, pos), lookahead) in &states[finished].items {
+                if let Some(symbol) = self.rules[*prod].1.get(*pos) {
+                    successor_kernels
+                        .entry(symbol.clone())
+                        .or_default()
+                        .insert((*prod, pos + 1), lookahead.clone());
+                }
+            }
+            for (symbol, kernel) in successor_kernels {
+                let successor = add_state(&mut states, kernel);
+                states[finished].transitions.insert(symbol, successor);
+            }
+            finished += 1;
+        }
+        states
+    }
+
+    /// The productions to reduce with for each lookahead terminal of the given items
+    fn reductions(&self, items: &Items) -> BTreeMap<TerminalIndex, BTreeSet<ProductionIndex>> {
+        items
+            .iter()
+            .filter(|((prod, pos), _)| *pos == self.rules[*prod].1.len())
+            .fold(BTreeMap::new(), |mut acc, ((prod, _), lookahead)| {
+                for t in lookahead {
+                    acc.entry(*t).or_insert_with(BTreeSet::new).insert(*prod);
+                }
+                acc
+            })
+    }
+
+    /// Returns true if merging the given states adds a reduce-reduce conflict that none of them
+    /// has on its own. The reductions of each state are given.
+    fn adds_reduce_reduce_conflict(
+        reductions: &[BTreeMap<TerminalIndex, BTreeSet<ProductionIndex>>],
+        members: &[usize],
+    ) -> bool {
+        let merged = members.iter().fold(BTreeMap::new(), |mut acc, s| {
+            for (t, prods) in &reductions[*s] {
+                acc.entry(*t)
+                    .or_insert_with(BTreeSet::new)
+                    .extend(prods.iter().cloned());
+            }
+            acc
+        });
+        merged.iter().any(|(t, prods)| {
+            prods.len() > 1 && !members.iter().any(|s| reductions[*s].get(t) == Some(prods))
+        })
+    }
+
+    /// Tries to merge the states `a` and `b` with the same LR(0) core. Their successors have to be
+    /// merged as well. Returns false and leaves the partition unchanged if the merge would add a
+    /// conflict.
+    fn try_merge(
+        states: &[State],
+        reductions: &[BTreeMap<TerminalIndex, BTreeSet<ProductionIndex>>],
+        partition: &mut Partition,
+        a: usize,
+        b: usize,
+    ) -> bool {
+        let checkpoint = partition.log.len();
+        let mut pending = vec![(a, b)];
+        while let Some((a, b)) = pending.pop() {
+            if partition.union(a, b) {
+                for (symbol, successor) in &states[a].transitions {
+                    pending.push((*successor, states[b].transitions[symbol]));
+                }
+            }
+        }
+        let roots = partition.log[checkpoint..]
+            .iter()
+            .map(|u| partition.find(u.root))
+            .collect::<BTreeSet<_>>();
+        if roots
+            .iter()
+            .any(|r| Self::adds_reduce_reduce_conflict(reductions, &partition.members[*r]))
+        {
+            partition.undo(checkpoint);
+            return false;
+        }
+        true
+    }
+
+    /// Merges states with the same LR(0) core as long as no reduce-reduce conflicts are added.
+    /// Returns the representative state for each state, i.e. the smallest state of its group.
+    fn merge_states(&self, states: &[State]) -> Vec<usize> {
+        let reductions = states
+            .iter()
+            .map(|s| self.reductions(&s.items))
+            .collect::<Vec<_>>();
+        let mut partition = Partition::new(states.len());
+        let mut cores = BTreeMap::<Vec<(ProductionIndex, usize)>, Vec<usize>>::new();
+        for (i, state) in states.iter().enumerate() {
+            if partition.representative(i) != i {
+                // Already merged as successor of other merged states
+                continue;
+            }
+            let representatives = cores
+                .entry(state.items.keys().cloned().collect())
+                .or_default();
+            if !representatives
+                .iter()
+                .any(|r| Self::try_merge(states, &reductions, &mut partition, *r, i))
+            {
+                representatives.push(i);
+            }
+        }
+        (0..states.len())
+            .map(|s| partition.representative(s))
+            .collect()
+    }
+}
+
+/// A union of two groups of states that can be undone
+#[derive(Debug)]
+struct Union {
+    /// The root of the smaller group
+    child: usize,
+    /// The root of the united group
+    root: usize,
+    /// The number of members moved from the child to the root
+    moved: usize,
+    /// The smallest state of the root's group before the union
+    smallest: usize,
+}
+
+/// A union-find structure of the groups of merged states.
+/// Each root keeps the members of its group, so the members are available without scanning all
+/// states. Unions are logged to undo them if a merge adds a conflict.
+#[derive(Debug)]
+struct Partition {
+    parent: Vec<usize>,
+    /// The members of each group, only valid for roots
+    members: Vec<Vec<usize>>,
+    /// The smallest state of each group, only valid for roots
+    smallest: Vec<usize>,
+    log: Vec<Union>,
+}
+
+impl Partition {
+    fn new(len: usize) -> Self {
+        Self {
+            parent: (0..len).collect(),
+            members: (0..len).map(|s| vec![s]).collect(),
+            smallest: (0..len).collect(),
+            log: Vec::new(),
+        }
+    }
+
+    fn find(&self, mut state: usize) -> usize {
+        while self.parent[state] != state {
+            state = self.parent[state];
+        }
+        state
+    }
+
+    fn representative(&self, state: usize) -> usize {
+        self.smallest[self.find(state)]
+    }
+
+    /// Unites the groups of the given states. The smaller group is attached to the larger one,
+    /// thus the trees stay flat without path compression that couldn't be undone.
+    /// Returns false if both are already in the same group.
+    fn union(&mut self, a: usize, b: usize) -> bool {
+        let (mut root, mut child) = (self.find(a), self.find(b));
+        if root == child {
+            return false;
+        }
+        if self.members[root].len() < self.members[child].len() {
+            std::mem::swap(&mut root, &mut child);
+        }
+        self.log.push(Union {
+            child,
+            root,
+            moved: self.members[child].len(),
+            smallest: self.smallest[root],
+        });
+        self.parent[child] = root;
+        self.smallest[root] = self.smallest[root].min(self.smallest[child]);
+        let members = std::mem::take(&mut self.members[child]);
+        self.members[root].extend(members);
+        true
+    }
+
+    /// Undoes all unions after the given checkpoint of the log
+    fn undo(&mut self, checkpoint: usize) {
+        while self.log.len() > checkpoint {
+            let Union {
+                child,
+                root,
+                moved,
+                smallest,
+            } = self.log.pop().unwrap();
+            let len = self.members[root].len() - moved;
+            self.members[child] = self.members[root].split_off(len);
+            self.smallest[root] = smallest;
+            self.parent[child] = child;
+        }
+    }
+}
+
+/// Adds the conflict if the same conflict wasn't already found in another state with the same
+/// LR(0) core.
+fn add_conflict(
+    conflicts: &mut Vec<LRResolvedConflict>,
+    conflict: LRConflict,
+    applied_resolution: LRConflictResolution,
+) {
+    if !conflicts.iter().any(|c| c.conflict == conflict) {
+        conflicts.push(LRResolvedConflict {
+            conflict,
+            applied_resolution,
+            counterexample: None,
+        });
+    }
+}
+
+/// Calculate the LR(1) parse table for the given grammar configuration.
+/// Conflicts are resolved the same way as in [crate::calculate_lalr1_parse_table], i.e. by the
+/// declared precedences or otherwise in favor of shift and in favor of the production that comes
+/// first in the grammar description.
+pub fn calculate_lr1_parse_table(
+    grammar_config: &GrammarConfig,
+) -> Result<(LRParseTable, Vec<LRResolvedConflict>)> {
+    let (parse_table, resolved_conflicts) = build_parse_table(grammar_config, false)?;
+    check_expected_conflicts(grammar_config, resolved_conflicts)
+        .map(|resolved_conflicts| (parse_table, resolved_conflicts))
+}
+
+/// Calculate the parse table of a GLR grammar for the given grammar configuration.
+/// The table is built like the LR(1) parse table. Conflicts that aren't resolved by the declared
+/// precedences are kept in the `conflicting_actions` of the states. The GLR parser follows all of
+/// them.
+/// If the grammar declares the expected conflicts with %expect or %expect-rr, the numbers of the
+/// kept conflicts must match them.
+pub fn calculate_glr_parse_table(grammar_config: &GrammarConfig) -> Result<LRParseTable> {
+    let (parse_table, conflicts) = build_parse_table(grammar_config, true)?;
+    check_expected_conflicts(grammar_config, conflicts).map(|_| parse_table)
+}
+
+// Builds the LR(1) parse table. If `keep_conflicts` is set, conflicts that aren't resolved by
+// precedences are added to the conflicting actions too. The returned conflicts are always the
+// ones that the LR(1) parse table would resolve.
+fn build_parse_table(
+    grammar_config: &GrammarConfig,
+    keep_conflicts: bool,
+) -> Result<(LRParseTable, Vec<LRResolvedConflict>)> {
+    trace!("CFG: \n{}", render_par_string(grammar_config, true)?);
+    let cfg = &grammar_config.cfg;
+    let grammar = GrammarLalr::from(cfg);
+    let builder = LR1Builder::new(&grammar, cfg.pr.len());
+    let states = builder.canonical_collection();
+    let representatives = builder.merge_states(&states);
+    let state_numbers = representatives
+        .iter()
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .enumerate()
+        .map(|(i, s)| (*s, i))
+        .collect::<BTreeMap<_, _>>();
+    trace!(
+        "Canonical LR(1) collection with {} states merged into {} states",
+        states.len(),
+        state_numbers.len()
+    );
+
+    let precedences = Precedences::new(grammar_config);
+    let mut resolved_conflicts = vec![];
+    let mut parse_table = LRParseTable { states: vec![] };
+    for representative in state_numbers.keys() {
+        let mut items = Items::new();
+        for (s, _) in representatives
+            .iter()
+            .enumerate()
+            .filter(|(_, r)| *r == representative)
+        {
+            for (item, lookahead) in &states[s].items {
+                items
+                    .entry(*item)
+                    .or_default()
+                    .extend(lookahead.iter().cloned());
+            }
+        }
+        let item_set = || ItemSet {
+            items: items
+                .keys()
+                .map(|(prod, pos)| Item {
+                    prod: *prod,
+                    pos: *pos,
+                })
+                .collect(),
+        };
+
+        let mut actions = BTreeMap::new();
+        let mut gotos = BTreeMap::new();
+        let mut conflicting_actions = BTreeMap::<TerminalIndex, Vec<LRAction>>::new();
+        for (symbol, successor) in &states[*representative].transitions {
+            let successor = state_numbers[&representatives[*successor]];
+            match symbol {
+                SymbolLalr::Terminal(t) => {
+                    actions.insert(*t, LRAction::Shift(successor));
+                }
+                SymbolLalr::Nonterminal(n) => {
+                    gotos.insert(*n, successor);
+                }
+            }
+        }
+
+        // The items are ordered by production, so in reduce-reduce conflicts the production that
+        // comes first in the grammar description wins.
+        let mut shift_reduce = vec![];
+        for ((prod, _), lookahead) in items
+            .iter()
+            .filter(|((prod, pos), _)| *pos == builder.rules[*prod].1.len())
+        {
+            let lhs = builder.rules[*prod].0;
+            for token in lookahead {
+                match actions.get(token) {
+                    None if *token == EOI && lhs == builder.start => {
+                        actions.insert(*token, LRAction::Accept);
+                    }
+                    None => {
+                        actions.insert(*token, LRAction::Reduce(lhs, *prod));
+                    }
+                    Some(LRAction::Reduce(_, r1)) => {
+                        if keep_conflicts {
+                            conflicting_actions
+                                .entry(*token)
+                                .or_default()
+                                .push(LRAction::Reduce(lhs, *prod));
+                        }
+                        add_conflict(
+                            &mut resolved_conflicts,
+                            LRConflict::ReduceReduce {
+                                state: item_set(),
+                                token: *token,
+                                r1: *r1,
+                                r2: *prod,
+                            },
+                            LRConflictResolution::ReduceFirstRule,
+                        );
+                    }
+                    Some(LRAction::Shift(_)) => shift_reduce.push((*token, lhs, *prod)),
+                    Some(LRAction::Accept) => unreachable!(),
+                }
+            }
+        }
+
+        for (token, lhs, rule) in shift_reduce {
+            // A former reduction already won over the shift by precedence, so the remaining
+            // reductions conflict with it and the first rule is kept.
+            if let Some(LRAction::Reduce(_, r1)) = actions.get(&token) {
+                if keep_conflicts {
+                    conflicting_actions
+                        .entry(token)
+                        .or_default()
+                        .push(LRAction::Reduce(lhs, rule));
+                }
+                add_conflict(
+                    &mut resolved_conflicts,
+                    LRConflict::ReduceReduce {
+                        state: item_set(),
+                        token,
+                        r1: *r1,
+                        r2: rule,
+                    },
+                    LRConflictResolution::ReduceFirstRule,
+                );
+                continue;
+            }
+            match precedences.decide(token, rule) {
+                None => {
+                    if keep_conflicts {
+                        conflicting_actions
+                            .entry(token)
+                            .or_default()
+                            .push(LRAction::Reduce(lhs, rule));
+                    }
+                    add_conflict(
+                        &mut resolved_conflicts,
+                        LRConflict::ShiftReduce {
+                            state: item_set(),
+                            token,
+                            rule,
+                        },
+                        LRConflictResolution::ShiftOverReduce,
+                    );
+                }
+                Some(PrecedenceResolution::Shift) => (),
+                Some(PrecedenceResolution::Reduce) => {
+                    actions.insert(token, LRAction::Reduce(lhs, rule));
+                }
+                Some(PrecedenceResolution::Error) => {
+                    actions.remove(&token);
+                }
+            }
+        }
+        parse_table.states.push(LR1State {
+            actions,
+            gotos,
+            conflicting_actions,
+        });
+    }
+
+    // The conflicts of GLR grammars are only reported if they differ from the expected ones
+    if !resolved_conflicts.is_empty() && (!keep_conflicts || grammar_config.expects_conflicts()) {
+        let finder = CounterexampleFinder::new(cfg, &grammar);
+        for resolved_conflict in &mut resolved_conflicts {
+            resolved_conflict.counterexample = finder.find(&resolved_conflict.conflict);
+        }
+    }
+    trace!("LR(1) parse table: {:#?}", parse_table);
+    Ok((parse_table, resolved_conflicts))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        calculate_lalr1_parse_table, generators::grammar_trans::check_and_transform_grammar,
+        grammar::cfg::TerminalIndexFn, TerminalKind,
+    };
+
+    fn grammar_config(grammar: &str) -> GrammarConfig {
+        let mut grammar_config = crate::obtain_grammar_config_from_string(grammar, false).unwrap();
+        let cfg =
+            check_and_transform_grammar(&grammar_config.cfg, grammar_config.grammar_type).unwrap();
+        grammar_config.update_cfg(cfg);
+        grammar_config
+    }
+
+    // Returns true if the parse table accepts the given terminals.
+    fn accepts(grammar_config: &GrammarConfig, table: &LRParseTable, input: &str) -> bool {
+        let cfg = &grammar_config.cfg;
+        let ti = cfg.get_terminal_index_function();
+        let mut input = input
+            .split_whitespace()
+            .map(|t| ti.terminal_index(t, TerminalKind::Raw, None))
+            .chain(std::iter::once(EOI))
+            .peekable();
+        let mut states = vec![0];
+        loop {
+            let token = *input.peek().unwrap();
+            match table.states[*states.last().unwrap()].actions.get(&token) {
+                Some(LRAction::Shift(s)) => {
+                    states.push(*s);
+                    input.next();
+                }
+                Some(LRAction::Reduce(nt, p)) => {
+                    states.truncate(states.len() - cfg.pr[*p].len());
+                    states.push(table.states[*states.last().unwrap()].gotos[nt]);
+                }
+                Some(LRAction::Accept) => return true,
+                None => return false,
+            }
+        }
+    }
+
+    #[test]
+    fn check_lr1_grammar_without_lalr1_conflicts() {
+        // LALR(1) merges the states after 'a' 'e' 'z' and 'b' 'e' 'z' which leads to a
+        // reduce-reduce conflict between `E: 'e' 'z'` and `F: 'e' 'z'`.
+        let grammar = r"%start S
+%grammar_type 'LR(1)'
+%%
+S: 'a' E 'c' | 'a' F 'd' | 'b' F 'c' | 'b' E 'd';
+E: 'e' 'z';
+F: 'e' 'z';
+";
+        let grammar_config = grammar_config(grammar);
+        let (_, lalr1_conflicts) = calculate_lalr1_parse_table(&grammar_config).unwrap();
+        assert!(!lalr1_conflicts.is_empty());
+
+        let (table, resolved_conflicts) = calculate_lr1_parse_table(&grammar_config).unwrap();
+        assert!(resolved_conflicts.is_empty());
+        for input in ["a e z c", "a e z d", "b e z c", "b e z d"] {
+            assert!(accepts(&grammar_config, &table, input), "{}", input);
+        }
+        assert!(!accepts(&grammar_config, &table, "a e z"));
+        assert!(!accepts(&grammar_config, &table, "e z c"));
+    }
+
+    #[test]
+    fn check_states_of_lalr1_grammar_are_merged() {
+        let grammar = r"%start Calc
+%grammar_type 'LR(1)'
+%left '+'
+%left '*'
+%%
+Calc: Expr;
+Expr: Expr '+' Expr | Expr '*' Expr | '(' Expr ')' | 'n';
+";
+        let grammar_config = grammar_config(grammar);
+        let (lalr1_table, _) = calculate_lalr1_parse_table(&grammar_config).unwrap();
+        let (table, resolved_conflicts) = calculate_lr1_parse_table(&grammar_config).unwrap();
+        assert!(resolved_conflicts.is_empty());
+        assert_eq!(lalr1_table.states.len(), table.states.len());
+        assert!(accepts(&grammar_config, &table, "( n + n ) * n"));
+        assert!(!accepts(&grammar_config, &table, "n + * n"));
+    }
+
+    #[test]
+    fn check_unresolved_conflicts_are_reported() {
+        let grammar = r"%start Stmt
+%grammar_type 'LR(1)'
+%expect 1
+%%
+Stmt: 'if' 'c' Stmt | 'if' 'c' Stmt 'else' Stmt | 's';
+";
+        let (_, resolved_conflicts) = calculate_lr1_parse_table(&grammar_config(grammar)).unwrap();
+        assert_eq!(1, resolved_conflicts.len());
+        assert!(resolved_conflicts[0].is_shift_reduce());
+        assert!(resolved_conflicts[0]
+            .counterexample
+            .as_ref()
+            .is_some_and(|c| c.is_ambiguous()));
+    }
+
+    #[test]
+    fn check_reductions_winning_over_a_shift_keep_the_first_rule() {
+        let grammar = r"%start S
+%grammar_type 'LR(1)'
+%expect-rr 1
+%left '+'
+%left 'a'
+%%
+S: A '+' 'b' | B '+' 'c' | 'x' 'a' '+' 'd';
+A: 'x' 'a';
+B: 'x' 'a';
+";
+        let grammar_config = grammar_config(grammar);
+        let (table, resolved_conflicts) = calculate_lr1_parse_table(&grammar_config).unwrap();
+        // Both reductions win over the shift of '+', the second one conflicts with the first one
+        assert_eq!(1, resolved_conflicts.len());
+        assert!(!resolved_conflicts[0].is_shift_reduce());
+        assert!(accepts(&grammar_config, &table, "x a + b"));
+        assert!(!accepts(&grammar_config, &table, "x a + c"));
+        assert!(!accepts(&grammar_config, &table, "x a + d"));
+    }
+
+    #[test]
+    fn check_glr_parse_table_keeps_conflicts() {
+        let grammar = r"%start Sum
+%grammar_type 'GLR'
+%%
+Sum: Expr;
+Expr: Expr '+' Expr | Expr '*' Expr | 'n';
+";
+        let grammar_config = grammar_config(grammar);
+        let (_, resolved_conflicts) = calculate_lr1_parse_table(&grammar_config).unwrap();
+        assert_eq!(4, resolved_conflicts.len());
+
+        let table = calculate_glr_parse_table(&grammar_config).unwrap();
+        let conflicting_actions = table
+            .states
+            .iter()
+            .flat_map(|s| s.conflicting_actions.values().flatten())
+            .collect::<Vec<_>>();
+        assert_eq!(4, conflicting_actions.len());
+        assert!(conflicting_actions
+            .iter()
+            .all(|a| matches!(a, LRAction::Reduce(..))));
+        // Without the conflicting actions the parse table is the one of the LR(1) grammar
+        assert!(accepts(&grammar_config, &table, "n + n * n"));
+    }
+
+    #[test]
+    fn check_glr_parse_table_applies_precedences() {
+        let grammar = r"%start Sum
+%grammar_type 'GLR'
+%left '+'
+%%
+Sum: Expr;
+Expr: Expr '+' Expr | Expr '*' Expr | 'n';
+";
+        let table = calculate_glr_parse_table(&grammar_config(grammar)).unwrap();
+        // Only the conflict of '+' with itself is resolved by the precedence
+        assert_eq!(
+            3,
+            table
+                .states
+                .iter()
+                .map(|s| s.conflicting_actions.values().flatten().count())
+                .sum::<usize>()
+        );
+    }
+
+    #[test]
+    fn check_glr_parse_table_checks_expected_conflicts() {
+        let grammar = |expect: &str| {
+            format!(
+                r"%start Sum
+%grammar_type 'GLR'
+{expect}
+%%
+Sum: Expr;
+Expr: Expr '+' Expr | 'n';
+"
+            )
+        };
+        assert!(calculate_glr_parse_table(&grammar_config(&grammar(""))).is_ok());
+        assert!(calculate_glr_parse_table(&grammar_config(&grammar("%expect 1"))).is_ok());
+        assert!(calculate_glr_parse_table(&grammar_config(&grammar("%expect 0"))).is_err());
+        assert!(calculate_glr_parse_table(&grammar_config(&grammar("%expect-rr 1"))).is_err());
+    }
+}
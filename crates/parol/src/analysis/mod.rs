@@ -12,6 +12,10 @@ pub use compiled_terminal::CompiledTerminal;
 pub mod errors;
 pub use errors::{GrammarAnalysisError, RecursiveNonTerminal, RelatedHint};
 
+/// Module with check for terminals that match the empty string
+pub mod empty_terminals;
+pub use empty_terminals::matches_empty_string;
+
 /// Module with check for left-recursions
 pub mod left_recursion;
 pub use left_recursion::detect_left_recursive_non_terminals;
@@ -35,16 +39,29 @@ pub use follow::{follow_k, FollowSet};
 /// Module with conflict calculations
 pub mod k_decision;
 pub use k_decision::{
-    calculate_k, calculate_k_tuples, calculate_lookahead_dfas, decidable, explain_conflicts,
-    FirstCache, FollowCache,
+    calculate_adaptive_lookahead_dfas, calculate_k, calculate_k_tuples, calculate_lookahead_dfas,
+    decidable, decide_non_terminals, explain_conflicts, max_lookahead, FirstCache, FollowCache,
 };
 
+/// Module with witnesses for LL(k) conflicts
+pub mod llk_conflict;
+pub use llk_conflict::{find_llk_conflict, LLKConflict, LLKConflictDerivation, LLKConflictWitness};
+
+/// Module with counterexamples for LALR(1) conflicts
+pub mod lalr1_counterexample;
+pub use lalr1_counterexample::{LRConflictDerivation, LRCounterexample};
+
 /// Module with calculations for the LALR(1) parse table
 pub mod lalr1_parse_table;
 pub use lalr1_parse_table::{
     calculate_lalr1_parse_table, LR1State, LRAction, LRConflict, LRConflictError, LRParseTable,
+    LRResolvedConflict,
 };
 
+/// Module with calculations for the LR(1) parse table
+pub mod lr1_parse_table;
+pub use lr1_parse_table::{calculate_glr_parse_table, calculate_lr1_parse_table};
+
 /// Module with types for production selection
 pub mod lookahead_dfa;
 pub use lookahead_dfa::LookaheadDFA;
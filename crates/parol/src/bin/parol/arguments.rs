@@ -59,6 +59,14 @@ pub(crate) struct CliArgs {
     #[arg(short = 'x', long = "trim")]
     pub trim_parse_tree: bool,
 
+    /// Predicts non-terminals that exceed the lookahead limit by speculative parsing
+    #[arg(long)]
+    pub adaptive_prediction: bool,
+
+    /// Eliminates left recursions of LL(k) grammars instead of reporting them as errors
+    #[arg(long)]
+    pub eliminate_left_recursion: bool,
+
     /// Increased verbosity
     #[arg(short, long)]
     pub verbose: bool,
@@ -17,7 +17,7 @@ use parol_runtime::{log::trace, Report, Result};
 
 use parol::{
     build::{BuildListener, IntermediateGrammar},
-    render_par_string, GrammarConfig, ParolErrorReporter, ParolGrammar,
+    render_par_string, GrammarConfig, LRResolvedConflict, ParolErrorReporter, ParolGrammar,
 };
 use parol_macros::parol;
 
@@ -66,6 +66,12 @@ fn run(args: &CliArgs) -> Result<u128> {
     if args.trim_parse_tree {
         builder.trim_parse_tree();
     }
+    if args.adaptive_prediction {
+        builder.adaptive_prediction();
+    }
+    if args.eliminate_left_recursion {
+        builder.eliminate_left_recursion();
+    }
     if args.auto_generate {
         builder.enable_auto_generation();
     }
@@ -168,6 +174,31 @@ impl BuildListener for CLIListener<'_> {
         }
         Ok(())
     }
+
+    fn on_resolved_conflicts(
+        &mut self,
+        resolved_conflicts: &[LRResolvedConflict],
+        grammar_config: &GrammarConfig,
+    ) -> Result<()> {
+        // Conflicts declared with %expect or %expect-rr have already been reviewed
+        if !grammar_config.expects_conflicts() {
+            for conflict in resolved_conflicts {
+                println!("{}", conflict.display(&grammar_config.cfg));
+            }
+        }
+        Ok(())
+    }
+
+    fn on_eliminated_left_recursions(
+        &mut self,
+        non_terminals: &[String],
+        _grammar_config: &GrammarConfig,
+    ) -> Result<()> {
+        for non_terminal in non_terminals {
+            println!("Left recursion of non-terminal '{non_terminal}' eliminated");
+        }
+        Ok(())
+    }
 }
 
 fn main() -> Result<std::process::ExitCode> {
@@ -210,8 +241,8 @@ fn post_process_args(args: &mut CliArgs) {
 
 // We need the file name to support error reporting
 fn extract_file_name(args: &CliArgs) -> Option<PathBuf> {
-    if args.subcommand.is_some() {
-        match args.subcommand.as_ref().unwrap() {
+    if let Some(subcommand) = args.subcommand.as_ref() {
+        match subcommand {
             tools::ToolsSubcommands::calculate_k(args) => Some(args.grammar_file.clone()),
             tools::ToolsSubcommands::calculate_k_tuples(args) => Some(args.grammar_file.clone()),
             tools::ToolsSubcommands::decidable(args) => Some(args.grammar_file.clone()),
@@ -1,5 +1,7 @@
 use anyhow::{bail, Result};
-use parol::analysis::k_decision::{calculate_k, FirstCache, FollowCache};
+use parol::analysis::{
+    decide_non_terminals, find_llk_conflict, max_lookahead, FirstCache, FollowCache,
+};
 use parol::{obtain_grammar_config, MAX_K};
 use std::path::PathBuf;
 
@@ -27,7 +29,17 @@ pub fn main(args: &Args) -> Result<()> {
 
     let first_cache = FirstCache::new();
     let follow_cache = FollowCache::new();
-    let result = calculate_k(&grammar_config, max_k, &first_cache, &follow_cache);
+    // The same calculation as calculate_k, but the decisions are kept to explain the conflicts
+    let decisions = decide_non_terminals(&grammar_config, max_k, &first_cache, &follow_cache);
+    let result: Result<usize> = Ok(max_lookahead(&decisions, max_k));
     println!("{:#?}", result);
+    // Explain the non-terminals that need more than max_k tokens of lookahead
+    for (nt, k) in &decisions {
+        if let Ok(None) = k {
+            let conflict =
+                find_llk_conflict(&grammar_config, nt, max_k, &first_cache, &follow_cache)?;
+            println!("{}", conflict);
+        }
+    }
     Ok(())
 }
@@ -2,10 +2,11 @@ use anyhow::{bail, Result};
 use parol::analysis::{decidable, explain_conflicts, FirstCache, FollowCache};
 use parol::generators::generate_terminal_names;
 use parol::obtain_grammar_config;
-use parol::MAX_K;
+use parol::{GrammarAnalysisError, MAX_K};
 use std::path::PathBuf;
 
-/// Detects the maximum lookahead needed for your grammar and shows possible conflicts in k-tuples.
+/// Detects the maximum lookahead needed for your grammar and shows possible conflicts in k-tuples
+/// together with the shared k-tuples of conflicting productions with sample derivations.
 #[derive(clap::Parser)]
 #[clap(name = "decidable")]
 pub struct Args {
@@ -42,11 +43,16 @@ pub fn main(args: &Args) -> Result<()> {
                 decidable(&grammar_config, n, max_k, &first_cache, &follow_cache),
             )
         })
-        .map(|(n, r)| {
-            if let Ok(r) = r {
-                r
-            } else {
+        .map(|(n, r)| match r {
+            Ok(r) => r,
+            Err(e) => {
                 errors += 1;
+                match e.downcast_ref::<GrammarAnalysisError>() {
+                    Some(GrammarAnalysisError::MaxKExceeded { conflict, .. }) => {
+                        println!("{}", conflict)
+                    }
+                    _ => println!("Non-terminal '{}': {}\n", n, e),
+                }
                 non_terminals_with_conflicts.push(n);
                 0
             }
@@ -125,12 +125,12 @@ use std::{env, fs};
 use crate::config::{CommonGeneratorConfig, ParserGeneratorConfig, UserTraitGeneratorConfig};
 use crate::parser::GrammarType;
 use crate::{
-    GrammarConfig, GrammarTypeInfo, LRParseTable, LookaheadDFA, ParolGrammar, UserTraitGenerator,
-    MAX_K,
+    GrammarConfig, GrammarTypeInfo, LRParseTable, LRResolvedConflict, LookaheadDFA, ParolGrammar,
+    UserTraitGenerator, MAX_K,
 };
 use clap::{Parser, ValueEnum};
 use parol_macros::parol;
-use parol_runtime::{ParseTree, Result};
+use parol_runtime::{ParolError, ParseTree, Result};
 
 /// Contains all attributes that should be inserted optionally on top of the generated trait source.
 /// * Used in the Builder API. Therefore it mus be public
@@ -204,6 +204,10 @@ pub struct Builder {
     /// Enables trimming of the parse tree during parsing.
     /// Generates the call to trim_parse_tree on the parser object before the call of parse.
     pub(crate) trim_parse_tree: bool,
+    /// Predicts non-terminals that exceed the maximum lookahead by speculative parsing
+    adaptive_prediction: bool,
+    /// Eliminates direct and indirect left recursions of LL(k) grammars
+    eliminate_left_recursion: bool,
 }
 
 impl Builder {
@@ -280,6 +284,8 @@ impl Builder {
             // By default, we require that output files != /dev/null
             output_sanity_checks: true,
             trim_parse_tree: false,
+            adaptive_prediction: false,
+            eliminate_left_recursion: false,
         }
     }
     /// By default, we require that the generated parser and action files are not discarded.
@@ -399,6 +405,27 @@ impl Builder {
         self.trim_parse_tree = true;
         self
     }
+    /// Enables the adaptive prediction of LL(k) grammars.
+    ///
+    /// Non-terminals that can't be decided with the maximum lookahead don't fail the generation.
+    /// Instead the generated parser predicts their productions by speculative parsing with
+    /// unbounded lookahead. All other non-terminals are still decided by their lookahead DFAs.
+    ///
+    pub fn adaptive_prediction(&mut self) -> &mut Self {
+        self.adaptive_prediction = true;
+        self
+    }
+    /// Enables the elimination of direct and indirect left recursions of LL(k) grammars.
+    ///
+    /// Left-recursive non-terminals are rewritten into repetitions instead of being reported as
+    /// errors. This changes the generated types of these non-terminals, they contain a `Vec` of the
+    /// repeated parts in input order. The rewritten non-terminals are reported to the
+    /// [BuildListener].
+    ///
+    pub fn eliminate_left_recursion(&mut self) -> &mut Self {
+        self.eliminate_left_recursion = true;
+        self
+    }
 
     /// Begin the process of generating the grammar
     /// using the specified listener (or None if no listener is desired).
@@ -529,6 +556,11 @@ impl GrammarGenerator<'_> {
         }
         let mut parol_grammar = ParolGrammar::new();
         let syntax_tree = crate::parser::parse(&input, &self.grammar_file, &mut parol_grammar)?;
+        if self.builder.cargo_integration {
+            for included_file in parol_grammar.included_files() {
+                println!("cargo:rerun-if-changed={}", included_file.display());
+            }
+        }
         self.listener
             .on_initial_grammar_parse(&syntax_tree, &parol_grammar)?;
         self.grammar_config = Some(GrammarConfig::try_from(parol_grammar)?);
@@ -542,8 +574,16 @@ impl GrammarGenerator<'_> {
         // NOTE: it's up to the listener to add appropriate error context
         self.listener
             .on_intermediate_grammar(IntermediateGrammar::Untransformed, &*grammar_config)?;
-        let cfg =
-            crate::check_and_transform_grammar(&grammar_config.cfg, grammar_config.grammar_type)?;
+        let cfg = if self.builder.eliminate_left_recursion
+            && grammar_config.grammar_type == GrammarType::LLK
+        {
+            let (cfg, non_terminals) = crate::eliminate_left_recursion(&grammar_config.cfg);
+            self.listener
+                .on_eliminated_left_recursions(&non_terminals, &*grammar_config)?;
+            crate::check_and_transform_grammar(&cfg, grammar_config.grammar_type)?
+        } else {
+            crate::check_and_transform_grammar(&grammar_config.cfg, grammar_config.grammar_type)?
+        };
 
         // To have at least a preliminary version of the expanded grammar,
         // even when the next checks fail, we write out the expanded grammar here.
@@ -577,12 +617,20 @@ impl GrammarGenerator<'_> {
         let grammar_config = self.grammar_config.as_mut().unwrap();
         match grammar_config.grammar_type {
             GrammarType::LLK => {
-                self.lookahead_dfa_s = Some(
+                let lookahead_dfa_s = if self.builder.adaptive_prediction {
+                    crate::calculate_adaptive_lookahead_dfas(
+                        grammar_config,
+                        self.builder.max_lookahead,
+                    )
+                } else {
                     crate::calculate_lookahead_dfas(grammar_config, self.builder.max_lookahead)
-                        .map_err(|e| {
-                            parol!("Lookahead calculation for the given grammar failed!: {}", e)
-                        })?,
-                );
+                };
+                // The context keeps the analysis error available for the error reporter
+                self.lookahead_dfa_s = Some(lookahead_dfa_s.map_err(|e| {
+                    ParolError::UserError(
+                        e.context("Lookahead calculation for the given grammar failed!"),
+                    )
+                })?);
 
                 if self.builder.debug_verbose {
                     print!(
@@ -604,7 +652,24 @@ impl GrammarGenerator<'_> {
                 );
             }
             GrammarType::LALR1 => {
-                self.parse_table = Some(crate::calculate_lalr1_parse_table(grammar_config)?.0);
+                let (parse_table, resolved_conflicts) =
+                    crate::calculate_lalr1_parse_table(grammar_config)?;
+                self.listener
+                    .on_resolved_conflicts(&resolved_conflicts, &*grammar_config)?;
+                self.parse_table = Some(parse_table);
+                grammar_config.update_lookahead_size(1);
+            }
+            GrammarType::LR1 => {
+                let (parse_table, resolved_conflicts) =
+                    crate::calculate_lr1_parse_table(grammar_config)?;
+                self.listener
+                    .on_resolved_conflicts(&resolved_conflicts, &*grammar_config)?;
+                self.parse_table = Some(parse_table);
+                grammar_config.update_lookahead_size(1);
+            }
+            GrammarType::GLR => {
+                // Conflicts aren't resolved but followed in parallel by the GLR parser
+                self.parse_table = Some(crate::calculate_glr_parse_table(grammar_config)?);
                 grammar_config.update_lookahead_size(1);
             }
         }
@@ -640,21 +705,22 @@ impl GrammarGenerator<'_> {
 
         let ast_type_has_lifetime = type_info.symbol_table.has_lifetime(type_info.ast_enum_type);
 
-        let parser_source = match grammar_config.grammar_type {
-            GrammarType::LLK => crate::generate_parser_source(
+        let parser_source = if grammar_config.grammar_type.is_lr() {
+            crate::generate_lalr1_parser_source(
                 grammar_config,
                 &lexer_source,
                 &self.builder,
-                self.lookahead_dfa_s.as_ref().unwrap(),
+                self.parse_table.as_ref().unwrap(),
                 ast_type_has_lifetime,
-            )?,
-            GrammarType::LALR1 => crate::generate_lalr1_parser_source(
+            )?
+        } else {
+            crate::generate_parser_source(
                 grammar_config,
                 &lexer_source,
                 &self.builder,
-                self.parse_table.as_ref().unwrap(),
+                self.lookahead_dfa_s.as_ref().unwrap(),
                 ast_type_has_lifetime,
-            )?,
+            )?
         };
 
         if let Some(ref parser_file_out) = self.builder.parser_output_file {
@@ -703,6 +769,24 @@ pub trait BuildListener {
     ) -> Result<()> {
         Ok(())
     }
+    /// Called with the conflicts of a LALR(1) grammar that were resolved automatically, i.e. not
+    /// by operator precedence.
+    fn on_resolved_conflicts(
+        &mut self,
+        resolved_conflicts: &[LRResolvedConflict],
+        config: &GrammarConfig,
+    ) -> Result<()> {
+        Ok(())
+    }
+    /// Called with the non-terminals whose left recursions were eliminated, if the elimination is
+    /// enabled with [Builder::eliminate_left_recursion].
+    fn on_eliminated_left_recursions(
+        &mut self,
+        non_terminals: &[String],
+        config: &GrammarConfig,
+    ) -> Result<()> {
+        Ok(())
+    }
 }
 #[derive(Default)]
 struct MaybeBuildListener<'l>(Option<&'l mut dyn BuildListener>);
@@ -730,6 +814,30 @@ impl<'l> BuildListener for MaybeBuildListener<'l> {
             Ok(())
         }
     }
+
+    fn on_resolved_conflicts(
+        &mut self,
+        resolved_conflicts: &[LRResolvedConflict],
+        config: &GrammarConfig,
+    ) -> Result<()> {
+        if let Some(ref mut inner) = self.0 {
+            inner.on_resolved_conflicts(resolved_conflicts, config)
+        } else {
+            Ok(())
+        }
+    }
+
+    fn on_eliminated_left_recursions(
+        &mut self,
+        non_terminals: &[String],
+        config: &GrammarConfig,
+    ) -> Result<()> {
+        if let Some(ref mut inner) = self.0 {
+            inner.on_eliminated_left_recursions(non_terminals, config)
+        } else {
+            Ok(())
+        }
+    }
 }
 
 /// Marks an intermediate stage of the grammar, in between the various transformations that parol does.
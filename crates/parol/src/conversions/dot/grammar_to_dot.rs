@@ -75,7 +75,9 @@ pub fn render_nt_dot_string(grammar_config: &GrammarConfig) -> String {
                 instances_to_types_edges.push(format!("\"{}\"->\"{}\";", to_node, n));
                 from_node = to_node;
             }
-            Symbol::T(Terminal::Trm(..)) | Symbol::T(Terminal::End) => {
+            Symbol::T(Terminal::Trm(..))
+            | Symbol::T(Terminal::End)
+            | Symbol::T(Terminal::Error(..)) => {
                 let to_node = format!("t{}_{}", pi, si + 1);
                 inside_production_edges.push(format!("\"{}\"->\"{}\";", from_node, to_node));
                 from_node = to_node;
@@ -138,6 +140,8 @@ mod test {
                 vec![0],
                 SymbolAttribute::None,
                 None,
+                None,
+                None,
             ))
         };
     }
@@ -88,6 +88,7 @@ mod tests {
             .into_iter()
             .collect(),
             k: 1,
+            adaptive: false,
         };
         assert_eq!(
             render_dfa_dot_string(&dfa, "Test"),
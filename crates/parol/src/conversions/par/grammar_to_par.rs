@@ -35,11 +35,50 @@ pub fn render_par_string(
         // This is no problem as the default is LLK
         GrammarType::LLK => "".to_owned(), // "\n%grammar_type 'll(k)'".to_owned(),
         GrammarType::LALR1 => "%grammar_type 'lalr(1)'\n".to_owned(),
+        GrammarType::LR1 => "%grammar_type 'lr(1)'\n".to_owned(),
+        GrammarType::GLR => "%grammar_type 'glr'\n".to_owned(),
     };
 
+    let precedence_levels =
+        grammar_config
+            .precedence_levels
+            .iter()
+            .fold(String::new(), |mut acc, l| {
+                acc.push_str(&format!("{}\n", l));
+                acc
+            });
+
+    let expected_conflicts = [
+        ("%expect", grammar_config.expected_shift_reduce_conflicts),
+        (
+            "%expect-rr",
+            grammar_config.expected_reduce_reduce_conflicts,
+        ),
+    ]
+    .iter()
+    .fold(String::new(), |mut acc, (d, n)| {
+        if let Some(n) = n {
+            acc.push_str(&format!("{} {}\n", d, n));
+        }
+        acc
+    });
+
     let scanner_state_resolver = grammar_config.get_scanner_state_resolver();
     let primary_non_terminal_finder = grammar_config.cfg.get_primary_non_terminal_finder();
 
+    let keywords = render_keywords_string(grammar_config, &primary_non_terminal_finder);
+
+    let display_names =
+        grammar_config
+            .display_names
+            .iter()
+            .fold(String::new(), |mut acc, (t, d)| {
+                if let Some(n) = primary_non_terminal_finder(*t) {
+                    acc.push_str(&format!("%display {} \"{}\"\n", n, d));
+                }
+                acc
+            });
+
     let initial_scanner_state = render_scanner_config_string(
         0,
         &grammar_config.scanner_configurations[0],
@@ -55,6 +94,8 @@ pub fn render_par_string(
             acc
         });
 
+    let ast_declarations = render_ast_declarations_string(grammar_config);
+
     let user_type_resolver = grammar_config.get_user_type_resolver();
 
     let mut productions =
@@ -107,13 +148,57 @@ pub fn render_par_string(
     let start_symbol = grammar_config.cfg.st.clone();
     Ok(format!(
         "%start {start_symbol}
-{title}{comment}{grammar_type}{initial_scanner_state}{user_types}
+{title}{comment}{grammar_type}{precedence_levels}{expected_conflicts}{keywords}{display_names}{initial_scanner_state}{user_types}{ast_declarations}
 {scanner_states}%%
 
 {productions}"
     ))
 }
 
+fn render_keywords_string(
+    grammar_config: &GrammarConfig,
+    primary_non_terminal_finder: &FnPrimaryNonTerminalFinder,
+) -> String {
+    let mut keywords = grammar_config
+        .scanner_configurations
+        .iter()
+        .flat_map(|sc| sc.keywords.iter().cloned())
+        .collect::<Vec<_>>();
+    if keywords.is_empty() {
+        return String::new();
+    }
+    keywords.sort_unstable();
+    keywords.dedup();
+    let keywords = keywords
+        .iter()
+        .map(|k| primary_non_terminal_finder(*k).unwrap_or(format!("{}", k)))
+        .collect::<Vec<_>>();
+    format!("%keywords {}\n", keywords.join(", "))
+}
+
+// Declarations that control the types generated in auto-gen mode
+fn render_ast_declarations_string(grammar_config: &GrammarConfig) -> String {
+    let mut declarations = String::new();
+    if !grammar_config.derives.is_empty() {
+        declarations.push_str(&format!("%derive {}\n", grammar_config.derives.join(", ")));
+    }
+    for (n, d) in &grammar_config.non_terminal_derives {
+        declarations.push_str(&format!("%derive {}: {}\n", n, d.join(", ")));
+    }
+    if !grammar_config.inline_non_terminals.is_empty() {
+        declarations.push_str(&format!(
+            "%inline {}\n",
+            grammar_config
+                .inline_non_terminals
+                .iter()
+                .cloned()
+                .collect::<Vec<_>>()
+                .join(", ")
+        ));
+    }
+    declarations
+}
+
 fn render_scanner_config_string(
     index: usize,
     scanner_config: &ScannerConfig,
@@ -137,6 +222,13 @@ fn render_scanner_config_string(
         scanner_directives.push_str(&format!("{}%block_comment \"{}\" \"{}\"\n", indent, s, e));
     }
 
+    for (s, e) in &scanner_config.nested_block_comments {
+        scanner_directives.push_str(&format!(
+            "{}%nested_block_comment \"{}\" \"{}\"\n",
+            indent, s, e
+        ));
+    }
+
     if !scanner_config.auto_newline {
         scanner_directives.push_str(&format!("{}%auto_newline_off\n", indent));
     }
@@ -145,6 +237,21 @@ fn render_scanner_config_string(
         scanner_directives.push_str(&format!("{}%auto_ws_off\n", indent));
     }
 
+    if scanner_config.longest_match {
+        scanner_directives.push_str(&format!("{}%longest_match\n", indent));
+    }
+
+    if let Some((i, d, n)) = scanner_config.offside {
+        let name = |t| primary_non_terminal_finder(t).unwrap_or(format!("{}", t));
+        scanner_directives.push_str(&format!(
+            "{}%offside {} {} {}\n",
+            indent,
+            name(i),
+            name(d),
+            name(n)
+        ));
+    }
+
     for (scanner, primary_nts) in group_by(&scanner_config.transitions, |(_, v)| *v) {
         let mut primary_nts = primary_nts
             .iter()
@@ -185,6 +292,8 @@ mod test {
                 vec![0],
                 SymbolAttribute::None,
                 None,
+                None,
+                None,
             ))
         };
     }
@@ -189,7 +189,7 @@ impl Report for ParolErrorReporter {
                                 Into::<Range<usize>>::into(token),
                             )])
                             .with_notes(vec![
-                                "Only 'LL(k)' and 'LALR(1)' are supported. Use RawString literals here.".to_string()
+                                "Only 'LL(k)', 'LALR(1)', 'LR(1)' and 'GLR' are supported. Use RawString literals here.".to_string()
                             ]),
                     )?)
                 }
@@ -278,6 +278,96 @@ impl Report for ParolErrorReporter {
                             ]),
                     )?)
                 }
+                ParolParserError::UndeclaredPrecedenceSymbol {
+                    context,
+                    symbol,
+                    input,
+                    location,
+                } => {
+                    let mut files = SimpleFiles::new();
+                    let content = fs::read_to_string(input).unwrap_or_default();
+                    let file_id = files.add(input.display().to_string(), content);
+
+                    Ok(term::emit(
+                        &mut writer.lock(),
+                        &config,
+                        &files,
+                        &Diagnostic::error()
+                            .with_message(format!(
+                                "{context} - Precedence symbol '{symbol}' is not declared"
+                            ))
+                            .with_code("parol::parser::undeclared_precedence_symbol")
+                            .with_labels(vec![Label::primary(
+                                file_id,
+                                Into::<Range<usize>>::into(location),
+                            )])
+                            .with_notes(vec![
+                                "Declare the symbol's precedence with %left, %right or %nonassoc."
+                                    .to_string(),
+                            ]),
+                    )?)
+                }
+                ParolParserError::ConflictingDefinitions {
+                    kind,
+                    name,
+                    first_input,
+                    first,
+                    second_input,
+                    second,
+                } => {
+                    let mut files = SimpleFiles::new();
+                    let content = fs::read_to_string(first_input).unwrap_or_default();
+                    let first_file_id = files.add(first_input.display().to_string(), content);
+                    let content = fs::read_to_string(second_input).unwrap_or_default();
+                    let second_file_id = files.add(second_input.display().to_string(), content);
+
+                    Ok(term::emit(
+                        &mut writer.lock(),
+                        &config,
+                        &files,
+                        &Diagnostic::error()
+                            .with_message(format!(
+                                "{kind} '{name}' is defined in different grammar files"
+                            ))
+                            .with_code("parol::parser::conflicting_definitions")
+                            .with_labels(vec![
+                                Label::primary(second_file_id, Into::<Range<usize>>::into(second))
+                                    .with_message("Second definition"),
+                                Label::secondary(first_file_id, Into::<Range<usize>>::into(first))
+                                    .with_message("First definition"),
+                            ])
+                            .with_notes(vec![
+                                "Rename one of the definitions or remove one of them.".to_string(),
+                            ]),
+                    )?)
+                }
+                ParolParserError::DuplicateLabel {
+                    kind,
+                    label,
+                    input,
+                    first,
+                    second,
+                } => {
+                    let mut files = SimpleFiles::new();
+                    let content = fs::read_to_string(input).unwrap_or_default();
+                    let file_id = files.add(input.display().to_string(), content);
+
+                    Ok(term::emit(
+                        &mut writer.lock(),
+                        &config,
+                        &files,
+                        &Diagnostic::error()
+                            .with_message(format!("{kind} '{label}' is used more than once"))
+                            .with_code("parol::parser::duplicate_label")
+                            .with_labels(vec![
+                                Label::primary(file_id, Into::<Range<usize>>::into(second))
+                                    .with_message("Second usage"),
+                                Label::secondary(file_id, Into::<Range<usize>>::into(first))
+                                    .with_message("First usage"),
+                            ])
+                            .with_notes(vec!["Rename one of the labels.".to_string()]),
+                    )?)
+                }
                 ParolParserError::MixedScannerSwitching {
                     context,
                     input,
@@ -305,6 +395,203 @@ impl Report for ParolErrorReporter {
                             ]),
                     )?)
                 }
+                ParolParserError::EmptyMatchingTerminal {
+                    context,
+                    terminal,
+                    input,
+                    location,
+                } => {
+                    let mut files = SimpleFiles::new();
+                    let content = fs::read_to_string(input).unwrap_or_default();
+                    let file_id = files.add(input.display().to_string(), content);
+
+                    Ok(term::emit(
+                        &mut writer.lock(),
+                        &config,
+                        &files,
+                        &Diagnostic::error()
+                            .with_message(format!(
+                                "{context} - Terminal '{terminal}' matches the empty string"
+                            ))
+                            .with_code("parol::parser::empty_matching_terminal")
+                            .with_labels(vec![Label::primary(
+                                file_id,
+                                Into::<Range<usize>>::into(location),
+                            )])
+                            .with_notes(vec![
+                                "Terminals that match the empty string make the scanner produce empty tokens without making progress in the input.".to_string(),
+                                "Use a repetition or an optional expression in your grammar instead.".to_string(),
+                            ]),
+                    )?)
+                }
+                ParolParserError::InvalidKeyword {
+                    context,
+                    keyword,
+                    input,
+                    location,
+                } => {
+                    let mut files = SimpleFiles::new();
+                    let content = fs::read_to_string(input).unwrap_or_default();
+                    let file_id = files.add(input.display().to_string(), content);
+
+                    Ok(term::emit(
+                        &mut writer.lock(),
+                        &config,
+                        &files,
+                        &Diagnostic::error()
+                            .with_message(format!(
+                                "{context} - Invalid keyword '{keyword}'. Use a primary non-terminal for the keyword."
+                            ))
+                            .with_code("parol::parser::invalid_keyword")
+                            .with_labels(vec![Label::primary(
+                                file_id,
+                                Into::<Range<usize>>::into(location),
+                            )])
+                            .with_notes(vec![
+                                "A primary non-terminal is a non-terminal with a single production that consists of a single terminal, e.g. `If: 'if';`".to_string(),
+                            ]),
+                    )?)
+                }
+                ParolParserError::InvalidOffsideToken {
+                    context,
+                    token,
+                    input,
+                    location,
+                } => {
+                    let mut files = SimpleFiles::new();
+                    let content = fs::read_to_string(input).unwrap_or_default();
+                    let file_id = files.add(input.display().to_string(), content);
+
+                    Ok(term::emit(
+                        &mut writer.lock(),
+                        &config,
+                        &files,
+                        &Diagnostic::error()
+                            .with_message(format!(
+                                "{context} - Invalid token '{token}' in %offside directive. Use a primary non-terminal for the token."
+                            ))
+                            .with_code("parol::parser::invalid_offside_token")
+                            .with_labels(vec![Label::primary(
+                                file_id,
+                                Into::<Range<usize>>::into(location),
+                            )])
+                            .with_notes(vec![
+                                "A primary non-terminal is a non-terminal with a single production that consists of a single terminal, e.g. `Indent: '<INDENT>';`".to_string(),
+                            ]),
+                    )?)
+                }
+                ParolParserError::UnknownNonTerminal {
+                    context,
+                    name,
+                    input,
+                    location,
+                } => {
+                    let mut files = SimpleFiles::new();
+                    let content = fs::read_to_string(input).unwrap_or_default();
+                    let file_id = files.add(input.display().to_string(), content);
+
+                    Ok(term::emit(
+                        &mut writer.lock(),
+                        &config,
+                        &files,
+                        &Diagnostic::error()
+                            .with_message(format!(
+                                "{context} - Non-terminal '{name}' is not defined"
+                            ))
+                            .with_code("parol::parser::unknown_non_terminal")
+                            .with_labels(vec![Label::primary(
+                                file_id,
+                                Into::<Range<usize>>::into(location),
+                            )])
+                            .with_notes(vec![
+                                "Use the name of a non-terminal that has productions".to_string(),
+                            ]),
+                    )?)
+                }
+                ParolParserError::InvalidInlineNonTerminal {
+                    context,
+                    name,
+                    input,
+                    location,
+                } => {
+                    let mut files = SimpleFiles::new();
+                    let content = fs::read_to_string(input).unwrap_or_default();
+                    let file_id = files.add(input.display().to_string(), content);
+
+                    Ok(term::emit(
+                        &mut writer.lock(),
+                        &config,
+                        &files,
+                        &Diagnostic::error()
+                            .with_message(format!(
+                                "{context} - Non-terminal '{name}' can't be inlined"
+                            ))
+                            .with_code("parol::parser::invalid_inline_non_terminal")
+                            .with_labels(vec![Label::primary(
+                                file_id,
+                                Into::<Range<usize>>::into(location),
+                            )])
+                            .with_notes(vec![
+                                "Only non-terminals with a single alternative can be inlined"
+                                    .to_string(),
+                            ]),
+                    )?)
+                }
+                ParolParserError::InvalidDisplayNameTarget {
+                    context,
+                    token,
+                    input,
+                    location,
+                } => {
+                    let mut files = SimpleFiles::new();
+                    let content = fs::read_to_string(input).unwrap_or_default();
+                    let file_id = files.add(input.display().to_string(), content);
+
+                    Ok(term::emit(
+                        &mut writer.lock(),
+                        &config,
+                        &files,
+                        &Diagnostic::error()
+                            .with_message(format!(
+                                "{context} - Invalid token '{token}' in %display declaration. Use a primary non-terminal for the token."
+                            ))
+                            .with_code("parol::parser::invalid_display_name_target")
+                            .with_labels(vec![Label::primary(
+                                file_id,
+                                Into::<Range<usize>>::into(location),
+                            )])
+                            .with_notes(vec![
+                                "A primary non-terminal is a non-terminal with a single production that consists of a single terminal, e.g. `LBracket: '[';`".to_string(),
+                            ]),
+                    )?)
+                }
+                ParolParserError::InvalidErrorTokenDefinition {
+                    context,
+                    input,
+                    location,
+                } => {
+                    let mut files = SimpleFiles::new();
+                    let content = fs::read_to_string(input).unwrap_or_default();
+                    let file_id = files.add(input.display().to_string(), content);
+
+                    Ok(term::emit(
+                        &mut writer.lock(),
+                        &config,
+                        &files,
+                        &Diagnostic::error()
+                            .with_message(format!(
+                                "{context} - The error token 'error' can't be defined by a production"
+                            ))
+                            .with_code("parol::parser::invalid_error_token_definition")
+                            .with_labels(vec![Label::primary(
+                                file_id,
+                                Into::<Range<usize>>::into(location),
+                            )])
+                            .with_notes(vec![
+                                "In LALR(1) grammars `error` is the error token that is used in resynchronization rules like `Stmt: error ';';`".to_string(),
+                            ]),
+                    )?)
+                }
             }
         } else if let Some(err) = err.downcast_ref::<GrammarAnalysisError>() {
             match err {
@@ -369,15 +656,21 @@ impl Report for ParolErrorReporter {
                             ]),
                     )?);
                 }
-                GrammarAnalysisError::MaxKExceeded { max_k } => {
+                GrammarAnalysisError::MaxKExceeded { max_k, conflict } => {
                     return Ok(term::emit(
                         &mut writer.lock(),
                         &config,
                         &files,
                         &Diagnostic::error()
-                            .with_message(format!("Maximum lookahead of {max_k} exceeded"))
+                            .with_message(format!(
+                                "Maximum lookahead of {max_k} exceeded for non-terminal {}",
+                                conflict.non_terminal
+                            ))
                             .with_code("parol::analysis::max_k_exceeded")
-                            .with_notes(vec!["Please examine your grammar.".to_string()]),
+                            .with_notes(vec![
+                                "Please examine your grammar.".to_string(),
+                                format!("{}", conflict),
+                            ]),
                     )?);
                 }
                 GrammarAnalysisError::LALR1ParseTableConstructionFailed { conflict } => {
@@ -394,6 +687,31 @@ impl Report for ParolErrorReporter {
                             ]),
                     )?);
                 }
+                GrammarAnalysisError::UnexpectedConflicts {
+                    expected_shift_reduce,
+                    shift_reduce,
+                    expected_reduce_reduce,
+                    reduce_reduce,
+                    conflicts,
+                } => {
+                    let mut notes = vec![format!(
+                        "Expected {expected_shift_reduce} shift-reduce and {expected_reduce_reduce} reduce-reduce conflicts but found {shift_reduce} and {reduce_reduce}."
+                    )];
+                    notes.extend(conflicts.iter().map(|c| c.to_string()));
+                    notes.push(
+                        "Please examine the conflicts and update the %expect and %expect-rr declarations."
+                            .to_string(),
+                    );
+                    return Ok(term::emit(
+                        &mut writer.lock(),
+                        &config,
+                        &files,
+                        &Diagnostic::error()
+                            .with_message("Unexpected number of conflicts in the LALR(1) grammar")
+                            .with_code("parol::analysis::unexpected_conflicts")
+                            .with_notes(notes),
+                    )?);
+                }
             }
         } else {
             let result = term::emit(
@@ -408,7 +726,7 @@ impl Report for ParolErrorReporter {
                             .map_or("No details".to_string(), |s| s.to_string()),
                     ]),
             );
-            return result.map_err(|e| anyhow::anyhow!(e));
+            result.map_err(|e| anyhow::anyhow!(e))
         }
     }
 }
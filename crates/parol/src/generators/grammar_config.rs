@@ -1,12 +1,13 @@
 use parol_runtime::log::trace;
 use parol_runtime::once_cell::sync::Lazy;
+use parol_runtime::TerminalIndex;
 
 use super::ScannerConfig;
 use crate::parser::parol_grammar::GrammarType;
 use crate::parser::try_to_convert;
-use crate::{Cfg, ParolGrammar};
+use crate::{Cfg, ParolGrammar, PrecedenceLevel};
 use anyhow::Result;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 use std::convert::TryFrom;
 use std::fmt::{Debug, Display, Error, Formatter};
 
@@ -55,6 +56,45 @@ pub struct GrammarConfig {
     ///
     pub user_type_defs: Vec<(String, String)>,
 
+    ///
+    /// Precedence levels declared with %left, %right or %nonassoc in ascending order of
+    /// precedence. They are used to resolve shift-reduce conflicts in LALR(1) grammars.
+    ///
+    pub precedence_levels: Vec<PrecedenceLevel>,
+
+    ///
+    /// Derives declared with %derive that are added to all generated types
+    ///
+    pub derives: Vec<String>,
+
+    ///
+    /// Derives declared with %derive for single non-terminals. They replace the global derives for
+    /// the types generated for these non-terminals.
+    ///
+    pub non_terminal_derives: BTreeMap<String, Vec<String>>,
+
+    ///
+    /// Non-terminals declared with %inline. In auto-gen mode their members are spliced into the
+    /// types of their parents.
+    ///
+    pub inline_non_terminals: BTreeSet<String>,
+
+    ///
+    /// Names of terminals declared with %display, indexed by terminal index. Generated parsers use
+    /// them in syntax errors instead of the generated terminal names.
+    ///
+    pub display_names: BTreeMap<TerminalIndex, String>,
+
+    ///
+    /// The number of shift-reduce conflicts declared with %expect
+    ///
+    pub expected_shift_reduce_conflicts: Option<usize>,
+
+    ///
+    /// The number of reduce-reduce conflicts declared with %expect-rr
+    ///
+    pub expected_reduce_reduce_conflicts: Option<usize>,
+
     ///
     /// At least one scanner configurations
     ///
@@ -109,6 +149,64 @@ impl GrammarConfig {
         self
     }
 
+    /// Sets the precedence levels
+    pub fn with_precedence_levels(mut self, precedence_levels: Vec<PrecedenceLevel>) -> Self {
+        self.precedence_levels = precedence_levels;
+        self
+    }
+
+    /// Sets the derives of all generated types
+    pub fn with_derives(mut self, derives: Vec<String>) -> Self {
+        self.derives = derives;
+        self
+    }
+
+    /// Adds derives for the types generated for the given non-terminal
+    pub fn add_non_terminal_derives(mut self, non_terminal: String, derives: Vec<String>) -> Self {
+        self.non_terminal_derives
+            .entry(non_terminal)
+            .or_default()
+            .extend(derives);
+        self
+    }
+
+    /// Returns the derives of the types generated for the given non-terminal
+    pub fn derives_of(&self, non_terminal: &str) -> &[String] {
+        self.non_terminal_derives
+            .get(non_terminal)
+            .unwrap_or(&self.derives)
+    }
+
+    /// Adds a non-terminal whose members are spliced into the types of its parents
+    pub fn add_inline_non_terminal(mut self, non_terminal: String) -> Self {
+        self.inline_non_terminals.insert(non_terminal);
+        self
+    }
+
+    /// Adds the name of a terminal that is used in syntax errors
+    pub fn add_display_name(mut self, terminal_index: TerminalIndex, display_name: String) -> Self {
+        self.display_names.insert(terminal_index, display_name);
+        self
+    }
+
+    /// Sets the numbers of shift-reduce and reduce-reduce conflicts the LALR(1) parse table
+    /// construction should resolve automatically
+    pub fn with_expected_conflicts(
+        mut self,
+        shift_reduce: Option<usize>,
+        reduce_reduce: Option<usize>,
+    ) -> Self {
+        self.expected_shift_reduce_conflicts = shift_reduce;
+        self.expected_reduce_reduce_conflicts = reduce_reduce;
+        self
+    }
+
+    /// Returns true if the number of expected conflicts is declared with %expect or %expect-rr
+    pub fn expects_conflicts(&self) -> bool {
+        self.expected_shift_reduce_conflicts.is_some()
+            || self.expected_reduce_reduce_conflicts.is_some()
+    }
+
     /// Adds a scanner configuration
     pub fn add_scanner(mut self, scanner_config: ScannerConfig) -> Self {
         self.scanner_configurations.push(scanner_config);
@@ -143,14 +241,13 @@ impl GrammarConfig {
             "UNMATCHABLE_TOKEN".to_owned(),
             "UNMATCHABLE_TOKEN".to_owned(),
         ];
-        let mut terminals =
-            self.cfg
-                .get_ordered_terminals()
-                .into_iter()
-                .fold(terminals, |mut acc, (t, k, _)| {
-                    acc.push(k.expand(t));
-                    acc
-                });
+        let mut terminals = self.cfg.get_ordered_terminals().into_iter().fold(
+            terminals,
+            |mut acc, (t, k, c, _)| {
+                acc.push(k.expand_with_context(t, c));
+                acc
+            },
+        );
         terminals.push("ERROR_TOKEN".to_owned());
         terminals
     }
@@ -237,6 +334,8 @@ mod test {
                 vec![0],
                 SymbolAttribute::None,
                 None,
+                None,
+                None,
             ))
         };
     }
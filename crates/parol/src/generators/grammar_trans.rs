@@ -36,9 +36,10 @@ pub fn check_and_transform_grammar(cfg: &Cfg, grammar_type: GrammarType) -> Resu
         bail!(GrammarAnalysisError::UnreachableNonTerminals { non_terminals });
     }
 
-    match grammar_type {
-        GrammarType::LLK => check_and_transform_ll(cfg),
-        GrammarType::LALR1 => check_and_transform_lr(cfg),
+    if grammar_type.is_lr() {
+        check_and_transform_lr(cfg)
+    } else {
+        check_and_transform_ll(cfg)
     }
 }
 
@@ -1,11 +1,11 @@
 use crate::analysis::lookahead_dfa::ProductionIndex;
 use crate::generators::NamingHelper as NmHlp;
-use crate::grammar::ProductionAttribute;
+use crate::grammar::{ProductionAttribute, ERROR_TOKEN_NAME};
 use crate::parser::GrammarType;
 use crate::{Pr, Symbol, Terminal};
 use anyhow::{anyhow, bail, Result};
 use parol_runtime::log::trace;
-use std::collections::{BTreeMap, HashSet};
+use std::collections::{BTreeMap, BTreeSet, HashSet};
 use std::fmt::{Debug, Display, Error, Formatter};
 
 use crate::{grammar::SymbolAttribute, Cfg, GrammarConfig};
@@ -78,6 +78,9 @@ pub struct GrammarTypeInfo {
     /// The grammar type
     pub(crate) grammar_type: GrammarType,
 
+    /// Non-terminals whose members are spliced into the types of their parents
+    inline_non_terminals: BTreeSet<String>,
+
     /// Helper
     terminals: Vec<String>,
     terminal_names: Vec<String>,
@@ -297,7 +300,7 @@ impl GrammarTypeInfo {
         self.terminals = cfg
             .get_ordered_terminals()
             .iter()
-            .map(|(t, k, _)| k.expand(t))
+            .map(|(t, k, c, _)| k.expand_with_context(t, *c))
             .collect::<Vec<String>>();
 
         self.terminal_names = self.terminals.iter().fold(Vec::new(), |mut acc, e| {
@@ -306,8 +309,12 @@ impl GrammarTypeInfo {
             acc
         });
 
+        self.inline_non_terminals = grammar_config.inline_non_terminals.clone();
+
         self.create_initial_non_terminal_types(&grammar_config.cfg)?;
         self.deduce_actions(grammar_config)?;
+        self.finish_inline_non_terminal_types(&grammar_config.cfg)?;
+        self.build_production_types(&grammar_config.cfg)?;
         self.finish_non_terminal_types(&grammar_config.cfg)?;
         self.generate_ast_enum_type()?;
         self.add_user_actions(grammar_config)?;
@@ -334,19 +341,52 @@ impl GrammarTypeInfo {
     }
 
     /// Create the initial non-terminal types for each non-terminal of the grammar.
+    /// The types of non-empty collections refer to the types of their element non-terminals, thus
+    /// they are created last.
     fn create_initial_non_terminal_types(&mut self, cfg: &Cfg) -> Result<()> {
+        let mut non_empty_collections = Vec::new();
         for nt in cfg.get_non_terminal_set() {
             let alternatives = cfg.matching_productions(&nt);
             if alternatives.is_empty() {
                 continue;
             }
+            if alternatives.len() == 1
+                && alternatives[0].1.get_attribute() == ProductionAttribute::NonEmptyCollectionStart
+            {
+                non_empty_collections.push((nt, alternatives[0].1.clone()));
+                continue;
+            }
             if let Ok(nt_type) = self.create_initial_non_terminal_type(&nt, alternatives) {
                 self.add_non_terminal_type(&nt, nt_type)?;
             }
         }
+        for (nt, pr) in non_empty_collections {
+            let nt_type = self.create_non_empty_collection_type(&nt, &pr)?;
+            self.add_non_terminal_type(&nt, nt_type)?;
+        }
         Ok(())
     }
 
+    /// Create the type of a non-empty collection.
+    /// The production has the form `R' -> a R''`, where `R''` is the collection of the remaining
+    /// elements. The type of its non-terminal is also the element type of the non-empty
+    /// collection.
+    fn create_non_empty_collection_type(
+        &mut self,
+        non_terminal: &str,
+        pr: &Pr,
+    ) -> Result<SymbolId> {
+        let element_type = match pr.get_r().last() {
+            Some(Symbol::N(n, SymbolAttribute::RepetitionAnchor, ..)) => *self
+                .non_terminal_types
+                .get(n)
+                .ok_or_else(|| anyhow!("No type for non-terminal {} found!", n))?,
+            _ => bail!("Unexpected production for non-empty collection {}", pr),
+        };
+        self.symbol_table
+            .insert_global_type(non_terminal, TypeEntrails::NonEmptyVec(element_type))
+    }
+
     /// Create the initial non-terminal type. This is done by looking at the productions of the
     /// non-terminal and deducing the type from the production attributes resp. from the number
     /// of alternatives.
@@ -364,6 +404,7 @@ impl GrammarTypeInfo {
                 ProductionAttribute::None => (),
                 ProductionAttribute::CollectionStart
                 | ProductionAttribute::AddToCollection
+                | ProductionAttribute::NonEmptyCollectionStart
                 | ProductionAttribute::OptionalSome
                 | ProductionAttribute::OptionalNone => {
                     return self
@@ -390,8 +431,67 @@ impl GrammarTypeInfo {
 
     fn finish_non_terminal_types(&mut self, cfg: &Cfg) -> Result<()> {
         for nt in cfg.get_non_terminal_set() {
-            self.finish_non_terminal_type(&nt, cfg)?;
+            // The types of inlined non-terminals are already finished
+            if !self.inline_non_terminals.contains(&nt) {
+                self.finish_non_terminal_type(&nt, cfg)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// The members of the inlined non-terminals' types are spliced into the types of their
+    /// parents. Thus these types are finished before all others.
+    fn finish_inline_non_terminal_types(&mut self, cfg: &Cfg) -> Result<()> {
+        let mut finished = Vec::new();
+        for nt in self.inline_non_terminals.clone() {
+            self.finish_inline_non_terminal_type(&nt, cfg, &mut Vec::new(), &mut finished)?;
+        }
+        Ok(())
+    }
+
+    /// Finishes the type of an inlined non-terminal after the types of the inlined non-terminals
+    /// it contains.
+    fn finish_inline_non_terminal_type(
+        &mut self,
+        nt: &str,
+        cfg: &Cfg,
+        path: &mut Vec<String>,
+        finished: &mut Vec<String>,
+    ) -> Result<()> {
+        if finished.iter().any(|f| f == nt) {
+            return Ok(());
+        }
+        if path.iter().any(|p| p == nt) {
+            bail!("Non-terminal {} can't be inlined into itself", nt);
+        }
+        let is_struct = self.non_terminal_types.get(nt).is_some_and(|t| {
+            matches!(
+                self.symbol_table.symbol_as_type(*t).entrails(),
+                TypeEntrails::Struct
+            )
+        });
+        if !is_struct {
+            bail!(
+                "Non-terminal {} can't be inlined because its type is no struct",
+                nt
+            );
         }
+        path.push(nt.to_string());
+        for action_id in self.matching_actions(nt) {
+            for arg in self.arguments(action_id)? {
+                let inner = self
+                    .symbol_table
+                    .symbol_as_instance(arg)
+                    .inline_non_terminal()
+                    .map(|n| n.to_string());
+                if let Some(inner) = inner {
+                    self.finish_inline_non_terminal_type(&inner, cfg, path, finished)?;
+                }
+            }
+        }
+        path.pop();
+        self.finish_non_terminal_type(nt, cfg)?;
+        finished.push(nt.to_string());
         Ok(())
     }
 
@@ -416,7 +516,9 @@ impl GrammarTypeInfo {
             },
         )?;
 
-        if actions.len() == 1 {
+        if actions.len() == 1 && actions[0].1 == ProductionAttribute::NonEmptyCollectionStart {
+            // The type is complete, see create_non_empty_collection_type
+        } else if actions.len() == 1 {
             let arguments = self.arguments(actions[0].0)?;
             let non_terminal_type = *self.non_terminal_types.get(nt).unwrap();
             // Copy the arguments as struct members
@@ -436,19 +538,12 @@ impl GrammarTypeInfo {
             };
             let mut arguments = self.arguments(primary_action)?;
             // Remove the recursive part. Vec is wrapped outside.
-            match self.grammar_type {
-                GrammarType::LLK => {
-                    trace!(
-                        "Removing recursive part from Vec type from the right end of the arguments"
-                    );
-                    arguments.pop();
-                }
-                GrammarType::LALR1 => {
-                    trace!(
-                        "Removing recursive part from Vec type from the left end of the arguments"
-                    );
-                    arguments.remove(0);
-                }
+            if self.grammar_type.is_lr() {
+                trace!("Removing recursive part from Vec type from the left end of the arguments");
+                arguments.remove(0);
+            } else {
+                trace!("Removing recursive part from Vec type from the right end of the arguments");
+                arguments.pop();
             }
             vector_typed_non_terminal_opt = Some(nt.to_string());
             let non_terminal_type = *self.non_terminal_types.get(nt).unwrap();
@@ -546,8 +641,15 @@ impl GrammarTypeInfo {
             self.build_arguments(grammar_config, function_id)?;
 
             self.adapter_actions.insert(i, function_id);
+        }
+        Ok(())
+    }
 
-            self.build_production_type(function_id, i, &grammar_config.cfg)?;
+    /// Builds the output types of all productions.
+    /// This is done after the types of the inlined non-terminals are finished.
+    fn build_production_types(&mut self, cfg: &Cfg) -> Result<()> {
+        for (prod_num, function_id) in self.adapter_actions.clone() {
+            self.build_production_type(function_id, prod_num, cfg)?;
         }
         Ok(())
     }
@@ -558,13 +660,25 @@ impl GrammarTypeInfo {
 
     /// Generates a member name from a symbol that stems from a production's right-hand side
     /// The second string in the returned tuple is used as description, here the terminal's content.
+    /// A label given by `label=` is used instead of the symbol's name.
     pub fn generate_member_name(&self, s: &Symbol) -> (String, String) {
         match s {
-            Symbol::N(n, ..) => (NmHlp::to_lower_snake_case(n), String::default()),
-            Symbol::T(Terminal::Trm(t, k, ..)) => {
-                let terminal_name = &self.terminal_names[self.get_terminal_index(&k.expand(t))];
-                (NmHlp::to_lower_snake_case(terminal_name), t.to_string())
+            Symbol::N(n, _, _, l) => (
+                NmHlp::to_lower_snake_case(l.as_deref().unwrap_or(n)),
+                String::default(),
+            ),
+            Symbol::T(Terminal::Trm(t, k, _, _, _, c, l)) => {
+                let terminal_name = &self.terminal_names
+                    [self.get_terminal_index(&k.expand_with_context(t, c.as_ref()))];
+                (
+                    NmHlp::to_lower_snake_case(l.as_deref().unwrap_or(terminal_name)),
+                    t.to_string(),
+                )
             }
+            Symbol::T(Terminal::Error(_, _, l)) => (
+                NmHlp::to_lower_snake_case(l.as_deref().unwrap_or(ERROR_TOKEN_NAME)),
+                ERROR_TOKEN_NAME.to_string(),
+            ),
             _ => panic!("Invalid symbol type {}", s),
         }
     }
@@ -598,7 +712,12 @@ impl GrammarTypeInfo {
                 .filter(|s| s.is_t() || s.is_n())
                 .try_fold(Vec::new(), |mut acc, s| {
                     self.deduce_type_of_symbol(s).map(|t| {
-                        acc.push((t, s.attribute()));
+                        acc.push((
+                            t,
+                            s.attribute(),
+                            self.non_empty_collection_type(s),
+                            self.inline_non_terminal(s),
+                        ));
                         acc
                     })
                 })?;
@@ -623,11 +742,13 @@ impl GrammarTypeInfo {
                 .generate_member_names(prod.get_r())
                 .iter()
                 .zip(types.drain(..))
-                .try_for_each(|((n, r), (t, a))| {
+                .try_for_each(|((n, r), (t, a, c, i))| {
                     // Tokens are taken from the parameter list per definition.
                     let mut used =
                         matches!(t, TypeEntrails::Token) && a != SymbolAttribute::Clipped;
-                    let type_id = if let TypeEntrails::UserDefinedType(k, ref u) = t {
+                    let type_id = if let Some(non_empty_collection_type) = c {
+                        non_empty_collection_type
+                    } else if let TypeEntrails::UserDefinedType(k, ref u) = t {
                         if k == MetaSymbolKind::Token {
                             used = true;
                         }
@@ -660,7 +781,10 @@ impl GrammarTypeInfo {
                             function_id,
                             n,
                             type_id,
-                            InstanceEntrailsBuilder::default().used(used).build()?,
+                            InstanceEntrailsBuilder::default()
+                                .used(used)
+                                .inline_non_terminal(i)
+                                .build()?,
                             a,
                             r,
                         )
@@ -674,7 +798,7 @@ impl GrammarTypeInfo {
 
     fn deduce_type_of_symbol(&self, symbol: &Symbol) -> Result<TypeEntrails> {
         match symbol {
-            Symbol::T(Terminal::Trm(_, _, _, a, u)) => {
+            Symbol::T(Terminal::Trm(_, _, _, a, u, ..)) | Symbol::T(Terminal::Error(a, u, _)) => {
                 if *a == SymbolAttribute::Clipped {
                     Ok(TypeEntrails::Clipped(MetaSymbolKind::Token))
                 } else if let Some(ref user_defined_type) = u {
@@ -686,7 +810,7 @@ impl GrammarTypeInfo {
                     Ok(TypeEntrails::Token)
                 }
             }
-            Symbol::N(n, a, u) => {
+            Symbol::N(n, a, u, _) => {
                 let inner_type = self.non_terminal_types.get(n).unwrap();
                 if let Some(ref user_defined_type) = u {
                     Ok(TypeEntrails::UserDefinedType(
@@ -705,6 +829,11 @@ impl GrammarTypeInfo {
                             }
                         }
                         SymbolAttribute::RepetitionAnchor => Ok(TypeEntrails::Vec(*inner_type)),
+                        SymbolAttribute::NonEmptyRepetitionAnchor => Ok(self
+                            .symbol_table
+                            .symbol_as_type(*inner_type)
+                            .entrails()
+                            .clone()),
                         SymbolAttribute::Option => Ok(TypeEntrails::Option(*inner_type)),
                         SymbolAttribute::Clipped => Ok(TypeEntrails::Clipped(
                             MetaSymbolKind::NonTerminal(*inner_type),
@@ -716,6 +845,30 @@ impl GrammarTypeInfo {
         }
     }
 
+    /// Returns the type of the non-empty collection if the symbol is one.
+    /// All references to the non-empty collection share this named type.
+    fn non_empty_collection_type(&self, symbol: &Symbol) -> Option<SymbolId> {
+        match symbol {
+            Symbol::N(n, SymbolAttribute::NonEmptyRepetitionAnchor, None, _) => {
+                self.non_terminal_types.get(n).copied()
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the name of the non-terminal if the symbol refers to an inlined non-terminal.
+    /// Only plain references are inlined, i.e. ones without user type and special semantic.
+    fn inline_non_terminal(&self, symbol: &Symbol) -> Option<String> {
+        match symbol {
+            Symbol::N(n, SymbolAttribute::None, None, _)
+                if self.inline_non_terminals.contains(n) =>
+            {
+                Some(n.clone())
+            }
+            _ => None,
+        }
+    }
+
     fn build_production_type(
         &mut self,
         function_id: SymbolId,
@@ -741,12 +894,22 @@ impl GrammarTypeInfo {
 
     /// Copy the arguments as struct members into the given production type.
     /// Here we convert members to boxed members if cycles are introduced.
+    /// Arguments of inlined non-terminals are replaced by the members of their types.
     fn arguments_to_struct_members(
         &mut self,
         arguments: &[SymbolId],
         production_type: SymbolId,
     ) -> Result<()> {
         for arg in arguments {
+            let inline_non_terminal = self
+                .symbol_table
+                .symbol_as_instance(*arg)
+                .inline_non_terminal()
+                .map(|n| n.to_string());
+            if let Some(inline_non_terminal) = inline_non_terminal {
+                self.splice_struct_members(*arg, &inline_non_terminal, production_type)?;
+                continue;
+            }
             let inst_name = self.symbol_table.symbol(*arg).name();
             let (type_of_inst, description, sem) = {
                 let inst = self.symbol_table.symbol_as_instance(*arg);
@@ -765,6 +928,39 @@ impl GrammarTypeInfo {
         Ok(())
     }
 
+    /// Copy the members of the inlined non-terminal's type into the given production type.
+    fn splice_struct_members(
+        &mut self,
+        arg: SymbolId,
+        inline_non_terminal: &str,
+        production_type: SymbolId,
+    ) -> Result<()> {
+        let inline_type = *self
+            .non_terminal_types
+            .get(inline_non_terminal)
+            .ok_or_else(|| anyhow!("No type for non-terminal {} found!", inline_non_terminal))?;
+        for member in self.symbol_table.members(inline_type)?.to_vec() {
+            let member_name = self.symbol_table.symbol(member).name();
+            let (type_of_inst, description, sem) = {
+                let inst = self.symbol_table.symbol_as_instance(member);
+                (inst.type_id(), inst.description().to_owned(), inst.sem())
+            };
+
+            self.symbol_table.insert_instance(
+                production_type,
+                &member_name,
+                type_of_inst,
+                InstanceEntrailsBuilder::default()
+                    .used(true)
+                    .inlined_from(Some((arg, member)))
+                    .build()?,
+                sem,
+                &description,
+            )?;
+        }
+        Ok(())
+    }
+
     fn generate_ast_enum_type(&mut self) -> Result<()> {
         self.ast_enum_type = self
             .symbol_table
@@ -807,20 +1003,27 @@ impl GrammarTypeInfo {
 
     // Generates an enum variant name for the given production from its right-hand side. If the
     // production has an empty RHS we simple name this enum variant "<NonTerminal>Empty".
+    // A label given by `#Label` is taken as it is.
     fn generate_production_rhs_name(&self, prod_num: usize, cfg: &Cfg) -> String {
         let pr = &cfg[prod_num];
         let lhs = pr.get_r();
-        if lhs.is_empty() {
+        if let Some(label) = pr.get_label() {
+            label.to_string()
+        } else if lhs.is_empty() {
             format!("{}Empty", NmHlp::to_upper_camel_case(pr.get_n_str()))
         } else {
             lhs.iter().fold(String::new(), |mut acc, s| {
                 match s {
-                    Symbol::N(n, _, _) => acc.push_str(&NmHlp::to_upper_camel_case(n)),
-                    Symbol::T(Terminal::Trm(t, k, ..)) => {
+                    Symbol::N(n, ..) => acc.push_str(&NmHlp::to_upper_camel_case(n)),
+                    Symbol::T(Terminal::Trm(t, k, _, _, _, c, _)) => {
                         acc.push_str(&NmHlp::to_upper_camel_case(
-                            &self.terminal_names[self.get_terminal_index(&k.expand(t))],
+                            &self.terminal_names
+                                [self.get_terminal_index(&k.expand_with_context(t, c.as_ref()))],
                         ))
                     }
+                    Symbol::T(Terminal::Error(..)) => {
+                        acc.push_str(&NmHlp::to_upper_camel_case(ERROR_TOKEN_NAME))
+                    }
                     _ => (),
                 }
                 acc
@@ -54,7 +54,7 @@ impl ScannerBuildInfo {
     }
 }
 
-fn determine_hashes_for_raw_string(e: &str) -> String {
+pub(crate) fn determine_hashes_for_raw_string(e: &str) -> String {
     let mut pattern = r#"""#.to_string();
     let mut count = 0;
     while e.contains(&pattern) {
@@ -69,6 +69,7 @@ struct LexerData {
     augmented_terminals: StrVec,
     used_token_constants: String,
     terminal_names: StrVec,
+    terminal_display_names: StrVec,
     terminal_count: usize,
     scanner_build_configs: StrVec,
 }
@@ -158,6 +159,23 @@ pub fn generate_lexer_source(grammar_config: &GrammarConfig) -> Result<String> {
             acc
         });
 
+    // Only grammars with %display declarations get an extra array of names for syntax errors
+    let terminal_display_names = if grammar_config.display_names.is_empty() {
+        StrVec::new(0)
+    } else {
+        terminal_names
+            .iter()
+            .enumerate()
+            .fold(StrVec::new(4), |mut acc, (i, e)| {
+                let display_name = grammar_config
+                    .display_names
+                    .get(&(i as TerminalIndex))
+                    .unwrap_or(e);
+                acc.push(format!(r#"/* {:w$} */ {:?},"#, i, display_name, w = width));
+                acc
+            })
+    };
+
     let terminal_names =
         terminal_names
             .iter()
@@ -171,6 +189,7 @@ pub fn generate_lexer_source(grammar_config: &GrammarConfig) -> Result<String> {
         augmented_terminals,
         used_token_constants,
         terminal_names,
+        terminal_display_names,
         terminal_count,
         scanner_build_configs,
     };
@@ -200,11 +219,20 @@ impl std::fmt::Display for LexerData {
             augmented_terminals,
             used_token_constants,
             terminal_names,
+            terminal_display_names,
             terminal_count,
             scanner_build_configs,
         } = self;
 
         let blank_line = "\n\n";
+        let terminal_display_names = if terminal_display_names.is_empty() {
+            String::default()
+        } else {
+            format!(
+                "pub const TERMINAL_DISPLAY_NAMES: &[&str; {}] = &[\n{}];\n\n",
+                terminal_count, terminal_display_names
+            )
+        };
         let scanner_build_configs = scanner_build_configs.join("\n\n");
         f.write_fmt(ume::ume! {
         use parol_runtime::lexer::tokenizer::{
@@ -217,6 +245,7 @@ impl std::fmt::Display for LexerData {
         pub const TERMINAL_NAMES: &[&str; #terminal_count] = &[
         #terminal_names];
         #blank_line
+        #terminal_display_names
         #scanner_build_configs
         })
     }
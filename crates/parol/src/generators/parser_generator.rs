@@ -4,7 +4,10 @@ use crate::analysis::lookahead_dfa::CompiledProductionIndex;
 use crate::analysis::LookaheadDFA;
 use crate::config::{CommonGeneratorConfig, ParserGeneratorConfig};
 use crate::conversions::dot::render_dfa_dot_string;
+use crate::generators::lexer_generator::determine_hashes_for_raw_string;
 use crate::generators::GrammarConfig;
+use crate::grammar::{cfg::TerminalIndexFn, ERROR_TOKEN_NAME};
+use crate::parser::parol_grammar::GrammarType;
 use crate::{LRAction, LRParseTable, Pr, Symbol, Terminal};
 use anyhow::{anyhow, Result};
 use parol_runtime::lexer::{
@@ -112,13 +115,10 @@ impl Production {
         pr: &Pr,
         prod_num: usize,
         non_terminals: &[&str],
-        terminals: &[&str],
+        terminal_index: &impl TerminalIndexFn,
     ) -> Self {
         let get_non_terminal_index =
             |nt: &str| non_terminals.iter().position(|n| *n == nt).unwrap();
-        let get_terminal_index = |tr: &str| {
-            terminals.iter().position(|t| *t == tr).unwrap() as TerminalIndex + FIRST_USER_TOKEN
-        };
         let lhs = get_non_terminal_index(pr.get_n_str());
         let production =
             pr.get_r()
@@ -129,9 +129,10 @@ impl Production {
                         Symbol::N(n, ..) => {
                             acc.push(format!("ParseType::N({}),", get_non_terminal_index(n)))
                         }
-                        Symbol::T(Terminal::Trm(t, ..)) => {
-                            acc.push(format!("ParseType::T({}),", get_terminal_index(t)))
-                        }
+                        Symbol::T(Terminal::Trm(t, k, _, _, _, c, _)) => acc.push(format!(
+                            "ParseType::T({}),",
+                            terminal_index.terminal_index(t, *k, c.as_ref())
+                        )),
                         Symbol::S(s) => acc.push(format!("ParseType::S({}),", s)),
                         Symbol::Push(s) => acc.push(format!("ParseType::Push({}),", s)),
                         Symbol::Pop => acc.push("ParseType::Pop,".to_string()),
@@ -264,6 +265,8 @@ struct ParserData<'a> {
     user_type_life_time: &'static str,
     module_name: &'a str,
     trim_parse_tree: bool,
+    use_display_names: bool,
+    adaptive_non_terminals: StrVec,
 }
 
 impl std::fmt::Display for ParserData<'_> {
@@ -282,6 +285,8 @@ impl std::fmt::Display for ParserData<'_> {
             user_type_life_time,
             module_name,
             trim_parse_tree,
+            use_display_names,
+            adaptive_non_terminals,
         } = self;
 
         writeln!(
@@ -333,6 +338,13 @@ impl std::fmt::Display for ParserData<'_> {
         })?;
 
         writeln!(f, "\n\n{}", dfa_source)?;
+        if !adaptive_non_terminals.is_empty() {
+            let adaptive_non_terminal_count = adaptive_non_terminals.len();
+            f.write_fmt(ume::ume! {
+                const ADAPTIVE_NON_TERMINALS: &[usize; #adaptive_non_terminal_count] = &[#adaptive_non_terminals];
+            })?;
+            writeln!(f, "\n")?;
+        }
         writeln!(f, "\n{}\n", productions)?;
 
         f.write_fmt(ume::ume! {
@@ -368,6 +380,16 @@ impl std::fmt::Display for ParserData<'_> {
         } else {
             ""
         };
+        let enable_display_names = if *use_display_names {
+            "llk_parser.set_terminal_display_names(TERMINAL_DISPLAY_NAMES);\n"
+        } else {
+            ""
+        };
+        let enable_adaptive_prediction = if adaptive_non_terminals.is_empty() {
+            ""
+        } else {
+            "llk_parser.set_adaptive_non_terminals(ADAPTIVE_NON_TERMINALS);\n"
+        };
         f.write_fmt(ume::ume! {
             pub fn parse<'t, T>(
                 input: &'t str,
@@ -382,6 +404,8 @@ impl std::fmt::Display for ParserData<'_> {
                     NON_TERMINALS,
                 );
                 #enable_trimming
+                #enable_display_names
+                #enable_adaptive_prediction
                 #auto_wrapper
                 llk_parser.parse(TokenStream::new(input, file_name, &SCANNERS, MAX_K).unwrap(),
                     #mut_ref_user_actions)
@@ -403,7 +427,9 @@ struct LRParserData<'a> {
     user_type_life_time: &'static str,
     module_name: &'a str,
     trim_parse_tree: bool,
+    use_display_names: bool,
     parse_table_source: String,
+    glr: bool,
 }
 
 impl std::fmt::Display for LRParserData<'_> {
@@ -420,7 +446,9 @@ impl std::fmt::Display for LRParserData<'_> {
             user_type_life_time,
             module_name,
             trim_parse_tree,
+            use_display_names,
             parse_table_source,
+            glr,
         } = self;
 
         writeln!(
@@ -439,6 +467,8 @@ impl std::fmt::Display for LRParserData<'_> {
         } else {
             ume::ume!(UserActionsTrait,).to_string()
         };
+        // The GLR parser uses the same parse table format as the LR parser
+        let parser_type = if *glr { "GLRParser" } else { "LRParser" };
         f.write_fmt(ume::ume! {
             use parol_runtime::{ScannerConfig, TokenStream, Tokenizer};
             use parol_runtime::once_cell::sync::Lazy;
@@ -447,7 +477,7 @@ impl std::fmt::Display for LRParserData<'_> {
             use parol_runtime::parser::{
                 ParseTreeType, Trans, ParseType, Production, #user_action_trait
             };
-            use parol_runtime::lr_parser::{LRParseTable, LRParser, LRProduction, LR1State, LRAction};
+            use parol_runtime::lr_parser::{LRParseTable, #parser_type, LRProduction, LR1State, LRAction};
             use std::path::Path;
         })?;
 
@@ -509,13 +539,18 @@ impl std::fmt::Display for LRParserData<'_> {
         } else {
             ""
         };
+        let enable_display_names = if *use_display_names {
+            "lr_parser.set_terminal_display_names(TERMINAL_DISPLAY_NAMES);\n"
+        } else {
+            ""
+        };
         f.write_fmt(ume::ume! {
             pub fn parse<'t, T>(
                 input: &'t str,
                 file_name: T,
                 user_actions: #user_actions,
             ) -> Result<ParseTree<'t>, ParolError> where T: AsRef<Path> {
-                let mut lr_parser = LRParser::new(
+                let mut lr_parser = #parser_type::new(
                     #start_symbol_index,
                     &PARSE_TABLE,
                     PRODUCTIONS,
@@ -523,6 +558,7 @@ impl std::fmt::Display for LRParserData<'_> {
                     NON_TERMINALS,
                 );
                 #enable_trimming
+                #enable_display_names
                 #auto_wrapper
                 lr_parser.parse(TokenStream::new(input, file_name, &SCANNERS, 1).unwrap(),
                     #mut_ref_user_actions)
@@ -545,7 +581,6 @@ pub fn generate_parser_source<C: CommonGeneratorConfig + ParserGeneratorConfig>(
     la_dfa: &BTreeMap<String, LookaheadDFA>,
     ast_type_has_lifetime: bool,
 ) -> Result<String> {
-    let terminals = get_terminals(grammar_config);
     let original_non_terminals = grammar_config.cfg.get_non_terminal_set();
     let non_terminal_count = original_non_terminals.len();
     let width = (non_terminal_count as f32).log10() as usize + 1;
@@ -564,7 +599,17 @@ pub fn generate_parser_source<C: CommonGeneratorConfig + ParserGeneratorConfig>(
 
     let dfa_source = generate_dfa_source(la_dfa);
 
-    let productions = generate_productions(grammar_config, &original_non_terminals, &terminals);
+    // The non-terminals whose productions are predicted by speculative parsing
+    let adaptive_non_terminals = original_non_terminals
+        .iter()
+        .enumerate()
+        .filter(|(_, n)| la_dfa.get(*n).is_some_and(|dfa| dfa.adaptive))
+        .fold(StrVec::new(4), |mut acc, (i, n)| {
+            acc.push(format!("{} /* {} */,", i, n));
+            acc
+        });
+
+    let productions = generate_productions(grammar_config, &original_non_terminals);
 
     let max_k = grammar_config.lookahead_size;
 
@@ -586,6 +631,8 @@ pub fn generate_parser_source<C: CommonGeneratorConfig + ParserGeneratorConfig>(
         user_type_life_time,
         module_name: config.module_name(),
         trim_parse_tree: config.trim_parse_tree(),
+        use_display_names: !grammar_config.display_names.is_empty(),
+        adaptive_non_terminals,
     };
 
     Ok(format!("{}", parser_data))
@@ -594,6 +641,11 @@ pub fn generate_parser_source<C: CommonGeneratorConfig + ParserGeneratorConfig>(
 fn generate_scanner_builds(grammar_config: &GrammarConfig) -> StrVec {
     let primary_non_terminal_finder = grammar_config.cfg.get_primary_non_terminal_finder();
     let scanner_state_resolver = grammar_config.get_scanner_state_resolver();
+    let terminals = grammar_config.cfg.get_ordered_terminals();
+    let raw = |s: &str| {
+        let hashes = determine_hashes_for_raw_string(s);
+        format!(r#"r{}"{}"{}"#, hashes, s, hashes)
+    };
     grammar_config
         .scanner_configurations
         .iter()
@@ -603,9 +655,40 @@ fn generate_scanner_builds(grammar_config: &GrammarConfig) -> StrVec {
                 acc.push(format!(r#"({} /* {} */, {} /* {} */),"#, t.0, primary_non_terminal_finder(t.0).unwrap_or("".to_string()), t.1, scanner_state_resolver(&[t.1])));
                 acc
             });
+            let build = if e.longest_match {
+                "build_longest_match"
+            } else {
+                "build"
+            };
+            let keywords = if e.keywords.is_empty() {
+                String::default()
+            } else {
+                let keywords = e.keywords.iter().map(|k| format!("{} /* {} */", k, primary_non_terminal_finder(*k).unwrap_or_default())).collect::<Vec<_>>().join(", ");
+                format!(".with_keywords(TERMINALS, &[{}]).unwrap()", keywords)
+            };
+            let nested_block_comments = if e.nested_block_comments.is_empty() {
+                String::default()
+            } else {
+                let comments = e.nested_block_comments.iter().map(|(s, e)| format!("({}, {})", raw(s), raw(e))).collect::<Vec<_>>().join(", ");
+                format!(".with_nested_block_comments(&[{}]).unwrap()", comments)
+            };
+            let trailing_contexts = terminals.iter().enumerate().filter_map(|(t, (term, kind, context, states))| {
+                let context = context.filter(|_| states.contains(&e.scanner_state))?;
+                let t = t as TerminalIndex + FIRST_USER_TOKEN;
+                Some(format!("({} /* {} */, {}, {})", t, primary_non_terminal_finder(t).unwrap_or_default(), raw(&kind.expand(term)), raw(&context.expand())))
+            }).collect::<Vec<_>>();
+            let trailing_contexts = if trailing_contexts.is_empty() {
+                String::default()
+            } else {
+                format!(".with_trailing_contexts(&[{}]).unwrap()", trailing_contexts.join(", "))
+            };
+            let offside = e.offside.map_or(String::default(), |(i, d, n)| {
+                let t = |t| format!("{} /* {} */", t, primary_non_terminal_finder(t).unwrap_or_default());
+                format!(".with_offside({}, {}, {})", t(i), t(d), t(n))
+            });
             acc.push(format!(
-                r#"ScannerConfig::new("{}", Tokenizer::build(TERMINALS, SCANNER_{}.0, SCANNER_{}.1).unwrap(), &[{}]),"#,
-                e.scanner_name, i, i, transitions
+                r#"ScannerConfig::new("{}", Tokenizer::{}(TERMINALS, SCANNER_{}.0, SCANNER_{}.1).unwrap(){}{}{}{}, &[{}]),"#,
+                e.scanner_name, build, i, i, keywords, nested_block_comments, trailing_contexts, offside, transitions
             ));
             acc
         })
@@ -616,7 +699,7 @@ fn get_terminals(grammar_config: &GrammarConfig) -> Vec<&str> {
         .cfg
         .get_ordered_terminals()
         .iter()
-        .map(|(t, _, _)| *t)
+        .map(|(t, ..)| *t)
         .collect::<Vec<&str>>()
 }
 
@@ -640,7 +723,7 @@ fn find_start_symbol_index(
 // *Changes will affect crate's version according to semver*
 // ---------------------------------------------------
 ///
-/// Generates the parser part of the parser output file in case of LALR(1) parser.
+/// Generates the parser part of the parser output file in case of LALR(1), LR(1) and GLR parser.
 ///
 pub fn generate_lalr1_parser_source<C: CommonGeneratorConfig + ParserGeneratorConfig>(
     grammar_config: &GrammarConfig,
@@ -684,7 +767,9 @@ pub fn generate_lalr1_parser_source<C: CommonGeneratorConfig + ParserGeneratorCo
         user_type_life_time,
         module_name: config.module_name(),
         trim_parse_tree: config.trim_parse_tree(),
+        use_display_names: !grammar_config.display_names.is_empty(),
         parse_table_source,
+        glr: grammar_config.grammar_type == GrammarType::GLR,
     };
 
     Ok(format!("{}", parser_data))
@@ -698,7 +783,11 @@ fn generate_parse_table_source(
     // Create a terminal resolver function
     let tr = |ti: TerminalIndex| {
         if ti >= FIRST_USER_TOKEN {
-            terminals[(ti - FIRST_USER_TOKEN) as usize]
+            // The error token is the only one that follows the user terminals
+            terminals
+                .get((ti - FIRST_USER_TOKEN) as usize)
+                .copied()
+                .unwrap_or(ERROR_TOKEN_NAME)
         } else {
             match ti {
                 EOI => "<$>",
@@ -721,6 +810,9 @@ fn generate_parse_table_source(
             s.actions.iter().for_each(|(_, a)| {
                 acc.insert(a.clone());
             });
+            s.conflicting_actions.values().flatten().for_each(|a| {
+                acc.insert(a.clone());
+            });
             acc
         });
 
@@ -776,11 +868,19 @@ fn generate_source_for_actions<'a>(
     tr: &impl Fn(TerminalIndex) -> &'a str,
     nr: &impl Fn(NonTerminalIndex) -> &'a str,
 ) -> String {
+    // The conflicting actions follow the regular ones, thus the LR parser finds the regular action
+    // of a terminal first
     format!(
         r#"&[{}]"#,
         state
             .actions
             .iter()
+            .chain(
+                state
+                    .conflicting_actions
+                    .iter()
+                    .flat_map(|(t, actions)| actions.iter().map(move |a| (t, a))),
+            )
             .map(|(t, a)| {
                 format!(
                     r#"
@@ -874,8 +974,9 @@ fn generate_dfa_source(la_dfa: &BTreeMap<String, LookaheadDFA>) -> String {
 fn generate_productions(
     grammar_config: &GrammarConfig,
     non_terminals: &BTreeSet<String>,
-    terminals: &[&str],
 ) -> String {
+    // Terminals are identified by their text and their kind
+    let terminal_index = grammar_config.cfg.get_terminal_index_function();
     let non_terminals = non_terminals
         .iter()
         .map(|n| n.as_str())
@@ -888,7 +989,8 @@ fn generate_productions(
             .iter()
             .enumerate()
             .fold(String::new(), |mut acc, (i, p)| {
-                let production = Production::from_cfg_production(p, i, &non_terminals, terminals);
+                let production =
+                    Production::from_cfg_production(p, i, &non_terminals, &terminal_index);
                 acc.push_str(format!("{}", production).as_str());
                 acc
             });
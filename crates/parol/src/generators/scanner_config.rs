@@ -33,6 +33,12 @@ pub struct ScannerConfig {
     ///
     pub block_comments: Vec<(String, String)>,
 
+    ///
+    /// (String, String) tuples with the characters that start and end
+    /// a block comment that can be nested.
+    ///
+    pub nested_block_comments: Vec<(String, String)>,
+
     ///
     /// If true the lexer handles (and skips) newlines.
     /// If false the user has to handle newlines on its own.
@@ -45,6 +51,25 @@ pub struct ScannerConfig {
     ///
     pub auto_ws: bool,
 
+    ///
+    /// If true the lexer takes the longest match of all terminals.
+    /// If false the first matching terminal wins.
+    ///
+    pub longest_match: bool,
+
+    ///
+    /// The terminals declared with %keywords that are valid in this scanner state.
+    /// They win over other terminals that match the same text.
+    ///
+    pub keywords: Vec<TerminalIndex>,
+
+    ///
+    /// The terminals (indent, dedent, newline) that the scanner synthesizes from the indentation
+    /// of the lines if the scanner state is in offside mode.
+    /// They are never matched against the input.
+    ///
+    pub offside: Option<(TerminalIndex, TerminalIndex, TerminalIndex)>,
+
     /// Scanner state transitions
     /// Maps from token to scanner state, where the token is identified by its TerminalIndex
     /// The scanner state is identified by its index.
@@ -59,8 +84,12 @@ impl ScannerConfig {
             scanner_state,
             line_comments: Vec::new(),
             block_comments: Vec::new(),
+            nested_block_comments: Vec::new(),
             auto_newline: true,
             auto_ws: true,
+            longest_match: false,
+            keywords: Vec::new(),
+            offside: None,
             transitions: Vec::new(),
         }
     }
@@ -77,6 +106,15 @@ impl ScannerConfig {
         self
     }
 
+    /// Adds block comments that can be nested to self
+    pub fn with_nested_block_comments(
+        mut self,
+        nested_block_comments: Vec<(String, String)>,
+    ) -> Self {
+        self.nested_block_comments = nested_block_comments;
+        self
+    }
+
     /// Sets auto newline behavior
     pub fn with_auto_newline(mut self, auto_newline: bool) -> Self {
         self.auto_newline = auto_newline;
@@ -89,11 +127,19 @@ impl ScannerConfig {
         self
     }
 
+    /// Sets the longest match strategy
+    pub fn with_longest_match(mut self, longest_match: bool) -> Self {
+        self.longest_match = longest_match;
+        self
+    }
+
     ///
     /// Generates the data needed by the lexer generator.
     /// The tuple contains of the specific internal tokens of the scanner (ws,
     /// comments etc.) and the indices of the terminals that are valid in this
     /// scanner.
+    /// The keywords are placed after all other terminals, thus they only match on their own if no
+    /// other terminal matches. The terminals synthesized in offside mode are omitted.
     ///
     pub fn generate_build_information(
         &self,
@@ -123,11 +169,18 @@ impl ScannerConfig {
         } else {
             scanner_specific.push("UNMATCHABLE_TOKEN".to_owned());
         }
-        if !self.block_comments.is_empty() {
+        if !self.block_comments.is_empty() || !self.nested_block_comments.is_empty() {
+            // Nested block comments are matched by their start only, the tokenizer determines their
+            // end. They come first to take precedence over flat ones with the same start.
             let block_comments_rx = self
-                .block_comments
+                .nested_block_comments
                 .iter()
-                .map(|(s, e)| format!(r###"((?ms){}.*?{})"###, s, e))
+                .map(|(s, _)| format!(r###"({})"###, s))
+                .chain(
+                    self.block_comments
+                        .iter()
+                        .map(|(s, e)| format!(r###"((?ms){}.*?{})"###, s, e)),
+                )
                 .collect::<Vec<String>>()
                 .join("|");
             scanner_specific.push(block_comments_rx);
@@ -137,19 +190,29 @@ impl ScannerConfig {
 
         let terminals = cfg.get_ordered_terminals();
 
-        let term_indices =
+        let mut term_indices =
             terminals
                 .iter()
                 .enumerate()
-                .fold(Vec::new(), |mut acc, (i, (_, _, s))| {
-                    if s.contains(&self.scanner_state) {
-                        acc.push(i as TerminalIndex + FIRST_USER_TOKEN);
+                .fold(Vec::new(), |mut acc, (i, (.., s))| {
+                    let term_index = i as TerminalIndex + FIRST_USER_TOKEN;
+                    if s.contains(&self.scanner_state)
+                        && !self.keywords.contains(&term_index)
+                        && !self.is_synthesized(term_index)
+                    {
+                        acc.push(term_index);
                     }
                     acc
                 });
+        term_indices.extend_from_slice(&self.keywords);
 
         (scanner_specific, term_indices, self.scanner_name.clone())
     }
+
+    fn is_synthesized(&self, term_index: TerminalIndex) -> bool {
+        self.offside
+            .is_some_and(|(i, d, n)| [i, d, n].contains(&term_index))
+    }
 }
 
 impl Default for ScannerConfig {
@@ -159,8 +222,12 @@ impl Default for ScannerConfig {
             scanner_state: 0,
             line_comments: Vec::new(),
             block_comments: Vec::new(),
+            nested_block_comments: Vec::new(),
             auto_newline: true,
             auto_ws: true,
+            longest_match: false,
+            keywords: Vec::new(),
+            offside: None,
             transitions: Vec::new(),
         }
     }
@@ -172,8 +239,14 @@ impl Display for ScannerConfig {
         writeln!(f, "scanner_state: {}", self.scanner_state)?;
         writeln!(f, "line_comments: {:?}", self.line_comments)?;
         writeln!(f, "block_comments: {:?}", self.block_comments)?;
+        writeln!(f, "nested_block_comments: {:?}", self.nested_block_comments)?;
         writeln!(f, "auto_newline: {:?}", self.auto_newline)?;
         writeln!(f, "auto_ws: {:?}", self.auto_ws)?;
+        writeln!(f, "longest_match: {:?}", self.longest_match)?;
+        writeln!(f, "keywords: {:?}", self.keywords)?;
+        if let Some(offside) = self.offside {
+            writeln!(f, "offside: {:?}", offside)?;
+        }
         self.transitions
             .iter()
             .try_for_each(|(k, v)| write!(f, "on {} enter {};", k, v))
@@ -137,10 +137,11 @@ impl Display for MetaSymbolKind {
 ///
 /// Type information used for auto-generation
 ///
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, TS)]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize, TS)]
 #[ts(export)]
 pub(crate) enum TypeEntrails {
     /// Not specified, used as prototype during generation
+    #[default]
     None,
     /// Will be generated as Token structure
     Token,
@@ -158,6 +159,8 @@ pub(crate) enum TypeEntrails {
     EnumVariant(SymbolId),
     /// Will be generated as `Vec<T>` where T is the type specified by SymbolId
     Vec(SymbolId),
+    /// Will be generated as `NonEmptyVec<T>` where T is the type specified by SymbolId
+    NonEmptyVec(SymbolId),
     /// A trait, normally the semantic actions trait generated for the user grammar
     Trait,
     /// A trait function
@@ -177,6 +180,7 @@ impl TypeEntrails {
             | TypeEntrails::Surrogate(t)
             | TypeEntrails::Ref(t)
             | TypeEntrails::Vec(t)
+            | TypeEntrails::NonEmptyVec(t)
             | TypeEntrails::Option(t)
             | TypeEntrails::UserDefinedType(MetaSymbolKind::NonTerminal(t), _) => {
                 symbol_table.symbol(*t).name()
@@ -190,13 +194,10 @@ impl TypeEntrails {
     }
 
     pub(crate) fn is_container(&self) -> bool {
-        matches!(self, Self::Vec(_) | Self::Option(_) | Self::Box(_))
-    }
-}
-
-impl Default for TypeEntrails {
-    fn default() -> Self {
-        Self::None
+        matches!(
+            self,
+            Self::Vec(_) | Self::NonEmptyVec(_) | Self::Option(_) | Self::Box(_)
+        )
     }
 }
 
@@ -254,6 +255,11 @@ impl Type {
                 symbol_table.symbol(*r).name(),
                 symbol_table.lifetime(*r)
             ),
+            TypeEntrails::NonEmptyVec(r) => format!(
+                "parol_runtime::NonEmptyVec<{}{}>",
+                symbol_table.symbol(*r).name(),
+                symbol_table.lifetime(*r)
+            ),
             TypeEntrails::Trait => format!("trait {}{}", my_type_name, lifetime),
             TypeEntrails::Function(f) => f.to_rust(my_type_name),
             TypeEntrails::Option(o) => format!("Option<{}>", symbol_table.symbol(*o).to_rust()),
@@ -285,6 +291,7 @@ impl Type {
             | TypeEntrails::Ref(t)
             | TypeEntrails::EnumVariant(t)
             | TypeEntrails::Vec(t)
+            | TypeEntrails::NonEmptyVec(t)
             | TypeEntrails::Option(t) => Some(t),
             _ => None,
         }
@@ -304,6 +311,16 @@ pub(crate) struct InstanceEntrails {
     /// Indicates if the argument is used
     #[builder(default)]
     pub(crate) used: bool,
+
+    /// The non-terminal declared with %inline whose members replace the argument in the struct
+    /// types
+    #[builder(default)]
+    pub(crate) inline_non_terminal: Option<String>,
+
+    /// The argument and the member of the inlined non-terminal's type a struct member is spliced
+    /// from
+    #[builder(default)]
+    pub(crate) inlined_from: Option<(SymbolId, SymbolId)>,
 }
 
 ///
@@ -421,6 +438,7 @@ impl Symbol {
                 | TypeEntrails::Surrogate(ref mut i)
                 | TypeEntrails::EnumVariant(ref mut i)
                 | TypeEntrails::Vec(ref mut i)
+                | TypeEntrails::NonEmptyVec(ref mut i)
                 | TypeEntrails::Option(ref mut i)
                 | TypeEntrails::Clipped(MetaSymbolKind::NonTerminal(ref mut i)) => {
                     *i = inner_type;
@@ -741,7 +759,7 @@ impl SymbolTable {
     }
 
     /// Returns a type facade of the symbol with the given id
-    pub(crate) fn symbol_as_type(&self, symbol_id: SymbolId) -> impl TypeFacade {
+    pub(crate) fn symbol_as_type(&self, symbol_id: SymbolId) -> impl TypeFacade<'_> {
         let symbol_type = match &self[symbol_id].kind {
             SymbolKind::Type(t) => t,
             SymbolKind::Instance(_) => panic!("Ain't no type!"),
@@ -1041,7 +1059,7 @@ impl SymbolTable {
         ));
         let parent_scope_id = self.symbol_as_type(parent_type).member_scope();
         let parent_scope = self.scope_mut(parent_scope_id);
-        if parent_scope.symbols.iter().any(|s| *s == type_symbol_id) {
+        if parent_scope.symbols.contains(&type_symbol_id) {
             self[type_symbol_id].set_inner_type(referred_type_id)?;
             // parent_scope.symbols[index] = referred_type_id;
         } else {
@@ -1212,10 +1230,7 @@ impl SymbolTable {
                                 .find(|s| {
                                     if let SymbolKind::Type(t) = &s.kind {
                                         if matches!(t.entrails, TypeEntrails::Enum) {
-                                            self.members(s.my_id())
-                                                .unwrap()
-                                                .iter()
-                                                .any(|m| *m == *symbol_id)
+                                            self.members(s.my_id()).unwrap().contains(symbol_id)
                                         } else {
                                             false
                                         }
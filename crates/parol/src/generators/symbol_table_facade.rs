@@ -21,6 +21,8 @@ pub(crate) trait InstanceFacade<'a>: SymbolFacade<'a> {
     fn description(&self) -> &str;
     fn sem(&self) -> SymbolAttribute;
     fn used(&self) -> bool;
+    fn inline_non_terminal(&self) -> Option<&str>;
+    fn inlined_from(&self) -> Option<(SymbolId, SymbolId)>;
 }
 
 pub(crate) trait TypeFacade<'a>: SymbolFacade<'a> {
@@ -123,6 +125,14 @@ impl<'a> InstanceFacade<'a> for InstanceItem<'a> {
     fn used(&self) -> bool {
         self.instance.entrails.used
     }
+
+    fn inline_non_terminal(&self) -> Option<&str> {
+        self.instance.entrails.inline_non_terminal.as_deref()
+    }
+
+    fn inlined_from(&self) -> Option<(SymbolId, SymbolId)> {
+        self.instance.entrails.inlined_from
+    }
 }
 
 pub(crate) struct TypeItem<'a> {
@@ -151,6 +161,7 @@ impl<'a> SymbolFacade<'a> for TypeItem<'a> {
             | TypeEntrails::Surrogate(t)
             | TypeEntrails::EnumVariant(t)
             | TypeEntrails::Vec(t)
+            | TypeEntrails::NonEmptyVec(t)
             | TypeEntrails::Option(t)
             | TypeEntrails::UserDefinedType(MetaSymbolKind::NonTerminal(t), _) => {
                 self.symbol_item.symbol_table.symbol(*t).name()
@@ -259,6 +270,8 @@ impl<'a> TypeFacade<'a> for TypeItem<'a> {
                     .symbols
                     .iter()
                     .fold(StrVec::new(8), |mut acc, v| {
+                        // The variant's own name, the facade would give us the name of its type
+                        let variant_name = self.symbol_item.symbol_table.name(*v);
                         let v = self.symbol_item.symbol_table.symbol_as_type(*v);
                         if let TypeEntrails::EnumVariant(a) = v.entrails() {
                             let enum_variant_type = self.symbol_item.symbol_table.symbol_as_type(*a);
@@ -267,7 +280,7 @@ impl<'a> TypeFacade<'a> for TypeItem<'a> {
                                     acc.push(format!(
                                         "{}::{}(v) => v.first().map_or(Span::default(), |f| f.span())",
                                         self.name(),
-                                        v.name()
+                                        variant_name
                                     ));
                                     acc.push(
                                         "+ v.last().map_or(Span::default(), |l| l.span()),".to_string(),
@@ -276,11 +289,11 @@ impl<'a> TypeFacade<'a> for TypeItem<'a> {
                                 TypeEntrails::Option(_) => acc.push(format!(
                                     "{}::{}(o) => o.as_ref().map_or(Span::default(), |o| o.span()),",
                                     self.name(),
-                                    v.name()
+                                    variant_name
                                 )),
                                 _ => {
                                     // Expr::CommentExpr(v) => v.span(),
-                                    acc.push(format!("{}::{}(v) => v.span(),", self.name(), v.name()))
+                                    acc.push(format!("{}::{}(v) => v.span(),", self.name(), variant_name))
                                 }
                             }
                         } else {
@@ -119,6 +119,8 @@ pub(crate) struct UserTraitData<'a> {
     pub trait_caller: StrVec,
     pub module_name: &'a str,
     pub user_trait_functions: StrVec,
+    pub error_recovery: bool,
+    pub glr: bool,
 }
 
 impl std::fmt::Display for UserTraitData<'_> {
@@ -136,6 +138,8 @@ impl std::fmt::Display for UserTraitData<'_> {
             trait_caller,
             module_name,
             user_trait_functions,
+            error_recovery,
+            glr,
         } = self;
 
         write!(
@@ -186,6 +190,11 @@ impl std::fmt::Display for UserTraitData<'_> {
             use parol_runtime::parser::{ParseTreeType, UserActionsTrait};
             use parol_runtime::{ParserError, Result, Token};
         })?;
+        if *glr {
+            f.write_fmt(ume::ume!(
+                use parol_runtime::lr_parser::Ambiguity;
+            ))?;
+        }
 
         let trait_name = format!("{}Trait", user_type_name);
         let blank_line = "\n\n";
@@ -217,11 +226,17 @@ impl std::fmt::Display for UserTraitData<'_> {
                 /// This method provides skipped language comments.
                 /// If you need comments please provide your own implementation of this method.
             ";
+            let on_ambiguity = if *glr {
+                on_ambiguity_function(anonymous_lifetime)
+            } else {
+                "".into()
+            };
             f.write_fmt(ume::ume! {
                 pub trait #trait_name #lifetime {
                     #user_trait_functions
                     #on_comment_parsed_comment
                     fn on_comment_parsed(&mut self, _token: Token #anonymous_lifetime) {}
+                    #on_ambiguity
                 }
             })?;
 
@@ -346,6 +361,30 @@ impl std::fmt::Display for UserTraitData<'_> {
                 }
             })?;
 
+            // The LR parser discards reduced non-terminals only when it recovers from errors with the
+            // help of the error token
+            let on_non_terminal_discarded = if *error_recovery {
+                ume::ume! {
+                    #blank_line
+                    fn on_non_terminal_discarded(&mut self) {
+                        self.pop("on_non_terminal_discarded");
+                    }
+                }
+                .to_string()
+            } else {
+                "".into()
+            };
+            let on_ambiguity = if *glr {
+                ume::ume! {
+                    #blank_line
+                    fn on_ambiguity(&mut self, ambiguity: &Ambiguity<'t>) -> usize {
+                        self.user_grammar.on_ambiguity(ambiguity)
+                    }
+                }
+                .to_string()
+            } else {
+                "".into()
+            };
             writeln!(f, "\n")?;
             f.write_fmt(ume::ume! {
                 #blank_line
@@ -364,6 +403,8 @@ impl std::fmt::Display for UserTraitData<'_> {
                     fn on_comment_parsed(&mut self, token: Token<'t>) {
                         self.user_grammar.on_comment_parsed(token)
                     }
+                    #on_non_terminal_discarded
+                    #on_ambiguity
                 }
             })?;
         } else {
@@ -380,9 +421,24 @@ impl std::fmt::Display for UserTraitData<'_> {
                 // This is currently only supported for auto generate mode.
                 // Please, file an issue if need arises.
                 ";
+            let (on_ambiguity, on_ambiguity_call) = if *glr {
+                (
+                    on_ambiguity_function("<'_>"),
+                    ume::ume! {
+                        #blank_line
+                        fn on_ambiguity(&mut self, ambiguity: &Ambiguity<'_>) -> usize {
+                            #trait_name::on_ambiguity(self, ambiguity)
+                        }
+                    }
+                    .to_string(),
+                )
+            } else {
+                ("".into(), "".into())
+            };
             f.write_fmt(ume::ume! {
                 pub trait #trait_name {
                     #trait_functions
+                    #on_ambiguity
                 }
                 #blank_line
                 #blank_line
@@ -401,6 +457,7 @@ impl std::fmt::Display for UserTraitData<'_> {
                     fn on_comment_parsed(&mut self, _token: Token<'_>) {
                         #supported_comment
                     }
+                    #on_ambiguity_call
                 }
             })?;
         }
@@ -408,12 +465,38 @@ impl std::fmt::Display for UserTraitData<'_> {
     }
 }
 
+// The hook of the GLR parser to choose among the alternatives of an ambiguity
+fn on_ambiguity_function(lifetime: &str) -> String {
+    let comment = r"
+
+        /// This method is called by the GLR parser for each part of the input that can be parsed
+        /// in more than one way. It returns the index of the alternative to use.
+        /// The default is the first alternative.
+    ";
+    ume::ume! {
+        #comment
+        fn on_ambiguity(&mut self, _ambiguity: &Ambiguity #lifetime) -> usize {
+            0
+        }
+    }
+    .to_string()
+}
+
+// The derives declared with %derive follow the ones that are always generated
+fn additional_derives(derives: &[String]) -> String {
+    derives.iter().fold(String::new(), |mut acc, d| {
+        let _ = write!(acc, ", {}", d);
+        acc
+    })
+}
+
 #[derive(Debug, Default)]
 pub(crate) struct NonTerminalTypeStruct {
     pub comment: StrVec,
     pub type_name: String,
     pub lifetime: String,
     pub members: StrVec,
+    pub derives: Vec<String>,
 }
 
 impl std::fmt::Display for NonTerminalTypeStruct {
@@ -423,6 +506,7 @@ impl std::fmt::Display for NonTerminalTypeStruct {
             type_name,
             lifetime,
             members,
+            derives,
         } = self;
         for comment in comment {
             writeln!(f, "/// {}", comment)?
@@ -431,9 +515,13 @@ impl std::fmt::Display for NonTerminalTypeStruct {
             let _ = writeln!(output, "pub {member}");
             output
         });
+        writeln!(f, "#[allow(dead_code)]")?;
+        writeln!(
+            f,
+            "#[derive(Builder, Debug, Clone{})]",
+            additional_derives(derives)
+        )?;
         f.write_fmt(ume::ume! {
-            #[allow(dead_code)]
-            #[derive(Builder, Debug, Clone)]
             #[builder(crate = "parol_runtime::derive_builder")]
             pub struct #type_name #lifetime {
                 #members
@@ -448,6 +536,7 @@ pub(crate) struct NonTerminalTypeEnum {
     pub type_name: String,
     pub lifetime: String,
     pub members: StrVec,
+    pub derives: Vec<String>,
 }
 
 impl std::fmt::Display for NonTerminalTypeEnum {
@@ -457,13 +546,14 @@ impl std::fmt::Display for NonTerminalTypeEnum {
             type_name,
             lifetime,
             members,
+            derives,
         } = self;
         for comment in comment {
             writeln!(f, "/// {}", comment)?
         }
+        writeln!(f, "#[allow(dead_code)]")?;
+        writeln!(f, "#[derive(Debug, Clone{})]", additional_derives(derives))?;
         f.write_fmt(ume::ume! {
-            #[allow(dead_code)]
-            #[derive(Debug, Clone)]
             pub enum #type_name #lifetime {
                 #members
             }
@@ -13,8 +13,9 @@ pub fn generate_terminal_name(terminal: &str, i: Option<TerminalIndex>, cfg: &Cf
             .iter()
             .find(|r| {
                 if r.len() == 1 {
-                    if let Symbol::T(Terminal::Trm(n, k, ..)) = &r.1[0] {
-                        k.expand(n) == terminal && cfg.matching_productions(&r.get_n()).len() == 1
+                    if let Symbol::T(Terminal::Trm(n, k, _, _, _, c, _)) = &r.1[0] {
+                        k.expand_with_context(n, c.as_ref()) == terminal
+                            && cfg.matching_productions(&r.get_n()).len() == 1
                     } else {
                         false
                     }
@@ -96,6 +97,11 @@ pub fn generate_terminal_name(terminal: &str, i: Option<TerminalIndex>, cfg: &Cf
                 if let Some(nt) = primary_non_terminal(cfg, terminal) {
                     NmHlp::to_upper_camel_case(&nt)
                 } else {
+                    // The group that makes a terminal case insensitive doesn't contribute to its name
+                    let terminal = terminal
+                        .strip_prefix("(?i:")
+                        .and_then(|t| t.strip_suffix(')'))
+                        .unwrap_or(terminal);
                     generate_name(terminal)
                 }
             }
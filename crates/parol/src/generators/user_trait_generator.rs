@@ -157,7 +157,8 @@ impl<'a> UserTraitGenerator<'a> {
             if matches!(
                 *arg_type.entrails(),
                 TypeEntrails::Clipped(MetaSymbolKind::NonTerminal(_))
-            ) {
+            ) || !Self::has_spliced_members(type_info, arg_inst.inline_non_terminal())?
+            {
                 // let arg_name = symbol_table.name(arg_inst.my_id());
                 // code.push(format!("// Ignore clipped member '{}'", arg_name));
                 code.push("self.pop(context);".to_string());
@@ -178,9 +179,10 @@ impl<'a> UserTraitGenerator<'a> {
                     )
                     .popped_item_is_mutable(
                         function.sem == ProductionAttribute::AddToCollection
-                            && match grammar_type {
-                                GrammarType::LLK => i == 0,
-                                GrammarType::LALR1 => i == member_count - 1,
+                            && if grammar_type.is_lr() {
+                                i == member_count - 1
+                            } else {
+                                i == 0
                             },
                     )
                     .build()
@@ -192,6 +194,31 @@ impl<'a> UserTraitGenerator<'a> {
         Ok(())
     }
 
+    /// Returns false if the argument is an inlined non-terminal whose type has no members that are
+    /// spliced into its parents, i.e. if all of them are clipped.
+    fn has_spliced_members(
+        type_info: &GrammarTypeInfo,
+        inline_non_terminal: Option<&str>,
+    ) -> Result<bool> {
+        if let Some(inline_non_terminal) = inline_non_terminal {
+            let symbol_table = &type_info.symbol_table;
+            let inline_type = type_info
+                .non_terminal_types
+                .get(inline_non_terminal)
+                .ok_or_else(|| anyhow!("Non-terminal type not accessible!"))?;
+            Ok(symbol_table.members(*inline_type)?.iter().any(|m| {
+                let member = symbol_table.symbol_as_instance(*m);
+                member.sem() != SymbolAttribute::Clipped
+                    && !matches!(
+                        symbol_table.symbol_as_type(member.type_id()).entrails(),
+                        TypeEntrails::Clipped(_)
+                    )
+            }))
+        } else {
+            Ok(true)
+        }
+    }
+
     fn generate_push_semantic<C: CommonGeneratorConfig + UserTraitGeneratorConfig>(
         &self,
         config: &C,
@@ -204,29 +231,26 @@ impl<'a> UserTraitGenerator<'a> {
         let fn_name = symbol_table.name(fn_type.my_id()).to_string();
 
         if config.auto_generate() && function.sem == ProductionAttribute::AddToCollection {
-            match self.grammar_config.grammar_type {
-                GrammarType::LLK => {
-                    let last_arg = symbol_table
-                        .members(action_id)?
-                        .iter()
-                        .last()
-                        .ok_or_else(|| anyhow!("There should be at least one argument!"))?;
-                    let arg_inst = symbol_table.symbol_as_instance(*last_arg);
-                    let arg_name = symbol_table.name(arg_inst.my_id());
-                    code.push("// Add an element to the vector".to_string());
-                    code.push(format!(" {}.push({}_built);", arg_name, fn_name,));
-                }
-                GrammarType::LALR1 => {
-                    let first_arg = symbol_table
-                        .members(action_id)?
-                        .iter()
-                        .next()
-                        .ok_or_else(|| anyhow!("There should be at least one argument!"))?;
-                    let arg_inst = symbol_table.symbol_as_instance(*first_arg);
-                    let arg_name = symbol_table.name(arg_inst.my_id());
-                    code.push("// Add an element to the vector".to_string());
-                    code.push(format!(" {}.push({}_built);", arg_name, fn_name,));
-                }
+            if self.grammar_config.grammar_type.is_lr() {
+                let first_arg = symbol_table
+                    .members(action_id)?
+                    .iter()
+                    .next()
+                    .ok_or_else(|| anyhow!("There should be at least one argument!"))?;
+                let arg_inst = symbol_table.symbol_as_instance(*first_arg);
+                let arg_name = symbol_table.name(arg_inst.my_id());
+                code.push("// Add an element to the vector".to_string());
+                code.push(format!(" {}.push({}_built);", arg_name, fn_name,));
+            } else {
+                let last_arg = symbol_table
+                    .members(action_id)?
+                    .iter()
+                    .last()
+                    .ok_or_else(|| anyhow!("There should be at least one argument!"))?;
+                let arg_inst = symbol_table.symbol_as_instance(*last_arg);
+                let arg_name = symbol_table.name(arg_inst.my_id());
+                code.push("// Add an element to the vector".to_string());
+                code.push(format!(" {}.push({}_built);", arg_name, fn_name,));
             }
         }
         Ok(())
@@ -243,7 +267,17 @@ impl<'a> UserTraitGenerator<'a> {
             return Ok(());
         }
         let arg_type = symbol_table.symbol_as_type(arg_inst.type_id());
-        if !matches!(*arg_type.entrails(), TypeEntrails::Clipped(_)) {
+        if let Some((inlined_arg, inlined_member)) = arg_inst.inlined_from() {
+            if !matches!(*arg_type.entrails(), TypeEntrails::Clipped(_)) {
+                Self::format_inlined_builder_call(
+                    symbol_table,
+                    member_id,
+                    inlined_arg,
+                    inlined_member,
+                    code,
+                );
+            }
+        } else if !matches!(*arg_type.entrails(), TypeEntrails::Clipped(_)) {
             let arg_name = symbol_table.name(arg_inst.my_id());
             let setter_name = &arg_name;
             let arg_name = if matches!(*arg_type.entrails(), TypeEntrails::Box(_)) &&
@@ -279,6 +313,67 @@ impl<'a> UserTraitGenerator<'a> {
         Ok(())
     }
 
+    /// A member spliced from an inlined non-terminal is moved out of the popped value of the
+    /// inlined non-terminal's type. It is boxed if the removal of type recursions boxed it.
+    fn format_inlined_builder_call(
+        symbol_table: &SymbolTable,
+        member_id: &SymbolId,
+        inlined_arg: SymbolId,
+        inlined_member: SymbolId,
+        code: &mut StrVec,
+    ) {
+        let member_name = symbol_table.name(*member_id);
+        let value = format!(
+            "{}.{}",
+            symbol_table.name(inlined_arg),
+            symbol_table.name(inlined_member)
+        );
+        let member_type = symbol_table.symbol_as_instance(*member_id).type_id();
+        let inlined_type = symbol_table.symbol_as_instance(inlined_member).type_id();
+        let value = match symbol_table.symbol_as_type(member_type).entrails() {
+            TypeEntrails::Box(t) if *t == inlined_type => format!("Box::new({})", value),
+            TypeEntrails::Option(t) if *t != inlined_type => {
+                if let TypeEntrails::Box(_) = symbol_table.symbol_as_type(*t).entrails() {
+                    format!("{}.map(Box::new)", value)
+                } else {
+                    value
+                }
+            }
+            _ => value,
+        };
+        code.push(format!("    {}: {},", member_name, value));
+    }
+
+    /// Formats the builder calls for the given argument. The argument of an inlined non-terminal
+    /// is replaced by the members that are spliced from it into the given struct type.
+    /// The members are found by the argument's name because the first element of a non-empty
+    /// collection is built from the arguments of another production than the element type.
+    fn format_builder_calls_of_argument(
+        symbol_table: &SymbolTable,
+        struct_type: SymbolId,
+        arg_id: &SymbolId,
+        sem: ProductionAttribute,
+        code: &mut StrVec,
+    ) -> Result<()> {
+        if symbol_table
+            .symbol_as_instance(*arg_id)
+            .inline_non_terminal()
+            .is_some()
+        {
+            let arg_name = symbol_table.name(*arg_id);
+            for member_id in symbol_table.members(struct_type)? {
+                let member = symbol_table.symbol_as_instance(*member_id);
+                if matches!(member.inlined_from(), Some((a, _)) if symbol_table.name(a) == arg_name)
+                {
+                    Self::format_builder_call(symbol_table, member_id, sem, code)?;
+                }
+            }
+            Ok(())
+        } else {
+            Self::format_builder_call(symbol_table, arg_id, sem, code)
+        }
+    }
+
     fn generate_result_builder<C: CommonGeneratorConfig + UserTraitGeneratorConfig>(
         &self,
         config: &C,
@@ -310,26 +405,69 @@ impl<'a> UserTraitGenerator<'a> {
         if function.sem == ProductionAttribute::CollectionStart {
             code.push(format!("let {}_built = Vec::new();", fn_name));
         } else if function.sem == ProductionAttribute::AddToCollection {
-            match self.grammar_config.grammar_type {
-                GrammarType::LLK => {
-                    code.push(format!("let {}_built = {} {{", fn_name, nt_type.name()));
-                    for member_id in symbol_table.members(action_id)?.iter().rev().skip(1) {
-                        Self::format_builder_call(symbol_table, member_id, function.sem, code)?;
-                    }
-                    code.push(r#"};"#.to_string());
+            if self.grammar_config.grammar_type.is_lr() {
+                code.push(format!("let {}_built = {} {{", fn_name, nt_type.name()));
+                for member_id in symbol_table.members(action_id)?.iter().skip(1).rev() {
+                    Self::format_builder_calls_of_argument(
+                        symbol_table,
+                        nt_type.my_id(),
+                        member_id,
+                        function.sem,
+                        code,
+                    )?;
                 }
-                GrammarType::LALR1 => {
-                    code.push(format!("let {}_built = {} {{", fn_name, nt_type.name()));
-                    for member_id in symbol_table.members(action_id)?.iter().skip(1).rev() {
-                        Self::format_builder_call(symbol_table, member_id, function.sem, code)?;
-                    }
-                    code.push(r#"};"#.to_string());
+                code.push(r#"};"#.to_string());
+            } else {
+                code.push(format!("let {}_built = {} {{", fn_name, nt_type.name()));
+                for member_id in symbol_table.members(action_id)?.iter().rev().skip(1) {
+                    Self::format_builder_calls_of_argument(
+                        symbol_table,
+                        nt_type.my_id(),
+                        member_id,
+                        function.sem,
+                        code,
+                    )?;
                 }
+                code.push(r#"};"#.to_string());
             }
+        } else if function.sem == ProductionAttribute::NonEmptyCollectionStart {
+            // The last argument holds the remaining elements, the others build the first element
+            let (remaining, first) = symbol_table
+                .members(action_id)?
+                .split_last()
+                .ok_or_else(|| anyhow!("There should be at least one argument!"))?;
+            code.push(format!("let {}_built = {} {{", fn_name, nt_type.name()));
+            let element_type = match nt_type.entrails() {
+                TypeEntrails::NonEmptyVec(element_type) => *element_type,
+                _ => nt_type.my_id(),
+            };
+            for member_id in first {
+                Self::format_builder_calls_of_argument(
+                    symbol_table,
+                    element_type,
+                    member_id,
+                    function.sem,
+                    code,
+                )?;
+            }
+            code.push(r#"};"#.to_string());
+            let remaining_inst = symbol_table.symbol_as_instance(*remaining);
+            code.push(format!(
+                "let {}_built = parol_runtime::NonEmptyVec::new({}_built, {});",
+                fn_name,
+                fn_name,
+                symbol_table.name(remaining_inst.my_id())
+            ));
         } else if function.sem == ProductionAttribute::OptionalSome {
             code.push(format!("let {}_built = {} {{", fn_name, nt_type.name()));
             for member_id in symbol_table.members(action_id)? {
-                Self::format_builder_call(symbol_table, member_id, function.sem, code)?;
+                Self::format_builder_calls_of_argument(
+                    symbol_table,
+                    nt_type.my_id(),
+                    member_id,
+                    function.sem,
+                    code,
+                )?;
             }
             code.push(r#"};"#.to_string());
         } else if function.sem == ProductionAttribute::OptionalNone {
@@ -466,37 +604,34 @@ impl<'a> UserTraitGenerator<'a> {
             if function.sem == ProductionAttribute::AddToCollection {
                 // The output type of the action is the type generated for the action's non-terminal
                 // filled with type of the action's last argument (the vector)
-                match self.grammar_config.grammar_type {
-                    GrammarType::LLK => {
-                        let last_arg = symbol_table
-                            .members(action_id)?
-                            .iter()
-                            .last()
-                            .ok_or_else(|| anyhow!("There should be at least one argument!"))?;
-                        let arg_inst = symbol_table.symbol_as_instance(*last_arg);
-                        let arg_name = symbol_table.name(arg_inst.my_id());
+                if self.grammar_config.grammar_type.is_lr() {
+                    let first_arg = symbol_table
+                        .members(action_id)?
+                        .iter()
+                        .next()
+                        .ok_or_else(|| anyhow!("There should be at least one argument!"))?;
+                    let arg_inst = symbol_table.symbol_as_instance(*first_arg);
+                    let arg_name = symbol_table.name(arg_inst.my_id());
 
-                        code.push(format!(
-                            "self.push(ASTType::{}({}), context);",
-                            NmHlp::to_upper_camel_case(&function.non_terminal),
-                            arg_name
-                        ));
-                    }
-                    GrammarType::LALR1 => {
-                        let first_arg = symbol_table
-                            .members(action_id)?
-                            .iter()
-                            .next()
-                            .ok_or_else(|| anyhow!("There should be at least one argument!"))?;
-                        let arg_inst = symbol_table.symbol_as_instance(*first_arg);
-                        let arg_name = symbol_table.name(arg_inst.my_id());
+                    code.push(format!(
+                        "self.push(ASTType::{}({}), context);",
+                        NmHlp::to_upper_camel_case(&function.non_terminal),
+                        arg_name
+                    ));
+                } else {
+                    let last_arg = symbol_table
+                        .members(action_id)?
+                        .iter()
+                        .last()
+                        .ok_or_else(|| anyhow!("There should be at least one argument!"))?;
+                    let arg_inst = symbol_table.symbol_as_instance(*last_arg);
+                    let arg_name = symbol_table.name(arg_inst.my_id());
 
-                        code.push(format!(
-                            "self.push(ASTType::{}({}), context);",
-                            NmHlp::to_upper_camel_case(&function.non_terminal),
-                            arg_name
-                        ));
-                    }
+                    code.push(format!(
+                        "self.push(ASTType::{}({}), context);",
+                        NmHlp::to_upper_camel_case(&function.non_terminal),
+                        arg_name
+                    ));
                 }
             } else if function.sem == ProductionAttribute::OptionalNone {
                 code.push(format!(
@@ -574,6 +709,7 @@ impl<'a> UserTraitGenerator<'a> {
         type_id: SymbolId,
         symbol_table: &SymbolTable,
         comment: StrVec,
+        derives: &[String],
     ) -> Result<Option<String>> {
         let type_symbol = symbol_table.symbol_as_type(type_id);
         let type_name = symbol_table.name(type_symbol.my_id()).to_string();
@@ -595,6 +731,7 @@ impl<'a> UserTraitGenerator<'a> {
                         }
                         acc
                     }),
+                    derives: derives.to_vec(),
                 };
                 Ok(Some(format!("{}", struct_data)))
             }
@@ -607,9 +744,12 @@ impl<'a> UserTraitGenerator<'a> {
                         acc.push(symbol_table.symbol(*m).to_rust());
                         acc
                     }),
+                    derives: derives.to_vec(),
                 };
                 Ok(Some(format!("{}", struct_data)))
             }
+            // Non-empty collections use the type provided by parol_runtime
+            TypeEntrails::NonEmptyVec(_) => Ok(None),
             _ => bail!("Unexpected type {:?}!", type_symbol.entrails()),
         }
     }
@@ -678,7 +818,12 @@ impl<'a> UserTraitGenerator<'a> {
                     }
                 })
                 .try_fold(StrVec::new(0), |acc, (t, f)| {
-                    Self::generate_single_production_output_type(f, t, type_info, acc, config)
+                    let derives = self
+                        .grammar_config
+                        .derives_of(self.grammar_config.cfg[f.prod_num].get_n_str());
+                    Self::generate_single_production_output_type(
+                        f, t, type_info, acc, config, derives,
+                    )
                 })?
         } else {
             StrVec::new(0)
@@ -689,7 +834,8 @@ impl<'a> UserTraitGenerator<'a> {
                 .non_terminal_types
                 .iter()
                 .try_fold(StrVec::new(0), |acc, (s, t)| {
-                    Self::generate_single_non_terminal_type(s, t, type_info, acc, config)
+                    let derives = self.grammar_config.derives_of(s);
+                    Self::generate_single_non_terminal_type(s, t, type_info, acc, config, derives)
                 })?
         } else {
             StrVec::new(0)
@@ -700,7 +846,14 @@ impl<'a> UserTraitGenerator<'a> {
             comment.push(String::default());
             comment.push("Deduced ASTType of expanded grammar".to_string());
             comment.push(String::default());
-            Self::format_type(type_info.ast_enum_type, &type_info.symbol_table, comment)?.unwrap()
+            // The ASTType comprises the types of all non-terminals which can have different derives
+            Self::format_type(
+                type_info.ast_enum_type,
+                &type_info.symbol_table,
+                comment,
+                &[],
+            )?
+            .unwrap()
         } else {
             String::default()
         };
@@ -765,6 +918,8 @@ impl<'a> UserTraitGenerator<'a> {
             .trait_caller(trait_caller)
             .module_name(config.module_name())
             .user_trait_functions(user_trait_functions)
+            .error_recovery(self.grammar_config.cfg.uses_error_token())
+            .glr(self.grammar_config.grammar_type == GrammarType::GLR)
             .build()
             .unwrap();
 
@@ -826,19 +981,20 @@ impl<'a> UserTraitGenerator<'a> {
         type_info: &GrammarTypeInfo,
         mut acc: StrVec,
         config: &C,
+        derives: &[String],
     ) -> std::result::Result<StrVec, anyhow::Error> {
         let mut comment = StrVec::new(0);
         comment.push(String::default());
         comment.push(format!("Type derived for non-terminal {}", s));
         comment.push(String::default());
-        Self::format_type(*t, &type_info.symbol_table, comment)?
-            .into_iter()
-            .for_each(|s| acc.push(s));
-        if config.range() {
-            acc.push(Self::generate_range_calculation(
-                *t,
-                &type_info.symbol_table,
-            )?);
+        if let Some(s) = Self::format_type(*t, &type_info.symbol_table, comment, derives)? {
+            acc.push(s);
+            if config.range() {
+                acc.push(Self::generate_range_calculation(
+                    *t,
+                    &type_info.symbol_table,
+                )?);
+            }
         }
         Ok(acc)
     }
@@ -851,6 +1007,7 @@ impl<'a> UserTraitGenerator<'a> {
         type_info: &GrammarTypeInfo,
         mut acc: StrVec,
         config: &C,
+        derives: &[String],
     ) -> std::result::Result<StrVec, anyhow::Error> {
         let mut comment = StrVec::new(0);
         comment.push(String::default());
@@ -858,7 +1015,7 @@ impl<'a> UserTraitGenerator<'a> {
         comment.push(String::default());
         comment.push(format!("`{}`", f.prod_string));
         comment.push(String::default());
-        Self::format_type(*t, &type_info.symbol_table, comment)?
+        Self::format_type(*t, &type_info.symbol_table, comment, derives)?
             .into_iter()
             .for_each(|s| acc.push(s));
         if config.range() {
@@ -23,15 +23,20 @@ where
 ///
 /// Attributes applicable to a production or an alternation
 ///
-#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, TS)]
+#[derive(
+    Debug, Clone, Copy, Default, Hash, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, TS,
+)]
 #[ts(export)]
 pub enum ProductionAttribute {
     /// No valid attribute, default value
+    #[default]
     None,
     /// Indicates a start of repetition, i.e. a collection
     CollectionStart,
     /// Add to a collection
     AddToCollection,
+    /// Start of a non-empty collection, i.e. its first element followed by the remaining ones
+    NonEmptyCollectionStart,
     /// Some case of an optional
     OptionalSome,
     /// None case of an optional
@@ -44,18 +49,13 @@ impl Display for ProductionAttribute {
             Self::None => write!(f, "-"),
             Self::CollectionStart => write!(f, "Vec<T>::New"),
             Self::AddToCollection => write!(f, "Vec<T>::Push"),
+            Self::NonEmptyCollectionStart => write!(f, "NonEmptyVec<T>::New"),
             Self::OptionalSome => write!(f, "Option<T>::Some"),
             Self::OptionalNone => write!(f, "Option<T>::None"),
         }
     }
 }
 
-impl Default for ProductionAttribute {
-    fn default() -> Self {
-        Self::None
-    }
-}
-
 impl<T, W> Decorate<T, W> for ProductionAttribute
 where
     T: Display,
@@ -68,6 +68,9 @@ where
             Self::AddToCollection => {
                 out.write_fmt(format_args!("{} /* Vec<T>::Push */", decoratee))
             }
+            Self::NonEmptyCollectionStart => {
+                out.write_fmt(format_args!("{} /* NonEmptyVec<T>::New */", decoratee))
+            }
             Self::OptionalSome => {
                 out.write_fmt(format_args!("{} /* Option<T>::Some */", decoratee))
             }
@@ -81,10 +84,13 @@ where
 ///
 /// Attributes applicable to a grammar symbol
 ///
-#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, TS)]
+#[derive(
+    Debug, Clone, Copy, Default, Hash, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, TS,
+)]
 #[ts(export)]
 pub enum SymbolAttribute {
     /// No valid attribute, default value
+    #[default]
     None,
 
     /// The symbol is actually a collection, i.e. a vector
@@ -93,6 +99,10 @@ pub enum SymbolAttribute {
     /// this collection should be reversed.
     RepetitionAnchor,
 
+    /// The symbol is actually a non-empty collection, i.e. a `NonEmptyVec`
+    /// Is attached to a non-terminal symbol.
+    NonEmptyRepetitionAnchor,
+
     /// The symbol is an option with the inner type that is determined by the non-terminal
     Option,
 
@@ -105,18 +115,13 @@ impl Display for SymbolAttribute {
         match self {
             Self::None => Ok(()),
             Self::RepetitionAnchor => write!(f, "`Vec<T>`"),
+            Self::NonEmptyRepetitionAnchor => write!(f, "`NonEmptyVec<T>`"),
             Self::Option => write!(f, "`Option<T>`"),
             Self::Clipped => write!(f, "Clipped"),
         }
     }
 }
 
-impl Default for SymbolAttribute {
-    fn default() -> Self {
-        Self::None
-    }
-}
-
 impl<T, W> Decorate<T, W> for SymbolAttribute
 where
     T: Display,
@@ -126,6 +131,9 @@ where
         match self {
             Self::None => out.write_fmt(format_args!("{}", decoratee)),
             Self::RepetitionAnchor => out.write_fmt(format_args!("{} /* Vec */", decoratee)),
+            Self::NonEmptyRepetitionAnchor => {
+                out.write_fmt(format_args!("{} /* NonEmptyVec */", decoratee))
+            }
             Self::Option => out.write_fmt(format_args!("{} /* Option */", decoratee)),
             Self::Clipped => out.write_fmt(format_args!("{}^ /* Clipped */", decoratee)),
         }
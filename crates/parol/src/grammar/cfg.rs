@@ -1,5 +1,5 @@
 use crate::analysis::lookahead_dfa::ProductionIndex;
-use crate::{Pos, Pr, Symbol, Terminal, TerminalKind};
+use crate::{Pos, Pr, Symbol, Terminal, TerminalKind, TrailingContext};
 use parol_runtime::once_cell::sync::Lazy;
 use parol_runtime::{NonTerminalIndex, TerminalIndex};
 use regex::Regex;
@@ -12,16 +12,26 @@ pub(crate) static RX_NUM_SUFFIX: Lazy<Regex> =
 
 /// Trait to resolve terminal indices
 pub trait TerminalIndexFn {
-    /// Returns the terminal index for a given terminal string and terminal kind
-    fn terminal_index(&self, t: &str, k: TerminalKind) -> TerminalIndex;
+    /// Returns the terminal index for a given terminal string, terminal kind and trailing context
+    fn terminal_index(
+        &self,
+        t: &str,
+        k: TerminalKind,
+        c: Option<&TrailingContext>,
+    ) -> TerminalIndex;
 }
 
 impl<F> TerminalIndexFn for F
 where
-    F: Fn(&str, TerminalKind) -> TerminalIndex,
+    F: Fn(&str, TerminalKind, Option<&TrailingContext>) -> TerminalIndex,
 {
-    fn terminal_index(&self, t: &str, k: TerminalKind) -> TerminalIndex {
-        self(t, k)
+    fn terminal_index(
+        &self,
+        t: &str,
+        k: TerminalKind,
+        c: Option<&TrailingContext>,
+    ) -> TerminalIndex {
+        self(t, k, c)
     }
 }
 
@@ -146,17 +156,19 @@ impl Cfg {
 
     ///
     /// Generates a function that returns the terminal index (in ordered of occurrence) for given
-    /// terminal string and terminal kind
+    /// terminal string, terminal kind and trailing context
     ///
     pub fn get_terminal_index_function(&self) -> impl TerminalIndexFn {
         let vec = self
             .get_ordered_terminals_owned()
             .into_iter()
-            .map(|(s, k, _)| (s, k))
-            .collect::<Vec<(String, TerminalKind)>>();
-        move |t: &str, k: TerminalKind| {
+            .map(|(s, k, c, _)| (s, k, c))
+            .collect::<Vec<(String, TerminalKind, Option<TrailingContext>)>>();
+        move |t: &str, k: TerminalKind, c: Option<&TrailingContext>| {
             (vec.iter()
-                .position(|(t0, k0)| t == t0 && k.behaves_like(*k0))
+                .position(|(t0, k0, c0)| {
+                    t == t0 && k.behaves_like(*k0) && TrailingContext::behaves_like(c, c0.as_ref())
+                })
                 .unwrap()) as TerminalIndex
                 + parol_runtime::lexer::FIRST_USER_TOKEN
         }
@@ -170,8 +182,8 @@ impl Cfg {
                 .iter()
                 .fold(HashMap::<TerminalIndex, String>::new(), |mut acc, p| {
                     if p.1.len() == 1 {
-                        if let crate::Symbol::T(Terminal::Trm(s, k, ..)) = &p.1[0] {
-                            let t = terminal_index_finder.terminal_index(s, *k);
+                        if let crate::Symbol::T(Terminal::Trm(s, k, _, _, _, c, _)) = &p.1[0] {
+                            let t = terminal_index_finder.terminal_index(s, *k, c.as_ref());
                             acc.insert(t, p.0.get_n().unwrap());
                         }
                     }
@@ -184,21 +196,25 @@ impl Cfg {
     /// Set of Terminals - ordered by occurrence.
     /// Used for Lexer generation.
     ///
-    pub fn get_ordered_terminals(&self) -> Vec<(&str, TerminalKind, Vec<usize>)> {
+    #[allow(clippy::type_complexity)]
+    pub fn get_ordered_terminals(
+        &self,
+    ) -> Vec<(&str, TerminalKind, Option<&TrailingContext>, Vec<usize>)> {
         self.pr.iter().fold(Vec::new(), |mut acc, p| {
             acc = p.get_r().iter().fold(acc, |mut acc, s| {
-                if let Symbol::T(Terminal::Trm(t, k, s, ..)) = s {
-                    if let Some(pos) = acc
-                        .iter_mut()
-                        .position(|(trm, knd, _)| trm == t && knd.behaves_like(*k))
-                    {
+                if let Symbol::T(Terminal::Trm(t, k, s, _, _, c, _)) = s {
+                    if let Some(pos) = acc.iter_mut().position(|(trm, knd, ctx, _)| {
+                        trm == t
+                            && knd.behaves_like(*k)
+                            && TrailingContext::behaves_like(*ctx, c.as_ref())
+                    }) {
                         for st in s {
-                            if !acc[pos].2.contains(st) {
-                                acc[pos].2.push(*st);
+                            if !acc[pos].3.contains(st) {
+                                acc[pos].3.push(*st);
                             }
                         }
                     } else {
-                        acc.push((t, *k, s.to_vec()));
+                        acc.push((t, *k, c.as_ref(), s.to_vec()));
                     }
                 }
                 acc
@@ -210,13 +226,35 @@ impl Cfg {
     ///
     /// Set of Terminals - ordered by occurrence as owned values.
     ///
-    pub fn get_ordered_terminals_owned(&self) -> Vec<(String, TerminalKind, Vec<usize>)> {
+    #[allow(clippy::type_complexity)]
+    pub fn get_ordered_terminals_owned(
+        &self,
+    ) -> Vec<(String, TerminalKind, Option<TrailingContext>, Vec<usize>)> {
         self.get_ordered_terminals()
             .into_iter()
-            .map(|(s, k, v)| (s.to_owned(), k, v))
+            .map(|(s, k, c, v)| (s.to_owned(), k, c.cloned(), v))
             .collect()
     }
 
+    ///
+    /// The index of the error token.
+    /// It follows the user terminals in the augmented terminal list.
+    ///
+    pub fn get_error_token_index(&self) -> TerminalIndex {
+        self.get_ordered_terminals().len() as TerminalIndex + parol_runtime::lexer::FIRST_USER_TOKEN
+    }
+
+    ///
+    /// Checks if the error token is used in any production
+    ///
+    pub fn uses_error_token(&self) -> bool {
+        self.pr.iter().any(|p| {
+            p.get_r()
+                .iter()
+                .any(|s| matches!(s, Symbol::T(Terminal::Error(..))))
+        })
+    }
+
     ///
     /// Terminal positions within the grammar
     /// Used for Nt grammar graphs
@@ -229,6 +267,7 @@ impl Cfg {
                 acc = p.get_r().iter().enumerate().fold(acc, |mut acc, (si, s)| {
                     if matches!(s, Symbol::T(Terminal::Trm(..)))
                         || matches!(s, Symbol::T(Terminal::End))
+                        || matches!(s, Symbol::T(Terminal::Error(..)))
                     {
                         acc.insert(Pos::new(pi, si + 1), s);
                     }
@@ -315,7 +354,7 @@ impl Cfg {
     ///
     /// macro_rules! terminal {
     ///     ($term:literal) => {Symbol::T(Terminal::Trm($term.to_string(), TerminalKind::Legacy,
-    ///         vec![0], SymbolAttribute::None, None))};
+    ///         vec![0], SymbolAttribute::None, None, None, None))};
     /// }
     ///
     /// let g = Cfg::with_start_symbol("S")
@@ -12,6 +12,10 @@ pub use cfg::Cfg;
 pub mod pos;
 pub use pos::Pos;
 
+/// Module with operator precedence and associativity types
+pub mod precedence;
+pub use precedence::{Associativity, PrecedenceLevel, PrecedenceSymbol};
+
 /// Module with types related to grammar productions
 pub mod production;
 pub use production::{Pr, Rhs};
@@ -20,4 +24,4 @@ pub(crate) mod symbol_string;
 
 /// Module with symbol types
 pub mod symbol;
-pub use symbol::{Symbol, Terminal, TerminalKind};
+pub use symbol::{Symbol, Terminal, TerminalKind, TrailingContext, ERROR_TOKEN_NAME};
@@ -0,0 +1,114 @@
+//! Operator precedence and associativity declarations.
+//! They are used to resolve shift-reduce conflicts in LALR(1) grammars in the style of Yacc and
+//! Bison.
+use std::fmt::{Debug, Display, Error, Formatter};
+
+use crate::TerminalKind;
+
+// ---------------------------------------------------
+// Part of the Public API
+// *Changes will affect crate's version according to semver*
+// ---------------------------------------------------
+///
+/// The associativity of a precedence level
+///
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Associativity {
+    /// Declared with `%left`, conflicts between equal precedences are resolved by reducing
+    Left,
+    /// Declared with `%right`, conflicts between equal precedences are resolved by shifting
+    Right,
+    /// Declared with `%nonassoc`, conflicts between equal precedences are syntax errors
+    NonAssoc,
+}
+
+impl Display for Associativity {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::result::Result<(), Error> {
+        match self {
+            Self::Left => write!(f, "%left"),
+            Self::Right => write!(f, "%right"),
+            Self::NonAssoc => write!(f, "%nonassoc"),
+        }
+    }
+}
+
+// ---------------------------------------------------
+// Part of the Public API
+// *Changes will affect crate's version according to semver*
+// ---------------------------------------------------
+///
+/// A symbol that is used in a precedence declaration or in a `%prec` directive
+///
+#[derive(Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum PrecedenceSymbol {
+    /// A terminal given by its literal
+    Terminal(String, TerminalKind),
+    /// A name, either of a primary non-terminal or of a pseudo symbol that is only referenced by
+    /// `%prec` directives
+    Name(String),
+}
+
+impl PrecedenceSymbol {
+    /// Checks if self and other denote the same symbol.
+    /// Terminals are compared by their text and their behavior regarding regex meta characters.
+    pub fn matches(&self, other: &PrecedenceSymbol) -> bool {
+        match (self, other) {
+            (Self::Terminal(t1, k1), Self::Terminal(t2, k2)) => t1 == t2 && k1.behaves_like(*k2),
+            (Self::Name(n1), Self::Name(n2)) => n1 == n2,
+            _ => false,
+        }
+    }
+}
+
+impl Display for PrecedenceSymbol {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::result::Result<(), Error> {
+        match self {
+            Self::Terminal(t, k) => {
+                let delimiter = k.delimiter();
+                write!(f, "{}{}{}{}", delimiter, t, delimiter, k.suffix())
+            }
+            Self::Name(n) => write!(f, "{}", n),
+        }
+    }
+}
+
+// ---------------------------------------------------
+// Part of the Public API
+// *Changes will affect crate's version according to semver*
+// ---------------------------------------------------
+///
+/// A precedence level declared with `%left`, `%right` or `%nonassoc`.
+/// Levels that are declared later bind tighter than levels that are declared earlier.
+///
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct PrecedenceLevel {
+    /// The associativity of all symbols on this level
+    pub associativity: Associativity,
+    /// The symbols on this level
+    pub symbols: Vec<PrecedenceSymbol>,
+}
+
+impl PrecedenceLevel {
+    /// Creates a new item
+    pub fn new(associativity: Associativity, symbols: Vec<PrecedenceSymbol>) -> Self {
+        Self {
+            associativity,
+            symbols,
+        }
+    }
+}
+
+impl Display for PrecedenceLevel {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::result::Result<(), Error> {
+        write!(
+            f,
+            "{} {}",
+            self.associativity,
+            self.symbols
+                .iter()
+                .map(|s| s.to_string())
+                .collect::<Vec<String>>()
+                .join(" ")
+        )
+    }
+}
@@ -1,4 +1,4 @@
-use crate::grammar::{Decorate, ProductionAttribute, SymbolAttribute};
+use crate::grammar::{Decorate, PrecedenceSymbol, ProductionAttribute, SymbolAttribute};
 use crate::{Symbol, Terminal};
 use anyhow::{anyhow, Result};
 use std::fmt::{Debug, Display, Error, Formatter};
@@ -20,9 +20,18 @@ pub type Rhs = Vec<Symbol>;
 // ---------------------------------------------------
 ///
 /// Production type
+/// The optional precedence symbol is given by a `%prec` directive and overrides the precedence
+/// that is otherwise derived from the last terminal of the production.
+/// The optional label is given by `#Label` and names the type generated for the production.
 ///
 #[derive(Debug, Clone, Hash, Eq, PartialEq, Ord, PartialOrd)]
-pub struct Pr(pub Symbol, pub Rhs, pub ProductionAttribute);
+pub struct Pr(
+    pub Symbol,
+    pub Rhs,
+    pub ProductionAttribute,
+    pub Option<PrecedenceSymbol>,
+    pub Option<String>,
+);
 
 impl Display for Pr {
     ///
@@ -33,7 +42,7 @@ impl Display for Pr {
     ///
     /// macro_rules! terminal {
     ///     ($term:literal) => {Symbol::T(Terminal::Trm($term.to_string(), TerminalKind::Legacy,
-    ///         vec![0], SymbolAttribute::None, None))};
+    ///         vec![0], SymbolAttribute::None, None, None, None))};
     /// }
     ///
     /// let pr = Pr::new("S", vec![]);
@@ -72,6 +81,8 @@ impl Default for Pr {
             Symbol::n(""),
             Rhs::default(),
             ProductionAttribute::default(),
+            None,
+            None,
         )
     }
 }
@@ -82,7 +93,7 @@ impl Pr {
         if !r.iter().all(Self::is_allowed_symbol) {
             panic!("Unexpected symbol kind!");
         }
-        Self(Symbol::n(n), r, ProductionAttribute::default())
+        Self(Symbol::n(n), r, ProductionAttribute::default(), None, None)
     }
 
     pub(crate) fn with_attribute(mut self, attribute: ProductionAttribute) -> Self {
@@ -90,6 +101,18 @@ impl Pr {
         self
     }
 
+    /// Sets the precedence symbol given by a `%prec` directive
+    pub fn with_precedence(mut self, precedence: Option<PrecedenceSymbol>) -> Self {
+        self.3 = precedence;
+        self
+    }
+
+    /// Sets the label given by `#Label`
+    pub fn with_label(mut self, label: Option<String>) -> Self {
+        self.4 = label;
+        self
+    }
+
     /// Returns a clone of the non-terminal
     pub fn get_n(&self) -> String {
         self.0.get_n().unwrap()
@@ -112,7 +135,7 @@ impl Pr {
 
     /// Sets the non-terminal
     pub fn set_n(&mut self, n: String) {
-        self.0 = Symbol::N(n, SymbolAttribute::default(), None);
+        self.0 = Symbol::N(n, SymbolAttribute::default(), None, None);
     }
 
     /// Checks if [Rhs] is empty
@@ -145,6 +168,16 @@ impl Pr {
         self.2
     }
 
+    /// Returns the precedence symbol given by a `%prec` directive
+    pub fn get_precedence(&self) -> Option<&PrecedenceSymbol> {
+        self.3.as_ref()
+    }
+
+    /// Returns the label given by `#Label`
+    pub fn get_label(&self) -> Option<&str> {
+        self.4.as_deref()
+    }
+
     /// Formats self with the help of a scanner state resolver
     pub fn format<R, S>(&self, scanner_state_resolver: &R, user_type_resolver: &S) -> Result<String>
     where
@@ -155,8 +188,16 @@ impl Pr {
         self.2
             .decorate(&mut s, &self.0)
             .map_err(|e| anyhow!("Decorate error!: {}", e))?;
+        let precedence = self
+            .3
+            .as_ref()
+            .map_or(String::new(), |p| format!(" %prec {}", p));
+        let label = self
+            .4
+            .as_ref()
+            .map_or(String::new(), |l| format!(" #{}", l));
         Ok(format!(
-            "{}: {};",
+            "{}: {}{}{};",
             s,
             self.1
                 .iter()
@@ -167,7 +208,9 @@ impl Pr {
                             acc
                         })
                 })
-                .map(|v| v.join(" "))?
+                .map(|v| v.join(" "))?,
+            precedence,
+            label
         ))
     }
 }
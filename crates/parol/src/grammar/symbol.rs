@@ -7,6 +7,9 @@ use parol_runtime::parser::ScannerIndex;
 use std::convert::TryFrom;
 use std::fmt::{Debug, Display, Error, Formatter, Write};
 
+/// The name of the error token that LALR(1) grammars use in resynchronization rules
+pub const ERROR_TOKEN_NAME: &str = "error";
+
 // ---------------------------------------------------
 // Part of the Public API
 // *Changes will affect crate's version according to semver*
@@ -22,18 +25,73 @@ pub enum TerminalKind {
     Regex,
     /// Meta characters will be escaped when regex for scanner is created
     Raw,
+    /// Like [TerminalKind::Legacy] but matches case insensitively, e.g. `"select"i`
+    LegacyCaseInsensitive,
+    /// Like [TerminalKind::Regex] but matches case insensitively, e.g. `/select/i`
+    RegexCaseInsensitive,
+    /// Like [TerminalKind::Raw] but matches case insensitively, e.g. `'select'i`
+    RawCaseInsensitive,
 }
 
 impl TerminalKind {
     /// Retrieves the syntactic delimiter character
     pub fn delimiter(&self) -> char {
         match self {
-            TerminalKind::Legacy => '"',
-            TerminalKind::Regex => '/',
-            TerminalKind::Raw => '\'',
+            TerminalKind::Legacy | TerminalKind::LegacyCaseInsensitive => '"',
+            TerminalKind::Regex | TerminalKind::RegexCaseInsensitive => '/',
+            TerminalKind::Raw | TerminalKind::RawCaseInsensitive => '\'',
+        }
+    }
+
+    /// Retrieves the flag that follows the closing delimiter, i.e. `i` for case insensitive
+    /// terminals and an empty string otherwise
+    pub fn suffix(&self) -> &'static str {
+        if self.is_case_insensitive() {
+            "i"
+        } else {
+            ""
+        }
+    }
+
+    /// Checks if the terminal matches case insensitively
+    pub fn is_case_insensitive(&self) -> bool {
+        matches!(
+            self,
+            TerminalKind::LegacyCaseInsensitive
+                | TerminalKind::RegexCaseInsensitive
+                | TerminalKind::RawCaseInsensitive
+        )
+    }
+
+    /// Returns the case insensitive counterpart of this kind
+    /// ```
+    /// use parol::TerminalKind;
+    ///
+    /// assert_eq!(TerminalKind::RawCaseInsensitive, TerminalKind::Raw.case_insensitive());
+    /// assert_eq!(
+    ///     TerminalKind::RawCaseInsensitive,
+    ///     TerminalKind::RawCaseInsensitive.case_insensitive());
+    /// ```
+    ///
+    pub fn case_insensitive(&self) -> Self {
+        match self {
+            TerminalKind::Legacy | TerminalKind::LegacyCaseInsensitive => {
+                TerminalKind::LegacyCaseInsensitive
+            }
+            TerminalKind::Regex | TerminalKind::RegexCaseInsensitive => {
+                TerminalKind::RegexCaseInsensitive
+            }
+            TerminalKind::Raw | TerminalKind::RawCaseInsensitive => {
+                TerminalKind::RawCaseInsensitive
+            }
         }
     }
 
+    // Raw strings are escaped during expansion
+    fn is_raw(&self) -> bool {
+        matches!(self, TerminalKind::Raw | TerminalKind::RawCaseInsensitive)
+    }
+
     /// Behavioral equivalence
     /// ```
     /// use parol::TerminalKind;
@@ -47,19 +105,13 @@ impl TerminalKind {
     /// assert!(TerminalKind::Raw.behaves_like(TerminalKind::Raw));
     /// assert!(!TerminalKind::Raw.behaves_like(TerminalKind::Regex));
     /// assert!(!TerminalKind::Raw.behaves_like(TerminalKind::Legacy));
+    /// assert!(TerminalKind::RegexCaseInsensitive.behaves_like(TerminalKind::LegacyCaseInsensitive));
+    /// assert!(!TerminalKind::RawCaseInsensitive.behaves_like(TerminalKind::Raw));
+    /// assert!(!TerminalKind::Regex.behaves_like(TerminalKind::RegexCaseInsensitive));
     /// ```
     ///
     pub fn behaves_like(&self, other: TerminalKind) -> bool {
-        match self {
-            TerminalKind::Legacy | TerminalKind::Regex => match other {
-                TerminalKind::Legacy | TerminalKind::Regex => true,
-                TerminalKind::Raw => false,
-            },
-            TerminalKind::Raw => match other {
-                TerminalKind::Legacy | TerminalKind::Regex => false,
-                TerminalKind::Raw => true,
-            },
-        }
+        self.is_raw() == other.is_raw() && self.is_case_insensitive() == other.is_case_insensitive()
     }
 
     /// Equivalence regarding expansion result
@@ -78,6 +130,9 @@ impl TerminalKind {
     /// assert!(!TerminalKind::expands_like(
     ///     r"\{", TerminalKind::Raw,
     ///     r"\{", TerminalKind::Legacy));
+    /// assert!(TerminalKind::expands_like(
+    ///     "a+", TerminalKind::RawCaseInsensitive,
+    ///     r"(?i:a\+)", TerminalKind::Regex));
     /// ```
     ///
     pub fn expands_like(
@@ -92,14 +147,97 @@ impl TerminalKind {
     /// The actual preparation for scanner regex generation
     /// * Raw strings and legacy strings are not specially treaded
     /// * Regex strings are escaped using regex::escape
+    /// * Case insensitive terminals are wrapped in a group with the `i` flag set
     pub fn expand(&self, term: &str) -> String {
-        match self {
-            crate::TerminalKind::Legacy | crate::TerminalKind::Regex => term.to_string(),
-            crate::TerminalKind::Raw => regex::escape(term),
+        let expanded = if self.is_raw() {
+            regex::escape(term)
+        } else {
+            term.to_string()
+        };
+        if self.is_case_insensitive() {
+            format!("(?i:{expanded})")
+        } else {
+            expanded
+        }
+    }
+
+    /// The preparation for scanner regex generation of a terminal with an optional trailing
+    /// context. The scanner matches the terminal together with its trailing context.
+    /// ```
+    /// use parol::{TerminalKind, TrailingContext};
+    ///
+    /// assert_eq!("a+", TerminalKind::Regex.expand_with_context("a+", None));
+    /// assert_eq!(
+    ///     r"(?:[0-9]+)(?:\.\.)",
+    ///     TerminalKind::Regex.expand_with_context(
+    ///         "[0-9]+",
+    ///         Some(&TrailingContext::new("..", TerminalKind::Raw))));
+    /// ```
+    ///
+    pub fn expand_with_context(&self, term: &str, context: Option<&TrailingContext>) -> String {
+        match context {
+            Some(context) => format!("(?:{})(?:{})", self.expand(term), context.expand()),
+            None => self.expand(term),
         }
     }
 }
 
+// ---------------------------------------------------
+// Part of the Public API
+// *Changes will affect crate's version according to semver*
+// ---------------------------------------------------
+///
+/// The trailing context of a terminal, i.e. the text that must follow the terminal in the input
+/// but that is not consumed by it, e.g. `/\.\./` in `/[0-9]+/ ?= /\.\./`
+///
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct TrailingContext {
+    /// The text of the trailing context
+    pub text: String,
+    /// The interpretation of the text regarding regular expression meta characters
+    pub kind: TerminalKind,
+}
+
+impl TrailingContext {
+    /// Creates a trailing context
+    pub fn new(text: &str, kind: TerminalKind) -> Self {
+        Self {
+            text: text.to_owned(),
+            kind,
+        }
+    }
+
+    /// The preparation for scanner regex generation, see [TerminalKind::expand]
+    pub fn expand(&self) -> String {
+        self.kind.expand(&self.text)
+    }
+
+    /// Behavioral equivalence of optional trailing contexts, see [TerminalKind::behaves_like]
+    pub fn behaves_like(this: Option<&Self>, other: Option<&Self>) -> bool {
+        match (this, other) {
+            (Some(this), Some(other)) => {
+                this.text == other.text && this.kind.behaves_like(other.kind)
+            }
+            (None, None) => true,
+            _ => false,
+        }
+    }
+}
+
+impl Display for TrailingContext {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::result::Result<(), Error> {
+        let delimiter = self.kind.delimiter();
+        write!(
+            f,
+            "?= {}{}{}{}",
+            delimiter,
+            self.text,
+            delimiter,
+            self.kind.suffix()
+        )
+    }
+}
+
 // ---------------------------------------------------
 // Part of the Public API
 // *Changes will affect crate's version according to semver*
@@ -113,12 +251,17 @@ pub enum Terminal {
     /// A physical terminal symbol with the scanner states it belongs to
     /// Entities that are provided by the lexer.
     ///
+    /// The optional trailing context must follow the terminal in the input but isn't part of it.
+    /// The optional label given by `label=` names the member generated for the terminal.
+    ///
     Trm(
         String,
         TerminalKind,
         Vec<usize>,
         SymbolAttribute,
         Option<UserDefinedTypeName>,
+        Option<TrailingContext>,
+        Option<String>,
     ),
 
     ///
@@ -133,12 +276,20 @@ pub enum Terminal {
     /// End of input symbol, End of grammar symbol (not belonging to any grammar)
     ///
     End,
+
+    ///
+    /// The error token of LALR(1) grammars, written `error` in the grammar description.
+    /// The LR parser shifts it when it recovers from a syntax error.
+    ///
+    /// The optional label given by `label=` names the member generated for the error token.
+    ///
+    Error(SymbolAttribute, Option<UserDefinedTypeName>, Option<String>),
 }
 
 impl Terminal {
     /// Creates a terminal
     pub fn t(t: &str, s: Vec<usize>, a: SymbolAttribute) -> Self {
-        Self::Trm(t.to_owned(), TerminalKind::Legacy, s, a, None)
+        Self::Trm(t.to_owned(), TerminalKind::Legacy, s, a, None, None, None)
     }
     /// Checks if self is a terminal
     pub fn is_trm(&self) -> bool {
@@ -152,14 +303,25 @@ impl Terminal {
     pub fn is_end(&self) -> bool {
         matches!(self, Self::End)
     }
+    /// Checks if self is the error token
+    pub fn is_error(&self) -> bool {
+        matches!(self, Self::Error(..))
+    }
 
     /// Creates a terminal from a [Symbol]
     pub fn create(s: &Symbol) -> Self {
         match s {
-            Symbol::T(Terminal::Trm(t, k, s, a, u)) => {
-                Terminal::Trm(t.to_string(), *k, s.to_vec(), *a, u.clone())
-            }
+            Symbol::T(Terminal::Trm(t, k, s, a, u, c, l)) => Terminal::Trm(
+                t.to_string(),
+                *k,
+                s.to_vec(),
+                *a,
+                u.clone(),
+                c.clone(),
+                l.clone(),
+            ),
             Symbol::T(Terminal::End) => Terminal::End,
+            Symbol::T(Terminal::Error(a, u, l)) => Terminal::Error(*a, u.clone(), l.clone()),
             _ => panic!("Unexpected symbol type: {:?}", s),
         }
     }
@@ -167,7 +329,7 @@ impl Terminal {
     /// Adds a scanner index
     pub fn add_scanner(&mut self, sc: usize) {
         match self {
-            Terminal::Trm(_, _, s, _, _) => {
+            Terminal::Trm(_, _, s, ..) => {
                 if !s.contains(&sc) {
                     s.push(sc);
                     s.sort_unstable();
@@ -186,10 +348,14 @@ impl Terminal {
         S: Fn(&str) -> Option<String>,
     {
         match self {
-            Self::Trm(t, k, s, a, u) => {
+            Self::Trm(t, k, s, a, u, c, l) => {
                 let mut d = String::new();
                 let delimiter = k.delimiter();
-                a.decorate(&mut d, &format!("{}{}{}", delimiter, t, delimiter))
+                let mut t = format!("{}{}{}{}", delimiter, t, delimiter, k.suffix());
+                if let Some(c) = c {
+                    write!(t, " {}", c).map_err(|e| anyhow!(e))?;
+                }
+                a.decorate(&mut d, &t)
                     .map_err(|e| anyhow!("Decorate error!: {}", e))?;
                 if let Some(ref user_type) = u {
                     let user_type =
@@ -200,15 +366,35 @@ impl Terminal {
                         };
                     write!(d, " : {}", user_type).map_err(|e| anyhow!(e))?;
                 }
-                if *s == vec![0] {
+                if *s != vec![0] {
                     // Don't print state if terminal is only in state INITIAL (0)
-                    Ok(d)
-                } else {
-                    Ok(format!("<{}>{}", scanner_state_resolver(s), d))
+                    d = format!("<{}>{}", scanner_state_resolver(s), d);
+                }
+                if let Some(l) = l {
+                    d = format!("{}={}", l, d);
                 }
+                Ok(d)
             }
             Self::Eps => Ok("\u{03B5}".to_string()), // Lower creek letter Epsilon (ε)
             Self::End => Ok("$".to_string()),
+            Self::Error(a, u, l) => {
+                let mut d = String::new();
+                a.decorate(&mut d, &ERROR_TOKEN_NAME)
+                    .map_err(|e| anyhow!("Decorate error!: {}", e))?;
+                if let Some(ref user_type) = u {
+                    let user_type =
+                        if let Some(alias) = user_type_resolver(user_type.to_string().as_str()) {
+                            alias
+                        } else {
+                            user_type.to_string()
+                        };
+                    write!(d, " : {}", user_type).map_err(|e| anyhow!(e))?;
+                }
+                if let Some(l) = l {
+                    d = format!("{}={}", l, d);
+                }
+                Ok(d)
+            }
         }
     }
 }
@@ -216,12 +402,17 @@ impl Terminal {
 impl Display for Terminal {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::result::Result<(), Error> {
         match self {
-            Self::Trm(t, k, ..) => {
+            Self::Trm(t, k, _, _, _, c, _) => {
                 let delimiter = k.delimiter();
-                write!(f, "{}{}{}", delimiter, t, delimiter)
+                write!(f, "{}{}{}{}", delimiter, t, delimiter, k.suffix())?;
+                if let Some(c) = c {
+                    write!(f, " {}", c)?;
+                }
+                Ok(())
             }
             Self::Eps => write!(f, "\u{03B5}"), // Lower creek letter Epsilon (ε)
             Self::End => write!(f, "$"),
+            Self::Error(..) => write!(f, "{}", ERROR_TOKEN_NAME),
         }
     }
 }
@@ -264,8 +455,14 @@ impl TerminalMappings<Terminal> for Terminal {
 pub enum Symbol {
     ///
     /// Non-terminal symbol, Meta symbol of the grammar.
+    /// The optional label given by `label=` names the member generated for the non-terminal.
     ///
-    N(String, SymbolAttribute, Option<UserDefinedTypeName>),
+    N(
+        String,
+        SymbolAttribute,
+        Option<UserDefinedTypeName>,
+        Option<String>,
+    ),
 
     ///
     /// Terminal symbol of the grammar.
@@ -293,7 +490,7 @@ pub enum Symbol {
 impl Symbol {
     /// Creates a non-terminal symbol
     pub fn n(n: &str) -> Self {
-        Self::N(n.to_owned(), SymbolAttribute::default(), None)
+        Self::N(n.to_owned(), SymbolAttribute::default(), None, None)
     }
     /// Creates a end-of-input terminal symbol
     pub fn e() -> Self {
@@ -355,7 +552,9 @@ impl Symbol {
     /// Get the symbol attribute or a default value
     pub fn attribute(&self) -> SymbolAttribute {
         match self {
-            Symbol::N(_, a, _) | Symbol::T(Terminal::Trm(_, _, _, a, _)) => *a,
+            Symbol::N(_, a, ..)
+            | Symbol::T(Terminal::Trm(_, _, _, a, ..))
+            | Symbol::T(Terminal::Error(a, ..)) => *a,
             _ => SymbolAttribute::None,
         }
     }
@@ -367,8 +566,11 @@ impl Symbol {
         S: Fn(&str) -> Option<String>,
     {
         match self {
-            Self::N(n, a, u) => {
+            Self::N(n, a, u, l) => {
                 let mut s = String::new();
+                if let Some(l) = l {
+                    write!(s, "{}=", l).map_err(|e| anyhow!("IO error!: {}", e))?;
+                }
                 a.decorate(&mut s, n)
                     .map_err(|e| anyhow!("Decorate error!: {}", e))?;
                 if let Some(ref user_type) = u {
@@ -399,7 +601,7 @@ impl Symbol {
 impl Display for Symbol {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::result::Result<(), Error> {
         match self {
-            Self::N(n, a, u) => {
+            Self::N(n, a, u, _) => {
                 let mut s = String::new();
                 a.decorate(&mut s, n)?;
                 if let Some(ref user_type) = u {
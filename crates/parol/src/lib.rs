@@ -26,7 +26,10 @@ pub use config::{CommonGeneratorConfig, ParserGeneratorConfig, UserTraitGenerato
 #[forbid(missing_docs)]
 pub mod grammar;
 
-pub use grammar::{Cfg, Pos, Pr, Rhs, Symbol, SymbolAttribute, Terminal, TerminalKind};
+pub use grammar::{
+    Associativity, Cfg, Pos, Pr, PrecedenceLevel, PrecedenceSymbol, Rhs, Symbol, SymbolAttribute,
+    Terminal, TerminalKind, TrailingContext,
+};
 
 ///
 /// Module with functionalities for grammar analysis
@@ -35,9 +38,11 @@ pub use grammar::{Cfg, Pos, Pr, Rhs, Symbol, SymbolAttribute, Terminal, Terminal
 pub mod analysis;
 
 pub use analysis::{
-    calculate_lalr1_parse_table, calculate_lookahead_dfas, detect_left_recursive_non_terminals,
+    calculate_adaptive_lookahead_dfas, calculate_glr_parse_table, calculate_lalr1_parse_table,
+    calculate_lookahead_dfas, calculate_lr1_parse_table, detect_left_recursive_non_terminals,
     CompiledTerminal, GrammarAnalysisError, KTuple, KTupleBuilder, KTuples, KTuplesBuilder,
-    LR1State, LRAction, LRParseTable, LookaheadDFA, RecursiveNonTerminal, RelatedHint,
+    LLKConflict, LR1State, LRAction, LRCounterexample, LRParseTable, LRResolvedConflict,
+    LookaheadDFA, RecursiveNonTerminal, RelatedHint,
 };
 
 ///
@@ -83,7 +88,7 @@ pub mod test_support;
 ///
 #[forbid(missing_docs)]
 pub mod transformation;
-pub use transformation::{augment_grammar, left_factor};
+pub use transformation::{augment_grammar, eliminate_left_recursion, left_factor};
 
 ///
 /// Module with utility functionalities
@@ -142,6 +142,38 @@ pub enum ParolParserError {
         location: Location,
     },
 
+    /// A symbol used in a %prec directive has no precedence declared with %left, %right or
+    /// %nonassoc.
+    #[error("{context} - Precedence symbol '{symbol}' is not declared")]
+    UndeclaredPrecedenceSymbol {
+        /// Context where the error was issued
+        context: String,
+        /// The undeclared precedence symbol
+        symbol: String,
+        /// Source file
+        input: PathBuf,
+        /// Location of the symbol
+        location: Location,
+    },
+
+    /// The same name is defined in different grammar files, i.e. in a grammar file and in a file
+    /// included by it.
+    #[error("{kind} '{name}' is defined in different grammar files")]
+    ConflictingDefinitions {
+        /// The kind of the conflicting definitions, e.g. non-terminal or scanner state
+        kind: String,
+        /// The name that is defined twice
+        name: String,
+        /// Source of the first definition
+        first_input: PathBuf,
+        /// Location of the first definition
+        first: Location,
+        /// Source of the second definition
+        second_input: PathBuf,
+        /// Location of the second definition
+        second: Location,
+    },
+
     /// Mixed scanner switching is not allowed - use either parser-based or scanner-based switching.
     /// Parser-based switching is done via the %sc, %push and %pop directives productions.
     /// Scanner-based switching is done via the %on directive in the header of the grammar file.
@@ -154,6 +186,122 @@ pub enum ParolParserError {
         /// Location of the token
         location: Location,
     },
+
+    /// Terminals that match the empty string would make the scanner produce empty tokens without
+    /// making progress in the input.
+    #[error("{context} - Terminal '{terminal}' matches the empty string")]
+    EmptyMatchingTerminal {
+        /// Context where the error was issued
+        context: String,
+        /// The terminal that matches the empty string
+        terminal: String,
+        /// Source file
+        input: PathBuf,
+        /// Location of the terminal
+        location: Location,
+    },
+
+    /// Labels must be unique, e.g. the labels of the alternatives of a production.
+    #[error("{kind} '{label}' is used more than once")]
+    DuplicateLabel {
+        /// The kind of the label, e.g. alternative label
+        kind: String,
+        /// The label that is used twice
+        label: String,
+        /// Source file
+        input: PathBuf,
+        /// Location of the first usage
+        first: Location,
+        /// Location of the second usage
+        second: Location,
+    },
+
+    /// Keywords declared with %keywords must be primary non-terminals for terminals.
+    #[error(
+        "{context} - Invalid keyword '{keyword}'. Use a primary non-terminal for the keyword."
+    )]
+    InvalidKeyword {
+        /// Context where the error was issued
+        context: String,
+        /// The name that is not a primary non-terminal
+        keyword: String,
+        /// Source file
+        input: PathBuf,
+        /// Location of the keyword
+        location: Location,
+    },
+
+    /// The tokens named in %offside directives must be primary non-terminals for terminals.
+    #[error(
+        "{context} - Invalid token '{token}' in %offside directive. Use a primary non-terminal for the token."
+    )]
+    InvalidOffsideToken {
+        /// Context where the error was issued
+        context: String,
+        /// The name that is not a primary non-terminal
+        token: String,
+        /// Source file
+        input: PathBuf,
+        /// Location of the token
+        location: Location,
+    },
+
+    /// A non-terminal that is referenced in a declaration, e.g. in a %derive directive, has no
+    /// productions.
+    #[error("{context} - Non-terminal '{name}' is not defined")]
+    UnknownNonTerminal {
+        /// Context where the error was issued
+        context: String,
+        /// The name of the non-terminal
+        name: String,
+        /// Source file
+        input: PathBuf,
+        /// Location of the name
+        location: Location,
+    },
+
+    /// Non-terminals declared with %inline must have exactly one alternative.
+    #[error(
+        "{context} - Non-terminal '{name}' can't be inlined. Only non-terminals with a single alternative can be inlined."
+    )]
+    InvalidInlineNonTerminal {
+        /// Context where the error was issued
+        context: String,
+        /// The name of the non-terminal
+        name: String,
+        /// Source file
+        input: PathBuf,
+        /// Location of the name
+        location: Location,
+    },
+
+    /// The terminals named in %display declarations must be given by primary non-terminals.
+    #[error(
+        "{context} - Invalid token '{token}' in %display declaration. Use a primary non-terminal for the token."
+    )]
+    InvalidDisplayNameTarget {
+        /// Context where the error was issued
+        context: String,
+        /// The name that is not a primary non-terminal
+        token: String,
+        /// Source file
+        input: PathBuf,
+        /// Location of the token
+        location: Location,
+    },
+
+    /// In LALR(1) grammars `error` denotes the error token and can't be defined by productions.
+    #[error(
+        "{context} - The error token 'error' can't be used as the left-hand side of a production in LALR(1) grammars"
+    )]
+    InvalidErrorTokenDefinition {
+        /// Context where the error was issued
+        context: String,
+        /// Source file
+        input: PathBuf,
+        /// Location of the production's name
+        location: Location,
+    },
 }
 
 impl From<ParolParserError> for parol_runtime::ParolError {
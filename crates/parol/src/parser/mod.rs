@@ -12,6 +12,7 @@ pub use errors::ParolParserError;
 pub mod parol_grammar;
 pub use parol_grammar::{
     Alternation, Alternations, Factor, GrammarType, ParolGrammar, ParolGrammarItem, Production,
+    Template,
 };
 
 pub mod parol_grammar_trait;
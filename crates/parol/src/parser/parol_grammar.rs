@@ -1,10 +1,16 @@
 use super::parol_grammar_trait::{
-    self, AlternationList, Declaration, GrammarDefinition, Parol, ParolGrammarTrait, Prolog,
-    PrologList, PrologList0, ScannerDirectives,
+    self, Declaration, DeclarationPercentIncludeString, GrammarDefinition, IdentifierList, Parol,
+    ParolGrammarTrait, Prolog, PrologList, PrologList0, ScannerDirectives,
+    ScannerDirectivesPercentOffsideIdentifierIdentifierIdentifier,
     ScannerDirectivesPercentOnIdentifierListPercentEnterIdentifier, ScannerSwitch,
-    StartDeclaration, TokenLiteral,
+    StartDeclaration, TokenExpression, TokenLiteral,
+};
+use super::parol_parser::parse;
+use crate::analysis::matches_empty_string;
+use crate::grammar::{
+    Associativity, Decorate, PrecedenceLevel, PrecedenceSymbol, ProductionAttribute,
+    SymbolAttribute, TerminalKind, TrailingContext, ERROR_TOKEN_NAME,
 };
-use crate::grammar::{Decorate, ProductionAttribute, SymbolAttribute, TerminalKind};
 use crate::ParolParserError;
 use anyhow::anyhow;
 
@@ -15,9 +21,11 @@ use parol_runtime::{lexer::Token, once_cell::sync::Lazy, Result};
 use serde::{Deserialize, Serialize};
 use ts_rs::TS;
 
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 use std::fmt::{Debug, Display, Error, Formatter, Write};
+use std::fs;
 use std::marker::PhantomData;
+use std::path::{Path, PathBuf};
 
 /// Used for implementation of trait `Default` for `&ParolGrammar`.
 static DEFAULT_PAROL_GRAMMAR: Lazy<ParolGrammar<'static>> = Lazy::new(ParolGrammar::default);
@@ -105,10 +113,12 @@ pub enum Factor {
     Group(Alternations),
     /// A Repetition
     Repeat(Alternations),
+    /// A Repetition that matches at least once, optionally with a separator between the elements
+    NonEmptyRepeat(Alternations, Option<Box<Factor>>),
     /// An Optional
     Optional(Alternations),
-    /// A terminal string with associated scanner states, a symbol attribute an an optional user
-    /// type name
+    /// A terminal string with associated scanner states, a symbol attribute, an optional user
+    /// type name and an optional member label
     Terminal(
         /// The scanned text
         String,
@@ -120,9 +130,28 @@ pub enum Factor {
         SymbolAttribute,
         /// A possibly provided user destination type
         Option<UserDefinedTypeName>,
+        /// The text that must follow the terminal without being part of it
+        Option<TrailingContext>,
+        /// A possibly provided label that names the generated member
+        Option<String>,
+    ),
+    /// A non-terminal with a symbol attribute, an optional user type name and an optional member
+    /// label
+    NonTerminal(
+        String,
+        SymbolAttribute,
+        Option<UserDefinedTypeName>,
+        Option<String>,
+    ),
+    /// The instantiation of a template with its arguments, a symbol attribute, an optional
+    /// user type name and an optional member label
+    TemplateInstance(
+        String,
+        Vec<Factor>,
+        SymbolAttribute,
+        Option<UserDefinedTypeName>,
+        Option<String>,
     ),
-    /// A non-terminal with a symbol attribute an an optional user type name
-    NonTerminal(String, SymbolAttribute, Option<UserDefinedTypeName>),
     /// An identifier, scanner state name
     Identifier(String),
     /// A scanner switch instruction
@@ -135,12 +164,15 @@ pub enum Factor {
 
 impl Factor {
     pub(crate) fn default_non_terminal(non_terminal: String) -> Self {
-        Self::NonTerminal(non_terminal, SymbolAttribute::default(), None)
+        Self::NonTerminal(non_terminal, SymbolAttribute::default(), None, None)
     }
 
     pub(crate) fn inner_alts_mut(&mut self) -> Result<&mut Alternations> {
         match self {
-            Factor::Group(alts) | Factor::Repeat(alts) | Factor::Optional(alts) => Ok(alts),
+            Factor::Group(alts)
+            | Factor::Repeat(alts)
+            | Factor::NonEmptyRepeat(alts, _)
+            | Factor::Optional(alts) => Ok(alts),
             _ => Err(parol_runtime::ParolError::UserError(anyhow!(
                 "Ain't no inner alternations"
             ))),
@@ -152,8 +184,12 @@ impl Factor {
         match self {
             Self::Group(g) => format!("({})", g.to_par()),
             Self::Repeat(r) => format!("{{{}}}", r.to_par()),
+            Self::NonEmptyRepeat(r, s) => match s {
+                Some(s) => format!("{{{} % {}}}", r.to_par(), s.to_par()),
+                None => format!("{{{}}}+", r.to_par()),
+            },
             Self::Optional(o) => format!("[{}]", o.to_par()),
-            Self::Terminal(t, k, s, a, u) => {
+            Self::Terminal(t, k, s, a, u, c, l) => {
                 let mut d = String::new();
                 a.decorate(&mut d, &format!("T({})", t))
                     .expect("Failed to decorate terminal!");
@@ -161,19 +197,30 @@ impl Factor {
                     let _ = write!(d, " /* : {} */", user_type);
                 }
                 let delimiter = k.delimiter();
-                format!(
-                    "<{}>{}{}{}",
+                let mut par = format!(
+                    "<{}>{}{}{}{}",
                     s.iter()
                         .map(|s| format!("{}", s))
                         .collect::<Vec<String>>()
                         .join(", "),
                     delimiter,
                     d,
-                    delimiter
-                )
+                    delimiter,
+                    k.suffix()
+                );
+                if let Some(c) = c {
+                    let _ = write!(par, " {}", c);
+                }
+                if let Some(l) = l {
+                    par.insert_str(0, &format!("{}=", l));
+                }
+                par
             }
-            Self::NonTerminal(n, a, u) => {
+            Self::NonTerminal(n, a, u, l) => {
                 let mut buf = String::new();
+                if let Some(l) = l {
+                    let _ = write!(buf, "{}=", l);
+                }
                 a.decorate(&mut buf, n)
                     .expect("Failed to decorate non-terminal!");
                 if let Some(ref user_type) = u {
@@ -181,6 +228,28 @@ impl Factor {
                 }
                 buf
             }
+            Self::TemplateInstance(n, args, a, u, l) => {
+                let mut buf = String::new();
+                if let Some(l) = l {
+                    let _ = write!(buf, "{}=", l);
+                }
+                a.decorate(
+                    &mut buf,
+                    &format!(
+                        "{}<{}>",
+                        n,
+                        args.iter()
+                            .map(|f| f.to_par())
+                            .collect::<Vec<String>>()
+                            .join(", ")
+                    ),
+                )
+                .expect("Failed to decorate template instance!");
+                if let Some(ref user_type) = u {
+                    let _ = write!(buf, " /* : {} */", user_type);
+                }
+                buf
+            }
             Factor::Identifier(i) => format!("\"{}\"", i),
             Self::ScannerSwitch(n, _) => format!("%sc({})", n),
             Self::ScannerSwitchPush(n, _) => format!("%push({})", n),
@@ -190,10 +259,17 @@ impl Factor {
 
     fn is_used_scanner(&self, scanner_index: usize) -> bool {
         match self {
-            Factor::Terminal(_, _, s, _, _) => s.contains(&scanner_index),
+            Factor::Terminal(_, _, s, ..) => s.contains(&scanner_index),
             Factor::Group(a) | Factor::Repeat(a) | Factor::Optional(a) => {
                 a.is_used_scanner(scanner_index)
             }
+            Factor::NonEmptyRepeat(a, s) => {
+                a.is_used_scanner(scanner_index)
+                    || s.as_ref().is_some_and(|s| s.is_used_scanner(scanner_index))
+            }
+            Factor::TemplateInstance(_, args, ..) => {
+                args.iter().any(|f| f.is_used_scanner(scanner_index))
+            }
             _ => false,
         }
     }
@@ -204,11 +280,22 @@ impl Display for Factor {
         match self {
             Self::Group(g) => write!(f, "G({})", g),
             Self::Repeat(r) => write!(f, "R{{{}}}", r),
+            Self::NonEmptyRepeat(r, s) => match s {
+                Some(s) => write!(f, "R{{{} % {}}}", r, s),
+                None => write!(f, "R{{{}}}+", r),
+            },
             Self::Optional(o) => write!(f, "O[{}]", o),
-            Self::Terminal(t, k, s, a, u) => {
+            Self::Terminal(t, k, s, a, u, c, l) => {
                 let mut d = String::new();
+                if let Some(l) = l {
+                    write!(f, "{}=", l)?;
+                }
                 let delimiter = k.delimiter();
-                a.decorate(&mut d, &format!("T({}{}{})", delimiter, t, delimiter))?;
+                let mut t = format!("T({}{}{}{})", delimiter, t, delimiter, k.suffix());
+                if let Some(c) = c {
+                    write!(t, " {}", c)?;
+                }
+                a.decorate(&mut d, &t)?;
                 if let Some(ref user_type) = u {
                     write!(d, " : {}", user_type)?;
                 }
@@ -222,14 +309,38 @@ impl Display for Factor {
                     d
                 )
             }
-            Self::NonTerminal(n, a, u) => {
+            Self::NonTerminal(n, a, u, l) => {
                 let mut s = String::new();
+                if let Some(l) = l {
+                    write!(s, "{}=", l)?;
+                }
                 a.decorate(&mut s, &format!("N({})", n))?;
                 if let Some(ref user_type) = u {
                     write!(s, " : {}", user_type)?;
                 }
                 write!(f, "{}", s)
             }
+            Self::TemplateInstance(n, args, a, u, l) => {
+                let mut s = String::new();
+                if let Some(l) = l {
+                    write!(s, "{}=", l)?;
+                }
+                a.decorate(
+                    &mut s,
+                    &format!(
+                        "I({}<{}>)",
+                        n,
+                        args.iter()
+                            .map(|f| format!("{}", f))
+                            .collect::<Vec<String>>()
+                            .join(", ")
+                    ),
+                )?;
+                if let Some(ref user_type) = u {
+                    write!(s, " : {}", user_type)?;
+                }
+                write!(f, "{}", s)
+            }
             Self::Identifier(n) => write!(f, "Id({})", n),
             Self::ScannerSwitch(n, _) => write!(f, "S({})", n),
             Self::ScannerSwitchPush(n, _) => write!(f, "Push({})", n),
@@ -241,13 +352,20 @@ impl Display for Factor {
 ///
 /// An Alternation is a sequence of factors.
 /// Valid operation on Alternation is "|".
+/// The optional precedence symbol is given by a `%prec` directive at the end of the alternation.
+/// The optional label given by `#Label` names the type generated for the alternation.
 ///
 #[derive(Debug, Clone, Eq, PartialEq)]
-pub struct Alternation(pub Vec<Factor>, pub ProductionAttribute);
+pub struct Alternation(
+    pub Vec<Factor>,
+    pub ProductionAttribute,
+    pub Option<PrecedenceSymbol>,
+    pub Option<String>,
+);
 
 impl Alternation {
     pub(crate) fn new() -> Self {
-        Self(Vec::new(), ProductionAttribute::default())
+        Self(Vec::new(), ProductionAttribute::default(), None, None)
     }
 
     pub(crate) fn with_factors(mut self, factors: Vec<Factor>) -> Self {
@@ -260,6 +378,16 @@ impl Alternation {
         self
     }
 
+    pub(crate) fn with_precedence(mut self, precedence: Option<PrecedenceSymbol>) -> Self {
+        self.2 = precedence;
+        self
+    }
+
+    pub(crate) fn with_label(mut self, label: Option<String>) -> Self {
+        self.3 = label;
+        self
+    }
+
     pub(crate) fn insert(&mut self, index: usize, fac: Factor) {
         self.0.insert(index, fac)
     }
@@ -270,11 +398,19 @@ impl Alternation {
 
     /// Generate parol's syntax
     pub fn to_par(&self) -> String {
-        self.0
+        let mut par = self
+            .0
             .iter()
             .map(|f| f.to_par())
             .collect::<Vec<String>>()
-            .join(" ")
+            .join(" ");
+        if let Some(ref precedence) = self.2 {
+            let _ = write!(par, " %prec {}", precedence);
+        }
+        if let Some(ref label) = self.3 {
+            let _ = write!(par, " #{}", label);
+        }
+        par
     }
 
     fn is_used_scanner(&self, scanner_index: usize) -> bool {
@@ -282,13 +418,13 @@ impl Alternation {
     }
 
     fn is_terminal(&self) -> bool {
-        self.0.len() == 1 && matches!(self.0[0], Factor::Terminal(_, _, _, _, _))
+        self.0.len() == 1 && matches!(self.0[0], Factor::Terminal(..))
     }
 
-    fn terminal(&self) -> Option<(&str, TerminalKind)> {
+    fn terminal(&self) -> Option<(&str, TerminalKind, Option<&TrailingContext>)> {
         if self.is_terminal() {
             match &self.0[0] {
-                Factor::Terminal(t, k, _, _, _) => Some((t, *k)),
+                Factor::Terminal(t, k, _, _, _, c, _) => Some((t, *k, c.as_ref())),
                 _ => None,
             }
         } else {
@@ -317,6 +453,12 @@ impl Display for Alternation {
                 .collect::<Vec<String>>()
                 .join(", ")
         )?;
+        if let Some(ref precedence) = self.2 {
+            write!(f, " %prec {}", precedence)?;
+        }
+        if let Some(ref label) = self.3 {
+            write!(f, " #{}", label)?;
+        }
         if self.1 != ProductionAttribute::default() {
             write!(f, ": {})", self.1)
         } else {
@@ -399,6 +541,48 @@ impl Display for Production {
     }
 }
 
+///
+/// [Template] is a production with parameters.
+/// It is instantiated with concrete arguments wherever it is used.
+///
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Template {
+    /// The template's name
+    pub name: String,
+    /// The template's parameters
+    pub params: Vec<String>,
+    /// Right-hand side, the parameters are referenced as non-terminals
+    pub rhs: Alternations,
+}
+
+impl Template {
+    fn has_switch(&self) -> Option<parol_runtime::Location> {
+        self.rhs.0.iter().find_map(|a| a.has_switch())
+    }
+
+    /// Generate parol's syntax
+    pub fn to_par(&self) -> String {
+        format!(
+            "{}<{}>: {};",
+            self.name,
+            self.params.join(", "),
+            self.rhs.to_par()
+        )
+    }
+}
+
+impl Display for Template {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::result::Result<(), Error> {
+        write!(
+            f,
+            "{}<{}>: {};",
+            self.name,
+            self.params.join(", "),
+            self.rhs
+        )
+    }
+}
+
 ///
 /// [ParolGrammarItem] is part of the structure of the grammar representation
 ///
@@ -463,10 +647,20 @@ pub struct ScannerConfig {
     pub line_comments: Vec<String>,
     /// Optional block comments
     pub block_comments: Vec<(String, String)>,
+    /// Optional block comments that can be nested
+    pub nested_block_comments: Vec<(String, String)>,
     /// Defines whether to handle newlines automatically in scanner
     pub auto_newline_off: bool,
     /// Defines whether to handle whitespace automatically in scanner
     pub auto_ws_off: bool,
+    /// Defines whether the scanner obeys the longest match strategy instead of taking the first
+    /// matching terminal
+    pub longest_match: bool,
+    /// Defines whether all terminals of the scanner state match case insensitively
+    pub case_insensitive: bool,
+    /// The tokens synthesized from the indentation of the lines in offside mode as
+    /// (indent, dedent, newline), each identified by its primary non-terminal name
+    pub offside: Option<(Token<'static>, Token<'static>, Token<'static>)>,
     /// Scanner state transitions
     /// Maps from (token, terminal kind) to scanner state, where the token is identified by its
     /// primary non-terminal name. The scanner state is identified by its name.
@@ -495,6 +689,17 @@ impl ScannerConfig {
                 );
             });
     }
+
+    pub(crate) fn set_offside(
+        &mut self,
+        offside: &ScannerDirectivesPercentOffsideIdentifierIdentifierIdentifier<'_>,
+    ) {
+        self.offside = Some((
+            offside.identifier.identifier.to_owned(),
+            offside.identifier0.identifier.to_owned(),
+            offside.identifier1.identifier.to_owned(),
+        ));
+    }
 }
 
 impl Display for ScannerConfig {
@@ -502,8 +707,30 @@ impl Display for ScannerConfig {
         write!(f, "name: {};", self.name)?;
         write!(f, "line_comments: {:?};", self.line_comments)?;
         write!(f, "block_comments: {:?};", self.block_comments)?;
+        if !self.nested_block_comments.is_empty() {
+            write!(
+                f,
+                "nested_block_comments: {:?};",
+                self.nested_block_comments
+            )?;
+        }
         write!(f, "auto_newline_off: {};", self.auto_newline_off)?;
         write!(f, "auto_ws_off: {};", self.auto_ws_off)?;
+        if self.longest_match {
+            write!(f, "longest_match: {};", self.longest_match)?;
+        }
+        if self.case_insensitive {
+            write!(f, "case_insensitive: {};", self.case_insensitive)?;
+        }
+        if let Some((indent, dedent, newline)) = &self.offside {
+            write!(
+                f,
+                "offside: {} {} {};",
+                indent.text(),
+                dedent.text(),
+                newline.text()
+            )?;
+        }
         self.transitions
             .iter()
             .try_for_each(|(k, v)| write!(f, "on {} enter {};", k, v))
@@ -516,8 +743,12 @@ impl Default for ScannerConfig {
             name: "INITIAL".to_owned(),
             line_comments: Vec::default(),
             block_comments: Vec::default(),
+            nested_block_comments: Vec::default(),
             auto_newline_off: false,
             auto_ws_off: false,
+            longest_match: false,
+            case_insensitive: false,
+            offside: None,
             transitions: BTreeMap::default(),
         }
     }
@@ -547,10 +778,23 @@ impl TryFrom<&parol_grammar_trait::ScannerState<'_>> for ScannerConfig {
                     ParolGrammar::expanded_token_literal(&block_comment.token_literal),
                     ParolGrammar::expanded_token_literal(&block_comment.token_literal0),
                 )),
+                ScannerDirectives::PercentNestedUnderscoreBlockUnderscoreCommentTokenLiteralTokenLiteral(
+                    block_comment,
+                ) => me.nested_block_comments.push((
+                    ParolGrammar::expanded_token_literal(&block_comment.token_literal),
+                    ParolGrammar::expanded_token_literal(&block_comment.token_literal0),
+                )),
                 ScannerDirectives::PercentAutoUnderscoreNewlineUnderscoreOff(_) => {
                     me.auto_newline_off = true
                 }
                 ScannerDirectives::PercentAutoUnderscoreWsUnderscoreOff(_) => me.auto_ws_off = true,
+                ScannerDirectives::PercentLongestUnderscoreMatch(_) => me.longest_match = true,
+                ScannerDirectives::PercentCaseUnderscoreInsensitive(_) => {
+                    me.case_insensitive = true
+                }
+                ScannerDirectives::PercentOffsideIdentifierIdentifierIdentifier(offside) => {
+                    me.set_offside(offside)
+                }
                 ScannerDirectives::PercentOnIdentifierListPercentEnterIdentifier(transitions) => {
                     me.add_transitions(transitions)
                 }
@@ -572,8 +816,62 @@ pub enum GrammarType {
     /// LLK grammar, default
     #[default]
     LLK,
-    /// LR(1) grammar, not yet supported
+    /// LALR(1) grammar
     LALR1,
+    /// LR(1) grammar, the parse table doesn't suffer from the merging of LALR(1) states
+    LR1,
+    /// LR(1) grammar with conflicts that are kept in the parse table and followed in parallel by
+    /// the GLR parser
+    GLR,
+}
+
+impl GrammarType {
+    /// Returns true if the grammar is parsed by a LR parser
+    pub fn is_lr(&self) -> bool {
+        matches!(
+            self,
+            GrammarType::LALR1 | GrammarType::LR1 | GrammarType::GLR
+        )
+    }
+}
+
+// A factor of the parse tree with the optional label of its symbol.
+// Symbols are resolved together with their neighbours because template instances and terminals
+// with scanner states consist of two symbols each.
+enum FactorItem<'a, 't> {
+    Symbol(Option<&'a Token<'t>>, &'a parol_grammar_trait::Symbol<'t>),
+    Other(&'a parol_grammar_trait::Factor<'t>),
+}
+
+impl<'a, 't> From<&'a parol_grammar_trait::Factor<'t>> for FactorItem<'a, 't> {
+    fn from(factor: &'a parol_grammar_trait::Factor<'t>) -> Self {
+        match factor {
+            parol_grammar_trait::Factor::Symbol(symbol) => Self::Symbol(None, &symbol.symbol),
+            parol_grammar_trait::Factor::FieldLabelSymbol(labeled) => Self::Symbol(
+                Some(&labeled.field_label.identifier.identifier),
+                &labeled.symbol,
+            ),
+            _ => Self::Other(factor),
+        }
+    }
+}
+
+/// The kinds of names whose definitions must not be spread over different grammar files
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum DefinitionKind {
+    NonTerminal,
+    ScannerState,
+    UserType,
+}
+
+impl Display for DefinitionKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::result::Result<(), Error> {
+        match self {
+            Self::NonTerminal => write!(f, "Non-terminal"),
+            Self::ScannerState => write!(f, "Scanner state"),
+            Self::UserType => write!(f, "User type"),
+        }
+    }
 }
 
 // ---------------------------------------------------
@@ -587,6 +885,8 @@ pub enum GrammarType {
 pub struct ParolGrammar<'t> {
     /// The parsed productions
     pub productions: Vec<Production>,
+    /// The parsed templates, i.e. productions with parameters
+    pub templates: Vec<Template>,
     /// The optional title of the grammar
     pub title: Option<String>,
     /// The optional comment of the grammar
@@ -599,9 +899,44 @@ pub struct ParolGrammar<'t> {
     pub user_type_definitions: BTreeMap<String, UserDefinedTypeName>,
     /// The grammar type
     pub grammar_type: GrammarType,
+    /// Precedence levels declared with %left, %right or %nonassoc, in ascending order of
+    /// precedence
+    pub precedence_levels: Vec<PrecedenceLevel>,
+    /// Keywords declared with %keywords, identified by the names of their primary non-terminals
+    pub keywords: Vec<Token<'static>>,
+    /// Derives declared with %derive that are added to all generated types
+    pub derives: Vec<UserDefinedTypeName>,
+    /// Derives declared with %derive for a single non-terminal, they replace the global derives
+    /// for the types generated for this non-terminal
+    pub non_terminal_derives: Vec<(Token<'static>, Vec<UserDefinedTypeName>)>,
+    /// Non-terminals declared with %inline whose members are spliced into the types of their
+    /// parents
+    pub inline_non_terminals: Vec<Token<'static>>,
+    /// Names of terminals shown in syntax errors declared with %display, the terminals are
+    /// identified by the names of their primary non-terminals
+    pub display_names: Vec<(Token<'static>, String)>,
+    /// The number of shift-reduce conflicts declared with %expect
+    pub expected_shift_reduce_conflicts: Option<usize>,
+    /// The number of reduce-reduce conflicts declared with %expect-rr
+    pub expected_reduce_reduce_conflicts: Option<usize>,
     /// Contains information about token aliases:
     /// (LHS identifier, Token literal, expanded text)
     token_aliases: Vec<(Token<'static>, String)>,
+    /// Tokens of the %left, %right and %nonassoc declarations and of the symbols of %prec
+    /// directives, precedences are only supported in LR grammars
+    precedence_directives: Vec<Token<'static>>,
+    /// Locations of the first definitions of names, used to detect conflicting definitions in
+    /// included files
+    definitions: BTreeMap<(DefinitionKind, String), Location>,
+    /// The canonical paths of all files processed so far, each file is included only once
+    included_files: BTreeSet<PathBuf>,
+    /// Productions of included files, they are appended after the productions of this file
+    included_productions: Vec<Production>,
+    /// True if this grammar is read from an included file
+    included: bool,
+    /// The names of all templates. They are collected before the productions are processed
+    /// because a template can be instantiated before it is defined.
+    template_names: BTreeSet<String>,
     // Just to hold the lifetime generated by parol
     phantom: PhantomData<&'t str>,
 }
@@ -617,16 +952,43 @@ impl ParolGrammar<'_> {
         }
     }
 
+    ///
+    /// Returns the canonical paths of the grammar files that were read because of `%include`
+    /// directives, together with the files that contain these directives
+    ///
+    pub fn included_files(&self) -> impl Iterator<Item = &Path> {
+        self.included_files.iter().map(|p| p.as_path())
+    }
+
     fn process_parol(&mut self, parol: &Parol<'_>) -> Result<()> {
         self.process_prolog(&parol.prolog)?;
         self.process_grammar_definition(&parol.grammar_definition)?;
-        self.check()
+        // The including file's productions come first, thus its start symbol and its terminals
+        // take precedence
+        self.productions.append(&mut self.included_productions);
+        if self.included {
+            // An included file is checked as part of the including grammar
+            Ok(())
+        } else {
+            self.check()
+        }
     }
 
     fn process_prolog(&mut self, prolog: &Prolog) -> Result<()> {
         self.process_start_declaration(&prolog.start_declaration)?;
         self.process_declarations(&prolog.prolog_list)?;
         self.process_scanner_states(&prolog.prolog_list0);
+        if self.scanner_configurations[INITIAL_STATE].case_insensitive {
+            // Precedence declarations can precede the %case_insensitive directive
+            self.precedence_levels
+                .iter_mut()
+                .flat_map(|l| l.symbols.iter_mut())
+                .for_each(|s| {
+                    if let PrecedenceSymbol::Terminal(_, k) = s {
+                        *k = k.case_insensitive();
+                    }
+                });
+        }
         Ok(())
     }
 
@@ -637,8 +999,22 @@ impl ParolGrammar<'_> {
         Ok(())
     }
 
+    // Checks for the case insensitivity flag 'i' after the closing delimiter
+    fn is_case_insensitive(string: &str) -> bool {
+        let delimiters: &[_] = &['"', '\'', '/'];
+        string
+            .strip_suffix('i')
+            .is_some_and(|s| s.ends_with(delimiters))
+    }
+
+    // Removes the delimiters and the optional case insensitivity flag
     fn trim_quotes(string: &str) -> String {
         let delimiters: &[_] = &['"', '\'', '/'];
+        let string = if Self::is_case_insensitive(string) {
+            &string[..string.len() - 1]
+        } else {
+            string
+        };
         string
             .strip_prefix(delimiters)
             .unwrap()
@@ -647,16 +1023,51 @@ impl ParolGrammar<'_> {
             .to_string()
     }
 
+    // Removes the delimiters of a string that isn't a terminal, thus the case insensitivity flag
+    // isn't allowed
+    fn plain_string(token: &Token) -> Result<String> {
+        if Self::is_case_insensitive(token.text()) {
+            bail!(ParolParserError::UnsupportedFeature {
+                feature: "Case insensitivity flag 'i' on a string that isn't a terminal"
+                    .to_string(),
+                hint: "Only terminals can match case insensitively, remove the trailing 'i'"
+                    .to_string(),
+                input: token.location.file_name.to_path_buf(),
+                token: token.location.clone(),
+            });
+        }
+        Ok(Self::trim_quotes(token.text()))
+    }
+
     fn process_declaration(&mut self, declaration: &PrologList) -> Result<()> {
         match &declaration.declaration {
             Declaration::PercentTitleString(title_decl) => {
-                self.title = Some(Self::trim_quotes(title_decl.string.string.text()))
+                self.title = Some(Self::plain_string(&title_decl.string.string)?)
             }
             Declaration::PercentCommentString(comment_decl) => {
-                self.comment = Some(Self::trim_quotes(comment_decl.string.string.text()))
+                self.comment = Some(Self::plain_string(&comment_decl.string.string)?)
             }
             Declaration::PercentUserUnderscoreTypeIdentifierEquUserTypeName(user_type_def) => {
-                self.process_user_type_definition(user_type_def)
+                self.process_user_type_definition(user_type_def)?
+            }
+            Declaration::PercentIncludeString(include) => self.process_include(include)?,
+            Declaration::PercentKeywordsIdentifierList(keywords) => {
+                self.process_keywords(&keywords.identifier_list)
+            }
+            Declaration::PercentDeriveDeclarationOptDeriveList(derive) => {
+                self.process_derive(derive)
+            }
+            Declaration::PercentInlineIdentifierList(inline) => {
+                self.process_inline(&inline.identifier_list)
+            }
+            Declaration::PercentDisplayIdentifierString(display) => self.process_display(display)?,
+            Declaration::ExpectDirectiveNumber(expect) => {
+                let number = Some(Self::process_number(&expect.number.number)?);
+                if expect.expect_directive.expect_directive.text() == "%expect-rr" {
+                    self.expected_reduce_reduce_conflicts = number
+                } else {
+                    self.expected_shift_reduce_conflicts = number
+                }
             }
             Declaration::ScannerDirectives(scanner_decl) => {
                 self.process_scanner_directive(&scanner_decl.scanner_directives)?
@@ -664,10 +1075,132 @@ impl ParolGrammar<'_> {
             Declaration::PercentGrammarUnderscoreTypeRawString(grammar_type) => {
                 self.process_grammar_type_declaration(&grammar_type.raw_string.raw_string)?
             }
+            Declaration::PrecedenceDeclaration(precedence_decl) => {
+                self.process_precedence_declaration(&precedence_decl.precedence_declaration)
+            }
         }
         Ok(())
     }
 
+    fn process_keywords(&mut self, identifier_list: &IdentifierList) {
+        Self::add_identifiers(&mut self.keywords, identifier_list);
+    }
+
+    fn process_inline(&mut self, identifier_list: &IdentifierList) {
+        Self::add_identifiers(&mut self.inline_non_terminals, identifier_list);
+    }
+
+    // A later declaration for the same terminal replaces an earlier one
+    fn process_display(
+        &mut self,
+        display: &parol_grammar_trait::DeclarationPercentDisplayIdentifierString,
+    ) -> Result<()> {
+        let terminal = &display.identifier.identifier;
+        let display_name = Self::plain_string(&display.string.string)?;
+        self.display_names
+            .retain(|(t, _)| t.text() != terminal.text());
+        self.display_names.push((terminal.to_owned(), display_name));
+        Ok(())
+    }
+
+    // Adds the identifiers of the list that aren't already contained in the given names
+    fn add_identifiers(names: &mut Vec<Token<'static>>, identifier_list: &IdentifierList) {
+        let identifiers = identifier_list.identifier_list_list.iter().fold(
+            vec![&identifier_list.identifier.identifier],
+            |mut acc, i| {
+                acc.push(&i.identifier.identifier);
+                acc
+            },
+        );
+        for identifier in identifiers {
+            if !names.iter().any(|n| n.text() == identifier.text()) {
+                names.push(identifier.to_owned());
+            }
+        }
+    }
+
+    fn process_derive(
+        &mut self,
+        derive: &parol_grammar_trait::DeclarationPercentDeriveDeclarationOptDeriveList,
+    ) {
+        let derives = derive.derive_list.derive_list_list.iter().fold(
+            vec![derive.derive_list.user_type_name.clone()],
+            |mut acc, d| {
+                acc.push(d.user_type_name.clone());
+                acc
+            },
+        );
+        if let Some(declaration_opt) = &derive.declaration_opt {
+            let non_terminal = &declaration_opt.derive_target.identifier.identifier;
+            self.non_terminal_derives
+                .push((non_terminal.to_owned(), derives));
+        } else {
+            self.derives.extend(derives);
+        }
+    }
+
+    fn process_precedence_declaration(
+        &mut self,
+        precedence_decl: &parol_grammar_trait::PrecedenceDeclaration,
+    ) {
+        let (associativity, token) = match &precedence_decl.associativity {
+            parol_grammar_trait::Associativity::PercentLeft(a) => {
+                (Associativity::Left, &a.percent_left)
+            }
+            parol_grammar_trait::Associativity::PercentRight(a) => {
+                (Associativity::Right, &a.percent_right)
+            }
+            parol_grammar_trait::Associativity::PercentNonassoc(a) => {
+                (Associativity::NonAssoc, &a.percent_nonassoc)
+            }
+        };
+        self.precedence_directives.push(token.to_owned());
+        let symbols = precedence_decl.precedence_declaration_list.iter().fold(
+            vec![self.process_precedence_symbol(&precedence_decl.precedence_symbol)],
+            |mut acc, s| {
+                acc.push(self.process_precedence_symbol(&s.precedence_symbol));
+                acc
+            },
+        );
+        self.precedence_levels
+            .push(PrecedenceLevel::new(associativity, symbols));
+    }
+
+    fn process_precedence_symbol(
+        &self,
+        precedence_symbol: &parol_grammar_trait::PrecedenceSymbol,
+    ) -> PrecedenceSymbol {
+        match precedence_symbol {
+            parol_grammar_trait::PrecedenceSymbol::TokenLiteral(t) => {
+                let (content, kind) = Self::measure_token_literal(&t.token_literal);
+                // Terminals in precedence declarations belong to scanner state INITIAL
+                PrecedenceSymbol::Terminal(content, self.terminal_kind(kind, &[INITIAL_STATE]))
+            }
+            parol_grammar_trait::PrecedenceSymbol::Identifier(i) => {
+                PrecedenceSymbol::Name(i.identifier.identifier.text().to_string())
+            }
+        }
+    }
+
+    fn precedence_symbol_token<'a>(
+        precedence_symbol: &'a parol_grammar_trait::PrecedenceSymbol,
+    ) -> &'a Token<'a> {
+        match precedence_symbol {
+            parol_grammar_trait::PrecedenceSymbol::TokenLiteral(t) => match &t.token_literal {
+                TokenLiteral::String(s) => &s.string.string,
+                TokenLiteral::RawString(r) => &r.raw_string.raw_string,
+                TokenLiteral::Regex(r) => &r.regex.regex,
+            },
+            parol_grammar_trait::PrecedenceSymbol::Identifier(i) => &i.identifier.identifier,
+        }
+    }
+
+    fn is_declared_precedence_symbol(&self, symbol: &PrecedenceSymbol) -> bool {
+        self.precedence_levels
+            .iter()
+            .any(|l| l.symbols.iter().any(|s| s.matches(symbol)))
+    }
+
     fn process_scanner_directive(&mut self, scanner_directives: &ScannerDirectives) -> Result<()> {
         match scanner_directives {
             ScannerDirectives::PercentLineUnderscoreCommentTokenLiteral(line_comment) => self
@@ -682,12 +1215,29 @@ impl ParolGrammar<'_> {
                     Self::expanded_token_literal(&block_comment.token_literal),
                     Self::expanded_token_literal(&block_comment.token_literal0),
                 )),
+            ScannerDirectives::PercentNestedUnderscoreBlockUnderscoreCommentTokenLiteralTokenLiteral(
+                block_comment,
+            ) => self.scanner_configurations[INITIAL_STATE]
+                .nested_block_comments
+                .push((
+                    Self::expanded_token_literal(&block_comment.token_literal),
+                    Self::expanded_token_literal(&block_comment.token_literal0),
+                )),
             ScannerDirectives::PercentAutoUnderscoreNewlineUnderscoreOff(_) => {
                 self.scanner_configurations[INITIAL_STATE].auto_newline_off = true
             }
             ScannerDirectives::PercentAutoUnderscoreWsUnderscoreOff(_) => {
                 self.scanner_configurations[INITIAL_STATE].auto_ws_off = true
             }
+            ScannerDirectives::PercentLongestUnderscoreMatch(_) => {
+                self.scanner_configurations[INITIAL_STATE].longest_match = true
+            }
+            ScannerDirectives::PercentCaseUnderscoreInsensitive(_) => {
+                self.scanner_configurations[INITIAL_STATE].case_insensitive = true
+            }
+            ScannerDirectives::PercentOffsideIdentifierIdentifierIdentifier(offside) => {
+                self.scanner_configurations[INITIAL_STATE].set_offside(offside)
+            }
             ScannerDirectives::PercentOnIdentifierListPercentEnterIdentifier(transitions) => {
                 self.scanner_configurations[INITIAL_STATE].add_transitions(transitions)
             }
@@ -709,6 +1259,17 @@ impl ParolGrammar<'_> {
                 acc
             },
         );
+        self.template_names = self
+            .templates
+            .iter()
+            .map(|t| t.name.clone())
+            .chain(
+                productions
+                    .iter()
+                    .filter(|p| p.production_name.production_name_opt.is_some())
+                    .map(|p| p.production_name.identifier.identifier.text().to_string()),
+            )
+            .collect();
         self.process_productions(&productions)
     }
 
@@ -739,28 +1300,129 @@ impl ParolGrammar<'_> {
     }
 
     fn process_production(&mut self, prod: &parol_grammar_trait::Production) -> Result<()> {
-        let lhs = prod.identifier.identifier.text().to_string();
         let alternations = Self::to_alternation_vec(&prod.alternations);
-        let rhs = self.process_alternations(&alternations)?;
-        self.productions.push(Production { lhs, rhs });
+        let rhs = self.process_alternations(&alternations, false)?;
+        let name = &prod.production_name.identifier.identifier;
+        match &prod.production_name.production_name_opt {
+            None => {
+                let lhs = name.text().to_string();
+                if self.grammar_type.is_lr() && lhs == ERROR_TOKEN_NAME {
+                    bail!(ParolParserError::InvalidErrorTokenDefinition {
+                        context: "process_production".to_string(),
+                        input: name.location.file_name.to_path_buf(),
+                        location: name.location.clone(),
+                    });
+                }
+                self.productions.push(Production { lhs, rhs });
+            }
+            Some(production_name_opt) => {
+                let identifier_list = &production_name_opt.template_parameters.identifier_list;
+                let params = identifier_list.identifier_list_list.iter().fold(
+                    vec![identifier_list.identifier.identifier.text().to_string()],
+                    |mut acc, i| {
+                        acc.push(i.identifier.identifier.text().to_string());
+                        acc
+                    },
+                );
+                self.templates.push(Template {
+                    name: name.text().to_string(),
+                    params,
+                    rhs,
+                });
+            }
+        }
         Ok(())
     }
 
     fn process_alternations(
         &mut self,
         alternations: &[&parol_grammar_trait::Alternation],
+        nested: bool,
     ) -> Result<Alternations> {
         let mut result = Alternations::new();
+        let mut labels: Vec<&Token<'_>> = Vec::new();
         for a in alternations {
-            result.insert(self.process_alternation(&a.alternation_list)?);
+            if let Some(alternation_opt0) = &a.alternation_opt0 {
+                let label = &alternation_opt0.alternation_label.identifier.identifier;
+                if let Some(first) = labels.iter().find(|l| l.text() == label.text()) {
+                    bail!(ParolParserError::DuplicateLabel {
+                        kind: "Alternative label".to_string(),
+                        label: label.text().to_string(),
+                        input: label.location.file_name.to_path_buf(),
+                        first: first.location.clone(),
+                        second: label.location.clone(),
+                    });
+                }
+                labels.push(label);
+            }
+            result.insert(self.process_alternation(a, nested)?);
         }
         Ok(result)
     }
 
-    fn process_alternation(&mut self, alternation_list: &[AlternationList]) -> Result<Alternation> {
+    #[named]
+    fn process_alternation(
+        &mut self,
+        alternation: &parol_grammar_trait::Alternation,
+        nested: bool,
+    ) -> Result<Alternation> {
+        let context = function_name!();
         let mut result = Alternation::new();
-        for a in alternation_list {
-            result.push(self.process_factor(&a.factor)?)
+        let mut labels: Vec<&Token<'_>> = Vec::new();
+        for a in &alternation.alternation_list {
+            if let parol_grammar_trait::Factor::FieldLabelSymbol(labeled) = &a.factor {
+                let label = &labeled.field_label.identifier.identifier;
+                if let Some(first) = labels.iter().find(|l| l.text() == label.text()) {
+                    bail!(ParolParserError::DuplicateLabel {
+                        kind: "Field label".to_string(),
+                        label: label.text().to_string(),
+                        input: label.location.file_name.to_path_buf(),
+                        first: first.location.clone(),
+                        second: label.location.clone(),
+                    });
+                }
+                labels.push(label);
+            }
+        }
+        let items = alternation
+            .alternation_list
+            .iter()
+            .map(|a| FactorItem::from(&a.factor))
+            .collect::<Vec<_>>();
+        for factor in self.process_factors(&items)? {
+            result.push(factor);
+        }
+        if let Some(alternation_opt) = &alternation.alternation_opt {
+            let token = Self::precedence_symbol_token(&alternation_opt.precedence_symbol);
+            if nested {
+                bail!(ParolParserError::UnsupportedFeature {
+                    feature: "%prec directive in a nested alternation".to_string(),
+                    hint: "Use %prec only at the end of a production's alternation".to_string(),
+                    input: token.location.file_name.to_path_buf(),
+                    token: token.into(),
+                });
+            }
+            let precedence = self.process_precedence_symbol(&alternation_opt.precedence_symbol);
+            if !self.is_declared_precedence_symbol(&precedence) {
+                bail!(ParolParserError::UndeclaredPrecedenceSymbol {
+                    context: context.to_string(),
+                    symbol: precedence.to_string(),
+                    input: token.location.file_name.to_path_buf(),
+                    location: token.location.clone(),
+                });
+            }
+            self.precedence_directives.push(token.to_owned());
+            result = result.with_precedence(Some(precedence));
+        }
+        if let Some(alternation_opt0) = &alternation.alternation_opt0 {
+            result = result.with_label(Some(
+                alternation_opt0
+                    .alternation_label
+                    .identifier
+                    .identifier
+                    .text()
+                    .to_string(),
+            ));
         }
         Ok(result)
     }
@@ -771,7 +1433,7 @@ impl ParolGrammar<'_> {
         match factor {
             parol_grammar_trait::Factor::Group(group) => {
                 let alternations = Self::to_alternation_vec(&group.group.alternations);
-                let factors = self.process_alternations(&alternations)?;
+                let factors = self.process_alternations(&alternations, true)?;
                 if factors.is_empty() {
                     Err(parol!(ParolParserError::EmptyGroup {
                         context: context.to_string(),
@@ -785,7 +1447,7 @@ impl ParolGrammar<'_> {
             }
             parol_grammar_trait::Factor::Repeat(repeat) => {
                 let alternations = Self::to_alternation_vec(&repeat.repeat.alternations);
-                let factors = self.process_alternations(&alternations)?;
+                let factors = self.process_alternations(&alternations, true)?;
                 if factors.is_empty() {
                     Err(parol!(ParolParserError::EmptyRepetition {
                         context: context.to_string(),
@@ -794,12 +1456,24 @@ impl ParolGrammar<'_> {
                         end: repeat.repeat.r_brace.location.clone(),
                     }))
                 } else {
-                    Ok(Factor::Repeat(factors))
+                    let separator = repeat
+                        .repeat
+                        .repeat_opt
+                        .as_ref()
+                        .map(|repeat_opt| {
+                            self.process_repeat_separator(&repeat_opt.repeat_separator)
+                        })
+                        .transpose()?;
+                    if separator.is_some() || repeat.repeat.repeat_opt0.is_some() {
+                        Ok(Factor::NonEmptyRepeat(factors, separator.map(Box::new)))
+                    } else {
+                        Ok(Factor::Repeat(factors))
+                    }
                 }
             }
             parol_grammar_trait::Factor::Optional(optional) => {
                 let alternations = Self::to_alternation_vec(&optional.optional.alternations);
-                let factors = self.process_alternations(&alternations)?;
+                let factors = self.process_alternations(&alternations, true)?;
                 if factors.is_empty() {
                     Err(parol!(ParolParserError::EmptyOptional {
                         context: context.to_string(),
@@ -811,7 +1485,142 @@ impl ParolGrammar<'_> {
                     Ok(Factor::Optional(factors))
                 }
             }
-            parol_grammar_trait::Factor::Symbol(symbol) => self.process_symbol(&symbol.symbol),
+            parol_grammar_trait::Factor::Symbol(_)
+            | parol_grammar_trait::Factor::FieldLabelSymbol(_) => {
+                let mut factors = self.process_factors(&[FactorItem::from(factor)])?;
+                Ok(factors.remove(0))
+            }
+        }
+    }
+
+    // Resolves the symbols of a sequence of factors.
+    // Angle arguments that follow the name of a template are the arguments of a template instance,
+    // all other angle arguments are the scanner states of the terminal that follows them.
+    fn process_factors(&mut self, items: &[FactorItem]) -> Result<Vec<Factor>> {
+        let mut result = Vec::with_capacity(items.len());
+        let mut i = 0;
+        while i < items.len() {
+            let (label, factor) = match (&items[i], items.get(i + 1)) {
+                (FactorItem::Other(factor), _) => (None, self.process_factor(factor)?),
+                (
+                    FactorItem::Symbol(label, parol_grammar_trait::Symbol::NonTerminal(n)),
+                    Some(FactorItem::Symbol(
+                        None,
+                        parol_grammar_trait::Symbol::AngleArguments(arguments),
+                    )),
+                ) if n.non_terminal.non_terminal_opt.is_none()
+                    && self
+                        .template_names
+                        .contains(n.non_terminal.identifier.identifier.text()) =>
+                {
+                    i += 1;
+                    let factor = self.process_template_instance(
+                        &n.non_terminal.identifier.identifier,
+                        &arguments.angle_arguments,
+                    )?;
+                    (*label, factor)
+                }
+                (
+                    FactorItem::Symbol(label, parol_grammar_trait::Symbol::AngleArguments(a)),
+                    Some(FactorItem::Symbol(
+                        None,
+                        parol_grammar_trait::Symbol::SimpleToken(simple_token),
+                    )),
+                ) => {
+                    i += 1;
+                    let factor = self.process_token_with_states(
+                        &a.angle_arguments,
+                        &simple_token.simple_token,
+                    )?;
+                    (*label, factor)
+                }
+                (FactorItem::Symbol(label, symbol), _) => (*label, self.process_symbol(symbol)?),
+            };
+            result.push(Self::with_label(factor, label)?);
+            i += 1;
+        }
+        Ok(result)
+    }
+
+    fn with_label(factor: Factor, label: Option<&Token<'_>>) -> Result<Factor> {
+        let Some(label) = label else {
+            return Ok(factor);
+        };
+        let l = Some(label.text().to_string());
+        match factor {
+            Factor::Terminal(t, k, s, a, u, c, _) => Ok(Factor::Terminal(t, k, s, a, u, c, l)),
+            Factor::NonTerminal(n, a, u, _) => Ok(Factor::NonTerminal(n, a, u, l)),
+            Factor::TemplateInstance(n, args, a, u, _) => {
+                Ok(Factor::TemplateInstance(n, args, a, u, l))
+            }
+            _ => bail!(ParolParserError::UnsupportedFeature {
+                feature: "Label of a scanner switch".to_string(),
+                hint: "Only terminals and non-terminals can be labeled".to_string(),
+                input: label.location.file_name.to_path_buf(),
+                token: label.location.clone(),
+            }),
+        }
+    }
+
+    // Returns the single factor of a template argument or of a repetition's separator
+    fn process_single_factor(&mut self, items: &[FactorItem], start: &Token<'_>) -> Result<Factor> {
+        let mut factors = self.process_factors(items)?;
+        if factors.len() != 1 {
+            bail!(ParolParserError::UnsupportedFeature {
+                feature: "Sequence of factors as template argument or separator".to_string(),
+                hint: "Use a single symbol or put the sequence into a production of its own"
+                    .to_string(),
+                input: start.location.file_name.to_path_buf(),
+                token: start.location.clone(),
+            });
+        }
+        Ok(factors.remove(0))
+    }
+
+    fn process_repeat_separator(
+        &mut self,
+        repeat_separator: &parol_grammar_trait::RepeatSeparator,
+    ) -> Result<Factor> {
+        let items = std::iter::once(&repeat_separator.symbol)
+            .chain(
+                repeat_separator
+                    .repeat_separator_list
+                    .iter()
+                    .map(|s| &s.symbol),
+            )
+            .map(|s| FactorItem::Symbol(None, s))
+            .collect::<Vec<_>>();
+        let percent = &repeat_separator.percent;
+        // The separator is never propagated to the AST
+        match self.process_single_factor(&items, percent)? {
+            Factor::Terminal(t, k, s, _, _, c, _) => Ok(Factor::Terminal(
+                t,
+                k,
+                s,
+                SymbolAttribute::Clipped,
+                None,
+                c,
+                None,
+            )),
+            Factor::NonTerminal(n, ..) => {
+                Ok(Factor::NonTerminal(n, SymbolAttribute::Clipped, None, None))
+            }
+            Factor::ScannerSwitch(_, location)
+            | Factor::ScannerSwitchPush(_, location)
+            | Factor::ScannerSwitchPop(location) => bail!(ParolParserError::UnsupportedFeature {
+                feature: "Scanner switch as separator of a repetition".to_string(),
+                hint: "Use a terminal or a non-terminal as separator".to_string(),
+                input: location.file_name.to_path_buf(),
+                token: location,
+            }),
+            Factor::TemplateInstance(..) => bail!(ParolParserError::UnsupportedFeature {
+                feature: "Template instance as separator of a repetition".to_string(),
+                hint: "Use a production that derives the template instance as separator"
+                    .to_string(),
+                input: percent.location.file_name.to_path_buf(),
+                token: percent.location.clone(),
+            }),
+            _ => unreachable!("Symbols are terminals, non-terminals or scanner switches"),
         }
     }
 
@@ -836,20 +1645,75 @@ impl ParolGrammar<'_> {
         }
     }
 
-    fn measure_token_literal<'a>(literal: &'a TokenLiteral) -> (&'a str, TerminalKind) {
+    fn token_of_token_literal<'a, 't>(
+        literal: &'a TokenLiteral<'t>,
+    ) -> (&'a Token<'t>, TerminalKind) {
         match literal {
             parol_grammar_trait::TokenLiteral::String(s) => {
-                (s.string.string.text(), TerminalKind::Legacy)
+                (&s.string.string, TerminalKind::Legacy)
             }
             parol_grammar_trait::TokenLiteral::RawString(l) => {
-                (l.raw_string.raw_string.text(), TerminalKind::Raw)
-            }
-            parol_grammar_trait::TokenLiteral::Regex(r) => {
-                (r.regex.regex.text(), TerminalKind::Regex)
+                (&l.raw_string.raw_string, TerminalKind::Raw)
             }
+            parol_grammar_trait::TokenLiteral::Regex(r) => (&r.regex.regex, TerminalKind::Regex),
+        }
+    }
+
+    // Returns the terminal's text without delimiters and its kind.
+    // A trailing 'i' after the closing delimiter makes the terminal case insensitive.
+    fn measure_token_literal(literal: &TokenLiteral) -> (String, TerminalKind) {
+        let (token, kind) = Self::token_of_token_literal(literal);
+        let content = Self::trim_quotes(token.text());
+        if Self::is_case_insensitive(token.text()) {
+            (content, kind.case_insensitive())
+        } else {
+            (content, kind)
         }
     }
 
+    // Terminals of scanner states that are all case insensitive are case insensitive, too
+    fn terminal_kind(&self, kind: TerminalKind, scanner_states: &[usize]) -> TerminalKind {
+        if scanner_states
+            .iter()
+            .all(|s| self.scanner_configurations[*s].case_insensitive)
+        {
+            kind.case_insensitive()
+        } else {
+            kind
+        }
+    }
+
+    // Terminals that match the empty string would stall the scanner at runtime
+    fn check_token_literal(literal: &TokenLiteral) -> Result<()> {
+        let (token, _) = Self::token_of_token_literal(literal);
+        let (terminal, kind) = Self::measure_token_literal(literal);
+        // Regexes that can't be parsed are reported later during scanner generation
+        if let Ok(true) = matches_empty_string(&terminal, kind) {
+            bail!(ParolParserError::EmptyMatchingTerminal {
+                context: "check_token_literal".to_string(),
+                terminal,
+                input: token.location.file_name.to_path_buf(),
+                location: token.location.clone(),
+            });
+        }
+        Ok(())
+    }
+
+    // Returns the terminal's text, its kind and its optional trailing context
+    fn process_token_expression(
+        &self,
+        token_expression: &TokenExpression,
+        scanner_states: &[usize],
+    ) -> Result<(String, TerminalKind, Option<TrailingContext>)> {
+        Self::check_token_literal(&token_expression.token_literal)?;
+        let (content, kind) = Self::measure_token_literal(&token_expression.token_literal);
+        let context = token_expression.token_expression_opt.as_ref().map(|opt| {
+            let (text, kind) = Self::measure_token_literal(&opt.trailing_context.token_literal);
+            TrailingContext::new(&text, self.terminal_kind(kind, scanner_states))
+        });
+        Ok((content, self.terminal_kind(kind, scanner_states), context))
+    }
+
     fn process_symbol(&mut self, symbol: &parol_grammar_trait::Symbol) -> Result<Factor> {
         match symbol {
             parol_grammar_trait::Symbol::NonTerminal(non_terminal) => {
@@ -870,6 +1734,7 @@ impl ParolGrammar<'_> {
                         .to_string(),
                     attr,
                     user_type_name,
+                    None,
                 ))
             }
             parol_grammar_trait::Symbol::SimpleToken(simple_token) => {
@@ -881,40 +1746,30 @@ impl ParolGrammar<'_> {
                         ASTControlKind::UserTyped(u) => user_type_name = Some(u),
                     }
                 }
-                let (content, kind) =
-                    Self::measure_token_literal(&simple_token.simple_token.token_literal);
+                let (content, kind, context) = self.process_token_expression(
+                    &simple_token.simple_token.token_expression,
+                    &[INITIAL_STATE],
+                )?;
                 Ok(Factor::Terminal(
-                    Self::trim_quotes(content),
+                    content,
                     kind,
                     vec![0],
                     attr,
                     user_type_name,
+                    context,
+                    None,
                 ))
             }
-            parol_grammar_trait::Symbol::TokenWithStates(token_with_states) => {
-                let mut scanner_states = self.process_scanner_state_list(
-                    &token_with_states.token_with_states.identifier_list,
-                )?;
-                scanner_states.sort_unstable();
-                let mut attr = SymbolAttribute::None;
-                let mut user_type_name = None;
-                if let Some(ref terminal_opt) =
-                    &token_with_states.token_with_states.token_with_states_opt
-                {
-                    match self.process_ast_control(&terminal_opt.a_s_t_control) {
-                        ASTControlKind::Attr(a) => attr = a,
-                        ASTControlKind::UserTyped(u) => user_type_name = Some(u),
-                    }
-                }
-                let (content, kind) =
-                    Self::measure_token_literal(&token_with_states.token_with_states.token_literal);
-                Ok(Factor::Terminal(
-                    Self::trim_quotes(content),
-                    kind,
-                    scanner_states,
-                    attr,
-                    user_type_name,
-                ))
+            parol_grammar_trait::Symbol::AngleArguments(arguments) => {
+                let token = &arguments.angle_arguments.l_t;
+                bail!(ParolParserError::UnsupportedFeature {
+                    feature: "Angle brackets without template name or terminal".to_string(),
+                    hint: "Template arguments follow the name of a template, scanner states \
+                        precede a terminal"
+                        .to_string(),
+                    input: token.location.file_name.to_path_buf(),
+                    token: token.into(),
+                })
             }
             parol_grammar_trait::Symbol::ScannerSwitch(scanner_switch) => {
                 self.process_scanner_switch(scanner_switch)
@@ -922,15 +1777,106 @@ impl ParolGrammar<'_> {
         }
     }
 
-    fn process_scanner_state_list(
+    fn process_template_instance(
         &mut self,
-        state_list: &parol_grammar_trait::IdentifierList,
-    ) -> Result<Vec<usize>> {
-        let mut result = vec![self.resolve_scanner(&state_list.identifier.identifier)?];
-        for s in &state_list.identifier_list_list {
-            result.push(self.resolve_scanner(&s.identifier.identifier)?);
+        name: &Token<'_>,
+        arguments: &parol_grammar_trait::AngleArguments,
+    ) -> Result<Factor> {
+        let mut attr = SymbolAttribute::None;
+        let mut user_type_name = None;
+        if let Some(ref angle_arguments_opt) = &arguments.angle_arguments_opt {
+            match self.process_ast_control(&angle_arguments_opt.a_s_t_control) {
+                ASTControlKind::Attr(a) => attr = a,
+                ASTControlKind::UserTyped(u) => user_type_name = Some(u),
+            }
         }
-        Ok(result)
+        let template_arguments = &arguments.template_arguments;
+        let mut args = Vec::new();
+        for argument in std::iter::once(&template_arguments.template_argument).chain(
+            template_arguments
+                .template_arguments_list
+                .iter()
+                .map(|a| &a.template_argument),
+        ) {
+            let items = std::iter::once(&argument.factor)
+                .chain(argument.template_argument_list.iter().map(|f| &f.factor))
+                .map(FactorItem::from)
+                .collect::<Vec<_>>();
+            args.push(self.process_single_factor(&items, &arguments.l_t)?);
+        }
+        Ok(Factor::TemplateInstance(
+            name.text().to_string(),
+            args,
+            attr,
+            user_type_name,
+            None,
+        ))
+    }
+
+    fn process_token_with_states(
+        &mut self,
+        arguments: &parol_grammar_trait::AngleArguments,
+        simple_token: &parol_grammar_trait::SimpleToken,
+    ) -> Result<Factor> {
+        let token = &arguments.l_t;
+        if arguments.angle_arguments_opt.is_some() {
+            bail!(ParolParserError::UnsupportedFeature {
+                feature: "Cut operator or user type after scanner states".to_string(),
+                hint: "Put it after the terminal".to_string(),
+                input: token.location.file_name.to_path_buf(),
+                token: token.into(),
+            });
+        }
+        let template_arguments = &arguments.template_arguments;
+        let mut scanner_states = Vec::new();
+        for argument in std::iter::once(&template_arguments.template_argument).chain(
+            template_arguments
+                .template_arguments_list
+                .iter()
+                .map(|a| &a.template_argument),
+        ) {
+            match &argument.factor {
+                parol_grammar_trait::Factor::Symbol(symbol)
+                    if argument.template_argument_list.is_empty() =>
+                {
+                    if let parol_grammar_trait::Symbol::NonTerminal(n) = &*symbol.symbol {
+                        if n.non_terminal.non_terminal_opt.is_none() {
+                            scanner_states
+                                .push(self.resolve_scanner(&n.non_terminal.identifier.identifier)?);
+                            continue;
+                        }
+                    }
+                }
+                _ => (),
+            }
+            bail!(ParolParserError::UnsupportedFeature {
+                feature: "Scanner states that aren't identifiers".to_string(),
+                hint: "List the names of scanner states in the angle brackets before a terminal"
+                    .to_string(),
+                input: token.location.file_name.to_path_buf(),
+                token: token.into(),
+            });
+        }
+        scanner_states.sort_unstable();
+        let mut attr = SymbolAttribute::None;
+        let mut user_type_name = None;
+        if let Some(ref terminal_opt) = &simple_token.simple_token_opt {
+            match self.process_ast_control(&terminal_opt.a_s_t_control) {
+                ASTControlKind::Attr(a) => attr = a,
+                ASTControlKind::UserTyped(u) => user_type_name = Some(u),
+            }
+        }
+        let (content, kind, context) =
+            self.process_token_expression(&simple_token.token_expression, &scanner_states)?;
+        Ok(Factor::Terminal(
+            content,
+            kind,
+            scanner_states,
+            attr,
+            user_type_name,
+            context,
+            None,
+        ))
     }
 
     #[named]
@@ -954,43 +1900,39 @@ impl ParolGrammar<'_> {
         &self,
         scanner_switch: &parol_grammar_trait::SymbolScannerSwitch,
     ) -> Result<Factor> {
-        match self.grammar_type {
-            GrammarType::LLK => match &scanner_switch.scanner_switch {
-                ScannerSwitch::PercentScLParenScannerSwitchOptRParen(sw) => {
-                    match &sw.scanner_switch_opt {
-                        Some(st) => Ok(Factor::ScannerSwitch(
-                            self.resolve_scanner(&st.identifier.identifier)?,
-                            sw.percent_sc.location.clone(),
-                        )),
-                        None => Ok(Factor::ScannerSwitch(
-                            INITIAL_STATE,
-                            sw.percent_sc.location.clone(),
-                        )),
-                    }
-                }
-                ScannerSwitch::PercentPushLParenIdentifierRParen(sw) => {
-                    Ok(Factor::ScannerSwitchPush(
-                        self.resolve_scanner(&sw.identifier.identifier)?,
-                        sw.percent_push.location.clone(),
-                    ))
-                }
-                ScannerSwitch::PercentPopLParenRParen(sw) => {
-                    Ok(Factor::ScannerSwitchPop(sw.percent_pop.location.clone()))
-                }
-            },
-            GrammarType::LALR1 => {
-                let token = match &scanner_switch.scanner_switch {
-                    ScannerSwitch::PercentScLParenScannerSwitchOptRParen(sw) => &sw.percent_sc,
-                    ScannerSwitch::PercentPushLParenIdentifierRParen(sw) => &sw.percent_push,
-                    ScannerSwitch::PercentPopLParenRParen(sw) => &sw.percent_pop,
-                };
-                Err(ParolParserError::UnsupportedFeature {
-                    feature: "Parser-based scanner switching in LALR(1) grammar".to_string(),
-                    hint: "Use scanner-based scanner switching (%on - %enter) instead".to_string(),
-                    input: token.location.file_name.to_path_buf(),
-                    token: token.into(),
+        if self.grammar_type.is_lr() {
+            let token = match &scanner_switch.scanner_switch {
+                ScannerSwitch::PercentScLParenScannerSwitchOptRParen(sw) => &sw.percent_sc,
+                ScannerSwitch::PercentPushLParenIdentifierRParen(sw) => &sw.percent_push,
+                ScannerSwitch::PercentPopLParenRParen(sw) => &sw.percent_pop,
+            };
+            return Err(ParolParserError::UnsupportedFeature {
+                feature: "Parser-based scanner switching in LALR(1) grammar".to_string(),
+                hint: "Use scanner-based scanner switching (%on - %enter) instead".to_string(),
+                input: token.location.file_name.to_path_buf(),
+                token: token.into(),
+            }
+            .into());
+        }
+        match &scanner_switch.scanner_switch {
+            ScannerSwitch::PercentScLParenScannerSwitchOptRParen(sw) => {
+                match &sw.scanner_switch_opt {
+                    Some(st) => Ok(Factor::ScannerSwitch(
+                        self.resolve_scanner(&st.identifier.identifier)?,
+                        sw.percent_sc.location.clone(),
+                    )),
+                    None => Ok(Factor::ScannerSwitch(
+                        INITIAL_STATE,
+                        sw.percent_sc.location.clone(),
+                    )),
                 }
-                .into())
+            }
+            ScannerSwitch::PercentPushLParenIdentifierRParen(sw) => Ok(Factor::ScannerSwitchPush(
+                self.resolve_scanner(&sw.identifier.identifier)?,
+                sw.percent_push.location.clone(),
+            )),
+            ScannerSwitch::PercentPopLParenRParen(sw) => {
+                Ok(Factor::ScannerSwitchPop(sw.percent_pop.location.clone()))
             }
         }
     }
@@ -998,11 +1940,117 @@ impl ParolGrammar<'_> {
     fn process_user_type_definition(
         &mut self,
         user_type_def: &parol_grammar_trait::DeclarationPercentUserUnderscoreTypeIdentifierEquUserTypeName,
-    ) {
+    ) -> Result<()> {
+        self.define(
+            DefinitionKind::UserType,
+            &user_type_def.identifier.identifier,
+        )?;
         self.user_type_definitions.insert(
             user_type_def.identifier.identifier.text().to_string(),
             user_type_def.user_type_name.clone(),
         );
+        Ok(())
+    }
+
+    fn process_include(&mut self, include: &DeclarationPercentIncludeString) -> Result<()> {
+        let token = &include.string.string;
+        // The path is relative to the including file
+        let file_name = token
+            .location
+            .file_name
+            .parent()
+            .unwrap_or_else(|| Path::new(""))
+            .join(Self::plain_string(token)?);
+        let canonical_name = fs::canonicalize(&file_name).map_err(|e| {
+            anyhow!(
+                "{} - Can't include file {}: {}",
+                token.location,
+                file_name.display(),
+                e
+            )
+        })?;
+        if let Ok(including_file) = fs::canonicalize(&*token.location.file_name) {
+            self.included_files.insert(including_file);
+        }
+        // Each file is included only once, this also breaks cyclic includes
+        if !self.included_files.insert(canonical_name) {
+            return Ok(());
+        }
+        let input = fs::read_to_string(&file_name).map_err(|e| {
+            anyhow!(
+                "{} - Can't read included file {}: {}",
+                token.location,
+                file_name.display(),
+                e
+            )
+        })?;
+        let mut included_grammar = self.take_definitions();
+        let result = parse(&input, &file_name, &mut included_grammar);
+        self.restore_definitions(included_grammar);
+        result.map(|_| ())
+    }
+
+    // Hands over all definitions made so far to a grammar for an included file.
+    // Thus scanner state indices stay valid and conflicts can be detected.
+    fn take_definitions<'a>(&mut self) -> ParolGrammar<'a> {
+        ParolGrammar {
+            templates: std::mem::take(&mut self.templates),
+            scanner_configurations: std::mem::take(&mut self.scanner_configurations),
+            user_type_definitions: std::mem::take(&mut self.user_type_definitions),
+            precedence_levels: std::mem::take(&mut self.precedence_levels),
+            keywords: std::mem::take(&mut self.keywords),
+            derives: std::mem::take(&mut self.derives),
+            non_terminal_derives: std::mem::take(&mut self.non_terminal_derives),
+            inline_non_terminals: std::mem::take(&mut self.inline_non_terminals),
+            display_names: std::mem::take(&mut self.display_names),
+            token_aliases: std::mem::take(&mut self.token_aliases),
+            precedence_directives: std::mem::take(&mut self.precedence_directives),
+            definitions: std::mem::take(&mut self.definitions),
+            included_files: std::mem::take(&mut self.included_files),
+            included: true,
+            ..Default::default()
+        }
+    }
+
+    fn restore_definitions(&mut self, mut included_grammar: ParolGrammar<'_>) {
+        self.included_productions
+            .append(&mut included_grammar.productions);
+        self.templates = included_grammar.templates;
+        self.scanner_configurations = included_grammar.scanner_configurations;
+        self.user_type_definitions = included_grammar.user_type_definitions;
+        self.precedence_levels = included_grammar.precedence_levels;
+        self.keywords = included_grammar.keywords;
+        self.derives = included_grammar.derives;
+        self.non_terminal_derives = included_grammar.non_terminal_derives;
+        self.inline_non_terminals = included_grammar.inline_non_terminals;
+        self.display_names = included_grammar.display_names;
+        self.token_aliases = included_grammar.token_aliases;
+        self.precedence_directives = included_grammar.precedence_directives;
+        self.definitions = included_grammar.definitions;
+        self.included_files = included_grammar.included_files;
+    }
+
+    // Names may be defined more than once within a single file, e.g. a non-terminal by several
+    // productions, but not in different files
+    fn define(&mut self, kind: DefinitionKind, name: &Token<'_>) -> Result<()> {
+        let key = (kind, name.text().to_string());
+        match self.definitions.get(&key) {
+            Some(first) if first.file_name != name.location.file_name => {
+                bail!(ParolParserError::ConflictingDefinitions {
+                    kind: kind.to_string(),
+                    name: key.1,
+                    first_input: first.file_name.to_path_buf(),
+                    first: first.clone(),
+                    second_input: name.location.file_name.to_path_buf(),
+                    second: name.into(),
+                })
+            }
+            Some(_) => (),
+            None => {
+                self.definitions.insert(key, name.into());
+            }
+        }
+        Ok(())
     }
 
     fn handle_token_alias(
@@ -1027,15 +2075,19 @@ impl ParolGrammar<'_> {
     }
 
     fn expanded_token_literal(token_literal: &parol_grammar_trait::TokenLiteral) -> String {
-        match token_literal {
-            TokenLiteral::String(s) => TerminalKind::Legacy
-                .expand(ParolGrammar::trim_quotes(s.string.string.text()).as_str()),
-            TokenLiteral::RawString(l) => TerminalKind::Raw
-                .expand(ParolGrammar::trim_quotes(l.raw_string.raw_string.text()).as_str()),
-            TokenLiteral::Regex(r) => {
-                TerminalKind::Regex.expand(ParolGrammar::trim_quotes(r.regex.regex.text()).as_str())
-            }
-        }
+        let (content, kind) = ParolGrammar::measure_token_literal(token_literal);
+        kind.expand(&content)
+    }
+
+    // The trailing context is part of the terminal's identity
+    fn expanded_token_expression(token_expression: &TokenExpression) -> String {
+        let (content, kind) = ParolGrammar::measure_token_literal(&token_expression.token_literal);
+        let context = token_expression.token_expression_opt.as_ref().map(|opt| {
+            let (text, kind) =
+                ParolGrammar::measure_token_literal(&opt.trailing_context.token_literal);
+            TrailingContext::new(&text, kind)
+        });
+        kind.expand_with_context(&content, context.as_ref())
     }
 
     fn check(&self) -> Result<()> {
@@ -1054,12 +2106,127 @@ impl ParolGrammar<'_> {
             return Err(ParolParserError::EmptyScanners { empty_scanners }.into());
         }
 
+        self.check_keywords()?;
+        self.check_derive_targets()?;
+        self.check_inline_non_terminals()?;
+        self.check_display_names()?;
+        self.check_precedence_directives()?;
+
+        self.scanner_configurations
+            .iter()
+            .try_for_each(|s| self.check_offside(s))?;
+
         self.scanner_configurations
             .iter()
             .enumerate()
             .try_for_each(|(i, s)| self.check_transitions(i, s))
     }
 
+    fn check_keywords(&self) -> Result<()> {
+        self.keywords.iter().try_for_each(|k| {
+            if !self.is_primary_non_terminal(k) {
+                bail!(ParolParserError::InvalidKeyword {
+                    context: "check_keywords".to_string(),
+                    keyword: k.text().to_string(),
+                    input: k.location.file_name.to_path_buf(),
+                    location: k.location.clone(),
+                });
+            }
+            Ok(())
+        })
+    }
+
+    // Precedences only resolve conflicts in LR parse tables, LL(k) grammars would silently ignore
+    // them
+    fn check_precedence_directives(&self) -> Result<()> {
+        if let Some(token) = self.precedence_directives.first() {
+            if !self.grammar_type.is_lr() {
+                bail!(ParolParserError::UnsupportedFeature {
+                    feature: "Precedence and associativity in LL(k) grammar".to_string(),
+                    hint: "Use %left, %right, %nonassoc and %prec only in LR grammars".to_string(),
+                    input: token.location.file_name.to_path_buf(),
+                    token: token.into(),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    fn check_derive_targets(&self) -> Result<()> {
+        self.non_terminal_derives.iter().try_for_each(|(n, _)| {
+            if !self.productions.iter().any(|p| p.lhs == n.text()) {
+                bail!(ParolParserError::UnknownNonTerminal {
+                    context: "check_derive_targets".to_string(),
+                    name: n.text().to_string(),
+                    input: n.location.file_name.to_path_buf(),
+                    location: n.location.clone(),
+                });
+            }
+            Ok(())
+        })
+    }
+
+    // Only the members of a struct can be spliced into the types of the parents. Thus inlined
+    // non-terminals must have exactly one alternative.
+    fn check_inline_non_terminals(&self) -> Result<()> {
+        self.inline_non_terminals.iter().try_for_each(|n| {
+            let alternatives = self
+                .productions
+                .iter()
+                .filter(|p| p.lhs == n.text())
+                .map(|p| p.rhs.0.len())
+                .sum::<usize>();
+            if alternatives == 0 {
+                bail!(ParolParserError::UnknownNonTerminal {
+                    context: "check_inline_non_terminals".to_string(),
+                    name: n.text().to_string(),
+                    input: n.location.file_name.to_path_buf(),
+                    location: n.location.clone(),
+                });
+            }
+            if alternatives > 1 {
+                bail!(ParolParserError::InvalidInlineNonTerminal {
+                    context: "check_inline_non_terminals".to_string(),
+                    name: n.text().to_string(),
+                    input: n.location.file_name.to_path_buf(),
+                    location: n.location.clone(),
+                });
+            }
+            Ok(())
+        })
+    }
+
+    fn check_display_names(&self) -> Result<()> {
+        self.display_names.iter().try_for_each(|(t, _)| {
+            if !self.is_primary_non_terminal(t) {
+                bail!(ParolParserError::InvalidDisplayNameTarget {
+                    context: "check_display_names".to_string(),
+                    token: t.text().to_string(),
+                    input: t.location.file_name.to_path_buf(),
+                    location: t.location.clone(),
+                });
+            }
+            Ok(())
+        })
+    }
+
+    fn check_offside(&self, s: &ScannerConfig) -> Result<()> {
+        if let Some((indent, dedent, newline)) = &s.offside {
+            [indent, dedent, newline].iter().try_for_each(|t| {
+                if !self.is_primary_non_terminal(t) {
+                    bail!(ParolParserError::InvalidOffsideToken {
+                        context: "check_offside".to_string(),
+                        token: t.text().to_string(),
+                        input: t.location.file_name.to_path_buf(),
+                        location: t.location.clone(),
+                    });
+                }
+                Ok(())
+            })?;
+        }
+        Ok(())
+    }
+
     fn check_transitions(&self, index: usize, s: &ScannerConfig) -> Result<()> {
         if !s.transitions.is_empty() {
             if let Some(location) = self.parser_based_scanner_switching_used() {
@@ -1104,12 +2271,31 @@ impl ParolGrammar<'_> {
         self.productions
             .iter()
             .any(|p| p.rhs.0.iter().any(|a| a.is_used_scanner(scanner_index)))
+            || self
+                .templates
+                .iter()
+                .any(|t| t.rhs.0.iter().any(|a| a.is_used_scanner(scanner_index)))
+    }
+
+    fn process_number(number: &Token) -> Result<usize> {
+        number.text().parse::<usize>().map_err(|e| {
+            parol!(
+                "{} - Invalid number {}: {}",
+                number.location,
+                number.text(),
+                e
+            )
+        })
     }
 
     fn process_grammar_type_declaration(&mut self, grammar_type: &Token) -> Result<()> {
         let grammar_type_name = grammar_type.text().to_string().to_lowercase();
         if grammar_type_name == "'lalr(1)'" {
             self.grammar_type = GrammarType::LALR1;
+        } else if grammar_type_name == "'lr(1)'" {
+            self.grammar_type = GrammarType::LR1;
+        } else if grammar_type_name == "'glr'" {
+            self.grammar_type = GrammarType::GLR;
         } else if grammar_type_name == "'ll(k)'" {
             self.grammar_type = GrammarType::LLK;
         } else {
@@ -1137,14 +2323,17 @@ impl ParolGrammar<'_> {
             .iter()
             .find(|p| p.lhs == terminal.text() && p.rhs.0.len() == 1 && p.rhs.0[0].is_terminal())
             .and_then(|p| p.rhs.0[0].terminal());
-        if let Some((tx, kind)) = term {
+        if let Some((tx, kind, context)) = term {
             // Find a terminal in the productions that matches the given terminal and is used in the
             // given scanner state
             self.productions.iter().any(|p| {
                 p.rhs.0.iter().any(|a| {
                     if a.0.len() == 1 {
-                        if let Factor::Terminal(t, k, _, _, _) = &a.0[0] {
-                            *t == tx && k.behaves_like(kind) && a.is_used_scanner(index)
+                        if let Factor::Terminal(t, k, _, _, _, c, _) = &a.0[0] {
+                            *t == tx
+                                && k.behaves_like(kind)
+                                && TrailingContext::behaves_like(c.as_ref(), context)
+                                && a.is_used_scanner(index)
                         } else {
                             false
                         }
@@ -1164,7 +2353,10 @@ impl ParolGrammar<'_> {
     /// scanner directives) is used, because the two cannot be mixed reasonably.
     fn parser_based_scanner_switching_used(&self) -> Option<parol_runtime::Location> {
         // Check if any of the productions uses scanner switching
-        self.productions.iter().find_map(|p| p.has_switch())
+        self.productions
+            .iter()
+            .find_map(|p| p.has_switch())
+            .or_else(|| self.templates.iter().find_map(|t| t.has_switch()))
     }
 }
 
@@ -1174,6 +2366,62 @@ impl Display for ParolGrammar<'_> {
         writeln!(f, "comment: {:?}", self.comment)?;
         writeln!(f, "start_symbol: {}", self.start_symbol)?;
         writeln!(f, "grammar_type: {:?}", self.grammar_type)?;
+        for level in &self.precedence_levels {
+            writeln!(f, "{}", level)?;
+        }
+        if !self.keywords.is_empty() {
+            writeln!(
+                f,
+                "keywords: {}",
+                self.keywords
+                    .iter()
+                    .map(|k| k.text())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )?;
+        }
+        if !self.derives.is_empty() {
+            writeln!(
+                f,
+                "derives: {}",
+                self.derives
+                    .iter()
+                    .map(|d| d.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )?;
+        }
+        for (n, d) in &self.non_terminal_derives {
+            writeln!(
+                f,
+                "derives of {}: {}",
+                n.text(),
+                d.iter()
+                    .map(|d| d.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )?;
+        }
+        if !self.inline_non_terminals.is_empty() {
+            writeln!(
+                f,
+                "inline: {}",
+                self.inline_non_terminals
+                    .iter()
+                    .map(|n| n.text())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )?;
+        }
+        for (t, d) in &self.display_names {
+            writeln!(f, "display name of {}: {:?}", t.text(), d)?;
+        }
+        if let Some(n) = self.expected_shift_reduce_conflicts {
+            writeln!(f, "expected shift-reduce conflicts: {}", n)?;
+        }
+        if let Some(n) = self.expected_reduce_reduce_conflicts {
+            writeln!(f, "expected reduce-reduce conflicts: {}", n)?;
+        }
         writeln!(
             f,
             "{}",
@@ -1191,7 +2439,11 @@ impl Display for ParolGrammar<'_> {
                 .map(|e| format!("{}", e))
                 .collect::<Vec<String>>()
                 .join("\n")
-        )
+        )?;
+        for template in &self.templates {
+            writeln!(f, "{}", template)?;
+        }
+        Ok(())
     }
 }
 
@@ -1204,44 +2456,43 @@ impl Default for &ParolGrammar<'_> {
 impl<'t> ParolGrammarTrait<'t> for ParolGrammar<'t> {
     /// Semantic action for non-terminal 'Production'
     fn production(&mut self, arg: &parol_grammar_trait::Production<'t>) -> Result<()> {
-        use super::parol_grammar_trait::{
-            Factor, Symbol, SymbolSimpleToken, SymbolTokenWithStates,
-        };
+        use super::parol_grammar_trait::{Factor, Symbol};
+        let production_name = &arg.production_name.identifier.identifier;
+        self.define(DefinitionKind::NonTerminal, production_name)?;
+        // Templates can't be token aliases
+        if arg.production_name.production_name_opt.is_some() {
+            return Ok(());
+        }
         // Only one alternation
         if arg.alternations.alternations_list.is_empty() {
-            // Only one factor in the single alternation
-            if arg.alternations.alternation.alternation_list.len() == 1 {
-                if let Factor::Symbol(symbol) =
-                    &arg.alternations.alternation.alternation_list[0].factor
-                {
-                    match &symbol.symbol {
-                        // Only applicable for SimpleToken ...
-                        Symbol::SimpleToken(SymbolSimpleToken { simple_token }) => {
-                            let expanded =
-                                ParolGrammar::expanded_token_literal(&simple_token.token_literal);
-                            self.handle_token_alias(
-                                arg.identifier.identifier.to_owned(),
-                                expanded,
-                            )?;
-                        }
-                        // .. and TokenWithStates!
-                        Symbol::TokenWithStates(SymbolTokenWithStates { token_with_states }) => {
-                            let expanded = ParolGrammar::expanded_token_literal(
-                                &token_with_states.token_literal,
-                            );
-                            self.handle_token_alias(
-                                arg.identifier.identifier.to_owned(),
-                                expanded,
-                            )?;
-                        }
-                        _ => (),
-                    }
-                }
+            let symbols = arg
+                .alternations
+                .alternation
+                .alternation_list
+                .iter()
+                .map(|a| match &a.factor {
+                    Factor::Symbol(symbol) => Some(&*symbol.symbol),
+                    _ => None,
+                })
+                .collect::<Vec<_>>();
+            // Only applicable for a single SimpleToken, optionally with scanner states
+            if let [Some(Symbol::SimpleToken(token))]
+            | [Some(Symbol::AngleArguments(_)), Some(Symbol::SimpleToken(token))] =
+                symbols.as_slice()
+            {
+                let expanded =
+                    ParolGrammar::expanded_token_expression(&token.simple_token.token_expression);
+                self.handle_token_alias(production_name.to_owned(), expanded)?;
             }
         }
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'ScannerState'
+    fn scanner_state(&mut self, arg: &parol_grammar_trait::ScannerState<'t>) -> Result<()> {
+        self.define(DefinitionKind::ScannerState, &arg.identifier.identifier)
+    }
+
     /// Semantic action for non-terminal 'Parol'
     fn parol(&mut self, parol: &Parol<'t>) -> Result<()> {
         self.process_parol(parol)
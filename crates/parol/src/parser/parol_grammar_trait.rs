@@ -39,6 +39,26 @@ pub trait ParolGrammarTrait<'t> {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'ExpectDirective'
+    fn expect_directive(&mut self, _arg: &ExpectDirective<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'PrecedenceDeclaration'
+    fn precedence_declaration(&mut self, _arg: &PrecedenceDeclaration<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'Associativity'
+    fn associativity(&mut self, _arg: &Associativity<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'PrecedenceSymbol'
+    fn precedence_symbol(&mut self, _arg: &PrecedenceSymbol<'t>) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'ScannerDirectives'
     fn scanner_directives(&mut self, _arg: &ScannerDirectives<'t>) -> Result<()> {
         Ok(())
@@ -54,11 +74,31 @@ pub trait ParolGrammarTrait<'t> {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'DeriveTarget'
+    fn derive_target(&mut self, _arg: &DeriveTarget<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'DeriveList'
+    fn derive_list(&mut self, _arg: &DeriveList) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'Production'
     fn production(&mut self, _arg: &Production<'t>) -> Result<()> {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'ProductionName'
+    fn production_name(&mut self, _arg: &ProductionName<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'TemplateParameters'
+    fn template_parameters(&mut self, _arg: &TemplateParameters<'t>) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'Alternations'
     fn alternations(&mut self, _arg: &Alternations<'t>) -> Result<()> {
         Ok(())
@@ -69,11 +109,21 @@ pub trait ParolGrammarTrait<'t> {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'AlternationLabel'
+    fn alternation_label(&mut self, _arg: &AlternationLabel<'t>) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'Factor'
     fn factor(&mut self, _arg: &Factor<'t>) -> Result<()> {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'FieldLabel'
+    fn field_label(&mut self, _arg: &FieldLabel<'t>) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'Symbol'
     fn symbol(&mut self, _arg: &Symbol<'t>) -> Result<()> {
         Ok(())
@@ -84,13 +134,18 @@ pub trait ParolGrammarTrait<'t> {
         Ok(())
     }
 
-    /// Semantic action for non-terminal 'SimpleToken'
-    fn simple_token(&mut self, _arg: &SimpleToken<'t>) -> Result<()> {
+    /// Semantic action for non-terminal 'TokenExpression'
+    fn token_expression(&mut self, _arg: &TokenExpression<'t>) -> Result<()> {
         Ok(())
     }
 
-    /// Semantic action for non-terminal 'TokenWithStates'
-    fn token_with_states(&mut self, _arg: &TokenWithStates<'t>) -> Result<()> {
+    /// Semantic action for non-terminal 'TrailingContext'
+    fn trailing_context(&mut self, _arg: &TrailingContext<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'SimpleToken'
+    fn simple_token(&mut self, _arg: &SimpleToken<'t>) -> Result<()> {
         Ok(())
     }
 
@@ -129,11 +184,31 @@ pub trait ParolGrammarTrait<'t> {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'AngleArguments'
+    fn angle_arguments(&mut self, _arg: &AngleArguments<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'TemplateArguments'
+    fn template_arguments(&mut self, _arg: &TemplateArguments<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'TemplateArgument'
+    fn template_argument(&mut self, _arg: &TemplateArgument<'t>) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'Identifier'
     fn identifier(&mut self, _arg: &Identifier<'t>) -> Result<()> {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'Number'
+    fn number(&mut self, _arg: &Number<'t>) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'ScannerState'
     fn scanner_state(&mut self, _arg: &ScannerState<'t>) -> Result<()> {
         Ok(())
@@ -169,6 +244,16 @@ pub trait ParolGrammarTrait<'t> {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'RepeatSeparator'
+    fn repeat_separator(&mut self, _arg: &RepeatSeparator<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'OneOrMore'
+    fn one_or_more(&mut self, _arg: &OneOrMore<'t>) -> Result<()> {
+        Ok(())
+    }
+
     /// This method provides skipped language comments.
     /// If you need comments please provide your own implementation of this method.
     fn on_comment_parsed(&mut self, _token: Token<'t>) {}
@@ -231,6 +316,93 @@ pub struct DeclarationPercentGrammarUnderscoreTypeRawString<'t> {
 ///
 /// Type derived for production 11
 ///
+/// `Declaration: '%include'^ /* Clipped */ String;`
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct DeclarationPercentIncludeString<'t> {
+    pub string: String<'t>,
+}
+
+///
+/// Type derived for production 12
+///
+/// `Declaration: '%keywords'^ /* Clipped */ IdentifierList;`
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct DeclarationPercentKeywordsIdentifierList<'t> {
+    pub identifier_list: IdentifierList<'t>,
+}
+
+///
+/// Type derived for production 13
+///
+/// `Declaration: '%derive'^ /* Clipped */ DeclarationOpt /* Option */ DeriveList;`
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct DeclarationPercentDeriveDeclarationOptDeriveList<'t> {
+    pub declaration_opt: Option<DeclarationOpt<'t>>,
+    pub derive_list: DeriveList,
+}
+
+///
+/// Type derived for production 14
+///
+/// `Declaration: '%inline'^ /* Clipped */ IdentifierList;`
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct DeclarationPercentInlineIdentifierList<'t> {
+    pub identifier_list: IdentifierList<'t>,
+}
+
+///
+/// Type derived for production 15
+///
+/// `Declaration: '%display'^ /* Clipped */ Identifier String;`
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct DeclarationPercentDisplayIdentifierString<'t> {
+    pub identifier: Identifier<'t>,
+    pub string: String<'t>,
+}
+
+///
+/// Type derived for production 16
+///
+/// `Declaration: ExpectDirective Number;`
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct DeclarationExpectDirectiveNumber<'t> {
+    pub expect_directive: ExpectDirective<'t>,
+    pub number: Number<'t>,
+}
+
+///
+/// Type derived for production 17
+///
+/// `Declaration: PrecedenceDeclaration;`
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct DeclarationPrecedenceDeclaration<'t> {
+    pub precedence_declaration: PrecedenceDeclaration<'t>,
+}
+
+///
+/// Type derived for production 18
+///
 /// `Declaration: ScannerDirectives;`
 ///
 #[allow(dead_code)]
@@ -241,7 +413,67 @@ pub struct DeclarationScannerDirectives<'t> {
 }
 
 ///
-/// Type derived for production 12
+/// Type derived for production 25
+///
+/// `Associativity: '%left';`
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct AssociativityPercentLeft<'t> {
+    pub percent_left: Token<'t>, /* %left */
+}
+
+///
+/// Type derived for production 26
+///
+/// `Associativity: '%right';`
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct AssociativityPercentRight<'t> {
+    pub percent_right: Token<'t>, /* %right */
+}
+
+///
+/// Type derived for production 27
+///
+/// `Associativity: '%nonassoc';`
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct AssociativityPercentNonassoc<'t> {
+    pub percent_nonassoc: Token<'t>, /* %nonassoc */
+}
+
+///
+/// Type derived for production 28
+///
+/// `PrecedenceSymbol: TokenLiteral;`
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct PrecedenceSymbolTokenLiteral<'t> {
+    pub token_literal: TokenLiteral<'t>,
+}
+
+///
+/// Type derived for production 29
+///
+/// `PrecedenceSymbol: Identifier;`
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct PrecedenceSymbolIdentifier<'t> {
+    pub identifier: Identifier<'t>,
+}
+
+///
+/// Type derived for production 30
 ///
 /// `ScannerDirectives: '%line_comment'^ /* Clipped */ TokenLiteral;`
 ///
@@ -253,7 +485,7 @@ pub struct ScannerDirectivesPercentLineUnderscoreCommentTokenLiteral<'t> {
 }
 
 ///
-/// Type derived for production 13
+/// Type derived for production 31
 ///
 /// `ScannerDirectives: '%block_comment'^ /* Clipped */ TokenLiteral TokenLiteral;`
 ///
@@ -266,7 +498,22 @@ pub struct ScannerDirectivesPercentBlockUnderscoreCommentTokenLiteralTokenLitera
 }
 
 ///
-/// Type derived for production 14
+/// Type derived for production 32
+///
+/// `ScannerDirectives: '%nested_block_comment'^ /* Clipped */ TokenLiteral TokenLiteral;`
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct ScannerDirectivesPercentNestedUnderscoreBlockUnderscoreCommentTokenLiteralTokenLiteral<
+    't,
+> {
+    pub token_literal: TokenLiteral<'t>,
+    pub token_literal0: TokenLiteral<'t>,
+}
+
+///
+/// Type derived for production 33
 ///
 /// `ScannerDirectives: '%auto_newline_off'^ /* Clipped */;`
 ///
@@ -276,7 +523,7 @@ pub struct ScannerDirectivesPercentBlockUnderscoreCommentTokenLiteralTokenLitera
 pub struct ScannerDirectivesPercentAutoUnderscoreNewlineUnderscoreOff {}
 
 ///
-/// Type derived for production 15
+/// Type derived for production 34
 ///
 /// `ScannerDirectives: '%auto_ws_off'^ /* Clipped */;`
 ///
@@ -286,7 +533,41 @@ pub struct ScannerDirectivesPercentAutoUnderscoreNewlineUnderscoreOff {}
 pub struct ScannerDirectivesPercentAutoUnderscoreWsUnderscoreOff {}
 
 ///
-/// Type derived for production 16
+/// Type derived for production 35
+///
+/// `ScannerDirectives: '%longest_match'^ /* Clipped */;`
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct ScannerDirectivesPercentLongestUnderscoreMatch {}
+
+///
+/// Type derived for production 36
+///
+/// `ScannerDirectives: '%case_insensitive'^ /* Clipped */;`
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct ScannerDirectivesPercentCaseUnderscoreInsensitive {}
+
+///
+/// Type derived for production 37
+///
+/// `ScannerDirectives: '%offside'^ /* Clipped */ Identifier Identifier Identifier;`
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct ScannerDirectivesPercentOffsideIdentifierIdentifierIdentifier<'t> {
+    pub identifier: Identifier<'t>,
+    pub identifier0: Identifier<'t>,
+    pub identifier1: Identifier<'t>,
+}
+
+///
+/// Type derived for production 38
 ///
 /// `ScannerDirectives: '%on'^ /* Clipped */ IdentifierList '%enter'^ /* Clipped */ Identifier;`
 ///
@@ -299,7 +580,7 @@ pub struct ScannerDirectivesPercentOnIdentifierListPercentEnterIdentifier<'t> {
 }
 
 ///
-/// Type derived for production 28
+/// Type derived for production 63
 ///
 /// `Factor: Group;`
 ///
@@ -311,7 +592,7 @@ pub struct FactorGroup<'t> {
 }
 
 ///
-/// Type derived for production 29
+/// Type derived for production 64
 ///
 /// `Factor: Repeat;`
 ///
@@ -319,11 +600,11 @@ pub struct FactorGroup<'t> {
 #[derive(Builder, Debug, Clone)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct FactorRepeat<'t> {
-    pub repeat: Repeat<'t>,
+    pub repeat: Box<Repeat<'t>>,
 }
 
 ///
-/// Type derived for production 30
+/// Type derived for production 65
 ///
 /// `Factor: Optional;`
 ///
@@ -335,7 +616,7 @@ pub struct FactorOptional<'t> {
 }
 
 ///
-/// Type derived for production 31
+/// Type derived for production 66
 ///
 /// `Factor: Symbol;`
 ///
@@ -343,11 +624,24 @@ pub struct FactorOptional<'t> {
 #[derive(Builder, Debug, Clone)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct FactorSymbol<'t> {
-    pub symbol: Symbol<'t>,
+    pub symbol: Box<Symbol<'t>>,
 }
 
 ///
-/// Type derived for production 32
+/// Type derived for production 67
+///
+/// `Factor: FieldLabel Symbol;`
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct FactorFieldLabelSymbol<'t> {
+    pub field_label: FieldLabel<'t>,
+    pub symbol: Box<Symbol<'t>>,
+}
+
+///
+/// Type derived for production 69
 ///
 /// `Symbol: NonTerminal;`
 ///
@@ -359,7 +653,7 @@ pub struct SymbolNonTerminal<'t> {
 }
 
 ///
-/// Type derived for production 33
+/// Type derived for production 70
 ///
 /// `Symbol: SimpleToken;`
 ///
@@ -371,19 +665,19 @@ pub struct SymbolSimpleToken<'t> {
 }
 
 ///
-/// Type derived for production 34
+/// Type derived for production 71
 ///
-/// `Symbol: TokenWithStates;`
+/// `Symbol: AngleArguments;`
 ///
 #[allow(dead_code)]
 #[derive(Builder, Debug, Clone)]
 #[builder(crate = "parol_runtime::derive_builder")]
-pub struct SymbolTokenWithStates<'t> {
-    pub token_with_states: TokenWithStates<'t>,
+pub struct SymbolAngleArguments<'t> {
+    pub angle_arguments: AngleArguments<'t>,
 }
 
 ///
-/// Type derived for production 35
+/// Type derived for production 72
 ///
 /// `Symbol: ScannerSwitch;`
 ///
@@ -395,7 +689,7 @@ pub struct SymbolScannerSwitch<'t> {
 }
 
 ///
-/// Type derived for production 36
+/// Type derived for production 73
 ///
 /// `TokenLiteral: String;`
 ///
@@ -407,7 +701,7 @@ pub struct TokenLiteralString<'t> {
 }
 
 ///
-/// Type derived for production 37
+/// Type derived for production 74
 ///
 /// `TokenLiteral: RawString;`
 ///
@@ -419,7 +713,7 @@ pub struct TokenLiteralRawString<'t> {
 }
 
 ///
-/// Type derived for production 38
+/// Type derived for production 75
 ///
 /// `TokenLiteral: Regex;`
 ///
@@ -431,7 +725,7 @@ pub struct TokenLiteralRegex<'t> {
 }
 
 ///
-/// Type derived for production 61
+/// Type derived for production 113
 ///
 /// `ScannerSwitch: '%sc' '('^ /* Clipped */ ScannerSwitchOpt /* Option */ ')'^ /* Clipped */;`
 ///
@@ -444,7 +738,7 @@ pub struct ScannerSwitchPercentScLParenScannerSwitchOptRParen<'t> {
 }
 
 ///
-/// Type derived for production 62
+/// Type derived for production 114
 ///
 /// `ScannerSwitch: '%push' '('^ /* Clipped */ Identifier ')'^ /* Clipped */;`
 ///
@@ -457,7 +751,7 @@ pub struct ScannerSwitchPercentPushLParenIdentifierRParen<'t> {
 }
 
 ///
-/// Type derived for production 63
+/// Type derived for production 115
 ///
 /// `ScannerSwitch: '%pop' '('^ /* Clipped */ ')'^ /* Clipped */;`
 ///
@@ -469,7 +763,7 @@ pub struct ScannerSwitchPercentPopLParenRParen<'t> {
 }
 
 ///
-/// Type derived for production 66
+/// Type derived for production 118
 ///
 /// `ASTControl: CutOperator;`
 ///
@@ -481,7 +775,7 @@ pub struct ASTControlCutOperator {
 }
 
 ///
-/// Type derived for production 67
+/// Type derived for production 119
 ///
 /// `ASTControl: UserTypeDeclaration;`
 ///
@@ -515,6 +809,18 @@ pub enum ASTControl {
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Alternation<'t> {
     pub alternation_list: Vec<AlternationList<'t>>,
+    pub alternation_opt: Option<AlternationOpt<'t>>,
+    pub alternation_opt0: Option<AlternationOpt0<'t>>,
+}
+
+///
+/// Type derived for non-terminal AlternationLabel
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct AlternationLabel<'t> {
+    pub identifier: Identifier<'t>,
 }
 
 ///
@@ -528,33 +834,86 @@ pub struct AlternationList<'t> {
 }
 
 ///
-/// Type derived for non-terminal Alternations
+/// Type derived for non-terminal AlternationOpt
 ///
 #[allow(dead_code)]
 #[derive(Builder, Debug, Clone)]
 #[builder(crate = "parol_runtime::derive_builder")]
-pub struct Alternations<'t> {
-    pub alternation: Alternation<'t>,
-    pub alternations_list: Vec<AlternationsList<'t>>,
+pub struct AlternationOpt<'t> {
+    pub precedence_symbol: PrecedenceSymbol<'t>,
 }
 
 ///
-/// Type derived for non-terminal AlternationsList
+/// Type derived for non-terminal AlternationOpt0
 ///
 #[allow(dead_code)]
 #[derive(Builder, Debug, Clone)]
 #[builder(crate = "parol_runtime::derive_builder")]
-pub struct AlternationsList<'t> {
-    pub alternation: Alternation<'t>,
+pub struct AlternationOpt0<'t> {
+    pub alternation_label: AlternationLabel<'t>,
 }
 
 ///
-/// Type derived for non-terminal CutOperator
+/// Type derived for non-terminal Alternations
 ///
 #[allow(dead_code)]
 #[derive(Builder, Debug, Clone)]
 #[builder(crate = "parol_runtime::derive_builder")]
-pub struct CutOperator {}
+pub struct Alternations<'t> {
+    pub alternation: Alternation<'t>,
+    pub alternations_list: Vec<AlternationsList<'t>>,
+}
+
+///
+/// Type derived for non-terminal AlternationsList
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct AlternationsList<'t> {
+    pub alternation: Alternation<'t>,
+}
+
+///
+/// Type derived for non-terminal AngleArguments
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct AngleArguments<'t> {
+    pub l_t: Token<'t>, /* < */
+    pub template_arguments: TemplateArguments<'t>,
+    pub angle_arguments_opt: Option<AngleArgumentsOpt>,
+}
+
+///
+/// Type derived for non-terminal AngleArgumentsOpt
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct AngleArgumentsOpt {
+    pub a_s_t_control: ASTControl,
+}
+
+///
+/// Type derived for non-terminal Associativity
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub enum Associativity<'t> {
+    PercentLeft(AssociativityPercentLeft<'t>),
+    PercentRight(AssociativityPercentRight<'t>),
+    PercentNonassoc(AssociativityPercentNonassoc<'t>),
+}
+
+///
+/// Type derived for non-terminal CutOperator
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct CutOperator {}
 
 ///
 /// Type derived for non-terminal Declaration
@@ -568,9 +927,57 @@ pub enum Declaration<'t> {
         DeclarationPercentUserUnderscoreTypeIdentifierEquUserTypeName<'t>,
     ),
     PercentGrammarUnderscoreTypeRawString(DeclarationPercentGrammarUnderscoreTypeRawString<'t>),
+    PercentIncludeString(DeclarationPercentIncludeString<'t>),
+    PercentKeywordsIdentifierList(DeclarationPercentKeywordsIdentifierList<'t>),
+    PercentDeriveDeclarationOptDeriveList(DeclarationPercentDeriveDeclarationOptDeriveList<'t>),
+    PercentInlineIdentifierList(DeclarationPercentInlineIdentifierList<'t>),
+    PercentDisplayIdentifierString(DeclarationPercentDisplayIdentifierString<'t>),
+    ExpectDirectiveNumber(DeclarationExpectDirectiveNumber<'t>),
+    PrecedenceDeclaration(DeclarationPrecedenceDeclaration<'t>),
     ScannerDirectives(DeclarationScannerDirectives<'t>),
 }
 
+///
+/// Type derived for non-terminal DeclarationOpt
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct DeclarationOpt<'t> {
+    pub derive_target: DeriveTarget<'t>,
+}
+
+///
+/// Type derived for non-terminal DeriveList
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct DeriveList {
+    pub user_type_name: crate::parser::parol_grammar::UserDefinedTypeName,
+    pub derive_list_list: Vec<DeriveListList>,
+}
+
+///
+/// Type derived for non-terminal DeriveListList
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct DeriveListList {
+    pub user_type_name: crate::parser::parol_grammar::UserDefinedTypeName,
+}
+
+///
+/// Type derived for non-terminal DeriveTarget
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct DeriveTarget<'t> {
+    pub identifier: Identifier<'t>,
+}
+
 ///
 /// Type derived for non-terminal DoubleColon
 ///
@@ -581,6 +988,16 @@ pub struct DoubleColon<'t> {
     pub double_colon: Token<'t>, /* :: */
 }
 
+///
+/// Type derived for non-terminal ExpectDirective
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct ExpectDirective<'t> {
+    pub expect_directive: Token<'t>, /* %expect(-rr)? */
+}
+
 ///
 /// Type derived for non-terminal Factor
 ///
@@ -591,6 +1008,17 @@ pub enum Factor<'t> {
     Repeat(FactorRepeat<'t>),
     Optional(FactorOptional<'t>),
     Symbol(FactorSymbol<'t>),
+    FieldLabelSymbol(FactorFieldLabelSymbol<'t>),
+}
+
+///
+/// Type derived for non-terminal FieldLabel
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct FieldLabel<'t> {
+    pub identifier: Identifier<'t>,
 }
 
 ///
@@ -678,6 +1106,26 @@ pub struct NonTerminalOpt {
     pub a_s_t_control: ASTControl,
 }
 
+///
+/// Type derived for non-terminal Number
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct Number<'t> {
+    pub number: Token<'t>, /* [0-9]+ */
+}
+
+///
+/// Type derived for non-terminal OneOrMore
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct OneOrMore<'t> {
+    pub one_or_more: Token<'t>, /* + */
+}
+
 ///
 /// Type derived for non-terminal Optional
 ///
@@ -701,6 +1149,38 @@ pub struct Parol<'t> {
     pub grammar_definition: GrammarDefinition<'t>,
 }
 
+///
+/// Type derived for non-terminal PrecedenceDeclaration
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct PrecedenceDeclaration<'t> {
+    pub associativity: Associativity<'t>,
+    pub precedence_symbol: PrecedenceSymbol<'t>,
+    pub precedence_declaration_list: Vec<PrecedenceDeclarationList<'t>>,
+}
+
+///
+/// Type derived for non-terminal PrecedenceDeclarationList
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct PrecedenceDeclarationList<'t> {
+    pub precedence_symbol: PrecedenceSymbol<'t>,
+}
+
+///
+/// Type derived for non-terminal PrecedenceSymbol
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub enum PrecedenceSymbol<'t> {
+    TokenLiteral(PrecedenceSymbolTokenLiteral<'t>),
+    Identifier(PrecedenceSymbolIdentifier<'t>),
+}
+
 ///
 /// Type derived for non-terminal Production
 ///
@@ -708,10 +1188,31 @@ pub struct Parol<'t> {
 #[derive(Builder, Debug, Clone)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Production<'t> {
-    pub identifier: Identifier<'t>,
+    pub production_name: ProductionName<'t>,
     pub alternations: Alternations<'t>,
 }
 
+///
+/// Type derived for non-terminal ProductionName
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct ProductionName<'t> {
+    pub identifier: Identifier<'t>,
+    pub production_name_opt: Option<ProductionNameOpt<'t>>,
+}
+
+///
+/// Type derived for non-terminal ProductionNameOpt
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct ProductionNameOpt<'t> {
+    pub template_parameters: TemplateParameters<'t>,
+}
+
 ///
 /// Type derived for non-terminal Prolog
 ///
@@ -751,7 +1252,7 @@ pub struct PrologList0 {
 #[derive(Builder, Debug, Clone)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct RawString<'t> {
-    pub raw_string: Token<'t>, /* '(\\'|[^'])*?' */
+    pub raw_string: Token<'t>, /* '(\\'|[^'])*?'i? */
 }
 
 ///
@@ -761,7 +1262,7 @@ pub struct RawString<'t> {
 #[derive(Builder, Debug, Clone)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Regex<'t> {
-    pub regex: Token<'t>, /* \u{2F}(\\.|[^\\])*?\u{2F} */
+    pub regex: Token<'t>, /* \u{2F}(\\.|[^\\])*?\u{2F}i? */
 }
 
 ///
@@ -773,7 +1274,51 @@ pub struct Regex<'t> {
 pub struct Repeat<'t> {
     pub l_brace: Token<'t>, /* { */
     pub alternations: Alternations<'t>,
+    pub repeat_opt: Option<RepeatOpt<'t>>,
     pub r_brace: Token<'t>, /* } */
+    pub repeat_opt0: Option<RepeatOpt0<'t>>,
+}
+
+///
+/// Type derived for non-terminal RepeatOpt
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct RepeatOpt<'t> {
+    pub repeat_separator: RepeatSeparator<'t>,
+}
+
+///
+/// Type derived for non-terminal RepeatOpt0
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct RepeatOpt0<'t> {
+    pub one_or_more: OneOrMore<'t>,
+}
+
+///
+/// Type derived for non-terminal RepeatSeparator
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct RepeatSeparator<'t> {
+    pub percent: Token<'t>, /* % */
+    pub symbol: Symbol<'t>,
+    pub repeat_separator_list: Vec<RepeatSeparatorList<'t>>,
+}
+
+///
+/// Type derived for non-terminal RepeatSeparatorList
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct RepeatSeparatorList<'t> {
+    pub symbol: Symbol<'t>,
 }
 
 ///
@@ -788,10 +1333,18 @@ pub enum ScannerDirectives<'t> {
     PercentBlockUnderscoreCommentTokenLiteralTokenLiteral(
         ScannerDirectivesPercentBlockUnderscoreCommentTokenLiteralTokenLiteral<'t>,
     ),
+    PercentNestedUnderscoreBlockUnderscoreCommentTokenLiteralTokenLiteral(
+        ScannerDirectivesPercentNestedUnderscoreBlockUnderscoreCommentTokenLiteralTokenLiteral<'t>,
+    ),
     PercentAutoUnderscoreNewlineUnderscoreOff(
         ScannerDirectivesPercentAutoUnderscoreNewlineUnderscoreOff,
     ),
     PercentAutoUnderscoreWsUnderscoreOff(ScannerDirectivesPercentAutoUnderscoreWsUnderscoreOff),
+    PercentLongestUnderscoreMatch(ScannerDirectivesPercentLongestUnderscoreMatch),
+    PercentCaseUnderscoreInsensitive(ScannerDirectivesPercentCaseUnderscoreInsensitive),
+    PercentOffsideIdentifierIdentifierIdentifier(
+        ScannerDirectivesPercentOffsideIdentifierIdentifierIdentifier<'t>,
+    ),
     PercentOnIdentifierListPercentEnterIdentifier(
         ScannerDirectivesPercentOnIdentifierListPercentEnterIdentifier<'t>,
     ),
@@ -846,7 +1399,7 @@ pub struct ScannerSwitchOpt<'t> {
 #[derive(Builder, Debug, Clone)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct SimpleToken<'t> {
-    pub token_literal: TokenLiteral<'t>,
+    pub token_expression: TokenExpression<'t>,
     pub simple_token_opt: Option<SimpleTokenOpt>,
 }
 
@@ -877,7 +1430,7 @@ pub struct StartDeclaration<'t> {
 #[derive(Builder, Debug, Clone)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct String<'t> {
-    pub string: Token<'t>, /* "(\\.|[^\\])*?" */
+    pub string: Token<'t>, /* "(\\.|[^\\])*?"i? */
 }
 
 ///
@@ -888,41 +1441,102 @@ pub struct String<'t> {
 pub enum Symbol<'t> {
     NonTerminal(SymbolNonTerminal<'t>),
     SimpleToken(SymbolSimpleToken<'t>),
-    TokenWithStates(SymbolTokenWithStates<'t>),
+    AngleArguments(SymbolAngleArguments<'t>),
     ScannerSwitch(SymbolScannerSwitch<'t>),
 }
 
 ///
-/// Type derived for non-terminal TokenLiteral
+/// Type derived for non-terminal TemplateArgument
 ///
 #[allow(dead_code)]
-#[derive(Debug, Clone)]
-pub enum TokenLiteral<'t> {
-    String(TokenLiteralString<'t>),
-    RawString(TokenLiteralRawString<'t>),
-    Regex(TokenLiteralRegex<'t>),
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct TemplateArgument<'t> {
+    pub factor: Factor<'t>,
+    pub template_argument_list: Vec<TemplateArgumentList<'t>>,
+}
+
+///
+/// Type derived for non-terminal TemplateArgumentList
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct TemplateArgumentList<'t> {
+    pub factor: Factor<'t>,
+}
+
+///
+/// Type derived for non-terminal TemplateArguments
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct TemplateArguments<'t> {
+    pub template_argument: TemplateArgument<'t>,
+    pub template_arguments_list: Vec<TemplateArgumentsList<'t>>,
+}
+
+///
+/// Type derived for non-terminal TemplateArgumentsList
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct TemplateArgumentsList<'t> {
+    pub template_argument: TemplateArgument<'t>,
 }
 
 ///
-/// Type derived for non-terminal TokenWithStates
+/// Type derived for non-terminal TemplateParameters
 ///
 #[allow(dead_code)]
 #[derive(Builder, Debug, Clone)]
 #[builder(crate = "parol_runtime::derive_builder")]
-pub struct TokenWithStates<'t> {
+pub struct TemplateParameters<'t> {
     pub identifier_list: IdentifierList<'t>,
+}
+
+///
+/// Type derived for non-terminal TokenExpression
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct TokenExpression<'t> {
     pub token_literal: TokenLiteral<'t>,
-    pub token_with_states_opt: Option<TokenWithStatesOpt>,
+    pub token_expression_opt: Option<TokenExpressionOpt<'t>>,
 }
 
 ///
-/// Type derived for non-terminal TokenWithStatesOpt
+/// Type derived for non-terminal TokenExpressionOpt
 ///
 #[allow(dead_code)]
 #[derive(Builder, Debug, Clone)]
 #[builder(crate = "parol_runtime::derive_builder")]
-pub struct TokenWithStatesOpt {
-    pub a_s_t_control: ASTControl,
+pub struct TokenExpressionOpt<'t> {
+    pub trailing_context: TrailingContext<'t>,
+}
+
+///
+/// Type derived for non-terminal TokenLiteral
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub enum TokenLiteral<'t> {
+    String(TokenLiteralString<'t>),
+    RawString(TokenLiteralRawString<'t>),
+    Regex(TokenLiteralRegex<'t>),
+}
+
+///
+/// Type derived for non-terminal TrailingContext
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct TrailingContext<'t> {
+    pub token_literal: TokenLiteral<'t>,
 }
 
 ///
@@ -966,13 +1580,25 @@ pub struct UserTypeNameList<'t> {
 pub enum ASTType<'t> {
     ASTControl(ASTControl),
     Alternation(Alternation<'t>),
+    AlternationLabel(AlternationLabel<'t>),
     AlternationList(Vec<AlternationList<'t>>),
+    AlternationOpt(Option<AlternationOpt<'t>>),
+    AlternationOpt0(Option<AlternationOpt0<'t>>),
     Alternations(Alternations<'t>),
     AlternationsList(Vec<AlternationsList<'t>>),
+    AngleArguments(AngleArguments<'t>),
+    AngleArgumentsOpt(Option<AngleArgumentsOpt>),
+    Associativity(Associativity<'t>),
     CutOperator(CutOperator),
     Declaration(Declaration<'t>),
+    DeclarationOpt(Option<DeclarationOpt<'t>>),
+    DeriveList(DeriveList),
+    DeriveListList(Vec<DeriveListList>),
+    DeriveTarget(DeriveTarget<'t>),
     DoubleColon(DoubleColon<'t>),
+    ExpectDirective(ExpectDirective<'t>),
     Factor(Factor<'t>),
+    FieldLabel(FieldLabel<'t>),
     GrammarDefinition(GrammarDefinition<'t>),
     GrammarDefinitionList(Vec<GrammarDefinitionList<'t>>),
     Group(Group<'t>),
@@ -981,15 +1607,26 @@ pub enum ASTType<'t> {
     IdentifierListList(Vec<IdentifierListList<'t>>),
     NonTerminal(NonTerminal<'t>),
     NonTerminalOpt(Option<NonTerminalOpt>),
+    Number(Number<'t>),
+    OneOrMore(OneOrMore<'t>),
     Optional(Optional<'t>),
     Parol(Parol<'t>),
+    PrecedenceDeclaration(PrecedenceDeclaration<'t>),
+    PrecedenceDeclarationList(Vec<PrecedenceDeclarationList<'t>>),
+    PrecedenceSymbol(PrecedenceSymbol<'t>),
     Production(Production<'t>),
+    ProductionName(ProductionName<'t>),
+    ProductionNameOpt(Option<ProductionNameOpt<'t>>),
     Prolog(Prolog<'t>),
     PrologList(Vec<PrologList<'t>>),
     PrologList0(Vec<PrologList0>),
     RawString(RawString<'t>),
     Regex(Regex<'t>),
     Repeat(Repeat<'t>),
+    RepeatOpt(Option<RepeatOpt<'t>>),
+    RepeatOpt0(Option<RepeatOpt0<'t>>),
+    RepeatSeparator(RepeatSeparator<'t>),
+    RepeatSeparatorList(Vec<RepeatSeparatorList<'t>>),
     ScannerDirectives(ScannerDirectives<'t>),
     ScannerState(ScannerState<'t>),
     ScannerStateList(Vec<ScannerStateList<'t>>),
@@ -1000,9 +1637,15 @@ pub enum ASTType<'t> {
     StartDeclaration(StartDeclaration<'t>),
     String(String<'t>),
     Symbol(Symbol<'t>),
+    TemplateArgument(TemplateArgument<'t>),
+    TemplateArgumentList(Vec<TemplateArgumentList<'t>>),
+    TemplateArguments(TemplateArguments<'t>),
+    TemplateArgumentsList(Vec<TemplateArgumentsList<'t>>),
+    TemplateParameters(TemplateParameters<'t>),
+    TokenExpression(TokenExpression<'t>),
+    TokenExpressionOpt(Option<TokenExpressionOpt<'t>>),
     TokenLiteral(TokenLiteral<'t>),
-    TokenWithStates(TokenWithStates<'t>),
-    TokenWithStatesOpt(Option<TokenWithStatesOpt>),
+    TrailingContext(TrailingContext<'t>),
     UserTypeDeclaration(UserTypeDeclaration),
     UserTypeName(UserTypeName<'t>),
     UserTypeNameList(Vec<UserTypeNameList<'t>>),
@@ -1307,15 +1950,19 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
 
     /// Semantic action for production 11:
     ///
-    /// `Declaration: ScannerDirectives;`
+    /// `Declaration: '%include'^ /* Clipped */ String;`
     ///
     #[parol_runtime::function_name::named]
-    fn declaration_4(&mut self, _scanner_directives: &ParseTreeType<'t>) -> Result<()> {
+    fn declaration_4(
+        &mut self,
+        _percent_include: &ParseTreeType<'t>,
+        _string: &ParseTreeType<'t>,
+    ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let scanner_directives = pop_item!(self, scanner_directives, ScannerDirectives, context);
-        let declaration_4_built = DeclarationScannerDirectives { scanner_directives };
-        let declaration_4_built = Declaration::ScannerDirectives(declaration_4_built);
+        let string = pop_item!(self, string, String, context);
+        let declaration_4_built = DeclarationPercentIncludeString { string };
+        let declaration_4_built = Declaration::PercentIncludeString(declaration_4_built);
         // Calling user action here
         self.user_grammar.declaration(&declaration_4_built)?;
         self.push(ASTType::Declaration(declaration_4_built), context);
@@ -1324,39 +1971,447 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
 
     /// Semantic action for production 12:
     ///
-    /// `ScannerDirectives: '%line_comment'^ /* Clipped */ TokenLiteral;`
+    /// `Declaration: '%keywords'^ /* Clipped */ IdentifierList;`
     ///
     #[parol_runtime::function_name::named]
-    fn scanner_directives_0(
+    fn declaration_5(
         &mut self,
-        _percent_line_underscore_comment: &ParseTreeType<'t>,
-        _token_literal: &ParseTreeType<'t>,
+        _percent_keywords: &ParseTreeType<'t>,
+        _identifier_list: &ParseTreeType<'t>,
     ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let token_literal = pop_item!(self, token_literal, TokenLiteral, context);
-        let scanner_directives_0_built =
-            ScannerDirectivesPercentLineUnderscoreCommentTokenLiteral { token_literal };
-        let scanner_directives_0_built =
-            ScannerDirectives::PercentLineUnderscoreCommentTokenLiteral(scanner_directives_0_built);
+        let identifier_list = pop_item!(self, identifier_list, IdentifierList, context);
+        let declaration_5_built = DeclarationPercentKeywordsIdentifierList { identifier_list };
+        let declaration_5_built = Declaration::PercentKeywordsIdentifierList(declaration_5_built);
         // Calling user action here
-        self.user_grammar
-            .scanner_directives(&scanner_directives_0_built)?;
-        self.push(
-            ASTType::ScannerDirectives(scanner_directives_0_built),
-            context,
-        );
+        self.user_grammar.declaration(&declaration_5_built)?;
+        self.push(ASTType::Declaration(declaration_5_built), context);
         Ok(())
     }
 
     /// Semantic action for production 13:
     ///
-    /// `ScannerDirectives: '%block_comment'^ /* Clipped */ TokenLiteral TokenLiteral;`
+    /// `Declaration: '%derive'^ /* Clipped */ DeclarationOpt /* Option */ DeriveList;`
     ///
     #[parol_runtime::function_name::named]
-    fn scanner_directives_1(
+    fn declaration_6(
         &mut self,
-        _percent_block_underscore_comment: &ParseTreeType<'t>,
+        _percent_derive: &ParseTreeType<'t>,
+        _declaration_opt: &ParseTreeType<'t>,
+        _derive_list: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let derive_list = pop_item!(self, derive_list, DeriveList, context);
+        let declaration_opt = pop_item!(self, declaration_opt, DeclarationOpt, context);
+        let declaration_6_built = DeclarationPercentDeriveDeclarationOptDeriveList {
+            declaration_opt,
+            derive_list,
+        };
+        let declaration_6_built =
+            Declaration::PercentDeriveDeclarationOptDeriveList(declaration_6_built);
+        // Calling user action here
+        self.user_grammar.declaration(&declaration_6_built)?;
+        self.push(ASTType::Declaration(declaration_6_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 14:
+    ///
+    /// `Declaration: '%inline'^ /* Clipped */ IdentifierList;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn declaration_7(
+        &mut self,
+        _percent_inline: &ParseTreeType<'t>,
+        _identifier_list: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let identifier_list = pop_item!(self, identifier_list, IdentifierList, context);
+        let declaration_7_built = DeclarationPercentInlineIdentifierList { identifier_list };
+        let declaration_7_built = Declaration::PercentInlineIdentifierList(declaration_7_built);
+        // Calling user action here
+        self.user_grammar.declaration(&declaration_7_built)?;
+        self.push(ASTType::Declaration(declaration_7_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 15:
+    ///
+    /// `Declaration: '%display'^ /* Clipped */ Identifier String;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn declaration_8(
+        &mut self,
+        _percent_display: &ParseTreeType<'t>,
+        _identifier: &ParseTreeType<'t>,
+        _string: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let string = pop_item!(self, string, String, context);
+        let identifier = pop_item!(self, identifier, Identifier, context);
+        let declaration_8_built = DeclarationPercentDisplayIdentifierString { identifier, string };
+        let declaration_8_built = Declaration::PercentDisplayIdentifierString(declaration_8_built);
+        // Calling user action here
+        self.user_grammar.declaration(&declaration_8_built)?;
+        self.push(ASTType::Declaration(declaration_8_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 16:
+    ///
+    /// `Declaration: ExpectDirective Number;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn declaration_9(
+        &mut self,
+        _expect_directive: &ParseTreeType<'t>,
+        _number: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let number = pop_item!(self, number, Number, context);
+        let expect_directive = pop_item!(self, expect_directive, ExpectDirective, context);
+        let declaration_9_built = DeclarationExpectDirectiveNumber {
+            expect_directive,
+            number,
+        };
+        let declaration_9_built = Declaration::ExpectDirectiveNumber(declaration_9_built);
+        // Calling user action here
+        self.user_grammar.declaration(&declaration_9_built)?;
+        self.push(ASTType::Declaration(declaration_9_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 17:
+    ///
+    /// `Declaration: PrecedenceDeclaration;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn declaration_10(&mut self, _precedence_declaration: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let precedence_declaration =
+            pop_item!(self, precedence_declaration, PrecedenceDeclaration, context);
+        let declaration_10_built = DeclarationPrecedenceDeclaration {
+            precedence_declaration,
+        };
+        let declaration_10_built = Declaration::PrecedenceDeclaration(declaration_10_built);
+        // Calling user action here
+        self.user_grammar.declaration(&declaration_10_built)?;
+        self.push(ASTType::Declaration(declaration_10_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 18:
+    ///
+    /// `Declaration: ScannerDirectives;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn declaration_11(&mut self, _scanner_directives: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let scanner_directives = pop_item!(self, scanner_directives, ScannerDirectives, context);
+        let declaration_11_built = DeclarationScannerDirectives { scanner_directives };
+        let declaration_11_built = Declaration::ScannerDirectives(declaration_11_built);
+        // Calling user action here
+        self.user_grammar.declaration(&declaration_11_built)?;
+        self.push(ASTType::Declaration(declaration_11_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 19:
+    ///
+    /// `DeclarationOpt /* Option<T>::Some */: DeriveTarget;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn declaration_opt_0(&mut self, _derive_target: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let derive_target = pop_item!(self, derive_target, DeriveTarget, context);
+        let declaration_opt_0_built = DeclarationOpt { derive_target };
+        self.push(
+            ASTType::DeclarationOpt(Some(declaration_opt_0_built)),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 20:
+    ///
+    /// `DeclarationOpt /* Option<T>::None */: ;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn declaration_opt_1(&mut self) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        self.push(ASTType::DeclarationOpt(None), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 21:
+    ///
+    /// `ExpectDirective: /%expect(-rr)?/;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn expect_directive(&mut self, expect_directive: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let expect_directive = expect_directive.token()?.clone();
+        let expect_directive_built = ExpectDirective { expect_directive };
+        // Calling user action here
+        self.user_grammar
+            .expect_directive(&expect_directive_built)?;
+        self.push(ASTType::ExpectDirective(expect_directive_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 22:
+    ///
+    /// `PrecedenceDeclaration: Associativity PrecedenceSymbol PrecedenceDeclarationList /* Vec */;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn precedence_declaration(
+        &mut self,
+        _associativity: &ParseTreeType<'t>,
+        _precedence_symbol: &ParseTreeType<'t>,
+        _precedence_declaration_list: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let precedence_declaration_list = pop_and_reverse_item!(
+            self,
+            precedence_declaration_list,
+            PrecedenceDeclarationList,
+            context
+        );
+        let precedence_symbol = pop_item!(self, precedence_symbol, PrecedenceSymbol, context);
+        let associativity = pop_item!(self, associativity, Associativity, context);
+        let precedence_declaration_built = PrecedenceDeclaration {
+            associativity,
+            precedence_symbol,
+            precedence_declaration_list,
+        };
+        // Calling user action here
+        self.user_grammar
+            .precedence_declaration(&precedence_declaration_built)?;
+        self.push(
+            ASTType::PrecedenceDeclaration(precedence_declaration_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 23:
+    ///
+    /// `PrecedenceDeclarationList /* Vec<T>::Push */: PrecedenceSymbol PrecedenceDeclarationList;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn precedence_declaration_list_0(
+        &mut self,
+        _precedence_symbol: &ParseTreeType<'t>,
+        _precedence_declaration_list: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let mut precedence_declaration_list = pop_item!(
+            self,
+            precedence_declaration_list,
+            PrecedenceDeclarationList,
+            context
+        );
+        let precedence_symbol = pop_item!(self, precedence_symbol, PrecedenceSymbol, context);
+        let precedence_declaration_list_0_built = PrecedenceDeclarationList { precedence_symbol };
+        // Add an element to the vector
+        precedence_declaration_list.push(precedence_declaration_list_0_built);
+        self.push(
+            ASTType::PrecedenceDeclarationList(precedence_declaration_list),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 24:
+    ///
+    /// `PrecedenceDeclarationList /* Vec<T>::New */: ;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn precedence_declaration_list_1(&mut self) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let precedence_declaration_list_1_built = Vec::new();
+        self.push(
+            ASTType::PrecedenceDeclarationList(precedence_declaration_list_1_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 25:
+    ///
+    /// `Associativity: '%left';`
+    ///
+    #[parol_runtime::function_name::named]
+    fn associativity_0(&mut self, percent_left: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let percent_left = percent_left.token()?.clone();
+        let associativity_0_built = AssociativityPercentLeft { percent_left };
+        let associativity_0_built = Associativity::PercentLeft(associativity_0_built);
+        // Calling user action here
+        self.user_grammar.associativity(&associativity_0_built)?;
+        self.push(ASTType::Associativity(associativity_0_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 26:
+    ///
+    /// `Associativity: '%right';`
+    ///
+    #[parol_runtime::function_name::named]
+    fn associativity_1(&mut self, percent_right: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let percent_right = percent_right.token()?.clone();
+        let associativity_1_built = AssociativityPercentRight { percent_right };
+        let associativity_1_built = Associativity::PercentRight(associativity_1_built);
+        // Calling user action here
+        self.user_grammar.associativity(&associativity_1_built)?;
+        self.push(ASTType::Associativity(associativity_1_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 27:
+    ///
+    /// `Associativity: '%nonassoc';`
+    ///
+    #[parol_runtime::function_name::named]
+    fn associativity_2(&mut self, percent_nonassoc: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let percent_nonassoc = percent_nonassoc.token()?.clone();
+        let associativity_2_built = AssociativityPercentNonassoc { percent_nonassoc };
+        let associativity_2_built = Associativity::PercentNonassoc(associativity_2_built);
+        // Calling user action here
+        self.user_grammar.associativity(&associativity_2_built)?;
+        self.push(ASTType::Associativity(associativity_2_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 28:
+    ///
+    /// `PrecedenceSymbol: TokenLiteral;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn precedence_symbol_0(&mut self, _token_literal: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let token_literal = pop_item!(self, token_literal, TokenLiteral, context);
+        let precedence_symbol_0_built = PrecedenceSymbolTokenLiteral { token_literal };
+        let precedence_symbol_0_built = PrecedenceSymbol::TokenLiteral(precedence_symbol_0_built);
+        // Calling user action here
+        self.user_grammar
+            .precedence_symbol(&precedence_symbol_0_built)?;
+        self.push(
+            ASTType::PrecedenceSymbol(precedence_symbol_0_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 29:
+    ///
+    /// `PrecedenceSymbol: Identifier;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn precedence_symbol_1(&mut self, _identifier: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let identifier = pop_item!(self, identifier, Identifier, context);
+        let precedence_symbol_1_built = PrecedenceSymbolIdentifier { identifier };
+        let precedence_symbol_1_built = PrecedenceSymbol::Identifier(precedence_symbol_1_built);
+        // Calling user action here
+        self.user_grammar
+            .precedence_symbol(&precedence_symbol_1_built)?;
+        self.push(
+            ASTType::PrecedenceSymbol(precedence_symbol_1_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 30:
+    ///
+    /// `ScannerDirectives: '%line_comment'^ /* Clipped */ TokenLiteral;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn scanner_directives_0(
+        &mut self,
+        _percent_line_underscore_comment: &ParseTreeType<'t>,
+        _token_literal: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let token_literal = pop_item!(self, token_literal, TokenLiteral, context);
+        let scanner_directives_0_built =
+            ScannerDirectivesPercentLineUnderscoreCommentTokenLiteral { token_literal };
+        let scanner_directives_0_built =
+            ScannerDirectives::PercentLineUnderscoreCommentTokenLiteral(scanner_directives_0_built);
+        // Calling user action here
+        self.user_grammar
+            .scanner_directives(&scanner_directives_0_built)?;
+        self.push(
+            ASTType::ScannerDirectives(scanner_directives_0_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 31:
+    ///
+    /// `ScannerDirectives: '%block_comment'^ /* Clipped */ TokenLiteral TokenLiteral;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn scanner_directives_1(
+        &mut self,
+        _percent_block_underscore_comment: &ParseTreeType<'t>,
+        _token_literal: &ParseTreeType<'t>,
+        _token_literal0: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let token_literal0 = pop_item!(self, token_literal0, TokenLiteral, context);
+        let token_literal = pop_item!(self, token_literal, TokenLiteral, context);
+        let scanner_directives_1_built =
+            ScannerDirectivesPercentBlockUnderscoreCommentTokenLiteralTokenLiteral {
+                token_literal,
+                token_literal0,
+            };
+        let scanner_directives_1_built =
+            ScannerDirectives::PercentBlockUnderscoreCommentTokenLiteralTokenLiteral(
+                scanner_directives_1_built,
+            );
+        // Calling user action here
+        self.user_grammar
+            .scanner_directives(&scanner_directives_1_built)?;
+        self.push(
+            ASTType::ScannerDirectives(scanner_directives_1_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 32:
+    ///
+    /// `ScannerDirectives: '%nested_block_comment'^ /* Clipped */ TokenLiteral TokenLiteral;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn scanner_directives_2(
+        &mut self,
+        _percent_nested_underscore_block_underscore_comment: &ParseTreeType<'t>,
         _token_literal: &ParseTreeType<'t>,
         _token_literal0: &ParseTreeType<'t>,
     ) -> Result<()> {
@@ -1364,82 +2419,163 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         trace!("{}", self.trace_item_stack(context));
         let token_literal0 = pop_item!(self, token_literal0, TokenLiteral, context);
         let token_literal = pop_item!(self, token_literal, TokenLiteral, context);
-        let scanner_directives_1_built =
-            ScannerDirectivesPercentBlockUnderscoreCommentTokenLiteralTokenLiteral {
-                token_literal,
-                token_literal0,
-            };
-        let scanner_directives_1_built =
-            ScannerDirectives::PercentBlockUnderscoreCommentTokenLiteralTokenLiteral(
-                scanner_directives_1_built,
-            );
+        let scanner_directives_2_built = ScannerDirectivesPercentNestedUnderscoreBlockUnderscoreCommentTokenLiteralTokenLiteral {
+            token_literal,
+            token_literal0,
+        };
+        let scanner_directives_2_built = ScannerDirectives::PercentNestedUnderscoreBlockUnderscoreCommentTokenLiteralTokenLiteral(scanner_directives_2_built);
         // Calling user action here
         self.user_grammar
-            .scanner_directives(&scanner_directives_1_built)?;
+            .scanner_directives(&scanner_directives_2_built)?;
         self.push(
-            ASTType::ScannerDirectives(scanner_directives_1_built),
+            ASTType::ScannerDirectives(scanner_directives_2_built),
             context,
         );
         Ok(())
     }
 
-    /// Semantic action for production 14:
+    /// Semantic action for production 33:
     ///
     /// `ScannerDirectives: '%auto_newline_off'^ /* Clipped */;`
     ///
     #[parol_runtime::function_name::named]
-    fn scanner_directives_2(
+    fn scanner_directives_3(
         &mut self,
         _percent_auto_underscore_newline_underscore_off: &ParseTreeType<'t>,
     ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let scanner_directives_2_built =
+        let scanner_directives_3_built =
             ScannerDirectivesPercentAutoUnderscoreNewlineUnderscoreOff {};
-        let scanner_directives_2_built =
+        let scanner_directives_3_built =
             ScannerDirectives::PercentAutoUnderscoreNewlineUnderscoreOff(
-                scanner_directives_2_built,
+                scanner_directives_3_built,
             );
         // Calling user action here
         self.user_grammar
-            .scanner_directives(&scanner_directives_2_built)?;
+            .scanner_directives(&scanner_directives_3_built)?;
         self.push(
-            ASTType::ScannerDirectives(scanner_directives_2_built),
+            ASTType::ScannerDirectives(scanner_directives_3_built),
             context,
         );
         Ok(())
     }
 
-    /// Semantic action for production 15:
+    /// Semantic action for production 34:
     ///
     /// `ScannerDirectives: '%auto_ws_off'^ /* Clipped */;`
     ///
     #[parol_runtime::function_name::named]
-    fn scanner_directives_3(
+    fn scanner_directives_4(
         &mut self,
         _percent_auto_underscore_ws_underscore_off: &ParseTreeType<'t>,
     ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let scanner_directives_3_built = ScannerDirectivesPercentAutoUnderscoreWsUnderscoreOff {};
-        let scanner_directives_3_built =
-            ScannerDirectives::PercentAutoUnderscoreWsUnderscoreOff(scanner_directives_3_built);
+        let scanner_directives_4_built = ScannerDirectivesPercentAutoUnderscoreWsUnderscoreOff {};
+        let scanner_directives_4_built =
+            ScannerDirectives::PercentAutoUnderscoreWsUnderscoreOff(scanner_directives_4_built);
         // Calling user action here
         self.user_grammar
-            .scanner_directives(&scanner_directives_3_built)?;
+            .scanner_directives(&scanner_directives_4_built)?;
         self.push(
-            ASTType::ScannerDirectives(scanner_directives_3_built),
+            ASTType::ScannerDirectives(scanner_directives_4_built),
             context,
         );
         Ok(())
     }
 
-    /// Semantic action for production 16:
+    /// Semantic action for production 35:
+    ///
+    /// `ScannerDirectives: '%longest_match'^ /* Clipped */;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn scanner_directives_5(
+        &mut self,
+        _percent_longest_underscore_match: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let scanner_directives_5_built = ScannerDirectivesPercentLongestUnderscoreMatch {};
+        let scanner_directives_5_built =
+            ScannerDirectives::PercentLongestUnderscoreMatch(scanner_directives_5_built);
+        // Calling user action here
+        self.user_grammar
+            .scanner_directives(&scanner_directives_5_built)?;
+        self.push(
+            ASTType::ScannerDirectives(scanner_directives_5_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 36:
+    ///
+    /// `ScannerDirectives: '%case_insensitive'^ /* Clipped */;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn scanner_directives_6(
+        &mut self,
+        _percent_case_underscore_insensitive: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let scanner_directives_6_built = ScannerDirectivesPercentCaseUnderscoreInsensitive {};
+        let scanner_directives_6_built =
+            ScannerDirectives::PercentCaseUnderscoreInsensitive(scanner_directives_6_built);
+        // Calling user action here
+        self.user_grammar
+            .scanner_directives(&scanner_directives_6_built)?;
+        self.push(
+            ASTType::ScannerDirectives(scanner_directives_6_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 37:
+    ///
+    /// `ScannerDirectives: '%offside'^ /* Clipped */ Identifier Identifier Identifier;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn scanner_directives_7(
+        &mut self,
+        _percent_offside: &ParseTreeType<'t>,
+        _identifier: &ParseTreeType<'t>,
+        _identifier0: &ParseTreeType<'t>,
+        _identifier1: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let identifier1 = pop_item!(self, identifier1, Identifier, context);
+        let identifier0 = pop_item!(self, identifier0, Identifier, context);
+        let identifier = pop_item!(self, identifier, Identifier, context);
+        let scanner_directives_7_built =
+            ScannerDirectivesPercentOffsideIdentifierIdentifierIdentifier {
+                identifier,
+                identifier0,
+                identifier1,
+            };
+        let scanner_directives_7_built =
+            ScannerDirectives::PercentOffsideIdentifierIdentifierIdentifier(
+                scanner_directives_7_built,
+            );
+        // Calling user action here
+        self.user_grammar
+            .scanner_directives(&scanner_directives_7_built)?;
+        self.push(
+            ASTType::ScannerDirectives(scanner_directives_7_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 38:
     ///
     /// `ScannerDirectives: '%on'^ /* Clipped */ IdentifierList '%enter'^ /* Clipped */ Identifier;`
     ///
     #[parol_runtime::function_name::named]
-    fn scanner_directives_4(
+    fn scanner_directives_8(
         &mut self,
         _percent_on: &ParseTreeType<'t>,
         _identifier_list: &ParseTreeType<'t>,
@@ -1450,147 +2586,313 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         trace!("{}", self.trace_item_stack(context));
         let identifier = pop_item!(self, identifier, Identifier, context);
         let identifier_list = pop_item!(self, identifier_list, IdentifierList, context);
-        let scanner_directives_4_built =
+        let scanner_directives_8_built =
             ScannerDirectivesPercentOnIdentifierListPercentEnterIdentifier {
                 identifier_list,
                 identifier,
             };
-        let scanner_directives_4_built =
+        let scanner_directives_8_built =
             ScannerDirectives::PercentOnIdentifierListPercentEnterIdentifier(
-                scanner_directives_4_built,
+                scanner_directives_8_built,
             );
         // Calling user action here
         self.user_grammar
-            .scanner_directives(&scanner_directives_4_built)?;
+            .scanner_directives(&scanner_directives_8_built)?;
         self.push(
-            ASTType::ScannerDirectives(scanner_directives_4_built),
+            ASTType::ScannerDirectives(scanner_directives_8_built),
             context,
         );
         Ok(())
     }
 
-    /// Semantic action for production 17:
+    /// Semantic action for production 39:
     ///
     /// `GrammarDefinition: '%%'^ /* Clipped */ Production GrammarDefinitionList /* Vec */;`
     ///
     #[parol_runtime::function_name::named]
-    fn grammar_definition(
+    fn grammar_definition(
+        &mut self,
+        _percent_percent: &ParseTreeType<'t>,
+        _production: &ParseTreeType<'t>,
+        _grammar_definition_list: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let grammar_definition_list = pop_and_reverse_item!(
+            self,
+            grammar_definition_list,
+            GrammarDefinitionList,
+            context
+        );
+        let production = pop_item!(self, production, Production, context);
+        let grammar_definition_built = GrammarDefinition {
+            production,
+            grammar_definition_list,
+        };
+        // Calling user action here
+        self.user_grammar
+            .grammar_definition(&grammar_definition_built)?;
+        self.push(
+            ASTType::GrammarDefinition(grammar_definition_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 40:
+    ///
+    /// `GrammarDefinitionList /* Vec<T>::Push */: Production GrammarDefinitionList;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn grammar_definition_list_0(
+        &mut self,
+        _production: &ParseTreeType<'t>,
+        _grammar_definition_list: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let mut grammar_definition_list = pop_item!(
+            self,
+            grammar_definition_list,
+            GrammarDefinitionList,
+            context
+        );
+        let production = pop_item!(self, production, Production, context);
+        let grammar_definition_list_0_built = GrammarDefinitionList { production };
+        // Add an element to the vector
+        grammar_definition_list.push(grammar_definition_list_0_built);
+        self.push(
+            ASTType::GrammarDefinitionList(grammar_definition_list),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 41:
+    ///
+    /// `GrammarDefinitionList /* Vec<T>::New */: ;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn grammar_definition_list_1(&mut self) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let grammar_definition_list_1_built = Vec::new();
+        self.push(
+            ASTType::GrammarDefinitionList(grammar_definition_list_1_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 42:
+    ///
+    /// `DoubleColon: '::';`
+    ///
+    #[parol_runtime::function_name::named]
+    fn double_colon(&mut self, double_colon: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let double_colon = double_colon.token()?.clone();
+        let double_colon_built = DoubleColon { double_colon };
+        // Calling user action here
+        self.user_grammar.double_colon(&double_colon_built)?;
+        self.push(ASTType::DoubleColon(double_colon_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 43:
+    ///
+    /// `DeriveTarget: Identifier ':'^ /* Clipped */;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn derive_target(
+        &mut self,
+        _identifier: &ParseTreeType<'t>,
+        _colon: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let identifier = pop_item!(self, identifier, Identifier, context);
+        let derive_target_built = DeriveTarget { identifier };
+        // Calling user action here
+        self.user_grammar.derive_target(&derive_target_built)?;
+        self.push(ASTType::DeriveTarget(derive_target_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 44:
+    ///
+    /// `DeriveList: UserTypeName : UserType DeriveListList /* Vec */;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn derive_list(
+        &mut self,
+        _user_type_name: &ParseTreeType<'t>,
+        _derive_list_list: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let derive_list_list =
+            pop_and_reverse_item!(self, derive_list_list, DeriveListList, context);
+        let user_type_name = pop_item!(self, user_type_name, UserTypeName, context);
+        let derive_list_built = DeriveList {
+            user_type_name: (&user_type_name)
+                .try_into()
+                .map_err(parol_runtime::ParolError::UserError)?,
+            derive_list_list,
+        };
+        // Calling user action here
+        self.user_grammar.derive_list(&derive_list_built)?;
+        self.push(ASTType::DeriveList(derive_list_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 45:
+    ///
+    /// `DeriveListList /* Vec<T>::Push */: ','^ /* Clipped */ UserTypeName : UserType DeriveListList;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn derive_list_list_0(
+        &mut self,
+        _comma: &ParseTreeType<'t>,
+        _user_type_name: &ParseTreeType<'t>,
+        _derive_list_list: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let mut derive_list_list = pop_item!(self, derive_list_list, DeriveListList, context);
+        let user_type_name = pop_item!(self, user_type_name, UserTypeName, context);
+        let derive_list_list_0_built = DeriveListList {
+            user_type_name: (&user_type_name)
+                .try_into()
+                .map_err(parol_runtime::ParolError::UserError)?,
+        };
+        // Add an element to the vector
+        derive_list_list.push(derive_list_list_0_built);
+        self.push(ASTType::DeriveListList(derive_list_list), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 46:
+    ///
+    /// `DeriveListList /* Vec<T>::New */: ;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn derive_list_list_1(&mut self) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let derive_list_list_1_built = Vec::new();
+        self.push(ASTType::DeriveListList(derive_list_list_1_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 47:
+    ///
+    /// `Production: ProductionName ':'^ /* Clipped */ Alternations ';'^ /* Clipped */;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn production(
         &mut self,
-        _percent_percent: &ParseTreeType<'t>,
-        _production: &ParseTreeType<'t>,
-        _grammar_definition_list: &ParseTreeType<'t>,
+        _production_name: &ParseTreeType<'t>,
+        _colon: &ParseTreeType<'t>,
+        _alternations: &ParseTreeType<'t>,
+        _semicolon: &ParseTreeType<'t>,
     ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let grammar_definition_list = pop_and_reverse_item!(
-            self,
-            grammar_definition_list,
-            GrammarDefinitionList,
-            context
-        );
-        let production = pop_item!(self, production, Production, context);
-        let grammar_definition_built = GrammarDefinition {
-            production,
-            grammar_definition_list,
+        let alternations = pop_item!(self, alternations, Alternations, context);
+        let production_name = pop_item!(self, production_name, ProductionName, context);
+        let production_built = Production {
+            production_name,
+            alternations,
         };
         // Calling user action here
-        self.user_grammar
-            .grammar_definition(&grammar_definition_built)?;
-        self.push(
-            ASTType::GrammarDefinition(grammar_definition_built),
-            context,
-        );
+        self.user_grammar.production(&production_built)?;
+        self.push(ASTType::Production(production_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 18:
+    /// Semantic action for production 48:
     ///
-    /// `GrammarDefinitionList /* Vec<T>::Push */: Production GrammarDefinitionList;`
+    /// `ProductionName: Identifier ProductionNameOpt /* Option */;`
     ///
     #[parol_runtime::function_name::named]
-    fn grammar_definition_list_0(
+    fn production_name(
         &mut self,
-        _production: &ParseTreeType<'t>,
-        _grammar_definition_list: &ParseTreeType<'t>,
+        _identifier: &ParseTreeType<'t>,
+        _production_name_opt: &ParseTreeType<'t>,
     ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let mut grammar_definition_list = pop_item!(
-            self,
-            grammar_definition_list,
-            GrammarDefinitionList,
-            context
-        );
-        let production = pop_item!(self, production, Production, context);
-        let grammar_definition_list_0_built = GrammarDefinitionList { production };
-        // Add an element to the vector
-        grammar_definition_list.push(grammar_definition_list_0_built);
-        self.push(
-            ASTType::GrammarDefinitionList(grammar_definition_list),
-            context,
-        );
+        let production_name_opt = pop_item!(self, production_name_opt, ProductionNameOpt, context);
+        let identifier = pop_item!(self, identifier, Identifier, context);
+        let production_name_built = ProductionName {
+            identifier,
+            production_name_opt,
+        };
+        // Calling user action here
+        self.user_grammar.production_name(&production_name_built)?;
+        self.push(ASTType::ProductionName(production_name_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 19:
+    /// Semantic action for production 49:
     ///
-    /// `GrammarDefinitionList /* Vec<T>::New */: ;`
+    /// `ProductionNameOpt /* Option<T>::Some */: TemplateParameters;`
     ///
     #[parol_runtime::function_name::named]
-    fn grammar_definition_list_1(&mut self) -> Result<()> {
+    fn production_name_opt_0(&mut self, _template_parameters: &ParseTreeType<'t>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let grammar_definition_list_1_built = Vec::new();
+        let template_parameters = pop_item!(self, template_parameters, TemplateParameters, context);
+        let production_name_opt_0_built = ProductionNameOpt {
+            template_parameters,
+        };
         self.push(
-            ASTType::GrammarDefinitionList(grammar_definition_list_1_built),
+            ASTType::ProductionNameOpt(Some(production_name_opt_0_built)),
             context,
         );
         Ok(())
     }
 
-    /// Semantic action for production 20:
+    /// Semantic action for production 50:
     ///
-    /// `DoubleColon: '::';`
+    /// `ProductionNameOpt /* Option<T>::None */: ;`
     ///
     #[parol_runtime::function_name::named]
-    fn double_colon(&mut self, double_colon: &ParseTreeType<'t>) -> Result<()> {
+    fn production_name_opt_1(&mut self) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let double_colon = double_colon.token()?.clone();
-        let double_colon_built = DoubleColon { double_colon };
-        // Calling user action here
-        self.user_grammar.double_colon(&double_colon_built)?;
-        self.push(ASTType::DoubleColon(double_colon_built), context);
+        self.push(ASTType::ProductionNameOpt(None), context);
         Ok(())
     }
 
-    /// Semantic action for production 21:
+    /// Semantic action for production 51:
     ///
-    /// `Production: Identifier ':'^ /* Clipped */ Alternations ';'^ /* Clipped */;`
+    /// `TemplateParameters: '<'^ /* Clipped */ IdentifierList '>'^ /* Clipped */;`
     ///
     #[parol_runtime::function_name::named]
-    fn production(
+    fn template_parameters(
         &mut self,
-        _identifier: &ParseTreeType<'t>,
-        _colon: &ParseTreeType<'t>,
-        _alternations: &ParseTreeType<'t>,
-        _semicolon: &ParseTreeType<'t>,
+        _l_t: &ParseTreeType<'t>,
+        _identifier_list: &ParseTreeType<'t>,
+        _g_t: &ParseTreeType<'t>,
     ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let alternations = pop_item!(self, alternations, Alternations, context);
-        let identifier = pop_item!(self, identifier, Identifier, context);
-        let production_built = Production {
-            identifier,
-            alternations,
-        };
+        let identifier_list = pop_item!(self, identifier_list, IdentifierList, context);
+        let template_parameters_built = TemplateParameters { identifier_list };
         // Calling user action here
-        self.user_grammar.production(&production_built)?;
-        self.push(ASTType::Production(production_built), context);
+        self.user_grammar
+            .template_parameters(&template_parameters_built)?;
+        self.push(
+            ASTType::TemplateParameters(template_parameters_built),
+            context,
+        );
         Ok(())
     }
 
-    /// Semantic action for production 22:
+    /// Semantic action for production 52:
     ///
     /// `Alternations: Alternation AlternationsList /* Vec */;`
     ///
@@ -1615,7 +2917,7 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 23:
+    /// Semantic action for production 53:
     ///
     /// `AlternationsList /* Vec<T>::Push */: '|'^ /* Clipped */ Alternation AlternationsList;`
     ///
@@ -1637,7 +2939,7 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 24:
+    /// Semantic action for production 54:
     ///
     /// `AlternationsList /* Vec<T>::New */: ;`
     ///
@@ -1653,24 +2955,35 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 25:
+    /// Semantic action for production 55:
     ///
-    /// `Alternation: AlternationList /* Vec */;`
+    /// `Alternation: AlternationList /* Vec */ AlternationOpt /* Option */ AlternationOpt0 /* Option */;`
     ///
     #[parol_runtime::function_name::named]
-    fn alternation(&mut self, _alternation_list: &ParseTreeType<'t>) -> Result<()> {
+    fn alternation(
+        &mut self,
+        _alternation_list: &ParseTreeType<'t>,
+        _alternation_opt: &ParseTreeType<'t>,
+        _alternation_opt0: &ParseTreeType<'t>,
+    ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
+        let alternation_opt0 = pop_item!(self, alternation_opt0, AlternationOpt0, context);
+        let alternation_opt = pop_item!(self, alternation_opt, AlternationOpt, context);
         let alternation_list =
             pop_and_reverse_item!(self, alternation_list, AlternationList, context);
-        let alternation_built = Alternation { alternation_list };
+        let alternation_built = Alternation {
+            alternation_list,
+            alternation_opt,
+            alternation_opt0,
+        };
         // Calling user action here
         self.user_grammar.alternation(&alternation_built)?;
         self.push(ASTType::Alternation(alternation_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 26:
+    /// Semantic action for production 56:
     ///
     /// `AlternationList /* Vec<T>::Push */: Factor AlternationList;`
     ///
@@ -1691,7 +3004,7 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 27:
+    /// Semantic action for production 57:
     ///
     /// `AlternationList /* Vec<T>::New */: ;`
     ///
@@ -1704,7 +3017,90 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 28:
+    /// Semantic action for production 58:
+    ///
+    /// `AlternationOpt0 /* Option<T>::Some */: AlternationLabel;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn alternation_opt0_0(&mut self, _alternation_label: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let alternation_label = pop_item!(self, alternation_label, AlternationLabel, context);
+        let alternation_opt0_0_built = AlternationOpt0 { alternation_label };
+        self.push(
+            ASTType::AlternationOpt0(Some(alternation_opt0_0_built)),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 59:
+    ///
+    /// `AlternationOpt0 /* Option<T>::None */: ;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn alternation_opt0_1(&mut self) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        self.push(ASTType::AlternationOpt0(None), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 60:
+    ///
+    /// `AlternationOpt /* Option<T>::Some */: '%prec'^ /* Clipped */ PrecedenceSymbol;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn alternation_opt_0(
+        &mut self,
+        _percent_prec: &ParseTreeType<'t>,
+        _precedence_symbol: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let precedence_symbol = pop_item!(self, precedence_symbol, PrecedenceSymbol, context);
+        let alternation_opt_0_built = AlternationOpt { precedence_symbol };
+        self.push(
+            ASTType::AlternationOpt(Some(alternation_opt_0_built)),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 61:
+    ///
+    /// `AlternationOpt /* Option<T>::None */: ;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn alternation_opt_1(&mut self) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        self.push(ASTType::AlternationOpt(None), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 62:
+    ///
+    /// `AlternationLabel: '#'^ /* Clipped */ Identifier;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn alternation_label(
+        &mut self,
+        _hash: &ParseTreeType<'t>,
+        _identifier: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let identifier = pop_item!(self, identifier, Identifier, context);
+        let alternation_label_built = AlternationLabel { identifier };
+        // Calling user action here
+        self.user_grammar
+            .alternation_label(&alternation_label_built)?;
+        self.push(ASTType::AlternationLabel(alternation_label_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 63:
     ///
     /// `Factor: Group;`
     ///
@@ -1721,7 +3117,7 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 29:
+    /// Semantic action for production 64:
     ///
     /// `Factor: Repeat;`
     ///
@@ -1730,7 +3126,9 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let repeat = pop_item!(self, repeat, Repeat, context);
-        let factor_1_built = FactorRepeat { repeat };
+        let factor_1_built = FactorRepeat {
+            repeat: Box::new(repeat),
+        };
         let factor_1_built = Factor::Repeat(factor_1_built);
         // Calling user action here
         self.user_grammar.factor(&factor_1_built)?;
@@ -1738,7 +3136,7 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 30:
+    /// Semantic action for production 65:
     ///
     /// `Factor: Optional;`
     ///
@@ -1755,7 +3153,7 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 31:
+    /// Semantic action for production 66:
     ///
     /// `Factor: Symbol;`
     ///
@@ -1764,7 +3162,9 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let symbol = pop_item!(self, symbol, Symbol, context);
-        let factor_3_built = FactorSymbol { symbol };
+        let factor_3_built = FactorSymbol {
+            symbol: Box::new(symbol),
+        };
         let factor_3_built = Factor::Symbol(factor_3_built);
         // Calling user action here
         self.user_grammar.factor(&factor_3_built)?;
@@ -1772,7 +3172,52 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 32:
+    /// Semantic action for production 67:
+    ///
+    /// `Factor: FieldLabel Symbol;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn factor_4(
+        &mut self,
+        _field_label: &ParseTreeType<'t>,
+        _symbol: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let symbol = pop_item!(self, symbol, Symbol, context);
+        let field_label = pop_item!(self, field_label, FieldLabel, context);
+        let factor_4_built = FactorFieldLabelSymbol {
+            field_label,
+            symbol: Box::new(symbol),
+        };
+        let factor_4_built = Factor::FieldLabelSymbol(factor_4_built);
+        // Calling user action here
+        self.user_grammar.factor(&factor_4_built)?;
+        self.push(ASTType::Factor(factor_4_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 68:
+    ///
+    /// `FieldLabel: Identifier '='^ /* Clipped */;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn field_label(
+        &mut self,
+        _identifier: &ParseTreeType<'t>,
+        _equ: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let identifier = pop_item!(self, identifier, Identifier, context);
+        let field_label_built = FieldLabel { identifier };
+        // Calling user action here
+        self.user_grammar.field_label(&field_label_built)?;
+        self.push(ASTType::FieldLabel(field_label_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 69:
     ///
     /// `Symbol: NonTerminal;`
     ///
@@ -1789,7 +3234,7 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 33:
+    /// Semantic action for production 70:
     ///
     /// `Symbol: SimpleToken;`
     ///
@@ -1806,24 +3251,24 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 34:
+    /// Semantic action for production 71:
     ///
-    /// `Symbol: TokenWithStates;`
+    /// `Symbol: AngleArguments;`
     ///
     #[parol_runtime::function_name::named]
-    fn symbol_2(&mut self, _token_with_states: &ParseTreeType<'t>) -> Result<()> {
+    fn symbol_2(&mut self, _angle_arguments: &ParseTreeType<'t>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let token_with_states = pop_item!(self, token_with_states, TokenWithStates, context);
-        let symbol_2_built = SymbolTokenWithStates { token_with_states };
-        let symbol_2_built = Symbol::TokenWithStates(symbol_2_built);
+        let angle_arguments = pop_item!(self, angle_arguments, AngleArguments, context);
+        let symbol_2_built = SymbolAngleArguments { angle_arguments };
+        let symbol_2_built = Symbol::AngleArguments(symbol_2_built);
         // Calling user action here
         self.user_grammar.symbol(&symbol_2_built)?;
         self.push(ASTType::Symbol(symbol_2_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 35:
+    /// Semantic action for production 72:
     ///
     /// `Symbol: ScannerSwitch;`
     ///
@@ -1840,7 +3285,7 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 36:
+    /// Semantic action for production 73:
     ///
     /// `TokenLiteral: String;`
     ///
@@ -1857,7 +3302,7 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 37:
+    /// Semantic action for production 74:
     ///
     /// `TokenLiteral: RawString;`
     ///
@@ -1874,7 +3319,7 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 38:
+    /// Semantic action for production 75:
     ///
     /// `TokenLiteral: Regex;`
     ///
@@ -1891,122 +3336,138 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 39:
+    /// Semantic action for production 76:
     ///
-    /// `SimpleToken: TokenLiteral SimpleTokenOpt /* Option */;`
+    /// `TokenExpression: TokenLiteral TokenExpressionOpt /* Option */;`
     ///
     #[parol_runtime::function_name::named]
-    fn simple_token(
+    fn token_expression(
         &mut self,
         _token_literal: &ParseTreeType<'t>,
-        _simple_token_opt: &ParseTreeType<'t>,
+        _token_expression_opt: &ParseTreeType<'t>,
     ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let simple_token_opt = pop_item!(self, simple_token_opt, SimpleTokenOpt, context);
+        let token_expression_opt =
+            pop_item!(self, token_expression_opt, TokenExpressionOpt, context);
         let token_literal = pop_item!(self, token_literal, TokenLiteral, context);
-        let simple_token_built = SimpleToken {
+        let token_expression_built = TokenExpression {
             token_literal,
-            simple_token_opt,
+            token_expression_opt,
         };
         // Calling user action here
-        self.user_grammar.simple_token(&simple_token_built)?;
-        self.push(ASTType::SimpleToken(simple_token_built), context);
+        self.user_grammar
+            .token_expression(&token_expression_built)?;
+        self.push(ASTType::TokenExpression(token_expression_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 40:
+    /// Semantic action for production 77:
     ///
-    /// `SimpleTokenOpt /* Option<T>::Some */: ASTControl;`
+    /// `TokenExpressionOpt /* Option<T>::Some */: TrailingContext;`
     ///
     #[parol_runtime::function_name::named]
-    fn simple_token_opt_0(&mut self, _a_s_t_control: &ParseTreeType<'t>) -> Result<()> {
+    fn token_expression_opt_0(&mut self, _trailing_context: &ParseTreeType<'t>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let a_s_t_control = pop_item!(self, a_s_t_control, ASTControl, context);
-        let simple_token_opt_0_built = SimpleTokenOpt { a_s_t_control };
+        let trailing_context = pop_item!(self, trailing_context, TrailingContext, context);
+        let token_expression_opt_0_built = TokenExpressionOpt { trailing_context };
         self.push(
-            ASTType::SimpleTokenOpt(Some(simple_token_opt_0_built)),
+            ASTType::TokenExpressionOpt(Some(token_expression_opt_0_built)),
             context,
         );
         Ok(())
     }
 
-    /// Semantic action for production 41:
+    /// Semantic action for production 78:
     ///
-    /// `SimpleTokenOpt /* Option<T>::None */: ;`
+    /// `TokenExpressionOpt /* Option<T>::None */: ;`
     ///
     #[parol_runtime::function_name::named]
-    fn simple_token_opt_1(&mut self) -> Result<()> {
+    fn token_expression_opt_1(&mut self) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        self.push(ASTType::SimpleTokenOpt(None), context);
+        self.push(ASTType::TokenExpressionOpt(None), context);
         Ok(())
     }
 
-    /// Semantic action for production 42:
+    /// Semantic action for production 79:
     ///
-    /// `TokenWithStates: '<'^ /* Clipped */ IdentifierList '>'^ /* Clipped */ TokenLiteral TokenWithStatesOpt /* Option */;`
+    /// `TrailingContext: '?='^ /* Clipped */ TokenLiteral;`
     ///
     #[parol_runtime::function_name::named]
-    fn token_with_states(
+    fn trailing_context(
         &mut self,
-        _l_t: &ParseTreeType<'t>,
-        _identifier_list: &ParseTreeType<'t>,
-        _g_t: &ParseTreeType<'t>,
+        _quest_equ: &ParseTreeType<'t>,
         _token_literal: &ParseTreeType<'t>,
-        _token_with_states_opt: &ParseTreeType<'t>,
     ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let token_with_states_opt =
-            pop_item!(self, token_with_states_opt, TokenWithStatesOpt, context);
-        let token_literal = pop_item!(self, token_literal, TokenLiteral, context);
-        let identifier_list = pop_item!(self, identifier_list, IdentifierList, context);
-        let token_with_states_built = TokenWithStates {
-            identifier_list,
-            token_literal,
-            token_with_states_opt,
+        let token_literal = pop_item!(self, token_literal, TokenLiteral, context);
+        let trailing_context_built = TrailingContext { token_literal };
+        // Calling user action here
+        self.user_grammar
+            .trailing_context(&trailing_context_built)?;
+        self.push(ASTType::TrailingContext(trailing_context_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 80:
+    ///
+    /// `SimpleToken: TokenExpression SimpleTokenOpt /* Option */;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn simple_token(
+        &mut self,
+        _token_expression: &ParseTreeType<'t>,
+        _simple_token_opt: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let simple_token_opt = pop_item!(self, simple_token_opt, SimpleTokenOpt, context);
+        let token_expression = pop_item!(self, token_expression, TokenExpression, context);
+        let simple_token_built = SimpleToken {
+            token_expression,
+            simple_token_opt,
         };
         // Calling user action here
-        self.user_grammar
-            .token_with_states(&token_with_states_built)?;
-        self.push(ASTType::TokenWithStates(token_with_states_built), context);
+        self.user_grammar.simple_token(&simple_token_built)?;
+        self.push(ASTType::SimpleToken(simple_token_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 43:
+    /// Semantic action for production 81:
     ///
-    /// `TokenWithStatesOpt /* Option<T>::Some */: ASTControl;`
+    /// `SimpleTokenOpt /* Option<T>::Some */: ASTControl;`
     ///
     #[parol_runtime::function_name::named]
-    fn token_with_states_opt_0(&mut self, _a_s_t_control: &ParseTreeType<'t>) -> Result<()> {
+    fn simple_token_opt_0(&mut self, _a_s_t_control: &ParseTreeType<'t>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let a_s_t_control = pop_item!(self, a_s_t_control, ASTControl, context);
-        let token_with_states_opt_0_built = TokenWithStatesOpt { a_s_t_control };
+        let simple_token_opt_0_built = SimpleTokenOpt { a_s_t_control };
         self.push(
-            ASTType::TokenWithStatesOpt(Some(token_with_states_opt_0_built)),
+            ASTType::SimpleTokenOpt(Some(simple_token_opt_0_built)),
             context,
         );
         Ok(())
     }
 
-    /// Semantic action for production 44:
+    /// Semantic action for production 82:
     ///
-    /// `TokenWithStatesOpt /* Option<T>::None */: ;`
+    /// `SimpleTokenOpt /* Option<T>::None */: ;`
     ///
     #[parol_runtime::function_name::named]
-    fn token_with_states_opt_1(&mut self) -> Result<()> {
+    fn simple_token_opt_1(&mut self) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        self.push(ASTType::TokenWithStatesOpt(None), context);
+        self.push(ASTType::SimpleTokenOpt(None), context);
         Ok(())
     }
 
-    /// Semantic action for production 45:
+    /// Semantic action for production 83:
     ///
-    /// `String: /"(\\.|[^\\])*?"/;`
+    /// `String: /"(\\.|[^\\])*?"i?/;`
     ///
     #[parol_runtime::function_name::named]
     fn string(&mut self, string: &ParseTreeType<'t>) -> Result<()> {
@@ -2020,9 +3481,9 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 46:
+    /// Semantic action for production 84:
     ///
-    /// `RawString: /'(\\'|[^'])*?'/;`
+    /// `RawString: /'(\\'|[^'])*?'i?/;`
     ///
     #[parol_runtime::function_name::named]
     fn raw_string(&mut self, raw_string: &ParseTreeType<'t>) -> Result<()> {
@@ -2036,9 +3497,9 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 47:
+    /// Semantic action for production 85:
     ///
-    /// `Regex: /\u{2F}(\\.|[^\\])*?\u{2F}/;`
+    /// `Regex: /\u{2F}(\\.|[^\\])*?\u{2F}i?/;`
     ///
     #[parol_runtime::function_name::named]
     fn regex(&mut self, regex: &ParseTreeType<'t>) -> Result<()> {
@@ -2052,7 +3513,7 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 48:
+    /// Semantic action for production 86:
     ///
     /// `Group: '(' Alternations ')';`
     ///
@@ -2079,7 +3540,7 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 49:
+    /// Semantic action for production 87:
     ///
     /// `Optional: '[' Alternations ']';`
     ///
@@ -2106,26 +3567,32 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 50:
+    /// Semantic action for production 88:
     ///
-    /// `Repeat: '{' Alternations '}';`
+    /// `Repeat: '{' Alternations RepeatOpt /* Option */ '}' RepeatOpt0 /* Option */;`
     ///
     #[parol_runtime::function_name::named]
     fn repeat(
         &mut self,
         l_brace: &ParseTreeType<'t>,
         _alternations: &ParseTreeType<'t>,
+        _repeat_opt: &ParseTreeType<'t>,
         r_brace: &ParseTreeType<'t>,
+        _repeat_opt0: &ParseTreeType<'t>,
     ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let l_brace = l_brace.token()?.clone();
         let r_brace = r_brace.token()?.clone();
+        let repeat_opt0 = pop_item!(self, repeat_opt0, RepeatOpt0, context);
+        let repeat_opt = pop_item!(self, repeat_opt, RepeatOpt, context);
         let alternations = pop_item!(self, alternations, Alternations, context);
         let repeat_built = Repeat {
             l_brace,
             alternations,
+            repeat_opt,
             r_brace,
+            repeat_opt0,
         };
         // Calling user action here
         self.user_grammar.repeat(&repeat_built)?;
@@ -2133,7 +3600,59 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 51:
+    /// Semantic action for production 89:
+    ///
+    /// `RepeatOpt0 /* Option<T>::Some */: OneOrMore;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn repeat_opt0_0(&mut self, _one_or_more: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let one_or_more = pop_item!(self, one_or_more, OneOrMore, context);
+        let repeat_opt0_0_built = RepeatOpt0 { one_or_more };
+        self.push(ASTType::RepeatOpt0(Some(repeat_opt0_0_built)), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 90:
+    ///
+    /// `RepeatOpt0 /* Option<T>::None */: ;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn repeat_opt0_1(&mut self) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        self.push(ASTType::RepeatOpt0(None), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 91:
+    ///
+    /// `RepeatOpt /* Option<T>::Some */: RepeatSeparator;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn repeat_opt_0(&mut self, _repeat_separator: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let repeat_separator = pop_item!(self, repeat_separator, RepeatSeparator, context);
+        let repeat_opt_0_built = RepeatOpt { repeat_separator };
+        self.push(ASTType::RepeatOpt(Some(repeat_opt_0_built)), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 92:
+    ///
+    /// `RepeatOpt /* Option<T>::None */: ;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn repeat_opt_1(&mut self) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        self.push(ASTType::RepeatOpt(None), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 93:
     ///
     /// `NonTerminal: Identifier NonTerminalOpt /* Option */;`
     ///
@@ -2157,7 +3676,7 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 52:
+    /// Semantic action for production 94:
     ///
     /// `NonTerminalOpt /* Option<T>::Some */: ASTControl;`
     ///
@@ -2174,7 +3693,7 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 53:
+    /// Semantic action for production 95:
     ///
     /// `NonTerminalOpt /* Option<T>::None */: ;`
     ///
@@ -2186,7 +3705,210 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 54:
+    /// Semantic action for production 96:
+    ///
+    /// `AngleArguments: '<' TemplateArguments '>'^ /* Clipped */ AngleArgumentsOpt /* Option */;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn angle_arguments(
+        &mut self,
+        l_t: &ParseTreeType<'t>,
+        _template_arguments: &ParseTreeType<'t>,
+        _g_t: &ParseTreeType<'t>,
+        _angle_arguments_opt: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let l_t = l_t.token()?.clone();
+        let angle_arguments_opt = pop_item!(self, angle_arguments_opt, AngleArgumentsOpt, context);
+        let template_arguments = pop_item!(self, template_arguments, TemplateArguments, context);
+        let angle_arguments_built = AngleArguments {
+            l_t,
+            template_arguments,
+            angle_arguments_opt,
+        };
+        // Calling user action here
+        self.user_grammar.angle_arguments(&angle_arguments_built)?;
+        self.push(ASTType::AngleArguments(angle_arguments_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 97:
+    ///
+    /// `AngleArgumentsOpt /* Option<T>::Some */: ASTControl;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn angle_arguments_opt_0(&mut self, _a_s_t_control: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let a_s_t_control = pop_item!(self, a_s_t_control, ASTControl, context);
+        let angle_arguments_opt_0_built = AngleArgumentsOpt { a_s_t_control };
+        self.push(
+            ASTType::AngleArgumentsOpt(Some(angle_arguments_opt_0_built)),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 98:
+    ///
+    /// `AngleArgumentsOpt /* Option<T>::None */: ;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn angle_arguments_opt_1(&mut self) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        self.push(ASTType::AngleArgumentsOpt(None), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 99:
+    ///
+    /// `TemplateArguments: TemplateArgument TemplateArgumentsList /* Vec */;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn template_arguments(
+        &mut self,
+        _template_argument: &ParseTreeType<'t>,
+        _template_arguments_list: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let template_arguments_list = pop_and_reverse_item!(
+            self,
+            template_arguments_list,
+            TemplateArgumentsList,
+            context
+        );
+        let template_argument = pop_item!(self, template_argument, TemplateArgument, context);
+        let template_arguments_built = TemplateArguments {
+            template_argument,
+            template_arguments_list,
+        };
+        // Calling user action here
+        self.user_grammar
+            .template_arguments(&template_arguments_built)?;
+        self.push(
+            ASTType::TemplateArguments(template_arguments_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 100:
+    ///
+    /// `TemplateArgumentsList /* Vec<T>::Push */: ','^ /* Clipped */ TemplateArgument TemplateArgumentsList;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn template_arguments_list_0(
+        &mut self,
+        _comma: &ParseTreeType<'t>,
+        _template_argument: &ParseTreeType<'t>,
+        _template_arguments_list: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let mut template_arguments_list = pop_item!(
+            self,
+            template_arguments_list,
+            TemplateArgumentsList,
+            context
+        );
+        let template_argument = pop_item!(self, template_argument, TemplateArgument, context);
+        let template_arguments_list_0_built = TemplateArgumentsList { template_argument };
+        // Add an element to the vector
+        template_arguments_list.push(template_arguments_list_0_built);
+        self.push(
+            ASTType::TemplateArgumentsList(template_arguments_list),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 101:
+    ///
+    /// `TemplateArgumentsList /* Vec<T>::New */: ;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn template_arguments_list_1(&mut self) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let template_arguments_list_1_built = Vec::new();
+        self.push(
+            ASTType::TemplateArgumentsList(template_arguments_list_1_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 102:
+    ///
+    /// `TemplateArgument: Factor TemplateArgumentList /* Vec */;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn template_argument(
+        &mut self,
+        _factor: &ParseTreeType<'t>,
+        _template_argument_list: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let template_argument_list =
+            pop_and_reverse_item!(self, template_argument_list, TemplateArgumentList, context);
+        let factor = pop_item!(self, factor, Factor, context);
+        let template_argument_built = TemplateArgument {
+            factor,
+            template_argument_list,
+        };
+        // Calling user action here
+        self.user_grammar
+            .template_argument(&template_argument_built)?;
+        self.push(ASTType::TemplateArgument(template_argument_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 103:
+    ///
+    /// `TemplateArgumentList /* Vec<T>::Push */: Factor TemplateArgumentList;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn template_argument_list_0(
+        &mut self,
+        _factor: &ParseTreeType<'t>,
+        _template_argument_list: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let mut template_argument_list =
+            pop_item!(self, template_argument_list, TemplateArgumentList, context);
+        let factor = pop_item!(self, factor, Factor, context);
+        let template_argument_list_0_built = TemplateArgumentList { factor };
+        // Add an element to the vector
+        template_argument_list.push(template_argument_list_0_built);
+        self.push(
+            ASTType::TemplateArgumentList(template_argument_list),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 104:
+    ///
+    /// `TemplateArgumentList /* Vec<T>::New */: ;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn template_argument_list_1(&mut self) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let template_argument_list_1_built = Vec::new();
+        self.push(
+            ASTType::TemplateArgumentList(template_argument_list_1_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 105:
     ///
     /// `Identifier: /[a-zA-Z_][a-zA-Z0-9_]*/;`
     ///
@@ -2202,7 +3924,23 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 55:
+    /// Semantic action for production 106:
+    ///
+    /// `Number: /[0-9]+/;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn number(&mut self, number: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let number = number.token()?.clone();
+        let number_built = Number { number };
+        // Calling user action here
+        self.user_grammar.number(&number_built)?;
+        self.push(ASTType::Number(number_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 107:
     ///
     /// `ScannerState: '%scanner'^ /* Clipped */ Identifier '{'^ /* Clipped */ ScannerStateList /* Vec */ '}'^ /* Clipped */;`
     ///
@@ -2230,7 +3968,7 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 56:
+    /// Semantic action for production 108:
     ///
     /// `ScannerStateList /* Vec<T>::Push */: ScannerDirectives ScannerStateList;`
     ///
@@ -2251,7 +3989,7 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 57:
+    /// Semantic action for production 109:
     ///
     /// `ScannerStateList /* Vec<T>::New */: ;`
     ///
@@ -2267,7 +4005,7 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 58:
+    /// Semantic action for production 110:
     ///
     /// `IdentifierList: Identifier IdentifierListList /* Vec */;`
     ///
@@ -2292,7 +4030,7 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 59:
+    /// Semantic action for production 111:
     ///
     /// `IdentifierListList /* Vec<T>::Push */: ','^ /* Clipped */ Identifier IdentifierListList;`
     ///
@@ -2315,7 +4053,7 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 60:
+    /// Semantic action for production 112:
     ///
     /// `IdentifierListList /* Vec<T>::New */: ;`
     ///
@@ -2331,7 +4069,7 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 61:
+    /// Semantic action for production 113:
     ///
     /// `ScannerSwitch: '%sc' '('^ /* Clipped */ ScannerSwitchOpt /* Option */ ')'^ /* Clipped */;`
     ///
@@ -2359,7 +4097,7 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 62:
+    /// Semantic action for production 114:
     ///
     /// `ScannerSwitch: '%push' '('^ /* Clipped */ Identifier ')'^ /* Clipped */;`
     ///
@@ -2387,7 +4125,7 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 63:
+    /// Semantic action for production 115:
     ///
     /// `ScannerSwitch: '%pop' '('^ /* Clipped */ ')'^ /* Clipped */;`
     ///
@@ -2409,7 +4147,7 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 64:
+    /// Semantic action for production 116:
     ///
     /// `ScannerSwitchOpt /* Option<T>::Some */: Identifier;`
     ///
@@ -2426,7 +4164,7 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 65:
+    /// Semantic action for production 117:
     ///
     /// `ScannerSwitchOpt /* Option<T>::None */: ;`
     ///
@@ -2438,7 +4176,7 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 66:
+    /// Semantic action for production 118:
     ///
     /// `ASTControl: CutOperator;`
     ///
@@ -2455,7 +4193,7 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 67:
+    /// Semantic action for production 119:
     ///
     /// `ASTControl: UserTypeDeclaration;`
     ///
@@ -2475,7 +4213,7 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 68:
+    /// Semantic action for production 120:
     ///
     /// `CutOperator: '^'^ /* Clipped */;`
     ///
@@ -2490,7 +4228,7 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 69:
+    /// Semantic action for production 121:
     ///
     /// `UserTypeDeclaration: ':'^ /* Clipped */ UserTypeName : UserType;`
     ///
@@ -2518,7 +4256,7 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 70:
+    /// Semantic action for production 122:
     ///
     /// `UserTypeName: Identifier UserTypeNameList /* Vec */;`
     ///
@@ -2543,7 +4281,7 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 71:
+    /// Semantic action for production 123:
     ///
     /// `UserTypeNameList /* Vec<T>::Push */: DoubleColon^ /* Clipped */ Identifier UserTypeNameList;`
     ///
@@ -2567,7 +4305,7 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 72:
+    /// Semantic action for production 124:
     ///
     /// `UserTypeNameList /* Vec<T>::New */: ;`
     ///
@@ -2582,6 +4320,89 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         );
         Ok(())
     }
+
+    /// Semantic action for production 125:
+    ///
+    /// `RepeatSeparator: '%' Symbol RepeatSeparatorList /* Vec */;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn repeat_separator(
+        &mut self,
+        percent: &ParseTreeType<'t>,
+        _symbol: &ParseTreeType<'t>,
+        _repeat_separator_list: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let percent = percent.token()?.clone();
+        let repeat_separator_list =
+            pop_and_reverse_item!(self, repeat_separator_list, RepeatSeparatorList, context);
+        let symbol = pop_item!(self, symbol, Symbol, context);
+        let repeat_separator_built = RepeatSeparator {
+            percent,
+            symbol,
+            repeat_separator_list,
+        };
+        // Calling user action here
+        self.user_grammar
+            .repeat_separator(&repeat_separator_built)?;
+        self.push(ASTType::RepeatSeparator(repeat_separator_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 126:
+    ///
+    /// `RepeatSeparatorList /* Vec<T>::Push */: Symbol RepeatSeparatorList;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn repeat_separator_list_0(
+        &mut self,
+        _symbol: &ParseTreeType<'t>,
+        _repeat_separator_list: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let mut repeat_separator_list =
+            pop_item!(self, repeat_separator_list, RepeatSeparatorList, context);
+        let symbol = pop_item!(self, symbol, Symbol, context);
+        let repeat_separator_list_0_built = RepeatSeparatorList { symbol };
+        // Add an element to the vector
+        repeat_separator_list.push(repeat_separator_list_0_built);
+        self.push(ASTType::RepeatSeparatorList(repeat_separator_list), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 127:
+    ///
+    /// `RepeatSeparatorList /* Vec<T>::New */: ;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn repeat_separator_list_1(&mut self) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let repeat_separator_list_1_built = Vec::new();
+        self.push(
+            ASTType::RepeatSeparatorList(repeat_separator_list_1_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 128:
+    ///
+    /// `OneOrMore: '+';`
+    ///
+    #[parol_runtime::function_name::named]
+    fn one_or_more(&mut self, one_or_more: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let one_or_more = one_or_more.token()?.clone();
+        let one_or_more_built = OneOrMore { one_or_more };
+        // Calling user action here
+        self.user_grammar.one_or_more(&one_or_more_built)?;
+        self.push(ASTType::OneOrMore(one_or_more_built), context);
+        Ok(())
+    }
 }
 
 impl<'t> UserActionsTrait<'t> for ParolGrammarAuto<'t, '_> {
@@ -2605,80 +4426,136 @@ impl<'t> UserActionsTrait<'t> for ParolGrammarAuto<'t, '_> {
             8 => self.declaration_1(&children[0], &children[1]),
             9 => self.declaration_2(&children[0], &children[1], &children[2], &children[3]),
             10 => self.declaration_3(&children[0], &children[1]),
-            11 => self.declaration_4(&children[0]),
-            12 => self.scanner_directives_0(&children[0], &children[1]),
-            13 => self.scanner_directives_1(&children[0], &children[1], &children[2]),
-            14 => self.scanner_directives_2(&children[0]),
-            15 => self.scanner_directives_3(&children[0]),
-            16 => self.scanner_directives_4(&children[0], &children[1], &children[2], &children[3]),
-            17 => self.grammar_definition(&children[0], &children[1], &children[2]),
-            18 => self.grammar_definition_list_0(&children[0], &children[1]),
-            19 => self.grammar_definition_list_1(),
-            20 => self.double_colon(&children[0]),
-            21 => self.production(&children[0], &children[1], &children[2], &children[3]),
-            22 => self.alternations(&children[0], &children[1]),
-            23 => self.alternations_list_0(&children[0], &children[1], &children[2]),
-            24 => self.alternations_list_1(),
-            25 => self.alternation(&children[0]),
-            26 => self.alternation_list_0(&children[0], &children[1]),
-            27 => self.alternation_list_1(),
-            28 => self.factor_0(&children[0]),
-            29 => self.factor_1(&children[0]),
-            30 => self.factor_2(&children[0]),
-            31 => self.factor_3(&children[0]),
-            32 => self.symbol_0(&children[0]),
-            33 => self.symbol_1(&children[0]),
-            34 => self.symbol_2(&children[0]),
-            35 => self.symbol_3(&children[0]),
-            36 => self.token_literal_0(&children[0]),
-            37 => self.token_literal_1(&children[0]),
-            38 => self.token_literal_2(&children[0]),
-            39 => self.simple_token(&children[0], &children[1]),
-            40 => self.simple_token_opt_0(&children[0]),
-            41 => self.simple_token_opt_1(),
-            42 => self.token_with_states(
+            11 => self.declaration_4(&children[0], &children[1]),
+            12 => self.declaration_5(&children[0], &children[1]),
+            13 => self.declaration_6(&children[0], &children[1], &children[2]),
+            14 => self.declaration_7(&children[0], &children[1]),
+            15 => self.declaration_8(&children[0], &children[1], &children[2]),
+            16 => self.declaration_9(&children[0], &children[1]),
+            17 => self.declaration_10(&children[0]),
+            18 => self.declaration_11(&children[0]),
+            19 => self.declaration_opt_0(&children[0]),
+            20 => self.declaration_opt_1(),
+            21 => self.expect_directive(&children[0]),
+            22 => self.precedence_declaration(&children[0], &children[1], &children[2]),
+            23 => self.precedence_declaration_list_0(&children[0], &children[1]),
+            24 => self.precedence_declaration_list_1(),
+            25 => self.associativity_0(&children[0]),
+            26 => self.associativity_1(&children[0]),
+            27 => self.associativity_2(&children[0]),
+            28 => self.precedence_symbol_0(&children[0]),
+            29 => self.precedence_symbol_1(&children[0]),
+            30 => self.scanner_directives_0(&children[0], &children[1]),
+            31 => self.scanner_directives_1(&children[0], &children[1], &children[2]),
+            32 => self.scanner_directives_2(&children[0], &children[1], &children[2]),
+            33 => self.scanner_directives_3(&children[0]),
+            34 => self.scanner_directives_4(&children[0]),
+            35 => self.scanner_directives_5(&children[0]),
+            36 => self.scanner_directives_6(&children[0]),
+            37 => self.scanner_directives_7(&children[0], &children[1], &children[2], &children[3]),
+            38 => self.scanner_directives_8(&children[0], &children[1], &children[2], &children[3]),
+            39 => self.grammar_definition(&children[0], &children[1], &children[2]),
+            40 => self.grammar_definition_list_0(&children[0], &children[1]),
+            41 => self.grammar_definition_list_1(),
+            42 => self.double_colon(&children[0]),
+            43 => self.derive_target(&children[0], &children[1]),
+            44 => self.derive_list(&children[0], &children[1]),
+            45 => self.derive_list_list_0(&children[0], &children[1], &children[2]),
+            46 => self.derive_list_list_1(),
+            47 => self.production(&children[0], &children[1], &children[2], &children[3]),
+            48 => self.production_name(&children[0], &children[1]),
+            49 => self.production_name_opt_0(&children[0]),
+            50 => self.production_name_opt_1(),
+            51 => self.template_parameters(&children[0], &children[1], &children[2]),
+            52 => self.alternations(&children[0], &children[1]),
+            53 => self.alternations_list_0(&children[0], &children[1], &children[2]),
+            54 => self.alternations_list_1(),
+            55 => self.alternation(&children[0], &children[1], &children[2]),
+            56 => self.alternation_list_0(&children[0], &children[1]),
+            57 => self.alternation_list_1(),
+            58 => self.alternation_opt0_0(&children[0]),
+            59 => self.alternation_opt0_1(),
+            60 => self.alternation_opt_0(&children[0], &children[1]),
+            61 => self.alternation_opt_1(),
+            62 => self.alternation_label(&children[0], &children[1]),
+            63 => self.factor_0(&children[0]),
+            64 => self.factor_1(&children[0]),
+            65 => self.factor_2(&children[0]),
+            66 => self.factor_3(&children[0]),
+            67 => self.factor_4(&children[0], &children[1]),
+            68 => self.field_label(&children[0], &children[1]),
+            69 => self.symbol_0(&children[0]),
+            70 => self.symbol_1(&children[0]),
+            71 => self.symbol_2(&children[0]),
+            72 => self.symbol_3(&children[0]),
+            73 => self.token_literal_0(&children[0]),
+            74 => self.token_literal_1(&children[0]),
+            75 => self.token_literal_2(&children[0]),
+            76 => self.token_expression(&children[0], &children[1]),
+            77 => self.token_expression_opt_0(&children[0]),
+            78 => self.token_expression_opt_1(),
+            79 => self.trailing_context(&children[0], &children[1]),
+            80 => self.simple_token(&children[0], &children[1]),
+            81 => self.simple_token_opt_0(&children[0]),
+            82 => self.simple_token_opt_1(),
+            83 => self.string(&children[0]),
+            84 => self.raw_string(&children[0]),
+            85 => self.regex(&children[0]),
+            86 => self.group(&children[0], &children[1], &children[2]),
+            87 => self.optional(&children[0], &children[1], &children[2]),
+            88 => self.repeat(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 &children[4],
             ),
-            43 => self.token_with_states_opt_0(&children[0]),
-            44 => self.token_with_states_opt_1(),
-            45 => self.string(&children[0]),
-            46 => self.raw_string(&children[0]),
-            47 => self.regex(&children[0]),
-            48 => self.group(&children[0], &children[1], &children[2]),
-            49 => self.optional(&children[0], &children[1], &children[2]),
-            50 => self.repeat(&children[0], &children[1], &children[2]),
-            51 => self.non_terminal(&children[0], &children[1]),
-            52 => self.non_terminal_opt_0(&children[0]),
-            53 => self.non_terminal_opt_1(),
-            54 => self.identifier(&children[0]),
-            55 => self.scanner_state(
+            89 => self.repeat_opt0_0(&children[0]),
+            90 => self.repeat_opt0_1(),
+            91 => self.repeat_opt_0(&children[0]),
+            92 => self.repeat_opt_1(),
+            93 => self.non_terminal(&children[0], &children[1]),
+            94 => self.non_terminal_opt_0(&children[0]),
+            95 => self.non_terminal_opt_1(),
+            96 => self.angle_arguments(&children[0], &children[1], &children[2], &children[3]),
+            97 => self.angle_arguments_opt_0(&children[0]),
+            98 => self.angle_arguments_opt_1(),
+            99 => self.template_arguments(&children[0], &children[1]),
+            100 => self.template_arguments_list_0(&children[0], &children[1], &children[2]),
+            101 => self.template_arguments_list_1(),
+            102 => self.template_argument(&children[0], &children[1]),
+            103 => self.template_argument_list_0(&children[0], &children[1]),
+            104 => self.template_argument_list_1(),
+            105 => self.identifier(&children[0]),
+            106 => self.number(&children[0]),
+            107 => self.scanner_state(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 &children[4],
             ),
-            56 => self.scanner_state_list_0(&children[0], &children[1]),
-            57 => self.scanner_state_list_1(),
-            58 => self.identifier_list(&children[0], &children[1]),
-            59 => self.identifier_list_list_0(&children[0], &children[1], &children[2]),
-            60 => self.identifier_list_list_1(),
-            61 => self.scanner_switch_0(&children[0], &children[1], &children[2], &children[3]),
-            62 => self.scanner_switch_1(&children[0], &children[1], &children[2], &children[3]),
-            63 => self.scanner_switch_2(&children[0], &children[1], &children[2]),
-            64 => self.scanner_switch_opt_0(&children[0]),
-            65 => self.scanner_switch_opt_1(),
-            66 => self.a_s_t_control_0(&children[0]),
-            67 => self.a_s_t_control_1(&children[0]),
-            68 => self.cut_operator(&children[0]),
-            69 => self.user_type_declaration(&children[0], &children[1]),
-            70 => self.user_type_name(&children[0], &children[1]),
-            71 => self.user_type_name_list_0(&children[0], &children[1], &children[2]),
-            72 => self.user_type_name_list_1(),
+            108 => self.scanner_state_list_0(&children[0], &children[1]),
+            109 => self.scanner_state_list_1(),
+            110 => self.identifier_list(&children[0], &children[1]),
+            111 => self.identifier_list_list_0(&children[0], &children[1], &children[2]),
+            112 => self.identifier_list_list_1(),
+            113 => self.scanner_switch_0(&children[0], &children[1], &children[2], &children[3]),
+            114 => self.scanner_switch_1(&children[0], &children[1], &children[2], &children[3]),
+            115 => self.scanner_switch_2(&children[0], &children[1], &children[2]),
+            116 => self.scanner_switch_opt_0(&children[0]),
+            117 => self.scanner_switch_opt_1(),
+            118 => self.a_s_t_control_0(&children[0]),
+            119 => self.a_s_t_control_1(&children[0]),
+            120 => self.cut_operator(&children[0]),
+            121 => self.user_type_declaration(&children[0], &children[1]),
+            122 => self.user_type_name(&children[0], &children[1]),
+            123 => self.user_type_name_list_0(&children[0], &children[1], &children[2]),
+            124 => self.user_type_name_list_1(),
+            125 => self.repeat_separator(&children[0], &children[1], &children[2]),
+            126 => self.repeat_separator_list_0(&children[0], &children[1]),
+            127 => self.repeat_separator_list_1(),
+            128 => self.one_or_more(&children[0]),
             _ => Err(ParserError::InternalError(format!(
                 "Unhandled production number: {}",
                 prod_num
@@ -18,7 +18,7 @@ use parol_runtime::lexer::tokenizer::{
     ERROR_TOKEN, NEW_LINE_TOKEN, UNMATCHABLE_TOKEN, WHITESPACE_TOKEN,
 };
 
-pub const TERMINALS: &[&str; 41] = &[
+pub const TERMINALS: &[&str; 60] = &[
     /*  0 */ UNMATCHABLE_TOKEN,
     /*  1 */ UNMATCHABLE_TOKEN,
     /*  2 */ UNMATCHABLE_TOKEN,
@@ -30,39 +30,58 @@ pub const TERMINALS: &[&str; 41] = &[
     /*  8 */ r"%user_type",
     /*  9 */ r"=",
     /* 10 */ r"%grammar_type",
-    /* 11 */ r"%line_comment",
-    /* 12 */ r"%block_comment",
-    /* 13 */ r"%auto_newline_off",
-    /* 14 */ r"%auto_ws_off",
-    /* 15 */ r"%on",
-    /* 16 */ r"%enter",
-    /* 17 */ r"%%",
-    /* 18 */ r"::",
-    /* 19 */ r":",
-    /* 20 */ r";",
-    /* 21 */ r"\|",
-    /* 22 */ r"<",
-    /* 23 */ r">",
-    /* 24 */ r#""(\\.|[^\\])*?""#,
-    /* 25 */ r"'(\\'|[^'])*?'",
-    /* 26 */ r"\u{2F}(\\.|[^\\])*?\u{2F}",
-    /* 27 */ r"\(",
-    /* 28 */ r"\)",
-    /* 29 */ r"\[",
-    /* 30 */ r"\]",
-    /* 31 */ r"\{",
-    /* 32 */ r"\}",
-    /* 33 */ r"[a-zA-Z_][a-zA-Z0-9_]*",
-    /* 34 */ r"%scanner",
-    /* 35 */ r",",
-    /* 36 */ r"%sc",
-    /* 37 */ r"%push",
-    /* 38 */ r"%pop",
-    /* 39 */ r"\^",
-    /* 40 */ ERROR_TOKEN,
+    /* 11 */ r"%include",
+    /* 12 */ r"%keywords",
+    /* 13 */ r"%derive",
+    /* 14 */ r"%inline",
+    /* 15 */ r"%display",
+    /* 16 */ r"%expect(-rr)?",
+    /* 17 */ r"%left",
+    /* 18 */ r"%right",
+    /* 19 */ r"%nonassoc",
+    /* 20 */ r"%line_comment",
+    /* 21 */ r"%block_comment",
+    /* 22 */ r"%nested_block_comment",
+    /* 23 */ r"%auto_newline_off",
+    /* 24 */ r"%auto_ws_off",
+    /* 25 */ r"%longest_match",
+    /* 26 */ r"%case_insensitive",
+    /* 27 */ r"%offside",
+    /* 28 */ r"%on",
+    /* 29 */ r"%enter",
+    /* 30 */ r"%%",
+    /* 31 */ r"::",
+    /* 32 */ r":",
+    /* 33 */ r",",
+    /* 34 */ r";",
+    /* 35 */ r"<",
+    /* 36 */ r">",
+    /* 37 */ r"\|",
+    /* 38 */ r"%prec",
+    /* 39 */ r"\#",
+    /* 40 */ r"\?=",
+    /* 41 */ r#""(\\.|[^\\])*?"i?"#,
+    /* 42 */ r"'(\\'|[^'])*?'i?",
+    /* 43 */ r"\u{2F}(\\.|[^\\])*?\u{2F}i?",
+    /* 44 */ r"\(",
+    /* 45 */ r"\)",
+    /* 46 */ r"\[",
+    /* 47 */ r"\]",
+    /* 48 */ r"\{",
+    /* 49 */ r"\}",
+    /* 50 */ r"[a-zA-Z_][a-zA-Z0-9_]*",
+    /* 51 */ r"[0-9]+",
+    /* 52 */ r"%scanner",
+    /* 53 */ r"%sc",
+    /* 54 */ r"%push",
+    /* 55 */ r"%pop",
+    /* 56 */ r"\^",
+    /* 57 */ r"%",
+    /* 58 */ r"\+",
+    /* 59 */ ERROR_TOKEN,
 ];
 
-pub const TERMINAL_NAMES: &[&str; 41] = &[
+pub const TERMINAL_NAMES: &[&str; 60] = &[
     /*  0 */ "EndOfInput",
     /*  1 */ "Newline",
     /*  2 */ "Whitespace",
@@ -74,40 +93,59 @@ pub const TERMINAL_NAMES: &[&str; 41] = &[
     /*  8 */ "PercentUserUnderscoreType",
     /*  9 */ "Equ",
     /* 10 */ "PercentGrammarUnderscoreType",
-    /* 11 */ "PercentLineUnderscoreComment",
-    /* 12 */ "PercentBlockUnderscoreComment",
-    /* 13 */ "PercentAutoUnderscoreNewlineUnderscoreOff",
-    /* 14 */ "PercentAutoUnderscoreWsUnderscoreOff",
-    /* 15 */ "PercentOn",
-    /* 16 */ "PercentEnter",
-    /* 17 */ "PercentPercent",
-    /* 18 */ "DoubleColon",
-    /* 19 */ "Colon",
-    /* 20 */ "Semicolon",
-    /* 21 */ "Or",
-    /* 22 */ "LT",
-    /* 23 */ "GT",
-    /* 24 */ "String",
-    /* 25 */ "RawString",
-    /* 26 */ "Regex",
-    /* 27 */ "LParen",
-    /* 28 */ "RParen",
-    /* 29 */ "LBracket",
-    /* 30 */ "RBracket",
-    /* 31 */ "LBrace",
-    /* 32 */ "RBrace",
-    /* 33 */ "Identifier",
-    /* 34 */ "PercentScanner",
-    /* 35 */ "Comma",
-    /* 36 */ "PercentSc",
-    /* 37 */ "PercentPush",
-    /* 38 */ "PercentPop",
-    /* 39 */ "CutOperator",
-    /* 40 */ "Error",
+    /* 11 */ "PercentInclude",
+    /* 12 */ "PercentKeywords",
+    /* 13 */ "PercentDerive",
+    /* 14 */ "PercentInline",
+    /* 15 */ "PercentDisplay",
+    /* 16 */ "ExpectDirective",
+    /* 17 */ "PercentLeft",
+    /* 18 */ "PercentRight",
+    /* 19 */ "PercentNonassoc",
+    /* 20 */ "PercentLineUnderscoreComment",
+    /* 21 */ "PercentBlockUnderscoreComment",
+    /* 22 */ "PercentNestedUnderscoreBlockUnderscoreComment",
+    /* 23 */ "PercentAutoUnderscoreNewlineUnderscoreOff",
+    /* 24 */ "PercentAutoUnderscoreWsUnderscoreOff",
+    /* 25 */ "PercentLongestUnderscoreMatch",
+    /* 26 */ "PercentCaseUnderscoreInsensitive",
+    /* 27 */ "PercentOffside",
+    /* 28 */ "PercentOn",
+    /* 29 */ "PercentEnter",
+    /* 30 */ "PercentPercent",
+    /* 31 */ "DoubleColon",
+    /* 32 */ "Colon",
+    /* 33 */ "Comma",
+    /* 34 */ "Semicolon",
+    /* 35 */ "LT",
+    /* 36 */ "GT",
+    /* 37 */ "Or",
+    /* 38 */ "PercentPrec",
+    /* 39 */ "Hash",
+    /* 40 */ "QuestEqu",
+    /* 41 */ "String",
+    /* 42 */ "RawString",
+    /* 43 */ "Regex",
+    /* 44 */ "LParen",
+    /* 45 */ "RParen",
+    /* 46 */ "LBracket",
+    /* 47 */ "RBracket",
+    /* 48 */ "LBrace",
+    /* 49 */ "RBrace",
+    /* 50 */ "Identifier",
+    /* 51 */ "Number",
+    /* 52 */ "PercentScanner",
+    /* 53 */ "PercentSc",
+    /* 54 */ "PercentPush",
+    /* 55 */ "PercentPop",
+    /* 56 */ "CutOperator",
+    /* 57 */ "Percent",
+    /* 58 */ "OneOrMore",
+    /* 59 */ "Error",
 ];
 
 /* SCANNER_0: "INITIAL" */
-const SCANNER_0: (&[&str; 5], &[TerminalIndex; 35]) = (
+const SCANNER_0: (&[&str; 5], &[TerminalIndex; 54]) = (
     &[
         /*  0 */ UNMATCHABLE_TOKEN,
         /*  1 */ NEW_LINE_TOKEN,
@@ -122,146 +160,279 @@ const SCANNER_0: (&[&str; 5], &[TerminalIndex; 35]) = (
         8,  /* PercentUserUnderscoreType */
         9,  /* Equ */
         10, /* PercentGrammarUnderscoreType */
-        11, /* PercentLineUnderscoreComment */
-        12, /* PercentBlockUnderscoreComment */
-        13, /* PercentAutoUnderscoreNewlineUnderscoreOff */
-        14, /* PercentAutoUnderscoreWsUnderscoreOff */
-        15, /* PercentOn */
-        16, /* PercentEnter */
-        17, /* PercentPercent */
-        18, /* DoubleColon */
-        19, /* Colon */
-        20, /* Semicolon */
-        21, /* Or */
-        22, /* LT */
-        23, /* GT */
-        24, /* String */
-        25, /* RawString */
-        26, /* Regex */
-        27, /* LParen */
-        28, /* RParen */
-        29, /* LBracket */
-        30, /* RBracket */
-        31, /* LBrace */
-        32, /* RBrace */
-        33, /* Identifier */
-        34, /* PercentScanner */
-        35, /* Comma */
-        36, /* PercentSc */
-        37, /* PercentPush */
-        38, /* PercentPop */
-        39, /* CutOperator */
+        11, /* PercentInclude */
+        12, /* PercentKeywords */
+        13, /* PercentDerive */
+        14, /* PercentInline */
+        15, /* PercentDisplay */
+        16, /* ExpectDirective */
+        17, /* PercentLeft */
+        18, /* PercentRight */
+        19, /* PercentNonassoc */
+        20, /* PercentLineUnderscoreComment */
+        21, /* PercentBlockUnderscoreComment */
+        22, /* PercentNestedUnderscoreBlockUnderscoreComment */
+        23, /* PercentAutoUnderscoreNewlineUnderscoreOff */
+        24, /* PercentAutoUnderscoreWsUnderscoreOff */
+        25, /* PercentLongestUnderscoreMatch */
+        26, /* PercentCaseUnderscoreInsensitive */
+        27, /* PercentOffside */
+        28, /* PercentOn */
+        29, /* PercentEnter */
+        30, /* PercentPercent */
+        31, /* DoubleColon */
+        32, /* Colon */
+        33, /* Comma */
+        34, /* Semicolon */
+        35, /* LT */
+        36, /* GT */
+        37, /* Or */
+        38, /* PercentPrec */
+        39, /* Hash */
+        40, /* QuestEqu */
+        41, /* String */
+        42, /* RawString */
+        43, /* Regex */
+        44, /* LParen */
+        45, /* RParen */
+        46, /* LBracket */
+        47, /* RBracket */
+        48, /* LBrace */
+        49, /* RBrace */
+        50, /* Identifier */
+        51, /* Number */
+        52, /* PercentScanner */
+        53, /* PercentSc */
+        54, /* PercentPush */
+        55, /* PercentPop */
+        56, /* CutOperator */
+        57, /* Percent */
+        58, /* OneOrMore */
     ],
 );
 
-const MAX_K: usize = 1;
+const MAX_K: usize = 2;
 
-pub const NON_TERMINALS: &[&str; 42] = &[
+pub const NON_TERMINALS: &[&str; 71] = &[
     /*  0 */ "ASTControl",
     /*  1 */ "Alternation",
-    /*  2 */ "AlternationList",
-    /*  3 */ "Alternations",
-    /*  4 */ "AlternationsList",
-    /*  5 */ "CutOperator",
-    /*  6 */ "Declaration",
-    /*  7 */ "DoubleColon",
-    /*  8 */ "Factor",
-    /*  9 */ "GrammarDefinition",
-    /* 10 */ "GrammarDefinitionList",
-    /* 11 */ "Group",
-    /* 12 */ "Identifier",
-    /* 13 */ "IdentifierList",
-    /* 14 */ "IdentifierListList",
-    /* 15 */ "NonTerminal",
-    /* 16 */ "NonTerminalOpt",
-    /* 17 */ "Optional",
-    /* 18 */ "Parol",
-    /* 19 */ "Production",
-    /* 20 */ "Prolog",
-    /* 21 */ "PrologList",
-    /* 22 */ "PrologList0",
-    /* 23 */ "RawString",
-    /* 24 */ "Regex",
-    /* 25 */ "Repeat",
-    /* 26 */ "ScannerDirectives",
-    /* 27 */ "ScannerState",
-    /* 28 */ "ScannerStateList",
-    /* 29 */ "ScannerSwitch",
-    /* 30 */ "ScannerSwitchOpt",
-    /* 31 */ "SimpleToken",
-    /* 32 */ "SimpleTokenOpt",
-    /* 33 */ "StartDeclaration",
-    /* 34 */ "String",
-    /* 35 */ "Symbol",
-    /* 36 */ "TokenLiteral",
-    /* 37 */ "TokenWithStates",
-    /* 38 */ "TokenWithStatesOpt",
-    /* 39 */ "UserTypeDeclaration",
-    /* 40 */ "UserTypeName",
-    /* 41 */ "UserTypeNameList",
+    /*  2 */ "AlternationLabel",
+    /*  3 */ "AlternationList",
+    /*  4 */ "AlternationOpt",
+    /*  5 */ "AlternationOpt0",
+    /*  6 */ "Alternations",
+    /*  7 */ "AlternationsList",
+    /*  8 */ "AngleArguments",
+    /*  9 */ "AngleArgumentsOpt",
+    /* 10 */ "Associativity",
+    /* 11 */ "CutOperator",
+    /* 12 */ "Declaration",
+    /* 13 */ "DeclarationOpt",
+    /* 14 */ "DeriveList",
+    /* 15 */ "DeriveListList",
+    /* 16 */ "DeriveTarget",
+    /* 17 */ "DoubleColon",
+    /* 18 */ "ExpectDirective",
+    /* 19 */ "Factor",
+    /* 20 */ "FieldLabel",
+    /* 21 */ "GrammarDefinition",
+    /* 22 */ "GrammarDefinitionList",
+    /* 23 */ "Group",
+    /* 24 */ "Identifier",
+    /* 25 */ "IdentifierList",
+    /* 26 */ "IdentifierListList",
+    /* 27 */ "NonTerminal",
+    /* 28 */ "NonTerminalOpt",
+    /* 29 */ "Number",
+    /* 30 */ "OneOrMore",
+    /* 31 */ "Optional",
+    /* 32 */ "Parol",
+    /* 33 */ "PrecedenceDeclaration",
+    /* 34 */ "PrecedenceDeclarationList",
+    /* 35 */ "PrecedenceSymbol",
+    /* 36 */ "Production",
+    /* 37 */ "ProductionName",
+    /* 38 */ "ProductionNameOpt",
+    /* 39 */ "Prolog",
+    /* 40 */ "PrologList",
+    /* 41 */ "PrologList0",
+    /* 42 */ "RawString",
+    /* 43 */ "Regex",
+    /* 44 */ "Repeat",
+    /* 45 */ "RepeatOpt",
+    /* 46 */ "RepeatOpt0",
+    /* 47 */ "RepeatSeparator",
+    /* 48 */ "RepeatSeparatorList",
+    /* 49 */ "ScannerDirectives",
+    /* 50 */ "ScannerState",
+    /* 51 */ "ScannerStateList",
+    /* 52 */ "ScannerSwitch",
+    /* 53 */ "ScannerSwitchOpt",
+    /* 54 */ "SimpleToken",
+    /* 55 */ "SimpleTokenOpt",
+    /* 56 */ "StartDeclaration",
+    /* 57 */ "String",
+    /* 58 */ "Symbol",
+    /* 59 */ "TemplateArgument",
+    /* 60 */ "TemplateArgumentList",
+    /* 61 */ "TemplateArguments",
+    /* 62 */ "TemplateArgumentsList",
+    /* 63 */ "TemplateParameters",
+    /* 64 */ "TokenExpression",
+    /* 65 */ "TokenExpressionOpt",
+    /* 66 */ "TokenLiteral",
+    /* 67 */ "TrailingContext",
+    /* 68 */ "UserTypeDeclaration",
+    /* 69 */ "UserTypeName",
+    /* 70 */ "UserTypeNameList",
 ];
 
-pub const LOOKAHEAD_AUTOMATA: &[LookaheadDFA; 42] = &[
+pub const LOOKAHEAD_AUTOMATA: &[LookaheadDFA; 71] = &[
     /* 0 - "ASTControl" */
     LookaheadDFA {
         prod0: -1,
-        transitions: &[Trans(0, 19, 2, 67), Trans(0, 39, 1, 66)],
+        transitions: &[Trans(0, 32, 2, 119), Trans(0, 56, 1, 118)],
         k: 1,
     },
     /* 1 - "Alternation" */
     LookaheadDFA {
-        prod0: 25,
+        prod0: 55,
+        transitions: &[],
+        k: 0,
+    },
+    /* 2 - "AlternationLabel" */
+    LookaheadDFA {
+        prod0: 62,
         transitions: &[],
         k: 0,
     },
-    /* 2 - "AlternationList" */
+    /* 3 - "AlternationList" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 20, 2, 27),
-            Trans(0, 21, 2, 27),
-            Trans(0, 22, 1, 26),
-            Trans(0, 24, 1, 26),
-            Trans(0, 25, 1, 26),
-            Trans(0, 26, 1, 26),
-            Trans(0, 27, 1, 26),
-            Trans(0, 28, 2, 27),
-            Trans(0, 29, 1, 26),
-            Trans(0, 30, 2, 27),
-            Trans(0, 31, 1, 26),
-            Trans(0, 32, 2, 27),
-            Trans(0, 33, 1, 26),
-            Trans(0, 36, 1, 26),
-            Trans(0, 37, 1, 26),
-            Trans(0, 38, 1, 26),
+            Trans(0, 34, 2, 57),
+            Trans(0, 35, 1, 56),
+            Trans(0, 37, 2, 57),
+            Trans(0, 38, 2, 57),
+            Trans(0, 39, 2, 57),
+            Trans(0, 41, 1, 56),
+            Trans(0, 42, 1, 56),
+            Trans(0, 43, 1, 56),
+            Trans(0, 44, 1, 56),
+            Trans(0, 45, 2, 57),
+            Trans(0, 46, 1, 56),
+            Trans(0, 47, 2, 57),
+            Trans(0, 48, 1, 56),
+            Trans(0, 49, 2, 57),
+            Trans(0, 50, 1, 56),
+            Trans(0, 53, 1, 56),
+            Trans(0, 54, 1, 56),
+            Trans(0, 55, 1, 56),
+            Trans(0, 57, 2, 57),
         ],
         k: 1,
     },
-    /* 3 - "Alternations" */
+    /* 4 - "AlternationOpt" */
     LookaheadDFA {
-        prod0: 22,
+        prod0: -1,
+        transitions: &[
+            Trans(0, 34, 2, 61),
+            Trans(0, 37, 2, 61),
+            Trans(0, 38, 1, 60),
+            Trans(0, 39, 2, 61),
+            Trans(0, 45, 2, 61),
+            Trans(0, 47, 2, 61),
+            Trans(0, 49, 2, 61),
+            Trans(0, 57, 2, 61),
+        ],
+        k: 1,
+    },
+    /* 5 - "AlternationOpt0" */
+    LookaheadDFA {
+        prod0: -1,
+        transitions: &[
+            Trans(0, 34, 2, 59),
+            Trans(0, 37, 2, 59),
+            Trans(0, 39, 1, 58),
+            Trans(0, 45, 2, 59),
+            Trans(0, 47, 2, 59),
+            Trans(0, 49, 2, 59),
+            Trans(0, 57, 2, 59),
+        ],
+        k: 1,
+    },
+    /* 6 - "Alternations" */
+    LookaheadDFA {
+        prod0: 52,
         transitions: &[],
         k: 0,
     },
-    /* 4 - "AlternationsList" */
+    /* 7 - "AlternationsList" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 20, 2, 24),
-            Trans(0, 21, 1, 23),
-            Trans(0, 28, 2, 24),
-            Trans(0, 30, 2, 24),
-            Trans(0, 32, 2, 24),
+            Trans(0, 34, 2, 54),
+            Trans(0, 37, 1, 53),
+            Trans(0, 45, 2, 54),
+            Trans(0, 47, 2, 54),
+            Trans(0, 49, 2, 54),
+            Trans(0, 57, 2, 54),
         ],
         k: 1,
     },
-    /* 5 - "CutOperator" */
+    /* 8 - "AngleArguments" */
     LookaheadDFA {
-        prod0: 68,
+        prod0: 96,
+        transitions: &[],
+        k: 0,
+    },
+    /* 9 - "AngleArgumentsOpt" */
+    LookaheadDFA {
+        prod0: -1,
+        transitions: &[
+            Trans(0, 32, 1, 97),
+            Trans(0, 33, 2, 98),
+            Trans(0, 34, 2, 98),
+            Trans(0, 35, 2, 98),
+            Trans(0, 36, 2, 98),
+            Trans(0, 37, 2, 98),
+            Trans(0, 38, 2, 98),
+            Trans(0, 39, 2, 98),
+            Trans(0, 41, 2, 98),
+            Trans(0, 42, 2, 98),
+            Trans(0, 43, 2, 98),
+            Trans(0, 44, 2, 98),
+            Trans(0, 45, 2, 98),
+            Trans(0, 46, 2, 98),
+            Trans(0, 47, 2, 98),
+            Trans(0, 48, 2, 98),
+            Trans(0, 49, 2, 98),
+            Trans(0, 50, 2, 98),
+            Trans(0, 53, 2, 98),
+            Trans(0, 54, 2, 98),
+            Trans(0, 55, 2, 98),
+            Trans(0, 56, 1, 97),
+            Trans(0, 57, 2, 98),
+        ],
+        k: 1,
+    },
+    /* 10 - "Associativity" */
+    LookaheadDFA {
+        prod0: -1,
+        transitions: &[
+            Trans(0, 17, 1, 25),
+            Trans(0, 18, 2, 26),
+            Trans(0, 19, 3, 27),
+        ],
+        k: 1,
+    },
+    /* 11 - "CutOperator" */
+    LookaheadDFA {
+        prod0: 120,
         transitions: &[],
         k: 0,
     },
-    /* 6 - "Declaration" */
+    /* 12 - "Declaration" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
@@ -270,133 +441,448 @@ pub const LOOKAHEAD_AUTOMATA: &[LookaheadDFA; 42] = &[
             Trans(0, 8, 3, 9),
             Trans(0, 10, 4, 10),
             Trans(0, 11, 5, 11),
-            Trans(0, 12, 5, 11),
-            Trans(0, 13, 5, 11),
-            Trans(0, 14, 5, 11),
-            Trans(0, 15, 5, 11),
+            Trans(0, 12, 6, 12),
+            Trans(0, 13, 7, 13),
+            Trans(0, 14, 8, 14),
+            Trans(0, 15, 9, 15),
+            Trans(0, 16, 10, 16),
+            Trans(0, 17, 11, 17),
+            Trans(0, 18, 11, 17),
+            Trans(0, 19, 11, 17),
+            Trans(0, 20, 12, 18),
+            Trans(0, 21, 12, 18),
+            Trans(0, 22, 12, 18),
+            Trans(0, 23, 12, 18),
+            Trans(0, 24, 12, 18),
+            Trans(0, 25, 12, 18),
+            Trans(0, 26, 12, 18),
+            Trans(0, 27, 12, 18),
+            Trans(0, 28, 12, 18),
         ],
         k: 1,
     },
-    /* 7 - "DoubleColon" */
+    /* 13 - "DeclarationOpt" */
     LookaheadDFA {
-        prod0: 20,
+        prod0: -1,
+        transitions: &[
+            Trans(0, 50, 1, -1),
+            Trans(1, 6, 3, 20),
+            Trans(1, 7, 3, 20),
+            Trans(1, 8, 3, 20),
+            Trans(1, 10, 3, 20),
+            Trans(1, 11, 3, 20),
+            Trans(1, 12, 3, 20),
+            Trans(1, 13, 3, 20),
+            Trans(1, 14, 3, 20),
+            Trans(1, 15, 3, 20),
+            Trans(1, 16, 3, 20),
+            Trans(1, 17, 3, 20),
+            Trans(1, 18, 3, 20),
+            Trans(1, 19, 3, 20),
+            Trans(1, 20, 3, 20),
+            Trans(1, 21, 3, 20),
+            Trans(1, 22, 3, 20),
+            Trans(1, 23, 3, 20),
+            Trans(1, 24, 3, 20),
+            Trans(1, 25, 3, 20),
+            Trans(1, 26, 3, 20),
+            Trans(1, 27, 3, 20),
+            Trans(1, 28, 3, 20),
+            Trans(1, 30, 3, 20),
+            Trans(1, 31, 3, 20),
+            Trans(1, 32, 2, 19),
+            Trans(1, 33, 3, 20),
+            Trans(1, 52, 3, 20),
+        ],
+        k: 2,
+    },
+    /* 14 - "DeriveList" */
+    LookaheadDFA {
+        prod0: 44,
         transitions: &[],
         k: 0,
     },
-    /* 8 - "Factor" */
+    /* 15 - "DeriveListList" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 22, 4, 31),
-            Trans(0, 24, 4, 31),
-            Trans(0, 25, 4, 31),
-            Trans(0, 26, 4, 31),
-            Trans(0, 27, 1, 28),
-            Trans(0, 29, 3, 30),
-            Trans(0, 31, 2, 29),
-            Trans(0, 33, 4, 31),
-            Trans(0, 36, 4, 31),
-            Trans(0, 37, 4, 31),
-            Trans(0, 38, 4, 31),
+            Trans(0, 6, 2, 46),
+            Trans(0, 7, 2, 46),
+            Trans(0, 8, 2, 46),
+            Trans(0, 10, 2, 46),
+            Trans(0, 11, 2, 46),
+            Trans(0, 12, 2, 46),
+            Trans(0, 13, 2, 46),
+            Trans(0, 14, 2, 46),
+            Trans(0, 15, 2, 46),
+            Trans(0, 16, 2, 46),
+            Trans(0, 17, 2, 46),
+            Trans(0, 18, 2, 46),
+            Trans(0, 19, 2, 46),
+            Trans(0, 20, 2, 46),
+            Trans(0, 21, 2, 46),
+            Trans(0, 22, 2, 46),
+            Trans(0, 23, 2, 46),
+            Trans(0, 24, 2, 46),
+            Trans(0, 25, 2, 46),
+            Trans(0, 26, 2, 46),
+            Trans(0, 27, 2, 46),
+            Trans(0, 28, 2, 46),
+            Trans(0, 30, 2, 46),
+            Trans(0, 33, 1, 45),
+            Trans(0, 52, 2, 46),
         ],
         k: 1,
     },
-    /* 9 - "GrammarDefinition" */
+    /* 16 - "DeriveTarget" */
+    LookaheadDFA {
+        prod0: 43,
+        transitions: &[],
+        k: 0,
+    },
+    /* 17 - "DoubleColon" */
+    LookaheadDFA {
+        prod0: 42,
+        transitions: &[],
+        k: 0,
+    },
+    /* 18 - "ExpectDirective" */
+    LookaheadDFA {
+        prod0: 21,
+        transitions: &[],
+        k: 0,
+    },
+    /* 19 - "Factor" */
+    LookaheadDFA {
+        prod0: -1,
+        transitions: &[
+            Trans(0, 35, 7, -1),
+            Trans(0, 41, 8, -1),
+            Trans(0, 42, 8, -1),
+            Trans(0, 43, 8, -1),
+            Trans(0, 44, 1, -1),
+            Trans(0, 46, 5, -1),
+            Trans(0, 48, 3, -1),
+            Trans(0, 50, 9, -1),
+            Trans(0, 53, 10, -1),
+            Trans(0, 54, 10, -1),
+            Trans(0, 55, 10, -1),
+            Trans(1, 35, 2, 63),
+            Trans(1, 37, 2, 63),
+            Trans(1, 38, 2, 63),
+            Trans(1, 39, 2, 63),
+            Trans(1, 41, 2, 63),
+            Trans(1, 42, 2, 63),
+            Trans(1, 43, 2, 63),
+            Trans(1, 44, 2, 63),
+            Trans(1, 45, 2, 63),
+            Trans(1, 46, 2, 63),
+            Trans(1, 48, 2, 63),
+            Trans(1, 50, 2, 63),
+            Trans(1, 53, 2, 63),
+            Trans(1, 54, 2, 63),
+            Trans(1, 55, 2, 63),
+            Trans(3, 35, 4, 64),
+            Trans(3, 37, 4, 64),
+            Trans(3, 38, 4, 64),
+            Trans(3, 39, 4, 64),
+            Trans(3, 41, 4, 64),
+            Trans(3, 42, 4, 64),
+            Trans(3, 43, 4, 64),
+            Trans(3, 44, 4, 64),
+            Trans(3, 46, 4, 64),
+            Trans(3, 48, 4, 64),
+            Trans(3, 49, 4, 64),
+            Trans(3, 50, 4, 64),
+            Trans(3, 53, 4, 64),
+            Trans(3, 54, 4, 64),
+            Trans(3, 55, 4, 64),
+            Trans(3, 57, 4, 64),
+            Trans(5, 35, 6, 65),
+            Trans(5, 37, 6, 65),
+            Trans(5, 38, 6, 65),
+            Trans(5, 39, 6, 65),
+            Trans(5, 41, 6, 65),
+            Trans(5, 42, 6, 65),
+            Trans(5, 43, 6, 65),
+            Trans(5, 44, 6, 65),
+            Trans(5, 46, 6, 65),
+            Trans(5, 47, 6, 65),
+            Trans(5, 48, 6, 65),
+            Trans(5, 50, 6, 65),
+            Trans(5, 53, 6, 65),
+            Trans(5, 54, 6, 65),
+            Trans(5, 55, 6, 65),
+            Trans(7, 35, 11, 66),
+            Trans(7, 41, 11, 66),
+            Trans(7, 42, 11, 66),
+            Trans(7, 43, 11, 66),
+            Trans(7, 44, 11, 66),
+            Trans(7, 46, 11, 66),
+            Trans(7, 48, 11, 66),
+            Trans(7, 50, 11, 66),
+            Trans(7, 53, 11, 66),
+            Trans(7, 54, 11, 66),
+            Trans(7, 55, 11, 66),
+            Trans(8, 32, 11, 66),
+            Trans(8, 33, 11, 66),
+            Trans(8, 34, 11, 66),
+            Trans(8, 35, 11, 66),
+            Trans(8, 36, 11, 66),
+            Trans(8, 37, 11, 66),
+            Trans(8, 38, 11, 66),
+            Trans(8, 39, 11, 66),
+            Trans(8, 40, 11, 66),
+            Trans(8, 41, 11, 66),
+            Trans(8, 42, 11, 66),
+            Trans(8, 43, 11, 66),
+            Trans(8, 44, 11, 66),
+            Trans(8, 45, 11, 66),
+            Trans(8, 46, 11, 66),
+            Trans(8, 47, 11, 66),
+            Trans(8, 48, 11, 66),
+            Trans(8, 49, 11, 66),
+            Trans(8, 50, 11, 66),
+            Trans(8, 53, 11, 66),
+            Trans(8, 54, 11, 66),
+            Trans(8, 55, 11, 66),
+            Trans(8, 56, 11, 66),
+            Trans(8, 57, 11, 66),
+            Trans(9, 9, 12, 67),
+            Trans(9, 32, 11, 66),
+            Trans(9, 33, 11, 66),
+            Trans(9, 34, 11, 66),
+            Trans(9, 35, 11, 66),
+            Trans(9, 36, 11, 66),
+            Trans(9, 37, 11, 66),
+            Trans(9, 38, 11, 66),
+            Trans(9, 39, 11, 66),
+            Trans(9, 41, 11, 66),
+            Trans(9, 42, 11, 66),
+            Trans(9, 43, 11, 66),
+            Trans(9, 44, 11, 66),
+            Trans(9, 45, 11, 66),
+            Trans(9, 46, 11, 66),
+            Trans(9, 47, 11, 66),
+            Trans(9, 48, 11, 66),
+            Trans(9, 49, 11, 66),
+            Trans(9, 50, 11, 66),
+            Trans(9, 53, 11, 66),
+            Trans(9, 54, 11, 66),
+            Trans(9, 55, 11, 66),
+            Trans(9, 56, 11, 66),
+            Trans(9, 57, 11, 66),
+            Trans(10, 44, 11, 66),
+        ],
+        k: 2,
+    },
+    /* 20 - "FieldLabel" */
     LookaheadDFA {
-        prod0: 17,
+        prod0: 68,
         transitions: &[],
         k: 0,
     },
-    /* 10 - "GrammarDefinitionList" */
+    /* 21 - "GrammarDefinition" */
+    LookaheadDFA {
+        prod0: 39,
+        transitions: &[],
+        k: 0,
+    },
+    /* 22 - "GrammarDefinitionList" */
     LookaheadDFA {
         prod0: -1,
-        transitions: &[Trans(0, 0, 2, 19), Trans(0, 33, 1, 18)],
+        transitions: &[Trans(0, 0, 2, 41), Trans(0, 50, 1, 40)],
         k: 1,
     },
-    /* 11 - "Group" */
+    /* 23 - "Group" */
     LookaheadDFA {
-        prod0: 48,
+        prod0: 86,
         transitions: &[],
         k: 0,
     },
-    /* 12 - "Identifier" */
+    /* 24 - "Identifier" */
     LookaheadDFA {
-        prod0: 54,
+        prod0: 105,
         transitions: &[],
         k: 0,
     },
-    /* 13 - "IdentifierList" */
+    /* 25 - "IdentifierList" */
     LookaheadDFA {
-        prod0: 58,
+        prod0: 110,
         transitions: &[],
         k: 0,
     },
-    /* 14 - "IdentifierListList" */
+    /* 26 - "IdentifierListList" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 16, 2, 60),
-            Trans(0, 23, 2, 60),
-            Trans(0, 35, 1, 59),
+            Trans(0, 6, 2, 112),
+            Trans(0, 7, 2, 112),
+            Trans(0, 8, 2, 112),
+            Trans(0, 10, 2, 112),
+            Trans(0, 11, 2, 112),
+            Trans(0, 12, 2, 112),
+            Trans(0, 13, 2, 112),
+            Trans(0, 14, 2, 112),
+            Trans(0, 15, 2, 112),
+            Trans(0, 16, 2, 112),
+            Trans(0, 17, 2, 112),
+            Trans(0, 18, 2, 112),
+            Trans(0, 19, 2, 112),
+            Trans(0, 20, 2, 112),
+            Trans(0, 21, 2, 112),
+            Trans(0, 22, 2, 112),
+            Trans(0, 23, 2, 112),
+            Trans(0, 24, 2, 112),
+            Trans(0, 25, 2, 112),
+            Trans(0, 26, 2, 112),
+            Trans(0, 27, 2, 112),
+            Trans(0, 28, 2, 112),
+            Trans(0, 29, 2, 112),
+            Trans(0, 30, 2, 112),
+            Trans(0, 33, 1, 111),
+            Trans(0, 36, 2, 112),
+            Trans(0, 52, 2, 112),
         ],
         k: 1,
     },
-    /* 15 - "NonTerminal" */
+    /* 27 - "NonTerminal" */
     LookaheadDFA {
-        prod0: 51,
+        prod0: 93,
         transitions: &[],
         k: 0,
     },
-    /* 16 - "NonTerminalOpt" */
+    /* 28 - "NonTerminalOpt" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 19, 1, 52),
-            Trans(0, 20, 2, 53),
-            Trans(0, 21, 2, 53),
-            Trans(0, 22, 2, 53),
-            Trans(0, 24, 2, 53),
-            Trans(0, 25, 2, 53),
-            Trans(0, 26, 2, 53),
-            Trans(0, 27, 2, 53),
-            Trans(0, 28, 2, 53),
-            Trans(0, 29, 2, 53),
-            Trans(0, 30, 2, 53),
-            Trans(0, 31, 2, 53),
-            Trans(0, 32, 2, 53),
-            Trans(0, 33, 2, 53),
-            Trans(0, 36, 2, 53),
-            Trans(0, 37, 2, 53),
-            Trans(0, 38, 2, 53),
-            Trans(0, 39, 1, 52),
+            Trans(0, 32, 1, 94),
+            Trans(0, 33, 2, 95),
+            Trans(0, 34, 2, 95),
+            Trans(0, 35, 2, 95),
+            Trans(0, 36, 2, 95),
+            Trans(0, 37, 2, 95),
+            Trans(0, 38, 2, 95),
+            Trans(0, 39, 2, 95),
+            Trans(0, 41, 2, 95),
+            Trans(0, 42, 2, 95),
+            Trans(0, 43, 2, 95),
+            Trans(0, 44, 2, 95),
+            Trans(0, 45, 2, 95),
+            Trans(0, 46, 2, 95),
+            Trans(0, 47, 2, 95),
+            Trans(0, 48, 2, 95),
+            Trans(0, 49, 2, 95),
+            Trans(0, 50, 2, 95),
+            Trans(0, 53, 2, 95),
+            Trans(0, 54, 2, 95),
+            Trans(0, 55, 2, 95),
+            Trans(0, 56, 1, 94),
+            Trans(0, 57, 2, 95),
         ],
         k: 1,
     },
-    /* 17 - "Optional" */
+    /* 29 - "Number" */
     LookaheadDFA {
-        prod0: 49,
+        prod0: 106,
         transitions: &[],
         k: 0,
     },
-    /* 18 - "Parol" */
+    /* 30 - "OneOrMore" */
+    LookaheadDFA {
+        prod0: 128,
+        transitions: &[],
+        k: 0,
+    },
+    /* 31 - "Optional" */
+    LookaheadDFA {
+        prod0: 87,
+        transitions: &[],
+        k: 0,
+    },
+    /* 32 - "Parol" */
     LookaheadDFA {
         prod0: 0,
         transitions: &[],
         k: 0,
     },
-    /* 19 - "Production" */
+    /* 33 - "PrecedenceDeclaration" */
     LookaheadDFA {
-        prod0: 21,
+        prod0: 22,
+        transitions: &[],
+        k: 0,
+    },
+    /* 34 - "PrecedenceDeclarationList" */
+    LookaheadDFA {
+        prod0: -1,
+        transitions: &[
+            Trans(0, 6, 2, 24),
+            Trans(0, 7, 2, 24),
+            Trans(0, 8, 2, 24),
+            Trans(0, 10, 2, 24),
+            Trans(0, 11, 2, 24),
+            Trans(0, 12, 2, 24),
+            Trans(0, 13, 2, 24),
+            Trans(0, 14, 2, 24),
+            Trans(0, 15, 2, 24),
+            Trans(0, 16, 2, 24),
+            Trans(0, 17, 2, 24),
+            Trans(0, 18, 2, 24),
+            Trans(0, 19, 2, 24),
+            Trans(0, 20, 2, 24),
+            Trans(0, 21, 2, 24),
+            Trans(0, 22, 2, 24),
+            Trans(0, 23, 2, 24),
+            Trans(0, 24, 2, 24),
+            Trans(0, 25, 2, 24),
+            Trans(0, 26, 2, 24),
+            Trans(0, 27, 2, 24),
+            Trans(0, 28, 2, 24),
+            Trans(0, 30, 2, 24),
+            Trans(0, 41, 1, 23),
+            Trans(0, 42, 1, 23),
+            Trans(0, 43, 1, 23),
+            Trans(0, 50, 1, 23),
+            Trans(0, 52, 2, 24),
+        ],
+        k: 1,
+    },
+    /* 35 - "PrecedenceSymbol" */
+    LookaheadDFA {
+        prod0: -1,
+        transitions: &[
+            Trans(0, 41, 1, 28),
+            Trans(0, 42, 1, 28),
+            Trans(0, 43, 1, 28),
+            Trans(0, 50, 2, 29),
+        ],
+        k: 1,
+    },
+    /* 36 - "Production" */
+    LookaheadDFA {
+        prod0: 47,
         transitions: &[],
         k: 0,
     },
-    /* 20 - "Prolog" */
+    /* 37 - "ProductionName" */
+    LookaheadDFA {
+        prod0: 48,
+        transitions: &[],
+        k: 0,
+    },
+    /* 38 - "ProductionNameOpt" */
+    LookaheadDFA {
+        prod0: -1,
+        transitions: &[Trans(0, 32, 2, 50), Trans(0, 35, 1, 49)],
+        k: 1,
+    },
+    /* 39 - "Prolog" */
     LookaheadDFA {
         prod0: 1,
         transitions: &[],
         k: 0,
     },
-    /* 21 - "PrologList" */
+    /* 40 - "PrologList" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
@@ -409,633 +895,1080 @@ pub const LOOKAHEAD_AUTOMATA: &[LookaheadDFA; 42] = &[
             Trans(0, 13, 1, 4),
             Trans(0, 14, 1, 4),
             Trans(0, 15, 1, 4),
-            Trans(0, 17, 2, 5),
-            Trans(0, 34, 2, 5),
+            Trans(0, 16, 1, 4),
+            Trans(0, 17, 1, 4),
+            Trans(0, 18, 1, 4),
+            Trans(0, 19, 1, 4),
+            Trans(0, 20, 1, 4),
+            Trans(0, 21, 1, 4),
+            Trans(0, 22, 1, 4),
+            Trans(0, 23, 1, 4),
+            Trans(0, 24, 1, 4),
+            Trans(0, 25, 1, 4),
+            Trans(0, 26, 1, 4),
+            Trans(0, 27, 1, 4),
+            Trans(0, 28, 1, 4),
+            Trans(0, 30, 2, 5),
+            Trans(0, 52, 2, 5),
         ],
         k: 1,
     },
-    /* 22 - "PrologList0" */
+    /* 41 - "PrologList0" */
     LookaheadDFA {
         prod0: -1,
-        transitions: &[Trans(0, 17, 2, 3), Trans(0, 34, 1, 2)],
+        transitions: &[Trans(0, 30, 2, 3), Trans(0, 52, 1, 2)],
         k: 1,
     },
-    /* 23 - "RawString" */
+    /* 42 - "RawString" */
     LookaheadDFA {
-        prod0: 46,
+        prod0: 84,
         transitions: &[],
         k: 0,
     },
-    /* 24 - "Regex" */
+    /* 43 - "Regex" */
     LookaheadDFA {
-        prod0: 47,
+        prod0: 85,
         transitions: &[],
         k: 0,
     },
-    /* 25 - "Repeat" */
+    /* 44 - "Repeat" */
     LookaheadDFA {
-        prod0: 50,
+        prod0: 88,
         transitions: &[],
         k: 0,
     },
-    /* 26 - "ScannerDirectives" */
+    /* 45 - "RepeatOpt" */
+    LookaheadDFA {
+        prod0: -1,
+        transitions: &[Trans(0, 49, 2, 92), Trans(0, 57, 1, 91)],
+        k: 1,
+    },
+    /* 46 - "RepeatOpt0" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 11, 1, 12),
-            Trans(0, 12, 2, 13),
-            Trans(0, 13, 3, 14),
-            Trans(0, 14, 4, 15),
-            Trans(0, 15, 5, 16),
+            Trans(0, 33, 2, 90),
+            Trans(0, 34, 2, 90),
+            Trans(0, 35, 2, 90),
+            Trans(0, 36, 2, 90),
+            Trans(0, 37, 2, 90),
+            Trans(0, 38, 2, 90),
+            Trans(0, 39, 2, 90),
+            Trans(0, 41, 2, 90),
+            Trans(0, 42, 2, 90),
+            Trans(0, 43, 2, 90),
+            Trans(0, 44, 2, 90),
+            Trans(0, 45, 2, 90),
+            Trans(0, 46, 2, 90),
+            Trans(0, 47, 2, 90),
+            Trans(0, 48, 2, 90),
+            Trans(0, 49, 2, 90),
+            Trans(0, 50, 2, 90),
+            Trans(0, 53, 2, 90),
+            Trans(0, 54, 2, 90),
+            Trans(0, 55, 2, 90),
+            Trans(0, 57, 2, 90),
+            Trans(0, 58, 1, 89),
         ],
         k: 1,
     },
-    /* 27 - "ScannerState" */
+    /* 47 - "RepeatSeparator" */
     LookaheadDFA {
-        prod0: 55,
+        prod0: 125,
         transitions: &[],
         k: 0,
     },
-    /* 28 - "ScannerStateList" */
+    /* 48 - "RepeatSeparatorList" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 11, 1, 56),
-            Trans(0, 12, 1, 56),
-            Trans(0, 13, 1, 56),
-            Trans(0, 14, 1, 56),
-            Trans(0, 15, 1, 56),
-            Trans(0, 32, 2, 57),
+            Trans(0, 35, 1, 126),
+            Trans(0, 41, 1, 126),
+            Trans(0, 42, 1, 126),
+            Trans(0, 43, 1, 126),
+            Trans(0, 49, 2, 127),
+            Trans(0, 50, 1, 126),
+            Trans(0, 53, 1, 126),
+            Trans(0, 54, 1, 126),
+            Trans(0, 55, 1, 126),
         ],
         k: 1,
     },
-    /* 29 - "ScannerSwitch" */
+    /* 49 - "ScannerDirectives" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 36, 1, 61),
-            Trans(0, 37, 2, 62),
-            Trans(0, 38, 3, 63),
+            Trans(0, 20, 1, 30),
+            Trans(0, 21, 2, 31),
+            Trans(0, 22, 3, 32),
+            Trans(0, 23, 4, 33),
+            Trans(0, 24, 5, 34),
+            Trans(0, 25, 6, 35),
+            Trans(0, 26, 7, 36),
+            Trans(0, 27, 8, 37),
+            Trans(0, 28, 9, 38),
         ],
         k: 1,
     },
-    /* 30 - "ScannerSwitchOpt" */
+    /* 50 - "ScannerState" */
+    LookaheadDFA {
+        prod0: 107,
+        transitions: &[],
+        k: 0,
+    },
+    /* 51 - "ScannerStateList" */
     LookaheadDFA {
         prod0: -1,
-        transitions: &[Trans(0, 28, 2, 65), Trans(0, 33, 1, 64)],
+        transitions: &[
+            Trans(0, 20, 1, 108),
+            Trans(0, 21, 1, 108),
+            Trans(0, 22, 1, 108),
+            Trans(0, 23, 1, 108),
+            Trans(0, 24, 1, 108),
+            Trans(0, 25, 1, 108),
+            Trans(0, 26, 1, 108),
+            Trans(0, 27, 1, 108),
+            Trans(0, 28, 1, 108),
+            Trans(0, 49, 2, 109),
+        ],
         k: 1,
     },
-    /* 31 - "SimpleToken" */
+    /* 52 - "ScannerSwitch" */
     LookaheadDFA {
-        prod0: 39,
+        prod0: -1,
+        transitions: &[
+            Trans(0, 53, 1, 113),
+            Trans(0, 54, 2, 114),
+            Trans(0, 55, 3, 115),
+        ],
+        k: 1,
+    },
+    /* 53 - "ScannerSwitchOpt" */
+    LookaheadDFA {
+        prod0: -1,
+        transitions: &[Trans(0, 45, 2, 117), Trans(0, 50, 1, 116)],
+        k: 1,
+    },
+    /* 54 - "SimpleToken" */
+    LookaheadDFA {
+        prod0: 80,
         transitions: &[],
         k: 0,
     },
-    /* 32 - "SimpleTokenOpt" */
+    /* 55 - "SimpleTokenOpt" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 19, 1, 40),
-            Trans(0, 20, 2, 41),
-            Trans(0, 21, 2, 41),
-            Trans(0, 22, 2, 41),
-            Trans(0, 24, 2, 41),
-            Trans(0, 25, 2, 41),
-            Trans(0, 26, 2, 41),
-            Trans(0, 27, 2, 41),
-            Trans(0, 28, 2, 41),
-            Trans(0, 29, 2, 41),
-            Trans(0, 30, 2, 41),
-            Trans(0, 31, 2, 41),
-            Trans(0, 32, 2, 41),
-            Trans(0, 33, 2, 41),
-            Trans(0, 36, 2, 41),
-            Trans(0, 37, 2, 41),
-            Trans(0, 38, 2, 41),
-            Trans(0, 39, 1, 40),
+            Trans(0, 32, 1, 81),
+            Trans(0, 33, 2, 82),
+            Trans(0, 34, 2, 82),
+            Trans(0, 35, 2, 82),
+            Trans(0, 36, 2, 82),
+            Trans(0, 37, 2, 82),
+            Trans(0, 38, 2, 82),
+            Trans(0, 39, 2, 82),
+            Trans(0, 41, 2, 82),
+            Trans(0, 42, 2, 82),
+            Trans(0, 43, 2, 82),
+            Trans(0, 44, 2, 82),
+            Trans(0, 45, 2, 82),
+            Trans(0, 46, 2, 82),
+            Trans(0, 47, 2, 82),
+            Trans(0, 48, 2, 82),
+            Trans(0, 49, 2, 82),
+            Trans(0, 50, 2, 82),
+            Trans(0, 53, 2, 82),
+            Trans(0, 54, 2, 82),
+            Trans(0, 55, 2, 82),
+            Trans(0, 56, 1, 81),
+            Trans(0, 57, 2, 82),
         ],
         k: 1,
     },
-    /* 33 - "StartDeclaration" */
+    /* 56 - "StartDeclaration" */
     LookaheadDFA {
         prod0: 6,
         transitions: &[],
         k: 0,
     },
-    /* 34 - "String" */
+    /* 57 - "String" */
     LookaheadDFA {
-        prod0: 45,
+        prod0: 83,
         transitions: &[],
         k: 0,
     },
-    /* 35 - "Symbol" */
+    /* 58 - "Symbol" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 22, 3, 34),
-            Trans(0, 24, 2, 33),
-            Trans(0, 25, 2, 33),
-            Trans(0, 26, 2, 33),
-            Trans(0, 33, 1, 32),
-            Trans(0, 36, 4, 35),
-            Trans(0, 37, 4, 35),
-            Trans(0, 38, 4, 35),
+            Trans(0, 35, 3, 71),
+            Trans(0, 41, 2, 70),
+            Trans(0, 42, 2, 70),
+            Trans(0, 43, 2, 70),
+            Trans(0, 50, 1, 69),
+            Trans(0, 53, 4, 72),
+            Trans(0, 54, 4, 72),
+            Trans(0, 55, 4, 72),
         ],
         k: 1,
     },
-    /* 36 - "TokenLiteral" */
+    /* 59 - "TemplateArgument" */
+    LookaheadDFA {
+        prod0: 102,
+        transitions: &[],
+        k: 0,
+    },
+    /* 60 - "TemplateArgumentList" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 24, 1, 36),
-            Trans(0, 25, 2, 37),
-            Trans(0, 26, 3, 38),
+            Trans(0, 33, 2, 104),
+            Trans(0, 35, 1, 103),
+            Trans(0, 36, 2, 104),
+            Trans(0, 41, 1, 103),
+            Trans(0, 42, 1, 103),
+            Trans(0, 43, 1, 103),
+            Trans(0, 44, 1, 103),
+            Trans(0, 46, 1, 103),
+            Trans(0, 48, 1, 103),
+            Trans(0, 50, 1, 103),
+            Trans(0, 53, 1, 103),
+            Trans(0, 54, 1, 103),
+            Trans(0, 55, 1, 103),
         ],
         k: 1,
     },
-    /* 37 - "TokenWithStates" */
+    /* 61 - "TemplateArguments" */
     LookaheadDFA {
-        prod0: 42,
+        prod0: 99,
+        transitions: &[],
+        k: 0,
+    },
+    /* 62 - "TemplateArgumentsList" */
+    LookaheadDFA {
+        prod0: -1,
+        transitions: &[Trans(0, 33, 1, 100), Trans(0, 36, 2, 101)],
+        k: 1,
+    },
+    /* 63 - "TemplateParameters" */
+    LookaheadDFA {
+        prod0: 51,
+        transitions: &[],
+        k: 0,
+    },
+    /* 64 - "TokenExpression" */
+    LookaheadDFA {
+        prod0: 76,
         transitions: &[],
         k: 0,
     },
-    /* 38 - "TokenWithStatesOpt" */
+    /* 65 - "TokenExpressionOpt" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 19, 1, 43),
-            Trans(0, 20, 2, 44),
-            Trans(0, 21, 2, 44),
-            Trans(0, 22, 2, 44),
-            Trans(0, 24, 2, 44),
-            Trans(0, 25, 2, 44),
-            Trans(0, 26, 2, 44),
-            Trans(0, 27, 2, 44),
-            Trans(0, 28, 2, 44),
-            Trans(0, 29, 2, 44),
-            Trans(0, 30, 2, 44),
-            Trans(0, 31, 2, 44),
-            Trans(0, 32, 2, 44),
-            Trans(0, 33, 2, 44),
-            Trans(0, 36, 2, 44),
-            Trans(0, 37, 2, 44),
-            Trans(0, 38, 2, 44),
-            Trans(0, 39, 1, 43),
+            Trans(0, 32, 2, 78),
+            Trans(0, 33, 2, 78),
+            Trans(0, 34, 2, 78),
+            Trans(0, 35, 2, 78),
+            Trans(0, 36, 2, 78),
+            Trans(0, 37, 2, 78),
+            Trans(0, 38, 2, 78),
+            Trans(0, 39, 2, 78),
+            Trans(0, 40, 1, 77),
+            Trans(0, 41, 2, 78),
+            Trans(0, 42, 2, 78),
+            Trans(0, 43, 2, 78),
+            Trans(0, 44, 2, 78),
+            Trans(0, 45, 2, 78),
+            Trans(0, 46, 2, 78),
+            Trans(0, 47, 2, 78),
+            Trans(0, 48, 2, 78),
+            Trans(0, 49, 2, 78),
+            Trans(0, 50, 2, 78),
+            Trans(0, 53, 2, 78),
+            Trans(0, 54, 2, 78),
+            Trans(0, 55, 2, 78),
+            Trans(0, 56, 2, 78),
+            Trans(0, 57, 2, 78),
         ],
         k: 1,
     },
-    /* 39 - "UserTypeDeclaration" */
+    /* 66 - "TokenLiteral" */
+    LookaheadDFA {
+        prod0: -1,
+        transitions: &[
+            Trans(0, 41, 1, 73),
+            Trans(0, 42, 2, 74),
+            Trans(0, 43, 3, 75),
+        ],
+        k: 1,
+    },
+    /* 67 - "TrailingContext" */
+    LookaheadDFA {
+        prod0: 79,
+        transitions: &[],
+        k: 0,
+    },
+    /* 68 - "UserTypeDeclaration" */
     LookaheadDFA {
-        prod0: 69,
+        prod0: 121,
         transitions: &[],
         k: 0,
     },
-    /* 40 - "UserTypeName" */
+    /* 69 - "UserTypeName" */
     LookaheadDFA {
-        prod0: 70,
+        prod0: 122,
         transitions: &[],
         k: 0,
     },
-    /* 41 - "UserTypeNameList" */
+    /* 70 - "UserTypeNameList" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 6, 2, 72),
-            Trans(0, 7, 2, 72),
-            Trans(0, 8, 2, 72),
-            Trans(0, 10, 2, 72),
-            Trans(0, 11, 2, 72),
-            Trans(0, 12, 2, 72),
-            Trans(0, 13, 2, 72),
-            Trans(0, 14, 2, 72),
-            Trans(0, 15, 2, 72),
-            Trans(0, 17, 2, 72),
-            Trans(0, 18, 1, 71),
-            Trans(0, 20, 2, 72),
-            Trans(0, 21, 2, 72),
-            Trans(0, 22, 2, 72),
-            Trans(0, 24, 2, 72),
-            Trans(0, 25, 2, 72),
-            Trans(0, 26, 2, 72),
-            Trans(0, 27, 2, 72),
-            Trans(0, 28, 2, 72),
-            Trans(0, 29, 2, 72),
-            Trans(0, 30, 2, 72),
-            Trans(0, 31, 2, 72),
-            Trans(0, 32, 2, 72),
-            Trans(0, 33, 2, 72),
-            Trans(0, 34, 2, 72),
-            Trans(0, 36, 2, 72),
-            Trans(0, 37, 2, 72),
-            Trans(0, 38, 2, 72),
+            Trans(0, 6, 2, 124),
+            Trans(0, 7, 2, 124),
+            Trans(0, 8, 2, 124),
+            Trans(0, 10, 2, 124),
+            Trans(0, 11, 2, 124),
+            Trans(0, 12, 2, 124),
+            Trans(0, 13, 2, 124),
+            Trans(0, 14, 2, 124),
+            Trans(0, 15, 2, 124),
+            Trans(0, 16, 2, 124),
+            Trans(0, 17, 2, 124),
+            Trans(0, 18, 2, 124),
+            Trans(0, 19, 2, 124),
+            Trans(0, 20, 2, 124),
+            Trans(0, 21, 2, 124),
+            Trans(0, 22, 2, 124),
+            Trans(0, 23, 2, 124),
+            Trans(0, 24, 2, 124),
+            Trans(0, 25, 2, 124),
+            Trans(0, 26, 2, 124),
+            Trans(0, 27, 2, 124),
+            Trans(0, 28, 2, 124),
+            Trans(0, 30, 2, 124),
+            Trans(0, 31, 1, 123),
+            Trans(0, 33, 2, 124),
+            Trans(0, 34, 2, 124),
+            Trans(0, 35, 2, 124),
+            Trans(0, 36, 2, 124),
+            Trans(0, 37, 2, 124),
+            Trans(0, 38, 2, 124),
+            Trans(0, 39, 2, 124),
+            Trans(0, 41, 2, 124),
+            Trans(0, 42, 2, 124),
+            Trans(0, 43, 2, 124),
+            Trans(0, 44, 2, 124),
+            Trans(0, 45, 2, 124),
+            Trans(0, 46, 2, 124),
+            Trans(0, 47, 2, 124),
+            Trans(0, 48, 2, 124),
+            Trans(0, 49, 2, 124),
+            Trans(0, 50, 2, 124),
+            Trans(0, 52, 2, 124),
+            Trans(0, 53, 2, 124),
+            Trans(0, 54, 2, 124),
+            Trans(0, 55, 2, 124),
+            Trans(0, 57, 2, 124),
         ],
         k: 1,
     },
 ];
 
-pub const PRODUCTIONS: &[Production; 73] = &[
+pub const PRODUCTIONS: &[Production; 129] = &[
     // 0 - Parol: Prolog GrammarDefinition;
     Production {
-        lhs: 18,
-        production: &[ParseType::N(9), ParseType::N(20)],
+        lhs: 32,
+        production: &[ParseType::N(21), ParseType::N(39)],
     },
     // 1 - Prolog: StartDeclaration PrologList /* Vec */ PrologList0 /* Vec */;
     Production {
-        lhs: 20,
-        production: &[ParseType::N(22), ParseType::N(21), ParseType::N(33)],
+        lhs: 39,
+        production: &[ParseType::N(41), ParseType::N(40), ParseType::N(56)],
     },
     // 2 - PrologList0: ScannerState : crate::parser::parol_grammar::ScannerConfig  PrologList0;
     Production {
-        lhs: 22,
-        production: &[ParseType::N(22), ParseType::N(27)],
+        lhs: 41,
+        production: &[ParseType::N(41), ParseType::N(50)],
     },
     // 3 - PrologList0: ;
     Production {
-        lhs: 22,
+        lhs: 41,
         production: &[],
     },
     // 4 - PrologList: Declaration PrologList;
     Production {
-        lhs: 21,
-        production: &[ParseType::N(21), ParseType::N(6)],
+        lhs: 40,
+        production: &[ParseType::N(40), ParseType::N(12)],
     },
     // 5 - PrologList: ;
     Production {
-        lhs: 21,
+        lhs: 40,
         production: &[],
     },
     // 6 - StartDeclaration: '%start'^ /* Clipped */ Identifier;
     Production {
-        lhs: 33,
-        production: &[ParseType::N(12), ParseType::T(5)],
+        lhs: 56,
+        production: &[ParseType::N(24), ParseType::T(5)],
     },
     // 7 - Declaration: '%title'^ /* Clipped */ String;
     Production {
-        lhs: 6,
-        production: &[ParseType::N(34), ParseType::T(6)],
+        lhs: 12,
+        production: &[ParseType::N(57), ParseType::T(6)],
     },
     // 8 - Declaration: '%comment'^ /* Clipped */ String;
     Production {
-        lhs: 6,
-        production: &[ParseType::N(34), ParseType::T(7)],
+        lhs: 12,
+        production: &[ParseType::N(57), ParseType::T(7)],
     },
     // 9 - Declaration: '%user_type'^ /* Clipped */ Identifier '='^ /* Clipped */ UserTypeName : crate::parser::parol_grammar::UserDefinedTypeName ;
     Production {
-        lhs: 6,
+        lhs: 12,
         production: &[
-            ParseType::N(40),
+            ParseType::N(69),
             ParseType::T(9),
-            ParseType::N(12),
+            ParseType::N(24),
             ParseType::T(8),
         ],
     },
     // 10 - Declaration: '%grammar_type'^ /* Clipped */ RawString;
     Production {
-        lhs: 6,
-        production: &[ParseType::N(23), ParseType::T(10)],
+        lhs: 12,
+        production: &[ParseType::N(42), ParseType::T(10)],
     },
-    // 11 - Declaration: ScannerDirectives;
+    // 11 - Declaration: '%include'^ /* Clipped */ String;
     Production {
-        lhs: 6,
-        production: &[ParseType::N(26)],
+        lhs: 12,
+        production: &[ParseType::N(57), ParseType::T(11)],
     },
-    // 12 - ScannerDirectives: '%line_comment'^ /* Clipped */ TokenLiteral;
+    // 12 - Declaration: '%keywords'^ /* Clipped */ IdentifierList;
     Production {
-        lhs: 26,
-        production: &[ParseType::N(36), ParseType::T(11)],
+        lhs: 12,
+        production: &[ParseType::N(25), ParseType::T(12)],
     },
-    // 13 - ScannerDirectives: '%block_comment'^ /* Clipped */ TokenLiteral TokenLiteral;
+    // 13 - Declaration: '%derive'^ /* Clipped */ DeclarationOpt /* Option */ DeriveList;
     Production {
-        lhs: 26,
-        production: &[ParseType::N(36), ParseType::N(36), ParseType::T(12)],
+        lhs: 12,
+        production: &[ParseType::N(14), ParseType::N(13), ParseType::T(13)],
     },
-    // 14 - ScannerDirectives: '%auto_newline_off'^ /* Clipped */;
+    // 14 - Declaration: '%inline'^ /* Clipped */ IdentifierList;
     Production {
-        lhs: 26,
-        production: &[ParseType::T(13)],
+        lhs: 12,
+        production: &[ParseType::N(25), ParseType::T(14)],
     },
-    // 15 - ScannerDirectives: '%auto_ws_off'^ /* Clipped */;
+    // 15 - Declaration: '%display'^ /* Clipped */ Identifier String;
     Production {
-        lhs: 26,
-        production: &[ParseType::T(14)],
+        lhs: 12,
+        production: &[ParseType::N(57), ParseType::N(24), ParseType::T(15)],
     },
-    // 16 - ScannerDirectives: '%on'^ /* Clipped */ IdentifierList '%enter'^ /* Clipped */ Identifier;
+    // 16 - Declaration: ExpectDirective Number;
     Production {
-        lhs: 26,
-        production: &[
-            ParseType::N(12),
-            ParseType::T(16),
-            ParseType::N(13),
-            ParseType::T(15),
-        ],
+        lhs: 12,
+        production: &[ParseType::N(29), ParseType::N(18)],
     },
-    // 17 - GrammarDefinition: '%%'^ /* Clipped */ Production GrammarDefinitionList /* Vec */;
+    // 17 - Declaration: PrecedenceDeclaration;
     Production {
-        lhs: 9,
-        production: &[ParseType::N(10), ParseType::N(19), ParseType::T(17)],
+        lhs: 12,
+        production: &[ParseType::N(33)],
+    },
+    // 18 - Declaration: ScannerDirectives;
+    Production {
+        lhs: 12,
+        production: &[ParseType::N(49)],
+    },
+    // 19 - DeclarationOpt: DeriveTarget;
+    Production {
+        lhs: 13,
+        production: &[ParseType::N(16)],
     },
-    // 18 - GrammarDefinitionList: Production GrammarDefinitionList;
+    // 20 - DeclarationOpt: ;
+    Production {
+        lhs: 13,
+        production: &[],
+    },
+    // 21 - ExpectDirective: /%expect(-rr)?/;
+    Production {
+        lhs: 18,
+        production: &[ParseType::T(16)],
+    },
+    // 22 - PrecedenceDeclaration: Associativity PrecedenceSymbol PrecedenceDeclarationList /* Vec */;
+    Production {
+        lhs: 33,
+        production: &[ParseType::N(34), ParseType::N(35), ParseType::N(10)],
+    },
+    // 23 - PrecedenceDeclarationList: PrecedenceSymbol PrecedenceDeclarationList;
+    Production {
+        lhs: 34,
+        production: &[ParseType::N(34), ParseType::N(35)],
+    },
+    // 24 - PrecedenceDeclarationList: ;
+    Production {
+        lhs: 34,
+        production: &[],
+    },
+    // 25 - Associativity: '%left';
     Production {
         lhs: 10,
-        production: &[ParseType::N(10), ParseType::N(19)],
+        production: &[ParseType::T(17)],
+    },
+    // 26 - Associativity: '%right';
+    Production {
+        lhs: 10,
+        production: &[ParseType::T(18)],
     },
-    // 19 - GrammarDefinitionList: ;
+    // 27 - Associativity: '%nonassoc';
     Production {
         lhs: 10,
+        production: &[ParseType::T(19)],
+    },
+    // 28 - PrecedenceSymbol: TokenLiteral;
+    Production {
+        lhs: 35,
+        production: &[ParseType::N(66)],
+    },
+    // 29 - PrecedenceSymbol: Identifier;
+    Production {
+        lhs: 35,
+        production: &[ParseType::N(24)],
+    },
+    // 30 - ScannerDirectives: '%line_comment'^ /* Clipped */ TokenLiteral;
+    Production {
+        lhs: 49,
+        production: &[ParseType::N(66), ParseType::T(20)],
+    },
+    // 31 - ScannerDirectives: '%block_comment'^ /* Clipped */ TokenLiteral TokenLiteral;
+    Production {
+        lhs: 49,
+        production: &[ParseType::N(66), ParseType::N(66), ParseType::T(21)],
+    },
+    // 32 - ScannerDirectives: '%nested_block_comment'^ /* Clipped */ TokenLiteral TokenLiteral;
+    Production {
+        lhs: 49,
+        production: &[ParseType::N(66), ParseType::N(66), ParseType::T(22)],
+    },
+    // 33 - ScannerDirectives: '%auto_newline_off'^ /* Clipped */;
+    Production {
+        lhs: 49,
+        production: &[ParseType::T(23)],
+    },
+    // 34 - ScannerDirectives: '%auto_ws_off'^ /* Clipped */;
+    Production {
+        lhs: 49,
+        production: &[ParseType::T(24)],
+    },
+    // 35 - ScannerDirectives: '%longest_match'^ /* Clipped */;
+    Production {
+        lhs: 49,
+        production: &[ParseType::T(25)],
+    },
+    // 36 - ScannerDirectives: '%case_insensitive'^ /* Clipped */;
+    Production {
+        lhs: 49,
+        production: &[ParseType::T(26)],
+    },
+    // 37 - ScannerDirectives: '%offside'^ /* Clipped */ Identifier Identifier Identifier;
+    Production {
+        lhs: 49,
+        production: &[
+            ParseType::N(24),
+            ParseType::N(24),
+            ParseType::N(24),
+            ParseType::T(27),
+        ],
+    },
+    // 38 - ScannerDirectives: '%on'^ /* Clipped */ IdentifierList '%enter'^ /* Clipped */ Identifier;
+    Production {
+        lhs: 49,
+        production: &[
+            ParseType::N(24),
+            ParseType::T(29),
+            ParseType::N(25),
+            ParseType::T(28),
+        ],
+    },
+    // 39 - GrammarDefinition: '%%'^ /* Clipped */ Production GrammarDefinitionList /* Vec */;
+    Production {
+        lhs: 21,
+        production: &[ParseType::N(22), ParseType::N(36), ParseType::T(30)],
+    },
+    // 40 - GrammarDefinitionList: Production GrammarDefinitionList;
+    Production {
+        lhs: 22,
+        production: &[ParseType::N(22), ParseType::N(36)],
+    },
+    // 41 - GrammarDefinitionList: ;
+    Production {
+        lhs: 22,
         production: &[],
     },
-    // 20 - DoubleColon: '::';
+    // 42 - DoubleColon: '::';
     Production {
-        lhs: 7,
-        production: &[ParseType::T(18)],
+        lhs: 17,
+        production: &[ParseType::T(31)],
     },
-    // 21 - Production: Identifier ':'^ /* Clipped */ Alternations ';'^ /* Clipped */;
+    // 43 - DeriveTarget: Identifier ':'^ /* Clipped */;
     Production {
-        lhs: 19,
+        lhs: 16,
+        production: &[ParseType::T(32), ParseType::N(24)],
+    },
+    // 44 - DeriveList: UserTypeName : crate::parser::parol_grammar::UserDefinedTypeName  DeriveListList /* Vec */;
+    Production {
+        lhs: 14,
+        production: &[ParseType::N(15), ParseType::N(69)],
+    },
+    // 45 - DeriveListList: ','^ /* Clipped */ UserTypeName : crate::parser::parol_grammar::UserDefinedTypeName  DeriveListList;
+    Production {
+        lhs: 15,
+        production: &[ParseType::N(15), ParseType::N(69), ParseType::T(33)],
+    },
+    // 46 - DeriveListList: ;
+    Production {
+        lhs: 15,
+        production: &[],
+    },
+    // 47 - Production: ProductionName ':'^ /* Clipped */ Alternations ';'^ /* Clipped */;
+    Production {
+        lhs: 36,
         production: &[
-            ParseType::T(20),
-            ParseType::N(3),
-            ParseType::T(19),
-            ParseType::N(12),
+            ParseType::T(34),
+            ParseType::N(6),
+            ParseType::T(32),
+            ParseType::N(37),
         ],
     },
-    // 22 - Alternations: Alternation AlternationsList /* Vec */;
+    // 48 - ProductionName: Identifier ProductionNameOpt /* Option */;
     Production {
-        lhs: 3,
-        production: &[ParseType::N(4), ParseType::N(1)],
+        lhs: 37,
+        production: &[ParseType::N(38), ParseType::N(24)],
     },
-    // 23 - AlternationsList: '|'^ /* Clipped */ Alternation AlternationsList;
+    // 49 - ProductionNameOpt: TemplateParameters;
     Production {
-        lhs: 4,
-        production: &[ParseType::N(4), ParseType::N(1), ParseType::T(21)],
+        lhs: 38,
+        production: &[ParseType::N(63)],
     },
-    // 24 - AlternationsList: ;
+    // 50 - ProductionNameOpt: ;
     Production {
-        lhs: 4,
+        lhs: 38,
         production: &[],
     },
-    // 25 - Alternation: AlternationList /* Vec */;
+    // 51 - TemplateParameters: '<'^ /* Clipped */ IdentifierList '>'^ /* Clipped */;
+    Production {
+        lhs: 63,
+        production: &[ParseType::T(36), ParseType::N(25), ParseType::T(35)],
+    },
+    // 52 - Alternations: Alternation AlternationsList /* Vec */;
+    Production {
+        lhs: 6,
+        production: &[ParseType::N(7), ParseType::N(1)],
+    },
+    // 53 - AlternationsList: '|'^ /* Clipped */ Alternation AlternationsList;
+    Production {
+        lhs: 7,
+        production: &[ParseType::N(7), ParseType::N(1), ParseType::T(37)],
+    },
+    // 54 - AlternationsList: ;
+    Production {
+        lhs: 7,
+        production: &[],
+    },
+    // 55 - Alternation: AlternationList /* Vec */ AlternationOpt /* Option */ AlternationOpt0 /* Option */;
     Production {
         lhs: 1,
-        production: &[ParseType::N(2)],
+        production: &[ParseType::N(5), ParseType::N(4), ParseType::N(3)],
     },
-    // 26 - AlternationList: Factor AlternationList;
+    // 56 - AlternationList: Factor AlternationList;
     Production {
-        lhs: 2,
-        production: &[ParseType::N(2), ParseType::N(8)],
+        lhs: 3,
+        production: &[ParseType::N(3), ParseType::N(19)],
     },
-    // 27 - AlternationList: ;
+    // 57 - AlternationList: ;
     Production {
-        lhs: 2,
+        lhs: 3,
         production: &[],
     },
-    // 28 - Factor: Group;
+    // 58 - AlternationOpt0: AlternationLabel;
     Production {
-        lhs: 8,
-        production: &[ParseType::N(11)],
+        lhs: 5,
+        production: &[ParseType::N(2)],
     },
-    // 29 - Factor: Repeat;
+    // 59 - AlternationOpt0: ;
     Production {
-        lhs: 8,
-        production: &[ParseType::N(25)],
+        lhs: 5,
+        production: &[],
     },
-    // 30 - Factor: Optional;
+    // 60 - AlternationOpt: '%prec'^ /* Clipped */ PrecedenceSymbol;
     Production {
-        lhs: 8,
-        production: &[ParseType::N(17)],
+        lhs: 4,
+        production: &[ParseType::N(35), ParseType::T(38)],
     },
-    // 31 - Factor: Symbol;
+    // 61 - AlternationOpt: ;
     Production {
-        lhs: 8,
-        production: &[ParseType::N(35)],
+        lhs: 4,
+        production: &[],
     },
-    // 32 - Symbol: NonTerminal;
+    // 62 - AlternationLabel: '#'^ /* Clipped */ Identifier;
     Production {
-        lhs: 35,
-        production: &[ParseType::N(15)],
+        lhs: 2,
+        production: &[ParseType::N(24), ParseType::T(39)],
     },
-    // 33 - Symbol: SimpleToken;
+    // 63 - Factor: Group;
     Production {
-        lhs: 35,
+        lhs: 19,
+        production: &[ParseType::N(23)],
+    },
+    // 64 - Factor: Repeat;
+    Production {
+        lhs: 19,
+        production: &[ParseType::N(44)],
+    },
+    // 65 - Factor: Optional;
+    Production {
+        lhs: 19,
         production: &[ParseType::N(31)],
     },
-    // 34 - Symbol: TokenWithStates;
+    // 66 - Factor: Symbol;
     Production {
-        lhs: 35,
-        production: &[ParseType::N(37)],
+        lhs: 19,
+        production: &[ParseType::N(58)],
     },
-    // 35 - Symbol: ScannerSwitch;
+    // 67 - Factor: FieldLabel Symbol;
     Production {
-        lhs: 35,
-        production: &[ParseType::N(29)],
+        lhs: 19,
+        production: &[ParseType::N(58), ParseType::N(20)],
     },
-    // 36 - TokenLiteral: String;
+    // 68 - FieldLabel: Identifier '='^ /* Clipped */;
     Production {
-        lhs: 36,
-        production: &[ParseType::N(34)],
+        lhs: 20,
+        production: &[ParseType::T(9), ParseType::N(24)],
     },
-    // 37 - TokenLiteral: RawString;
+    // 69 - Symbol: NonTerminal;
     Production {
-        lhs: 36,
-        production: &[ParseType::N(23)],
+        lhs: 58,
+        production: &[ParseType::N(27)],
     },
-    // 38 - TokenLiteral: Regex;
+    // 70 - Symbol: SimpleToken;
     Production {
-        lhs: 36,
-        production: &[ParseType::N(24)],
+        lhs: 58,
+        production: &[ParseType::N(54)],
     },
-    // 39 - SimpleToken: TokenLiteral SimpleTokenOpt /* Option */;
+    // 71 - Symbol: AngleArguments;
     Production {
-        lhs: 31,
-        production: &[ParseType::N(32), ParseType::N(36)],
+        lhs: 58,
+        production: &[ParseType::N(8)],
     },
-    // 40 - SimpleTokenOpt: ASTControl;
+    // 72 - Symbol: ScannerSwitch;
     Production {
-        lhs: 32,
+        lhs: 58,
+        production: &[ParseType::N(52)],
+    },
+    // 73 - TokenLiteral: String;
+    Production {
+        lhs: 66,
+        production: &[ParseType::N(57)],
+    },
+    // 74 - TokenLiteral: RawString;
+    Production {
+        lhs: 66,
+        production: &[ParseType::N(42)],
+    },
+    // 75 - TokenLiteral: Regex;
+    Production {
+        lhs: 66,
+        production: &[ParseType::N(43)],
+    },
+    // 76 - TokenExpression: TokenLiteral TokenExpressionOpt /* Option */;
+    Production {
+        lhs: 64,
+        production: &[ParseType::N(65), ParseType::N(66)],
+    },
+    // 77 - TokenExpressionOpt: TrailingContext;
+    Production {
+        lhs: 65,
+        production: &[ParseType::N(67)],
+    },
+    // 78 - TokenExpressionOpt: ;
+    Production {
+        lhs: 65,
+        production: &[],
+    },
+    // 79 - TrailingContext: '?='^ /* Clipped */ TokenLiteral;
+    Production {
+        lhs: 67,
+        production: &[ParseType::N(66), ParseType::T(40)],
+    },
+    // 80 - SimpleToken: TokenExpression SimpleTokenOpt /* Option */;
+    Production {
+        lhs: 54,
+        production: &[ParseType::N(55), ParseType::N(64)],
+    },
+    // 81 - SimpleTokenOpt: ASTControl;
+    Production {
+        lhs: 55,
         production: &[ParseType::N(0)],
     },
-    // 41 - SimpleTokenOpt: ;
+    // 82 - SimpleTokenOpt: ;
     Production {
-        lhs: 32,
+        lhs: 55,
         production: &[],
     },
-    // 42 - TokenWithStates: '<'^ /* Clipped */ IdentifierList '>'^ /* Clipped */ TokenLiteral TokenWithStatesOpt /* Option */;
+    // 83 - String: /"(\\.|[^\\])*?"i?/;
     Production {
-        lhs: 37,
+        lhs: 57,
+        production: &[ParseType::T(41)],
+    },
+    // 84 - RawString: /'(\\'|[^'])*?'i?/;
+    Production {
+        lhs: 42,
+        production: &[ParseType::T(42)],
+    },
+    // 85 - Regex: /\u{2F}(\\.|[^\\])*?\u{2F}i?/;
+    Production {
+        lhs: 43,
+        production: &[ParseType::T(43)],
+    },
+    // 86 - Group: '(' Alternations ')';
+    Production {
+        lhs: 23,
+        production: &[ParseType::T(45), ParseType::N(6), ParseType::T(44)],
+    },
+    // 87 - Optional: '[' Alternations ']';
+    Production {
+        lhs: 31,
+        production: &[ParseType::T(47), ParseType::N(6), ParseType::T(46)],
+    },
+    // 88 - Repeat: '{' Alternations RepeatOpt /* Option */ '}' RepeatOpt0 /* Option */;
+    Production {
+        lhs: 44,
         production: &[
-            ParseType::N(38),
-            ParseType::N(36),
-            ParseType::T(23),
-            ParseType::N(13),
-            ParseType::T(22),
+            ParseType::N(46),
+            ParseType::T(49),
+            ParseType::N(45),
+            ParseType::N(6),
+            ParseType::T(48),
         ],
     },
-    // 43 - TokenWithStatesOpt: ASTControl;
+    // 89 - RepeatOpt0: OneOrMore;
     Production {
-        lhs: 38,
+        lhs: 46,
+        production: &[ParseType::N(30)],
+    },
+    // 90 - RepeatOpt0: ;
+    Production {
+        lhs: 46,
+        production: &[],
+    },
+    // 91 - RepeatOpt: RepeatSeparator;
+    Production {
+        lhs: 45,
+        production: &[ParseType::N(47)],
+    },
+    // 92 - RepeatOpt: ;
+    Production {
+        lhs: 45,
+        production: &[],
+    },
+    // 93 - NonTerminal: Identifier NonTerminalOpt /* Option */;
+    Production {
+        lhs: 27,
+        production: &[ParseType::N(28), ParseType::N(24)],
+    },
+    // 94 - NonTerminalOpt: ASTControl;
+    Production {
+        lhs: 28,
         production: &[ParseType::N(0)],
     },
-    // 44 - TokenWithStatesOpt: ;
+    // 95 - NonTerminalOpt: ;
     Production {
-        lhs: 38,
+        lhs: 28,
         production: &[],
     },
-    // 45 - String: /"(\\.|[^\\])*?"/;
+    // 96 - AngleArguments: '<' TemplateArguments '>'^ /* Clipped */ AngleArgumentsOpt /* Option */;
     Production {
-        lhs: 34,
-        production: &[ParseType::T(24)],
+        lhs: 8,
+        production: &[
+            ParseType::N(9),
+            ParseType::T(36),
+            ParseType::N(61),
+            ParseType::T(35),
+        ],
     },
-    // 46 - RawString: /'(\\'|[^'])*?'/;
+    // 97 - AngleArgumentsOpt: ASTControl;
     Production {
-        lhs: 23,
-        production: &[ParseType::T(25)],
+        lhs: 9,
+        production: &[ParseType::N(0)],
     },
-    // 47 - Regex: /\u{2F}(\\.|[^\\])*?\u{2F}/;
+    // 98 - AngleArgumentsOpt: ;
     Production {
-        lhs: 24,
-        production: &[ParseType::T(26)],
+        lhs: 9,
+        production: &[],
     },
-    // 48 - Group: '(' Alternations ')';
+    // 99 - TemplateArguments: TemplateArgument TemplateArgumentsList /* Vec */;
     Production {
-        lhs: 11,
-        production: &[ParseType::T(28), ParseType::N(3), ParseType::T(27)],
+        lhs: 61,
+        production: &[ParseType::N(62), ParseType::N(59)],
     },
-    // 49 - Optional: '[' Alternations ']';
+    // 100 - TemplateArgumentsList: ','^ /* Clipped */ TemplateArgument TemplateArgumentsList;
     Production {
-        lhs: 17,
-        production: &[ParseType::T(30), ParseType::N(3), ParseType::T(29)],
+        lhs: 62,
+        production: &[ParseType::N(62), ParseType::N(59), ParseType::T(33)],
     },
-    // 50 - Repeat: '{' Alternations '}';
+    // 101 - TemplateArgumentsList: ;
     Production {
-        lhs: 25,
-        production: &[ParseType::T(32), ParseType::N(3), ParseType::T(31)],
+        lhs: 62,
+        production: &[],
     },
-    // 51 - NonTerminal: Identifier NonTerminalOpt /* Option */;
+    // 102 - TemplateArgument: Factor TemplateArgumentList /* Vec */;
     Production {
-        lhs: 15,
-        production: &[ParseType::N(16), ParseType::N(12)],
+        lhs: 59,
+        production: &[ParseType::N(60), ParseType::N(19)],
     },
-    // 52 - NonTerminalOpt: ASTControl;
+    // 103 - TemplateArgumentList: Factor TemplateArgumentList;
     Production {
-        lhs: 16,
-        production: &[ParseType::N(0)],
+        lhs: 60,
+        production: &[ParseType::N(60), ParseType::N(19)],
     },
-    // 53 - NonTerminalOpt: ;
+    // 104 - TemplateArgumentList: ;
     Production {
-        lhs: 16,
+        lhs: 60,
         production: &[],
     },
-    // 54 - Identifier: /[a-zA-Z_][a-zA-Z0-9_]*/;
+    // 105 - Identifier: /[a-zA-Z_][a-zA-Z0-9_]*/;
     Production {
-        lhs: 12,
-        production: &[ParseType::T(33)],
+        lhs: 24,
+        production: &[ParseType::T(50)],
     },
-    // 55 - ScannerState: '%scanner'^ /* Clipped */ Identifier '{'^ /* Clipped */ ScannerStateList /* Vec */ '}'^ /* Clipped */;
+    // 106 - Number: /[0-9]+/;
     Production {
-        lhs: 27,
+        lhs: 29,
+        production: &[ParseType::T(51)],
+    },
+    // 107 - ScannerState: '%scanner'^ /* Clipped */ Identifier '{'^ /* Clipped */ ScannerStateList /* Vec */ '}'^ /* Clipped */;
+    Production {
+        lhs: 50,
         production: &[
-            ParseType::T(32),
-            ParseType::N(28),
-            ParseType::T(31),
-            ParseType::N(12),
-            ParseType::T(34),
+            ParseType::T(49),
+            ParseType::N(51),
+            ParseType::T(48),
+            ParseType::N(24),
+            ParseType::T(52),
         ],
     },
-    // 56 - ScannerStateList: ScannerDirectives ScannerStateList;
+    // 108 - ScannerStateList: ScannerDirectives ScannerStateList;
     Production {
-        lhs: 28,
-        production: &[ParseType::N(28), ParseType::N(26)],
+        lhs: 51,
+        production: &[ParseType::N(51), ParseType::N(49)],
     },
-    // 57 - ScannerStateList: ;
+    // 109 - ScannerStateList: ;
     Production {
-        lhs: 28,
+        lhs: 51,
         production: &[],
     },
-    // 58 - IdentifierList: Identifier IdentifierListList /* Vec */;
+    // 110 - IdentifierList: Identifier IdentifierListList /* Vec */;
     Production {
-        lhs: 13,
-        production: &[ParseType::N(14), ParseType::N(12)],
+        lhs: 25,
+        production: &[ParseType::N(26), ParseType::N(24)],
     },
-    // 59 - IdentifierListList: ','^ /* Clipped */ Identifier IdentifierListList;
+    // 111 - IdentifierListList: ','^ /* Clipped */ Identifier IdentifierListList;
     Production {
-        lhs: 14,
-        production: &[ParseType::N(14), ParseType::N(12), ParseType::T(35)],
+        lhs: 26,
+        production: &[ParseType::N(26), ParseType::N(24), ParseType::T(33)],
     },
-    // 60 - IdentifierListList: ;
+    // 112 - IdentifierListList: ;
     Production {
-        lhs: 14,
+        lhs: 26,
         production: &[],
     },
-    // 61 - ScannerSwitch: '%sc' '('^ /* Clipped */ ScannerSwitchOpt /* Option */ ')'^ /* Clipped */;
+    // 113 - ScannerSwitch: '%sc' '('^ /* Clipped */ ScannerSwitchOpt /* Option */ ')'^ /* Clipped */;
     Production {
-        lhs: 29,
+        lhs: 52,
         production: &[
-            ParseType::T(28),
-            ParseType::N(30),
-            ParseType::T(27),
-            ParseType::T(36),
+            ParseType::T(45),
+            ParseType::N(53),
+            ParseType::T(44),
+            ParseType::T(53),
         ],
     },
-    // 62 - ScannerSwitch: '%push' '('^ /* Clipped */ Identifier ')'^ /* Clipped */;
+    // 114 - ScannerSwitch: '%push' '('^ /* Clipped */ Identifier ')'^ /* Clipped */;
     Production {
-        lhs: 29,
+        lhs: 52,
         production: &[
-            ParseType::T(28),
-            ParseType::N(12),
-            ParseType::T(27),
-            ParseType::T(37),
+            ParseType::T(45),
+            ParseType::N(24),
+            ParseType::T(44),
+            ParseType::T(54),
         ],
     },
-    // 63 - ScannerSwitch: '%pop' '('^ /* Clipped */ ')'^ /* Clipped */;
+    // 115 - ScannerSwitch: '%pop' '('^ /* Clipped */ ')'^ /* Clipped */;
     Production {
-        lhs: 29,
-        production: &[ParseType::T(28), ParseType::T(27), ParseType::T(38)],
+        lhs: 52,
+        production: &[ParseType::T(45), ParseType::T(44), ParseType::T(55)],
     },
-    // 64 - ScannerSwitchOpt: Identifier;
+    // 116 - ScannerSwitchOpt: Identifier;
     Production {
-        lhs: 30,
-        production: &[ParseType::N(12)],
+        lhs: 53,
+        production: &[ParseType::N(24)],
     },
-    // 65 - ScannerSwitchOpt: ;
+    // 117 - ScannerSwitchOpt: ;
     Production {
-        lhs: 30,
+        lhs: 53,
         production: &[],
     },
-    // 66 - ASTControl: CutOperator;
+    // 118 - ASTControl: CutOperator;
     Production {
         lhs: 0,
-        production: &[ParseType::N(5)],
+        production: &[ParseType::N(11)],
     },
-    // 67 - ASTControl: UserTypeDeclaration;
+    // 119 - ASTControl: UserTypeDeclaration;
     Production {
         lhs: 0,
-        production: &[ParseType::N(39)],
+        production: &[ParseType::N(68)],
     },
-    // 68 - CutOperator: '^'^ /* Clipped */;
+    // 120 - CutOperator: '^'^ /* Clipped */;
     Production {
-        lhs: 5,
-        production: &[ParseType::T(39)],
+        lhs: 11,
+        production: &[ParseType::T(56)],
     },
-    // 69 - UserTypeDeclaration: ':'^ /* Clipped */ UserTypeName : crate::parser::parol_grammar::UserDefinedTypeName ;
+    // 121 - UserTypeDeclaration: ':'^ /* Clipped */ UserTypeName : crate::parser::parol_grammar::UserDefinedTypeName ;
     Production {
-        lhs: 39,
-        production: &[ParseType::N(40), ParseType::T(19)],
+        lhs: 68,
+        production: &[ParseType::N(69), ParseType::T(32)],
     },
-    // 70 - UserTypeName: Identifier UserTypeNameList /* Vec */;
+    // 122 - UserTypeName: Identifier UserTypeNameList /* Vec */;
     Production {
-        lhs: 40,
-        production: &[ParseType::N(41), ParseType::N(12)],
+        lhs: 69,
+        production: &[ParseType::N(70), ParseType::N(24)],
     },
-    // 71 - UserTypeNameList: DoubleColon^ /* Clipped */ Identifier UserTypeNameList;
+    // 123 - UserTypeNameList: DoubleColon^ /* Clipped */ Identifier UserTypeNameList;
     Production {
-        lhs: 41,
-        production: &[ParseType::N(41), ParseType::N(12), ParseType::N(7)],
+        lhs: 70,
+        production: &[ParseType::N(70), ParseType::N(24), ParseType::N(17)],
     },
-    // 72 - UserTypeNameList: ;
+    // 124 - UserTypeNameList: ;
     Production {
-        lhs: 41,
+        lhs: 70,
+        production: &[],
+    },
+    // 125 - RepeatSeparator: '%' Symbol RepeatSeparatorList /* Vec */;
+    Production {
+        lhs: 47,
+        production: &[ParseType::N(48), ParseType::N(58), ParseType::T(57)],
+    },
+    // 126 - RepeatSeparatorList: Symbol RepeatSeparatorList;
+    Production {
+        lhs: 48,
+        production: &[ParseType::N(48), ParseType::N(58)],
+    },
+    // 127 - RepeatSeparatorList: ;
+    Production {
+        lhs: 48,
         production: &[],
     },
+    // 128 - OneOrMore: '+';
+    Production {
+        lhs: 30,
+        production: &[ParseType::T(58)],
+    },
 ];
 
 static SCANNERS: Lazy<Vec<ScannerConfig>> = Lazy::new(|| {
@@ -1055,7 +1988,7 @@ where
     T: AsRef<Path>,
 {
     let mut llk_parser = LLKParser::new(
-        18,
+        32,
         LOOKAHEAD_AUTOMATA,
         PRODUCTIONS,
         TERMINAL_NAMES,
@@ -1,21 +1,30 @@
+use crate::grammar::cfg::TerminalIndexFn;
+use crate::grammar::ERROR_TOKEN_NAME;
 use crate::parser::{Factor, ParolGrammar};
-use crate::transformation::transform_productions;
-use crate::{generators, Cfg, GrammarConfig, ScannerConfig, Symbol, Terminal, TerminalKind};
-use anyhow::{bail, Result};
+use crate::transformation::{instantiate_templates, transform_productions};
+use crate::{
+    generators, Cfg, GrammarConfig, Pr, ScannerConfig, Symbol, Terminal, TerminalKind,
+    TrailingContext,
+};
+use anyhow::{anyhow, bail, Result};
+use parol_runtime::lexer::{Token, FIRST_USER_TOKEN};
+use parol_runtime::TerminalIndex;
 
 pub(crate) fn try_to_convert(parol_grammar: ParolGrammar) -> Result<GrammarConfig> {
     let st = parol_grammar.start_symbol;
-    let non_terminals =
-        parol_grammar
-            .productions
-            .iter()
-            .fold(Vec::<String>::new(), |mut acc, p| {
-                if !acc.contains(&p.lhs) {
-                    acc.push(p.lhs.clone());
-                }
-                acc
-            });
-    let pr = transform_productions(parol_grammar.productions, parol_grammar.grammar_type)?;
+    let productions = instantiate_templates(parol_grammar.productions, &parol_grammar.templates)?;
+    let non_terminals = productions.iter().fold(Vec::<String>::new(), |mut acc, p| {
+        if !acc.contains(&p.lhs) {
+            acc.push(p.lhs.clone());
+        }
+        acc
+    });
+    let pr = transform_productions(productions, parol_grammar.grammar_type)?;
+    let pr = if parol_grammar.grammar_type.is_lr() {
+        insert_error_tokens(pr)
+    } else {
+        pr
+    };
     let cfg = Cfg { st, pr };
     let title = parol_grammar.title;
     let comment = parol_grammar.comment;
@@ -25,27 +34,56 @@ pub(crate) fn try_to_convert(parol_grammar: ParolGrammar) -> Result<GrammarConfi
     let block_comments = parol_grammar.scanner_configurations[0]
         .block_comments
         .clone();
+    let nested_block_comments = parol_grammar.scanner_configurations[0]
+        .nested_block_comments
+        .clone();
     let auto_newline = !parol_grammar.scanner_configurations[0].auto_newline_off;
     let auto_ws = !parol_grammar.scanner_configurations[0].auto_ws_off;
+    let longest_match = parol_grammar.scanner_configurations[0].longest_match;
     let lookahead_size = 1; // Updated later
 
     let scanner_config = ScannerConfig::default()
         .with_line_comments(line_comments)
         .with_block_comments(block_comments)
+        .with_nested_block_comments(nested_block_comments)
         .with_auto_newline(auto_newline)
-        .with_auto_ws(auto_ws);
+        .with_auto_ws(auto_ws)
+        .with_longest_match(longest_match);
 
     let mut grammar_config = GrammarConfig::new(cfg, lookahead_size)
         .with_title(title)
         .with_non_terminals(non_terminals)
         .with_comment(comment)
         .with_grammar_type(parol_grammar.grammar_type)
+        .with_precedence_levels(parol_grammar.precedence_levels)
+        .with_expected_conflicts(
+            parol_grammar.expected_shift_reduce_conflicts,
+            parol_grammar.expected_reduce_reduce_conflicts,
+        )
         .add_scanner(scanner_config);
 
     for u in parol_grammar.user_type_definitions {
         grammar_config = grammar_config.add_user_type_def(u.0, u.1.to_string());
     }
 
+    grammar_config = grammar_config.with_derives(
+        parol_grammar
+            .derives
+            .iter()
+            .map(|d| d.to_string())
+            .collect(),
+    );
+    for (n, d) in &parol_grammar.non_terminal_derives {
+        grammar_config = grammar_config.add_non_terminal_derives(
+            n.text().to_string(),
+            d.iter().map(|d| d.to_string()).collect(),
+        );
+    }
+
+    for n in &parol_grammar.inline_non_terminals {
+        grammar_config = grammar_config.add_inline_non_terminal(n.text().to_string());
+    }
+
     for s in 1..parol_grammar.scanner_configurations.len() {
         grammar_config = grammar_config.add_scanner(try_from_scanner_config(
             &parol_grammar.scanner_configurations[s],
@@ -62,18 +100,21 @@ pub(crate) fn try_to_convert(parol_grammar: ParolGrammar) -> Result<GrammarConfi
     };
     // Finds the terminal token from the name of the primary non-terminal
     let pr_copy = grammar_config.cfg.pr.clone();
-    let terminal_finder = move |name: &str| -> Option<(String, TerminalKind)> {
-        pr_copy.iter().find_map(|p| {
-            if p.0.get_n_ref().unwrap() == name && p.1.len() == 1 {
-                match &p.1[0] {
-                    Symbol::T(Terminal::Trm(t, k, ..)) => Some((t.to_owned(), *k)),
-                    _ => None,
+    let terminal_finder =
+        move |name: &str| -> Option<(String, TerminalKind, Option<TrailingContext>)> {
+            pr_copy.iter().find_map(|p| {
+                if p.0.get_n_ref().unwrap() == name && p.1.len() == 1 {
+                    match &p.1[0] {
+                        Symbol::T(Terminal::Trm(t, k, _, _, _, c, _)) => {
+                            Some((t.to_owned(), *k, c.clone()))
+                        }
+                        _ => None,
+                    }
+                } else {
+                    None
                 }
-            } else {
-                None
-            }
-        })
-    };
+            })
+        };
     grammar_config
         .scanner_configurations
         .iter_mut()
@@ -87,15 +128,76 @@ pub(crate) fn try_to_convert(parol_grammar: ParolGrammar) -> Result<GrammarConfi
             )
         })?;
 
+    let keywords = parol_grammar
+        .keywords
+        .iter()
+        .map(|k| {
+            terminal_finder(k.text())
+                .map(|(txt, kind, context)| {
+                    terminal_resolver.terminal_index(&txt, kind, context.as_ref())
+                })
+                .ok_or_else(|| anyhow!("Keyword {} not found", k))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let terminals = grammar_config.cfg.get_ordered_terminals();
+    // Each scanner state reserves the keywords that are valid in it
+    grammar_config
+        .scanner_configurations
+        .iter_mut()
+        .for_each(|sc| {
+            sc.keywords = keywords
+                .iter()
+                .filter(|k| {
+                    terminals[(**k - FIRST_USER_TOKEN) as usize]
+                        .3
+                        .contains(&sc.scanner_state)
+                })
+                .cloned()
+                .collect();
+        });
+
+    // The terminals synthesized in offside mode are resolved to their terminal indices
+    let offside_resolver = |t: &Token<'static>| -> Result<TerminalIndex> {
+        terminal_finder(t.text())
+            .map(|(txt, kind, context)| {
+                terminal_resolver.terminal_index(&txt, kind, context.as_ref())
+            })
+            .ok_or_else(|| anyhow!("Offside token {} not found", t))
+    };
+    grammar_config
+        .scanner_configurations
+        .iter_mut()
+        .try_for_each(|sc| -> Result<()> {
+            if let Some((indent, dedent, newline)) =
+                &parol_grammar.scanner_configurations[sc.scanner_state].offside
+            {
+                sc.offside = Some((
+                    offside_resolver(indent)?,
+                    offside_resolver(dedent)?,
+                    offside_resolver(newline)?,
+                ));
+            }
+            Ok(())
+        })?;
+
+    for (t, d) in &parol_grammar.display_names {
+        let terminal_index = terminal_finder(t.text())
+            .map(|(txt, kind, context)| {
+                terminal_resolver.terminal_index(&txt, kind, context.as_ref())
+            })
+            .ok_or_else(|| anyhow!("Terminal {} of display name not found", t))?;
+        grammar_config = grammar_config.add_display_name(terminal_index, d.clone());
+    }
+
     Ok(grammar_config)
 }
 
 fn insert_transitions(
     sc: &mut generators::ScannerConfig,
     scanner_configurations: &[crate::parser::parol_grammar::ScannerConfig],
-    terminal_resolver: &impl crate::grammar::cfg::TerminalIndexFn,
+    terminal_resolver: &impl TerminalIndexFn,
     scanner_resolver: impl Fn(&str) -> Option<usize>,
-    terminal_finder: impl Fn(&str) -> Option<(String, TerminalKind)>,
+    terminal_finder: impl Fn(&str) -> Option<(String, TerminalKind, Option<TrailingContext>)>,
 ) -> Result<()> {
     if let Some(source_configuartion) = scanner_resolver(&sc.scanner_name) {
         let mut transitions = Vec::new();
@@ -103,10 +205,12 @@ fn insert_transitions(
             .transitions
             .iter()
             .try_for_each(|(token, target_state_name)| {
-                if let Some((txt, kind)) = terminal_finder(token.text()) {
+                if let Some((txt, kind, context)) = terminal_finder(token.text()) {
                     if let Some(target_scanner) = scanner_resolver(target_state_name.text()) {
-                        transitions
-                            .push((terminal_resolver.terminal_index(&txt, kind), target_scanner));
+                        transitions.push((
+                            terminal_resolver.terminal_index(&txt, kind, context.as_ref()),
+                            target_scanner,
+                        ));
                     } else {
                         bail!(
                             "Target scanner configuration {} not found",
@@ -118,7 +222,7 @@ fn insert_transitions(
                 }
                 Ok(())
             })?;
-        transitions.sort_by(|a, b| a.0.cmp(&b.0));
+        transitions.sort_by_key(|a| a.0);
         sc.transitions = transitions;
     } else {
         bail!("Scanner configuration {} not found", sc.scanner_name);
@@ -133,15 +237,33 @@ fn try_from_scanner_config(
     let scanner_config = ScannerConfig::new(sc.name.clone(), scanner_state)
         .with_line_comments(sc.line_comments.clone())
         .with_block_comments(sc.block_comments.clone())
+        .with_nested_block_comments(sc.nested_block_comments.clone())
         .with_auto_newline(!sc.auto_newline_off)
-        .with_auto_ws(!sc.auto_ws_off);
+        .with_auto_ws(!sc.auto_ws_off)
+        .with_longest_match(sc.longest_match);
     Ok(scanner_config)
 }
 
+// In LALR(1) grammars the identifier `error` on a right-hand side denotes the error token.
+fn insert_error_tokens(pr: Vec<Pr>) -> Vec<Pr> {
+    pr.into_iter()
+        .map(|mut p| {
+            p.1.iter_mut().for_each(|s| {
+                if let Symbol::N(n, a, u, l) = s {
+                    if n == ERROR_TOKEN_NAME {
+                        *s = Symbol::T(Terminal::Error(*a, u.clone(), l.clone()));
+                    }
+                }
+            });
+            p
+        })
+        .collect()
+}
+
 pub(crate) fn try_from_factor(factor: Factor) -> Result<Symbol> {
     match factor {
-        Factor::NonTerminal(n, a, u) => Ok(Symbol::N(n, a, u)),
-        Factor::Terminal(t, k, s, a, u) => Ok(Symbol::T(Terminal::Trm(t, k, s, a, u))),
+        Factor::NonTerminal(n, a, u, l) => Ok(Symbol::N(n, a, u, l)),
+        Factor::Terminal(t, k, s, a, u, c, l) => Ok(Symbol::T(Terminal::Trm(t, k, s, a, u, c, l))),
         Factor::ScannerSwitch(s, _) => Ok(Symbol::s(s)),
         Factor::ScannerSwitchPush(s, _) => Ok(Symbol::Push(s)),
         Factor::ScannerSwitchPop(_) => Ok(Symbol::Pop),
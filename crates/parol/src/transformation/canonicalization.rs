@@ -1,15 +1,17 @@
 use crate::analysis::lookahead_dfa::ProductionIndex;
 use crate::generate_name;
+use crate::generators::generate_terminal_name;
 use crate::grammar::{ProductionAttribute, SymbolAttribute};
-use crate::parser::parol_grammar::GrammarType;
-use crate::parser::{Alternation, Alternations, Factor, Production};
+use crate::parser::parol_grammar::{GrammarType, UserDefinedTypeName};
+use crate::parser::{Alternation, Alternations, Factor, Production, Template};
 use crate::utils::combine;
-use crate::{Pr, Symbol};
+use crate::{Cfg, Pr, Symbol};
 // $env:RUST_LOG="parol::transformation::canonicalization=trace"
 use anyhow::{bail, Result};
 use parol_runtime::log::trace;
 use parol_runtime::once_cell::sync::Lazy;
 use regex::Regex;
+use std::collections::VecDeque;
 use std::convert::TryFrom;
 
 pub(crate) static RX_OPT_WITH_NUM_SUFFIX: Lazy<Regex> =
@@ -48,7 +50,9 @@ fn finalize(productions: Vec<Production>) -> Result<Vec<Pr>> {
                             });
                     prod?
                 },
-                single_alternative.1
+                single_alternative.1,
+                single_alternative.2,
+                single_alternative.3,
             ))
         })
         .collect()
@@ -150,7 +154,7 @@ fn extract_options(opd: TransformationOperand) -> TransformationOperand {
     ) -> Option<(String, Alternations)> {
         for factor in &mut alt.0 {
             match factor.clone() {
-                Factor::Group(_) | Factor::Repeat(_) => {
+                Factor::Group(_) | Factor::Repeat(_) | Factor::NonEmptyRepeat(..) => {
                     if let Some((name, alts)) = extract_optional_in_alts(
                         factor.inner_alts_mut().expect("Should always succeed"),
                         non_terminal.clone(),
@@ -170,6 +174,7 @@ fn extract_options(opd: TransformationOperand) -> TransformationOperand {
                         new_opt_production_name.clone(),
                         SymbolAttribute::Option,
                         None,
+                        None,
                     );
                     trace!(
                         "Extracting optional {} into production {}",
@@ -254,10 +259,9 @@ fn extract_options(opd: TransformationOperand) -> TransformationOperand {
                 prod_num + 1,
                 Production::new(
                     name,
-                    Alternations(vec![Alternation(
-                        vec![Factor::Group(alts)],
-                        ProductionAttribute::OptionalSome,
-                    )]),
+                    Alternations(vec![Alternation::new()
+                        .with_factors(vec![Factor::Group(alts)])
+                        .with_attribute(ProductionAttribute::OptionalSome)]),
                 ),
             );
         } else {
@@ -359,24 +363,34 @@ fn eliminate_single_rep(
     if let Some(rpt_index_in_alt) = production.rhs.0[alt_index]
         .0
         .iter()
-        .position(|f| matches!(f, Factor::Repeat(_)))
+        .position(|f| matches!(f, Factor::Repeat(_) | Factor::NonEmptyRepeat(..)))
     {
+        if let Factor::NonEmptyRepeat(..) = &production.rhs.0[alt_index].0[rpt_index_in_alt] {
+            return eliminate_single_non_empty_rep(
+                exclusions,
+                alt_index,
+                rpt_index_in_alt,
+                grammar_type,
+                production,
+            );
+        }
         let r_tick_name = generate_name(exclusions, production_name + "List");
         if let Factor::Repeat(repeat) = production.rhs.0[alt_index].0[rpt_index_in_alt].clone() {
             let mut production1 = production.clone();
-            production1.rhs.0[alt_index].0[rpt_index_in_alt] =
-                Factor::NonTerminal(r_tick_name.clone(), SymbolAttribute::RepetitionAnchor, None);
+            production1.rhs.0[alt_index].0[rpt_index_in_alt] = Factor::NonTerminal(
+                r_tick_name.clone(),
+                SymbolAttribute::RepetitionAnchor,
+                None,
+                None,
+            );
 
             let production2 = if repeat.0.len() == 1 {
                 // Case 1
                 let mut rhs_p2 = repeat.0;
-                match grammar_type {
-                    GrammarType::LLK => {
-                        rhs_p2[0].push(Factor::default_non_terminal(r_tick_name.clone()));
-                    }
-                    GrammarType::LALR1 => {
-                        rhs_p2[0].insert(0, Factor::default_non_terminal(r_tick_name.clone()));
-                    }
+                if grammar_type.is_lr() {
+                    rhs_p2[0].insert(0, Factor::default_non_terminal(r_tick_name.clone()));
+                } else {
+                    rhs_p2[0].push(Factor::default_non_terminal(r_tick_name.clone()));
                 }
                 rhs_p2[0].1 = ProductionAttribute::AddToCollection;
 
@@ -389,15 +403,16 @@ fn eliminate_single_rep(
                 Production {
                     lhs: r_tick_name.clone(),
                     rhs: Alternations(vec![Alternation::new()
-                        .with_factors(match grammar_type {
-                            GrammarType::LLK => vec![
-                                Factor::Group(repeat),
-                                Factor::default_non_terminal(r_tick_name.clone()),
-                            ],
-                            GrammarType::LALR1 => vec![
+                        .with_factors(if grammar_type.is_lr() {
+                            vec![
                                 Factor::default_non_terminal(r_tick_name.clone()),
                                 Factor::Group(repeat),
-                            ],
+                            ]
+                        } else {
+                            vec![
+                                Factor::Group(repeat),
+                                Factor::default_non_terminal(r_tick_name.clone()),
+                            ]
                         })
                         .with_attribute(ProductionAttribute::AddToCollection)]),
                 }
@@ -419,6 +434,116 @@ fn eliminate_single_rep(
     }
 }
 
+// -------------------------------------------------------------------------
+// Replace the Factor at the given position that is a non-empty R with a production that matches
+// the first element followed by a repetition of the remaining elements.
+// -------------------------------------------------------------------------
+// R   -> x { a % s } y
+// =>
+// For LL grammars:
+// R   -> x R' y       (1) - Non-terminal R' receives SymbolAttribute::NonEmptyRepetitionAnchor
+// R'  -> a R''        (2) - Alternation receives ProductionAttribute::NonEmptyCollectionStart
+//                           Non-terminal R'' receives SymbolAttribute::RepetitionAnchor
+// R'' -> s a R''      (3) - Alternation receives ProductionAttribute::AddToCollection
+// R'' ->              (3a)- Alternation receives ProductionAttribute::CollectionStart
+// For LR grammars (3) is left-recursive:
+// R'' -> R'' s a      (3) - Alternation receives ProductionAttribute::AddToCollection
+//
+// A repetition with '+' like { a }+ is handled the same way without s.
+// If a is not a single sequence of terminals and non-terminals it is extracted into a production
+// of its own. Otherwise the two copies of a would be expanded into different types.
+// R'  -> E R''        (2)
+// R'' -> s E R''      (3)
+// E   -> a            (4)
+fn eliminate_single_non_empty_rep(
+    exclusions: &[String],
+    alt_index: usize,
+    rpt_index_in_alt: usize,
+    grammar_type: GrammarType,
+    production: Production,
+) -> Vec<Production> {
+    let (repeat, separator) = if let Factor::NonEmptyRepeat(repeat, separator) =
+        production.rhs.0[alt_index].0[rpt_index_in_alt].clone()
+    {
+        (repeat, separator)
+    } else {
+        panic!("Expected Factor::NonEmptyRepeat!");
+    };
+
+    let mut exclusions = exclusions.to_vec();
+    let r_tick_name = generate_name(&exclusions, production.lhs.clone() + "List");
+    exclusions.push(r_tick_name.clone());
+    let r_tick_tick_name = generate_name(&exclusions, r_tick_name.clone() + "Item");
+    exclusions.push(r_tick_tick_name.clone());
+
+    let mut production1 = production;
+    production1.rhs.0[alt_index].0[rpt_index_in_alt] = Factor::NonTerminal(
+        r_tick_name.clone(),
+        SymbolAttribute::NonEmptyRepetitionAnchor,
+        None,
+        None,
+    );
+    let mut productions = vec![production1];
+
+    let is_simple_sequence = repeat.0.len() == 1
+        && repeat.0[0]
+            .0
+            .iter()
+            .all(|f| matches!(f, Factor::Terminal(..) | Factor::NonTerminal(..)));
+    let element = if is_simple_sequence {
+        repeat.0[0].0.clone()
+    } else {
+        // Case with extraction of E
+        let e_name = generate_name(&exclusions, r_tick_name.clone() + "Group");
+        productions.push(Production {
+            lhs: e_name.clone(),
+            rhs: repeat,
+        });
+        vec![Factor::default_non_terminal(e_name)]
+    };
+
+    let mut head = element.clone();
+    head.push(Factor::NonTerminal(
+        r_tick_tick_name.clone(),
+        SymbolAttribute::RepetitionAnchor,
+        None,
+        None,
+    ));
+    let production2 = Production {
+        lhs: r_tick_name,
+        rhs: Alternations(vec![Alternation::new()
+            .with_factors(head)
+            .with_attribute(ProductionAttribute::NonEmptyCollectionStart)]),
+    };
+
+    let mut tail: Vec<Factor> = separator.into_iter().map(|s| *s).collect();
+    tail.extend(element);
+    if grammar_type.is_lr() {
+        tail.insert(0, Factor::default_non_terminal(r_tick_tick_name.clone()))
+    } else {
+        tail.push(Factor::default_non_terminal(r_tick_tick_name.clone()))
+    }
+    let production3 = Production {
+        lhs: r_tick_tick_name.clone(),
+        rhs: Alternations(vec![Alternation::new()
+            .with_factors(tail)
+            .with_attribute(ProductionAttribute::AddToCollection)]),
+    };
+
+    let production3a = Production {
+        lhs: r_tick_tick_name,
+        rhs: Alternations(vec![
+            Alternation::new().with_attribute(ProductionAttribute::CollectionStart)
+        ]),
+    };
+
+    // Keep the extracted E behind the productions of the repetition
+    let extracted = productions.split_off(1);
+    productions.extend([production2, production3, production3a]);
+    productions.extend(extracted);
+    productions
+}
+
 // Eliminate repetitions
 fn eliminate_repetitions(
     opd: TransformationOperand,
@@ -427,7 +552,9 @@ fn eliminate_repetitions(
     fn find_production_with_repetition(
         productions: &[Production],
     ) -> Option<(ProductionIndex, usize)> {
-        find_production_with_factor(productions, |f| matches!(f, Factor::Repeat(_)))
+        find_production_with_factor(productions, |f| {
+            matches!(f, Factor::Repeat(_) | Factor::NonEmptyRepeat(..))
+        })
     }
 
     fn eliminate_repetition(productions: &mut Vec<Production>, grammar_type: GrammarType) -> bool {
@@ -655,6 +782,230 @@ fn eliminate_groups(opd: TransformationOperand) -> TransformationOperand {
     }
 }
 
+// The maximum nesting of template instantiations.
+// It stops templates that instantiate themselves with ever growing arguments.
+const MAX_TEMPLATE_NESTING: usize = 16;
+
+struct TemplateInstances<'a> {
+    templates: &'a [Template],
+    exclusions: Vec<String>,
+    // Template name, arguments and the name of the non-terminal that replaces the instance
+    instances: Vec<(String, Vec<Factor>, String)>,
+    // Indices of instances whose productions are not created yet, with their nesting depth
+    pending: VecDeque<(usize, usize)>,
+}
+
+impl<'a> TemplateInstances<'a> {
+    fn new(templates: &'a [Template], exclusions: Vec<String>) -> Self {
+        Self {
+            templates,
+            exclusions,
+            instances: Vec::new(),
+            pending: VecDeque::new(),
+        }
+    }
+
+    fn resolve_alternations(&mut self, alts: &mut Alternations, depth: usize) -> Result<()> {
+        for alt in &mut alts.0 {
+            for factor in &mut alt.0 {
+                self.resolve_factor(factor, depth)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn resolve_factor(&mut self, factor: &mut Factor, depth: usize) -> Result<()> {
+        match factor {
+            Factor::Group(alts) | Factor::Repeat(alts) | Factor::Optional(alts) => {
+                self.resolve_alternations(alts, depth)
+            }
+            Factor::NonEmptyRepeat(alts, separator) => {
+                self.resolve_alternations(alts, depth)?;
+                if let Some(separator) = separator {
+                    self.resolve_factor(separator, depth)?;
+                }
+                Ok(())
+            }
+            Factor::TemplateInstance(name, args, attr, user_type, label) => {
+                for arg in args.iter_mut() {
+                    self.resolve_factor(arg, depth)?;
+                }
+                let instance_name = self.instance_name(name, args, depth)?;
+                *factor =
+                    Factor::NonTerminal(instance_name, *attr, user_type.clone(), label.clone());
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    // Returns the name of the non-terminal for the given instance. It is created on first use.
+    fn instance_name(&mut self, name: &str, args: &[Factor], depth: usize) -> Result<String> {
+        if let Some((_, _, instance_name)) = self
+            .instances
+            .iter()
+            .find(|(n, a, _)| n == name && a.as_slice() == args)
+        {
+            return Ok(instance_name.clone());
+        }
+        let templates = self.templates.iter().filter(|t| t.name == name);
+        let mut found = false;
+        for template in templates {
+            found = true;
+            if template.params.len() != args.len() {
+                bail!(
+                    "Template {} expects {} argument(s) but {} were given!",
+                    name,
+                    template.params.len(),
+                    args.len()
+                );
+            }
+        }
+        if !found {
+            bail!("Template {} is not defined!", name);
+        }
+        if depth >= MAX_TEMPLATE_NESTING {
+            bail!(
+                "Instantiations of template {} are nested too deeply, it probably instantiates itself with growing arguments!",
+                name
+            );
+        }
+        let preferred_name = args
+            .iter()
+            .fold(name.to_string(), |acc, arg| acc + &argument_name(arg));
+        let instance_name = generate_name(&self.exclusions, preferred_name);
+        trace!("Instantiating template {} as {}", name, instance_name);
+        self.exclusions.push(instance_name.clone());
+        self.instances
+            .push((name.to_string(), args.to_vec(), instance_name.clone()));
+        self.pending
+            .push_back((self.instances.len() - 1, depth + 1));
+        Ok(instance_name)
+    }
+
+    // Creates the productions of all pending instances
+    fn instantiate_pending(&mut self, productions: &mut Vec<Production>) -> Result<()> {
+        while let Some((index, depth)) = self.pending.pop_front() {
+            let (name, args, instance_name) = self.instances[index].clone();
+            for template in self.templates.iter().filter(|t| t.name == name) {
+                let mut rhs = template.rhs.clone();
+                substitute_parameters(&mut rhs, &template.params, &args);
+                self.resolve_alternations(&mut rhs, depth)?;
+                productions.push(Production::new(instance_name.clone(), rhs));
+            }
+        }
+        Ok(())
+    }
+}
+
+// A part of the instance name that is derived from the argument
+fn argument_name(arg: &Factor) -> String {
+    match arg {
+        Factor::NonTerminal(n, ..) => n.clone(),
+        Factor::Terminal(t, _, _, _, _, c, _) => {
+            let name = generate_terminal_name(t, None, &Cfg::default());
+            match c {
+                Some(c) => name + &generate_terminal_name(&c.text, None, &Cfg::default()),
+                None => name,
+            }
+        }
+        Factor::Group(_) => "Group".to_string(),
+        Factor::Repeat(_) | Factor::NonEmptyRepeat(..) => "List".to_string(),
+        Factor::Optional(_) => "Opt".to_string(),
+        _ => String::new(),
+    }
+}
+
+fn substitute_parameters(alts: &mut Alternations, params: &[String], args: &[Factor]) {
+    for alt in &mut alts.0 {
+        for factor in &mut alt.0 {
+            substitute_parameter(factor, params, args);
+        }
+    }
+}
+
+fn substitute_parameter(factor: &mut Factor, params: &[String], args: &[Factor]) {
+    match factor {
+        Factor::Group(alts) | Factor::Repeat(alts) | Factor::Optional(alts) => {
+            substitute_parameters(alts, params, args)
+        }
+        Factor::NonEmptyRepeat(alts, separator) => {
+            substitute_parameters(alts, params, args);
+            if let Some(separator) = separator {
+                substitute_parameter(separator, params, args);
+            }
+        }
+        Factor::TemplateInstance(_, inner_args, ..) => {
+            for arg in inner_args.iter_mut() {
+                substitute_parameter(arg, params, args);
+            }
+        }
+        Factor::NonTerminal(n, attr, user_type, label) => {
+            if let Some(index) = params.iter().position(|p| p == n) {
+                *factor =
+                    with_ast_control(args[index].clone(), *attr, user_type.clone(), label.clone());
+            }
+        }
+        _ => (),
+    }
+}
+
+// Applies the cut operator, the user type or the label of the parameter to the symbol given as
+// argument
+fn with_ast_control(
+    arg: Factor,
+    attr: SymbolAttribute,
+    user_type: Option<UserDefinedTypeName>,
+    label: Option<String>,
+) -> Factor {
+    let merge_attr = |a: SymbolAttribute| {
+        if attr == SymbolAttribute::None {
+            a
+        } else {
+            attr
+        }
+    };
+    match arg {
+        Factor::Terminal(t, k, s, a, u, c, l) => {
+            Factor::Terminal(t, k, s, merge_attr(a), user_type.or(u), c, label.or(l))
+        }
+        Factor::NonTerminal(n, a, u, l) => {
+            Factor::NonTerminal(n, merge_attr(a), user_type.or(u), label.or(l))
+        }
+        Factor::TemplateInstance(n, args, a, u, l) => {
+            Factor::TemplateInstance(n, args, merge_attr(a), user_type.or(u), label.or(l))
+        }
+        _ => arg,
+    }
+}
+
+// -------------------------------------------------------------------------
+// Replace all template instances with non-terminals of new productions.
+// -------------------------------------------------------------------------
+// List<X, S>: X { S X };
+// R: List<A, ','>;
+// =>
+// R: ListAComma;
+// ListAComma: A { ',' A };
+//
+// Each distinct instance is created only once and thus yields one non-terminal.
+// Instances within a template are resolved after its parameters are substituted.
+// This step must be executed before the transformation of the productions.
+pub(crate) fn instantiate_templates(
+    productions: Vec<Production>,
+    templates: &[Template],
+) -> Result<Vec<Production>> {
+    let mut exclusions = variable_names(&productions);
+    exclusions.extend(templates.iter().map(|t| t.name.clone()));
+    let mut instances = TemplateInstances::new(templates, exclusions);
+    let mut productions = productions;
+    for production in &mut productions {
+        instances.resolve_alternations(&mut production.rhs, 0)?;
+    }
+    instances.instantiate_pending(&mut productions)?;
+    Ok(productions)
+}
+
 // -------------------------------------------------------------------------
 // Guidelines:
 // After applying all transformation inner (sub-) expressions should be factored out.
@@ -706,8 +1057,8 @@ pub(crate) fn transform_productions(
 #[cfg(test)]
 mod test {
     use super::{
-        eliminate_single_grp, eliminate_single_opt, eliminate_single_rep, Alternation,
-        Alternations, Factor, Production,
+        eliminate_single_grp, eliminate_single_opt, eliminate_single_rep, instantiate_templates,
+        Alternation, Alternations, Factor, Production, Template,
     };
     use crate::{
         grammar::{ProductionAttribute, SymbolAttribute, TerminalKind},
@@ -723,6 +1074,8 @@ mod test {
                 vec![0],
                 SymbolAttribute::None,
                 None,
+                None,
+                None,
             )
         };
     }
@@ -746,8 +1099,13 @@ mod test {
             ])]),
         };
 
-        let productions =
-            eliminate_single_rep(&[production.lhs.clone()], 0, GrammarType::LALR1, production);
+        let lhs = production.lhs.clone();
+        let productions = eliminate_single_rep(
+            std::slice::from_ref(&lhs),
+            0,
+            GrammarType::LALR1,
+            production,
+        );
         assert_eq!(3, productions.len());
         // Start: x StartList y;
         assert_eq!(
@@ -758,6 +1116,7 @@ mod test {
                     Factor::NonTerminal(
                         "StartList".to_string(),
                         SymbolAttribute::RepetitionAnchor,
+                        None,
                         None
                     ),
                     terminal!("y"),
@@ -810,8 +1169,9 @@ mod test {
             ])]),
         };
 
+        let lhs = production.lhs.clone();
         let productions =
-            eliminate_single_rep(&[production.lhs.clone()], 0, GrammarType::LLK, production);
+            eliminate_single_rep(std::slice::from_ref(&lhs), 0, GrammarType::LLK, production);
         assert_eq!(3, productions.len());
         // Start: x StartList y;
         assert_eq!(
@@ -822,6 +1182,7 @@ mod test {
                     Factor::NonTerminal(
                         "StartList".to_string(),
                         SymbolAttribute::RepetitionAnchor,
+                        None,
                         None
                     ),
                     terminal!("y"),
@@ -875,8 +1236,9 @@ mod test {
             ])]),
         };
 
+        let lhs = production.lhs.clone();
         let productions =
-            eliminate_single_rep(&[production.lhs.clone()], 0, GrammarType::LLK, production);
+            eliminate_single_rep(std::slice::from_ref(&lhs), 0, GrammarType::LLK, production);
         assert_eq!(3, productions.len());
         // Start: x StartList y;
         assert_eq!(
@@ -887,6 +1249,7 @@ mod test {
                     Factor::NonTerminal(
                         "StartList".to_string(),
                         SymbolAttribute::RepetitionAnchor,
+                        None,
                         None
                     ),
                     terminal!("x"),
@@ -942,8 +1305,13 @@ mod test {
             ])]),
         };
 
-        let productions =
-            eliminate_single_rep(&[production.lhs.clone()], 0, GrammarType::LALR1, production);
+        let lhs = production.lhs.clone();
+        let productions = eliminate_single_rep(
+            std::slice::from_ref(&lhs),
+            0,
+            GrammarType::LALR1,
+            production,
+        );
         assert_eq!(3, productions.len());
         // Start: x StartList y;
         assert_eq!(
@@ -954,6 +1322,7 @@ mod test {
                     Factor::NonTerminal(
                         "StartList".to_string(),
                         SymbolAttribute::RepetitionAnchor,
+                        None,
                         None
                     ),
                     terminal!("x"),
@@ -989,6 +1358,169 @@ mod test {
         );
     }
 
+    // R   -> x { r % s } y
+    // =>
+    // R   -> x R' y        (1)
+    // R'  -> r R''         (2)
+    // R'' -> s r R''       (3)
+    // R'' ->               (3a)
+    #[test]
+    fn eliminate_single_non_empty_rep_case_1_ll() {
+        // Start: x { r % s } y;
+        let production = Production {
+            lhs: "Start".to_string(),
+            rhs: Alternations(vec![Alternation::new().with_factors(vec![
+                terminal!("x"),
+                Factor::NonEmptyRepeat(
+                    Alternations(vec![Alternation::new().with_factors(vec![terminal!("r")])]),
+                    Some(Box::new(terminal!("s"))),
+                ),
+                terminal!("y"),
+            ])]),
+        };
+
+        let lhs = production.lhs.clone();
+        let productions =
+            eliminate_single_rep(std::slice::from_ref(&lhs), 0, GrammarType::LLK, production);
+        assert_eq!(4, productions.len());
+        // Start: x StartList y;
+        assert_eq!(
+            Production {
+                lhs: "Start".to_string(),
+                rhs: Alternations(vec![Alternation::new().with_factors(vec![
+                    terminal!("x"),
+                    Factor::NonTerminal(
+                        "StartList".to_string(),
+                        SymbolAttribute::NonEmptyRepetitionAnchor,
+                        None,
+                        None
+                    ),
+                    terminal!("y"),
+                ])])
+            },
+            productions[0]
+        );
+        // StartList: r StartListItem;
+        assert_eq!(
+            Production {
+                lhs: "StartList".to_string(),
+                rhs: Alternations(vec![Alternation::new()
+                    .with_factors(vec![
+                        terminal!("r"),
+                        Factor::NonTerminal(
+                            "StartListItem".to_string(),
+                            SymbolAttribute::RepetitionAnchor,
+                            None,
+                            None
+                        ),
+                    ])
+                    .with_attribute(ProductionAttribute::NonEmptyCollectionStart)])
+            },
+            productions[1]
+        );
+        // StartListItem: s r StartListItem;
+        assert_eq!(
+            Production {
+                lhs: "StartListItem".to_string(),
+                rhs: Alternations(vec![Alternation::new()
+                    .with_factors(vec![
+                        terminal!("s"),
+                        terminal!("r"),
+                        Factor::default_non_terminal("StartListItem".to_string()),
+                    ])
+                    .with_attribute(ProductionAttribute::AddToCollection)])
+            },
+            productions[2]
+        );
+        // StartListItem: ;
+        assert_eq!(
+            Production {
+                lhs: "StartListItem".to_string(),
+                rhs: Alternations(vec![
+                    Alternation::new().with_attribute(ProductionAttribute::CollectionStart)
+                ])
+            },
+            productions[3]
+        );
+    }
+
+    // R   -> x { r1 | r2 }+ y
+    // =>
+    // R   -> x R' y        (1)
+    // R'  -> E R''         (2)
+    // R'' -> R'' E         (3)
+    // R'' ->               (3a)
+    // E   -> r1 | r2       (4)
+    #[test]
+    fn eliminate_single_non_empty_rep_case_2_lr() {
+        // Start: x { r1 | r2 }+ y;
+        let production = Production {
+            lhs: "Start".to_string(),
+            rhs: Alternations(vec![Alternation::new().with_factors(vec![
+                terminal!("x"),
+                Factor::NonEmptyRepeat(
+                    Alternations(vec![
+                        Alternation::new().with_factors(vec![terminal!("r1")]),
+                        Alternation::new().with_factors(vec![terminal!("r2")]),
+                    ]),
+                    None,
+                ),
+                terminal!("y"),
+            ])]),
+        };
+
+        let lhs = production.lhs.clone();
+        let productions = eliminate_single_rep(
+            std::slice::from_ref(&lhs),
+            0,
+            GrammarType::LALR1,
+            production,
+        );
+        assert_eq!(5, productions.len());
+        // StartList: StartListGroup StartListItem;
+        assert_eq!(
+            Production {
+                lhs: "StartList".to_string(),
+                rhs: Alternations(vec![Alternation::new()
+                    .with_factors(vec![
+                        Factor::default_non_terminal("StartListGroup".to_string()),
+                        Factor::NonTerminal(
+                            "StartListItem".to_string(),
+                            SymbolAttribute::RepetitionAnchor,
+                            None,
+                            None
+                        ),
+                    ])
+                    .with_attribute(ProductionAttribute::NonEmptyCollectionStart)])
+            },
+            productions[1]
+        );
+        // StartListItem: StartListItem StartListGroup;
+        assert_eq!(
+            Production {
+                lhs: "StartListItem".to_string(),
+                rhs: Alternations(vec![Alternation::new()
+                    .with_factors(vec![
+                        Factor::default_non_terminal("StartListItem".to_string()),
+                        Factor::default_non_terminal("StartListGroup".to_string()),
+                    ])
+                    .with_attribute(ProductionAttribute::AddToCollection)])
+            },
+            productions[2]
+        );
+        // StartListGroup: r1 | r2;
+        assert_eq!(
+            Production {
+                lhs: "StartListGroup".to_string(),
+                rhs: Alternations(vec![
+                    Alternation::new().with_factors(vec![terminal!("r1")]),
+                    Alternation::new().with_factors(vec![terminal!("r2")]),
+                ])
+            },
+            productions[4]
+        );
+    }
+
     // R  -> x [ o1 o2 ] y.
     // =>
     // R  -> x o1 o2 y     (1)
@@ -1007,7 +1539,8 @@ mod test {
             ])]),
         };
 
-        let productions = eliminate_single_opt(&[production.lhs.clone()], 0, production);
+        let lhs = production.lhs.clone();
+        let productions = eliminate_single_opt(std::slice::from_ref(&lhs), 0, production);
         assert_eq!(2, productions.len());
         // Start: x o1 o2 y;
         assert_eq!(
@@ -1054,7 +1587,8 @@ mod test {
             ])]),
         };
 
-        let productions = eliminate_single_opt(&[production.lhs.clone()], 0, production);
+        let lhs = production.lhs.clone();
+        let productions = eliminate_single_opt(std::slice::from_ref(&lhs), 0, production);
         assert_eq!(3, productions.len());
         // Start: x StartOpt y;
         assert_eq!(
@@ -1108,7 +1642,8 @@ mod test {
             ])]),
         };
 
-        let productions = eliminate_single_grp(&[production.lhs.clone()], 0, production);
+        let lhs = production.lhs.clone();
+        let productions = eliminate_single_grp(std::slice::from_ref(&lhs), 0, production);
         assert_eq!(1, productions.len());
         // Start: x g1 g2 y;
         assert_eq!(
@@ -1144,7 +1679,8 @@ mod test {
             ])]),
         };
 
-        let productions = eliminate_single_grp(&[production.lhs.clone()], 0, production);
+        let lhs = production.lhs.clone();
+        let productions = eliminate_single_grp(std::slice::from_ref(&lhs), 0, production);
         assert_eq!(2, productions.len());
         // Start: x StartGroup y;
         assert_eq!(
@@ -1171,6 +1707,130 @@ mod test {
         );
     }
 
+    // List<X, S>: X { S X };
+    // R: List<A, ','> List<A, ','>;
+    // =>
+    // R: ListAComma ListAComma;
+    // ListAComma: A { ',' A };
+    #[test]
+    fn instantiate_templates_once_per_instance() {
+        let list = Template {
+            name: "List".to_string(),
+            params: vec!["X".to_string(), "S".to_string()],
+            rhs: Alternations(vec![Alternation::new().with_factors(vec![
+                Factor::default_non_terminal("X".to_string()),
+                Factor::Repeat(Alternations(vec![Alternation::new().with_factors(vec![
+                    Factor::NonTerminal("S".to_string(), SymbolAttribute::Clipped, None, None),
+                    Factor::default_non_terminal("X".to_string()),
+                ])])),
+            ])]),
+        };
+        let instance = Factor::TemplateInstance(
+            "List".to_string(),
+            vec![
+                Factor::default_non_terminal("A".to_string()),
+                terminal!(","),
+            ],
+            SymbolAttribute::None,
+            None,
+            None,
+        );
+        let production = Production {
+            lhs: "R".to_string(),
+            rhs: Alternations(vec![
+                Alternation::new().with_factors(vec![instance.clone(), instance])
+            ]),
+        };
+
+        let productions = instantiate_templates(vec![production], &[list]).unwrap();
+        assert_eq!(2, productions.len());
+        assert_eq!(
+            Production {
+                lhs: "R".to_string(),
+                rhs: Alternations(vec![Alternation::new().with_factors(vec![
+                    Factor::default_non_terminal("ListAComma".to_string()),
+                    Factor::default_non_terminal("ListAComma".to_string()),
+                ])]),
+            },
+            productions[0]
+        );
+        assert_eq!(
+            Production {
+                lhs: "ListAComma".to_string(),
+                rhs: Alternations(vec![Alternation::new().with_factors(vec![
+                    Factor::default_non_terminal("A".to_string()),
+                    Factor::Repeat(Alternations(vec![Alternation::new().with_factors(vec![
+                        Factor::Terminal(
+                            ",".to_string(),
+                            TerminalKind::Legacy,
+                            vec![0],
+                            SymbolAttribute::Clipped,
+                            None,
+                            None,
+                            None,
+                        ),
+                        Factor::default_non_terminal("A".to_string()),
+                    ])])),
+                ])]),
+            },
+            productions[1]
+        );
+    }
+
+    // Seq<X>: X Seq<X> | ;
+    // R: Seq<A>;
+    // =>
+    // R: SeqA;
+    // SeqA: A SeqA | ;
+    #[test]
+    fn instantiate_recursive_template() {
+        let seq = Template {
+            name: "Seq".to_string(),
+            params: vec!["X".to_string()],
+            rhs: Alternations(vec![
+                Alternation::new().with_factors(vec![
+                    Factor::default_non_terminal("X".to_string()),
+                    Factor::TemplateInstance(
+                        "Seq".to_string(),
+                        vec![Factor::default_non_terminal("X".to_string())],
+                        SymbolAttribute::None,
+                        None,
+                        None,
+                    ),
+                ]),
+                Alternation::new(),
+            ]),
+        };
+        let production = Production {
+            lhs: "R".to_string(),
+            rhs: Alternations(vec![Alternation::new().with_factors(vec![
+                Factor::TemplateInstance(
+                    "Seq".to_string(),
+                    vec![Factor::default_non_terminal("A".to_string())],
+                    SymbolAttribute::None,
+                    None,
+                    None,
+                ),
+            ])]),
+        };
+
+        let productions = instantiate_templates(vec![production], &[seq]).unwrap();
+        assert_eq!(2, productions.len());
+        assert_eq!(
+            Production {
+                lhs: "SeqA".to_string(),
+                rhs: Alternations(vec![
+                    Alternation::new().with_factors(vec![
+                        Factor::default_non_terminal("A".to_string()),
+                        Factor::default_non_terminal("SeqA".to_string()),
+                    ]),
+                    Alternation::new(),
+                ]),
+            },
+            productions[1]
+        );
+    }
+
     #[test]
     fn test_apply_production_transformation1() {
         let mut productions = vec![
@@ -0,0 +1,332 @@
+use crate::grammar::ProductionAttribute;
+use crate::{detect_left_recursive_non_terminals, generate_name, Cfg, Pr, Symbol, SymbolAttribute};
+use parol_runtime::log::trace;
+
+// Replaces the productions of the given non-terminal with the given ones at the position of its
+// first production
+fn replace_productions(pr: &mut Vec<Pr>, non_terminal: &str, mut productions: Vec<Pr>) {
+    let position = pr
+        .iter()
+        .position(|p| p.get_n_str() == non_terminal)
+        .unwrap_or(pr.len());
+    pr.retain(|p| p.get_n_str() != non_terminal);
+    let mut upper_productions = pr.split_off(position);
+    pr.append(&mut productions);
+    pr.append(&mut upper_productions);
+}
+
+fn starts_with(p: &Pr, non_terminal: &str) -> bool {
+    matches!(p.get_r().first(), Some(Symbol::N(n, ..)) if n == non_terminal)
+}
+
+// -------------------------------------------------------------------------
+// Substitute the leading non-terminal B with the alternatives of B
+// -------------------------------------------------------------------------
+// A  -> B y
+// B  -> x1 | x2
+// =>
+// A  -> x1 y | x2 y
+//
+// The label of a substituted alternative of A is kept. If it is duplicated it is combined with the
+// labels of the alternatives of B or numbered, because the labels of A must stay unique.
+fn substitute_leading_non_terminal(pr: &mut Vec<Pr>, a: &str, b: &str) {
+    let b_productions = pr
+        .iter()
+        .filter(|p| p.get_n_str() == b)
+        .cloned()
+        .collect::<Vec<Pr>>();
+    let mut labels = pr
+        .iter()
+        .filter(|p| p.get_n_str() == a && !starts_with(p, b))
+        .filter_map(|p| p.4.clone())
+        .collect::<Vec<String>>();
+    let a_productions = pr
+        .iter()
+        .filter(|p| p.get_n_str() == a)
+        .fold(Vec::new(), |mut acc, p| {
+            if starts_with(p, b) {
+                acc.extend(b_productions.iter().map(|b_production| {
+                    let label = p.4.as_ref().map(|label| {
+                        let preferred_name = match &b_production.4 {
+                            Some(b_label) if b_productions.len() > 1 => label.clone() + b_label,
+                            _ => label.clone(),
+                        };
+                        let label = generate_name(&labels, preferred_name);
+                        labels.push(label.clone());
+                        label
+                    });
+                    let mut rhs = b_production.get_r().clone();
+                    rhs.extend(p.get_r()[1..].iter().cloned());
+                    Pr::new(a, rhs)
+                        .with_attribute(p.2)
+                        .with_precedence(p.3.clone())
+                        .with_label(label)
+                }));
+            } else {
+                acc.push(p.clone());
+            }
+            acc
+        });
+    replace_productions(pr, a, a_productions);
+}
+
+// -------------------------------------------------------------------------
+// Replace the direct left recursion of A with a repetition
+// -------------------------------------------------------------------------
+// A  -> A a | b
+// =>
+// Case 1: Iff there is only one left-recursive alternative
+// A  -> b A'          (1) - Non-terminal A' receives SymbolAttribute::RepetitionAnchor
+// A' -> a A'          (2) - Alternation receives ProductionAttribute::AddToCollection
+// A' ->               (2a)- Alternation receives ProductionAttribute::CollectionStart
+// Case 2: Otherwise
+// A  -> b A'          (1) - Non-terminal A' receives SymbolAttribute::RepetitionAnchor
+// A' -> A'' A'        (2) - Alternation receives ProductionAttribute::AddToCollection
+// A' ->               (2a)- Alternation receives ProductionAttribute::CollectionStart
+// A''-> a1 | a2 ...   (3)
+// This is the same structure as the canonicalization of the equivalent grammar A -> b { a }
+// produces.
+fn eliminate_direct_left_recursion(pr: &mut Vec<Pr>, exclusions: &mut Vec<String>, a: &str) {
+    let (recursive, non_recursive): (Vec<Pr>, Vec<Pr>) = pr
+        .iter()
+        .filter(|p| p.get_n_str() == a)
+        // Productions of the form A -> A don't add anything to the language
+        .filter(|p| !(p.len() == 1 && starts_with(p, a)))
+        .cloned()
+        .partition(|p| starts_with(p, a));
+    if recursive.is_empty() {
+        return;
+    }
+
+    let list_name = generate_name(exclusions, a.to_owned() + "List");
+    exclusions.push(list_name.clone());
+
+    let mut productions = non_recursive
+        .into_iter()
+        .map(|p| {
+            let mut p = p;
+            p.1.push(Symbol::N(
+                list_name.clone(),
+                SymbolAttribute::RepetitionAnchor,
+                None,
+                None,
+            ));
+            p
+        })
+        .collect::<Vec<Pr>>();
+
+    let mut group_productions = Vec::new();
+    if recursive.len() == 1 {
+        // Case 1
+        let p = &recursive[0];
+        let mut rhs = p.get_r()[1..].to_vec();
+        rhs.push(Symbol::n(&list_name));
+        productions.push(
+            Pr::new(&list_name, rhs)
+                .with_attribute(ProductionAttribute::AddToCollection)
+                .with_precedence(p.3.clone())
+                .with_label(p.4.clone()),
+        );
+    } else {
+        // Case 2
+        let group_name = generate_name(exclusions, list_name.clone() + "Group");
+        exclusions.push(group_name.clone());
+        productions.push(
+            Pr::new(
+                &list_name,
+                vec![Symbol::n(&group_name), Symbol::n(&list_name)],
+            )
+            .with_attribute(ProductionAttribute::AddToCollection),
+        );
+        group_productions = recursive
+            .iter()
+            .map(|p| {
+                Pr::new(&group_name, p.get_r()[1..].to_vec())
+                    .with_precedence(p.3.clone())
+                    .with_label(p.4.clone())
+            })
+            .collect();
+    }
+    productions
+        .push(Pr::new(&list_name, vec![]).with_attribute(ProductionAttribute::CollectionStart));
+    productions.append(&mut group_productions);
+
+    replace_productions(pr, a, productions);
+}
+
+// ---------------------------------------------------
+// Part of the Public API
+// *Changes will affect crate's version according to semver*
+// ---------------------------------------------------
+///
+/// Eliminates direct and indirect left recursions from the given grammar.
+/// Returns the transformed grammar and the non-terminals whose productions were rewritten.
+///
+/// The left-recursive non-terminals are ordered by the position of their first production.
+/// Leading non-terminals that come earlier in this order are substituted by their alternatives
+/// until only direct left recursions remain. These are replaced by repetitions of the recursive
+/// alternatives' suffixes like the ones the canonicalization creates for `A: b { a };`.
+/// This way the items of the repetition keep the order of the input and left-associative
+/// structures can be built from them.
+///
+/// Left recursions that are hidden behind nullable non-terminals are not eliminated.
+///
+/// Note that the transformation changes the structure of the grammar and thus the types that are
+/// generated in auto-gen mode. A left-recursive non-terminal gets a `Vec` of the repeated
+/// alternatives' suffixes, a left-nested structure is not generated.
+///
+pub fn eliminate_left_recursion(cfg: &Cfg) -> (Cfg, Vec<String>) {
+    let recursive_non_terminals = detect_left_recursive_non_terminals(cfg);
+    if recursive_non_terminals.is_empty() {
+        return (cfg.clone(), Vec::new());
+    }
+
+    let Cfg { st, mut pr } = cfg.clone();
+    let mut exclusions = cfg
+        .get_non_terminal_set()
+        .into_iter()
+        .collect::<Vec<String>>();
+    let ordering = pr.iter().fold(Vec::<String>::new(), |mut acc, p| {
+        let n = p.get_n();
+        if recursive_non_terminals.contains(&n) && !acc.contains(&n) {
+            acc.push(n);
+        }
+        acc
+    });
+
+    for (i, a) in ordering.iter().enumerate() {
+        for b in &ordering[..i] {
+            substitute_leading_non_terminal(&mut pr, a, b);
+        }
+        eliminate_direct_left_recursion(&mut pr, &mut exclusions, a);
+    }
+
+    // $env:RUST_LOG="parol::transformation::left_recursion_elimination=trace"
+    trace!(
+        "Left recursion eliminated\n{}",
+        pr.iter()
+            .map(|p| p.to_string())
+            .collect::<Vec<String>>()
+            .join("\n")
+    );
+    let productions_of = |pr: &[Pr], n: &str| {
+        pr.iter()
+            .filter(|p| p.get_n_str() == n)
+            .cloned()
+            .collect::<Vec<Pr>>()
+    };
+    let rewritten = ordering
+        .into_iter()
+        .filter(|n| productions_of(&cfg.pr, n) != productions_of(&pr, n))
+        .collect();
+    (Cfg { st, pr }, rewritten)
+}
+
+#[cfg(test)]
+mod test {
+    use super::eliminate_left_recursion;
+    use crate::{detect_left_recursive_non_terminals, obtain_grammar_config_from_string};
+
+    #[derive(Debug)]
+    struct TestData {
+        input: &'static str,
+        output: &'static str,
+        rewritten: &'static [&'static str],
+    }
+
+    const TESTS: &[TestData] = &[
+        TestData {
+            input: r#"%start E %% E: E "+" T; E: T; T: T "*" F; T: F; F: "id";"#,
+            output: r#"E: T EList /* Vec */;
+EList: "+" T EList;
+EList: ;
+T: F TList /* Vec */;
+TList: "*" F TList;
+TList: ;
+F: "id";"#,
+            rewritten: &["E", "T"],
+        },
+        TestData {
+            input: r#"%start S %% S: S "a"; S: S "b"; S: "c";"#,
+            output: r#"S: "c" SList /* Vec */;
+SList: SListGroup SList;
+SList: ;
+SListGroup: "a";
+SListGroup: "b";"#,
+            rewritten: &["S"],
+        },
+        TestData {
+            input: r#"%start A %% A: B "r"; A: "a"; B: C "d"; C: A "t"; C: "c";"#,
+            output: r#"A: B "r";
+A: "a";
+B: C "d";
+C: "a" "t" CList /* Vec */;
+C: "c" CList /* Vec */;
+CList: "d" "r" "t" CList;
+CList: ;"#,
+            rewritten: &["C"],
+        },
+    ];
+
+    #[test]
+    fn check_eliminate_left_recursion() {
+        for (i, test) in TESTS.iter().enumerate() {
+            let grammar_config = obtain_grammar_config_from_string(test.input, false).unwrap();
+            let (cfg, rewritten) = eliminate_left_recursion(&grammar_config.cfg);
+            assert!(
+                detect_left_recursive_non_terminals(&cfg).is_empty(),
+                "Error at test #{i}"
+            );
+            assert_eq!(test.rewritten, rewritten, "Error at test #{i}");
+            assert_eq!(
+                test.output,
+                cfg.pr
+                    .iter()
+                    .map(|p| p.to_string())
+                    .collect::<Vec<String>>()
+                    .join("\n"),
+                "Error at test #{i}"
+            );
+        }
+    }
+
+    #[test]
+    fn check_hidden_left_recursion_is_kept() {
+        let grammar_config =
+            obtain_grammar_config_from_string(r#"%start S %% S: A S "a"; S: "b"; A: ;"#, false)
+                .unwrap();
+        let (cfg, rewritten) = eliminate_left_recursion(&grammar_config.cfg);
+        assert_eq!(
+            vec!["S".to_string()],
+            detect_left_recursive_non_terminals(&cfg)
+        );
+        assert!(rewritten.is_empty());
+    }
+
+    #[test]
+    fn check_labels_are_kept() {
+        let grammar_config = obtain_grammar_config_from_string(
+            r#"%start A %% A: B "r" #Bee | "a"; B: C "d"; C: A "t" #Aye | "c" #Cee;"#,
+            false,
+        )
+        .unwrap();
+        let (cfg, _) = eliminate_left_recursion(&grammar_config.cfg);
+        // A: B "r" #Bee; A: "a"; B: C "d"; C: "a" "t" CList #Aye; C: "c" CList #Cee;
+        // CList: "d" "r" "t" CList #AyeBee; CList: ;
+        assert_eq!(
+            vec![
+                Some("Bee"),
+                None,
+                None,
+                Some("Aye"),
+                Some("Cee"),
+                Some("AyeBee"),
+                None
+            ],
+            cfg.pr
+                .iter()
+                .map(|p| p.4.as_deref())
+                .collect::<Vec<Option<&str>>>()
+        );
+    }
+}
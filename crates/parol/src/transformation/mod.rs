@@ -3,7 +3,7 @@
 /// This is actually the transformation from an EBNF like structure to a BNF like structure.
 ///
 pub mod canonicalization;
-pub(crate) use canonicalization::transform_productions;
+pub(crate) use canonicalization::{instantiate_templates, transform_productions};
 
 ///
 /// Module with left-factoring functionality
@@ -11,6 +11,12 @@ pub(crate) use canonicalization::transform_productions;
 pub mod left_factoring;
 pub use left_factoring::left_factor;
 
+///
+/// Module with the elimination of left recursions for LL grammars
+///
+pub mod left_recursion_elimination;
+pub use left_recursion_elimination::eliminate_left_recursion;
+
 /// Module that handles the augmentation of the grammar with a new start symbol for LR parsing
 pub mod lr_augmentation;
 pub use lr_augmentation::augment_grammar;
@@ -114,6 +114,9 @@ fn run_examples_test() -> Result<()> {
     println!("Running Keywords2 examples...");
     run_keywords2_examples()?;
 
+    println!("Running Keywords3 examples...");
+    run_keywords3_examples()?;
+
     println!("Running Basic Interpreter examples...");
     run_basic_interpreter_examples()?;
 
@@ -168,6 +171,20 @@ fn run_parol_examples() -> Result<()> {
             assert!(!exit_status.success());
         }
     }
+    // Left recursions are only eliminated on demand
+    for name in ["direct-left-recursion.par", "indirect-left-recursion.par"] {
+        let grammar = std::path::Path::new(path).join(name);
+        println!(
+            "Parsing {} with eliminated left recursions...",
+            grammar.display()
+        );
+        let exit_status = run_parol(&[
+            "-f",
+            grammar.to_str().unwrap(),
+            "--eliminate-left-recursion",
+        ])?;
+        assert!(exit_status.success());
+    }
     Ok(())
 }
 
@@ -220,6 +237,37 @@ fn run_keywords2_examples() -> Result<()> {
     Ok(())
 }
 
+fn run_keywords3_examples() -> Result<()> {
+    let parser = example_path!("keywords3");
+    for entry in std::path::Path::new(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../../examples/keywords3/testfiles/valid"
+    ))
+    .read_dir()?
+    .flatten()
+    {
+        if entry.path().extension().unwrap().to_str().unwrap() == "txt" {
+            println!("Parsing {}...", entry.path().display());
+            let exit_status = run(&parser, &[entry.path().to_str().unwrap()])?;
+            assert!(exit_status.success());
+        }
+    }
+    for entry in std::path::Path::new(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../../examples/keywords3/testfiles/invalid"
+    ))
+    .read_dir()?
+    .flatten()
+    {
+        if entry.path().extension().unwrap().to_str().unwrap() == "txt" {
+            println!("Parsing {} should fail...", entry.path().display());
+            let exit_status = run(&parser, &[entry.path().to_str().unwrap()])?;
+            assert!(!exit_status.success());
+        }
+    }
+    Ok(())
+}
+
 fn run_basic_interpreter_examples() -> Result<()> {
     let parser = example_path!("basic");
     for entry in std::path::Path::new(concat!(
@@ -122,6 +122,57 @@ pub trait Report {
                         .with_message(format!("Lexer recovery error: {e}"))
                         .with_code("parol_runtime::lexer::recovery"),
                 )?),
+                LexerError::EmptyTokenMatch {
+                    token_type,
+                    location,
+                } => Ok(term::emit(
+                    &mut writer.lock(),
+                    &config,
+                    &files,
+                    &Diagnostic::error()
+                        .with_message(format!("Token type {token_type} matches the empty string"))
+                        .with_code("parol_runtime::lexer::empty_token_match")
+                        .with_labels(vec![Label::primary(
+                            file_id,
+                            Into::<Range<usize>>::into(&**location),
+                        )])
+                        .with_notes(vec![
+                            "The scanner can't make progress in the input".to_string(),
+                            "Avoid terminals that can match the empty string".to_string(),
+                        ]),
+                )?),
+                LexerError::UnterminatedBlockComment { location } => Ok(term::emit(
+                    &mut writer.lock(),
+                    &config,
+                    &files,
+                    &Diagnostic::error()
+                        .with_message("Unterminated block comment".to_string())
+                        .with_code("parol_runtime::lexer::unterminated_block_comment")
+                        .with_labels(vec![Label::primary(
+                            file_id,
+                            Into::<Range<usize>>::into(&**location),
+                        )])
+                        .with_notes(vec![
+                            "Nested block comments need a closing delimiter for each opening one"
+                                .to_string(),
+                        ]),
+                )?),
+                LexerError::InconsistentDedent { location } => Ok(term::emit(
+                    &mut writer.lock(),
+                    &config,
+                    &files,
+                    &Diagnostic::error()
+                        .with_message("Inconsistent dedent".to_string())
+                        .with_code("parol_runtime::lexer::inconsistent_dedent")
+                        .with_labels(vec![Label::primary(
+                            file_id,
+                            Into::<Range<usize>>::into(&**location),
+                        )])
+                        .with_notes(vec![
+                            "The line must start in the column of an outer indentation level"
+                                .to_string(),
+                        ]),
+                )?),
             }
         };
 
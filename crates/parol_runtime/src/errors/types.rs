@@ -1,5 +1,5 @@
 use crate::lexer::token_stream::TokenStream;
-use crate::lexer::{Location, Token};
+use crate::lexer::{Location, TerminalIndex, Token};
 use std::fmt::{Display, Formatter};
 use std::path::PathBuf;
 use std::sync::Arc;
@@ -63,6 +63,18 @@ pub enum LexerError {
 
     #[error("{0}")]
     RecoveryError(String),
+
+    #[error("Token type {token_type} matches the empty string at {location}")]
+    EmptyTokenMatch {
+        token_type: TerminalIndex,
+        location: Box<Location>,
+    },
+
+    #[error("Unterminated block comment at {location}")]
+    UnterminatedBlockComment { location: Box<Location> },
+
+    #[error("Dedent to a column that matches no outer indentation level at {location}")]
+    InconsistentDedent { location: Box<Location> },
 }
 
 #[derive(Error, Debug)]
@@ -138,7 +150,7 @@ impl TokenVec {
     }
 
     /// Returns an iterator over the tokens
-    pub fn iter(&self) -> std::slice::Iter<String> {
+    pub fn iter(&self) -> std::slice::Iter<'_, String> {
         self.0.iter()
     }
 
@@ -9,6 +9,7 @@ use derive_builder::Builder;
 /// Common Location type
 ///
 #[derive(Builder, Debug, Default, Clone, Eq, PartialEq, Ord, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Location {
     /// Position information: line number, starting by 1
     /// A value of 0 indicates an invalid position, for instance for EOF token.
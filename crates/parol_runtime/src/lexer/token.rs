@@ -33,6 +33,7 @@ const EOI_TOKEN: &str = "$";
 /// The lifetime parameter `'t` refers to the lifetime of the scanned text.
 ///
 #[derive(Debug, Clone, Default, Eq, PartialEq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Token<'t> {
     /// The matched string
     pub(crate) text: Cow<'t, str>,
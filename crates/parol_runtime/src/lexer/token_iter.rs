@@ -1,12 +1,110 @@
 use crate::{
-    lexer::{location, Token, Tokenizer, RX_NEW_LINE},
-    Location, TokenNumber,
+    lexer::{location, tokenizer::Matcher, Token, Tokenizer, BLOCK_COMMENT, RX_NEW_LINE},
+    LexerError, Location, TokenNumber,
 };
 use location::LocationBuilder;
 use log::trace;
-use regex_automata::dfa::{dense::DFA, regex::FindMatches};
+use regex_automata::{
+    dfa::{dense::DFA, regex::FindMatches, Automaton, OverlappingState},
+    Anchored, HalfMatch, Input, Match,
+};
 use std::{path::PathBuf, sync::Arc};
 
+///
+/// Iterator over the longest matches in the input.
+/// At each position the longest of all matches is taken, ties are broken by the lower pattern id,
+/// i.e. by the terminals' order.
+///
+struct LongestMatches<'t> {
+    dfa: &'static DFA<Vec<u32>>,
+    haystack: &'t [u8],
+    pos: usize,
+}
+
+impl<'t> LongestMatches<'t> {
+    fn new(dfa: &'static DFA<Vec<u32>>, haystack: &'t [u8], pos: usize) -> Self {
+        Self { dfa, haystack, pos }
+    }
+
+    // Finds the longest non-empty match that starts at the current position
+    fn longest_match(&self) -> Option<HalfMatch> {
+        let input = Input::new(self.haystack)
+            .range(self.pos..)
+            .anchored(Anchored::Yes);
+        let mut state = OverlappingState::start();
+        let mut longest: Option<HalfMatch> = None;
+        loop {
+            self.dfa
+                .try_search_overlapping_fwd(&input, &mut state)
+                .ok()?;
+            match state.get_match() {
+                Some(m) => {
+                    let is_longest = match longest {
+                        Some(l) => {
+                            m.offset() > l.offset()
+                                || (m.offset() == l.offset() && m.pattern() < l.pattern())
+                        }
+                        None => true,
+                    };
+                    if m.offset() > self.pos && is_longest {
+                        longest = Some(m);
+                    }
+                }
+                None => break longest,
+            }
+        }
+    }
+}
+
+impl Iterator for LongestMatches<'_> {
+    type Item = Match;
+    fn next(&mut self) -> Option<Match> {
+        while self.pos < self.haystack.len() {
+            if let Some(m) = self.longest_match() {
+                let start = self.pos;
+                self.pos = m.offset();
+                return Some(Match::new(m.pattern(), start..m.offset()));
+            }
+            // Like an unanchored search we skip the input that no terminal matches
+            self.pos += std::str::from_utf8(&self.haystack[self.pos..])
+                .ok()
+                .and_then(|s| s.chars().next())
+                .map_or(1, char::len_utf8);
+        }
+        None
+    }
+}
+
+///
+/// The kind of search is determined by the tokenizer's matcher
+///
+enum Matches<'t> {
+    LeftmostFirst(FindMatches<'static, 't, DFA<Vec<u32>>>),
+    Longest(LongestMatches<'t>),
+}
+
+impl<'t> Matches<'t> {
+    // Creates the matches of the given matcher that start at or after `pos`
+    fn new(matcher: &'static Matcher, haystack: &'t [u8], pos: usize) -> Self {
+        match matcher {
+            Matcher::LeftmostFirst(regex) => {
+                Matches::LeftmostFirst(regex.find_iter(Input::new(haystack).range(pos..)))
+            }
+            Matcher::Longest(dfa) => Matches::Longest(LongestMatches::new(dfa, haystack, pos)),
+        }
+    }
+}
+
+impl Iterator for Matches<'_> {
+    type Item = Match;
+    fn next(&mut self) -> Option<Match> {
+        match self {
+            Matches::LeftmostFirst(find_iter) => find_iter.next(),
+            Matches::Longest(longest_matches) => longest_matches.next(),
+        }
+    }
+}
+
 ///
 /// The TokenIter type provides iterator functionality for Token<'t> objects.
 /// The lifetime parameter `'t` refers to the lifetime of the scanned text.
@@ -19,7 +117,7 @@ pub struct TokenIter<'t> {
     col: u32,
 
     /// An iterator over token matches
-    find_iter: FindMatches<'static, 't, DFA<Vec<u32>>>,
+    find_iter: Matches<'t>,
 
     /// The tokenizer itself
     rx: &'static Tokenizer,
@@ -36,6 +134,13 @@ pub struct TokenIter<'t> {
     token_number: TokenNumber,
 
     last_location: Option<Location>,
+
+    /// Set if a terminal matched the empty string or if a nested block comment isn't terminated.
+    /// The iteration ends then because the scanner can't make any progress in the input.
+    error: Option<LexerError>,
+
+    /// True after an error ended the iteration
+    stalled: bool,
 }
 
 impl<'t> TokenIter<'t> {
@@ -47,16 +152,37 @@ impl<'t> TokenIter<'t> {
         Self {
             line: 1,
             col: 1,
-            find_iter: rx.rx.find_iter(input.as_bytes()),
+            find_iter: Matches::new(&rx.matcher, input.as_bytes(), 0),
             rx,
             input,
             k,
             file_name: file_name.clone(),
             token_number: 0,
             last_location: None,
+            error: None,
+            stalled: false,
         }
     }
 
+    ///
+    /// Returns the error that ended the iteration, if any.
+    ///
+    /// The iterator stops when a terminal matches the empty string because otherwise it would
+    /// produce empty tokens without making progress in the input. It also stops at a nested block
+    /// comment without matching closing delimiter.
+    ///
+    pub fn error(&self) -> Option<&LexerError> {
+        self.error.as_ref()
+    }
+
+    ///
+    /// Takes the error that ended the iteration, if any.
+    /// The iteration doesn't resume afterwards.
+    ///
+    pub fn take_error(&mut self) -> Option<LexerError> {
+        self.error.take()
+    }
+
     /// Sets the initial position of the iterator.
     pub fn with_position(mut self, line: u32, column: u32) -> Self {
         self.line = line;
@@ -87,10 +213,36 @@ impl<'t> TokenIter<'t> {
 impl<'t> Iterator for TokenIter<'t> {
     type Item = Token<'t>;
     fn next(&mut self) -> Option<Token<'t>> {
+        if self.stalled {
+            return None;
+        }
         if let Some(ref multi_match) = self.find_iter.next() {
-            let token_type = self.rx.terminal_index_of_pattern(multi_match.pattern());
+            let mut range = multi_match.range();
+            let mut token_type = self.rx.terminal_index_of_pattern(multi_match.pattern());
+            let mut unterminated = false;
+            if let Some(context) = self.rx.trailing_context(token_type) {
+                // The trailing context isn't part of the token and is scanned again
+                if let Some(end) = context.find_end(self.input, range.start, range.end) {
+                    range.end = end;
+                    self.find_iter = Matches::new(&self.rx.matcher, self.input.as_bytes(), end);
+                }
+            }
+            if token_type == BLOCK_COMMENT {
+                if let Some(comment) = self.rx.nested_block_comment(&self.input[range.clone()]) {
+                    // The comment extends to the closing delimiter of the same nesting depth
+                    match comment.find_end(self.input.as_bytes(), range.end) {
+                        Some(end) => {
+                            range.end = end;
+                            self.find_iter =
+                                Matches::new(&self.rx.matcher, self.input.as_bytes(), end);
+                        }
+                        None => unterminated = true,
+                    }
+                }
+            }
             // The token's text is taken from the match
-            let text = &self.input[multi_match.range()];
+            let text = &self.input[range.clone()];
+            token_type = self.rx.resolve_keyword(token_type, text);
             let length = text.len() as u32;
             // The token position is calculated from the matched text
             let start_line = self.line;
@@ -98,7 +250,7 @@ impl<'t> Iterator for TokenIter<'t> {
 
             // Set the inner position behind the scanned token
             let (new_lines, column_after_nl) = Self::count_nl(text);
-            let pos = multi_match.end();
+            let pos = range.end;
             self.line += new_lines;
             self.col = if new_lines > 0 {
                 column_after_nl
@@ -116,6 +268,23 @@ impl<'t> Iterator for TokenIter<'t> {
                 .file_name(self.file_name.clone())
                 .build()
             {
+                if length == 0 {
+                    trace!("Error: Token type {} matches the empty string", token_type);
+                    self.stalled = true;
+                    self.error = Some(LexerError::EmptyTokenMatch {
+                        token_type,
+                        location: Box::new(location),
+                    });
+                    return None;
+                }
+                if unterminated {
+                    trace!("Error: Unterminated block comment");
+                    self.stalled = true;
+                    self.error = Some(LexerError::UnterminatedBlockComment {
+                        location: Box::new(location),
+                    });
+                    return None;
+                }
                 self.last_location = Some(location.clone());
                 let token = Token::with(text, token_type, location, self.token_number);
                 if !token.is_skip_token() || token.is_comment_token() {
@@ -6,6 +6,7 @@ use log::trace;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
+use super::tokenizer::Offside;
 use super::ScannerConfig;
 
 ///
@@ -63,6 +64,13 @@ pub struct TokenStream<'t> {
 
     /// Scanner stack to support push and pop operations for scanner configurations
     scanner_stack: Vec<ScannerIndex>,
+
+    /// Indentation state after the last read token, if any scanner is in offside mode
+    offside: Option<OffsideState>,
+
+    /// Indentation state after the last consumed token.
+    /// It replaces the state of the read tokens when the token buffer is cleared.
+    consumed_offside: Option<OffsideState>,
 }
 
 impl<'t> TokenStream<'t> {
@@ -89,6 +97,11 @@ impl<'t> TokenStream<'t> {
         // Ensure that k is at least 1
         let k = std::cmp::max(1, k);
 
+        let offside = scanners
+            .iter()
+            .find_map(|s| s.tokenizer.offside())
+            .map(OffsideState::new);
+
         let mut token_stream = Self {
             k,
             input,
@@ -104,6 +117,8 @@ impl<'t> TokenStream<'t> {
             column: 1,
             current_scanner_index: 0,
             scanner_stack: Vec::new(),
+            offside: offside.clone(),
+            consumed_offside: offside,
         };
         token_stream.read_tokens(k)?;
         Ok(token_stream)
@@ -150,6 +165,25 @@ impl<'t> TokenStream<'t> {
         }
     }
 
+    ///
+    /// Provides lookahead beyond k for the speculative parsing of adaptively predicted
+    /// non-terminals. The token buffer grows as far as needed.
+    /// Beyond the end of the input the type of the end of input token is returned.
+    ///
+    pub(crate) fn speculative_lookahead_token_type(
+        &mut self,
+        n: usize,
+    ) -> Result<TerminalIndex, LexerError> {
+        self.ensure_buffer()?;
+        while n >= self.tokens.len() {
+            if self.read_tokens(n + 1 - self.tokens.len())? == 0 {
+                return Ok(EOI);
+            }
+        }
+        trace!("Type(LA({})): {}", n, self.tokens[n]);
+        Ok(self.tokens[n].token_type)
+    }
+
     ///
     /// Consumes one token.
     /// If necessary more input is read via the token_iter into the tokens buffer.
@@ -167,7 +201,9 @@ impl<'t> TokenStream<'t> {
             trace!("Consuming {}", &self.tokens[0]);
             self.update_position(0);
             let token = self.tokens.remove(0);
-            self.error_token_type = token.token_type;
+            if let Some(offside) = self.consumed_offside.as_mut() {
+                offside.advance(&token);
+            }
             self.ensure_buffer()?;
             Ok(token)
         }
@@ -262,7 +298,7 @@ impl<'t> TokenStream<'t> {
             self.token_iter = self.switch_to(scanner_index);
             self.current_scanner_index = scanner_index;
             if clear {
-                self.tokens.clear();
+                self.clear_tokens();
             }
             tokens_read = self.ensure_buffer()?;
         }
@@ -294,7 +330,7 @@ impl<'t> TokenStream<'t> {
             self.scanner_stack.push(self.current_scanner_index);
             self.token_iter = self.switch_to(scanner_index);
             self.current_scanner_index = scanner_index;
-            self.tokens.clear();
+            self.clear_tokens();
             self.ensure_buffer()?;
             trace!(
                 "push_scanner: Resulting scanner stack: {:?}",
@@ -324,7 +360,7 @@ impl<'t> TokenStream<'t> {
                 );
                 self.token_iter = self.switch_to(scanner_index);
                 self.current_scanner_index = scanner_index;
-                self.tokens.clear();
+                self.clear_tokens();
                 self.ensure_buffer()?;
                 trace!(
                     "pop_scanner: Resulting scanner stack: {:?}",
@@ -345,6 +381,15 @@ impl<'t> TokenStream<'t> {
         self.scanners[self.current_scanner_index].name
     }
 
+    ///
+    /// Clears the token buffer. The tokens are read again after a scanner switch, thus the
+    /// indentation state is reset to the one after the last consumed token.
+    ///
+    fn clear_tokens(&mut self) {
+        self.tokens.clear();
+        self.offside.clone_from(&self.consumed_offside);
+    }
+
     ///
     /// Reads at most n tokens from the input stream and stores them in the token buffer.
     /// It returns the number of tokens read.
@@ -359,9 +404,24 @@ impl<'t> TokenStream<'t> {
         let mut token_type = EOI;
         for mut token in &mut self.token_iter {
             if !token.is_skip_token() {
+                token.location.scanner_switch_pos = self.start_pos;
+                if let Some(offside) = self.offside.as_mut() {
+                    if self.scanners[self.current_scanner_index]
+                        .tokenizer
+                        .offside()
+                        .is_some()
+                    {
+                        for synthesized in offside.synthesize(&token)? {
+                            tokens_read += 1;
+                            trace!("Synthesized {}: {}", self.tokens.len(), synthesized);
+                            offside.advance(&synthesized);
+                            self.tokens.push(synthesized);
+                        }
+                    }
+                    offside.advance(&token);
+                }
                 tokens_read += 1;
                 trace!("Read {}: {}", self.tokens.len(), token);
-                token.location.scanner_switch_pos = self.start_pos;
                 token_type = token.token_type;
                 self.tokens.push(token);
                 new_scanner = self.scanners[self.current_scanner_index].has_transition(token_type);
@@ -376,6 +436,17 @@ impl<'t> TokenStream<'t> {
                 self.comments.push(token);
             }
         }
+        if let Some(mut err) = self.token_iter.take_error() {
+            match &mut err {
+                LexerError::EmptyTokenMatch { location, .. }
+                | LexerError::UnterminatedBlockComment { location }
+                | LexerError::InconsistentDedent { location } => {
+                    location.scanner_switch_pos = self.start_pos;
+                }
+                _ => (),
+            }
+            return Err(err);
+        }
         if let Some(scanner) = new_scanner {
             trace!("Switching to scanner {} on token {}", scanner, token_type);
             self.update_position(self.tokens.len() - 1);
@@ -491,3 +562,103 @@ impl<'t> TokenStream<'t> {
         }
     }
 }
+
+///
+/// The indentation state of a token stream whose scanners operate in offside mode, see
+/// [crate::lexer::Tokenizer::with_offside].
+///
+/// The state only depends on the sequence of tokens it is advanced with. Thus it can be
+/// reproduced for the consumed tokens when the token buffer is cleared on scanner switches.
+///
+#[derive(Debug, Clone)]
+struct OffsideState {
+    /// The synthesized terminals
+    terminals: Offside,
+    /// Columns of the open indentation levels, the outermost level starts in column 1
+    columns: Vec<u32>,
+    /// End line of the last token that was not synthesized, 0 before the first one
+    last_line: u32,
+    /// True if a token was read after the last synthesized newline
+    pending_newline: bool,
+}
+
+impl OffsideState {
+    fn new(terminals: Offside) -> Self {
+        Self {
+            terminals,
+            columns: vec![1],
+            last_line: 0,
+            pending_newline: false,
+        }
+    }
+
+    ///
+    /// Returns the tokens that have to be inserted before the given token.
+    ///
+    fn synthesize<'t>(&self, token: &Token<'t>) -> Result<Vec<Token<'t>>, LexerError> {
+        let mut terminals = Vec::new();
+        if token.token_type == EOI {
+            if self.pending_newline {
+                terminals.push(self.terminals.newline);
+            }
+            terminals.extend(std::iter::repeat_n(
+                self.terminals.dedent,
+                self.columns.len() - 1,
+            ));
+        } else if self.last_line == 0 || token.location.start_line > self.last_line {
+            // The token is the first one of its line
+            if self.pending_newline {
+                terminals.push(self.terminals.newline);
+            }
+            let column = token.location.start_column;
+            let mut levels = self.columns.len();
+            if column > self.columns[levels - 1] {
+                terminals.push(self.terminals.indent);
+            } else {
+                while column < self.columns[levels - 1] {
+                    terminals.push(self.terminals.dedent);
+                    levels -= 1;
+                }
+                if column != self.columns[levels - 1] {
+                    return Err(LexerError::InconsistentDedent {
+                        location: Box::new(token.location.clone()),
+                    });
+                }
+            }
+        }
+        // The synthesized tokens are located in front of the token, at the end of the input
+        // respectively
+        let mut location = token.location.clone();
+        if token.token_type == EOI {
+            location.start_line = location.end_line;
+            location.start_column = location.end_column;
+        } else {
+            location.offset -= location.length as usize;
+            location.end_line = location.start_line;
+            location.end_column = location.start_column;
+        }
+        location.length = 0;
+        Ok(terminals
+            .into_iter()
+            .map(|t| Token::with("", t, location.clone(), token.token_number))
+            .collect())
+    }
+
+    ///
+    /// Updates the indentation state with the given token, which is either synthesized or read.
+    ///
+    fn advance(&mut self, token: &Token<'_>) {
+        if token.token_type == self.terminals.indent {
+            self.columns.push(token.location.start_column);
+        } else if token.token_type == self.terminals.dedent {
+            if self.columns.len() > 1 {
+                self.columns.pop();
+            }
+        } else if token.token_type == self.terminals.newline {
+            self.pending_newline = false;
+        } else if token.token_type != EOI {
+            self.last_line = token.location.end_line;
+            self.pending_newline = true;
+        }
+    }
+}
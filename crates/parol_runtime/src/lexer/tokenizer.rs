@@ -1,10 +1,17 @@
 #[cfg(not(target_family = "wasm"))]
 use std::time::Instant;
 
-use crate::lexer::TerminalIndex;
+use crate::lexer::{TerminalIndex, FIRST_USER_TOKEN};
 use anyhow::{anyhow, Result};
 use log::trace;
-use regex_automata::{dfa::regex::Regex, PatternID};
+use regex_automata::{
+    dfa::{
+        dense::{self, DFA},
+        regex::Regex,
+        StartKind,
+    },
+    Anchored, Input, MatchKind, PatternID,
+};
 
 ///
 /// This is an  unmatchable regular expression.
@@ -32,18 +39,130 @@ pub const WHITESPACE_TOKEN: &str = r"[\s--\r\n]+";
 ///
 pub const ERROR_TOKEN: &str = r###"."###;
 
+///
+/// The automaton that finds the tokens in the input
+///
+#[derive(Debug)]
+pub(crate) enum Matcher {
+    /// The first pattern that matches wins, i.e. the patterns' order decides
+    LeftmostFirst(Box<Regex>),
+    /// The longest match wins, ties are broken by the patterns' order.
+    /// The DFA reports all matches of anchored searches.
+    Longest(Box<DFA<Vec<u32>>>),
+}
+
+///
+/// A block comment that can be nested.
+/// After its opening delimiter was matched the scanner counts the nesting depth until the
+/// matching closing delimiter is found.
+///
+#[derive(Debug)]
+pub(crate) struct NestedBlockComment {
+    /// Matches the whole text of the opening delimiter
+    start: Regex,
+    /// Finds the next opening (pattern 0) or closing (pattern 1) delimiter
+    delimiters: Regex,
+}
+
+impl NestedBlockComment {
+    ///
+    /// Returns the end of the comment whose opening delimiter ends at `pos`.
+    /// Returns None if the comment isn't terminated.
+    ///
+    pub(crate) fn find_end(&self, haystack: &[u8], pos: usize) -> Option<usize> {
+        let mut depth = 1usize;
+        let mut pos = pos;
+        while depth > 0 {
+            let m = self.delimiters.find(Input::new(haystack).range(pos..))?;
+            if m.is_empty() {
+                // The delimiters can't make any progress in the input
+                return None;
+            }
+            if m.pattern().as_usize() == 0 {
+                depth += 1;
+            } else {
+                depth -= 1;
+            }
+            pos = m.end();
+        }
+        Some(pos)
+    }
+}
+
+///
+/// A terminal that only matches if it is followed by its trailing context.
+/// The scanner matches the terminal together with its trailing context and cuts the trailing
+/// context off afterwards.
+///
+#[derive(Debug)]
+pub(crate) struct TrailingContext {
+    /// Matches the whole text of the terminal without its trailing context
+    terminal: Regex,
+    /// Matches the trailing context in anchored searches
+    context: Regex,
+}
+
+impl TrailingContext {
+    ///
+    /// Returns the end of the terminal within the match `start..end` of the terminal together
+    /// with its trailing context.
+    /// If the text can be split in several ways the longest terminal wins.
+    ///
+    pub(crate) fn find_end(&self, haystack: &str, start: usize, end: usize) -> Option<usize> {
+        (start + 1..end).rev().find(|pos| {
+            haystack.is_char_boundary(*pos)
+                && self.terminal.is_match(&haystack.as_bytes()[start..*pos])
+                && self
+                    .context
+                    .find(Input::new(haystack).range(*pos..).anchored(Anchored::Yes))
+                    .is_some()
+        })
+    }
+}
+
+///
+/// The terminals that the token stream synthesizes from the indentation of the lines when the
+/// tokenizer operates in offside mode, see [Tokenizer::with_offside].
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Offside {
+    /// Opens a deeper indentation level
+    pub(crate) indent: TerminalIndex,
+    /// Closes an indentation level
+    pub(crate) dedent: TerminalIndex,
+    /// Ends a logical line
+    pub(crate) newline: TerminalIndex,
+}
+
 ///
 /// The Tokenizer creates a specially formatted regular expression that can be
 /// used for tokenizing an input string.
 ///
 #[derive(Debug)]
 pub struct Tokenizer {
-    pub(crate) rx: Regex,
+    pub(crate) matcher: Matcher,
 
     /// This vector provides the mapping of
     /// scanned PatternID (index in vec) to TerminalIndex (content at index)
     token_types: Vec<TerminalIndex>,
 
+    /// The automaton that matches the keywords against whole lexemes, see
+    /// [Tokenizer::with_keywords]
+    keywords: Option<Box<Regex>>,
+
+    /// This vector provides the mapping of
+    /// keyword PatternID (index in vec) to TerminalIndex (content at index)
+    keyword_types: Vec<TerminalIndex>,
+
+    /// The block comments that can be nested, see [Tokenizer::with_nested_block_comments]
+    nested_block_comments: Vec<NestedBlockComment>,
+
+    /// The terminals with trailing context, see [Tokenizer::with_trailing_contexts]
+    trailing_contexts: Vec<(TerminalIndex, TrailingContext)>,
+
+    /// The terminals synthesized in offside mode, see [Tokenizer::with_offside]
+    offside: Option<Offside>,
+
     ///
     /// This is the token index for the special error token.
     /// Its value isn't constant and depends on the given token count.
@@ -80,6 +199,43 @@ impl Tokenizer {
         augmented_terminals: &[&str],
         scanner_specifics: &[&str],
         scanner_terminal_indices: &[TerminalIndex],
+    ) -> Result<Self> {
+        Self::build_with_match_kind(
+            augmented_terminals,
+            scanner_specifics,
+            scanner_terminal_indices,
+            MatchKind::LeftmostFirst,
+        )
+    }
+
+    ///
+    /// Creates a new Tokenizer object that obeys the _longest match_ strategy.
+    /// From all terminals that match at the current position the one with the longest match is
+    /// taken. If several terminals match the same length the first one wins.
+    ///
+    /// The arguments are the same as in [Tokenizer::build].
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the regex patterns can't be compiled.
+    pub fn build_longest_match(
+        augmented_terminals: &[&str],
+        scanner_specifics: &[&str],
+        scanner_terminal_indices: &[TerminalIndex],
+    ) -> Result<Self> {
+        Self::build_with_match_kind(
+            augmented_terminals,
+            scanner_specifics,
+            scanner_terminal_indices,
+            MatchKind::All,
+        )
+    }
+
+    fn build_with_match_kind(
+        augmented_terminals: &[&str],
+        scanner_specifics: &[&str],
+        scanner_terminal_indices: &[TerminalIndex],
+        match_kind: MatchKind,
     ) -> Result<Self> {
         debug_assert_eq!(5, scanner_specifics.len());
         let mut token_types = Vec::with_capacity(augmented_terminals.len());
@@ -120,18 +276,192 @@ impl Tokenizer {
         trace!("Generating regex for scanner:\n{:?}...", patterns);
         #[cfg(not(target_family = "wasm"))]
         let now = Instant::now();
-        let rx = Regex::builder()
-            .build_many(&patterns)
-            .map_err(|e| anyhow!(e))?;
+        let matcher = if match_kind == MatchKind::All {
+            Matcher::Longest(Box::new(
+                dense::Builder::new()
+                    .configure(
+                        DFA::config()
+                            .match_kind(MatchKind::All)
+                            .start_kind(StartKind::Anchored),
+                    )
+                    .build_many(&patterns)
+                    .map_err(|e| anyhow!(e))?,
+            ))
+        } else {
+            Matcher::LeftmostFirst(Box::new(
+                Regex::builder()
+                    .build_many(&patterns)
+                    .map_err(|e| anyhow!(e))?,
+            ))
+        };
         #[cfg(not(target_family = "wasm"))]
         trace!("took {} milliseconds.", now.elapsed().as_millis());
         Ok(Self {
-            rx,
+            matcher,
             token_types,
+            keywords: None,
+            keyword_types: Vec::new(),
+            nested_block_comments: Vec::new(),
+            trailing_contexts: Vec::new(),
+            offside: None,
             error_token_type,
         })
     }
 
+    ///
+    /// Reserves the given keywords against all other terminals of the tokenizer.
+    /// If a keyword matches the whole lexeme of another terminal, for instance of an identifier,
+    /// the token is reported as the keyword instead. Keywords that match only a part of the
+    /// lexeme don't affect the token.
+    ///
+    /// The keywords should be the last ones in `scanner_terminal_indices` when the tokenizer is
+    /// built. Then they only match on their own if no other terminal matches.
+    ///
+    /// # Arguments
+    ///
+    /// ## augmented_terminals
+    /// All valid terminals of the grammar, the same as in [Tokenizer::build].
+    ///
+    /// ## keywords
+    /// The indices of the keyword terminals. If several keywords match a lexeme the first one wins.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the regex patterns can't be compiled.
+    pub fn with_keywords(
+        mut self,
+        augmented_terminals: &[&str],
+        keywords: &[TerminalIndex],
+    ) -> Result<Self> {
+        // The keyword has to match the whole lexeme
+        let patterns = keywords
+            .iter()
+            .map(|k| format!(r"\A(?:{})\z", augmented_terminals[*k as usize]))
+            .collect::<Vec<_>>();
+        trace!("Generating regex for keywords:\n{:?}...", patterns);
+        self.keywords = Some(Box::new(
+            Regex::builder()
+                .build_many(&patterns)
+                .map_err(|e| anyhow!(e))?,
+        ));
+        self.keyword_types = keywords.to_vec();
+        Ok(self)
+    }
+
+    ///
+    /// Adds block comments that can be nested.
+    /// When the `BLOCK_COMMENT` pattern of the scanner specifics matches exactly the opening
+    /// delimiter of such a comment, the comment extends to the closing delimiter that matches the
+    /// nesting depth. The whole comment is then returned as a single `BLOCK_COMMENT` token.
+    ///
+    /// Thus the opening delimiters must be contained in the `BLOCK_COMMENT` pattern given to
+    /// [Tokenizer::build], e.g. `/\*`.
+    ///
+    /// # Arguments
+    ///
+    /// ## nested_block_comments
+    /// Tuples of the regular expressions of the opening and closing delimiters.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the regex patterns can't be compiled.
+    pub fn with_nested_block_comments(
+        mut self,
+        nested_block_comments: &[(&str, &str)],
+    ) -> Result<Self> {
+        self.nested_block_comments = nested_block_comments
+            .iter()
+            .map(|(start, end)| {
+                trace!("Generating regex for nested block comment {start} {end}...");
+                Ok(NestedBlockComment {
+                    start: Regex::new(&format!(r"\A(?:{})\z", start)).map_err(|e| anyhow!(e))?,
+                    delimiters: Regex::builder()
+                        .build_many(&[start, end])
+                        .map_err(|e| anyhow!(e))?,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(self)
+    }
+
+    ///
+    /// Adds terminals that only match if they are followed by a trailing context which isn't
+    /// part of the token.
+    ///
+    /// The pattern of such a terminal in the `augmented_terminals` given to [Tokenizer::build]
+    /// must match the terminal together with its trailing context, e.g. `(?:[0-9]+)(?:\.\.)`.
+    /// After a match the scanner cuts the trailing context off and continues scanning behind
+    /// the terminal.
+    ///
+    /// # Arguments
+    ///
+    /// ## trailing_contexts
+    /// Tuples of the terminal index and the regular expressions of the terminal and of its
+    /// trailing context.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the regex patterns can't be compiled.
+    pub fn with_trailing_contexts(
+        mut self,
+        trailing_contexts: &[(TerminalIndex, &str, &str)],
+    ) -> Result<Self> {
+        self.trailing_contexts = trailing_contexts
+            .iter()
+            .map(|(terminal_index, terminal, context)| {
+                trace!("Generating regex for trailing context {terminal} {context}...");
+                Ok((
+                    *terminal_index,
+                    TrailingContext {
+                        terminal: Regex::new(&format!(r"\A(?:{})\z", terminal))
+                            .map_err(|e| anyhow!(e))?,
+                        context: Regex::builder()
+                            .dense(dense::Config::new().start_kind(StartKind::Anchored))
+                            .build(context)
+                            .map_err(|e| anyhow!(e))?,
+                    },
+                ))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(self)
+    }
+
+    ///
+    /// Switches the tokenizer to offside mode in which the indentation of the lines is
+    /// significant.
+    ///
+    /// The [crate::lexer::TokenStream] then synthesizes tokens without text from the leading
+    /// whitespace of the lines, using a stack of the columns of the open indentation levels:
+    /// * A `newline` token precedes the first token of each line except the first one,
+    /// * an `indent` token follows if the line starts in a deeper column than the current
+    ///   indentation level,
+    /// * a `dedent` token follows for each indentation level the line leaves.
+    ///
+    /// At the end of the input a `newline` token and the `dedent` tokens of all open indentation
+    /// levels are synthesized. Lines that contain only whitespace or comments are ignored.
+    ///
+    /// The synthesized terminals should not be contained in `scanner_terminal_indices` when the
+    /// tokenizer is built. All tokenizers in offside mode that are used by the same token stream
+    /// share its indentation levels and must therefore use the same terminals.
+    ///
+    /// # Arguments
+    ///
+    /// ## indent, dedent, newline
+    /// The terminal indices of the synthesized tokens.
+    pub fn with_offside(
+        mut self,
+        indent: TerminalIndex,
+        dedent: TerminalIndex,
+        newline: TerminalIndex,
+    ) -> Self {
+        self.offside = Some(Offside {
+            indent,
+            dedent,
+            newline,
+        });
+        self
+    }
+
     /// Decode the pattern index to a terminal index.
     ///
     /// # Panics
@@ -141,4 +471,50 @@ impl Tokenizer {
     pub(crate) fn terminal_index_of_pattern(&self, pattern_id: PatternID) -> TerminalIndex {
         self.token_types[pattern_id]
     }
+
+    /// Returns the nested block comment whose opening delimiter matches the given text, if any.
+    pub(crate) fn nested_block_comment(&self, text: &str) -> Option<&NestedBlockComment> {
+        self.nested_block_comments
+            .iter()
+            .find(|c| c.start.is_match(text.as_bytes()))
+    }
+
+    /// Returns the trailing context of the terminal with the given index, if any.
+    pub(crate) fn trailing_context(
+        &self,
+        terminal_index: TerminalIndex,
+    ) -> Option<&TrailingContext> {
+        self.trailing_contexts
+            .iter()
+            .find_map(|(t, c)| (*t == terminal_index).then_some(c))
+    }
+
+    /// Returns the terminals synthesized in offside mode, if the tokenizer is in offside mode.
+    #[inline]
+    pub(crate) fn offside(&self) -> Option<Offside> {
+        self.offside
+    }
+
+    /// Replaces the terminal index of a lexeme that is entirely matched by a keyword with the
+    /// keyword's terminal index.
+    /// The internal terminals like whitespace or comments, the error token and the keywords
+    /// themselves are never replaced.
+    pub(crate) fn resolve_keyword(
+        &self,
+        terminal_index: TerminalIndex,
+        text: &str,
+    ) -> TerminalIndex {
+        match &self.keywords {
+            Some(keywords)
+                if terminal_index >= FIRST_USER_TOKEN
+                    && terminal_index != self.error_token_type
+                    && !self.keyword_types.contains(&terminal_index) =>
+            {
+                keywords
+                    .find(text.as_bytes())
+                    .map_or(terminal_index, |m| self.keyword_types[m.pattern()])
+            }
+            _ => terminal_index,
+        }
+    }
 }
@@ -23,7 +23,7 @@ pub use lexer::{
 };
 
 pub mod parser_common;
-pub use parser_common::ParseTreeStack;
+pub use parser_common::{NonEmptyVec, ParseTreeStack};
 
 ///
 /// Module that provides types for syntactical analysis.
@@ -38,7 +38,9 @@ pub use parser::{
 /// Module that provides types for the LR parser.
 ///
 pub mod lr_parser;
-pub use lr_parser::{LR1State, LRAction, LRParseTable, LRParseTree, LRParser, LRProduction};
+pub use lr_parser::{
+    GLRParser, LR1State, LRAction, LRParseTable, LRParseTree, LRParser, LRProduction,
+};
 
 ///
 /// Module with error types reported from this crate.
@@ -0,0 +1,638 @@
+//! GLR parser for ambiguous grammars.
+//! The GLR parser uses the LR parse table of the grammar, but a state of the table can contain
+//! more than one action for a terminal. All actions are followed in parallel on a graph-structured
+//! stack. The derivations found are collected in a shared packed parse forest. Afterwards the
+//! semantic actions are called for one parse tree of this forest. The user chooses this tree with
+//! the `on_ambiguity` hook of the `UserActionsTrait`.
+//! The GLR parser doesn't recover from syntax errors.
+
+use std::{
+    collections::{BTreeMap, BTreeSet, VecDeque},
+    convert::TryInto,
+};
+
+use log::trace;
+
+use crate::{
+    parser::parser_types::TreeBuilder, FileSource, LRAction, LRParseTable, LRParseTree,
+    LRProduction, NonTerminalIndex, ParolError, ParseTree, ParseTreeType, ParserError,
+    ProductionIndex, Result, SyntaxError, TerminalIndex, Token, TokenStream, TokenVec,
+    UnexpectedToken, UserActionsTrait,
+};
+
+/// One derivation of a non-terminal node in the parse forest.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ForestFamily {
+    /// The production applied
+    pub production: ProductionIndex,
+    /// The indices of the forest nodes of the production's right-hand side symbols
+    pub children: Vec<usize>,
+}
+
+/// A node of the shared packed parse forest.
+#[derive(Debug, Clone)]
+pub enum ForestNode<'t> {
+    /// A shifted token
+    Terminal(Token<'t>),
+    /// A non-terminal that derives a certain range of tokens
+    NonTerminal {
+        /// The non-terminal
+        non_terminal: NonTerminalIndex,
+        /// The derivations of the tokens, more than one if the input is ambiguous here
+        families: Vec<ForestFamily>,
+    },
+}
+
+/// The shared packed parse forest that contains all parse trees of the input.
+/// Sub-trees that are equal in different parse trees are shared.
+#[derive(Debug, Clone, Default)]
+pub struct ParseForest<'t> {
+    /// The nodes of the forest
+    pub nodes: Vec<ForestNode<'t>>,
+    /// The index of the root node
+    pub root: usize,
+}
+
+impl<'t> ParseForest<'t> {
+    /// Returns true if the forest contains more than one parse tree.
+    pub fn is_ambiguous(&self) -> bool {
+        self.nodes.iter().any(|n| match n {
+            ForestNode::Terminal(_) => false,
+            ForestNode::NonTerminal { families, .. } => families.len() > 1,
+        })
+    }
+
+    fn add_node(&mut self, node: ForestNode<'t>) -> usize {
+        self.nodes.push(node);
+        self.nodes.len() - 1
+    }
+}
+
+/// One of the alternatives of an ambiguity.
+#[derive(Debug, Clone)]
+pub struct GLRAlternative<'t> {
+    /// The production applied
+    pub production: ProductionIndex,
+    /// The right-hand side symbols, i.e. tokens and non-terminal names
+    pub children: Vec<ParseTreeType<'t>>,
+}
+
+/// A part of the input that can be derived from a non-terminal in more than one way.
+#[derive(Debug, Clone)]
+pub struct Ambiguity<'t> {
+    /// The name of the non-terminal
+    pub non_terminal: &'static str,
+    /// The alternative derivations
+    pub alternatives: Vec<GLRAlternative<'t>>,
+}
+
+/// A node of the graph-structured stack.
+#[derive(Debug)]
+struct StackNode {
+    /// The state of the parse table
+    state: usize,
+    /// The number of tokens shifted before this node was created
+    position: usize,
+    /// The nodes below this node together with the forest node of the symbol in between
+    edges: Vec<(usize, usize)>,
+}
+
+/// A reduction to process on the graph-structured stack
+#[derive(Debug, Clone, Copy)]
+struct Reduction {
+    node: usize,
+    non_terminal: NonTerminalIndex,
+    production: ProductionIndex,
+    /// If set, only paths that contain this edge are reduced
+    edge: Option<(usize, usize)>,
+}
+
+///
+/// The GLR parser.
+/// It follows all actions of an LR parse table with conflicts.
+/// All data of the generated parser are provided in the 'new' function.
+///
+/// The lifetime parameter `'t` refers to the lifetime of the scanned text.
+///
+#[derive(Debug)]
+pub struct GLRParser<'t> {
+    ///
+    /// The non-terminal index of the start symbol
+    ///
+    start_symbol_index: NonTerminalIndex,
+
+    /// The parse table.
+    pub parse_table: &'static LRParseTable,
+
+    ///
+    /// The array of generated grammar productions.
+    ///
+    productions: &'static [LRProduction],
+
+    ///
+    /// Array of generated terminal names.
+    ///
+    terminal_names: &'static [&'static str],
+
+    ///
+    /// Array of generated non-terminal names.
+    ///
+    non_terminal_names: &'static [&'static str],
+
+    ///
+    /// Names of the terminals used in syntax errors.
+    /// They default to the terminal names and can be set with `set_terminal_display_names`.
+    ///
+    terminal_display_names: &'static [&'static str],
+
+    /// Enables trimming of the parse tree.
+    ///
+    /// Default is `false`.
+    trim_parse_tree: bool,
+
+    /// The graph-structured stack
+    stack_nodes: Vec<StackNode>,
+
+    /// The parse forest built during parsing
+    forest: ParseForest<'t>,
+}
+
+impl<'t> GLRParser<'t> {
+    ///
+    /// Creates a new GLR parser.
+    ///
+    pub fn new(
+        start_symbol_index: NonTerminalIndex,
+        parse_table: &'static LRParseTable,
+        productions: &'static [LRProduction],
+        terminal_names: &'static [&'static str],
+        non_terminal_names: &'static [&'static str],
+    ) -> Self {
+        GLRParser {
+            start_symbol_index,
+            parse_table,
+            productions,
+            terminal_names,
+            non_terminal_names,
+            terminal_display_names: terminal_names,
+            trim_parse_tree: false,
+            stack_nodes: Vec::new(),
+            forest: ParseForest::default(),
+        }
+    }
+
+    ///
+    /// Returns an empty parse tree instead of the chosen one.
+    /// Useful when enabling production mode and the whole parse tree is not needed.
+    ///
+    pub fn trim_parse_tree(&mut self) {
+        self.trim_parse_tree = true;
+    }
+
+    ///
+    /// Sets the names of the terminals that are shown in syntax errors instead of the generated
+    /// terminal names.
+    ///
+    /// The array must be parallel to the terminal names, i.e. it is indexed by terminal index.
+    ///
+    pub fn set_terminal_display_names(&mut self, terminal_display_names: &'static [&'static str]) {
+        self.terminal_display_names = terminal_display_names;
+    }
+
+    ///
+    /// Parses the input text into a parse forest without calling semantic actions.
+    ///
+    pub fn parse_forest(&mut self, stream: TokenStream<'t>) -> Result<ParseForest<'t>> {
+        self.parse_forest_with(stream, &mut |_| ())
+    }
+
+    ///
+    /// Parses the input text.
+    /// The semantic actions are called for the parse tree that is chosen by the user's
+    /// `on_ambiguity` hook.
+    ///
+    pub fn parse<'u>(
+        &mut self,
+        stream: TokenStream<'t>,
+        user_actions: &'u mut dyn UserActionsTrait<'t>,
+    ) -> Result<ParseTree<'t>> {
+        let forest = self.parse_forest_with(stream, &mut |c| user_actions.on_comment_parsed(c))?;
+        trace!("Parse forest: {:?}", forest);
+        let parse_tree = self.replay(&forest, forest.root, user_actions, &mut vec![])?;
+        let parse_tree = if self.trim_parse_tree {
+            // Return an empty parse tree
+            TreeBuilder::new().build()
+        } else {
+            parse_tree.try_into()
+        };
+        Ok(parse_tree.map_err(|source| ParserError::TreeError { source })?)
+    }
+
+    fn parse_forest_with(
+        &mut self,
+        mut stream: TokenStream<'t>,
+        on_comment: &mut dyn FnMut(Token<'t>),
+    ) -> Result<ParseForest<'t>> {
+        self.stack_nodes = vec![StackNode {
+            state: 0,
+            position: 0,
+            edges: Vec::new(),
+        }];
+        self.forest = ParseForest::default();
+        // The stack nodes of the current position by state
+        let mut frontier = BTreeMap::from([(0, 0)]);
+        let mut position = 0;
+        loop {
+            stream
+                .drain_comments()
+                .into_iter()
+                .for_each(&mut *on_comment);
+            let terminal_index = stream.lookahead_token_type(0)?;
+            trace!(
+                "Position {}: {} stacks, token type: {} ({})",
+                position,
+                frontier.len(),
+                terminal_index,
+                self.terminal_names[terminal_index as usize]
+            );
+            if terminal_index == stream.error_token_type() {
+                // A character the scanner can't match is never the error token of the grammar
+                return Err(self.syntax_error(&mut stream, &frontier, terminal_index)?);
+            }
+            self.reduce(&mut frontier, terminal_index, position)?;
+
+            if let Some(root) = self.accept(&frontier, terminal_index, position)? {
+                let mut forest = std::mem::take(&mut self.forest);
+                forest.root = root;
+                return Ok(forest);
+            }
+
+            let shifts = frontier
+                .values()
+                .flat_map(|n| {
+                    self.parse_table
+                        .actions(self.stack_nodes[*n].state, terminal_index)
+                        .filter_map(move |a| match a {
+                            LRAction::Shift(s) => Some((*s, *n)),
+                            _ => None,
+                        })
+                })
+                .collect::<Vec<_>>();
+            if shifts.is_empty() {
+                return Err(self.syntax_error(&mut stream, &frontier, terminal_index)?);
+            }
+            let token = stream.consume()?;
+            trace!("Shift token {} on {} stacks", token, shifts.len());
+            let terminal = self.forest.add_node(ForestNode::Terminal(token));
+            position += 1;
+            let mut next_frontier = BTreeMap::new();
+            for (state, node) in shifts {
+                let next_node = self.stack_node(&mut next_frontier, state, position);
+                self.stack_nodes[next_node].edges.push((node, terminal));
+            }
+            frontier = next_frontier;
+        }
+    }
+
+    // Returns the stack node of the given state in the frontier and creates it if necessary
+    fn stack_node(
+        &mut self,
+        frontier: &mut BTreeMap<usize, usize>,
+        state: usize,
+        position: usize,
+    ) -> usize {
+        let stack_nodes = &mut self.stack_nodes;
+        *frontier.entry(state).or_insert_with(|| {
+            stack_nodes.push(StackNode {
+                state,
+                position,
+                edges: Vec::new(),
+            });
+            stack_nodes.len() - 1
+        })
+    }
+
+    // The reductions of the given stack node on the given terminal
+    fn reductions(
+        &self,
+        node: usize,
+        terminal_index: TerminalIndex,
+        edge: Option<(usize, usize)>,
+    ) -> Vec<Reduction> {
+        self.parse_table
+            .actions(self.stack_nodes[node].state, terminal_index)
+            .filter_map(|a| match a {
+                LRAction::Reduce(non_terminal, production) => Some(Reduction {
+                    node,
+                    non_terminal: *non_terminal,
+                    production: *production,
+                    edge,
+                }),
+                _ => None,
+            })
+            .collect()
+    }
+
+    // Applies all reductions on the frontier until no new stack nodes or edges are added.
+    // If an edge is added to an existing stack node the reductions of all stack nodes of the
+    // frontier are repeated for the paths through the new edge.
+    fn reduce(
+        &mut self,
+        frontier: &mut BTreeMap<usize, usize>,
+        terminal_index: TerminalIndex,
+        position: usize,
+    ) -> Result<()> {
+        // The non-terminal forest nodes that end at the current position by their start position
+        let mut symbol_nodes = BTreeMap::<(NonTerminalIndex, usize), usize>::new();
+        let mut pending = frontier
+            .values()
+            .flat_map(|n| self.reductions(*n, terminal_index, None))
+            .collect::<VecDeque<_>>();
+        while let Some(reduction) = pending.pop_front() {
+            let len = self.productions[reduction.production].len;
+            for (bottom, children) in self.paths(reduction.node, len, reduction.edge, position) {
+                let start = self.stack_nodes[bottom].position;
+                let non_terminal = reduction.non_terminal;
+                let forest = &mut self.forest;
+                let symbol = *symbol_nodes
+                    .entry((non_terminal, start))
+                    .or_insert_with(|| {
+                        forest.add_node(ForestNode::NonTerminal {
+                            non_terminal,
+                            families: Vec::new(),
+                        })
+                    });
+                if let ForestNode::NonTerminal { families, .. } = &mut self.forest.nodes[symbol] {
+                    let family = ForestFamily {
+                        production: reduction.production,
+                        children,
+                    };
+                    if !families.contains(&family) {
+                        families.push(family);
+                    }
+                }
+
+                let bottom_state = self.stack_nodes[bottom].state;
+                let goto = self
+                    .parse_table
+                    .goto(bottom_state, non_terminal)
+                    .ok_or_else(|| {
+                        ParserError::InternalError(format!(
+                            "No goto for non-terminal '{}' in state {}",
+                            non_terminal, bottom_state
+                        ))
+                    })?;
+                match frontier.get(&goto) {
+                    Some(node) => {
+                        let node = *node;
+                        if self.stack_nodes[node]
+                            .edges
+                            .iter()
+                            .any(|(n, _)| *n == bottom)
+                        {
+                            continue;
+                        }
+                        self.stack_nodes[node].edges.push((bottom, symbol));
+                        for n in frontier.values() {
+                            pending.extend(
+                                self.reductions(*n, terminal_index, Some((node, bottom)))
+                                    .into_iter()
+                                    .filter(|r| self.productions[r.production].len > 0),
+                            );
+                        }
+                    }
+                    None => {
+                        let node = self.stack_node(frontier, goto, position);
+                        self.stack_nodes[node].edges.push((bottom, symbol));
+                        pending.extend(self.reductions(node, terminal_index, None));
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    // The paths of the given length down from the given stack node.
+    // Returns the bottom node and the forest nodes along the path from left to right.
+    // If an edge is given only the paths through this edge are returned. The upper node of such an
+    // edge belongs to the current position, so the walk doesn't descend to nodes of earlier
+    // positions before the edge is passed.
+    fn paths(
+        &self,
+        node: usize,
+        len: usize,
+        edge: Option<(usize, usize)>,
+        position: usize,
+    ) -> Vec<(usize, Vec<usize>)> {
+        let mut paths = Vec::new();
+        self.walk(node, len, edge, position, &mut Vec::new(), &mut paths);
+        paths
+    }
+
+    // Walks down the shared stack and collects the paths into the given vector.
+    // The forest nodes of the current path are kept from right to left in `symbols`.
+    fn walk(
+        &self,
+        node: usize,
+        len: usize,
+        edge: Option<(usize, usize)>,
+        position: usize,
+        symbols: &mut Vec<usize>,
+        paths: &mut Vec<(usize, Vec<usize>)>,
+    ) {
+        if len == 0 {
+            if edge.is_none() {
+                paths.push((node, symbols.iter().rev().cloned().collect()));
+            }
+            return;
+        }
+        for (below, symbol) in &self.stack_nodes[node].edges {
+            let edge = match edge {
+                Some(e) if e == (node, *below) => None,
+                Some(_) if self.stack_nodes[*below].position < position => continue,
+                edge => edge,
+            };
+            symbols.push(*symbol);
+            self.walk(*below, len - 1, edge, position, symbols, paths);
+            symbols.pop();
+        }
+    }
+
+    // Returns the root of the parse forest if one of the stacks accepts the input.
+    // The production of the start symbol is reduced over all paths down to the bottom of the
+    // stack, so the root contains a family for each of them.
+    fn accept(
+        &mut self,
+        frontier: &BTreeMap<usize, usize>,
+        terminal_index: TerminalIndex,
+        position: usize,
+    ) -> Result<Option<usize>> {
+        // Find the production number of the start symbol
+        let production = self
+            .productions
+            .iter()
+            .position(|p| p.lhs == self.start_symbol_index)
+            .ok_or_else(|| {
+                ParserError::InternalError(format!(
+                    "No production found for start symbol '{}'",
+                    self.non_terminal_names[self.start_symbol_index]
+                ))
+            })?;
+        let len = self.productions[production].len;
+        let families = frontier
+            .values()
+            .filter(|n| {
+                self.parse_table
+                    .actions(self.stack_nodes[**n].state, terminal_index)
+                    .any(|a| *a == LRAction::Accept)
+            })
+            .flat_map(|n| self.paths(*n, len, None, position))
+            .filter(|(bottom, _)| *bottom == 0)
+            .map(|(_, children)| ForestFamily {
+                production,
+                children,
+            })
+            .collect::<Vec<_>>();
+        if families.is_empty() {
+            return Ok(None);
+        }
+        trace!("Accept");
+        Ok(Some(self.forest.add_node(ForestNode::NonTerminal {
+            non_terminal: self.start_symbol_index,
+            families,
+        })))
+    }
+
+    // Calls the semantic actions for the parse tree below the given forest node and returns it.
+    // The ancestors are used to skip the cyclic derivations of infinitely ambiguous grammars.
+    fn replay<'u>(
+        &self,
+        forest: &ParseForest<'t>,
+        node: usize,
+        user_actions: &'u mut dyn UserActionsTrait<'t>,
+        ancestors: &mut Vec<usize>,
+    ) -> Result<LRParseTree<'t>> {
+        let (non_terminal, families) = match &forest.nodes[node] {
+            ForestNode::Terminal(token) => return Ok(LRParseTree::Terminal(token.clone())),
+            ForestNode::NonTerminal {
+                non_terminal,
+                families,
+            } => (*non_terminal, families),
+        };
+        let name = self.non_terminal_names[non_terminal];
+        let families = families
+            .iter()
+            .filter(|f| !f.children.iter().any(|c| ancestors.contains(c)))
+            .collect::<Vec<_>>();
+        let family = match families.len() {
+            0 => {
+                return Err(ParserError::InternalError(format!(
+                    "No acyclic derivation for non-terminal '{}'",
+                    name
+                ))
+                .into())
+            }
+            1 => families[0],
+            count => {
+                let ambiguity = Ambiguity {
+                    non_terminal: name,
+                    alternatives: families
+                        .iter()
+                        .map(|f| GLRAlternative {
+                            production: f.production,
+                            children: f
+                                .children
+                                .iter()
+                                .map(|c| match &forest.nodes[*c] {
+                                    ForestNode::Terminal(token) => ParseTreeType::T(token.clone()),
+                                    ForestNode::NonTerminal { non_terminal, .. } => {
+                                        ParseTreeType::N(self.non_terminal_names[*non_terminal])
+                                    }
+                                })
+                                .collect(),
+                        })
+                        .collect(),
+                };
+                let alternative = user_actions.on_ambiguity(&ambiguity);
+                trace!(
+                    "Alternative {} of {} chosen for '{}'",
+                    alternative,
+                    count,
+                    name
+                );
+                families.get(alternative).copied().ok_or_else(|| {
+                    ParserError::InternalError(format!(
+                        "Alternative {} chosen for non-terminal '{}' doesn't exist, there are {}",
+                        alternative, name, count
+                    ))
+                })?
+            }
+        };
+
+        ancestors.push(node);
+        let children = family
+            .children
+            .iter()
+            .map(|c| self.replay(forest, *c, user_actions, ancestors))
+            .collect::<Result<Vec<_>>>()?;
+        ancestors.pop();
+
+        // Prepare the arguments for the user's semantic action
+        let arguments = children
+            .iter()
+            .map(|pt| pt.into())
+            .collect::<Vec<ParseTreeType<'t>>>();
+        trace!("Call semantic action for production {}", family.production);
+        user_actions.call_semantic_action_for_production_number(family.production, &arguments)?;
+        Ok(LRParseTree::NonTerminal(
+            name,
+            if self.trim_parse_tree {
+                None
+            } else {
+                Some(children)
+            },
+        ))
+    }
+
+    fn syntax_error(
+        &self,
+        stream: &mut TokenStream<'t>,
+        frontier: &BTreeMap<usize, usize>,
+        terminal_index: TerminalIndex,
+    ) -> Result<ParolError> {
+        let token = stream.lookahead(0)?;
+        trace!("No stack can shift token '{}'", token);
+        let error_token_type = stream.error_token_type();
+        let expected_tokens = frontier
+            .keys()
+            .flat_map(|s| self.parse_table.viable_terminal_indices(*s))
+            // The error token is never expected in the input
+            .filter(|t| *t != error_token_type)
+            .collect::<BTreeSet<_>>()
+            .iter()
+            .fold(TokenVec::new(), |mut acc, t| {
+                acc.push(self.terminal_display_names[*t as usize].to_owned());
+                acc
+            });
+        Ok(ParserError::SyntaxErrors {
+            entries: vec![SyntaxError {
+                cause: format!(
+                    "No action for token '{}' in states {:?}\nCurrent scanner is '{}'",
+                    self.terminal_display_names[terminal_index as usize],
+                    frontier.keys().collect::<Vec<_>>(),
+                    stream.current_scanner()
+                ),
+                input: Some(Box::new(FileSource::from_stream(stream))),
+                error_location: Box::new((&token).into()),
+                unexpected_tokens: vec![UnexpectedToken::new(
+                    "LA(1)".to_owned(),
+                    self.terminal_display_names[terminal_index as usize].to_owned(),
+                    &token,
+                )],
+                expected_tokens,
+                source: None,
+            }],
+        }
+        .into())
+    }
+}
@@ -4,3 +4,7 @@ pub use parser_types::{LR1State, LRAction, LRParseTable, LRParser, LRProduction}
 
 pub mod parse_tree;
 pub use parse_tree::LRParseTree;
+
+#[forbid(missing_docs)]
+pub mod glr_parser;
+pub use glr_parser::{Ambiguity, ForestFamily, ForestNode, GLRAlternative, GLRParser, ParseForest};
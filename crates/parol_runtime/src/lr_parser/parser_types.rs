@@ -10,14 +10,21 @@ use std::{cell::RefCell, collections::BTreeSet, convert::TryInto, rc::Rc};
 use log::trace;
 
 use crate::{
-    parser::parser_types::TreeBuilder, FileSource, LRParseTree, NonTerminalIndex, ParolError,
-    ParseTree, ParseTreeStack, ParseTreeType, ParserError, ProductionIndex, Result, SyntaxError,
-    TerminalIndex, TokenStream, TokenVec, UnexpectedToken, UserActionsTrait,
+    lexer::EOI, parser::parser_types::TreeBuilder, FileSource, LRParseTree, NonTerminalIndex,
+    ParolError, ParseTree, ParseTreeStack, ParseTreeType, ParserError, ProductionIndex, Result,
+    SyntaxError, TerminalIndex, Token, TokenStream, TokenVec, UnexpectedToken, UserActionsTrait,
 };
 
 /// The type of the index of a LR action in the parse table's actions array.
 pub type LRActionIndex = usize;
 
+/// The number of tokens that have to be shifted after an error recovery before new syntax errors
+/// are reported. Like in yacc this avoids cascades of error messages.
+const RECOVERY_SHIFTS: usize = 3;
+
+/// The maximum number of tokens that the automatic error recovery inserts at the end of the input.
+const MAX_INSERTIONS: usize = 3;
+
 ///
 /// The type that contains all data to process a production within the lr-parser.
 ///
@@ -87,6 +94,18 @@ impl LR1State {
             .map(|(_, a)| *a)
     }
 
+    /// Returns the indices of all actions for the given terminal index.
+    /// A state of a GLR parse table can contain more than one action for a terminal.
+    pub fn action_indices(
+        &self,
+        terminal_index: TerminalIndex,
+    ) -> impl Iterator<Item = LRActionIndex> + '_ {
+        self.actions
+            .iter()
+            .filter(move |(t, _)| *t == terminal_index)
+            .map(|(_, a)| *a)
+    }
+
     /// Returns the goto state for the given non-terminal index.
     /// If the non-terminal index is not found in the state, `None` is returned.
     pub fn goto_state(&self, non_terminal_index: NonTerminalIndex) -> Option<usize> {
@@ -121,6 +140,19 @@ impl LRParseTable {
         state.action_index(terminal_index).map(|a| &self.actions[a])
     }
 
+    /// Returns all actions for the given state and terminal index.
+    /// The first one is the same as the one returned by `action`, the others are only followed by
+    /// the GLR parser.
+    pub fn actions(
+        &self,
+        state: usize,
+        terminal_index: TerminalIndex,
+    ) -> impl Iterator<Item = &LRAction> + '_ {
+        self.states[state]
+            .action_indices(terminal_index)
+            .map(move |a| &self.actions[a])
+    }
+
     /// Returns the goto state for the given state and non-terminal index.
     /// If the non-terminal index is not found in the state, `None` is returned.
     pub fn goto(&self, state: usize, non_terminal_index: NonTerminalIndex) -> Option<usize> {
@@ -209,6 +241,12 @@ pub struct LRParser<'t> {
     ///
     non_terminal_names: &'static [&'static str],
 
+    ///
+    /// Names of the terminals used in syntax errors.
+    /// They default to the terminal names and can be set with `set_terminal_display_names`.
+    ///
+    terminal_display_names: &'static [&'static str],
+
     /// Enables trimming of the parse tree during parsing.
     /// Thus the parse tree doesn't grow much and runtime overhead is diminished.
     /// Useful when enabling production mode and the whole parse tree is not needed.
@@ -217,6 +255,23 @@ pub struct LRParser<'t> {
     ///
     /// Default is `false`.
     trim_parse_tree: bool,
+
+    ///
+    /// The parser can generate multiple syntax errors during the course of recovering from an error
+    ///
+    error_entries: Vec<SyntaxError>,
+
+    ///
+    /// Set during error recovery when the error token is the next symbol to be processed instead
+    /// of the lookahead token.
+    ///
+    error_token_pending: bool,
+
+    ///
+    /// The number of tokens that still have to be shifted after an error recovery before new
+    /// syntax errors are reported.
+    ///
+    shifts_until_recovered: usize,
 }
 
 impl<'t> LRParser<'t> {
@@ -238,7 +293,11 @@ impl<'t> LRParser<'t> {
             productions,
             terminal_names,
             non_terminal_names,
+            terminal_display_names: terminal_names,
             trim_parse_tree: false,
+            error_entries: Vec::new(),
+            error_token_pending: false,
+            shifts_until_recovered: 0,
         }
     }
 
@@ -251,6 +310,42 @@ impl<'t> LRParser<'t> {
         self.trim_parse_tree = true;
     }
 
+    ///
+    /// Sets the names of the terminals that are shown in syntax errors instead of the generated
+    /// terminal names.
+    ///
+    /// The array must be parallel to the terminal names, i.e. it is indexed by terminal index.
+    ///
+    pub fn set_terminal_display_names(&mut self, terminal_display_names: &'static [&'static str]) {
+        self.terminal_display_names = terminal_display_names;
+    }
+
+    #[inline]
+    fn add_error(&mut self, error: SyntaxError) -> Result<()> {
+        if self
+            .error_entries
+            .iter()
+            .any(|e| e.error_location == error.error_location)
+        {
+            return Err(ParserError::RecoveryFailed.into());
+        }
+        self.error_entries.push(error);
+        if self.error_entries.len() > 100 {
+            return Err(ParserError::TooManyErrors {
+                count: self.error_entries.len(),
+            }
+            .into());
+        }
+        Ok(())
+    }
+
+    fn syntax_errors(&mut self) -> ParolError {
+        ParserError::SyntaxErrors {
+            entries: self.error_entries.drain(..).collect(),
+        }
+        .into()
+    }
+
     fn call_action<'u>(
         &mut self,
         prod_num: ProductionIndex,
@@ -313,10 +408,16 @@ impl<'t> LRParser<'t> {
 
         // Initialize the parse stack and the parse tree stack.
         self.parser_stack = LRParseStack::new();
+        self.error_token_pending = false;
+        self.shifts_until_recovered = 0;
 
         loop {
             self.handle_comments(&stream, user_actions)?;
-            let terminal_index = stream.borrow_mut().lookahead_token_type(0)?;
+            let terminal_index = if self.error_token_pending {
+                stream.borrow().error_token_type()
+            } else {
+                stream.borrow_mut().lookahead_token_type(0)?
+            };
             let current_state = self.parser_stack.current_state();
             trace!(
                 "Current state: {}, token type: {} ({})",
@@ -324,15 +425,31 @@ impl<'t> LRParser<'t> {
                 terminal_index,
                 self.terminal_names[terminal_index as usize]
             );
-            // Get the action for the current state and the current terminal
-            let action = self.parse_table.action(current_state, terminal_index);
+            // Get the action for the current state and the current terminal.
+            // The scanner delivers characters it can't match with the type of the error token.
+            // Such a token is always a syntax error and never the error token of the grammar.
+            let action = if !self.error_token_pending
+                && terminal_index == stream.borrow().error_token_type()
+            {
+                None
+            } else {
+                self.parse_table.action(current_state, terminal_index)
+            };
 
             match action {
                 Some(action) => {
                     match action {
                         LRAction::Shift(next_state) => {
-                            // Consume the token
-                            let token = stream.borrow_mut().consume()?;
+                            let token = if self.error_token_pending {
+                                // The error token doesn't consume any input
+                                self.error_token_pending = false;
+                                Self::error_token(&stream)?
+                            } else {
+                                // Consume the token
+                                self.shifts_until_recovered =
+                                    self.shifts_until_recovered.saturating_sub(1);
+                                stream.borrow_mut().consume()?
+                            };
                             trace!("Shift to state {}", next_state);
                             self.parser_stack.push(*next_state);
                             trace!(
@@ -400,10 +517,13 @@ impl<'t> LRParser<'t> {
                     }
                 }
                 None => {
-                    self.handle_parse_error(&stream, current_state, terminal_index)?;
+                    self.handle_parse_error(&stream, current_state, terminal_index, user_actions)?;
                 }
             }
         }
+        if !self.error_entries.is_empty() {
+            return Err(self.syntax_errors());
+        }
         let parse_tree = if self.trim_parse_tree {
             // Return an empty parse tree
             TreeBuilder::new().build()
@@ -416,21 +536,199 @@ impl<'t> LRParser<'t> {
         Ok(parse_tree.map_err(|source| ParserError::TreeError { source })?)
     }
 
-    fn handle_parse_error(
+    // Handles a missing action.
+    // A new syntax error is recorded unless the last error recovery is still in progress.
+    // If the grammar has a resynchronization rule with the error token that applies here the
+    // parser recovers like yacc does. States are popped from the stack until a state is found that
+    // has an action for the error token. The error token is processed next and the parser discards
+    // lookahead tokens until one of them can follow the error token.
+    // Otherwise the token stream is repaired automatically.
+    fn handle_parse_error<'u>(
         &mut self,
         stream: &Rc<RefCell<TokenStream<'t>>>,
         current_state: usize,
-        terminal_index: u16,
+        terminal_index: TerminalIndex,
+        user_actions: &'u mut dyn UserActionsTrait<'t>,
     ) -> Result<()> {
+        if self.error_token_pending {
+            trace!("No action for the error token in state {}", current_state);
+            return Err(self.syntax_errors());
+        }
+        let error_token_type = stream.borrow().error_token_type();
+        let resynchronizable = self
+            .parser_stack
+            .stack
+            .iter()
+            .any(|s| self.parse_table.action(*s, error_token_type).is_some());
+        if resynchronizable && self.shifts_until_recovered == RECOVERY_SHIFTS {
+            // Nothing was shifted after the error token yet
+            if terminal_index == EOI {
+                return Err(self.syntax_errors());
+            }
+            let token = stream.borrow_mut().consume()?;
+            trace!("Discard token '{}' during error recovery", token);
+            return Ok(());
+        }
+        if self.shifts_until_recovered == 0 {
+            let error = self.syntax_error(stream, current_state, terminal_index)?;
+            self.add_error(error)?;
+        }
+        if resynchronizable {
+            while self
+                .parse_table
+                .action(self.parser_stack.current_state(), error_token_type)
+                .is_none()
+            {
+                self.parser_stack.pop();
+                if let Some(LRParseTree::NonTerminal(..)) = self.parse_tree_stack.pop() {
+                    user_actions.on_non_terminal_discarded();
+                }
+            }
+            trace!(
+                "Recover from error in state {}",
+                self.parser_stack.current_state()
+            );
+            self.error_token_pending = true;
+        } else {
+            self.repair_token_stream(stream, error_token_type)?;
+        }
+        self.shifts_until_recovered = RECOVERY_SHIFTS;
+        Ok(())
+    }
+
+    // Repairs the token stream with a minimal number of changes.
+    // Before the lookahead token the shortest sequence of tokens is inserted that lets the parser
+    // shift the lookahead token. At most one token is inserted in the middle of the input and at
+    // most MAX_INSERTIONS tokens at its end. If there is no such sequence the lookahead token is
+    // deleted and the next one is tried.
+    fn repair_token_stream(
+        &mut self,
+        stream: &Rc<RefCell<TokenStream<'t>>>,
+        error_token_type: TerminalIndex,
+    ) -> Result<()> {
+        loop {
+            let terminal_index = stream.borrow_mut().lookahead_token_type(0)?;
+            let max_insertions = if terminal_index == EOI {
+                MAX_INSERTIONS
+            } else {
+                1
+            };
+            // Unmatched characters can only be deleted
+            let insertions = if terminal_index == error_token_type {
+                None
+            } else {
+                self.find_insertions(terminal_index, max_insertions, error_token_type)
+            };
+            if let Some(insertions) = insertions {
+                trace!("Repair by inserting {:?}", insertions);
+                for (i, t) in insertions.iter().enumerate() {
+                    stream.borrow_mut().insert_token_at(i, *t)?;
+                }
+                return Ok(());
+            }
+            if terminal_index == EOI {
+                return Err(self.syntax_errors());
+            }
+            let token = stream.borrow_mut().consume()?;
+            trace!("Repair by deleting token '{}'", token);
+        }
+    }
+
+    // Searches breadth-first for the shortest sequence of at most `max_insertions` terminals after
+    // which the parser can shift the given terminal.
+    // The result is empty if the terminal can be shifted right away.
+    fn find_insertions(
+        &self,
+        terminal_index: TerminalIndex,
+        max_insertions: usize,
+        error_token_type: TerminalIndex,
+    ) -> Option<Vec<TerminalIndex>> {
+        let mut candidates = vec![(self.parser_stack.stack.clone(), Vec::new())];
+        for depth in 0..=max_insertions {
+            let mut next_candidates = Vec::new();
+            for (stack, insertions) in candidates {
+                if self.simulate_shift(&mut stack.clone(), terminal_index) {
+                    return Some(insertions);
+                }
+                if depth == max_insertions {
+                    continue;
+                }
+                let state = *stack.last()?;
+                for t in self.parse_table.viable_terminal_indices(state) {
+                    if t == EOI || t == error_token_type {
+                        continue;
+                    }
+                    let mut next_stack = stack.clone();
+                    if self.simulate_shift(&mut next_stack, t) {
+                        let mut next_insertions = insertions.clone();
+                        next_insertions.push(t);
+                        next_candidates.push((next_stack, next_insertions));
+                    }
+                }
+            }
+            candidates = next_candidates;
+        }
+        None
+    }
+
+    // Simulates the parser on the given state stack without calling semantic actions.
+    // Returns true if the terminal is shifted or, in case of the end of input, accepted.
+    fn simulate_shift(&self, stack: &mut Vec<usize>, terminal_index: TerminalIndex) -> bool {
+        loop {
+            let state = match stack.last() {
+                Some(state) => *state,
+                None => return false,
+            };
+            match self.parse_table.action(state, terminal_index) {
+                Some(LRAction::Shift(next_state)) => {
+                    stack.push(*next_state);
+                    return true;
+                }
+                Some(LRAction::Reduce(nt_index, prod_index)) => {
+                    let n = self.productions[*prod_index].len;
+                    if n >= stack.len() {
+                        return false;
+                    }
+                    stack.truncate(stack.len() - n);
+                    match self.parse_table.goto(*stack.last().unwrap(), *nt_index) {
+                        Some(goto) => stack.push(goto),
+                        None => return false,
+                    }
+                }
+                Some(LRAction::Accept) => return true,
+                None => return false,
+            }
+        }
+    }
+
+    // The error token is located at the lookahead token that caused the syntax error
+    fn error_token(stream: &Rc<RefCell<TokenStream<'t>>>) -> Result<Token<'t>> {
+        let token = stream.borrow_mut().lookahead(0)?;
+        let error_token_type = stream.borrow().error_token_type();
+        Ok(Token::with(
+            "",
+            error_token_type,
+            token.location,
+            token.token_number,
+        ))
+    }
+
+    fn syntax_error(
+        &mut self,
+        stream: &Rc<RefCell<TokenStream<'t>>>,
+        current_state: usize,
+        terminal_index: TerminalIndex,
+    ) -> Result<SyntaxError> {
         let token = stream.borrow_mut().lookahead(0)?;
         trace!("No action for token '{}' in state {}", token, current_state);
         trace!("Current scanner is '{}'", stream.borrow().current_scanner());
         trace!("Parse stack: {:?}", self.parser_stack.stack);
         trace!("Parse tree stack:\n{}", self.parse_tree_stack);
-        let entries = vec![SyntaxError {
+        let error_token_type = stream.borrow().error_token_type();
+        Ok(SyntaxError {
             cause: format!(
                 "No action for token '{}' in state {}\nCurrent scanner is '{}'",
-                self.terminal_names[terminal_index as usize],
+                self.terminal_display_names[terminal_index as usize],
                 current_state,
                 stream.borrow().current_scanner()
             ),
@@ -438,21 +736,20 @@ impl<'t> LRParser<'t> {
             error_location: Box::new((&token).into()),
             unexpected_tokens: vec![UnexpectedToken::new(
                 "LA(1)".to_owned(),
-                self.terminal_names[terminal_index as usize].to_owned(),
+                self.terminal_display_names[terminal_index as usize].to_owned(),
                 &token,
             )],
             expected_tokens: self
                 .parse_table
                 .viable_terminal_indices(current_state)
                 .iter()
+                // The error token is never expected in the input
+                .filter(|t| **t != error_token_type)
                 .fold(TokenVec::new(), |mut acc, t| {
-                    acc.push(self.terminal_names[*t as usize].to_owned());
+                    acc.push(self.terminal_display_names[*t as usize].to_owned());
                     acc
                 }),
             source: None,
-        }];
-        Err(ParolError::ParserError(ParserError::SyntaxErrors {
-            entries,
-        }))
+        })
     }
 }
@@ -169,13 +169,13 @@ impl LookaheadDFA {
                 state,
                 token_stream.token_types()
             );
-            return Err(ParserError::PredictionError {
+            Err(ParserError::PredictionError {
                 cause: format!(
                     "Production prediction failed for non-terminal {}, trying to recover...",
                     non_terminal
                 ),
             }
-            .into());
+            .into())
         }
     }
 
@@ -70,4 +70,9 @@ pub use user_access::UserActionsTrait;
 /// Module with recovery algorithms
 ///
 pub(crate) mod recovery;
+
+///
+/// Module with the speculative parsing of adaptively predicted non-terminals
+///
+pub(crate) mod speculation;
 // pub(crate) use recovery::Recovery;
@@ -5,7 +5,7 @@ use std::fmt::{Display, Error, Formatter};
 ///
 /// The type of the elements in the parser stack.
 ///
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ParseType {
     ///
     /// The index of a non-terminal in the generated NON_TERMINALS array
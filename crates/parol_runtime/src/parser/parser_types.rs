@@ -1,7 +1,8 @@
 use crate::{
-    parser::recovery::Recovery, FileSource, FormatToken, Location, LookaheadDFA, NonTerminalIndex,
-    ParseStack, ParseTreeStack, ParseTreeType, ParseType, ParserError, ProductionIndex, Result,
-    SyntaxError, TerminalIndex, TokenStream, TokenVec, UnexpectedToken, UserActionsTrait,
+    parser::{recovery::Recovery, speculation::Speculation},
+    FileSource, FormatToken, Location, LookaheadDFA, NonTerminalIndex, ParseStack, ParseTreeStack,
+    ParseTreeType, ParseType, ParserError, ProductionIndex, Result, SyntaxError, TerminalIndex,
+    TokenStream, TokenVec, UnexpectedToken, UserActionsTrait,
 };
 use log::trace;
 use std::{cell::RefCell, cmp::Ord, rc::Rc};
@@ -109,6 +110,12 @@ pub struct LLKParser<'t> {
     ///
     non_terminal_names: &'static [&'static str],
 
+    ///
+    /// Names of the terminals used in syntax errors.
+    /// They default to the terminal names and can be set with `set_terminal_display_names`.
+    ///
+    terminal_display_names: &'static [&'static str],
+
     ///
     /// Enables trimming of the parse tree during parsing.
     /// Thus the parse tree doesn't grow much and runtime overhead is diminished.
@@ -122,6 +129,13 @@ pub struct LLKParser<'t> {
     /// The parser can generate multiple syntax errors during the course of recovering from an error
     ///
     error_entries: Vec<SyntaxError>,
+
+    ///
+    /// The non-terminals whose productions are predicted by speculative parsing if their lookahead
+    /// DFAs can't decide.
+    /// They can be set with `set_adaptive_non_terminals`.
+    ///
+    adaptive_non_terminals: &'static [NonTerminalIndex],
 }
 
 impl<'t> LLKParser<'t> {
@@ -144,8 +158,10 @@ impl<'t> LLKParser<'t> {
             productions,
             terminal_names,
             non_terminal_names,
+            terminal_display_names: terminal_names,
             trim_parse_tree: false,
             error_entries: Vec::new(),
+            adaptive_non_terminals: &[],
         }
     }
 
@@ -159,6 +175,35 @@ impl<'t> LLKParser<'t> {
         self.trim_parse_tree = true;
     }
 
+    ///
+    /// Sets the names of the terminals that are shown in syntax errors instead of the generated
+    /// terminal names.
+    ///
+    /// The array must be parallel to the terminal names, i.e. it is indexed by terminal index.
+    ///
+    pub fn set_terminal_display_names(&mut self, terminal_display_names: &'static [&'static str]) {
+        self.terminal_display_names = terminal_display_names;
+    }
+
+    ///
+    /// Sets the non-terminals that need more lookahead than their lookahead DFAs provide.
+    /// If the DFA of such a non-terminal can't decide, the production is predicted by speculative
+    /// parsing with unbounded lookahead. If more than one production matches the whole input the
+    /// one that comes first in the grammar is chosen.
+    ///
+    /// The speculation doesn't follow scanner switches. A production that reaches a scanner
+    /// switch is considered to match the input.
+    ///
+    /// The speculation reads at most 1000 tokens ahead and follows at most 1000 derivations per
+    /// production. Beyond these limits the prediction fails like the one of the lookahead DFA.
+    ///
+    pub fn set_adaptive_non_terminals(
+        &mut self,
+        adaptive_non_terminals: &'static [NonTerminalIndex],
+    ) {
+        self.adaptive_non_terminals = adaptive_non_terminals;
+    }
+
     fn input_accepted(&self) -> bool {
         matches!(self.parser_stack.stack[..], [] | [ParseType::T(0)])
     }
@@ -262,7 +307,22 @@ impl<'t> LLKParser<'t> {
         stream: Rc<RefCell<TokenStream<'t>>>,
     ) -> Result<ProductionIndex> {
         let lookahead_dfa = &self.lookahead_automata[non_terminal];
-        lookahead_dfa.eval(&mut stream.borrow_mut(), non_terminal)
+        let result = lookahead_dfa.eval(&mut stream.borrow_mut(), non_terminal);
+        match result {
+            Err(source) if self.adaptive_non_terminals.contains(&non_terminal) => {
+                // The non-terminal on top of the parse stack is replaced by the predicted production
+                let stack = &self.parser_stack.stack;
+                let continuation = match stack.last() {
+                    Some(ParseType::N(n)) if *n == non_terminal => &stack[..stack.len() - 1],
+                    _ => &stack[..],
+                };
+                trace!("Predict non-terminal {} speculatively", non_terminal);
+                Speculation::new(self.productions, continuation)
+                    .predict(non_terminal, &mut stream.borrow_mut())?
+                    .ok_or(source)
+            }
+            result => result,
+        }
     }
 
     fn handle_comments<'u>(
@@ -313,11 +373,7 @@ impl<'t> LLKParser<'t> {
         let prod_num = match self.predict_production(self.start_symbol_index, stream.clone()) {
             Ok(prod_num) => prod_num,
             Err(source) => {
-                match self.handle_prediction_error(self.start_symbol_index, stream.clone(), source)
-                {
-                    Ok(p) => p,
-                    Err(e) => return Err(e),
-                }
+                self.handle_prediction_error(self.start_symbol_index, stream.clone(), source)?
             }
         };
 
@@ -428,13 +484,13 @@ impl<'t> LLKParser<'t> {
         stream: Rc<RefCell<TokenStream<'t>>>,
     ) -> Result<()> {
         let mut expected_tokens = TokenVec::default();
-        expected_tokens.push(self.terminal_names[t as usize].to_string());
+        expected_tokens.push(self.terminal_display_names[t as usize].to_string());
         self.add_error(SyntaxError {
             cause: self.diagnostic_message(
                 format!(
                     "Syntax error: found {} \n\
                                             Current scanner is {}",
-                    token.format(self.terminal_names),
+                    token.format(self.terminal_display_names),
                     stream.borrow().current_scanner(),
                 )
                 .as_str(),
@@ -444,7 +500,7 @@ impl<'t> LLKParser<'t> {
             error_location: Box::new((&token).into()),
             unexpected_tokens: vec![UnexpectedToken::new(
                 "LA(1)".to_owned(),
-                self.terminal_names[token.token_type as usize].to_owned(),
+                self.terminal_display_names[token.token_type as usize].to_owned(),
                 &token,
             )],
             expected_tokens,
@@ -462,7 +518,7 @@ impl<'t> LLKParser<'t> {
         let nt_name = self.non_terminal_names[non_terminal];
         let (message, unexpected_tokens, expected_tokens) =
             self.lookahead_automata[non_terminal]
-                .build_error(self.terminal_names, &stream.borrow())?;
+                .build_error(self.terminal_display_names, &stream.borrow())?;
         self.add_error(SyntaxError {
             cause: self.diagnostic_message(
                 format!(
@@ -0,0 +1,182 @@
+use log::trace;
+
+use crate::{
+    LexerError, NonTerminalIndex, ParseType, Production, ProductionIndex, TerminalIndex,
+    TokenStream,
+};
+
+///
+/// The maximum number of tokens read ahead by the speculation.
+/// If no production is decided within this lookahead the speculation gives up.
+///
+pub(crate) const MAX_SPECULATIVE_LOOKAHEAD: usize = 1000;
+
+///
+/// The maximum number of configurations a production may have while the speculation advances over
+/// one token. Ambiguous derivations can multiply the configurations; if they exceed this number the
+/// speculation gives up.
+///
+pub(crate) const MAX_CONFIGURATIONS: usize = 1000;
+
+///
+/// A configuration of the speculative parser.
+/// The symbols still to parse are the ones on the local stack followed by the first `depth`
+/// symbols of the parse stack of the actual parser, the continuation.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Configuration {
+    stack: Vec<ParseType>,
+    depth: usize,
+}
+
+///
+/// The state of a production whose derivations are followed speculatively.
+///
+#[derive(Debug)]
+enum Candidate {
+    /// The configurations that matched all tokens so far
+    Viable(Vec<Configuration>),
+    /// A configuration parsed all symbols of the parse stack or reached a scanner switch that
+    /// can't be followed speculatively
+    Finished,
+    /// No configuration matches the tokens so far
+    Failed,
+    /// The configurations exceeded `MAX_CONFIGURATIONS`
+    Exhausted,
+}
+
+///
+/// Predicts productions by speculative parsing with unbounded lookahead.
+/// It is used for non-terminals whose productions can't be decided with the lookahead DFAs.
+///
+pub(crate) struct Speculation<'a> {
+    productions: &'static [Production],
+    continuation: &'a [ParseType],
+}
+
+impl<'a> Speculation<'a> {
+    pub(crate) fn new(productions: &'static [Production], continuation: &'a [ParseType]) -> Self {
+        Self {
+            productions,
+            continuation,
+        }
+    }
+
+    ///
+    /// Follows the derivations of all productions of the given non-terminal in parallel until at
+    /// most one of them still matches the input. If more than one production matches the whole
+    /// input the one that comes first in the grammar is chosen.
+    /// Returns `None` if no production matches the input or if the speculation exceeds
+    /// `MAX_SPECULATIVE_LOOKAHEAD` or `MAX_CONFIGURATIONS`.
+    ///
+    pub(crate) fn predict(
+        &self,
+        non_terminal: NonTerminalIndex,
+        stream: &mut TokenStream<'_>,
+    ) -> Result<Option<ProductionIndex>, LexerError> {
+        let mut candidates = self
+            .productions
+            .iter()
+            .enumerate()
+            .filter(|(_, p)| p.lhs == non_terminal)
+            .map(|(prod_num, p)| {
+                let mut stack = Vec::new();
+                stack.extend(p.production);
+                (
+                    prod_num,
+                    Candidate::Viable(vec![Configuration {
+                        stack,
+                        depth: self.continuation.len(),
+                    }]),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let mut lookahead = 0;
+        while candidates
+            .iter()
+            .filter(|(_, c)| !matches!(c, Candidate::Failed))
+            .count()
+            > 1
+            && candidates
+                .iter()
+                .any(|(_, c)| matches!(c, Candidate::Viable(_)))
+        {
+            if lookahead == MAX_SPECULATIVE_LOOKAHEAD {
+                trace!("Speculation exceeded the maximum lookahead");
+                return Ok(None);
+            }
+            let token_type = stream.speculative_lookahead_token_type(lookahead)?;
+            for (prod_num, candidate) in candidates.iter_mut() {
+                if let Candidate::Viable(configurations) = candidate {
+                    *candidate = self.advance(configurations, token_type);
+                    trace!(
+                        "Speculation LA({}): production {} {:?}",
+                        lookahead + 1,
+                        prod_num,
+                        candidate
+                    );
+                    if matches!(candidate, Candidate::Exhausted) {
+                        return Ok(None);
+                    }
+                }
+            }
+            lookahead += 1;
+        }
+
+        Ok(candidates
+            .iter()
+            .find(|(_, c)| !matches!(c, Candidate::Failed))
+            .map(|(prod_num, _)| *prod_num))
+    }
+
+    // Advances all configurations over the given token
+    fn advance(&self, configurations: &[Configuration], token_type: TerminalIndex) -> Candidate {
+        let mut advanced = Vec::new();
+        let mut pending = configurations.to_vec();
+        while let Some(mut configuration) = pending.pop() {
+            if advanced.len() + pending.len() >= MAX_CONFIGURATIONS {
+                return Candidate::Exhausted;
+            }
+            match self.pop(&mut configuration) {
+                // Remaining input is reported by the parser itself
+                None => return Candidate::Finished,
+                Some(ParseType::T(t)) if t == token_type => {
+                    if !advanced.contains(&configuration) {
+                        advanced.push(configuration);
+                    }
+                }
+                Some(ParseType::T(_)) => (),
+                Some(ParseType::N(n)) => {
+                    for p in self.productions.iter().filter(|p| p.lhs == n) {
+                        let mut expanded = configuration.clone();
+                        expanded.stack.extend(p.production);
+                        pending.push(expanded);
+                    }
+                }
+                // The tokens after a scanner switch can't be read in advance
+                Some(ParseType::S(_) | ParseType::Push(_) | ParseType::Pop) => {
+                    return Candidate::Finished
+                }
+                Some(ParseType::E(_)) => pending.push(configuration),
+            }
+        }
+        if advanced.is_empty() {
+            Candidate::Failed
+        } else {
+            Candidate::Viable(advanced)
+        }
+    }
+
+    // Pops the next symbol to parse from the configuration
+    fn pop(&self, configuration: &mut Configuration) -> Option<ParseType> {
+        configuration.stack.pop().or_else(|| {
+            if configuration.depth == 0 {
+                None
+            } else {
+                configuration.depth -= 1;
+                Some(self.continuation[configuration.depth])
+            }
+        })
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{ParseTreeType, Result, Token};
+use crate::{lr_parser::Ambiguity, ParseTreeType, Result, Token};
 
 ///
 /// This trait is used as a coupling point between the generated parser and
@@ -29,4 +29,21 @@ pub trait UserActionsTrait<'t> {
     /// itself.
     ///
     fn on_comment_parsed(&mut self, _token: Token<'t>);
+
+    ///
+    /// This function is called by the LR parser when it discards an already reduced non-terminal
+    /// while it recovers from a syntax error with the help of the `error` token.
+    /// Implementors that collect the results of semantic actions on a stack can remove the value
+    /// of the non-terminal here.
+    ///
+    fn on_non_terminal_discarded(&mut self) {}
+
+    ///
+    /// This function is called by the GLR parser for each part of the input that can be derived
+    /// from a non-terminal in more than one way. It returns the index of the alternative whose
+    /// semantic actions are called. The default is the first alternative.
+    ///
+    fn on_ambiguity(&mut self, _ambiguity: &Ambiguity<'t>) -> usize {
+        0
+    }
 }
@@ -1,2 +1,5 @@
+pub mod non_empty_vec;
+pub use non_empty_vec::NonEmptyVec;
+
 pub mod parse_tree_stack;
 pub use parse_tree_stack::ParseTreeStack;
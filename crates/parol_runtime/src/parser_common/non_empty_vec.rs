@@ -0,0 +1,116 @@
+use std::convert::TryFrom;
+use std::ops::Deref;
+
+use crate::{Span, ToSpan};
+
+/// A vector that contains at least one element.
+///
+/// It is used in generated AST types for one-or-more repetitions (`{ Item }+`) and separated lists
+/// (`{ Item % ',' }`).
+/// Its contents can be accessed as a slice. Additionally, the methods [NonEmptyVec::first] and
+/// [NonEmptyVec::last] return the element directly instead of an `Option`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct NonEmptyVec<T>(Vec<T>);
+
+impl<T> NonEmptyVec<T> {
+    /// Creates a new instance from the first element and the remaining elements.
+    pub fn new(first: T, rest: Vec<T>) -> Self {
+        let mut elements = Vec::with_capacity(rest.len() + 1);
+        elements.push(first);
+        elements.extend(rest);
+        Self(elements)
+    }
+
+    /// Returns the first element.
+    pub fn first(&self) -> &T {
+        &self.0[0]
+    }
+
+    /// Returns the last element.
+    pub fn last(&self) -> &T {
+        &self.0[self.0.len() - 1]
+    }
+
+    /// Appends an element to the back.
+    pub fn push(&mut self, element: T) {
+        self.0.push(element);
+    }
+
+    /// Returns the elements as slice.
+    pub fn as_slice(&self) -> &[T] {
+        &self.0
+    }
+
+    /// Converts into a `Vec`, which is guaranteed to be non-empty.
+    pub fn into_vec(self) -> Vec<T> {
+        self.0
+    }
+}
+
+impl<T> Deref for NonEmptyVec<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> From<NonEmptyVec<T>> for Vec<T> {
+    fn from(non_empty: NonEmptyVec<T>) -> Self {
+        non_empty.0
+    }
+}
+
+impl<T> TryFrom<Vec<T>> for NonEmptyVec<T> {
+    type Error = Vec<T>;
+
+    /// Fails with the given vector if it is empty.
+    fn try_from(elements: Vec<T>) -> Result<Self, Self::Error> {
+        if elements.is_empty() {
+            Err(elements)
+        } else {
+            Ok(Self(elements))
+        }
+    }
+}
+
+impl<T> IntoIterator for NonEmptyVec<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a NonEmptyVec<T> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl<T: ToSpan> ToSpan for NonEmptyVec<T> {
+    fn span(&self) -> Span {
+        self.first().span() + self.last().span()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize> serde::Serialize for NonEmptyVec<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: serde::Deserialize<'de>> serde::Deserialize<'de> for NonEmptyVec<T> {
+    /// Fails if the deserialized sequence is empty.
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let elements = Vec::<T>::deserialize(deserializer)?;
+        Self::try_from(elements)
+            .map_err(|_| serde::de::Error::custom("expected at least one element"))
+    }
+}
@@ -0,0 +1,238 @@
+//! This test is based on the following grammar that is not LL(k) for any k:
+//! ```parol
+//! %start S
+//!
+//! %%
+//!
+//! S: A "x" | B "y";
+//! A: "a" A | "a";
+//! B: "a" B | "a";
+//! ```
+//! The tables are generated with `parol -k 2 --adaptive-prediction`. The non-terminal `S` is
+//! predicted by speculative parsing.
+
+use parol_runtime::lexer::tokenizer::{
+    ERROR_TOKEN, NEW_LINE_TOKEN, UNMATCHABLE_TOKEN, WHITESPACE_TOKEN,
+};
+use parol_runtime::once_cell::sync::Lazy;
+use parol_runtime::parser::{
+    LLKParser, LookaheadDFA, ParseTreeType, ParseType, Production, Trans, UserActionsTrait,
+};
+use parol_runtime::{
+    ParolError, ParserError, Result, ScannerConfig, TerminalIndex, Token, TokenStream, Tokenizer,
+};
+
+const TERMINALS: &[&str; 9] = &[
+    /* 0 */ UNMATCHABLE_TOKEN,
+    /* 1 */ UNMATCHABLE_TOKEN,
+    /* 2 */ UNMATCHABLE_TOKEN,
+    /* 3 */ UNMATCHABLE_TOKEN,
+    /* 4 */ UNMATCHABLE_TOKEN,
+    /* 5 */ r"x",
+    /* 6 */ r"y",
+    /* 7 */ r"a",
+    /* 8 */ ERROR_TOKEN,
+];
+
+const TERMINAL_NAMES: &[&str; 9] = &[
+    /* 0 */ "EndOfInput",
+    /* 1 */ "Newline",
+    /* 2 */ "Whitespace",
+    /* 3 */ "LineComment",
+    /* 4 */ "BlockComment",
+    /* 5 */ "X",
+    /* 6 */ "Y",
+    /* 7 */ "A",
+    /* 8 */ "Error",
+];
+
+/* SCANNER_0: "INITIAL" */
+const SCANNER_0: (&[&str; 5], &[TerminalIndex; 3]) = (
+    &[
+        /* 0 */ UNMATCHABLE_TOKEN,
+        /* 1 */ NEW_LINE_TOKEN,
+        /* 2 */ WHITESPACE_TOKEN,
+        /* 3 */ UNMATCHABLE_TOKEN,
+        /* 4 */ UNMATCHABLE_TOKEN,
+    ],
+    &[5 /* X */, 6 /* Y */, 7 /* A */],
+);
+
+const MAX_K: usize = 1;
+
+const NON_TERMINALS: &[&str; 5] = &[
+    /* 0 */ "A", /* 1 */ "ASuffix", /* 2 */ "B", /* 3 */ "BSuffix",
+    /* 4 */ "S",
+];
+
+const LOOKAHEAD_AUTOMATA: &[LookaheadDFA; 5] = &[
+    /* 0 - "A" */
+    LookaheadDFA {
+        prod0: 2,
+        transitions: &[],
+        k: 0,
+    },
+    /* 1 - "ASuffix" */
+    LookaheadDFA {
+        prod0: -1,
+        transitions: &[Trans(0, 5, 2, 4), Trans(0, 7, 1, 3)],
+        k: 1,
+    },
+    /* 2 - "B" */
+    LookaheadDFA {
+        prod0: 5,
+        transitions: &[],
+        k: 0,
+    },
+    /* 3 - "BSuffix" */
+    LookaheadDFA {
+        prod0: -1,
+        transitions: &[Trans(0, 6, 2, 7), Trans(0, 7, 1, 6)],
+        k: 1,
+    },
+    /* 4 - "S" */
+    LookaheadDFA {
+        prod0: -1,
+        transitions: &[Trans(0, 7, 1, -1)],
+        k: 1,
+    },
+];
+const ADAPTIVE_NON_TERMINALS: &[usize; 1] = &[4 /* S */];
+
+const PRODUCTIONS: &[Production; 8] = &[
+    // 0 - S: A "x";
+    Production {
+        lhs: 4,
+        production: &[ParseType::T(5), ParseType::N(0)],
+    },
+    // 1 - S: B "y";
+    Production {
+        lhs: 4,
+        production: &[ParseType::T(6), ParseType::N(2)],
+    },
+    // 2 - A: "a" ASuffix;
+    Production {
+        lhs: 0,
+        production: &[ParseType::N(1), ParseType::T(7)],
+    },
+    // 3 - ASuffix: A;
+    Production {
+        lhs: 1,
+        production: &[ParseType::N(0)],
+    },
+    // 4 - ASuffix: ;
+    Production {
+        lhs: 1,
+        production: &[],
+    },
+    // 5 - B: "a" BSuffix;
+    Production {
+        lhs: 2,
+        production: &[ParseType::N(3), ParseType::T(7)],
+    },
+    // 6 - BSuffix: B;
+    Production {
+        lhs: 3,
+        production: &[ParseType::N(2)],
+    },
+    // 7 - BSuffix: ;
+    Production {
+        lhs: 3,
+        production: &[],
+    },
+];
+
+static SCANNERS: Lazy<Vec<ScannerConfig>> = Lazy::new(|| {
+    vec![ScannerConfig::new(
+        "INITIAL",
+        Tokenizer::build(TERMINALS, SCANNER_0.0, SCANNER_0.1).unwrap(),
+        &[],
+    )]
+});
+
+// Records the numbers of the reduced productions
+#[derive(Default)]
+struct Recorder {
+    productions: Vec<usize>,
+}
+
+impl<'t> UserActionsTrait<'t> for Recorder {
+    fn call_semantic_action_for_production_number(
+        &mut self,
+        prod_num: usize,
+        _children: &[ParseTreeType<'t>],
+    ) -> Result<()> {
+        self.productions.push(prod_num);
+        Ok(())
+    }
+
+    fn on_comment_parsed(&mut self, _token: Token<'t>) {}
+}
+
+fn parse(input: &str, adaptive: bool) -> Result<Vec<usize>> {
+    let mut llk_parser = LLKParser::new(
+        4,
+        LOOKAHEAD_AUTOMATA,
+        PRODUCTIONS,
+        TERMINAL_NAMES,
+        NON_TERMINALS,
+    );
+    if adaptive {
+        llk_parser.set_adaptive_non_terminals(ADAPTIVE_NON_TERMINALS);
+    }
+    let mut recorder = Recorder::default();
+    llk_parser.parse(
+        TokenStream::new(input, "test", &SCANNERS, MAX_K).unwrap(),
+        &mut recorder,
+    )?;
+    Ok(recorder.productions)
+}
+
+#[test]
+fn first_production_is_predicted() {
+    assert_eq!(0, *parse("a a a x", true).unwrap().last().unwrap());
+}
+
+#[test]
+fn second_production_is_predicted() {
+    assert_eq!(1, *parse("a a a a a y", true).unwrap().last().unwrap());
+}
+
+#[test]
+fn prediction_fails_without_adaptive_non_terminals() {
+    assert!(parse("a x", false).is_err());
+}
+
+#[test]
+fn syntax_error() {
+    // No production of S matches the input
+    match parse("x", true) {
+        Err(ParolError::ParserError(ParserError::SyntaxErrors { entries })) => {
+            assert_eq!(1, entries.len());
+        }
+        result => panic!("Unexpected result: {:?}", result),
+    }
+}
+
+#[test]
+fn unprocessed_input() {
+    assert!(matches!(
+        parse("a a x y", true),
+        Err(ParolError::ParserError(
+            ParserError::UnprocessedInput { .. }
+        ))
+    ));
+}
+
+#[test]
+fn speculation_is_limited() {
+    // The speculation gives up before it reaches the decisive token
+    let input = format!("{}x", "a ".repeat(1000));
+    match parse(&input, true) {
+        Err(ParolError::ParserError(ParserError::SyntaxErrors { entries })) => {
+            assert_eq!(1, entries.len());
+        }
+        result => panic!("Unexpected result: {:?}", result),
+    }
+    assert!(parse(&format!("{}x", "a ".repeat(999)), true).is_ok());
+}
@@ -0,0 +1,229 @@
+//! This test is based on the following ambiguous grammar whose start production has more than
+//! one symbol:
+//! ```parol
+//! %start Stmt
+//! %grammar_type 'glr'
+//!
+//! %%
+//!
+//! Stmt: Expr ';';
+//! Expr: Expr '+' Expr | Num;
+//! Num: /[0-9]+/;
+//! ```
+//! The start production is reduced by the GLR parser when it accepts the input.
+
+use parol_runtime::lexer::tokenizer::{
+    ERROR_TOKEN, NEW_LINE_TOKEN, UNMATCHABLE_TOKEN, WHITESPACE_TOKEN,
+};
+use parol_runtime::lr_parser::{
+    Ambiguity, ForestNode, GLRParser, LR1State, LRAction, LRParseTable, LRProduction,
+};
+use parol_runtime::once_cell::sync::Lazy;
+use parol_runtime::parser::{ParseTreeType, UserActionsTrait};
+use parol_runtime::{Result, ScannerConfig, TerminalIndex, Token, TokenStream, Tokenizer};
+
+const TERMINALS: &[&str; 9] = &[
+    /* 0 */ UNMATCHABLE_TOKEN,
+    /* 1 */ UNMATCHABLE_TOKEN,
+    /* 2 */ UNMATCHABLE_TOKEN,
+    /* 3 */ UNMATCHABLE_TOKEN,
+    /* 4 */ UNMATCHABLE_TOKEN,
+    /* 5 */ r";",
+    /* 6 */ r"\+",
+    /* 7 */ r"[0-9]+",
+    /* 8 */ ERROR_TOKEN,
+];
+
+const TERMINAL_NAMES: &[&str; 9] = &[
+    /* 0 */ "EndOfInput",
+    /* 1 */ "Newline",
+    /* 2 */ "Whitespace",
+    /* 3 */ "LineComment",
+    /* 4 */ "BlockComment",
+    /* 5 */ "Semicolon",
+    /* 6 */ "Plus",
+    /* 7 */ "Num",
+    /* 8 */ "Error",
+];
+
+/* SCANNER_0: "INITIAL" */
+const SCANNER_0: (&[&str; 5], &[TerminalIndex; 3]) = (
+    &[
+        /* 0 */ UNMATCHABLE_TOKEN,
+        /* 1 */ NEW_LINE_TOKEN,
+        /* 2 */ WHITESPACE_TOKEN,
+        /* 3 */ UNMATCHABLE_TOKEN,
+        /* 4 */ UNMATCHABLE_TOKEN,
+    ],
+    &[5 /* Semicolon */, 6 /* Plus */, 7 /* Num */],
+);
+
+const NON_TERMINALS: &[&str; 3] = &[/* 0 */ "Expr", /* 1 */ "Num", /* 2 */ "Stmt"];
+
+static PARSE_TABLE: LRParseTable = LRParseTable {
+    actions: &[
+        /* 0 */ LRAction::Shift(1),
+        /* 1 */ LRAction::Shift(4),
+        /* 2 */ LRAction::Shift(5),
+        /* 3 */ LRAction::Reduce(0 /* Expr */, 1),
+        /* 4 */ LRAction::Reduce(0 /* Expr */, 2),
+        /* 5 */ LRAction::Reduce(1 /* Num */, 3),
+        /* 6 */ LRAction::Accept,
+    ],
+    states: &[
+        // State 0
+        LR1State {
+            actions: &[(7, 0) /* '[0-9]+' => LRAction::Shift(1) */],
+            gotos: &[(0, 2) /* Expr => 2 */, (1, 3) /* Num => 3 */],
+        },
+        // State 1
+        LR1State {
+            actions: &[
+                (5, 5), /* ';' => LRAction::Reduce(Num, 3) */
+                (6, 5), /* '+' => LRAction::Reduce(Num, 3) */
+            ],
+            gotos: &[],
+        },
+        // State 2
+        LR1State {
+            actions: &[
+                (5, 1), /* ';' => LRAction::Shift(4) */
+                (6, 2), /* '+' => LRAction::Shift(5) */
+            ],
+            gotos: &[],
+        },
+        // State 3
+        LR1State {
+            actions: &[
+                (5, 4), /* ';' => LRAction::Reduce(Expr, 2) */
+                (6, 4), /* '+' => LRAction::Reduce(Expr, 2) */
+            ],
+            gotos: &[],
+        },
+        // State 4
+        LR1State {
+            actions: &[(0, 6) /* '<$>' => LRAction::Accept */],
+            gotos: &[],
+        },
+        // State 5
+        LR1State {
+            actions: &[(7, 0) /* '[0-9]+' => LRAction::Shift(1) */],
+            gotos: &[(0, 6) /* Expr => 6 */, (1, 3) /* Num => 3 */],
+        },
+        // State 6
+        LR1State {
+            actions: &[
+                (5, 3), /* ';' => LRAction::Reduce(Expr, 1) */
+                (6, 2), /* '+' => LRAction::Shift(5) */
+                (6, 3), /* '+' => LRAction::Reduce(Expr, 1) */
+            ],
+            gotos: &[],
+        },
+    ],
+};
+
+const PRODUCTIONS: &[LRProduction; 4] = &[
+    // 0 - Stmt: Expr ';';
+    LRProduction { lhs: 2, len: 2 },
+    // 1 - Expr: Expr '+' Expr;
+    LRProduction { lhs: 0, len: 3 },
+    // 2 - Expr: Num;
+    LRProduction { lhs: 0, len: 1 },
+    // 3 - Num: /[0-9]+/;
+    LRProduction { lhs: 1, len: 1 },
+];
+
+static SCANNERS: Lazy<Vec<ScannerConfig>> = Lazy::new(|| {
+    vec![ScannerConfig::new(
+        "INITIAL",
+        Tokenizer::build(TERMINALS, SCANNER_0.0, SCANNER_0.1).unwrap(),
+        &[],
+    )]
+});
+
+// Renders the chosen parse tree with parentheses around each sum
+#[derive(Default)]
+struct Bracketing {
+    // The index of the alternative chosen at each ambiguity
+    choice: usize,
+    stack: Vec<String>,
+}
+
+impl<'t> UserActionsTrait<'t> for Bracketing {
+    fn call_semantic_action_for_production_number(
+        &mut self,
+        prod_num: usize,
+        children: &[ParseTreeType<'t>],
+    ) -> Result<()> {
+        match prod_num {
+            0 => {
+                let expr = self.stack.pop().unwrap();
+                self.stack.push(format!("{};", expr));
+            }
+            1 => {
+                let rhs = self.stack.pop().unwrap();
+                let lhs = self.stack.pop().unwrap();
+                self.stack.push(format!("({}+{})", lhs, rhs));
+            }
+            3 => self
+                .stack
+                .push(children[0].token().unwrap().text().to_string()),
+            _ => (),
+        }
+        Ok(())
+    }
+
+    fn on_comment_parsed(&mut self, _token: Token<'t>) {}
+
+    fn on_ambiguity(&mut self, ambiguity: &Ambiguity<'t>) -> usize {
+        assert_eq!("Expr", ambiguity.non_terminal);
+        self.choice
+    }
+}
+
+fn parse(input: &str, choice: usize) -> Result<String> {
+    let mut glr_parser =
+        GLRParser::new(2, &PARSE_TABLE, PRODUCTIONS, TERMINAL_NAMES, NON_TERMINALS);
+    let mut bracketing = Bracketing {
+        choice,
+        ..Default::default()
+    };
+    glr_parser.parse(
+        TokenStream::new(input, "test", &SCANNERS, 1).unwrap(),
+        &mut bracketing,
+    )?;
+    Ok(bracketing.stack.pop().unwrap())
+}
+
+#[test]
+fn start_production_is_reduced() {
+    assert_eq!("(1+2);", parse("1 + 2;", 0).unwrap());
+    assert_eq!("((1+2)+3);", parse("1 + 2 + 3;", 0).unwrap());
+    assert_eq!("(1+(2+3));", parse("1 + 2 + 3;", 1).unwrap());
+}
+
+#[test]
+fn root_contains_the_start_production() {
+    let mut glr_parser =
+        GLRParser::new(2, &PARSE_TABLE, PRODUCTIONS, TERMINAL_NAMES, NON_TERMINALS);
+    let forest = glr_parser
+        .parse_forest(TokenStream::new("1 + 2;", "test", &SCANNERS, 1).unwrap())
+        .unwrap();
+    match &forest.nodes[forest.root] {
+        ForestNode::NonTerminal {
+            non_terminal,
+            families,
+        } => {
+            assert_eq!(2, *non_terminal);
+            assert_eq!(1, families.len());
+            assert_eq!(0, families[0].production);
+            assert_eq!(2, families[0].children.len());
+        }
+        node => panic!("Unexpected root: {:?}", node),
+    }
+}
+
+#[test]
+fn missing_semicolon() {
+    assert!(parse("1 + 2", 0).is_err());
+}
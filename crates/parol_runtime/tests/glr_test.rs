@@ -0,0 +1,242 @@
+//! This test is based on the following ambiguous grammar:
+//! ```parol
+//! %start Sum
+//! %grammar_type 'glr'
+//!
+//! %%
+//!
+//! Sum: Expr;
+//! Expr: Expr '+' Expr | Num;
+//! Num: /[0-9]+/;
+//! ```
+//! The parse table contains a shift-reduce conflict on '+' in state 5 that the GLR parser follows.
+
+use parol_runtime::lexer::tokenizer::{
+    ERROR_TOKEN, NEW_LINE_TOKEN, UNMATCHABLE_TOKEN, WHITESPACE_TOKEN,
+};
+use parol_runtime::lr_parser::{
+    Ambiguity, GLRParser, LR1State, LRAction, LRParseTable, LRProduction,
+};
+use parol_runtime::once_cell::sync::Lazy;
+use parol_runtime::parser::{ParseTreeType, UserActionsTrait};
+use parol_runtime::{
+    ParolError, ParserError, Result, ScannerConfig, TerminalIndex, Token, TokenStream, Tokenizer,
+};
+
+const TERMINALS: &[&str; 8] = &[
+    /* 0 */ UNMATCHABLE_TOKEN,
+    /* 1 */ UNMATCHABLE_TOKEN,
+    /* 2 */ UNMATCHABLE_TOKEN,
+    /* 3 */ UNMATCHABLE_TOKEN,
+    /* 4 */ UNMATCHABLE_TOKEN,
+    /* 5 */ r"\+",
+    /* 6 */ r"[0-9]+",
+    /* 7 */ ERROR_TOKEN,
+];
+
+const TERMINAL_NAMES: &[&str; 8] = &[
+    /* 0 */ "EndOfInput",
+    /* 1 */ "Newline",
+    /* 2 */ "Whitespace",
+    /* 3 */ "LineComment",
+    /* 4 */ "BlockComment",
+    /* 5 */ "Plus",
+    /* 6 */ "Num",
+    /* 7 */ "Error",
+];
+
+/* SCANNER_0: "INITIAL" */
+const SCANNER_0: (&[&str; 5], &[TerminalIndex; 2]) = (
+    &[
+        /* 0 */ UNMATCHABLE_TOKEN,
+        /* 1 */ NEW_LINE_TOKEN,
+        /* 2 */ WHITESPACE_TOKEN,
+        /* 3 */ UNMATCHABLE_TOKEN,
+        /* 4 */ UNMATCHABLE_TOKEN,
+    ],
+    &[5 /* Plus */, 6 /* Num */],
+);
+
+const NON_TERMINALS: &[&str; 3] = &[/* 0 */ "Expr", /* 1 */ "Num", /* 2 */ "Sum"];
+
+static PARSE_TABLE: LRParseTable = LRParseTable {
+    actions: &[
+        /* 0 */ LRAction::Shift(1),
+        /* 1 */ LRAction::Shift(4),
+        /* 2 */ LRAction::Reduce(0 /* Expr */, 1),
+        /* 3 */ LRAction::Reduce(0 /* Expr */, 2),
+        /* 4 */ LRAction::Reduce(1 /* Num */, 3),
+        /* 5 */ LRAction::Accept,
+    ],
+    states: &[
+        // State 0
+        LR1State {
+            actions: &[(6, 0) /* '[0-9]+' => LRAction::Shift(1) */],
+            gotos: &[(0, 2) /* Expr => 2 */, (1, 3) /* Num => 3 */],
+        },
+        // State 1
+        LR1State {
+            actions: &[
+                (0, 4), /* '<$>' => LRAction::Reduce(Num, 3) */
+                (5, 4), /* '+' => LRAction::Reduce(Num, 3) */
+            ],
+            gotos: &[],
+        },
+        // State 2
+        LR1State {
+            actions: &[
+                (0, 5), /* '<$>' => LRAction::Accept */
+                (5, 1), /* '+' => LRAction::Shift(4) */
+            ],
+            gotos: &[],
+        },
+        // State 3
+        LR1State {
+            actions: &[
+                (0, 3), /* '<$>' => LRAction::Reduce(Expr, 2) */
+                (5, 3), /* '+' => LRAction::Reduce(Expr, 2) */
+            ],
+            gotos: &[],
+        },
+        // State 4
+        LR1State {
+            actions: &[(6, 0) /* '[0-9]+' => LRAction::Shift(1) */],
+            gotos: &[(0, 5) /* Expr => 5 */, (1, 3) /* Num => 3 */],
+        },
+        // State 5
+        LR1State {
+            actions: &[
+                (0, 2), /* '<$>' => LRAction::Reduce(Expr, 1) */
+                (5, 1), /* '+' => LRAction::Shift(4) */
+                (5, 2), /* '+' => LRAction::Reduce(Expr, 1) */
+            ],
+            gotos: &[],
+        },
+    ],
+};
+
+const PRODUCTIONS: &[LRProduction; 4] = &[
+    // 0 - Sum: Expr;
+    LRProduction { lhs: 2, len: 1 },
+    // 1 - Expr: Expr '+' Expr;
+    LRProduction { lhs: 0, len: 3 },
+    // 2 - Expr: Num;
+    LRProduction { lhs: 0, len: 1 },
+    // 3 - Num: /[0-9]+/;
+    LRProduction { lhs: 1, len: 1 },
+];
+
+static SCANNERS: Lazy<Vec<ScannerConfig>> = Lazy::new(|| {
+    vec![ScannerConfig::new(
+        "INITIAL",
+        Tokenizer::build(TERMINALS, SCANNER_0.0, SCANNER_0.1).unwrap(),
+        &[],
+    )]
+});
+
+// Renders the chosen parse tree with parentheses around each sum and counts the ambiguities
+#[derive(Default)]
+struct Bracketing {
+    // The index of the alternative chosen at each ambiguity
+    choice: usize,
+    ambiguities: usize,
+    stack: Vec<String>,
+}
+
+impl<'t> UserActionsTrait<'t> for Bracketing {
+    fn call_semantic_action_for_production_number(
+        &mut self,
+        prod_num: usize,
+        children: &[ParseTreeType<'t>],
+    ) -> Result<()> {
+        match prod_num {
+            1 => {
+                let rhs = self.stack.pop().unwrap();
+                let lhs = self.stack.pop().unwrap();
+                self.stack.push(format!("({}+{})", lhs, rhs));
+            }
+            3 => self
+                .stack
+                .push(children[0].token().unwrap().text().to_string()),
+            _ => (),
+        }
+        Ok(())
+    }
+
+    fn on_comment_parsed(&mut self, _token: Token<'t>) {}
+
+    fn on_ambiguity(&mut self, ambiguity: &Ambiguity<'t>) -> usize {
+        assert_eq!("Expr", ambiguity.non_terminal);
+        assert_eq!(2, ambiguity.alternatives.len());
+        self.ambiguities += 1;
+        self.choice
+    }
+}
+
+// Returns the bracketed input and the number of ambiguities
+fn parse(input: &str, choice: usize) -> Result<(String, usize)> {
+    let mut glr_parser =
+        GLRParser::new(2, &PARSE_TABLE, PRODUCTIONS, TERMINAL_NAMES, NON_TERMINALS);
+    let mut bracketing = Bracketing {
+        choice,
+        ..Default::default()
+    };
+    glr_parser.parse(
+        TokenStream::new(input, "test", &SCANNERS, 1).unwrap(),
+        &mut bracketing,
+    )?;
+    Ok((bracketing.stack.pop().unwrap(), bracketing.ambiguities))
+}
+
+#[test]
+fn unambiguous_input() {
+    assert_eq!(("(1+2)".to_string(), 0), parse("1 + 2", 0).unwrap());
+}
+
+#[test]
+fn chosen_alternative_is_used() {
+    assert_eq!(("((1+2)+3)".to_string(), 1), parse("1 + 2 + 3", 0).unwrap());
+    assert_eq!(("(1+(2+3))".to_string(), 1), parse("1 + 2 + 3", 1).unwrap());
+}
+
+#[test]
+fn parse_forest_contains_all_derivations() {
+    let mut glr_parser =
+        GLRParser::new(2, &PARSE_TABLE, PRODUCTIONS, TERMINAL_NAMES, NON_TERMINALS);
+    let forest = glr_parser
+        .parse_forest(TokenStream::new("1 + 2", "test", &SCANNERS, 1).unwrap())
+        .unwrap();
+    assert!(!forest.is_ambiguous());
+    let forest = glr_parser
+        .parse_forest(TokenStream::new("1 + 2 + 3 + 4", "test", &SCANNERS, 1).unwrap())
+        .unwrap();
+    assert!(forest.is_ambiguous());
+}
+
+#[test]
+fn syntax_error() {
+    match parse("1 + + 2", 0) {
+        Err(ParolError::ParserError(ParserError::SyntaxErrors { entries })) => {
+            assert_eq!(1, entries.len());
+            assert_eq!(5, entries[0].error_location.start_column);
+        }
+        result => panic!("Unexpected result: {:?}", result.map(|(s, _)| s)),
+    }
+}
+
+#[test]
+fn long_ambiguous_input() {
+    // The number of parse trees grows exponentially with the number of operands, the forest
+    // doesn't
+    let mut glr_parser =
+        GLRParser::new(2, &PARSE_TABLE, PRODUCTIONS, TERMINAL_NAMES, NON_TERMINALS);
+    let input = (1..=40)
+        .map(|i| i.to_string())
+        .collect::<Vec<_>>()
+        .join(" + ");
+    let forest = glr_parser
+        .parse_forest(TokenStream::new(&input, "test", &SCANNERS, 1).unwrap())
+        .unwrap();
+    assert!(forest.is_ambiguous());
+    assert!(forest.nodes.len() < 1000);
+}
@@ -2,11 +2,12 @@ use parol_runtime::lexer::tokenizer::{
     ERROR_TOKEN, NEW_LINE_TOKEN, UNMATCHABLE_TOKEN, WHITESPACE_TOKEN,
 };
 use parol_runtime::once_cell::sync::Lazy;
-use parol_runtime::{LocationBuilder, ScannerConfig};
-use parol_runtime::{Token, TokenStream, Tokenizer};
+use parol_runtime::{LexerError, LocationBuilder, ScannerConfig};
+use parol_runtime::{Token, TokenIter, TokenStream, Tokenizer};
 use std::borrow::Cow;
 use std::cell::RefCell;
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
 
 const PAROL_CFG_1: &str = r#"%start Grammar
 %%
@@ -143,3 +144,322 @@ fn lookahead_beyond_buffer_must_fail() {
     // This must fail
     println!("{:?}", token_stream.borrow_mut().lookahead(0).unwrap());
 }
+
+const LONGEST_MATCH_TERMINALS: &[&str; 10] = &[
+    /*  0 */ UNMATCHABLE_TOKEN, // token::EOI
+    /*  1 */ UNMATCHABLE_TOKEN, // token::NEW_LINE
+    /*  2 */ UNMATCHABLE_TOKEN, // token::WHITESPACE
+    /*  3 */ UNMATCHABLE_TOKEN, // token::LINE_COMMENT
+    /*  4 */ UNMATCHABLE_TOKEN, // token::BLOCK_COMMENT
+    /*  5 */ r"-",
+    /*  6 */ r"--",
+    /*  7 */ r"if",
+    /*  8 */ r"[a-zA-Z_]\w*",
+    /*  9 */ ERROR_TOKEN,
+];
+
+static LONGEST_MATCH_TOKENIZER: Lazy<Tokenizer> = Lazy::new(|| {
+    Tokenizer::build_longest_match(LONGEST_MATCH_TERMINALS, SCANNER_0, &[5, 6, 7, 8]).unwrap()
+});
+
+#[test]
+fn longest_match_tokenization() {
+    let token_iter = TokenIter::new(
+        &LONGEST_MATCH_TOKENIZER,
+        "x--- if iffy",
+        Arc::new(PathBuf::default()),
+        0,
+    );
+    let tokens = token_iter
+        .filter(|t| !t.is_skip_token())
+        .map(|t| (t.text().to_string(), t.token_type))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        vec![
+            ("x".to_string(), 8),
+            // The longer match wins although "-" is defined first
+            ("--".to_string(), 6),
+            ("-".to_string(), 5),
+            // A tie is resolved in favor of the terminal defined first
+            ("if".to_string(), 7),
+            ("iffy".to_string(), 8),
+        ],
+        tokens
+    );
+}
+
+const EMPTY_MATCH_TERMINALS: &[&str; 8] = &[
+    /*  0 */ UNMATCHABLE_TOKEN, // token::EOI
+    /*  1 */ UNMATCHABLE_TOKEN, // token::NEW_LINE
+    /*  2 */ UNMATCHABLE_TOKEN, // token::WHITESPACE
+    /*  3 */ UNMATCHABLE_TOKEN, // token::LINE_COMMENT
+    /*  4 */ UNMATCHABLE_TOKEN, // token::BLOCK_COMMENT
+    /*  5 */ r"b",
+    /*  6 */ r"a?",
+    /*  7 */ ERROR_TOKEN,
+];
+
+static EMPTY_MATCH_TOKENIZER: Lazy<Tokenizer> =
+    Lazy::new(|| Tokenizer::build(EMPTY_MATCH_TERMINALS, SCANNER_0, &[5, 6]).unwrap());
+
+#[test]
+fn empty_token_match_stops_iteration() {
+    let mut token_iter = TokenIter::new(
+        &EMPTY_MATCH_TOKENIZER,
+        "ab c",
+        Arc::new(PathBuf::default()),
+        1,
+    );
+    let tokens = token_iter
+        .by_ref()
+        .map(|t| (t.text().to_string(), t.token_type))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        vec![
+            ("a".to_string(), 6),
+            ("b".to_string(), 5),
+            (" ".to_string(), 2)
+        ],
+        tokens
+    );
+    match token_iter.take_error() {
+        Some(LexerError::EmptyTokenMatch {
+            token_type,
+            location,
+        }) => {
+            assert_eq!(6, token_type);
+            assert_eq!((1, 4), (location.start_line, location.start_column));
+        }
+        e => panic!("Expected an EmptyTokenMatch error, got {:?}", e),
+    }
+    // The iteration doesn't resume
+    assert!(token_iter.next().is_none());
+}
+
+const KEYWORD_TERMINALS: &[&str; 11] = &[
+    /*  0 */ UNMATCHABLE_TOKEN, // token::EOI
+    /*  1 */ UNMATCHABLE_TOKEN, // token::NEW_LINE
+    /*  2 */ UNMATCHABLE_TOKEN, // token::WHITESPACE
+    /*  3 */ UNMATCHABLE_TOKEN, // token::LINE_COMMENT
+    /*  4 */ UNMATCHABLE_TOKEN, // token::BLOCK_COMMENT
+    /*  5 */ r"if",
+    /*  6 */ r"then",
+    /*  7 */ r"(?i:end)",
+    /*  8 */ r"<>",
+    /*  9 */ r"[a-zA-Z_]\w*",
+    /* 10 */ ERROR_TOKEN,
+];
+
+static KEYWORD_TOKENIZER: Lazy<Tokenizer> = Lazy::new(|| {
+    // The keywords are placed after the identifier
+    Tokenizer::build(KEYWORD_TERMINALS, SCANNER_0, &[9, 5, 6, 7, 8])
+        .unwrap()
+        .with_keywords(KEYWORD_TERMINALS, &[5, 6, 7, 8])
+        .unwrap()
+});
+
+#[test]
+fn keyword_tokenization() {
+    let token_iter = TokenIter::new(
+        &KEYWORD_TOKENIZER,
+        "if iffy then End <> x",
+        Arc::new(PathBuf::default()),
+        0,
+    );
+    let tokens = token_iter
+        .filter(|t| !t.is_skip_token())
+        .map(|t| (t.text().to_string(), t.token_type))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        vec![
+            ("if".to_string(), 5),
+            // A keyword that matches only a part of the identifier doesn't win
+            ("iffy".to_string(), 9),
+            ("then".to_string(), 6),
+            ("End".to_string(), 7),
+            // Keywords that no other terminal matches are still found
+            ("<>".to_string(), 8),
+            ("x".to_string(), 9),
+        ],
+        tokens
+    );
+}
+
+const NESTED_COMMENT_SCANNER: &[&str; 5] = &[
+    /*  0 */ UNMATCHABLE_TOKEN, // token::EOI
+    /*  1 */ NEW_LINE_TOKEN, // token::NEW_LINE
+    /*  2 */ WHITESPACE_TOKEN, // token::WHITESPACE
+    /*  3 */ UNMATCHABLE_TOKEN, // token::LINE_COMMENT
+    /*  4 */ r"(/\*)", // token::BLOCK_COMMENT, only the opening delimiter
+];
+
+static NESTED_COMMENT_TOKENIZER: Lazy<Tokenizer> = Lazy::new(|| {
+    Tokenizer::build(TERMINALS, NESTED_COMMENT_SCANNER, &[5, 6, 7, 8, 9, 10])
+        .unwrap()
+        .with_nested_block_comments(&[(r"/\*", r"\*/")])
+        .unwrap()
+});
+
+#[test]
+fn nested_block_comment_tokenization() {
+    let token_iter = TokenIter::new(
+        &NESTED_COMMENT_TOKENIZER,
+        "a /* b /* c */\n d */ e /**/ f",
+        Arc::new(PathBuf::default()),
+        0,
+    );
+    let tokens = token_iter
+        .filter(|t| !t.is_skip_token() || t.is_comment_token())
+        .map(|t| {
+            (
+                t.text().to_string(),
+                t.token_type,
+                t.location.start_line,
+                t.location.start_column,
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        vec![
+            ("a".to_string(), 9, 1, 1),
+            ("/* b /* c */\n d */".to_string(), 4, 1, 3),
+            ("e".to_string(), 9, 2, 7),
+            ("/**/".to_string(), 4, 2, 9),
+            ("f".to_string(), 9, 2, 14),
+        ],
+        tokens
+    );
+}
+
+#[test]
+fn unterminated_nested_block_comment() {
+    let mut token_iter = TokenIter::new(
+        &NESTED_COMMENT_TOKENIZER,
+        "a /* b /* c */",
+        Arc::new(PathBuf::default()),
+        1,
+    );
+    let tokens = token_iter
+        .by_ref()
+        .filter(|t| !t.is_skip_token())
+        .map(|t| t.text().to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(vec!["a".to_string()], tokens);
+    match token_iter.take_error() {
+        Some(LexerError::UnterminatedBlockComment { location }) => {
+            assert_eq!((1, 3), (location.start_line, location.start_column));
+        }
+        e => panic!("Expected an UnterminatedBlockComment error, got {:?}", e),
+    }
+}
+
+const TRAILING_CONTEXT_TERMINALS: &[&str; 9] = &[
+    /*  0 */ UNMATCHABLE_TOKEN, // token::EOI
+    /*  1 */ UNMATCHABLE_TOKEN, // token::NEW_LINE
+    /*  2 */ UNMATCHABLE_TOKEN, // token::WHITESPACE
+    /*  3 */ UNMATCHABLE_TOKEN, // token::LINE_COMMENT
+    /*  4 */ UNMATCHABLE_TOKEN, // token::BLOCK_COMMENT
+    /*  5 */ r"(?:[0-9]+)(?:\.\.)", // An integer followed by the range operator
+    /*  6 */ r"[0-9]+(?:\.[0-9]*)?",
+    /*  7 */ r"\.\.",
+    /*  8 */ ERROR_TOKEN,
+];
+
+static TRAILING_CONTEXT_TOKENIZER: Lazy<Tokenizer> = Lazy::new(|| {
+    Tokenizer::build(TRAILING_CONTEXT_TERMINALS, SCANNER_0, &[5, 6, 7])
+        .unwrap()
+        .with_trailing_contexts(&[(5, r"[0-9]+", r"\.\.")])
+        .unwrap()
+});
+
+#[test]
+fn trailing_context_tokenization() {
+    let token_iter = TokenIter::new(
+        &TRAILING_CONTEXT_TOKENIZER,
+        "1..10 1.5 2. 3..",
+        Arc::new(PathBuf::default()),
+        0,
+    );
+    let tokens = token_iter
+        .filter(|t| !t.is_skip_token())
+        .map(|t| (t.text().to_string(), t.token_type, t.location.start_column))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        vec![
+            // The trailing context isn't part of the integer and is scanned again
+            ("1".to_string(), 5, 1),
+            ("..".to_string(), 7, 2),
+            ("10".to_string(), 6, 4),
+            ("1.5".to_string(), 6, 7),
+            ("2.".to_string(), 6, 11),
+            ("3".to_string(), 5, 14),
+            ("..".to_string(), 7, 15),
+        ],
+        tokens
+    );
+}
+
+const OFFSIDE_TERMINALS: &[&str; 11] = &[
+    /*  0 */ UNMATCHABLE_TOKEN, // token::EOI
+    /*  1 */ UNMATCHABLE_TOKEN, // token::NEW_LINE
+    /*  2 */ UNMATCHABLE_TOKEN, // token::WHITESPACE
+    /*  3 */ UNMATCHABLE_TOKEN, // token::LINE_COMMENT
+    /*  4 */ UNMATCHABLE_TOKEN, // token::BLOCK_COMMENT
+    /*  5 */ r":",
+    /*  6 */ r"[a-zA-Z_]\w*",
+    /*  7 */ r"<INDENT>", // Synthesized
+    /*  8 */ r"<DEDENT>", // Synthesized
+    /*  9 */ r"<NEWLINE>", // Synthesized
+    /* 10 */ ERROR_TOKEN,
+];
+
+static OFFSIDE_TOKENIZERS: Lazy<Vec<ScannerConfig>> = Lazy::new(|| {
+    vec![ScannerConfig {
+        name: "INITIAL",
+        tokenizer: Tokenizer::build(OFFSIDE_TERMINALS, SCANNER_0, &[5, 6])
+            .unwrap()
+            .with_offside(7, 8, 9),
+        transitions: &[],
+    }]
+});
+
+fn offside_tokens(input: &str) -> Result<Vec<String>, LexerError> {
+    let file_name: Cow<'static, Path> = Cow::Owned(PathBuf::default());
+    let mut token_stream = TokenStream::new(input, file_name, &OFFSIDE_TOKENIZERS, 2)?;
+    let mut tokens = Vec::new();
+    loop {
+        let token = token_stream.consume()?;
+        tokens.push(match token.token_type {
+            0 => break,
+            7 => "INDENT".to_string(),
+            8 => "DEDENT".to_string(),
+            9 => "NEWLINE".to_string(),
+            _ => token.text().to_string(),
+        });
+    }
+    Ok(tokens)
+}
+
+#[test]
+fn offside_token_synthesis() {
+    let input = "if a:\n    b\n\n      // comment\n    c:\n        d\ne:\n  f\n";
+    let tokens = offside_tokens(input).unwrap();
+    assert_eq!(
+        vec![
+            "if", "a", ":", "NEWLINE", "INDENT", "b", "NEWLINE", "c", ":", "NEWLINE", "INDENT",
+            "d", "NEWLINE", "DEDENT", "DEDENT", "e", ":", "NEWLINE", "INDENT", "f", "NEWLINE",
+            "DEDENT",
+        ],
+        tokens
+    );
+}
+
+#[test]
+fn offside_inconsistent_dedent() {
+    match offside_tokens("a:\n    b\n  c") {
+        Err(LexerError::InconsistentDedent { location }) => {
+            assert_eq!((3, 3), (location.start_line, location.start_column));
+        }
+        r => panic!("Expected an InconsistentDedent error, got {:?}", r),
+    }
+}
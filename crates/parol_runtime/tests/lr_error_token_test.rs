@@ -0,0 +1,335 @@
+//! This test is based on the following LALR(1) grammar with a resynchronization rule:
+//! ```parol
+//! %start List
+//! %grammar_type 'lalr(1)'
+//!
+//! %%
+//!
+//! List: { Stmt };
+//! Stmt: Expr ';' | error ';';
+//! Expr: Num | Expr '+' Num | '(' Expr ')';
+//! Num: /[0-9]+/;
+//! ```
+//! The parser recovers from syntax errors with the help of the error token.
+
+use parol_runtime::lexer::tokenizer::{
+    ERROR_TOKEN, NEW_LINE_TOKEN, UNMATCHABLE_TOKEN, WHITESPACE_TOKEN,
+};
+use parol_runtime::lr_parser::{LR1State, LRAction, LRParseTable, LRParser, LRProduction};
+use parol_runtime::once_cell::sync::Lazy;
+use parol_runtime::parser::{ParseTreeType, UserActionsTrait};
+use parol_runtime::{
+    ParolError, ParserError, Result, ScannerConfig, TerminalIndex, Token, TokenStream, Tokenizer,
+};
+
+const TERMINALS: &[&str; 11] = &[
+    /*  0 */ UNMATCHABLE_TOKEN,
+    /*  1 */ UNMATCHABLE_TOKEN,
+    /*  2 */ UNMATCHABLE_TOKEN,
+    /*  3 */ UNMATCHABLE_TOKEN,
+    /*  4 */ UNMATCHABLE_TOKEN,
+    /*  5 */ r";",
+    /*  6 */ r"\+",
+    /*  7 */ r"\(",
+    /*  8 */ r"\)",
+    /*  9 */ r"[0-9]+",
+    /* 10 */ ERROR_TOKEN,
+];
+
+const TERMINAL_NAMES: &[&str; 11] = &[
+    /*  0 */ "EndOfInput",
+    /*  1 */ "Newline",
+    /*  2 */ "Whitespace",
+    /*  3 */ "LineComment",
+    /*  4 */ "BlockComment",
+    /*  5 */ "Semicolon",
+    /*  6 */ "Plus",
+    /*  7 */ "LParen",
+    /*  8 */ "RParen",
+    /*  9 */ "Num",
+    /* 10 */ "Error",
+];
+
+/* SCANNER_0: "INITIAL" */
+const SCANNER_0: (&[&str; 5], &[TerminalIndex; 5]) = (
+    &[
+        /*  0 */ UNMATCHABLE_TOKEN,
+        /*  1 */ NEW_LINE_TOKEN,
+        /*  2 */ WHITESPACE_TOKEN,
+        /*  3 */ UNMATCHABLE_TOKEN,
+        /*  4 */ UNMATCHABLE_TOKEN,
+    ],
+    &[
+        5, /* Semicolon */
+        6, /* Plus */
+        7, /* LParen */
+        8, /* RParen */
+        9, /* Num */
+    ],
+);
+
+const NON_TERMINALS: &[&str; 5] = &[
+    /* 0 */ "Expr", /* 1 */ "List", /* 2 */ "ListList", /* 3 */ "Num",
+    /* 4 */ "Stmt",
+];
+
+static PARSE_TABLE: LRParseTable = LRParseTable {
+    actions: &[
+        /* 0 */ LRAction::Shift(2),
+        /* 1 */ LRAction::Shift(3),
+        /* 2 */ LRAction::Shift(4),
+        /* 3 */ LRAction::Shift(9),
+        /* 4 */ LRAction::Shift(10),
+        /* 5 */ LRAction::Shift(11),
+        /* 6 */ LRAction::Shift(12),
+        /* 7 */ LRAction::Reduce(0 /* Expr */, 5),
+        /* 8 */ LRAction::Reduce(0 /* Expr */, 6),
+        /* 9 */ LRAction::Reduce(0 /* Expr */, 7),
+        /* 10 */ LRAction::Reduce(2 /* ListList */, 1),
+        /* 11 */ LRAction::Reduce(2 /* ListList */, 2),
+        /* 12 */ LRAction::Reduce(3 /* Num */, 8),
+        /* 13 */ LRAction::Reduce(4 /* Stmt */, 3),
+        /* 14 */ LRAction::Reduce(4 /* Stmt */, 4),
+        /* 15 */ LRAction::Accept,
+    ],
+    states: &[
+        // State 0
+        LR1State {
+            actions: &[
+                (0, 11),  /* '<$>' => LRAction::Reduce(ListList, 2) */
+                (7, 11),  /* '(' => LRAction::Reduce(ListList, 2) */
+                (9, 11),  /* '[0-9]+' => LRAction::Reduce(ListList, 2) */
+                (10, 11), /* 'error' => LRAction::Reduce(ListList, 2) */
+            ],
+            gotos: &[(2, 1) /* ListList => 1 */],
+        },
+        // State 1
+        LR1State {
+            actions: &[
+                (0, 15), /* '<$>' => LRAction::Accept */
+                (7, 0),  /* '(' => LRAction::Shift(2) */
+                (9, 1),  /* '[0-9]+' => LRAction::Shift(3) */
+                (10, 2), /* 'error' => LRAction::Shift(4) */
+            ],
+            gotos: &[
+                (0, 5), /* Expr => 5 */
+                (3, 6), /* Num => 6 */
+                (4, 7), /* Stmt => 7 */
+            ],
+        },
+        // State 2
+        LR1State {
+            actions: &[
+                (7, 0), /* '(' => LRAction::Shift(2) */
+                (9, 1), /* '[0-9]+' => LRAction::Shift(3) */
+            ],
+            gotos: &[(0, 8) /* Expr => 8 */, (3, 6) /* Num => 6 */],
+        },
+        // State 3
+        LR1State {
+            actions: &[
+                (5, 12), /* ';' => LRAction::Reduce(Num, 8) */
+                (6, 12), /* '+' => LRAction::Reduce(Num, 8) */
+                (8, 12), /* ')' => LRAction::Reduce(Num, 8) */
+            ],
+            gotos: &[],
+        },
+        // State 4
+        LR1State {
+            actions: &[(5, 3) /* ';' => LRAction::Shift(9) */],
+            gotos: &[],
+        },
+        // State 5
+        LR1State {
+            actions: &[
+                (5, 4), /* ';' => LRAction::Shift(10) */
+                (6, 5), /* '+' => LRAction::Shift(11) */
+            ],
+            gotos: &[],
+        },
+        // State 6
+        LR1State {
+            actions: &[
+                (5, 7), /* ';' => LRAction::Reduce(Expr, 5) */
+                (6, 7), /* '+' => LRAction::Reduce(Expr, 5) */
+                (8, 7), /* ')' => LRAction::Reduce(Expr, 5) */
+            ],
+            gotos: &[],
+        },
+        // State 7
+        LR1State {
+            actions: &[
+                (0, 10),  /* '<$>' => LRAction::Reduce(ListList, 1) */
+                (7, 10),  /* '(' => LRAction::Reduce(ListList, 1) */
+                (9, 10),  /* '[0-9]+' => LRAction::Reduce(ListList, 1) */
+                (10, 10), /* 'error' => LRAction::Reduce(ListList, 1) */
+            ],
+            gotos: &[],
+        },
+        // State 8
+        LR1State {
+            actions: &[
+                (6, 5), /* '+' => LRAction::Shift(11) */
+                (8, 6), /* ')' => LRAction::Shift(12) */
+            ],
+            gotos: &[],
+        },
+        // State 9
+        LR1State {
+            actions: &[
+                (0, 14),  /* '<$>' => LRAction::Reduce(Stmt, 4) */
+                (7, 14),  /* '(' => LRAction::Reduce(Stmt, 4) */
+                (9, 14),  /* '[0-9]+' => LRAction::Reduce(Stmt, 4) */
+                (10, 14), /* 'error' => LRAction::Reduce(Stmt, 4) */
+            ],
+            gotos: &[],
+        },
+        // State 10
+        LR1State {
+            actions: &[
+                (0, 13),  /* '<$>' => LRAction::Reduce(Stmt, 3) */
+                (7, 13),  /* '(' => LRAction::Reduce(Stmt, 3) */
+                (9, 13),  /* '[0-9]+' => LRAction::Reduce(Stmt, 3) */
+                (10, 13), /* 'error' => LRAction::Reduce(Stmt, 3) */
+            ],
+            gotos: &[],
+        },
+        // State 11
+        LR1State {
+            actions: &[(9, 1) /* '[0-9]+' => LRAction::Shift(3) */],
+            gotos: &[(3, 13) /* Num => 13 */],
+        },
+        // State 12
+        LR1State {
+            actions: &[
+                (5, 9), /* ';' => LRAction::Reduce(Expr, 7) */
+                (6, 9), /* '+' => LRAction::Reduce(Expr, 7) */
+                (8, 9), /* ')' => LRAction::Reduce(Expr, 7) */
+            ],
+            gotos: &[],
+        },
+        // State 13
+        LR1State {
+            actions: &[
+                (5, 8), /* ';' => LRAction::Reduce(Expr, 6) */
+                (6, 8), /* '+' => LRAction::Reduce(Expr, 6) */
+                (8, 8), /* ')' => LRAction::Reduce(Expr, 6) */
+            ],
+            gotos: &[],
+        },
+    ],
+};
+
+const PRODUCTIONS: &[LRProduction; 9] = &[
+    // 0 - List: ListList /* Vec */;
+    LRProduction { lhs: 1, len: 1 },
+    // 1 - ListList: ListList Stmt;
+    LRProduction { lhs: 2, len: 2 },
+    // 2 - ListList: ;
+    LRProduction { lhs: 2, len: 0 },
+    // 3 - Stmt: Expr ';';
+    LRProduction { lhs: 4, len: 2 },
+    // 4 - Stmt: error ';';
+    LRProduction { lhs: 4, len: 2 },
+    // 5 - Expr: Num;
+    LRProduction { lhs: 0, len: 1 },
+    // 6 - Expr: Expr '+' Num;
+    LRProduction { lhs: 0, len: 3 },
+    // 7 - Expr: '(' Expr ')';
+    LRProduction { lhs: 0, len: 3 },
+    // 8 - Num: /[0-9]+/;
+    LRProduction { lhs: 3, len: 1 },
+];
+
+static SCANNERS: Lazy<Vec<ScannerConfig>> = Lazy::new(|| {
+    vec![ScannerConfig::new(
+        "INITIAL",
+        Tokenizer::build(TERMINALS, SCANNER_0.0, SCANNER_0.1).unwrap(),
+        &[],
+    )]
+});
+
+// Counts the reduced statements, the resynchronized statements and the discarded non-terminals
+#[derive(Debug, Default, PartialEq)]
+struct Counter {
+    statements: usize,
+    resynchronized: usize,
+    discarded: usize,
+}
+
+impl<'t> UserActionsTrait<'t> for Counter {
+    fn call_semantic_action_for_production_number(
+        &mut self,
+        prod_num: usize,
+        _children: &[ParseTreeType<'t>],
+    ) -> Result<()> {
+        match prod_num {
+            3 => self.statements += 1,
+            4 => self.resynchronized += 1,
+            _ => (),
+        }
+        Ok(())
+    }
+
+    fn on_comment_parsed(&mut self, _token: Token<'t>) {}
+
+    fn on_non_terminal_discarded(&mut self) {
+        self.discarded += 1;
+    }
+}
+
+// Returns the counters and the columns of the syntax errors
+fn parse(input: &str) -> (Counter, Vec<usize>) {
+    let mut lr_parser = LRParser::new(1, &PARSE_TABLE, PRODUCTIONS, TERMINAL_NAMES, NON_TERMINALS);
+    let mut counter = Counter::default();
+    let result = lr_parser.parse(
+        TokenStream::new(input, "test", &SCANNERS, 1).unwrap(),
+        &mut counter,
+    );
+    match result {
+        Ok(_) => (counter, vec![]),
+        Err(ParolError::ParserError(ParserError::SyntaxErrors { entries })) => (
+            counter,
+            entries
+                .iter()
+                .map(|e| e.error_location.start_column as usize)
+                .collect(),
+        ),
+        Err(e) => panic!("Unexpected error: {}", e),
+    }
+}
+
+fn counter(statements: usize, resynchronized: usize, discarded: usize) -> Counter {
+    Counter {
+        statements,
+        resynchronized,
+        discarded,
+    }
+}
+
+#[test]
+fn no_errors() {
+    assert_eq!((counter(2, 0, 0), vec![]), parse("1 + 2; (3);"));
+}
+
+#[test]
+fn parser_resynchronizes_at_the_error_rule() {
+    // The Expr before the second '+' is discarded, the error token is shifted and the tokens up
+    // to the ';' are skipped
+    assert_eq!((counter(1, 1, 1), vec![5]), parse("1 + + 2; 3;"));
+}
+
+#[test]
+fn errors_in_the_first_three_shifts_after_a_recovery_are_suppressed() {
+    // The ')' follows two shifts after the recovery
+    assert_eq!((counter(0, 2, 2), vec![5]), parse("1 + ; 2 ) 3;"));
+    // The ')' follows three shifts after the recovery
+    assert_eq!((counter(1, 2, 1), vec![5, 10]), parse("1 + ; 2; ) 3;"));
+}
+
+#[test]
+fn unmatched_character_is_a_syntax_error() {
+    // The scanner delivers the '$' with the type of the error token
+    assert_eq!((counter(1, 1, 0), vec![1]), parse("$; 1;"));
+    assert_eq!((counter(1, 1, 0), vec![3]), parse("1 $ 2; 3;"));
+}
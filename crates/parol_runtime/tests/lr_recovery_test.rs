@@ -0,0 +1,293 @@
+//! This test is based on the following LALR(1) grammar without resynchronization rules:
+//! ```parol
+//! %start List
+//! %grammar_type 'lalr(1)'
+//!
+//! %%
+//!
+//! List: { Stmt };
+//! Stmt: Expr ';';
+//! Expr: Num | Expr '+' Num | '(' Expr ')';
+//! Num: /[0-9]+/;
+//! ```
+//! The parser recovers from syntax errors automatically and reports all of them.
+
+use parol_runtime::lexer::tokenizer::{
+    ERROR_TOKEN, NEW_LINE_TOKEN, UNMATCHABLE_TOKEN, WHITESPACE_TOKEN,
+};
+use parol_runtime::lr_parser::{LR1State, LRAction, LRParseTable, LRParser, LRProduction};
+use parol_runtime::once_cell::sync::Lazy;
+use parol_runtime::parser::{ParseTreeType, UserActionsTrait};
+use parol_runtime::{
+    ParolError, ParserError, Result, ScannerConfig, TerminalIndex, Token, TokenStream, Tokenizer,
+};
+
+const TERMINALS: &[&str; 11] = &[
+    /*  0 */ UNMATCHABLE_TOKEN,
+    /*  1 */ UNMATCHABLE_TOKEN,
+    /*  2 */ UNMATCHABLE_TOKEN,
+    /*  3 */ UNMATCHABLE_TOKEN,
+    /*  4 */ UNMATCHABLE_TOKEN,
+    /*  5 */ r";",
+    /*  6 */ r"\+",
+    /*  7 */ r"\(",
+    /*  8 */ r"\)",
+    /*  9 */ r"[0-9]+",
+    /* 10 */ ERROR_TOKEN,
+];
+
+const TERMINAL_NAMES: &[&str; 11] = &[
+    /*  0 */ "EndOfInput",
+    /*  1 */ "Newline",
+    /*  2 */ "Whitespace",
+    /*  3 */ "LineComment",
+    /*  4 */ "BlockComment",
+    /*  5 */ "Semicolon",
+    /*  6 */ "Plus",
+    /*  7 */ "LParen",
+    /*  8 */ "RParen",
+    /*  9 */ "Num",
+    /* 10 */ "Error",
+];
+
+/* SCANNER_0: "INITIAL" */
+const SCANNER_0: (&[&str; 5], &[TerminalIndex; 5]) = (
+    &[
+        /*  0 */ UNMATCHABLE_TOKEN,
+        /*  1 */ NEW_LINE_TOKEN,
+        /*  2 */ WHITESPACE_TOKEN,
+        /*  3 */ UNMATCHABLE_TOKEN,
+        /*  4 */ UNMATCHABLE_TOKEN,
+    ],
+    &[
+        5, /* Semicolon */
+        6, /* Plus */
+        7, /* LParen */
+        8, /* RParen */
+        9, /* Num */
+    ],
+);
+
+const NON_TERMINALS: &[&str; 5] = &[
+    /* 0 */ "Expr", /* 1 */ "List", /* 2 */ "ListList", /* 3 */ "Num",
+    /* 4 */ "Stmt",
+];
+
+static PARSE_TABLE: LRParseTable = LRParseTable {
+    actions: &[
+        /* 0 */ LRAction::Shift(2),
+        /* 1 */ LRAction::Shift(3),
+        /* 2 */ LRAction::Shift(8),
+        /* 3 */ LRAction::Shift(9),
+        /* 4 */ LRAction::Shift(10),
+        /* 5 */ LRAction::Reduce(0 /* Expr */, 4),
+        /* 6 */ LRAction::Reduce(0 /* Expr */, 5),
+        /* 7 */ LRAction::Reduce(0 /* Expr */, 6),
+        /* 8 */ LRAction::Reduce(2 /* ListList */, 1),
+        /* 9 */ LRAction::Reduce(2 /* ListList */, 2),
+        /* 10 */ LRAction::Reduce(3 /* Num */, 7),
+        /* 11 */ LRAction::Reduce(4 /* Stmt */, 3),
+        /* 12 */ LRAction::Accept,
+    ],
+    states: &[
+        // State 0
+        LR1State {
+            actions: &[
+                (0, 9), /* '<$>' => LRAction::Reduce(ListList, 2) */
+                (7, 9), /* '(' => LRAction::Reduce(ListList, 2) */
+                (9, 9), /* '[0-9]+' => LRAction::Reduce(ListList, 2) */
+            ],
+            gotos: &[(2, 1) /* ListList => 1 */],
+        },
+        // State 1
+        LR1State {
+            actions: &[
+                (0, 12), /* '<$>' => LRAction::Accept */
+                (7, 0),  /* '(' => LRAction::Shift(2) */
+                (9, 1),  /* '[0-9]+' => LRAction::Shift(3) */
+            ],
+            gotos: &[
+                (0, 4), /* Expr => 4 */
+                (3, 5), /* Num => 5 */
+                (4, 6), /* Stmt => 6 */
+            ],
+        },
+        // State 2
+        LR1State {
+            actions: &[
+                (7, 0), /* '(' => LRAction::Shift(2) */
+                (9, 1), /* '[0-9]+' => LRAction::Shift(3) */
+            ],
+            gotos: &[(0, 7) /* Expr => 7 */, (3, 5) /* Num => 5 */],
+        },
+        // State 3
+        LR1State {
+            actions: &[
+                (5, 10), /* ';' => LRAction::Reduce(Num, 7) */
+                (6, 10), /* '+' => LRAction::Reduce(Num, 7) */
+                (8, 10), /* ')' => LRAction::Reduce(Num, 7) */
+            ],
+            gotos: &[],
+        },
+        // State 4
+        LR1State {
+            actions: &[
+                (5, 2), /* ';' => LRAction::Shift(8) */
+                (6, 3), /* '+' => LRAction::Shift(9) */
+            ],
+            gotos: &[],
+        },
+        // State 5
+        LR1State {
+            actions: &[
+                (5, 5), /* ';' => LRAction::Reduce(Expr, 4) */
+                (6, 5), /* '+' => LRAction::Reduce(Expr, 4) */
+                (8, 5), /* ')' => LRAction::Reduce(Expr, 4) */
+            ],
+            gotos: &[],
+        },
+        // State 6
+        LR1State {
+            actions: &[
+                (0, 8), /* '<$>' => LRAction::Reduce(ListList, 1) */
+                (7, 8), /* '(' => LRAction::Reduce(ListList, 1) */
+                (9, 8), /* '[0-9]+' => LRAction::Reduce(ListList, 1) */
+            ],
+            gotos: &[],
+        },
+        // State 7
+        LR1State {
+            actions: &[
+                (6, 3), /* '+' => LRAction::Shift(9) */
+                (8, 4), /* ')' => LRAction::Shift(10) */
+            ],
+            gotos: &[],
+        },
+        // State 8
+        LR1State {
+            actions: &[
+                (0, 11), /* '<$>' => LRAction::Reduce(Stmt, 3) */
+                (7, 11), /* '(' => LRAction::Reduce(Stmt, 3) */
+                (9, 11), /* '[0-9]+' => LRAction::Reduce(Stmt, 3) */
+            ],
+            gotos: &[],
+        },
+        // State 9
+        LR1State {
+            actions: &[(9, 1) /* '[0-9]+' => LRAction::Shift(3) */],
+            gotos: &[(3, 11) /* Num => 11 */],
+        },
+        // State 10
+        LR1State {
+            actions: &[
+                (5, 7), /* ';' => LRAction::Reduce(Expr, 6) */
+                (6, 7), /* '+' => LRAction::Reduce(Expr, 6) */
+                (8, 7), /* ')' => LRAction::Reduce(Expr, 6) */
+            ],
+            gotos: &[],
+        },
+        // State 11
+        LR1State {
+            actions: &[
+                (5, 6), /* ';' => LRAction::Reduce(Expr, 5) */
+                (6, 6), /* '+' => LRAction::Reduce(Expr, 5) */
+                (8, 6), /* ')' => LRAction::Reduce(Expr, 5) */
+            ],
+            gotos: &[],
+        },
+    ],
+};
+
+const PRODUCTIONS: &[LRProduction; 8] = &[
+    // 0 - List: ListList /* Vec */;
+    LRProduction { lhs: 1, len: 1 },
+    // 1 - ListList: ListList Stmt;
+    LRProduction { lhs: 2, len: 2 },
+    // 2 - ListList: ;
+    LRProduction { lhs: 2, len: 0 },
+    // 3 - Stmt: Expr ';';
+    LRProduction { lhs: 4, len: 2 },
+    // 4 - Expr: Num;
+    LRProduction { lhs: 0, len: 1 },
+    // 5 - Expr: Expr '+' Num;
+    LRProduction { lhs: 0, len: 3 },
+    // 6 - Expr: '(' Expr ')';
+    LRProduction { lhs: 0, len: 3 },
+    // 7 - Num: /[0-9]+/;
+    LRProduction { lhs: 3, len: 1 },
+];
+
+static SCANNERS: Lazy<Vec<ScannerConfig>> = Lazy::new(|| {
+    vec![ScannerConfig::new(
+        "INITIAL",
+        Tokenizer::build(TERMINALS, SCANNER_0.0, SCANNER_0.1).unwrap(),
+        &[],
+    )]
+});
+
+// Counts the reduced statements
+#[derive(Default)]
+struct StatementCounter(usize);
+
+impl<'t> UserActionsTrait<'t> for StatementCounter {
+    fn call_semantic_action_for_production_number(
+        &mut self,
+        prod_num: usize,
+        _children: &[ParseTreeType<'t>],
+    ) -> Result<()> {
+        if prod_num == 3 {
+            self.0 += 1;
+        }
+        Ok(())
+    }
+
+    fn on_comment_parsed(&mut self, _token: Token<'t>) {}
+}
+
+// Returns the number of statements and the columns of the syntax errors
+fn parse(input: &str) -> (usize, Vec<usize>) {
+    let mut lr_parser = LRParser::new(1, &PARSE_TABLE, PRODUCTIONS, TERMINAL_NAMES, NON_TERMINALS);
+    let mut statements = StatementCounter::default();
+    let result = lr_parser.parse(
+        TokenStream::new(input, "test", &SCANNERS, 1).unwrap(),
+        &mut statements,
+    );
+    match result {
+        Ok(_) => (statements.0, vec![]),
+        Err(ParolError::ParserError(ParserError::SyntaxErrors { entries })) => (
+            statements.0,
+            entries
+                .iter()
+                .map(|e| e.error_location.start_column as usize)
+                .collect(),
+        ),
+        Err(e) => panic!("Unexpected error: {}", e),
+    }
+}
+
+#[test]
+fn no_errors() {
+    assert_eq!((2, vec![]), parse("1 + 2; (3);"));
+}
+
+#[test]
+fn missing_token_is_inserted() {
+    // A ';' is inserted before the '3' and before the '6'
+    assert_eq!((5, vec![7, 17]), parse("1 + 2 3; (4); 5 6;"));
+}
+
+#[test]
+fn superfluous_token_is_deleted() {
+    // The second '+' and the ')' are deleted
+    assert_eq!((3, vec![5, 12]), parse("1 + + 2; 3 ) ; 4;"));
+}
+
+#[test]
+fn missing_tokens_at_end_of_input_are_inserted() {
+    assert_eq!((2, vec![6]), parse("1; 2 +"));
+}
+
+#[test]
+fn errors_in_the_first_tokens_after_a_recovery_are_suppressed() {
+    assert_eq!((3, vec![1]), parse("; ; ;"));
+}